@@ -0,0 +1,16 @@
+CREATE TABLE tasks (
+    task_id BIGINT NOT NULL AUTO_INCREMENT PRIMARY KEY,
+    created_time TIMESTAMP(6) NOT NULL
+);
+
+CREATE TABLE task_events (
+    event_id BIGINT NOT NULL AUTO_INCREMENT PRIMARY KEY,
+    task_id BIGINT NOT NULL,
+    dataset_id VARCHAR(100),
+    event_time TIMESTAMP(6) NOT NULL,
+    event_type VARCHAR(50) NOT NULL,
+    event_payload JSON NOT NULL
+);
+
+CREATE INDEX task_events_task_id_idx ON task_events (task_id);
+CREATE INDEX task_events_dataset_id_idx ON task_events (dataset_id);
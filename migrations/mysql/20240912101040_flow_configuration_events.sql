@@ -0,0 +1,23 @@
+CREATE TABLE flow_configuration_event (
+    event_id BIGINT NOT NULL AUTO_INCREMENT PRIMARY KEY,
+    created_time TIMESTAMP(6) NOT NULL
+);
+
+CREATE TABLE system_flow_configuration_events (
+    event_id BIGINT NOT NULL PRIMARY KEY,
+    system_flow_type ENUM('gc') NOT NULL,
+    event_type VARCHAR(50) NOT NULL,
+    event_time TIMESTAMP(6) NOT NULL,
+    event_payload JSON NOT NULL
+);
+
+CREATE TABLE dataset_flow_configuration_events (
+    event_id BIGINT NOT NULL PRIMARY KEY,
+    dataset_id VARCHAR(100) NOT NULL,
+    dataset_flow_type ENUM('ingest', 'execute_transform', 'hard_compaction', 'reset') NOT NULL,
+    event_type VARCHAR(50) NOT NULL,
+    event_time TIMESTAMP(6) NOT NULL,
+    event_payload JSON NOT NULL
+);
+
+CREATE INDEX dataset_flow_configuration_events_dataset_id_idx ON dataset_flow_configuration_events (dataset_id, dataset_flow_type);
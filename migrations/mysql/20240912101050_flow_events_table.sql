@@ -0,0 +1,29 @@
+CREATE TABLE flow_ids (
+    flow_id BIGINT NOT NULL AUTO_INCREMENT PRIMARY KEY,
+    created_time TIMESTAMP(6) NOT NULL
+);
+
+CREATE TABLE flows (
+    flow_id BIGINT NOT NULL PRIMARY KEY,
+    dataset_id VARCHAR(100),
+    dataset_flow_type ENUM('ingest', 'execute_transform', 'hard_compaction', 'reset'),
+    system_flow_type ENUM('gc'),
+    initiator VARCHAR(100),
+    flow_status ENUM('waiting', 'running', 'finished') NOT NULL,
+    last_attempt_at TIMESTAMP(6) NULL,
+    last_success_at TIMESTAMP(6) NULL
+);
+
+CREATE INDEX flows_dataset_id_idx ON flows (dataset_id, dataset_flow_type);
+CREATE INDEX flows_system_flow_type_idx ON flows (system_flow_type);
+CREATE INDEX flows_flow_status_idx ON flows (flow_status);
+
+CREATE TABLE flow_events (
+    event_id BIGINT NOT NULL AUTO_INCREMENT PRIMARY KEY,
+    flow_id BIGINT NOT NULL REFERENCES flows (flow_id),
+    event_time TIMESTAMP(6) NOT NULL,
+    event_type VARCHAR(50) NOT NULL,
+    event_payload JSON NOT NULL
+);
+
+CREATE INDEX flow_events_flow_id_idx ON flow_events (flow_id);
@@ -0,0 +1 @@
+ALTER TABLE dataset_env_vars ADD COLUMN encryption_key_id VARCHAR(100);
@@ -0,0 +1,11 @@
+CREATE TABLE outbox_dead_letters(
+    producer_name VARCHAR(200) NOT NULL,
+    consumer_name VARCHAR(200) NOT NULL,
+    message_id BIGINT NOT NULL,
+    content_json JSONB NOT NULL,
+    occurred_on timestamptz NOT NULL,
+    attempts INT NOT NULL,
+    last_error TEXT NOT NULL,
+    dead_lettered_at timestamptz NOT NULL,
+    PRIMARY KEY (producer_name, consumer_name, message_id)
+);
@@ -0,0 +1,30 @@
+CREATE SEQUENCE flow_id_seq AS BIGINT;
+
+CREATE TYPE flow_status_type AS ENUM ('waiting', 'running', 'finished');
+
+CREATE TABLE flows
+(
+    flow_id           BIGINT PRIMARY KEY,
+    dataset_id        VARCHAR(100),
+    dataset_flow_type dataset_flow_type,
+    system_flow_type  system_flow_type,
+    initiator         VARCHAR(100),
+    flow_status       flow_status_type NOT NULL,
+    last_attempt_at   TIMESTAMPTZ,
+    last_success_at   TIMESTAMPTZ
+);
+
+CREATE INDEX flows_dataset_id_idx ON flows (dataset_id, dataset_flow_type);
+CREATE INDEX flows_system_flow_type_idx ON flows (system_flow_type);
+CREATE INDEX flows_flow_status_idx ON flows (flow_status);
+
+CREATE TABLE flow_events
+(
+    event_id      BIGSERIAL PRIMARY KEY,
+    flow_id       BIGINT      NOT NULL REFERENCES flows (flow_id),
+    event_time    TIMESTAMPTZ NOT NULL,
+    event_type    VARCHAR(50) NOT NULL,
+    event_payload JSONB       NOT NULL
+);
+
+CREATE INDEX flow_events_flow_id_idx ON flow_events (flow_id);
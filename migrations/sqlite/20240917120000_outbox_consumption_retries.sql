@@ -0,0 +1,3 @@
+ALTER TABLE outbox_message_consumptions ADD COLUMN failed_message_id BIGINT;
+ALTER TABLE outbox_message_consumptions ADD COLUMN failed_attempts INT NOT NULL DEFAULT 0;
+ALTER TABLE outbox_message_consumptions ADD COLUMN next_attempt_at timestamptz;
@@ -0,0 +1,32 @@
+CREATE TABLE flow_ids
+(
+    flow_id      INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+    created_time timestamptz                       NOT NULL
+);
+
+CREATE TABLE flows
+(
+    flow_id           INTEGER PRIMARY KEY                                                                                    NOT NULL,
+    dataset_id        VARCHAR(100),
+    dataset_flow_type VARCHAR(20) CHECK ( dataset_flow_type IN ('ingest', 'execute_transform', 'hard_compaction', 'reset') ),
+    system_flow_type  VARCHAR(10) CHECK ( system_flow_type IN ('gc') ),
+    initiator         VARCHAR(100),
+    flow_status       VARCHAR(10) CHECK ( flow_status IN ('waiting', 'running', 'finished') )                                NOT NULL,
+    last_attempt_at   timestamptz,
+    last_success_at   timestamptz
+);
+
+CREATE INDEX flows_dataset_id_idx ON flows (dataset_id, dataset_flow_type);
+CREATE INDEX flows_system_flow_type_idx ON flows (system_flow_type);
+CREATE INDEX flows_flow_status_idx ON flows (flow_status);
+
+CREATE TABLE flow_events
+(
+    event_id      INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+    flow_id       INTEGER                           NOT NULL REFERENCES flows (flow_id),
+    event_time    timestamptz                       NOT NULL,
+    event_type    VARCHAR(50)                       NOT NULL,
+    event_payload JSONB                             NOT NULL
+);
+
+CREATE INDEX flow_events_flow_id_idx ON flow_events (flow_id);
@@ -0,0 +1,53 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use messaging_outbox as outbox;
+
+use crate::prelude::*;
+use crate::AdminGuard;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub struct AdminMut;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[Object]
+impl AdminMut {
+    /// Delivers a dead-lettered outbox message to its consumer once again.
+    /// The message is removed from dead letters if the consumer succeeds.
+    #[graphql(guard = "AdminGuard::new()")]
+    async fn replay_outbox_dead_letter(
+        &self,
+        ctx: &Context<'_>,
+        producer_name: String,
+        consumer_name: String,
+        message_id: i64,
+    ) -> Result<bool> {
+        let dead_letter_service = from_catalog::<dyn outbox::OutboxDeadLetterService>(ctx).unwrap();
+
+        match dead_letter_service
+            .replay_dead_letter(
+                &producer_name,
+                &consumer_name,
+                outbox::OutboxMessageID::new(message_id),
+            )
+            .await
+        {
+            Ok(()) => Ok(true),
+            Err(
+                e @ (outbox::ReplayDeadLetterError::NotFound(_)
+                | outbox::ReplayDeadLetterError::ConsumerFailed(_)),
+            ) => Err(GqlError::Gql(async_graphql::Error::new(e.to_string()))),
+            Err(outbox::ReplayDeadLetterError::Internal(e)) => Err(e.into()),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -144,6 +144,56 @@ impl DatasetMetadataMut {
 
         Ok(result)
     }
+
+    /// Disables the polling source of the root dataset
+    #[graphql(guard = "LoggedInGuard::new()")]
+    async fn disable_polling_source(&self, ctx: &Context<'_>) -> Result<DisableSourceResult> {
+        self.disable_source(ctx, odf::DisablePollingSource {}.into())
+            .await
+    }
+
+    /// Disables the push source with the specified name
+    #[graphql(guard = "LoggedInGuard::new()")]
+    async fn disable_push_source(
+        &self,
+        ctx: &Context<'_>,
+        source_name: String,
+    ) -> Result<DisableSourceResult> {
+        self.disable_source(ctx, odf::DisablePushSource { source_name }.into())
+            .await
+    }
+
+    #[graphql(skip)]
+    async fn disable_source(
+        &self,
+        ctx: &Context<'_>,
+        event: odf::MetadataEvent,
+    ) -> Result<DisableSourceResult> {
+        let commit_event = from_catalog::<dyn CommitDatasetEventUseCase>(ctx).unwrap();
+
+        let result = match commit_event
+            .execute(&self.dataset_handle, event, domain::CommitOpts::default())
+            .await
+        {
+            Ok(result) => DisableSourceResult::Success(CommitResultSuccess {
+                old_head: result.old_head.map(Into::into),
+                new_head: result.new_head.into(),
+            }),
+            Err(domain::CommitError::MetadataAppendError(e)) => {
+                DisableSourceResult::AppendError(CommitResultAppendError {
+                    message: e.to_string(),
+                })
+            }
+            Err(domain::CommitError::Access(_)) => {
+                return Err(make_dataset_access_error(&self.dataset_handle))
+            }
+            Err(
+                e @ (domain::CommitError::ObjectNotFound(_) | domain::CommitError::Internal(_)),
+            ) => return Err(e.int_err().into()),
+        };
+
+        Ok(result)
+    }
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -157,3 +207,12 @@ pub enum UpdateReadmeResult {
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Interface, Debug, Clone)]
+#[graphql(field(name = "message", ty = "String"))]
+pub enum DisableSourceResult {
+    Success(CommitResultSuccess),
+    AppendError(CommitResultAppendError),
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -16,9 +16,11 @@ use kamu_flow_system::{
     FlowConfigurationService,
     FlowKeyDataset,
     IngestRule,
+    RetryPolicy,
     Schedule,
     ScheduleCronError,
     SetFlowConfigurationError,
+    SetFlowRetryPolicyError,
     TransformRule,
 };
 use opendatafabric as odf;
@@ -226,6 +228,55 @@ impl DatasetFlowConfigsMut {
         ))
     }
 
+    /// Sets the policy of retrying failed tasks of the configured flow,
+    /// or disables retries when the policy is omitted
+    #[graphql(guard = "LoggedInGuard::new()")]
+    async fn set_config_retry_policy(
+        &self,
+        ctx: &Context<'_>,
+        dataset_flow_type: DatasetFlowType,
+        retry_policy: Option<FlowRetryPolicyInput>,
+    ) -> Result<SetFlowRetryPolicyResult> {
+        let retry_policy = match retry_policy.map(RetryPolicy::try_from).transpose() {
+            Ok(retry_policy) => retry_policy,
+            Err(e) => {
+                return Ok(SetFlowRetryPolicyResult::InvalidRetryPolicy(
+                    FlowInvalidRetryPolicy {
+                        reason: e.to_string(),
+                    },
+                ))
+            }
+        };
+
+        if let Some(e) =
+            ensure_expected_dataset_kind(ctx, &self.dataset_handle, dataset_flow_type).await?
+        {
+            return Ok(SetFlowRetryPolicyResult::IncompatibleDatasetKind(e));
+        }
+        ensure_scheduling_permission(ctx, &self.dataset_handle).await?;
+
+        let flow_config_service = from_catalog::<dyn FlowConfigurationService>(ctx).unwrap();
+
+        let res = flow_config_service
+            .set_retry_policy(
+                Utc::now(),
+                FlowKeyDataset::new(self.dataset_handle.id.clone(), dataset_flow_type.into())
+                    .into(),
+                retry_policy,
+            )
+            .await;
+
+        match res {
+            Ok(state) => Ok(SetFlowRetryPolicyResult::Success(SetFlowConfigSuccess {
+                config: state.into(),
+            })),
+            Err(SetFlowRetryPolicyError::NotFound(_)) => Ok(
+                SetFlowRetryPolicyResult::ConfigurationNotFound(FlowConfigurationNotFound),
+            ),
+            Err(SetFlowRetryPolicyError::Internal(e)) => Err(GqlError::Internal(e)),
+        }
+    }
+
     #[graphql(guard = "LoggedInGuard::new()")]
     async fn pause_flows(
         &self,
@@ -329,6 +380,29 @@ impl FlowInvalidCompactionConfig {
     }
 }
 
+#[derive(SimpleObject, Debug, Clone)]
+#[graphql(complex)]
+pub(crate) struct FlowInvalidRetryPolicy {
+    reason: String,
+}
+
+#[ComplexObject]
+impl FlowInvalidRetryPolicy {
+    pub async fn message(&self) -> String {
+        self.reason.clone()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct FlowConfigurationNotFound;
+
+#[Object]
+impl FlowConfigurationNotFound {
+    pub async fn message(&self) -> String {
+        "Flow is not configured".to_string()
+    }
+}
+
 #[derive(Interface)]
 #[graphql(field(name = "message", ty = "String"))]
 enum SetFlowCompactionConfigResult {
@@ -348,4 +422,13 @@ enum SetFlowTransformConfigResult {
     TypeIsNotSupported(FlowTypeIsNotSupported),
 }
 
+#[derive(Interface)]
+#[graphql(field(name = "message", ty = "String"))]
+enum SetFlowRetryPolicyResult {
+    Success(SetFlowConfigSuccess),
+    ConfigurationNotFound(FlowConfigurationNotFound),
+    IncompatibleDatasetKind(FlowIncompatibleDatasetKind),
+    InvalidRetryPolicy(FlowInvalidRetryPolicy),
+}
+
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
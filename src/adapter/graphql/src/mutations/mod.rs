@@ -11,6 +11,7 @@ mod auth_mut;
 
 mod account_mut;
 mod accounts_mut;
+mod admin_mut;
 mod dataset_env_vars_mut;
 mod dataset_metadata_mut;
 mod dataset_mut;
@@ -21,6 +22,7 @@ mod tasks_mut;
 
 pub(crate) use account_mut::*;
 pub(crate) use accounts_mut::*;
+pub(crate) use admin_mut::*;
 pub(crate) use auth_mut::*;
 pub(crate) use dataset_env_vars_mut::*;
 pub(crate) use dataset_metadata_mut::*;
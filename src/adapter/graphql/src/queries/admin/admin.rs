@@ -7,6 +7,9 @@
 // the Business Source License, use of this software will be governed
 // by the Apache License, Version 2.0.
 
+use messaging_outbox as outbox;
+
+use super::OutboxDeadLetter;
 use crate::prelude::*;
 use crate::AdminGuard;
 
@@ -19,4 +22,13 @@ impl Admin {
     async fn self_test(&self) -> Result<String> {
         Ok("OK".to_string())
     }
+
+    /// Messages that outbox consumers failed to process after exhausting all
+    /// retry attempts
+    #[graphql(guard = "AdminGuard::new()")]
+    async fn outbox_dead_letters(&self, ctx: &Context<'_>) -> Result<Vec<OutboxDeadLetter>> {
+        let dead_letter_service = from_catalog::<dyn outbox::OutboxDeadLetterService>(ctx).unwrap();
+        let dead_letters = dead_letter_service.list_dead_letters().await?;
+        Ok(dead_letters.into_iter().map(Into::into).collect())
+    }
 }
@@ -8,5 +8,7 @@
 // by the Apache License, Version 2.0.
 
 mod admin;
+mod outbox_dead_letter;
 
 pub(crate) use admin::*;
+pub(crate) use outbox_dead_letter::*;
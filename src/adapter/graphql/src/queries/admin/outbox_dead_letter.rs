@@ -0,0 +1,52 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use chrono::{DateTime, Utc};
+use messaging_outbox as outbox;
+
+use crate::prelude::*;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(SimpleObject, Debug, Clone)]
+pub struct OutboxDeadLetter {
+    /// Name of the component that produced the message
+    pub producer_name: String,
+    /// Name of the component that failed to consume the message
+    pub consumer_name: String,
+    /// Identifier of the message in the outbox
+    pub message_id: i64,
+    /// Message content serialized as JSON
+    pub content_json: String,
+    /// Time when the message was produced
+    pub occurred_on: DateTime<Utc>,
+    /// Number of failed attempts to process the message
+    pub attempts: i32,
+    /// Error returned by the consumer on the last attempt
+    pub last_error: String,
+    /// Time when the message was moved to dead letters
+    pub dead_lettered_at: DateTime<Utc>,
+}
+
+impl From<outbox::OutboxDeadLetter> for OutboxDeadLetter {
+    fn from(value: outbox::OutboxDeadLetter) -> Self {
+        Self {
+            producer_name: value.producer_name,
+            consumer_name: value.consumer_name,
+            message_id: value.message_id.into_inner(),
+            content_json: value.content_json.to_string(),
+            occurred_on: value.occurred_on,
+            attempts: value.attempts,
+            last_error: value.last_error,
+            dead_lettered_at: value.dead_lettered_at,
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -62,6 +62,9 @@ impl DatasetData {
     /// )
     /// order by offset
     /// ```
+    ///
+    /// Records can be narrowed down to the specified range before the skip and
+    /// limit are applied
     async fn tail(
         &self,
         ctx: &Context<'_>,
@@ -69,6 +72,7 @@ impl DatasetData {
         limit: Option<u64>,
         data_format: Option<DataBatchFormat>,
         schema_format: Option<DataSchemaFormat>,
+        range: Option<DataRangeInput>,
     ) -> Result<DataQueryResult> {
         // TODO: Default to JsonSoA format once implemented
         let data_format = data_format.unwrap_or(DataBatchFormat::Json);
@@ -81,6 +85,7 @@ impl DatasetData {
                 &self.dataset_handle.as_local_ref(),
                 skip.unwrap_or(0),
                 limit,
+                range.unwrap_or_default().into(),
             )
             .await;
         let df = match tail_result {
@@ -7,6 +7,8 @@
 // the Business Source License, use of this software will be governed
 // by the Apache License, Version 2.0.
 
+use std::str::FromStr;
+
 use futures::{StreamExt, TryStreamExt};
 use kamu_core::{self as domain, MetadataChainExt};
 use opendatafabric as odf;
@@ -47,19 +49,38 @@ impl MetadataChain {
         dataset_repo.get_dataset_by_handle(&self.dataset_handle)
     }
 
+    #[graphql(skip)]
+    async fn resolve_block_pointer(
+        &self,
+        chain: &dyn domain::MetadataChain,
+        block_ref: &str,
+    ) -> Result<Option<odf::Multihash>> {
+        let pointer = domain::BlockPointer::from_str(block_ref).map_err(|_| {
+            GqlError::Gql(async_graphql::Error::new(format!(
+                "Invalid block reference: {block_ref}"
+            )))
+        })?;
+
+        match chain.resolve_pointer(&pointer).await {
+            Ok(hash) => Ok(Some(hash)),
+            Err(domain::GetRefError::NotFound(_)) => Ok(None),
+            Err(e) => Err(e.int_err().into()),
+        }
+    }
+
     /// Returns all named metadata block references
     #[tracing::instrument(level = "info", skip_all)]
     async fn refs(&self, ctx: &Context<'_>) -> Result<Vec<BlockRef>> {
         let dataset = self.get_dataset(ctx);
-        Ok(vec![BlockRef {
-            name: "head".to_owned(),
-            block_hash: dataset
-                .as_metadata_chain()
-                .resolve_ref(&domain::BlockRef::Head)
-                .await
-                .int_err()?
-                .into(),
-        }])
+        let refs = dataset.as_metadata_chain().list_refs().await?;
+
+        Ok(refs
+            .into_iter()
+            .map(|(r, hash)| BlockRef {
+                name: r.to_string(),
+                block_hash: hash.into(),
+            })
+            .collect())
     }
 
     /// Returns a metadata block corresponding to the specified hash
@@ -77,6 +98,28 @@ impl MetadataChain {
         Ok(block.map(|b| MetadataBlockExtended::new(hash, b, account)))
     }
 
+    /// Returns a metadata block pointed to by the specified reference (e.g.
+    /// "head", "tag.v1", "branch.dev") or a block hash
+    #[tracing::instrument(level = "info", skip_all)]
+    async fn block_by_ref(
+        &self,
+        ctx: &Context<'_>,
+        block_ref: String,
+    ) -> Result<Option<MetadataBlockExtended>> {
+        let dataset = self.get_dataset(ctx);
+        let chain = dataset.as_metadata_chain();
+
+        let Some(hash) = self.resolve_block_pointer(chain, &block_ref).await? else {
+            return Ok(None);
+        };
+
+        let block = chain.try_get_block(&hash).await?;
+        let account = Account::from_dataset_alias(ctx, &self.dataset_handle.alias)
+            .await?
+            .expect("Account must exist");
+        Ok(block.map(|b| MetadataBlockExtended::new(hash, b, account)))
+    }
+
     /// Returns a metadata block corresponding to the specified hash and encoded
     /// in desired format
     #[tracing::instrument(level = "info", skip_all)]
@@ -102,15 +145,16 @@ impl MetadataChain {
         }
     }
 
-    // TODO: Add ref parameter (defaulting to "head")
     // TODO: Support before/after style iteration
-    /// Iterates all metadata blocks in the reverse chronological order
+    /// Iterates all metadata blocks in the reverse chronological order starting
+    /// from the specified reference or block hash (defaults to "head")
     #[tracing::instrument(level = "info", skip_all)]
     async fn blocks(
         &self,
         ctx: &Context<'_>,
         page: Option<usize>,
         per_page: Option<usize>,
+        block_ref: Option<String>,
     ) -> Result<MetadataBlockConnection> {
         let page = page.unwrap_or(0);
         let per_page = per_page.unwrap_or(Self::DEFAULT_BLOCKS_PER_PAGE);
@@ -118,7 +162,17 @@ impl MetadataChain {
         let dataset = self.get_dataset(ctx);
         let chain = dataset.as_metadata_chain();
 
-        let head = chain.resolve_ref(&domain::BlockRef::Head).await.int_err()?;
+        let head = match block_ref {
+            None => chain.resolve_ref(&domain::BlockRef::Head).await.int_err()?,
+            Some(block_ref) => self
+                .resolve_block_pointer(chain, &block_ref)
+                .await?
+                .ok_or_else(|| {
+                    GqlError::Gql(async_graphql::Error::new(format!(
+                        "Block reference not found: {block_ref}"
+                    )))
+                })?,
+        };
         let total_count =
             usize::try_from(chain.get_block(&head).await.int_err()?.sequence_number).unwrap() + 1;
 
@@ -221,6 +221,11 @@ impl Flow {
     async fn config_snapshot(&self) -> Option<FlowConfigurationSnapshot> {
         self.flow_state.config_snapshot.clone().map(Into::into)
     }
+
+    /// Policy of retrying failed tasks, captured when the flow was initiated
+    async fn retry_policy(&self) -> Option<FlowRetryPolicy> {
+        self.flow_state.retry_policy.clone().map(Into::into)
+    }
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -97,6 +97,16 @@ impl FlowOutcome {
                             message: "New head hash to reset not found".to_owned(),
                         }),
                     }),
+                    FlowError::GarbageCollectionFailed(err) => Self::Failed(FlowFailedError {
+                        reason: FlowFailedReason::FlowFailed(FlowFailedMessage {
+                            message: format!("Garbage collection failed: {}", err.message),
+                        }),
+                    }),
+                    FlowError::TimedOut => Self::Failed(FlowFailedError {
+                        reason: FlowFailedReason::FlowFailed(FlowFailedMessage {
+                            message: "Task execution timed out".to_owned(),
+                        }),
+                    }),
                 },
                 kamu_flow_system::FlowOutcome::Aborted => Self::Aborted(FlowAbortedResult {
                     message: "ABORTED".to_owned(),
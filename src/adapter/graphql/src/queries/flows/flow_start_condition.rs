@@ -21,6 +21,7 @@ pub(crate) enum FlowStartCondition {
     Throttling(FlowStartConditionThrottling),
     Batching(FlowStartConditionBatching),
     Executor(FlowStartConditionExecutor),
+    Retry(FlowStartConditionRetry),
 }
 
 impl FlowStartCondition {
@@ -72,6 +73,10 @@ impl FlowStartCondition {
             fs::FlowStartCondition::Executor(e) => Self::Executor(FlowStartConditionExecutor {
                 task_id: e.task_id.into(),
             }),
+            fs::FlowStartCondition::Retry(r) => Self::Retry(FlowStartConditionRetry {
+                attempt: r.attempt,
+                wake_up_at: r.wake_up_at,
+            }),
         })
     }
 }
@@ -112,4 +117,11 @@ pub(crate) struct FlowStartConditionExecutor {
     pub task_id: TaskID,
 }
 
+#[derive(SimpleObject)]
+pub(crate) struct FlowStartConditionRetry {
+    /// Number of the upcoming attempt, starting from 1 for the initial one
+    pub attempt: u32,
+    pub wake_up_at: DateTime<Utc>,
+}
+
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
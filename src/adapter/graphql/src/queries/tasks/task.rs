@@ -41,6 +41,21 @@ impl Task {
         self.state.cancellation_requested
     }
 
+    /// Amount of work done by the task, as last reported
+    async fn progress(&self) -> TaskProgress {
+        (&self.state.progress).into()
+    }
+
+    /// Output captured during execution of the task in chronological order
+    async fn logs(&self, ctx: &Context<'_>) -> Result<Vec<TaskLogEntry>> {
+        let task_sched = from_catalog::<dyn ts::TaskScheduler>(ctx).unwrap();
+        let entries = task_sched
+            .get_task_logs(self.state.task_id)
+            .await
+            .int_err()?;
+        Ok(entries.into_iter().map(Into::into).collect())
+    }
+
     /// Describes a certain final outcome of the task once it reaches the
     /// "finished" status
     async fn outcome(&self) -> Option<TaskOutcome> {
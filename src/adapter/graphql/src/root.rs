@@ -107,6 +107,11 @@ impl Mutation {
     async fn tasks(&self) -> TasksMut {
         TasksMut
     }
+
+    /// Admin-related functionality group
+    async fn admin(&self) -> AdminMut {
+        AdminMut
+    }
 }
 
 pub type Schema = async_graphql::Schema<Query, Mutation, EmptySubscription>;
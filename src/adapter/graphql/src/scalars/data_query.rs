@@ -7,11 +7,63 @@
 // the Business Source License, use of this software will be governed
 // by the Apache License, Version 2.0.
 
+use chrono::{DateTime, Utc};
 use datafusion::error::DataFusionError;
-use kamu_core::QueryError;
+use kamu_core::{self as domain, QueryError};
 
 use crate::prelude::*;
 
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// DataRangeInput
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Narrows down the records of a dataset being read. When multiple bounds are
+/// specified - only records satisfying all of them are returned
+#[derive(InputObject, Debug, Default)]
+pub struct DataRangeInput {
+    /// Only include records with offsets starting from this one (inclusive)
+    pub from_offset: Option<u64>,
+    /// Only include records with offsets up to this one (inclusive)
+    pub to_offset: Option<u64>,
+    /// Only include records with event time starting from this one
+    /// (inclusive)
+    pub from_event_time: Option<DateTime<Utc>>,
+    /// Only include records with event time before this one (exclusive)
+    pub to_event_time: Option<DateTime<Utc>>,
+    /// Only include records added by the blocks following this one
+    pub after_block: Option<Multihash>,
+    /// Only include records added by the blocks up to this one (inclusive)
+    pub up_to_block: Option<Multihash>,
+}
+
+impl From<DataRangeInput> for domain::GetDataOptions {
+    fn from(value: DataRangeInput) -> Self {
+        let offset_range = (value.from_offset.is_some() || value.to_offset.is_some()).then_some(
+            domain::OffsetRange {
+                start: value.from_offset,
+                end: value.to_offset,
+            },
+        );
+        let event_time_range = (value.from_event_time.is_some() || value.to_event_time.is_some())
+            .then_some(domain::EventTimeRange {
+                start: value.from_event_time,
+                end: value.to_event_time,
+            });
+        let block_range = (value.after_block.is_some() || value.up_to_block.is_some()).then(|| {
+            domain::BlockRange {
+                after: value.after_block.map(Into::into),
+                up_to: value.up_to_block.map(Into::into),
+            }
+        });
+
+        Self {
+            offset_range,
+            event_time_range,
+            block_range,
+        }
+    }
+}
+
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 // DataQueryResult
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -85,6 +137,8 @@ impl From<QueryError> for DataQueryResult {
     fn from(e: QueryError) -> Self {
         match e {
             QueryError::DatasetNotFound(e) => DataQueryResult::invalid_sql(e.to_string()),
+            QueryError::BlockRefNotFound(e) => DataQueryResult::invalid_sql(e.to_string()),
+            QueryError::InvalidDataRange(e) => DataQueryResult::invalid_sql(e.to_string()),
             QueryError::DataFusionError(e) => e.source.into(),
             QueryError::DatasetSchemaNotAvailable(_) => unreachable!(),
             QueryError::Access(e) => DataQueryResult::unauthorized(e.to_string()),
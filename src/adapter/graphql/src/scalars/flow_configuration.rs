@@ -16,6 +16,9 @@ use kamu_flow_system::{
     FlowConfigurationSnapshot,
     IngestRule,
     ResetRule,
+    RetryErrorFilter,
+    RetryPolicy,
+    RetryPolicyValidationError,
     Schedule,
     ScheduleCron,
     ScheduleCronError,
@@ -36,6 +39,7 @@ pub struct FlowConfiguration {
     pub transform: Option<FlowConfigurationTransform>,
     pub compaction: Option<FlowConfigurationCompaction>,
     pub reset: Option<FlowConfigurationReset>,
+    pub retry_policy: Option<FlowRetryPolicy>,
 }
 
 impl From<kamu_flow_system::FlowConfigurationState> for FlowConfiguration {
@@ -70,6 +74,7 @@ impl From<kamu_flow_system::FlowConfigurationState> for FlowConfiguration {
             } else {
                 None
             },
+            retry_policy: value.retry_policy.map(Into::into),
         }
     }
 }
@@ -199,6 +204,34 @@ impl From<CompactionRuleMetadataOnly> for CompactionMetadataOnly {
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
+#[derive(SimpleObject, Clone, PartialEq, Eq)]
+pub struct FlowRetryPolicy {
+    /// Maximum number of task attempts, including the first one
+    pub max_attempts: u32,
+    pub min_delay: TimeDelta,
+    pub backoff_type: FlowRetryBackoffType,
+    /// Kinds of errors that are retried, all errors are retried when not set
+    pub retry_on_errors: Option<Vec<FlowErrorKind>>,
+}
+
+impl From<RetryPolicy> for FlowRetryPolicy {
+    fn from(value: RetryPolicy) -> Self {
+        Self {
+            max_attempts: value.max_attempts(),
+            min_delay: (*value.min_delay()).into(),
+            backoff_type: value.backoff_type().into(),
+            retry_on_errors: match value.retry_on() {
+                RetryErrorFilter::AnyError => None,
+                RetryErrorFilter::SelectedErrors(error_kinds) => {
+                    Some(error_kinds.iter().map(|kind| (*kind).into()).collect())
+                }
+            },
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
 #[derive(SimpleObject, Clone, PartialEq, Eq)]
 pub struct Cron5ComponentExpression {
     pub cron_5component_expression: String,
@@ -403,6 +436,34 @@ impl TryFrom<IngestConditionInput> for IngestRule {
     }
 }
 
+#[derive(InputObject)]
+pub struct FlowRetryPolicyInput {
+    /// Maximum number of task attempts, including the first one
+    pub max_attempts: u32,
+    pub min_delay: TimeDeltaInput,
+    pub backoff_type: FlowRetryBackoffType,
+    /// Kinds of errors to retry, all errors are retried when omitted
+    pub retry_on_errors: Option<Vec<FlowErrorKind>>,
+}
+
+impl TryFrom<FlowRetryPolicyInput> for RetryPolicy {
+    type Error = RetryPolicyValidationError;
+
+    fn try_from(value: FlowRetryPolicyInput) -> std::result::Result<Self, Self::Error> {
+        RetryPolicy::new_checked(
+            value.max_attempts,
+            value.min_delay.into(),
+            value.backoff_type.into(),
+            match value.retry_on_errors {
+                None => RetryErrorFilter::AnyError,
+                Some(error_kinds) => RetryErrorFilter::SelectedErrors(
+                    error_kinds.into_iter().map(Into::into).collect(),
+                ),
+            },
+        )
+    }
+}
+
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
 impl FlowRunConfiguration {
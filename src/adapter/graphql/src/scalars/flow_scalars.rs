@@ -118,3 +118,24 @@ pub enum SystemFlowType {
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Enum, Debug, Copy, Clone, Eq, PartialEq)]
+#[graphql(remote = "kamu_flow_system::FlowErrorKind")]
+pub enum FlowErrorKind {
+    Failed,
+    RootDatasetCompacted,
+    ResetHeadNotFound,
+    GarbageCollectionFailed,
+    TimedOut,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Enum, Debug, Copy, Clone, Eq, PartialEq)]
+#[graphql(remote = "kamu_flow_system::RetryBackoffType")]
+pub enum FlowRetryBackoffType {
+    Fixed,
+    Exponential,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -61,6 +61,7 @@ pub struct AddPushSource {
     pub read: ReadStep,
     pub preprocess: Option<Transform>,
     pub merge: MergeStrategy,
+    pub watermark: Option<WatermarkStrategy>,
 }
 
 impl From<odf::AddPushSource> for AddPushSource {
@@ -70,6 +71,7 @@ impl From<odf::AddPushSource> for AddPushSource {
             read: v.read.into(),
             preprocess: v.preprocess.map(Into::into),
             merge: v.merge.into(),
+            watermark: v.watermark.map(Into::into),
         }
     }
 }
@@ -594,12 +596,48 @@ impl From<odf::MergeStrategyAppend> for MergeStrategyAppend {
 #[derive(SimpleObject, Debug, Clone, PartialEq, Eq)]
 pub struct MergeStrategyLedger {
     pub primary_key: Vec<String>,
+    pub mode: Option<LedgerMergeMode>,
+    pub dedupe_window_records: Option<u64>,
+    pub dedupe_window_seconds: Option<u64>,
 }
 
 impl From<odf::MergeStrategyLedger> for MergeStrategyLedger {
     fn from(v: odf::MergeStrategyLedger) -> Self {
         Self {
             primary_key: v.primary_key.into_iter().map(Into::into).collect(),
+            mode: v.mode.map(Into::into),
+            dedupe_window_records: v.dedupe_window_records.map(Into::into),
+            dedupe_window_seconds: v.dedupe_window_seconds.map(Into::into),
+        }
+    }
+}
+
+#[derive(Enum, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LedgerMergeMode {
+    Deduplicate,
+    NewEventsOnly,
+    SupersetRetractMissing,
+    SupersetStrict,
+}
+
+impl From<odf::LedgerMergeMode> for LedgerMergeMode {
+    fn from(v: odf::LedgerMergeMode) -> Self {
+        match v {
+            odf::LedgerMergeMode::Deduplicate => Self::Deduplicate,
+            odf::LedgerMergeMode::NewEventsOnly => Self::NewEventsOnly,
+            odf::LedgerMergeMode::SupersetRetractMissing => Self::SupersetRetractMissing,
+            odf::LedgerMergeMode::SupersetStrict => Self::SupersetStrict,
+        }
+    }
+}
+
+impl Into<odf::LedgerMergeMode> for LedgerMergeMode {
+    fn into(self) -> odf::LedgerMergeMode {
+        match self {
+            Self::Deduplicate => odf::LedgerMergeMode::Deduplicate,
+            Self::NewEventsOnly => odf::LedgerMergeMode::NewEventsOnly,
+            Self::SupersetRetractMissing => odf::LedgerMergeMode::SupersetRetractMissing,
+            Self::SupersetStrict => odf::LedgerMergeMode::SupersetStrict,
         }
     }
 }
@@ -943,6 +981,9 @@ pub enum ReadStep {
     Json(ReadStepJson),
     NdJson(ReadStepNdJson),
     NdGeoJson(ReadStepNdGeoJson),
+    Avro(ReadStepAvro),
+    Orc(ReadStepOrc),
+    Excel(ReadStepExcel),
 }
 
 impl From<odf::ReadStep> for ReadStep {
@@ -955,6 +996,9 @@ impl From<odf::ReadStep> for ReadStep {
             odf::ReadStep::Json(v) => Self::Json(v.into()),
             odf::ReadStep::NdJson(v) => Self::NdJson(v.into()),
             odf::ReadStep::NdGeoJson(v) => Self::NdGeoJson(v.into()),
+            odf::ReadStep::Avro(v) => Self::Avro(v.into()),
+            odf::ReadStep::Orc(v) => Self::Orc(v.into()),
+            odf::ReadStep::Excel(v) => Self::Excel(v.into()),
         }
     }
 }
@@ -1084,6 +1128,51 @@ impl From<odf::ReadStepNdGeoJson> for ReadStepNdGeoJson {
     }
 }
 
+#[derive(SimpleObject, Debug, Clone, PartialEq, Eq)]
+pub struct ReadStepAvro {
+    pub schema: Option<Vec<String>>,
+}
+
+impl From<odf::ReadStepAvro> for ReadStepAvro {
+    fn from(v: odf::ReadStepAvro) -> Self {
+        Self {
+            schema: v.schema.map(|v| v.into_iter().map(Into::into).collect()),
+        }
+    }
+}
+
+#[derive(SimpleObject, Debug, Clone, PartialEq, Eq)]
+pub struct ReadStepOrc {
+    pub schema: Option<Vec<String>>,
+}
+
+impl From<odf::ReadStepOrc> for ReadStepOrc {
+    fn from(v: odf::ReadStepOrc) -> Self {
+        Self {
+            schema: v.schema.map(|v| v.into_iter().map(Into::into).collect()),
+        }
+    }
+}
+
+#[derive(SimpleObject, Debug, Clone, PartialEq, Eq)]
+pub struct ReadStepExcel {
+    pub schema: Option<Vec<String>>,
+    pub sheet: Option<String>,
+    pub header: Option<bool>,
+    pub skip_rows: Option<u64>,
+}
+
+impl From<odf::ReadStepExcel> for ReadStepExcel {
+    fn from(v: odf::ReadStepExcel) -> Self {
+        Self {
+            schema: v.schema.map(|v| v.into_iter().map(Into::into).collect()),
+            sheet: v.sheet.map(Into::into),
+            header: v.header.map(Into::into),
+            skip_rows: v.skip_rows.map(Into::into),
+        }
+    }
+}
+
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 // RequestHeader
 // https://github.com/kamu-data/open-data-fabric/blob/master/open-data-fabric.md#requestheader-schema
@@ -1218,6 +1307,7 @@ pub struct SetPollingSource {
     pub read: ReadStep,
     pub preprocess: Option<Transform>,
     pub merge: MergeStrategy,
+    pub watermark: Option<WatermarkStrategy>,
 }
 
 impl From<odf::SetPollingSource> for SetPollingSource {
@@ -1228,6 +1318,7 @@ impl From<odf::SetPollingSource> for SetPollingSource {
             read: v.read.into(),
             preprocess: v.preprocess.map(Into::into),
             merge: v.merge.into(),
+            watermark: v.watermark.map(Into::into),
         }
     }
 }
@@ -1605,3 +1696,79 @@ impl From<odf::Watermark> for Watermark {
         }
     }
 }
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// WatermarkStrategy
+// https://github.com/kamu-data/open-data-fabric/blob/master/open-data-fabric.md#watermarkstrategy-schema
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Union, Debug, Clone, PartialEq, Eq)]
+pub enum WatermarkStrategy {
+    MaxEventTime(WatermarkStrategyMaxEventTime),
+    BoundedOutOfOrderness(WatermarkStrategyBoundedOutOfOrderness),
+    SystemTime(WatermarkStrategySystemTime),
+    Manual(WatermarkStrategyManual),
+}
+
+impl From<odf::WatermarkStrategy> for WatermarkStrategy {
+    fn from(v: odf::WatermarkStrategy) -> Self {
+        match v {
+            odf::WatermarkStrategy::MaxEventTime(v) => Self::MaxEventTime(v.into()),
+            odf::WatermarkStrategy::BoundedOutOfOrderness(v) => {
+                Self::BoundedOutOfOrderness(v.into())
+            }
+            odf::WatermarkStrategy::SystemTime(v) => Self::SystemTime(v.into()),
+            odf::WatermarkStrategy::Manual(v) => Self::Manual(v.into()),
+        }
+    }
+}
+
+#[derive(SimpleObject, Debug, Clone, PartialEq, Eq)]
+pub struct WatermarkStrategyMaxEventTime {
+    pub _dummy: Option<String>,
+}
+
+impl From<odf::WatermarkStrategyMaxEventTime> for WatermarkStrategyMaxEventTime {
+    fn from(v: odf::WatermarkStrategyMaxEventTime) -> Self {
+        Self { _dummy: None }
+    }
+}
+
+#[derive(SimpleObject, Debug, Clone, PartialEq, Eq)]
+pub struct WatermarkStrategyBoundedOutOfOrderness {
+    pub lag_seconds: u64,
+}
+
+impl From<odf::WatermarkStrategyBoundedOutOfOrderness> for WatermarkStrategyBoundedOutOfOrderness {
+    fn from(v: odf::WatermarkStrategyBoundedOutOfOrderness) -> Self {
+        Self {
+            lag_seconds: v.lag_seconds.into(),
+        }
+    }
+}
+
+#[derive(SimpleObject, Debug, Clone, PartialEq, Eq)]
+pub struct WatermarkStrategySystemTime {
+    pub delay_seconds: u64,
+    pub min_idle_advance_seconds: Option<u64>,
+}
+
+impl From<odf::WatermarkStrategySystemTime> for WatermarkStrategySystemTime {
+    fn from(v: odf::WatermarkStrategySystemTime) -> Self {
+        Self {
+            delay_seconds: v.delay_seconds.into(),
+            min_idle_advance_seconds: v.min_idle_advance_seconds.map(Into::into),
+        }
+    }
+}
+
+#[derive(SimpleObject, Debug, Clone, PartialEq, Eq)]
+pub struct WatermarkStrategyManual {
+    pub _dummy: Option<String>,
+}
+
+impl From<odf::WatermarkStrategyManual> for WatermarkStrategyManual {
+    fn from(v: odf::WatermarkStrategyManual) -> Self {
+        Self { _dummy: None }
+    }
+}
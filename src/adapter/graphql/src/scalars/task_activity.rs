@@ -0,0 +1,58 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use chrono::{DateTime, Utc};
+use kamu_task_system as ts;
+
+use crate::prelude::*;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// A single line of output captured during task execution
+#[derive(SimpleObject, Debug, Clone)]
+pub struct TaskLogEntry {
+    /// Time when the line was captured
+    pub event_time: DateTime<Utc>,
+    /// Contents of the line
+    pub message: String,
+}
+
+impl From<ts::TaskLogEntry> for TaskLogEntry {
+    fn from(value: ts::TaskLogEntry) -> Self {
+        Self {
+            event_time: value.event_time,
+            message: value.message,
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Cumulative amount of work done by the task so far
+#[derive(SimpleObject, Debug, Clone)]
+pub struct TaskProgress {
+    /// Number of bytes fetched from external sources
+    pub fetched_bytes: u64,
+    /// Number of records read from input datasets
+    pub records_read: u64,
+    /// Number of records written into the target dataset
+    pub records_written: u64,
+}
+
+impl From<&ts::TaskProgress> for TaskProgress {
+    fn from(value: &ts::TaskProgress) -> Self {
+        Self {
+            fetched_bytes: value.fetched_bytes,
+            records_read: value.records_read,
+            records_written: value.records_written,
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
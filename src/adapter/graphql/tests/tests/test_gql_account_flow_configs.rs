@@ -36,9 +36,9 @@ use kamu_accounts_services::{AccessTokenServiceImpl, AuthenticationServiceImpl};
 use kamu_core::*;
 use kamu_flow_system::FlowServiceRunConfig;
 use kamu_flow_system_inmem::{InMemoryFlowConfigurationEventStore, InMemoryFlowEventStore};
-use kamu_flow_system_services::{FlowConfigurationServiceImpl, FlowServiceImpl};
+use kamu_flow_system_services::{FlowConfigurationServiceImpl, FlowServiceImpl, FlowServiceState};
 use kamu_task_system_inmem::InMemoryTaskSystemEventStore;
-use kamu_task_system_services::TaskSchedulerImpl;
+use kamu_task_system_services::{TaskQueue, TaskSchedulerImpl};
 use messaging_outbox::{register_message_dispatcher, Outbox, OutboxImmediateImpl};
 use opendatafabric::{AccountName, DatasetAlias, DatasetID, DatasetKind, DatasetName};
 use time_source::SystemTimeSourceDefault;
@@ -680,12 +680,14 @@ impl FlowConfigHarness {
             .bind::<dyn DependencyGraphRepository, MockDependencyGraphRepository>()
             .add::<FlowConfigurationServiceImpl>()
             .add::<InMemoryFlowConfigurationEventStore>()
+            .add::<FlowServiceState>()
             .add::<FlowServiceImpl>()
             .add::<InMemoryFlowEventStore>()
             .add_value(FlowServiceRunConfig::new(
                 Duration::try_seconds(1).unwrap(),
                 Duration::try_minutes(1).unwrap(),
             ))
+            .add::<TaskQueue>()
             .add::<TaskSchedulerImpl>()
             .add::<InMemoryTaskSystemEventStore>()
             .add_value(transform_service_mock)
@@ -67,11 +67,12 @@ use kamu_flow_system_inmem::{InMemoryFlowConfigurationEventStore, InMemoryFlowEv
 use kamu_flow_system_services::{
     FlowConfigurationServiceImpl,
     FlowServiceImpl,
+    FlowServiceState,
     MESSAGE_PRODUCER_KAMU_FLOW_CONFIGURATION_SERVICE,
 };
 use kamu_task_system::{self as ts};
 use kamu_task_system_inmem::InMemoryTaskSystemEventStore;
-use kamu_task_system_services::TaskSchedulerImpl;
+use kamu_task_system_services::{TaskQueue, TaskSchedulerImpl};
 use messaging_outbox::{register_message_dispatcher, Outbox, OutboxExt, OutboxImmediateImpl};
 use opendatafabric::{AccountID, DatasetID, DatasetKind, Multihash};
 use time_source::SystemTimeSourceDefault;
@@ -3183,12 +3184,14 @@ impl FlowRunsHarness {
             .bind::<dyn DependencyGraphRepository, MockDependencyGraphRepository>()
             .add::<FlowConfigurationServiceImpl>()
             .add::<InMemoryFlowConfigurationEventStore>()
+            .add::<FlowServiceState>()
             .add::<FlowServiceImpl>()
             .add::<InMemoryFlowEventStore>()
             .add_value(FlowServiceRunConfig::new(
                 Duration::try_seconds(1).unwrap(),
                 Duration::try_minutes(1).unwrap(),
             ))
+            .add::<TaskQueue>()
             .add::<TaskSchedulerImpl>()
             .add::<InMemoryTaskSystemEventStore>()
             .add_value(transform_service_mock)
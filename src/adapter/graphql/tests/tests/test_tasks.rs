@@ -22,11 +22,13 @@ mockall::mock! {
     #[async_trait::async_trait]
     impl TaskScheduler for TaskScheduler {
         async fn get_task(&self, task_id: TaskID) -> Result<TaskState, GetTaskError>;
+        async fn get_task_logs(&self, task_id: TaskID) -> Result<Vec<TaskLogEntry>, GetTaskError>;
         async fn list_tasks_by_dataset<'a>(&'a self, dataset_id: &DatasetID, pagination: TaskPaginationOpts) -> Result<TaskStateListing<'a>, ListTasksByDatasetError>;
         async fn create_task(&self, plan: LogicalPlan) -> Result<TaskState, CreateTaskError>;
         async fn cancel_task(&self, task_id: TaskID) -> Result<TaskState, CancelTaskError>;
         async fn take(&self) -> Result<TaskID, TakeTaskError>;
         async fn try_take(&self) -> Result<Option<TaskID>, TakeTaskError>;
+        async fn try_take_constrained(&self, constraints: &TaskTakeConstraints) -> Result<Option<TaskID>, TakeTaskError>;
     }
 }
 
@@ -82,6 +84,7 @@ async fn test_task_get_existing() {
             dataset_id: DatasetID::new_seeded_ed25519(b"foo"),
             fetch_uncacheable: false,
         }),
+        progress: TaskProgress::default(),
         created_at: Utc::now(),
         ran_at: None,
         cancellation_requested_at: None,
@@ -135,6 +138,98 @@ async fn test_task_get_existing() {
     );
 }
 
+#[test_log::test(tokio::test)]
+async fn test_task_get_progress_and_logs() {
+    let event_time = Utc::now();
+    let returned_task = TaskState {
+        task_id: TaskID::new(123),
+        status: TaskStatus::Running,
+        cancellation_requested: false,
+        logical_plan: LogicalPlan::UpdateDataset(UpdateDataset {
+            dataset_id: DatasetID::new_seeded_ed25519(b"foo"),
+            fetch_uncacheable: false,
+        }),
+        progress: TaskProgress {
+            fetched_bytes: 1024,
+            records_read: 0,
+            records_written: 10,
+        },
+        created_at: event_time,
+        ran_at: Some(event_time),
+        cancellation_requested_at: None,
+        finished_at: None,
+    };
+    let task_id = returned_task.task_id;
+
+    let mut task_sched_mock = MockTaskScheduler::new();
+    task_sched_mock
+        .expect_get_task()
+        .with(mockall::predicate::eq(task_id))
+        .return_once(move |_| Ok(returned_task));
+    task_sched_mock
+        .expect_get_task_logs()
+        .with(mockall::predicate::eq(task_id))
+        .return_once(move |_| {
+            Ok(vec![
+                TaskLogEntry {
+                    event_time,
+                    message: "Ingest stage: Fetch".to_string(),
+                },
+                TaskLogEntry {
+                    event_time,
+                    message: "Ingest stage: Read".to_string(),
+                },
+            ])
+        });
+
+    let cat = dill::CatalogBuilder::new()
+        .add_value(task_sched_mock)
+        .bind::<dyn TaskScheduler, MockTaskScheduler>()
+        .build();
+
+    let schema = kamu_adapter_graphql::schema_quiet();
+    let res = schema
+        .execute(
+            async_graphql::Request::new(format!(
+                r#"{{
+                    tasks {{
+                        getTask (taskId: "{task_id}") {{
+                            progress {{
+                                fetchedBytes
+                                recordsRead
+                                recordsWritten
+                            }}
+                            logs {{
+                                message
+                            }}
+                        }}
+                    }}
+                }}"#,
+            ))
+            .data(cat),
+        )
+        .await;
+    assert!(res.is_ok(), "{res:?}");
+    assert_eq!(
+        res.data,
+        value!({
+            "tasks": {
+                "getTask": {
+                    "progress": {
+                        "fetchedBytes": 1024,
+                        "recordsRead": 0,
+                        "recordsWritten": 10,
+                    },
+                    "logs": [
+                        { "message": "Ingest stage: Fetch" },
+                        { "message": "Ingest stage: Read" },
+                    ],
+                },
+            }
+        })
+    );
+}
+
 #[test_log::test(tokio::test)]
 async fn test_task_list_by_dataset() {
     let dataset_id = DatasetID::new_seeded_ed25519(b"foo");
@@ -147,6 +242,7 @@ async fn test_task_list_by_dataset() {
             dataset_id: dataset_id.clone(),
             fetch_uncacheable: false,
         }),
+        progress: TaskProgress::default(),
         created_at: Utc::now(),
         ran_at: None,
         cancellation_requested_at: None,
@@ -231,6 +327,7 @@ async fn test_task_create_probe() {
         status: TaskStatus::Queued,
         cancellation_requested: false,
         logical_plan: expected_logical_plan.clone(),
+        progress: TaskProgress::default(),
         created_at: Utc::now(),
         ran_at: None,
         cancellation_requested_at: None,
@@ -128,6 +128,7 @@ pub async fn dataset_ingest_handler(
         Ok(_) => Ok(()),
         Err(PushIngestError::ReadError(e)) => Err(ApiError::bad_request(e)),
         Err(PushIngestError::SourceNotFound(e)) => Err(ApiError::bad_request(e)),
+        Err(PushIngestError::SourceDisabled(e)) => Err(ApiError::bad_request(e)),
         Err(PushIngestError::UnsupportedMediaType(_)) => {
             Err(ApiError::new_unsupported_media_type())
         }
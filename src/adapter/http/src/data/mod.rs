@@ -9,6 +9,7 @@
 
 mod ingest_handler;
 mod query_handler;
+mod record_batch_stream;
 mod router;
 mod tail_handler;
 
@@ -19,7 +19,7 @@
 use std::fmt::Debug;
 
 use axum::extract::{Extension, Query};
-use axum::response::Json;
+use axum::response::{IntoResponse, Json};
 use database_common_macros::transactional_handler;
 use datafusion::arrow::array::RecordBatch;
 use datafusion::common::DFSchema;
@@ -30,6 +30,8 @@ use kamu_core::*;
 use kamu_data_utils::data::format::*;
 use opendatafabric::{DatasetID, Multihash};
 
+use super::record_batch_stream::*;
+
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
 // TODO: Externalize
@@ -40,8 +42,9 @@ const MAX_SOA_BUFFER_SIZE: usize = 100_000_000;
 #[transactional_handler]
 pub async fn dataset_query_handler_post(
     Extension(catalog): Extension<Catalog>,
+    headers: http::HeaderMap,
     Json(body): Json<QueryRequestBody>,
-) -> Result<Json<QueryResponseBody>, ApiError> {
+) -> Result<axum::response::Response, ApiError> {
     let query_svc = catalog.get_one::<dyn QueryService>().unwrap();
 
     let res = match query_svc
@@ -50,6 +53,7 @@ pub async fn dataset_query_handler_post(
     {
         Ok(res) => res,
         Err(QueryError::DatasetNotFound(err)) => Err(ApiError::not_found(err))?,
+        Err(QueryError::BlockRefNotFound(err)) => Err(ApiError::not_found(err))?,
         Err(QueryError::DataFusionError(DataFusionError {
             source: datafusion::error::DataFusionError::SQL(err, _),
             ..
@@ -71,6 +75,20 @@ pub async fn dataset_query_handler_post(
         .int_err()
         .api_err()?;
 
+    // Explicitly requested format takes precedence over the `Accept` header
+    let streaming_format = match body.data_format {
+        Some(data_format) => data_format.streaming_format(),
+        None => headers
+            .get(http::header::ACCEPT)
+            .and_then(|accept| accept.to_str().ok())
+            .and_then(StreamingDataFormat::from_accept_header),
+    };
+
+    if let Some(streaming_format) = streaming_format {
+        let record_batches = df.execute_stream().await.int_err().api_err()?;
+        return record_batch_stream_into_response(record_batches, streaming_format);
+    }
+
     let arrow_schema = df.schema().inner().clone();
 
     let schema = if body.include_schema {
@@ -86,7 +104,7 @@ pub async fn dataset_query_handler_post(
     };
 
     let record_batches = df.collect().await.int_err().api_err()?;
-    let json = serialize_data(&record_batches, body.data_format).api_err()?;
+    let json = serialize_data(&record_batches, body.data_format.unwrap_or_default()).api_err()?;
     let data = serde_json::value::RawValue::from_string(json).unwrap();
 
     let data_hash = if body.include_data_hash {
@@ -103,16 +121,18 @@ pub async fn dataset_query_handler_post(
         schema,
         state,
         data_hash,
-    }))
+    })
+    .into_response())
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
 pub async fn dataset_query_handler(
     catalog: Extension<Catalog>,
+    headers: http::HeaderMap,
     Query(params): Query<QueryRequestParams>,
-) -> Result<Json<QueryResponseBody>, ApiError> {
-    dataset_query_handler_post(catalog, Json(params.into())).await
+) -> Result<axum::response::Response, ApiError> {
+    dataset_query_handler_post(catalog, headers, Json(params.into())).await
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -124,9 +144,11 @@ pub struct QueryRequestBody {
     /// SQL query
     query: String,
 
-    /// How data should be layed out in the response
-    #[serde(default)]
-    data_format: DataFormat,
+    /// How data should be layed out in the response. When not specified, the
+    /// format is negotiated via the `Accept` header, falling back to
+    /// `JsonAos`. Non-JSON formats are streamed as the response body without
+    /// the schema, state and data hash information
+    data_format: Option<DataFormat>,
 
     /// What representation to use for the schema
     #[serde(default)]
@@ -184,6 +206,7 @@ impl QueryRequestBody {
                 .as_ref()
                 .map(|v| v.iter().map(|i| (i.alias.clone(), i.id.clone())).collect()),
             as_of_state: self.as_of_state.as_ref().map(QueryState::to_state),
+            as_of_refs: None,
             hints: None,
         }
     }
@@ -200,8 +223,7 @@ pub struct QueryRequestParams {
     #[serde(default = "QueryRequestBody::default_limit")]
     limit: u64,
     #[serde(alias = "format")]
-    #[serde(default)]
-    data_format: DataFormat,
+    data_format: Option<DataFormat>,
     #[serde(default)]
     schema_format: SchemaFormat,
     #[serde(alias = "schema")]
@@ -307,8 +329,33 @@ pub enum DataFormat {
     #[serde(alias = "jsonaoa")]
     #[serde(alias = "json-aoa")]
     JsonAoa,
+    #[serde(alias = "csv")]
+    Csv,
+    #[serde(alias = "arrow")]
+    #[serde(alias = "arrowipc")]
+    #[serde(alias = "arrow-ipc")]
+    ArrowIpc,
+    #[serde(alias = "parquet")]
+    Parquet,
 }
 
+impl DataFormat {
+    /// Returns the format for data that is streamed as a response body instead
+    /// of being embedded into a JSON response
+    pub fn streaming_format(self) -> Option<StreamingDataFormat> {
+        match self {
+            Self::JsonAos | Self::JsonSoa | Self::JsonAoa => None,
+            Self::Csv => Some(StreamingDataFormat::Csv),
+            Self::ArrowIpc => Some(StreamingDataFormat::ArrowIpc),
+            Self::Parquet => Some(StreamingDataFormat::Parquet),
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error("Data format {0:?} can't be embedded into a JSON response")]
+pub struct NonJsonDataFormatError(pub DataFormat);
+
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
 #[allow(clippy::enum_variant_names)]
@@ -368,6 +415,9 @@ pub(crate) fn serialize_data(
                 Box::new(JsonStructOfArraysWriter::new(&mut buf, MAX_SOA_BUFFER_SIZE))
             }
             DataFormat::JsonAoa => Box::new(JsonArrayOfArraysWriter::new(&mut buf)),
+            DataFormat::Csv | DataFormat::ArrowIpc | DataFormat::Parquet => {
+                return Err(NonJsonDataFormatError(format).int_err())
+            }
         };
 
         for batch in record_batches {
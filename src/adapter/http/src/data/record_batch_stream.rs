@@ -0,0 +1,194 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::io::Write;
+use std::sync::{Arc, Mutex};
+
+use bytes::Bytes;
+use datafusion::arrow::array::RecordBatch;
+use datafusion::arrow::datatypes::SchemaRef;
+use datafusion::arrow::ipc::writer::StreamWriter;
+use datafusion::execution::SendableRecordBatchStream;
+use datafusion::parquet::arrow::ArrowWriter;
+use futures::TryStreamExt;
+use http_common::*;
+use internal_error::{InternalError, ResultIntoInternal};
+use kamu_data_utils::data::format::{CsvWriter, CsvWriterOptions, RecordsWriter};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Binary and text formats in which the data is streamed directly into the
+/// response body, without being wrapped into a JSON envelope
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum StreamingDataFormat {
+    Csv,
+    ArrowIpc,
+    Parquet,
+}
+
+impl StreamingDataFormat {
+    pub const MEDIA_TYPE_CSV: &'static str = "text/csv";
+    pub const MEDIA_TYPE_ARROW_IPC: &'static str = "application/vnd.apache.arrow.stream";
+    pub const MEDIA_TYPE_PARQUET: &'static str = "application/vnd.apache.parquet";
+
+    pub fn media_type(&self) -> &'static str {
+        match self {
+            Self::Csv => Self::MEDIA_TYPE_CSV,
+            Self::ArrowIpc => Self::MEDIA_TYPE_ARROW_IPC,
+            Self::Parquet => Self::MEDIA_TYPE_PARQUET,
+        }
+    }
+
+    /// Picks the first streaming format listed in the `Accept` header value.
+    /// Quality factors are not taken into account.
+    pub fn from_accept_header(accept: &str) -> Option<Self> {
+        accept
+            .split(',')
+            .map(|media_range| {
+                media_range
+                    .split(';')
+                    .next()
+                    .unwrap_or_default()
+                    .trim()
+                    .to_ascii_lowercase()
+            })
+            .find_map(|media_type| match media_type.as_str() {
+                Self::MEDIA_TYPE_CSV => Some(Self::Csv),
+                Self::MEDIA_TYPE_ARROW_IPC => Some(Self::ArrowIpc),
+                Self::MEDIA_TYPE_PARQUET => Some(Self::Parquet),
+                _ => None,
+            })
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Creates a response which encodes record batches as they are produced by
+/// the query execution, so that the result set is never fully buffered in
+/// memory.
+///
+/// Note that errors that occur after the first chunk was sent can only be
+/// signalled by aborting the response.
+pub(crate) fn record_batch_stream_into_response(
+    record_batches: SendableRecordBatchStream,
+    format: StreamingDataFormat,
+) -> Result<axum::response::Response, ApiError> {
+    let encoder = BatchEncoder::new(format, record_batches.schema()).api_err()?;
+
+    let body_stream = futures::stream::try_unfold(Some((record_batches, encoder)), next_chunk);
+
+    axum::response::Response::builder()
+        .header(http::header::CONTENT_TYPE, format.media_type())
+        .body(axum::body::boxed(axum::body::StreamBody::new(body_stream)))
+        .int_err()
+        .api_err()
+}
+
+type EncodingState = (SendableRecordBatchStream, BatchEncoder);
+
+async fn next_chunk(
+    state: Option<EncodingState>,
+) -> Result<Option<(Bytes, Option<EncodingState>)>, InternalError> {
+    let Some((mut record_batches, mut encoder)) = state else {
+        return Ok(None);
+    };
+
+    // Batches that did not produce any output yet (e.g. rows buffered into a
+    // Parquet row group) are not sent as empty chunks
+    while let Some(batch) = record_batches.try_next().await.int_err()? {
+        encoder.write_batch(&batch)?;
+
+        let chunk = encoder.take_output();
+        if !chunk.is_empty() {
+            return Ok(Some((chunk, Some((record_batches, encoder)))));
+        }
+    }
+
+    encoder.finish()?;
+    Ok(Some((encoder.take_output(), None)))
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+enum BatchEncoder {
+    Csv(CsvWriter<SharedBuffer>, SharedBuffer),
+    ArrowIpc(StreamWriter<SharedBuffer>, SharedBuffer),
+    Parquet(ArrowWriter<SharedBuffer>, SharedBuffer),
+}
+
+impl BatchEncoder {
+    fn new(format: StreamingDataFormat, schema: SchemaRef) -> Result<Self, InternalError> {
+        let buffer = SharedBuffer::default();
+
+        Ok(match format {
+            StreamingDataFormat::Csv => Self::Csv(
+                CsvWriter::new(buffer.clone(), CsvWriterOptions::default()),
+                buffer,
+            ),
+            StreamingDataFormat::ArrowIpc => Self::ArrowIpc(
+                StreamWriter::try_new(buffer.clone(), &schema).int_err()?,
+                buffer,
+            ),
+            StreamingDataFormat::Parquet => Self::Parquet(
+                ArrowWriter::try_new(buffer.clone(), schema, None).int_err()?,
+                buffer,
+            ),
+        })
+    }
+
+    fn write_batch(&mut self, batch: &RecordBatch) -> Result<(), InternalError> {
+        match self {
+            Self::Csv(writer, _) => writer.write_batch(batch).int_err(),
+            Self::ArrowIpc(writer, _) => writer.write(batch).int_err(),
+            Self::Parquet(writer, _) => writer.write(batch).int_err(),
+        }
+    }
+
+    fn finish(&mut self) -> Result<(), InternalError> {
+        match self {
+            Self::Csv(writer, _) => writer.finish().int_err(),
+            Self::ArrowIpc(writer, _) => writer.finish().int_err(),
+            Self::Parquet(writer, _) => writer.finish().map(|_| ()).int_err(),
+        }
+    }
+
+    fn take_output(&self) -> Bytes {
+        match self {
+            Self::Csv(_, buffer) | Self::ArrowIpc(_, buffer) | Self::Parquet(_, buffer) => {
+                buffer.take()
+            }
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Accumulates the output of a writer until it is taken to be sent as a
+/// response chunk
+#[derive(Default, Clone)]
+struct SharedBuffer(Arc<Mutex<Vec<u8>>>);
+
+impl SharedBuffer {
+    fn take(&self) -> Bytes {
+        Bytes::from(std::mem::take(&mut *self.0.lock().unwrap()))
+    }
+}
+
+impl Write for SharedBuffer {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0.lock().unwrap().extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
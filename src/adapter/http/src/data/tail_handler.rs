@@ -18,14 +18,15 @@
 
 use axum::extract::{Extension, Query};
 use axum::response::Json;
+use chrono::{DateTime, Utc};
 use database_common_macros::transactional_handler;
 use dill::Catalog;
 use http_common::*;
 use internal_error::{ErrorIntoInternal, ResultIntoInternal};
 use kamu_core::*;
-use opendatafabric::DatasetRef;
+use opendatafabric::{DatasetRef, Multihash};
 
-use super::query_handler::{DataFormat, SchemaFormat};
+use super::query_handler::{DataFormat, NonJsonDataFormatError, SchemaFormat};
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
@@ -38,13 +39,21 @@ pub async fn dataset_tail_handler(
     Extension(dataset_ref): Extension<DatasetRef>,
     Query(params): Query<TailRequestParams>,
 ) -> Result<Json<TailResponseBody>, ApiError> {
+    if params.data_format.streaming_format().is_some() {
+        return Err(ApiError::bad_request(NonJsonDataFormatError(
+            params.data_format,
+        )));
+    }
+
     let query_svc = catalog.get_one::<dyn QueryService>().unwrap();
 
     let df = query_svc
-        .tail(&dataset_ref, params.skip, params.limit)
+        .tail(&dataset_ref, params.skip, params.limit, params.to_options())
         .await
         .map_err(|e| match e {
             QueryError::DatasetNotFound(e) => ApiError::not_found(e),
+            QueryError::BlockRefNotFound(e) => ApiError::not_found(e),
+            QueryError::InvalidDataRange(e) => ApiError::bad_request(e),
             QueryError::DatasetSchemaNotAvailable(e) => ApiError::no_content(e),
             QueryError::DataFusionError(e) => e.int_err().api_err(),
             QueryError::Access(e) => e.api_err(),
@@ -83,6 +92,19 @@ pub struct TailRequestParams {
     #[serde(alias = "schema")]
     #[serde(default = "TailRequestParams::default_include_schema")]
     include_schema: bool,
+    /// Only consider records with offsets starting from this one (inclusive)
+    from_offset: Option<u64>,
+    /// Only consider records with offsets up to this one (inclusive)
+    to_offset: Option<u64>,
+    /// Only consider records with event time starting from this one
+    /// (inclusive)
+    from_event_time: Option<DateTime<Utc>>,
+    /// Only consider records with event time before this one (exclusive)
+    to_event_time: Option<DateTime<Utc>>,
+    /// Only consider records added after this block
+    after_block: Option<Multihash>,
+    /// Only consider records added up to this block (inclusive)
+    up_to_block: Option<Multihash>,
 }
 
 impl TailRequestParams {
@@ -93,6 +115,28 @@ impl TailRequestParams {
     fn default_include_schema() -> bool {
         true
     }
+
+    fn to_options(&self) -> GetDataOptions {
+        GetDataOptions {
+            offset_range: (self.from_offset.is_some() || self.to_offset.is_some()).then_some(
+                OffsetRange {
+                    start: self.from_offset,
+                    end: self.to_offset,
+                },
+            ),
+            event_time_range: (self.from_event_time.is_some() || self.to_event_time.is_some())
+                .then_some(EventTimeRange {
+                    start: self.from_event_time,
+                    end: self.to_event_time,
+                }),
+            block_range: (self.after_block.is_some() || self.up_to_block.is_some()).then(|| {
+                BlockRange {
+                    after: self.after_block.clone(),
+                    up_to: self.up_to_block.clone(),
+                }
+            }),
+        }
+    }
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
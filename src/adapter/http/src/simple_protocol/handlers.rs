@@ -57,6 +57,24 @@ pub async fn dataset_refs_handler(
     axum::extract::Extension(dataset): axum::extract::Extension<Arc<dyn Dataset>>,
     axum::extract::Path(ref_param): axum::extract::Path<RefFromPath>,
 ) -> Result<String, ApiError> {
+    // Mirror the index of user-defined references the same way it is laid out
+    // in storage
+    if ref_param.reference == kamu::REFS_INDEX_NAME {
+        let refs = dataset
+            .as_metadata_chain()
+            .as_reference_repo()
+            .list()
+            .await
+            .int_err()
+            .api_err()?;
+
+        return Ok(refs
+            .into_iter()
+            .filter(|r| !r.is_head())
+            .map(|r| format!("{r}\n"))
+            .collect());
+    }
+
     let block_ref = match BlockRef::from_str(ref_param.reference.as_str()) {
         Ok(block_ref) => Ok(block_ref),
         Err(e) => Err(ApiError::not_found(e)),
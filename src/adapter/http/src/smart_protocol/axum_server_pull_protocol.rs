@@ -120,12 +120,19 @@ impl AxumServerPullProtocolInstance {
         )
         .await;
 
+        let refs = prepare_transfer_refs(metadata_chain)
+            .await
+            .map_err(PullServerError::Internal)?;
+
         axum_write_payload::<DatasetPullResponse>(
             &mut self.socket,
             match transfer_plan_result {
                 Ok(transfer_plan) => {
                     tracing::debug!("Sending size estimate: {:?}", transfer_plan);
-                    DatasetPullResponse::Ok(DatasetPullSuccessResponse { transfer_plan })
+                    DatasetPullResponse::Ok(DatasetPullSuccessResponse {
+                        transfer_plan,
+                        refs: Some(refs),
+                    })
                 }
                 Err(PrepareDatasetTransferEstimateError::InvalidInterval(e)) => {
                     tracing::debug!("Sending invalid interval error: {:?}", e);
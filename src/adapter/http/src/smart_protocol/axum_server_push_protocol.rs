@@ -25,6 +25,8 @@ use kamu_core::{
     DatasetVisibility,
     GetRefError,
     HashedMetadataBlock,
+    ImportRefsError,
+    ImportRefsOpts,
 };
 use opendatafabric::{AsTypedBlock, DatasetRef};
 use url::Url;
@@ -96,6 +98,10 @@ impl AxumServerPushProtocolInstance {
     async fn push_main_flow(&mut self) -> Result<(), PushServerError> {
         let push_request = self.handle_push_request_initiation().await?;
         let force_update_if_diverged = push_request.force_update_if_diverged;
+        let refs = push_request.refs.clone();
+        let import_refs_opts = ImportRefsOpts {
+            delete_missing: push_request.delete_missing_refs,
+        };
 
         let mut new_blocks = self.try_handle_push_metadata_request(push_request).await?;
         if !new_blocks.is_empty() {
@@ -174,7 +180,7 @@ impl AxumServerPushProtocolInstance {
             }
         }
 
-        self.try_handle_push_complete(new_blocks, force_update_if_diverged)
+        self.try_handle_push_complete(new_blocks, force_update_if_diverged, refs, import_refs_opts)
             .await?;
 
         Ok(())
@@ -215,15 +221,36 @@ impl AxumServerPushProtocolInstance {
             None
         };
 
-        let response = if push_request.current_head == actual_head {
-            Ok(DatasetPushRequestAccepted {})
-        } else {
+        // Tags can't be moved by a push, reject the conflicts before any blocks are
+        // transferred
+        let ref_conflict = match (self.dataset.as_ref(), push_request.refs.as_ref()) {
+            (Some(dataset), Some(refs)) => {
+                match check_transfer_refs(dataset.as_metadata_chain(), refs).await {
+                    Ok(()) => None,
+                    Err(ImportRefsError::Conflict(e)) => Some(e),
+                    Err(ImportRefsError::Internal(e)) => return Err(PushServerError::Internal(e)),
+                }
+            }
+            _ => None,
+        };
+
+        let response = if push_request.current_head != actual_head {
             Err(DatasetPushRequestError::InvalidHead(
                 DatasetPushInvalidHeadError {
                     actual_head: push_request.current_head.clone(),
                     expected_head: actual_head,
                 },
             ))
+        } else if let Some(e) = ref_conflict {
+            Err(DatasetPushRequestError::RefConflict(
+                DatasetPushRefConflictError {
+                    ref_name: e.block_ref.to_string(),
+                    src_hash: e.src_hash,
+                    dst_hash: e.dst_hash,
+                },
+            ))
+        } else {
+            Ok(DatasetPushRequestAccepted {})
         };
 
         axum_write_payload::<DatasetPushResponse>(&mut self.socket, response)
@@ -363,6 +390,8 @@ impl AxumServerPushProtocolInstance {
         &mut self,
         new_blocks: VecDeque<HashedMetadataBlock>,
         force_update_if_diverged: bool,
+        refs: Option<Vec<TransferRef>>,
+        import_refs_opts: ImportRefsOpts,
     ) -> Result<(), PushServerError> {
         axum_read_payload::<DatasetPushComplete>(&mut self.socket)
             .await
@@ -384,6 +413,16 @@ impl AxumServerPushProtocolInstance {
             .await
             .int_err()?;
 
+        if let Some(refs) = refs {
+            import_transfer_refs(
+                self.dataset.as_ref().unwrap().as_metadata_chain(),
+                &refs,
+                import_refs_opts,
+            )
+            .await
+            .int_err()?;
+        }
+
         tracing::debug!("Sending completion confirmation");
 
         axum_write_payload::<DatasetPushCompleteConfirmed>(
@@ -10,7 +10,7 @@
 use std::fmt::{self, Display};
 
 use internal_error::InternalError;
-use kamu_core::{InvalidIntervalError, RefCASError, RefCollisionError};
+use kamu_core::{InvalidIntervalError, RefCASError, RefCollisionError, RefConflictError};
 use thiserror::Error;
 
 use super::phases::*;
@@ -197,6 +197,9 @@ pub enum PushClientError {
     #[error(transparent)]
     RefCollision(RefCollisionError),
 
+    #[error(transparent)]
+    RefConflict(RefConflictError),
+
     #[error(transparent)]
     Internal(
         #[from]
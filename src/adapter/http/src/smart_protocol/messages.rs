@@ -29,6 +29,10 @@ pub type DatasetPullResponse = Result<DatasetPullSuccessResponse, DatasetPullReq
 #[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Debug)]
 pub struct DatasetPullSuccessResponse {
     pub transfer_plan: TransferPlan,
+    /// User-defined references of the source dataset, absent when the server
+    /// does not support transferring them
+    #[serde(default)]
+    pub refs: Option<Vec<TransferRef>>,
 }
 
 // Unsuccessful response to initial dataset pull request
@@ -87,6 +91,14 @@ pub struct DatasetPushRequest {
     pub current_head: Option<Multihash>,
     pub transfer_plan: TransferPlan,
     pub force_update_if_diverged: bool,
+    /// User-defined references of the source dataset, absent when the client
+    /// does not support transferring them
+    #[serde(default)]
+    pub refs: Option<Vec<TransferRef>>,
+    /// Whether user-defined references of the destination dataset that are
+    /// missing in `refs` should be deleted
+    #[serde(default)]
+    pub delete_missing_refs: bool,
 }
 
 /// Response to initial dataset push request message
@@ -101,6 +113,7 @@ pub struct DatasetPushRequestAccepted {}
 pub enum DatasetPushRequestError {
     Internal(DatasetInternalError),
     InvalidHead(DatasetPushInvalidHeadError),
+    RefConflict(DatasetPushRefConflictError),
 }
 
 // Wrong head suggested during push. Client's data on what the head is got out
@@ -111,6 +124,15 @@ pub struct DatasetPushInvalidHeadError {
     pub expected_head: Option<Multihash>,
 }
 
+// Reference of the pushed dataset would move an existing tag or rewind a
+// branch on the server
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Debug)]
+pub struct DatasetPushRefConflictError {
+    pub ref_name: String,
+    pub src_hash: Multihash,
+    pub dst_hash: Multihash,
+}
+
 /// Push phase 1: push metadata request
 #[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Debug)]
 pub struct DatasetPushMetadataRequest {
@@ -195,6 +217,13 @@ pub struct DatasetPushComplete {}
 #[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Debug)]
 pub struct DatasetPushCompleteConfirmed {}
 
+/// User-defined reference (tag or branch) transferred along with the blocks
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Debug)]
+pub struct TransferRef {
+    pub name: String,
+    pub block_hash: Multihash,
+}
+
 /// Packed metadata blocks batch
 #[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Debug)]
 pub struct MetadataBlocksBatch {
@@ -185,6 +185,56 @@ pub async fn prepare_dataset_metadata_batch(
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
+pub async fn prepare_transfer_refs(
+    metadata_chain: &dyn MetadataChain,
+) -> Result<Vec<TransferRef>, InternalError> {
+    Ok(metadata_chain
+        .list_refs()
+        .await?
+        .into_iter()
+        .filter(|(r, _)| !r.is_head())
+        .map(|(r, block_hash)| TransferRef {
+            name: r.to_string(),
+            block_hash,
+        })
+        .collect())
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub fn decode_transfer_refs(
+    refs: &[TransferRef],
+) -> Result<Vec<(BlockRef, Multihash)>, InternalError> {
+    refs.iter()
+        .map(|r| Ok((BlockRef::from_str(&r.name)?, r.block_hash.clone())))
+        .collect()
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub async fn check_transfer_refs(
+    metadata_chain: &dyn MetadataChain,
+    refs: &[TransferRef],
+) -> Result<(), ImportRefsError> {
+    let refs = decode_transfer_refs(refs)?;
+
+    metadata_chain.check_refs_import(&refs).await
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub async fn import_transfer_refs(
+    metadata_chain: &dyn MetadataChain,
+    refs: &[TransferRef],
+    opts: ImportRefsOpts,
+) -> Result<(), ImportRefsError> {
+    let refs = decode_transfer_refs(refs)?;
+
+    metadata_chain.import_refs(&refs, opts).await
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
 pub fn decode_metadata_batch(
     blocks_batch: &MetadataBlocksBatch,
 ) -> Result<VecDeque<HashedMetadataBlock>, GetBlockError> {
@@ -7,6 +7,7 @@
 // the Business Source License, use of this software will be governed
 // by the Apache License, Version 2.0.
 
+use std::str::FromStr;
 use std::sync::atomic::{AtomicI32, Ordering};
 use std::sync::Arc;
 
@@ -197,11 +198,15 @@ impl WsSmartTransferProtocolClient {
         transfer_plan: TransferPlan,
         dst_head: Option<&Multihash>,
         force_update_if_diverged: bool,
+        refs: Vec<TransferRef>,
+        delete_missing_refs: bool,
     ) -> Result<DatasetPushRequestAccepted, PushClientError> {
         let push_request_message = DatasetPushRequest {
             current_head: dst_head.cloned(),
             transfer_plan,
             force_update_if_diverged,
+            refs: Some(refs),
+            delete_missing_refs,
         };
 
         tracing::debug!("Sending push request: {:?}", push_request_message);
@@ -236,6 +241,13 @@ impl WsSmartTransferProtocolClient {
                     reference: BlockRef::Head,
                 }))
             }
+            Err(DatasetPushRequestError::RefConflict(e)) => {
+                Err(PushClientError::RefConflict(RefConflictError {
+                    block_ref: BlockRef::from_str(&e.ref_name)?,
+                    src_hash: e.src_hash,
+                    dst_hash: e.dst_hash,
+                }))
+            }
         }
     }
 
@@ -565,6 +577,15 @@ impl SmartTransferProtocolClient for WsSmartTransferProtocolClient {
             }
         };
 
+        // Reject conflicting references before any blocks are transferred
+        if let (Some(dst), Some(refs)) = (&dst, &dataset_pull_result.refs) {
+            check_transfer_refs(dst.as_metadata_chain(), refs).await?;
+        }
+
+        let import_refs_opts = ImportRefsOpts {
+            delete_missing: transfer_options.delete_missing_refs,
+        };
+
         let sync_result = if dataset_pull_result.transfer_plan.num_blocks > 0 {
             let dataset_pull_metadata_response =
                 match self.pull_send_metadata_request(&mut ws_stream).await {
@@ -658,6 +679,10 @@ impl SmartTransferProtocolClient for WsSmartTransferProtocolClient {
                 .await
                 .int_err()?;
 
+            if let Some(refs) = &dataset_pull_result.refs {
+                import_transfer_refs(dst.as_metadata_chain(), refs, import_refs_opts).await?;
+            }
+
             let new_dst_head = dst
                 .as_metadata_chain()
                 .resolve_ref(&BlockRef::Head)
@@ -670,6 +695,9 @@ impl SmartTransferProtocolClient for WsSmartTransferProtocolClient {
                 num_blocks: u64::from(dataset_pull_result.transfer_plan.num_blocks),
             }
         } else {
+            if let (Some(dst), Some(refs)) = (&dst, &dataset_pull_result.refs) {
+                import_transfer_refs(dst.as_metadata_chain(), refs, import_refs_opts).await?;
+            }
             SyncResult::UpToDate
         };
 
@@ -750,19 +778,26 @@ impl SmartTransferProtocolClient for WsSmartTransferProtocolClient {
             }
         };
 
+        let refs = prepare_transfer_refs(src.as_metadata_chain()).await?;
+
         match self
             .push_send_request(
                 &mut ws_stream,
                 transfer_plan,
                 dst_head,
                 transfer_options.force_update_if_diverged,
+                refs,
+                transfer_options.delete_missing_refs,
             )
             .await
         {
             Ok(_) => {}
             Err(e) => {
                 tracing::debug!("Push process aborted with error: {}", e);
-                return Err(SyncError::Internal(e.int_err()));
+                return Err(match e {
+                    PushClientError::RefConflict(err) => SyncError::RefConflict(err),
+                    _ => SyncError::Internal(e.int_err()),
+                });
             }
         };
 
@@ -118,6 +118,8 @@ impl ClientSideHarness {
 
         b.add::<SyncServiceImpl>();
 
+        b.add::<CreateDatasetUseCaseImpl>();
+
         b.add::<TransformServiceImpl>();
 
         b.add::<CompactionServiceImpl>();
@@ -127,7 +127,11 @@ async fn test_data_push_ingest_handler() {
                     ),
                     merge: MergeStrategy::Ledger(MergeStrategyLedger {
                         primary_key: vec!["event_time".to_owned(), "city".to_owned()],
+                        mode: None,
+                        dedupe_window_records: None,
+                        dedupe_window_seconds: None,
                     }),
+                    watermark: None,
                 }
                 .into(),
                 CommitOpts {
@@ -641,7 +645,11 @@ impl DataIngestHarness {
                             preprocess: None,
                             merge: MergeStrategy::Ledger(MergeStrategyLedger {
                                 primary_key: vec!["event_time".to_owned(), "city".to_owned()],
+                                mode: None,
+                                dedupe_window_records: None,
+                                dedupe_window_seconds: None,
                             }),
+                            watermark: None,
                         }
                         .into()]
                     } else {
@@ -101,6 +101,7 @@ impl Harness {
                     source_event_time: system_time,
                     new_watermark: None,
                     new_source_state: None,
+                    retraction_filter: None,
                     data_staging_path: run_info_dir.path().join(".temp-data"),
                 },
             )
@@ -583,6 +584,125 @@ async fn test_data_query_handler_data_formats() {
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
+#[test_group::group(engine, datafusion)]
+#[test_log::test(tokio::test)]
+async fn test_data_query_handler_streaming_data_formats() {
+    let harness = Harness::new().await;
+
+    let client = async move {
+        let cl = reqwest::Client::new();
+
+        let query = format!(
+            "select offset, city, population from \"{}\" order by offset desc",
+            harness.dataset_handle.alias
+        );
+        let query_url = format!("{}query", harness.root_url);
+
+        let expected_table = indoc::indoc!(
+            r#"
+            +--------+------+------------+
+            | offset | city | population |
+            +--------+------+------------+
+            | 1      | B    | 200        |
+            | 0      | A    | 100        |
+            +--------+------+------------+
+            "#
+        );
+
+        // CSV via explicit format
+        let res = cl
+            .get(&query_url)
+            .query(&[("query", query.as_str()), ("format", "csv")])
+            .send()
+            .await
+            .unwrap()
+            .error_for_status()
+            .unwrap();
+
+        assert_eq!(res.headers()[http::header::CONTENT_TYPE], "text/csv");
+        assert_eq!(
+            res.text().await.unwrap(),
+            "offset,city,population\n1,B,200\n0,A,100"
+        );
+
+        // Arrow IPC via content negotiation
+        let res = cl
+            .get(&query_url)
+            .query(&[("query", query.as_str())])
+            .header(
+                http::header::ACCEPT,
+                "application/vnd.apache.arrow.stream, application/json;q=0.5",
+            )
+            .send()
+            .await
+            .unwrap()
+            .error_for_status()
+            .unwrap();
+
+        assert_eq!(
+            res.headers()[http::header::CONTENT_TYPE],
+            "application/vnd.apache.arrow.stream"
+        );
+
+        let body = res.bytes().await.unwrap();
+        let batches: Vec<_> =
+            datafusion::arrow::ipc::reader::StreamReader::try_new(std::io::Cursor::new(body), None)
+                .unwrap()
+                .collect::<Result<_, _>>()
+                .unwrap();
+
+        assert_eq!(
+            datafusion::arrow::util::pretty::pretty_format_batches(&batches)
+                .unwrap()
+                .to_string()
+                .trim(),
+            expected_table.trim()
+        );
+
+        // Parquet via POST body, taking precedence over the Accept header
+        let res = cl
+            .post(&query_url)
+            .header(http::header::ACCEPT, "text/csv")
+            .json(&json!({
+                "query": query,
+                "dataFormat": "Parquet",
+            }))
+            .send()
+            .await
+            .unwrap()
+            .error_for_status()
+            .unwrap();
+
+        assert_eq!(
+            res.headers()[http::header::CONTENT_TYPE],
+            "application/vnd.apache.parquet"
+        );
+
+        let body = res.bytes().await.unwrap();
+        let batches: Vec<_> =
+            datafusion::parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder::try_new(
+                body,
+            )
+            .unwrap()
+            .build()
+            .unwrap()
+            .collect::<Result<_, _>>()
+            .unwrap();
+
+        assert_eq!(
+            datafusion::arrow::util::pretty::pretty_format_batches(&batches)
+                .unwrap()
+                .to_string()
+                .trim(),
+            expected_table.trim()
+        );
+    };
+
+    await_client_server_flow!(harness.server_harness.api_server_run(), client);
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
 #[test_group::group(engine, datafusion)]
 #[test_log::test(tokio::test)]
 async fn test_data_query_handler_schema_formats() {
@@ -16,12 +16,23 @@
 // the Business Source License, use of this software will be governed
 // by the Apache License, Version 2.0.
 
-use kamu::domain::PullResult;
+use std::assert_matches::assert_matches;
+
+use kamu::domain::{
+    BlockRef,
+    MetadataChainExt,
+    PullError,
+    PullResult,
+    RefConflictError,
+    SetRefOpts,
+    SyncError,
+};
 use kamu::testing::DatasetTestHelper;
 use opendatafabric::DatasetRefAny;
 
 use crate::harness::{
     await_client_server_flow,
+    make_dataset_ref,
     ClientSideHarness,
     ClientSideHarnessOptions,
     ServerSideHarness,
@@ -76,3 +87,95 @@ async fn test_smart_pull_unauthenticated() {
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_log::test(tokio::test)]
+async fn test_smart_pull_tag_conflict() {
+    let scenario = SmartPullExistingEvolvedDatasetScenario::prepare(
+        ClientSideHarness::new(ClientSideHarnessOptions {
+            multi_tenant: false,
+            authenticated_remotely: false,
+        }),
+        ServerSideLocalFsHarness::new(ServerSideHarnessOptions {
+            multi_tenant: false,
+            authorized_writes: true,
+            base_catalog: None,
+        }),
+    )
+    .await;
+
+    // Same tag points at different blocks on the server and on the client
+    let tag = BlockRef::tag("v1").unwrap();
+
+    let server_dataset = scenario
+        .server_harness
+        .cli_dataset_repository()
+        .find_dataset_by_ref(&make_dataset_ref(
+            &scenario.server_harness.operating_account_name(),
+            "foo",
+        ))
+        .await
+        .unwrap();
+    server_dataset
+        .as_metadata_chain()
+        .set_ref(
+            &tag,
+            &scenario.server_commit_result.new_head,
+            SetRefOpts::default(),
+        )
+        .await
+        .unwrap();
+
+    let client_dataset = scenario
+        .client_harness
+        .dataset_repository()
+        .find_dataset_by_ref(&make_dataset_ref(
+            &scenario.client_harness.operating_account_name(),
+            "foo",
+        ))
+        .await
+        .unwrap();
+    client_dataset
+        .as_metadata_chain()
+        .set_ref(
+            &tag,
+            &scenario.server_create_result.head,
+            SetRefOpts::default(),
+        )
+        .await
+        .unwrap();
+
+    let api_server_handle = scenario.server_harness.api_server_run();
+
+    let client_handle = async {
+        let pull_result = scenario
+            .client_harness
+            .pull_datasets(DatasetRefAny::from(scenario.server_dataset_ref), false)
+            .await;
+
+        assert_matches!(
+            &pull_result.first().unwrap().result,
+            Err(PullError::SyncError(SyncError::RefConflict(RefConflictError {
+                block_ref,
+                src_hash,
+                dst_hash,
+            }))) if *block_ref == tag
+                && *src_hash == scenario.server_commit_result.new_head
+                && *dst_hash == scenario.server_create_result.head
+        );
+
+        // Nothing was transferred
+        let client_chain = client_dataset.as_metadata_chain();
+        assert_eq!(
+            client_chain.resolve_ref(&BlockRef::Head).await.unwrap(),
+            scenario.server_create_result.head
+        );
+        assert_eq!(
+            client_chain.try_get_ref(&tag).await.unwrap(),
+            Some(scenario.server_create_result.head.clone())
+        );
+    };
+
+    await_client_server_flow!(api_server_handle, client_handle);
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
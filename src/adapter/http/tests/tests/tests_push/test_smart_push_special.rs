@@ -9,11 +9,20 @@
 
 use std::assert_matches::assert_matches;
 
-use kamu::domain::{AccessError, PushError, SyncError};
+use kamu::domain::{
+    AccessError,
+    BlockRef,
+    MetadataChainExt,
+    PushError,
+    RefConflictError,
+    SetRefOpts,
+    SyncError,
+};
 use opendatafabric::{AccountName, DatasetAlias, DatasetRefRemote};
 
 use crate::harness::{
     await_client_server_flow,
+    make_dataset_ref,
     ClientSideHarness,
     ClientSideHarnessOptions,
     ServerSideHarness,
@@ -236,3 +245,96 @@ async fn test_smart_push_existing_ref_collision() {
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_log::test(tokio::test)]
+async fn test_smart_push_tag_conflict() {
+    let scenario = SmartPushExistingEvolvedDatasetScenario::prepare(
+        ClientSideHarness::new(ClientSideHarnessOptions {
+            multi_tenant: false,
+            authenticated_remotely: true,
+        }),
+        ServerSideLocalFsHarness::new(ServerSideHarnessOptions {
+            multi_tenant: true,
+            authorized_writes: true,
+            base_catalog: None,
+        }),
+    )
+    .await;
+
+    // Same tag points at different blocks on the client and on the server
+    let tag = BlockRef::tag("v1").unwrap();
+
+    let client_dataset = scenario
+        .client_harness
+        .dataset_repository()
+        .find_dataset_by_ref(&scenario.client_dataset_ref)
+        .await
+        .unwrap();
+    client_dataset
+        .as_metadata_chain()
+        .set_ref(
+            &tag,
+            &scenario.client_commit_result.new_head,
+            SetRefOpts::default(),
+        )
+        .await
+        .unwrap();
+
+    let server_dataset = scenario
+        .server_harness
+        .cli_dataset_repository()
+        .find_dataset_by_ref(&make_dataset_ref(
+            &scenario.server_harness.operating_account_name(),
+            "foo",
+        ))
+        .await
+        .unwrap();
+    server_dataset
+        .as_metadata_chain()
+        .set_ref(
+            &tag,
+            &scenario.client_create_result.head,
+            SetRefOpts::default(),
+        )
+        .await
+        .unwrap();
+
+    let api_server_handle = scenario.server_harness.api_server_run();
+
+    let client_handle = async {
+        let push_result = scenario
+            .client_harness
+            .push_dataset(
+                scenario.client_dataset_ref,
+                scenario.server_dataset_ref,
+                false,
+            )
+            .await;
+
+        assert_matches!(
+            &push_result.first().unwrap().result,
+            Err(PushError::SyncError(SyncError::RefConflict(RefConflictError {
+                block_ref,
+                src_hash,
+                dst_hash,
+            }))) if *block_ref == tag
+                && *src_hash == scenario.client_commit_result.new_head
+                && *dst_hash == scenario.client_create_result.head
+        );
+
+        // Nothing was transferred
+        let server_chain = server_dataset.as_metadata_chain();
+        assert_eq!(
+            server_chain.resolve_ref(&BlockRef::Head).await.unwrap(),
+            scenario.client_create_result.head
+        );
+        assert_eq!(
+            server_chain.try_get_ref(&tag).await.unwrap(),
+            Some(scenario.client_create_result.head.clone())
+        );
+    };
+
+    await_client_server_flow!(api_server_handle, client_handle);
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
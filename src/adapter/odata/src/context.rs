@@ -213,7 +213,10 @@ impl CollectionContext for ODataCollectionContext {
         let query_svc: Arc<dyn QueryService> = self.catalog.get_one().unwrap();
 
         let df = query_svc
-            .get_data(&self.dataset_handle.as_local_ref())
+            .get_data(
+                &self.dataset_handle.as_local_ref(),
+                GetDataOptions::default(),
+            )
             .await
             .unwrap();
 
@@ -21,7 +21,7 @@ use kamu_accounts_services::PredefinedAccountsRegistrator;
 use kamu_adapter_http::{FileUploadLimitConfig, UploadServiceLocal};
 use kamu_adapter_oauth::GithubAuthenticationConfig;
 use kamu_auth_rebac_services::{MultiTenantRebacDatasetLifecycleMessageConsumer, RebacServiceImpl};
-use kamu_datasets::DatasetEnvVar;
+use kamu_datasets::DatasetEnvVarEncryptionKeyRing;
 use kamu_flow_system_inmem::domain::FlowConfigurationUpdatedMessage;
 use kamu_flow_system_services::MESSAGE_PRODUCER_KAMU_FLOW_CONFIGURATION_SERVICE;
 use kamu_task_system_inmem::domain::{TaskProgressMessage, MESSAGE_PRODUCER_KAMU_TASK_EXECUTOR};
@@ -180,7 +180,10 @@ pub async fn run(
         cli_catalog.get_one::<GcService>()?.evict_cache()?;
     }
 
-    initialize_components(&cli_catalog).await?;
+    let is_database_used = maybe_db_connection_settings.is_some();
+    let need_to_reconcile_dataset_entries = is_database_used && workspace_svc.is_in_workspace();
+
+    initialize_components(&cli_catalog, need_to_reconcile_dataset_entries).await?;
 
     let need_to_wrap_with_transaction = cli_commands::command_needs_transaction(&matches)?;
     let run_command = |catalog: Catalog| async move {
@@ -200,7 +203,6 @@ pub async fn run(
             Err(e) => Err(e),
         }
     };
-    let is_database_used = maybe_db_connection_settings.is_some();
     let command_result = if is_database_used && need_to_wrap_with_transaction {
         let transaction_runner = DatabaseTransactionRunner::new(cli_catalog);
 
@@ -303,6 +305,7 @@ pub fn configure_base_catalog(
     );
     b.bind::<dyn DatasetRepository, DatasetRepositoryLocalFs>();
     b.bind::<dyn DatasetRepositoryWriter, DatasetRepositoryLocalFs>();
+    b.bind::<dyn DatasetStorageScanner, DatasetRepositoryLocalFs>();
 
     b.add::<DatasetFactoryImpl>();
 
@@ -338,6 +341,8 @@ pub fn configure_base_catalog(
 
     b.add::<ResetServiceImpl>();
 
+    b.add::<StorageGcServiceImpl>();
+
     b.add::<ProvenanceServiceImpl>();
 
     b.add::<QueryServiceImpl>();
@@ -350,6 +355,7 @@ pub fn configure_base_catalog(
 
     b.add::<kamu_adapter_http::SmartTransferProtocolClientWs>();
 
+    b.add::<kamu_task_system_services::TaskQueue>();
     b.add::<kamu_task_system_services::TaskSchedulerImpl>();
 
     b.add::<kamu_task_system_services::TaskExecutorImpl>();
@@ -358,15 +364,19 @@ pub fn configure_base_catalog(
 
     b.add::<DatasetOwnershipServiceInMemory>();
     b.add::<DatasetOwnershipServiceInMemoryStateInitializer>();
+    b.add::<DatasetEntriesReconciler>();
 
     b.add::<AppendDatasetMetadataBatchUseCaseImpl>();
     b.add::<CommitDatasetEventUseCaseImpl>();
     b.add::<CreateDatasetUseCaseImpl>();
     b.add::<CreateDatasetFromSnapshotUseCaseImpl>();
     b.add::<DeleteDatasetUseCaseImpl>();
+    b.add::<DeleteDatasetRefUseCaseImpl>();
     b.add::<RenameDatasetUseCaseImpl>();
+    b.add::<SetDatasetRefUseCaseImpl>();
 
     b.add::<kamu_flow_system_services::FlowConfigurationServiceImpl>();
+    b.add::<kamu_flow_system_services::FlowServiceState>();
     b.add::<kamu_flow_system_services::FlowServiceImpl>();
     b.add_value(kamu_flow_system_inmem::domain::FlowServiceRunConfig::new(
         chrono::Duration::try_seconds(1).unwrap(),
@@ -413,6 +423,7 @@ pub fn configure_base_catalog(
     b.add::<messaging_outbox::OutboxDispatchingImpl>();
     b.bind::<dyn Outbox, OutboxDispatchingImpl>();
     b.add::<messaging_outbox::OutboxTransactionalProcessor>();
+    b.add::<messaging_outbox::OutboxDeadLetterServiceImpl>();
 
     register_message_dispatcher::<DatasetLifecycleMessage>(
         &mut b,
@@ -443,7 +454,10 @@ pub fn configure_cli_catalog(
 }
 
 #[tracing::instrument(level = "info", skip_all)]
-async fn initialize_components(cli_catalog: &Catalog) -> Result<(), CLIError> {
+async fn initialize_components(
+    cli_catalog: &Catalog,
+    need_to_reconcile_dataset_entries: bool,
+) -> Result<(), CLIError> {
     // TODO: Generalize on-startup initialization into a trait
     DatabaseTransactionRunner::new(cli_catalog.clone())
         .transactional(|transactional_catalog| async move {
@@ -456,6 +470,19 @@ async fn initialize_components(cli_catalog: &Catalog) -> Result<(), CLIError> {
                 .await
                 .map_err(CLIError::critical)?;
 
+            // Populate the catalog of dataset entries of an existing workspace or bring it
+            // up to date with the datasets that were added or removed directly
+            if need_to_reconcile_dataset_entries {
+                let reconciler = transactional_catalog
+                    .get_one::<DatasetEntriesReconciler>()
+                    .map_err(CLIError::critical)?;
+
+                reconciler
+                    .reconcile_if_out_of_date()
+                    .await
+                    .map_err(CLIError::critical)?;
+            }
+
             let initializer = transactional_catalog
                 .get_one::<DatasetOwnershipServiceInMemoryStateInitializer>()
                 .map_err(CLIError::critical)?;
@@ -513,6 +540,12 @@ pub fn register_config_in_catalog(
             .shutdown_timeout
             .unwrap()
             .into(),
+        datafusion_in_process: config
+            .engine
+            .as_ref()
+            .unwrap()
+            .datafusion_in_process
+            .unwrap(),
         spark_image: config
             .engine
             .as_ref()
@@ -639,7 +672,7 @@ pub fn register_config_in_catalog(
             catalog_builder.add::<kamu_datasets_services::DatasetKeyValueServiceSysEnv>();
             catalog_builder.add::<kamu_datasets_services::DatasetEnvVarServiceNull>();
         }
-        Some(encryption_key) => {
+        Some(_) => {
             if let Some(enabled) = &dataset_env_vars_config.enabled
                 && !enabled
             {
@@ -647,12 +680,14 @@ pub fn register_config_in_catalog(
                 catalog_builder.add::<kamu_datasets_services::DatasetKeyValueServiceSysEnv>();
                 catalog_builder.add::<kamu_datasets_services::DatasetEnvVarServiceNull>();
             } else {
-                assert!(
-                    DatasetEnvVar::try_asm_256_gcm_from_str(encryption_key).is_ok(),
-                    "Invalid dataset env var encryption key",
-                );
+                if let Err(err) =
+                    DatasetEnvVarEncryptionKeyRing::try_from_config(dataset_env_vars_config)
+                {
+                    panic!("Invalid dataset env var encryption keys: {err}");
+                }
                 catalog_builder.add::<kamu_datasets_services::DatasetKeyValueServiceImpl>();
                 catalog_builder.add::<kamu_datasets_services::DatasetEnvVarServiceImpl>();
+                catalog_builder.add::<kamu_datasets_services::DatasetEnvVarEncryptionKeyRotator>();
             }
         }
     }
@@ -661,6 +696,23 @@ pub fn register_config_in_catalog(
     catalog_builder.add_value(messaging_outbox::OutboxConfig::new(
         Duration::seconds(outbox_config.awaiting_step_secs.unwrap()),
         outbox_config.batch_size.unwrap(),
+        outbox_config.max_attempts.unwrap(),
+        Duration::seconds(outbox_config.retry_backoff_base_secs.unwrap()),
+        Duration::seconds(outbox_config.retry_backoff_max_secs.unwrap()),
+    ));
+
+    let task_executor_config = config.task_executor.as_ref().unwrap();
+    catalog_builder.add_value(kamu_task_system_inmem::domain::TaskExecutorConfig::new(
+        task_executor_config.max_concurrent_tasks.unwrap(),
+        task_executor_config
+            .max_concurrent_tasks_by_kind
+            .clone()
+            .unwrap_or_default()
+            .into_iter()
+            .collect(),
+        task_executor_config
+            .task_timeout
+            .map(|timeout| Duration::from_std(timeout.into()).unwrap()),
     ));
 }
 
@@ -7,6 +7,16 @@
 // the Business Source License, use of this software will be governed
 // by the Apache License, Version 2.0.
 
+use std::path::PathBuf;
+
+use kamu::domain::{
+    BlockPointer,
+    BlockRange,
+    BlockRef,
+    EventTimeRange,
+    GetDataOptions,
+    OffsetRange,
+};
 use kamu_accounts::CurrentAccountSubject;
 use opendatafabric::*;
 use url::Url;
@@ -105,6 +115,38 @@ pub fn get_command(
             submatches.get_flag("recursive"),
             submatches.get_flag("yes"),
         )),
+        Some(("disable-source", submatches)) => Box::new(DisableSourceCommand::new(
+            cli_catalog.get_one()?,
+            cli_catalog.get_one()?,
+            validate_dataset_ref(
+                cli_catalog,
+                submatches.get_one::<DatasetRef>("dataset").unwrap().clone(),
+            )?,
+            submatches
+                .get_one::<String>("push-source")
+                .map(String::as_str),
+        )),
+        Some(("export", submatches)) => Box::new(ExportCommand::new(
+            cli_catalog.get_one()?,
+            cli_catalog.get_one()?,
+            validate_dataset_ref(
+                cli_catalog,
+                submatches.get_one::<DatasetRef>("dataset").unwrap().clone(),
+            )?,
+            submatches.get_one::<PathBuf>("output").unwrap().clone(),
+            match submatches.get_one::<String>("format").unwrap().as_str() {
+                "parquet" => ExportFormat::Parquet,
+                "csv" => ExportFormat::Csv,
+                "ndjson" => ExportFormat::NdJson,
+                "arrow" => ExportFormat::Arrow,
+                _ => unreachable!(),
+            },
+            submatches.get_one("records-per-file").copied(),
+            submatches.get_one("max-file-size").copied(),
+            submatches.get_one("projection").map(String::as_str),
+            submatches.get_flag("system-columns"),
+            get_data_options(submatches),
+        )),
         Some(("ingest", submatches)) => Box::new(IngestCommand::new(
             cli_catalog.get_one()?,
             cli_catalog.get_one()?,
@@ -208,6 +250,7 @@ pub fn get_command(
             submatches.get_one("output-format").map(String::as_str),
             submatches.get_one("filter").map(String::as_str),
             *(submatches.get_one("limit").unwrap()),
+            submatches.get_one::<BlockPointer>("ref").cloned(),
             cli_catalog.get_one()?,
         )),
         Some(("login", submatches)) => match submatches.subcommand() {
@@ -312,6 +355,7 @@ pub fn get_command(
                     submatches.get_one("as").cloned(),
                     !submatches.get_flag("no-alias"),
                     submatches.get_flag("force"),
+                    submatches.get_flag("prune-refs"),
                     submatches.get_flag("reset-derivatives-on-diverged-input"),
                 ))
             }
@@ -329,9 +373,53 @@ pub fn get_command(
             push_matches.get_flag("recursive"),
             !push_matches.get_flag("no-alias"),
             push_matches.get_flag("force"),
+            push_matches.get_flag("prune-refs"),
             push_matches.get_one("to").cloned(),
             cli_catalog.get_one()?,
         )),
+        Some(("ref", ref_matches)) => match ref_matches.subcommand() {
+            Some(("list", list_matches)) => Box::new(RefListCommand::new(
+                cli_catalog.get_one()?,
+                cli_catalog.get_one()?,
+                cli_catalog.get_one()?,
+                validate_dataset_ref(
+                    cli_catalog,
+                    list_matches
+                        .get_one::<DatasetRef>("dataset")
+                        .unwrap()
+                        .clone(),
+                )?,
+            )),
+            Some(("set", set_matches)) => Box::new(RefSetCommand::new(
+                cli_catalog.get_one()?,
+                cli_catalog.get_one()?,
+                validate_dataset_ref(
+                    cli_catalog,
+                    set_matches
+                        .get_one::<DatasetRef>("dataset")
+                        .unwrap()
+                        .clone(),
+                )?,
+                set_matches.get_one::<BlockRef>("ref").unwrap().clone(),
+                set_matches
+                    .get_one::<BlockPointer>("target")
+                    .unwrap()
+                    .clone(),
+            )),
+            Some(("delete", delete_matches)) => Box::new(RefDeleteCommand::new(
+                cli_catalog.get_one()?,
+                cli_catalog.get_one()?,
+                validate_dataset_ref(
+                    cli_catalog,
+                    delete_matches
+                        .get_one::<DatasetRef>("dataset")
+                        .unwrap()
+                        .clone(),
+                )?,
+                delete_matches.get_one::<BlockRef>("ref").unwrap().clone(),
+            )),
+            _ => return Err(CommandInterpretationFailed.into()),
+        },
         Some(("rename", rename_matches)) => Box::new(RenameCommand::new(
             cli_catalog.get_one()?,
             validate_dataset_ref(
@@ -405,7 +493,7 @@ pub fn get_command(
                 cli_catalog,
                 submatches.get_one::<DatasetRef>("dataset").unwrap().clone(),
             )?,
-            submatches.get_one::<Multihash>("hash").unwrap().clone(),
+            submatches.get_one::<BlockPointer>("hash").unwrap().clone(),
             submatches.get_flag("yes"),
         )),
         Some(("search", submatches)) => Box::new(SearchCommand::new(
@@ -455,7 +543,14 @@ pub fn get_command(
             _ => return Err(CommandInterpretationFailed.into()),
         },
         Some(("system", submatches)) => match submatches.subcommand() {
-            Some(("gc", _)) => Box::new(GcCommand::new(cli_catalog.get_one()?)),
+            Some(("gc", gc_matches)) => Box::new(GcCommand::new(
+                cli_catalog.get_one()?,
+                cli_catalog.get_one()?,
+                gc_matches.get_flag("dry-run"),
+                gc_matches
+                    .get_one::<chrono::Duration>("grace-period")
+                    .copied(),
+            )),
             Some(("upgrade-workspace", _)) => {
                 Box::new(UpgradeWorkspaceCommand::new(cli_catalog.get_one()?))
             }
@@ -508,6 +603,32 @@ pub fn get_command(
                 gen_matches.get_one("subject").cloned(),
                 *gen_matches.get_one::<usize>("expiration-time-sec").unwrap(),
             )),
+            Some(("outbox", outbox_matches)) => match outbox_matches.subcommand() {
+                Some(("dead-letters", _)) => Box::new(OutboxDeadLetterListCommand::new(
+                    cli_catalog.get_one()?,
+                    cli_catalog.get_one()?,
+                )),
+                Some(("replay", replay_matches)) => Box::new(OutboxDeadLetterReplayCommand::new(
+                    cli_catalog.get_one()?,
+                    replay_matches.get_one::<String>("producer").unwrap(),
+                    replay_matches.get_one::<String>("consumer").unwrap(),
+                    *replay_matches.get_one::<i64>("message-id").unwrap(),
+                )),
+                _ => return Err(CommandInterpretationFailed.into()),
+            },
+            Some(("reconcile-dataset-entries", _)) => Box::new(
+                SystemReconcileDatasetEntriesCommand::new(cli_catalog.get_one().ok()),
+            ),
+            Some(("reencrypt-dataset-env-vars", _)) => Box::new(
+                SystemReencryptDatasetEnvVarsCommand::new(cli_catalog.get_one().ok()),
+            ),
+            Some(("task", task_matches)) => match task_matches.subcommand() {
+                Some(("logs", logs_matches)) => Box::new(TaskLogsCommand::new(
+                    cli_catalog.get_one()?,
+                    *logs_matches.get_one::<i64>("task-id").unwrap(),
+                )),
+                _ => return Err(CommandInterpretationFailed.into()),
+            },
             Some(("ipfs", ipfs_matches)) => match ipfs_matches.subcommand() {
                 Some(("add", add_matches)) => Box::new(SystemIpfsAddCommand::new(
                     cli_catalog.get_one()?,
@@ -550,6 +671,7 @@ pub fn get_command(
             )?,
             *(submatches.get_one("skip-records").unwrap()),
             *(submatches.get_one("num-records").unwrap()),
+            get_data_options(submatches),
             cli_catalog.get_one()?,
         )),
         Some(("ui", submatches)) => {
@@ -604,7 +726,13 @@ pub fn get_command(
 pub fn command_needs_transaction(arg_matches: &clap::ArgMatches) -> Result<bool, CLIError> {
     match arg_matches.subcommand() {
         Some(("system", system_matches)) => match system_matches.subcommand() {
-            Some(("generate-token", _)) => Ok(true),
+            Some(
+                ("generate-token", _)
+                | ("outbox", _)
+                | ("reconcile-dataset-entries", _)
+                | ("reencrypt-dataset-env-vars", _)
+                | ("task", _),
+            ) => Ok(true),
             Some(_) => Ok(false),
             None => Err(CommandInterpretationFailed.into()),
         },
@@ -613,6 +741,31 @@ pub fn command_needs_transaction(arg_matches: &clap::ArgMatches) -> Result<bool,
     }
 }
 
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+fn get_data_options(submatches: &clap::ArgMatches) -> GetDataOptions {
+    GetDataOptions {
+        offset_range: (submatches.contains_id("from-offset")
+            || submatches.contains_id("to-offset"))
+        .then(|| OffsetRange {
+            start: submatches.get_one("from-offset").copied(),
+            end: submatches.get_one("to-offset").copied(),
+        }),
+        event_time_range: (submatches.contains_id("from-event-time")
+            || submatches.contains_id("to-event-time"))
+        .then(|| EventTimeRange {
+            start: submatches.get_one("from-event-time").copied(),
+            end: submatches.get_one("to-event-time").copied(),
+        }),
+        block_range: (submatches.contains_id("after-block")
+            || submatches.contains_id("up-to-block"))
+        .then(|| BlockRange {
+            after: submatches.get_one("after-block").cloned(),
+            up_to: submatches.get_one("up-to-block").cloned(),
+        }),
+    }
+}
+
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 // Dataset reference validation
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
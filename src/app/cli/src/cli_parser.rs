@@ -32,6 +32,41 @@ fn tabular_output_params(app: Command) -> Command {
         .help("Format to display the results in")])
 }
 
+fn data_range_args() -> [Arg; 6] {
+    [
+        Arg::new("from-offset")
+            .long("from-offset")
+            .value_parser(value_parser!(u64))
+            .value_name("OFFSET")
+            .help("Only consider records with offsets starting from this one (inclusive)"),
+        Arg::new("to-offset")
+            .long("to-offset")
+            .value_parser(value_parser!(u64))
+            .value_name("OFFSET")
+            .help("Only consider records with offsets up to this one (inclusive)"),
+        Arg::new("from-event-time")
+            .long("from-event-time")
+            .value_parser(value_parse_datetime)
+            .value_name("TIME")
+            .help("Only consider records with event time starting from this one (inclusive)"),
+        Arg::new("to-event-time")
+            .long("to-event-time")
+            .value_parser(value_parse_datetime)
+            .value_name("TIME")
+            .help("Only consider records with event time before this one (exclusive)"),
+        Arg::new("after-block")
+            .long("after-block")
+            .value_parser(value_parse_multihash)
+            .value_name("HASH")
+            .help("Only consider records added by the blocks following this one"),
+        Arg::new("up-to-block")
+            .long("up-to-block")
+            .value_parser(value_parse_multihash)
+            .value_name("HASH")
+            .help("Only consider records added by the blocks up to this one (inclusive)"),
+    ]
+}
+
 pub fn cli() -> Command {
     Command::new(crate::BINARY_NAME)
         .subcommand_required(true)
@@ -313,6 +348,95 @@ pub fn cli() -> Command {
                             kamu delete my.dataset.%
                         "#
                     )),
+                Command::new("disable-source")
+                    .about("Disables the polling or a push source of a root dataset")
+                    .args([
+                        Arg::new("dataset")
+                            .required(true)
+                            .index(1)
+                            .value_parser(value_parse_dataset_ref_local)
+                            .help("Local dataset reference"),
+                        Arg::new("push-source")
+                            .long("push-source")
+                            .value_name("SRC")
+                            .help("Name of the push source to disable instead of the polling source"),
+                    ])
+                    .after_help(indoc::indoc!(
+                        r#"
+                        Disabling a source appends a corresponding event to the dataset's metadata chain. Data can no longer be ingested through a disabled source, and scheduled ingest flows of the dataset are paused when its polling source gets disabled.
+
+                        A source can be enabled again by defining it anew, e.g. via `kamu add` of an updated manifest.
+
+                        **Examples:**
+
+                        Disable the polling source of a dataset:
+
+                            kamu disable-source my.dataset
+
+                        Disable a named push source:
+
+                            kamu disable-source my.dataset --push-source device-feed
+                        "#
+                    )),
+                Command::new("export")
+                    .about("Writes dataset data into files")
+                    .args([
+                        Arg::new("dataset")
+                            .required(true)
+                            .index(1)
+                            .value_parser(value_parse_dataset_ref_local)
+                            .help("Local dataset reference"),
+                        Arg::new("output")
+                            .long("output")
+                            .required(true)
+                            .value_parser(value_parser!(PathBuf))
+                            .value_name("DIR")
+                            .help("Directory to write the files into, must be empty or not exist"),
+                        Arg::new("format")
+                            .long("format")
+                            .value_parser(["parquet", "csv", "ndjson", "arrow"])
+                            .default_value("parquet")
+                            .value_name("FMT")
+                            .help("Format of the files"),
+                        Arg::new("records-per-file")
+                            .long("records-per-file")
+                            .value_parser(value_parser!(u64).range(1..))
+                            .value_name("NUM")
+                            .help("Maximum number of records written into one file"),
+                        Arg::new("max-file-size")
+                            .long("max-file-size")
+                            .value_parser(value_parse_byte_size)
+                            .value_name("SIZE")
+                            .help("Approximate size after which the next file is started, e.g. '128MiB'"),
+                        Arg::new("projection")
+                            .long("projection")
+                            .value_name("SQL")
+                            .help("SQL expressions to select instead of the dataset columns"),
+                        Arg::new("system-columns")
+                            .long("system-columns")
+                            .action(ArgAction::SetTrue)
+                            .help("Include the offset, operation type and system time columns"),
+                    ])
+                    .args(data_range_args())
+                    .after_help(indoc::indoc!(
+                        r#"
+                        Files are named `part-<N>.<ext>` and are written in the order of record offsets. System columns are not exported unless `--system-columns` is specified.
+
+                        **Examples:**
+
+                        Export the entire dataset into a single Parquet file:
+
+                            kamu export my.dataset --output ./export
+
+                        Export records added since offset 1000 as CSV files of up to 100k records each:
+
+                            kamu export my.dataset --output ./export --format csv --from-offset 1000 --records-per-file 100000
+
+                        Export a subset of columns within a time range:
+
+                            kamu export my.dataset --output ./export --projection "city, population / 1000 as population_k" --from-event-time 2024-01-01T00:00:00Z
+                        "#
+                    )),
                 Command::new("ingest")
                     .about("Adds data to the root dataset according to its push source configuration")
                     .args([
@@ -354,6 +478,9 @@ pub fn cli() -> Command {
                                 "ndgeojson",
                                 "parquet",
                                 "esrishapefile",
+                                "avro",
+                                "orc",
+                                "excel",
                             ])
                             .help("Overrides the media type of the data expected by the push source"),
                     ]).after_help(indoc::indoc!(
@@ -560,6 +687,11 @@ pub fn cli() -> Command {
                             .value_parser(value_parser!(usize))
                             .default_value("500")
                             .help("Maximum number of blocks to display"),
+                        Arg::new("ref")
+                            .long("ref")
+                            .value_name("REF")
+                            .value_parser(value_parse_block_pointer)
+                            .help("Block hash or reference (e.g. 'tag.v1') to start the history from"),
                     ])
                     .after_help(indoc::indoc!(
                         r#"
@@ -586,6 +718,10 @@ pub fn cli() -> Command {
                         Using a filter to inspect blocks containing query changes of a derivative dataset:
 
                             kamu log -o yaml --filter source org.example.data
+
+                        Show history starting from a tagged block:
+
+                            kamu log --ref tag.v1 org.example.data
                         "#
                     )),
                 Command::new("login")
@@ -753,6 +889,10 @@ pub fn cli() -> Command {
                             .long("force")
                             .action(ArgAction::SetTrue)
                             .help("Overwrite local version with remote, even if revisions have diverged"),
+                        Arg::new("prune-refs")
+                            .long("prune-refs")
+                            .action(ArgAction::SetTrue)
+                            .help("Delete local tags and branches that don't exist in the remote dataset"),
                         Arg::new("reset-derivatives-on-diverged-input")
                             .long("reset-derivatives-on-diverged-input")
                             .action(ArgAction::SetTrue)
@@ -831,6 +971,10 @@ pub fn cli() -> Command {
                             .long("force")
                             .action(ArgAction::SetTrue)
                             .help("Overwrite remote version with local, even if revisions have diverged"),
+                        Arg::new("prune-refs")
+                            .long("prune-refs")
+                            .action(ArgAction::SetTrue)
+                            .help("Delete remote tags and branches that don't exist in the local dataset"),
                     ])
                     .after_help(indoc::indoc!(
                         r#"
@@ -861,6 +1005,79 @@ pub fn cli() -> Command {
                             kamu push org.example.data --to ipns://k5..zy
                         "#
                     )),
+                Command::new("ref")
+                    .about("Manage named references (tags and branches) of a dataset")
+                    .subcommand_required(true)
+                    .arg_required_else_help(true)
+                    .subcommands([
+                        tabular_output_params(
+                            Command::new("list")
+                                .about("Lists references of a dataset")
+                                .visible_alias("ls")
+                                .args([
+                                    Arg::new("dataset")
+                                        .required(true)
+                                        .index(1)
+                                        .value_parser(value_parse_dataset_ref_local)
+                                        .help("Local dataset reference"),
+                                ]),
+                        ),
+                        Command::new("set")
+                            .about("Creates or moves a reference to point at the specified block")
+                            .args([
+                                Arg::new("dataset")
+                                    .required(true)
+                                    .index(1)
+                                    .value_parser(value_parse_dataset_ref_local)
+                                    .help("Local dataset reference"),
+                                Arg::new("ref")
+                                    .required(true)
+                                    .index(2)
+                                    .value_parser(value_parse_block_ref)
+                                    .help("Name of the reference, e.g. 'tag.v1' or 'branch.dev'"),
+                                Arg::new("target")
+                                    .index(3)
+                                    .default_value("head")
+                                    .value_parser(value_parse_block_pointer)
+                                    .help("Hash of the block or another reference to point at"),
+                            ]),
+                        Command::new("delete")
+                            .about("Deletes a reference")
+                            .visible_alias("rm")
+                            .args([
+                                Arg::new("dataset")
+                                    .required(true)
+                                    .index(1)
+                                    .value_parser(value_parse_dataset_ref_local)
+                                    .help("Local dataset reference"),
+                                Arg::new("ref")
+                                    .required(true)
+                                    .index(2)
+                                    .value_parser(value_parse_block_ref)
+                                    .help("Name of the reference to delete"),
+                            ]),
+                    ])
+                    .after_help(indoc::indoc!(
+                        r#"
+                        References give human-readable names to blocks in the metadata chain of a dataset. Tags (`tag.<name>`) are immutable and are typically used to mark releases, while branches (`branch.<name>`) can be moved freely. The `head` reference always points to the latest block and is managed by the system.
+
+                        References are transferred along with the dataset during push and pull.
+
+                        **Examples:**
+
+                        Tag the current head of a dataset:
+
+                            kamu ref set org.example.data tag.v1
+
+                        List all references of a dataset:
+
+                            kamu ref list org.example.data
+
+                        Show metadata history starting from the tagged block:
+
+                            kamu log --ref tag.v1 org.example.data
+                        "#
+                    )),
                 Command::new("rename")
                     .about("Rename a dataset")
                     .visible_alias("mv")
@@ -898,8 +1115,8 @@ pub fn cli() -> Command {
                         Arg::new("hash")
                             .required(true)
                             .index(2)
-                            .value_parser(value_parse_multihash)
-                            .help("Hash of the block to reset to"),
+                            .value_parser(value_parse_block_pointer)
+                            .help("Hash or reference (e.g. 'tag.v1') of the block to reset to"),
                         Arg::new("yes")
                             .short('y')
                             .long("yes")
@@ -1189,7 +1406,33 @@ pub fn cli() -> Command {
                     .arg_required_else_help(true)
                     .subcommands([
                         Command::new("gc")
-                            .about("Runs garbage collection to clean up cached and unreachable objects in the workspace"),
+                            .about("Runs garbage collection to clean up cached and unreachable objects in the workspace")
+                            .args([
+                                Arg::new("dry-run")
+                                    .long("dry-run")
+                                    .action(ArgAction::SetTrue)
+                                    .help("Only report unreferenced objects without deleting anything"),
+                                Arg::new("grace-period")
+                                    .long("grace-period")
+                                    .value_name("DURATION")
+                                    .value_parser(value_parse_duration)
+                                    .help("Objects modified more recently than this are kept to avoid racing with in-flight commits (e.g. '30m', '1h')"),
+                            ])
+                            .after_help(indoc::indoc!(
+                                r#"
+                                Besides purging the cache this command finds data slices and checkpoints that are no longer referenced by any block reachable from the references of a dataset. Such objects are left behind after resetting a dataset, hard compaction, or a force-pull of a diverged history.
+
+                                **Examples:**
+
+                                See which objects would be deleted:
+
+                                    kamu system gc --dry-run
+
+                                Delete unreferenced objects immediately, without waiting for the grace period:
+
+                                    kamu system gc --grace-period 0s
+                                "#
+                            )),
                         Command::new("upgrade-workspace")
                             .about("Upgrade the layout of a local workspace to the latest version"),
                         Command::new("api-server")
@@ -1252,6 +1495,100 @@ pub fn cli() -> Command {
                             ]),
                         Command::new("diagnose")
                             .about("Run basic system diagnose check"),
+                        Command::new("outbox")
+                            .about("Inspect and recover messages that outbox consumers failed to process")
+                            .subcommand_required(true)
+                            .arg_required_else_help(true)
+                            .subcommands([
+                                tabular_output_params(
+                                    Command::new("dead-letters")
+                                        .about("Lists messages that were moved to dead letters after exhausting all retries")
+                                ),
+                                Command::new("replay")
+                                    .about("Delivers a dead-lettered message to its consumer once again")
+                                    .args([
+                                        Arg::new("producer")
+                                            .index(1)
+                                            .required(true)
+                                            .help("Name of the message producer"),
+                                        Arg::new("consumer")
+                                            .index(2)
+                                            .required(true)
+                                            .help("Name of the consumer that failed to process the message"),
+                                        Arg::new("message-id")
+                                            .index(3)
+                                            .required(true)
+                                            .value_parser(value_parser!(i64))
+                                            .help("ID of the dead-lettered message"),
+                                    ]),
+                            ])
+                            .after_help(indoc::indoc!(
+                                r#"
+                                When a consumer keeps failing to process an outbox message, the message is retried with an exponential backoff. After `outbox.maxAttempts` attempts the message is moved to dead letters, so that the consumer and all other consumers of the same producer can proceed with the following messages.
+
+                                **Examples:**
+
+                                List all dead-lettered messages:
+
+                                    kamu system outbox dead-letters
+
+                                Replay a message after fixing the cause of the failure:
+
+                                    kamu system outbox replay dev.kamu.domain.core.services.DatasetService dev.kamu.domain.auth-rebac.RebacService 42
+                                "#
+                            )),
+                        Command::new("reconcile-dataset-entries")
+                            .about("Brings the catalog of dataset entries in line with the datasets present in the workspace")
+                            .after_help(indoc::indoc!(
+                                r#"
+                                When the workspace is configured to use a database, datasets are resolved and listed using the catalog of dataset entries stored in it instead of scanning the workspace. The catalog is reconciled with the workspace automatically on startup whenever it is out of date, this command allows to force a full re-synchronization.
+
+                                **Examples:**
+
+                                Reconcile the catalog with the workspace:
+
+                                    kamu system reconcile-dataset-entries
+                                "#
+                            )),
+                        Command::new("reencrypt-dataset-env-vars")
+                            .about("Re-encrypts secret dataset env vars with the active encryption key")
+                            .after_help(indoc::indoc!(
+                                r#"
+                                Secret dataset env vars are decrypted with any key of the key ring: `datasetEnvVars.encryptionKey` identified by `datasetEnvVars.encryptionKeyId` is the active one, keys listed in `datasetEnvVars.previousEncryptionKeys` are kept after a rotation until all secrets are re-encrypted. Once this command succeeds the previous keys can be removed from the config.
+
+                                **Examples:**
+
+                                Rotate the key: move the current key with its identifier to `previousEncryptionKeys`, set a new `encryptionKey` and `encryptionKeyId`, then run:
+
+                                    kamu system reencrypt-dataset-env-vars
+                                "#
+                            )),
+                        Command::new("task")
+                            .about("Inspect tasks executed by the API server")
+                            .subcommand_required(true)
+                            .arg_required_else_help(true)
+                            .subcommands([
+                                Command::new("logs")
+                                    .about("Shows progress and output captured during execution of a task")
+                                    .args([
+                                        Arg::new("task-id")
+                                            .index(1)
+                                            .required(true)
+                                            .value_parser(value_parser!(i64))
+                                            .help("ID of the task"),
+                                    ]),
+                            ])
+                            .after_help(indoc::indoc!(
+                                r#"
+                                Output of engines and fetch containers, along with progress of ingest and transform steps, is stored together with the task. Tasks are only persisted when the workspace is configured to use a database.
+
+                                **Examples:**
+
+                                Show logs of a task that failed:
+
+                                    kamu system task logs 42
+                                "#
+                            )),
                         Command::new("ipfs")
                             .about("IPFS helpers")
                             .subcommand_required(true)
@@ -1378,11 +1715,24 @@ pub fn cli() -> Command {
                                 .value_name("NUM")
                                 .help("Number of initial records to skip before applying the limit"),
                         ])
+                        .args(data_range_args())
                         .after_help(indoc::indoc!(
                             r#"
                             This command can be thought of as a shortcut for:
 
                                 kamu sql --engine datafusion --command 'select * from "{dataset}" order by {offset_col} desc limit {num_records}'
+
+                            The range options narrow down the records before the limit is applied. Data files that can't contain records within the range are not read at all.
+
+                            **Examples:**
+
+                            Display records added since offset 1000:
+
+                                kamu tail my.dataset --from-offset 1000
+
+                            Display records with event time within January 2024:
+
+                                kamu tail my.dataset --from-event-time 2024-01-01T00:00:00Z --to-event-time 2024-02-01T00:00:00Z
                             "#
                         )),
                 ),
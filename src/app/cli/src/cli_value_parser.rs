@@ -9,7 +9,8 @@
 
 use std::str::FromStr;
 
-use kamu::domain::DatasetVisibility;
+use chrono::{DateTime, Utc};
+use kamu::domain::{BlockPointer, BlockRef, DatasetVisibility};
 use opendatafabric::{
     DatasetName,
     DatasetRef,
@@ -92,15 +93,83 @@ pub(crate) fn value_parse_repo_name(s: &str) -> Result<RepoName, String> {
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
-pub(crate) fn value_parse_multihash(s: &str) -> Result<Multihash, String> {
-    match Multihash::from_multibase(s) {
+pub(crate) fn value_parse_block_ref(s: &str) -> Result<BlockRef, String> {
+    match BlockRef::from_str(s) {
+        Ok(v) => Ok(v),
+        Err(_) => Err(
+            "Reference name must be in form: 'tag.<name>' or 'branch.<name>', where name consists \
+             of alphanumeric characters, '-', '_', and '.'"
+                .to_string(),
+        ),
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub(crate) fn value_parse_block_pointer(s: &str) -> Result<BlockPointer, String> {
+    match BlockPointer::from_str(s) {
         Ok(v) => Ok(v),
-        Err(_) => Err("Block hash must be a valid multihash string".to_string()),
+        Err(_) => Err(
+            "Block pointer must be a valid multihash or a reference name like 'head', \
+             'tag.<name>', or 'branch.<name>'"
+                .to_string(),
+        ),
     }
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
+pub(crate) fn value_parse_multihash(s: &str) -> Result<Multihash, String> {
+    Multihash::from_multibase(s).map_err(|_| "Block hash must be a valid multihash".to_string())
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub(crate) fn value_parse_datetime(s: &str) -> Result<DateTime<Utc>, String> {
+    DateTime::parse_from_rfc3339(s)
+        .map(Into::into)
+        .map_err(|_| "Time must be in RFC3339 format, e.g. '2024-01-01T12:00:00Z'".to_string())
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub(crate) fn value_parse_duration(s: &str) -> Result<chrono::Duration, String> {
+    let duration: std::time::Duration = duration_string::DurationString::from_string(s.to_owned())
+        .map_err(|_| "Duration should be in form like: '30s', '5m', '1h', '2d'".to_string())?
+        .into();
+
+    chrono::Duration::from_std(duration).map_err(|e| e.to_string())
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub(crate) fn value_parse_byte_size(s: &str) -> Result<u64, String> {
+    let err = || "Size should be in form like: '1000', '500KB', '128MiB', '1GB'".to_string();
+
+    let s = s.trim();
+    let (number, unit) = s.split_at(s.find(|c: char| !c.is_ascii_digit()).unwrap_or(s.len()));
+
+    let multiplier: u64 = match unit.trim().to_ascii_lowercase().as_str() {
+        "" | "b" => 1,
+        "kb" => 1_000,
+        "kib" => 1 << 10,
+        "mb" => 1_000_000,
+        "mib" => 1 << 20,
+        "gb" => 1_000_000_000,
+        "gib" => 1 << 30,
+        _ => return Err(err()),
+    };
+
+    number
+        .parse::<u64>()
+        .ok()
+        .and_then(|n| n.checked_mul(multiplier))
+        .filter(|n| *n > 0)
+        .ok_or_else(err)
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
 pub(crate) fn validate_log_filter(s: &str) -> Result<String, String> {
     let items: Vec<_> = s.split(',').collect();
     for item in items {
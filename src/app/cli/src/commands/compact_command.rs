@@ -122,6 +122,7 @@ impl Command for CompactCommand {
                     max_slice_size: Some(self.max_slice_size),
                     max_slice_records: Some(self.max_slice_records),
                     keep_metadata_only: self.keep_metadata_only,
+                    ..Default::default()
                 },
                 Some(listener.clone()),
             )
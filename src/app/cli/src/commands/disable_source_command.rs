@@ -0,0 +1,80 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::sync::Arc;
+
+use kamu::domain::*;
+use opendatafabric::*;
+
+use super::{CLIError, Command};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub struct DisableSourceCommand {
+    dataset_repo: Arc<dyn DatasetRepository>,
+    commit_dataset_event: Arc<dyn CommitDatasetEventUseCase>,
+    dataset_ref: DatasetRef,
+    push_source_name: Option<String>,
+}
+
+impl DisableSourceCommand {
+    pub fn new(
+        dataset_repo: Arc<dyn DatasetRepository>,
+        commit_dataset_event: Arc<dyn CommitDatasetEventUseCase>,
+        dataset_ref: DatasetRef,
+        push_source_name: Option<&str>,
+    ) -> Self {
+        Self {
+            dataset_repo,
+            commit_dataset_event,
+            dataset_ref,
+            push_source_name: push_source_name.map(ToOwned::to_owned),
+        }
+    }
+}
+
+#[async_trait::async_trait(?Send)]
+impl Command for DisableSourceCommand {
+    async fn run(&mut self) -> Result<(), CLIError> {
+        let dataset_handle = self
+            .dataset_repo
+            .resolve_dataset_ref(&self.dataset_ref)
+            .await?;
+
+        let event: MetadataEvent = match &self.push_source_name {
+            None => DisablePollingSource {}.into(),
+            Some(source_name) => DisablePushSource {
+                source_name: source_name.clone(),
+            }
+            .into(),
+        };
+
+        let commit_result = self
+            .commit_dataset_event
+            .execute(&dataset_handle, event, CommitOpts::default())
+            .await
+            .map_err(|e| match e {
+                CommitError::MetadataAppendError(AppendError::InvalidBlock(e)) => {
+                    CLIError::usage_error_from(e)
+                }
+                e => CLIError::failure(e),
+            })?;
+
+        eprintln!(
+            "{} {} {}",
+            console::style("Disabled source of").green().bold(),
+            dataset_handle.alias,
+            console::style(format!("(new head: {})", commit_result.new_head)).dim(),
+        );
+
+        Ok(())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,362 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+use datafusion::arrow::datatypes::SchemaRef;
+use datafusion::arrow::ipc::writer::FileWriter;
+use datafusion::arrow::record_batch::RecordBatch;
+use datafusion::common::Column;
+use datafusion::parquet::arrow::ArrowWriter;
+use datafusion::prelude::*;
+use futures::TryStreamExt;
+use internal_error::{InternalError, ResultIntoInternal};
+use kamu::domain::*;
+use opendatafabric::*;
+
+use super::{CLIError, Command};
+use crate::records_writers::{CsvWriter, CsvWriterOptions, JsonLineDelimitedWriter, RecordsWriter};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat {
+    Parquet,
+    Csv,
+    NdJson,
+    /// Arrow IPC file format
+    Arrow,
+}
+
+impl ExportFormat {
+    fn file_extension(self) -> &'static str {
+        match self {
+            Self::Parquet => "parquet",
+            Self::Csv => "csv",
+            Self::NdJson => "ndjson",
+            Self::Arrow => "arrow",
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub struct ExportCommand {
+    dataset_repo: Arc<dyn DatasetRepository>,
+    query_svc: Arc<dyn QueryService>,
+    dataset_ref: DatasetRef,
+    output_dir: PathBuf,
+    format: ExportFormat,
+    records_per_file: Option<u64>,
+    max_file_size: Option<u64>,
+    projection: Option<String>,
+    system_columns: bool,
+    options: GetDataOptions,
+}
+
+impl ExportCommand {
+    pub fn new(
+        dataset_repo: Arc<dyn DatasetRepository>,
+        query_svc: Arc<dyn QueryService>,
+        dataset_ref: DatasetRef,
+        output_dir: PathBuf,
+        format: ExportFormat,
+        records_per_file: Option<u64>,
+        max_file_size: Option<u64>,
+        projection: Option<&str>,
+        system_columns: bool,
+        options: GetDataOptions,
+    ) -> Self {
+        Self {
+            dataset_repo,
+            query_svc,
+            dataset_ref,
+            output_dir,
+            format,
+            records_per_file,
+            max_file_size,
+            projection: projection.map(ToOwned::to_owned),
+            system_columns,
+            options,
+        }
+    }
+
+    async fn get_data(&self) -> Result<DataFrame, CLIError> {
+        let df = self
+            .query_svc
+            .get_data(&self.dataset_ref, self.options.clone())
+            .await
+            .map_err(|e| match e {
+                QueryError::InvalidDataRange(e) => CLIError::usage_error_from(e),
+                e => CLIError::failure(e),
+            })?;
+
+        if df.schema().fields().is_empty() {
+            return Err(CLIError::failure(DatasetSchemaNotAvailableError {
+                dataset_ref: self.dataset_ref.clone(),
+            }));
+        }
+
+        let dataset_handle = self
+            .dataset_repo
+            .resolve_dataset_ref(&self.dataset_ref)
+            .await?;
+
+        let vocab: DatasetVocabulary = self
+            .dataset_repo
+            .get_dataset_by_handle(&dataset_handle)
+            .as_metadata_chain()
+            .accept_one(SearchSetVocabVisitor::new())
+            .await
+            .int_err()?
+            .into_event()
+            .unwrap_or_default()
+            .into();
+
+        let df = df
+            .sort(vec![
+                col(Column::from_name(&vocab.offset_column)).sort(true, false)
+            ])
+            .int_err()?;
+
+        if let Some(projection) = &self.projection {
+            // The projection is evaluated against a view in the same session, so that
+            // the data is still read from the dataset's object store
+            let ctx = SessionContext::new_with_state(df.clone().into_parts().0);
+            ctx.register_table("input", df.into_view()).int_err()?;
+
+            return ctx
+                .sql(&format!("select {projection} from input"))
+                .await
+                .map_err(CLIError::usage_error_from);
+        }
+
+        if self.system_columns {
+            return Ok(df);
+        }
+
+        let system_columns = [
+            &vocab.offset_column,
+            &vocab.operation_type_column,
+            &vocab.system_time_column,
+        ];
+
+        let data_columns: Vec<_> = df
+            .schema()
+            .fields()
+            .iter()
+            .filter(|f| !system_columns.contains(&f.name()))
+            .map(|f| col(Column::from_name(f.name())))
+            .collect();
+
+        df.select(data_columns).int_err().map_err(Into::into)
+    }
+
+    fn ensure_output_dir_empty(&self) -> Result<(), CLIError> {
+        if self.output_dir.exists() && self.output_dir.read_dir()?.next().is_some() {
+            return Err(CLIError::usage_error(format!(
+                "Output directory {} is not empty",
+                self.output_dir.display()
+            )));
+        }
+        Ok(())
+    }
+
+    fn is_part_full(&self, part: &PartWriter) -> bool {
+        self.records_per_file
+            .is_some_and(|max| part.records_written >= max)
+            || self
+                .max_file_size
+                .is_some_and(|max| part.estimated_size() >= max)
+    }
+
+    fn new_part(&self, index: usize, schema: &SchemaRef) -> Result<PartWriter, CLIError> {
+        let path = self
+            .output_dir
+            .join(format!("part-{index:05}.{}", self.format.file_extension()));
+        PartWriter::new(&path, self.format, schema).map_err(Into::into)
+    }
+}
+
+#[async_trait::async_trait(?Send)]
+impl Command for ExportCommand {
+    async fn run(&mut self) -> Result<(), CLIError> {
+        self.ensure_output_dir_empty()?;
+
+        let df = self.get_data().await?;
+        let mut record_batches = df.execute_stream().await.int_err()?;
+        let schema = record_batches.schema();
+
+        std::fs::create_dir_all(&self.output_dir)?;
+
+        // An empty result still produces one file that preserves the schema
+        let mut part = self.new_part(0, &schema)?;
+        let mut num_parts = 1;
+        let mut num_records = 0;
+
+        while let Some(mut batch) = record_batches.try_next().await.int_err()? {
+            while batch.num_rows() != 0 {
+                if self.is_part_full(&part) {
+                    part.finish()?;
+                    part = self.new_part(num_parts, &schema)?;
+                    num_parts += 1;
+                }
+
+                let num_rows = batch.num_rows() as u64;
+                let take = self.records_per_file.map_or(num_rows, |max| {
+                    std::cmp::min(num_rows, max - part.records_written)
+                });
+                let take = usize::try_from(take).unwrap();
+
+                part.write(&batch.slice(0, take))?;
+                batch = batch.slice(take, batch.num_rows() - take);
+                num_records += take;
+            }
+        }
+
+        part.finish()?;
+
+        eprintln!(
+            "{} {} {} {} {}",
+            console::style("Exported").green().bold(),
+            num_records,
+            console::style("record(s) into").green().bold(),
+            num_parts,
+            console::style(format!("file(s) in {}", self.output_dir.display()))
+                .green()
+                .bold(),
+        );
+
+        Ok(())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+struct PartWriter {
+    encoder: PartEncoder,
+    file: PartFile,
+    records_written: u64,
+}
+
+enum PartEncoder {
+    Parquet(ArrowWriter<PartFile>),
+    Arrow(FileWriter<PartFile>),
+    Records(Box<dyn RecordsWriter>),
+}
+
+impl PartWriter {
+    fn new(path: &Path, format: ExportFormat, schema: &SchemaRef) -> Result<Self, InternalError> {
+        let file = PartFile::create(path).int_err()?;
+
+        let encoder = match format {
+            ExportFormat::Parquet => PartEncoder::Parquet(
+                ArrowWriter::try_new(file.clone(), schema.clone(), None).int_err()?,
+            ),
+            ExportFormat::Arrow => {
+                PartEncoder::Arrow(FileWriter::try_new(file.clone(), schema).int_err()?)
+            }
+            ExportFormat::Csv => PartEncoder::Records(Box::new(CsvWriter::new(
+                file.clone(),
+                CsvWriterOptions {
+                    header: true,
+                    ..Default::default()
+                },
+            ))),
+            ExportFormat::NdJson => {
+                PartEncoder::Records(Box::new(JsonLineDelimitedWriter::new(file.clone())))
+            }
+        };
+
+        Ok(Self {
+            encoder,
+            file,
+            records_written: 0,
+        })
+    }
+
+    fn write(&mut self, batch: &RecordBatch) -> Result<(), InternalError> {
+        match &mut self.encoder {
+            PartEncoder::Parquet(writer) => writer.write(batch).int_err()?,
+            PartEncoder::Arrow(writer) => writer.write(batch).int_err()?,
+            PartEncoder::Records(writer) => writer.write_batch(batch).int_err()?,
+        }
+        self.records_written += batch.num_rows() as u64;
+        Ok(())
+    }
+
+    /// Parquet writer buffers the row group in memory, so its size is only
+    /// known approximately until the file is finished
+    fn estimated_size(&self) -> u64 {
+        let buffered = match &self.encoder {
+            PartEncoder::Parquet(writer) => writer.in_progress_size() as u64,
+            PartEncoder::Arrow(_) | PartEncoder::Records(_) => 0,
+        };
+        self.file.bytes_written() + buffered
+    }
+
+    fn finish(self) -> Result<(), InternalError> {
+        let Self {
+            encoder, mut file, ..
+        } = self;
+
+        match encoder {
+            PartEncoder::Parquet(writer) => {
+                writer.close().int_err()?;
+            }
+            PartEncoder::Arrow(mut writer) => writer.finish().int_err()?,
+            PartEncoder::Records(mut writer) => writer.finish().int_err()?,
+        }
+        file.flush().int_err()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Buffered output file shared between the encoder and the [`PartWriter`], so
+/// that the latter can track the size and flush the file when the encoder does
+/// not provide access to the underlying writer
+#[derive(Clone)]
+struct PartFile(Arc<Mutex<PartFileState>>);
+
+struct PartFileState {
+    writer: BufWriter<File>,
+    bytes_written: u64,
+}
+
+impl PartFile {
+    fn create(path: &Path) -> std::io::Result<Self> {
+        Ok(Self(Arc::new(Mutex::new(PartFileState {
+            writer: BufWriter::new(File::create(path)?),
+            bytes_written: 0,
+        }))))
+    }
+
+    fn bytes_written(&self) -> u64 {
+        self.0.lock().unwrap().bytes_written
+    }
+}
+
+impl Write for PartFile {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        let mut state = self.0.lock().unwrap();
+        let written = state.writer.write(buf)?;
+        state.bytes_written += written as u64;
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.0.lock().unwrap().writer.flush()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -9,22 +9,38 @@
 
 use std::sync::Arc;
 
+use kamu::domain::{StorageGcOptions, StorageGcService};
+
 use super::{CLIError, Command};
 use crate::GcService;
 
 pub struct GcCommand {
     gc_service: Arc<GcService>,
+    storage_gc_service: Arc<dyn StorageGcService>,
+    dry_run: bool,
+    grace_period: Option<chrono::Duration>,
 }
 
 impl GcCommand {
-    pub fn new(gc_service: Arc<GcService>) -> Self {
-        Self { gc_service }
+    pub fn new(
+        gc_service: Arc<GcService>,
+        storage_gc_service: Arc<dyn StorageGcService>,
+        dry_run: bool,
+        grace_period: Option<chrono::Duration>,
+    ) -> Self {
+        Self {
+            gc_service,
+            storage_gc_service,
+            dry_run,
+            grace_period,
+        }
     }
-}
 
-#[async_trait::async_trait(?Send)]
-impl Command for GcCommand {
-    async fn run(&mut self) -> Result<(), CLIError> {
+    fn clean_cache(&self) -> Result<u64, CLIError> {
+        if self.dry_run {
+            return Ok(0);
+        }
+
         eprint!("Cleaning cache...");
         let result = self.gc_service.purge_cache()?;
         if result.bytes_freed != 0 {
@@ -36,15 +52,77 @@ impl Command for GcCommand {
             eprintln!();
         }
 
-        if result.bytes_freed != 0 {
+        Ok(result.bytes_freed)
+    }
+
+    async fn clean_storage(&self) -> Result<u64, CLIError> {
+        let mut options = StorageGcOptions {
+            dry_run: self.dry_run,
+            ..Default::default()
+        };
+        if let Some(grace_period) = self.grace_period {
+            options.grace_period = grace_period;
+        }
+
+        eprintln!("Looking for unreferenced objects in datasets...");
+        let results = self
+            .storage_gc_service
+            .collect_garbage(options)
+            .await
+            .map_err(CLIError::critical)?;
+
+        let mut bytes_freed = 0;
+        for result in results {
+            if result.unreferenced_objects.is_empty() {
+                continue;
+            }
+
+            eprintln!(
+                "  {}: {} object(s) ({})",
+                result.dataset_handle.alias,
+                result.unreferenced_objects.len(),
+                humansize::format_size(result.bytes_freed(), humansize::BINARY)
+            );
+
+            if self.dry_run {
+                for object in &result.unreferenced_objects {
+                    eprintln!(
+                        "    {:?} {} ({})",
+                        object.kind,
+                        object.hash,
+                        humansize::format_size(object.size, humansize::BINARY)
+                    );
+                }
+            }
+
+            bytes_freed += result.bytes_freed();
+        }
+
+        Ok(bytes_freed)
+    }
+}
+
+#[async_trait::async_trait(?Send)]
+impl Command for GcCommand {
+    async fn run(&mut self) -> Result<(), CLIError> {
+        let bytes_freed = self.clean_cache()? + self.clean_storage().await?;
+
+        if bytes_freed == 0 {
+            eprintln!("{}", console::style("Workspace is already clean").yellow());
+        } else if self.dry_run {
+            eprintln!(
+                "{} {} {}",
+                console::style("Would clean up").yellow().bold(),
+                humansize::format_size(bytes_freed, humansize::BINARY),
+                console::style("in the workspace (dry run)").yellow().bold(),
+            );
+        } else {
             eprintln!(
                 "{} {} {}",
                 console::style("Cleaned up").green().bold(),
-                humansize::format_size(result.bytes_freed, humansize::BINARY),
+                humansize::format_size(bytes_freed, humansize::BINARY),
                 console::style("in the workspace").green().bold(),
             );
-        } else {
-            eprintln!("{}", console::style("Workspace is already clean").yellow());
         }
 
         Ok(())
@@ -109,6 +109,8 @@ impl InspectSchemaCommand {
     fn query_errors(e: QueryError) -> CLIError {
         match e {
             QueryError::DatasetNotFound(e) => CLIError::usage_error_from(e),
+            QueryError::BlockRefNotFound(e) => CLIError::usage_error_from(e),
+            QueryError::InvalidDataRange(e) => CLIError::usage_error_from(e),
             QueryError::DatasetSchemaNotAvailable(_) => unreachable!(),
             e @ (QueryError::DataFusionError(_) | QueryError::Access(_)) => CLIError::failure(e),
             e @ QueryError::Internal(_) => CLIError::critical(e),
@@ -30,6 +30,7 @@ pub struct LogCommand {
     output_format: Option<String>,
     filter: Option<String>,
     limit: usize,
+    block_pointer: Option<BlockPointer>,
     output_config: Arc<OutputConfig>,
 }
 
@@ -41,6 +42,7 @@ impl LogCommand {
         output_format: Option<&str>,
         filter: Option<&str>,
         limit: usize,
+        block_pointer: Option<BlockPointer>,
         output_config: Arc<OutputConfig>,
     ) -> Self {
         Self {
@@ -50,6 +52,7 @@ impl LogCommand {
             output_format: output_format.map(ToOwned::to_owned),
             filter: filter.map(ToOwned::to_owned),
             limit,
+            block_pointer,
             output_config,
         }
     }
@@ -113,11 +116,19 @@ impl Command for LogCommand {
             })?;
 
         let dataset = self.dataset_repo.get_dataset_by_handle(&dataset_handle);
+        let chain = dataset.as_metadata_chain();
+
+        let head = chain
+            .resolve_pointer(
+                self.block_pointer
+                    .as_ref()
+                    .unwrap_or(&BlockPointer::Ref(BlockRef::Head)),
+            )
+            .await?;
 
         let blocks = Box::pin(
-            dataset
-                .as_metadata_chain()
-                .iter_blocks()
+            chain
+                .iter_blocks_interval(&head, None, false)
                 .filter_ok(|(_, b)| self.filter_block(b)),
         );
 
@@ -17,6 +17,8 @@ mod complete_command;
 mod completions_command;
 mod config_command;
 mod delete_command;
+mod disable_source_command;
+mod export_command;
 mod gc_command;
 mod ingest_command;
 mod init_command;
@@ -30,9 +32,14 @@ mod login_silent_command;
 mod logout_command;
 mod new_dataset_command;
 mod notebook_command;
+mod outbox_dead_letter_list_command;
+mod outbox_dead_letter_replay_command;
 mod pull_command;
 mod pull_images_command;
 mod push_command;
+mod ref_delete_command;
+mod ref_list_command;
+mod ref_set_command;
 mod rename_command;
 mod repository_add_command;
 mod repository_delete_command;
@@ -53,7 +60,10 @@ mod system_e2e_command;
 mod system_generate_token_command;
 mod system_info_command;
 mod system_ipfs_add_command;
+mod system_reconcile_dataset_entries_command;
+mod system_reencrypt_dataset_env_vars_command;
 mod tail_command;
+mod task_logs_command;
 mod ui_command;
 mod upgrade_workspace_command;
 mod verify_command;
@@ -67,6 +77,8 @@ pub use complete_command::*;
 pub use completions_command::*;
 pub use config_command::*;
 pub use delete_command::*;
+pub use disable_source_command::*;
+pub use export_command::*;
 pub use gc_command::*;
 pub use ingest_command::*;
 pub use init_command::*;
@@ -80,9 +92,14 @@ pub use login_silent_command::*;
 pub use logout_command::*;
 pub use new_dataset_command::*;
 pub use notebook_command::*;
+pub use outbox_dead_letter_list_command::*;
+pub use outbox_dead_letter_replay_command::*;
 pub use pull_command::*;
 pub use pull_images_command::*;
 pub use push_command::*;
+pub use ref_delete_command::*;
+pub use ref_list_command::*;
+pub use ref_set_command::*;
 pub use rename_command::*;
 pub use repository_add_command::*;
 pub use repository_delete_command::*;
@@ -103,7 +120,10 @@ pub use system_e2e_command::*;
 pub use system_generate_token_command::*;
 pub use system_info_command::*;
 pub use system_ipfs_add_command::*;
+pub use system_reconcile_dataset_entries_command::*;
+pub use system_reencrypt_dataset_env_vars_command::*;
 pub use tail_command::*;
+pub use task_logs_command::*;
 pub use ui_command::*;
 pub use upgrade_workspace_command::*;
 pub use verify_command::*;
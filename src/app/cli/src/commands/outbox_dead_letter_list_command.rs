@@ -0,0 +1,116 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::sync::Arc;
+
+use messaging_outbox::{OutboxDeadLetter, OutboxDeadLetterService};
+
+use super::{CLIError, Command};
+use crate::output::*;
+use crate::records_writers::TableWriter;
+
+pub struct OutboxDeadLetterListCommand {
+    dead_letter_service: Arc<dyn OutboxDeadLetterService>,
+    output_config: Arc<OutputConfig>,
+}
+
+impl OutboxDeadLetterListCommand {
+    pub fn new(
+        dead_letter_service: Arc<dyn OutboxDeadLetterService>,
+        output_config: Arc<OutputConfig>,
+    ) -> Self {
+        Self {
+            dead_letter_service,
+            output_config,
+        }
+    }
+
+    // TODO: support multiple format specifiers
+    fn print_machine_readable(dead_letters: &[OutboxDeadLetter]) -> Result<(), CLIError> {
+        use std::io::Write;
+
+        let mut out = std::io::stdout();
+        writeln!(
+            out,
+            "Producer,Consumer,Message ID,Attempts,Dead Lettered At,Last Error"
+        )?;
+
+        for dead_letter in dead_letters {
+            writeln!(
+                out,
+                "{},{},{},{},{},\"{}\"",
+                dead_letter.producer_name,
+                dead_letter.consumer_name,
+                dead_letter.message_id,
+                dead_letter.attempts,
+                dead_letter.dead_lettered_at.to_rfc3339(),
+                dead_letter.last_error.replace('"', "\"\""),
+            )?;
+        }
+
+        Ok(())
+    }
+
+    fn print_pretty(dead_letters: &[OutboxDeadLetter]) {
+        use prettytable::*;
+
+        let mut table = Table::new();
+        table.set_format(TableWriter::<Vec<u8>>::get_table_format());
+
+        table.set_titles(row![
+            bc->"Producer",
+            bc->"Consumer",
+            bc->"Message ID",
+            bc->"Attempts",
+            bc->"Dead Lettered At",
+            bc->"Last Error",
+        ]);
+
+        for dead_letter in dead_letters {
+            table.add_row(Row::new(vec![
+                Cell::new(&dead_letter.producer_name),
+                Cell::new(&dead_letter.consumer_name),
+                Cell::new(&dead_letter.message_id.to_string()).style_spec("r"),
+                Cell::new(&dead_letter.attempts.to_string()).style_spec("r"),
+                Cell::new(&dead_letter.dead_lettered_at.to_rfc3339()),
+                Cell::new(&dead_letter.last_error),
+            ]));
+        }
+
+        // Header doesn't render when there are no data rows in the table
+        if dead_letters.is_empty() {
+            table.add_row(Row::new(vec![
+                Cell::new(""),
+                Cell::new(""),
+                Cell::new(""),
+                Cell::new(""),
+                Cell::new(""),
+                Cell::new(""),
+            ]));
+        }
+
+        table.printstd();
+    }
+}
+
+#[async_trait::async_trait(?Send)]
+impl Command for OutboxDeadLetterListCommand {
+    async fn run(&mut self) -> Result<(), CLIError> {
+        let dead_letters = self.dead_letter_service.list_dead_letters().await?;
+
+        // TODO: replace with formatters
+        match self.output_config.format {
+            OutputFormat::Table => Self::print_pretty(&dead_letters),
+            OutputFormat::Csv => Self::print_machine_readable(&dead_letters)?,
+            _ => unimplemented!("Unsupported format: {:?}", self.output_config.format),
+        }
+
+        Ok(())
+    }
+}
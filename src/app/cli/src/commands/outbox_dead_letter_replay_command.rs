@@ -0,0 +1,68 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::sync::Arc;
+
+use messaging_outbox::{OutboxDeadLetterService, OutboxMessageID, ReplayDeadLetterError};
+
+use super::{CLIError, Command};
+
+pub struct OutboxDeadLetterReplayCommand {
+    dead_letter_service: Arc<dyn OutboxDeadLetterService>,
+    producer_name: String,
+    consumer_name: String,
+    message_id: OutboxMessageID,
+}
+
+impl OutboxDeadLetterReplayCommand {
+    pub fn new<S1, S2>(
+        dead_letter_service: Arc<dyn OutboxDeadLetterService>,
+        producer_name: S1,
+        consumer_name: S2,
+        message_id: i64,
+    ) -> Self
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+    {
+        Self {
+            dead_letter_service,
+            producer_name: producer_name.into(),
+            consumer_name: consumer_name.into(),
+            message_id: OutboxMessageID::new(message_id),
+        }
+    }
+}
+
+#[async_trait::async_trait(?Send)]
+impl Command for OutboxDeadLetterReplayCommand {
+    async fn run(&mut self) -> Result<(), CLIError> {
+        self.dead_letter_service
+            .replay_dead_letter(&self.producer_name, &self.consumer_name, self.message_id)
+            .await
+            .map_err(|e| match e {
+                ReplayDeadLetterError::NotFound(_) | ReplayDeadLetterError::ConsumerFailed(_) => {
+                    CLIError::failure(e)
+                }
+                ReplayDeadLetterError::Internal(e) => CLIError::critical(e),
+            })?;
+
+        eprintln!(
+            "{}",
+            console::style(format!(
+                "Message {} was successfully replayed to consumer '{}'",
+                self.message_id, self.consumer_name
+            ))
+            .green()
+            .bold()
+        );
+
+        Ok(())
+    }
+}
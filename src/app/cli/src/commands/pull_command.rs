@@ -38,6 +38,7 @@ pub struct PullCommand {
     as_name: Option<DatasetName>,
     add_aliases: bool,
     force: bool,
+    prune_refs: bool,
     reset_derivatives_on_diverged_input: bool,
 }
 
@@ -55,6 +56,7 @@ impl PullCommand {
         as_name: Option<DatasetName>,
         add_aliases: bool,
         force: bool,
+        prune_refs: bool,
         reset_derivatives_on_diverged_input: bool,
     ) -> Self
     where
@@ -73,6 +75,7 @@ impl PullCommand {
             as_name,
             add_aliases,
             force,
+            prune_refs,
             reset_derivatives_on_diverged_input,
         }
     }
@@ -141,9 +144,11 @@ impl PullCommand {
                         exhaust_sources: true,
                         dataset_env_vars: HashMap::new(),
                         schema_inference: SchemaInferenceOpts::default(),
+                        ..PollingIngestOptions::default()
                     },
                     sync_options: SyncOptions {
                         force: self.force,
+                        delete_missing_refs: self.prune_refs,
                         ..SyncOptions::default()
                     },
                 },
@@ -33,6 +33,7 @@ pub struct PushCommand {
     recursive: bool,
     add_aliases: bool,
     force: bool,
+    prune_refs: bool,
     to: Option<DatasetRefRemote>,
     output_config: Arc<OutputConfig>,
 }
@@ -48,6 +49,7 @@ impl PushCommand {
         recursive: bool,
         add_aliases: bool,
         force: bool,
+        prune_refs: bool,
         to: Option<DatasetRefRemote>,
         output_config: Arc<OutputConfig>,
     ) -> Self
@@ -64,6 +66,7 @@ impl PushCommand {
             recursive,
             add_aliases,
             force,
+            prune_refs,
             to,
             output_config,
         }
@@ -143,6 +146,7 @@ impl PushCommand {
     fn sync_options(&self) -> SyncOptions {
         SyncOptions {
             force: self.force,
+            delete_missing_refs: self.prune_refs,
             ..SyncOptions::default()
         }
     }
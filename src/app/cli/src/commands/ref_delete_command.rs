@@ -0,0 +1,63 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::sync::Arc;
+
+use kamu::domain::*;
+use opendatafabric::*;
+
+use super::{CLIError, Command};
+
+pub struct RefDeleteCommand {
+    dataset_repo: Arc<dyn DatasetRepository>,
+    delete_dataset_ref_use_case: Arc<dyn DeleteDatasetRefUseCase>,
+    dataset_ref: DatasetRef,
+    block_ref: BlockRef,
+}
+
+impl RefDeleteCommand {
+    pub fn new(
+        dataset_repo: Arc<dyn DatasetRepository>,
+        delete_dataset_ref_use_case: Arc<dyn DeleteDatasetRefUseCase>,
+        dataset_ref: DatasetRef,
+        block_ref: BlockRef,
+    ) -> Self {
+        Self {
+            dataset_repo,
+            delete_dataset_ref_use_case,
+            dataset_ref,
+            block_ref,
+        }
+    }
+}
+
+#[async_trait::async_trait(?Send)]
+impl Command for RefDeleteCommand {
+    async fn run(&mut self) -> Result<(), CLIError> {
+        let dataset_handle = self
+            .dataset_repo
+            .resolve_dataset_ref(&self.dataset_ref)
+            .await?;
+
+        self.delete_dataset_ref_use_case
+            .execute(&dataset_handle, &self.block_ref)
+            .await
+            .map_err(|e| match e {
+                DeleteDatasetRefError::Internal(e) => CLIError::critical(e),
+                _ => CLIError::failure(e),
+            })?;
+
+        eprintln!(
+            "{}",
+            console::style(format!("Deleted {}", self.block_ref)).green()
+        );
+
+        Ok(())
+    }
+}
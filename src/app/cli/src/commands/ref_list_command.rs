@@ -0,0 +1,111 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::sync::Arc;
+
+use kamu::domain::*;
+use opendatafabric::*;
+
+use super::{CLIError, Command};
+use crate::output::*;
+use crate::records_writers::TableWriter;
+
+pub struct RefListCommand {
+    dataset_repo: Arc<dyn DatasetRepository>,
+    dataset_action_authorizer: Arc<dyn auth::DatasetActionAuthorizer>,
+    output_config: Arc<OutputConfig>,
+    dataset_ref: DatasetRef,
+}
+
+impl RefListCommand {
+    pub fn new(
+        dataset_repo: Arc<dyn DatasetRepository>,
+        dataset_action_authorizer: Arc<dyn auth::DatasetActionAuthorizer>,
+        output_config: Arc<OutputConfig>,
+        dataset_ref: DatasetRef,
+    ) -> Self {
+        Self {
+            dataset_repo,
+            dataset_action_authorizer,
+            output_config,
+            dataset_ref,
+        }
+    }
+
+    // TODO: support multiple format specifiers
+    fn print_machine_readable(refs: &[(BlockRef, Multihash)]) -> Result<(), CLIError> {
+        use std::io::Write;
+
+        let mut out = std::io::stdout();
+        writeln!(out, "Ref,Block Hash")?;
+
+        for (block_ref, hash) in refs {
+            writeln!(out, "{block_ref},{hash}")?;
+        }
+
+        Ok(())
+    }
+
+    fn print_pretty(refs: &[(BlockRef, Multihash)]) {
+        use prettytable::*;
+
+        let mut table = Table::new();
+        table.set_format(TableWriter::<Vec<u8>>::get_table_format());
+
+        table.set_titles(row![bc->"Ref", bc->"Block Hash"]);
+
+        for (block_ref, hash) in refs {
+            table.add_row(Row::new(vec![
+                Cell::new(&block_ref.to_string()),
+                Cell::new(&hash.to_string()),
+            ]));
+        }
+
+        // Header doesn't render when there are no data rows in the table
+        if refs.is_empty() {
+            table.add_row(Row::new(vec![Cell::new(""), Cell::new("")]));
+        }
+
+        table.printstd();
+    }
+}
+
+#[async_trait::async_trait(?Send)]
+impl Command for RefListCommand {
+    async fn run(&mut self) -> Result<(), CLIError> {
+        let dataset_handle = self
+            .dataset_repo
+            .resolve_dataset_ref(&self.dataset_ref)
+            .await?;
+
+        self.dataset_action_authorizer
+            .check_action_allowed(&dataset_handle, auth::DatasetAction::Read)
+            .await
+            .map_err(|e| match e {
+                auth::DatasetActionUnauthorizedError::Access(e) => CLIError::failure(e),
+                auth::DatasetActionUnauthorizedError::Internal(e) => CLIError::critical(e),
+            })?;
+
+        let refs = self
+            .dataset_repo
+            .get_dataset_by_handle(&dataset_handle)
+            .as_metadata_chain()
+            .list_refs()
+            .await?;
+
+        // TODO: replace with formatters
+        match self.output_config.format {
+            OutputFormat::Table => Self::print_pretty(&refs),
+            OutputFormat::Csv => Self::print_machine_readable(&refs)?,
+            _ => unimplemented!("Unsupported format: {:?}", self.output_config.format),
+        }
+
+        Ok(())
+    }
+}
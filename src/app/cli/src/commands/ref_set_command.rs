@@ -0,0 +1,73 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::sync::Arc;
+
+use kamu::domain::*;
+use opendatafabric::*;
+
+use super::{CLIError, Command};
+
+pub struct RefSetCommand {
+    dataset_repo: Arc<dyn DatasetRepository>,
+    set_dataset_ref_use_case: Arc<dyn SetDatasetRefUseCase>,
+    dataset_ref: DatasetRef,
+    block_ref: BlockRef,
+    target: BlockPointer,
+}
+
+impl RefSetCommand {
+    pub fn new(
+        dataset_repo: Arc<dyn DatasetRepository>,
+        set_dataset_ref_use_case: Arc<dyn SetDatasetRefUseCase>,
+        dataset_ref: DatasetRef,
+        block_ref: BlockRef,
+        target: BlockPointer,
+    ) -> Self {
+        Self {
+            dataset_repo,
+            set_dataset_ref_use_case,
+            dataset_ref,
+            block_ref,
+            target,
+        }
+    }
+}
+
+#[async_trait::async_trait(?Send)]
+impl Command for RefSetCommand {
+    async fn run(&mut self) -> Result<(), CLIError> {
+        let dataset_handle = self
+            .dataset_repo
+            .resolve_dataset_ref(&self.dataset_ref)
+            .await?;
+
+        let block_hash = self
+            .dataset_repo
+            .get_dataset_by_handle(&dataset_handle)
+            .as_metadata_chain()
+            .resolve_pointer(&self.target)
+            .await?;
+
+        self.set_dataset_ref_use_case
+            .execute(&dataset_handle, &self.block_ref, &block_hash)
+            .await
+            .map_err(|e| match e {
+                SetDatasetRefError::Internal(e) => CLIError::critical(e),
+                _ => CLIError::failure(e),
+            })?;
+
+        eprintln!(
+            "{}",
+            console::style(format!("Set {} to {}", self.block_ref, block_hash)).green()
+        );
+
+        Ok(())
+    }
+}
@@ -18,7 +18,7 @@ pub struct ResetCommand {
     dataset_repo: Arc<dyn DatasetRepository>,
     reset_svc: Arc<dyn ResetService>,
     dataset_ref: DatasetRef,
-    block_hash: Multihash,
+    block_pointer: BlockPointer,
     no_confirmation: bool,
 }
 
@@ -27,14 +27,14 @@ impl ResetCommand {
         dataset_repo: Arc<dyn DatasetRepository>,
         reset_svc: Arc<dyn ResetService>,
         dataset_ref: DatasetRef,
-        block_hash: Multihash,
+        block_pointer: BlockPointer,
         no_confirmation: bool,
     ) -> Self {
         Self {
             dataset_repo,
             reset_svc,
             dataset_ref,
-            block_hash,
+            block_pointer,
             no_confirmation,
         }
     }
@@ -48,6 +48,13 @@ impl Command for ResetCommand {
             .resolve_dataset_ref(&self.dataset_ref)
             .await?;
 
+        let block_hash = self
+            .dataset_repo
+            .get_dataset_by_handle(&dataset_handle)
+            .as_metadata_chain()
+            .resolve_pointer(&self.block_pointer)
+            .await?;
+
         let confirmed = if self.no_confirmation {
             true
         } else {
@@ -64,7 +71,7 @@ impl Command for ResetCommand {
         }
 
         self.reset_svc
-            .reset_dataset(&dataset_handle, Some(&self.block_hash), None)
+            .reset_dataset(&dataset_handle, Some(&block_hash), None)
             .await
             .map_err(CLIError::failure)?;
 
@@ -0,0 +1,52 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::sync::Arc;
+
+use kamu::DatasetEntriesReconciler;
+
+use super::{CLIError, Command};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub struct SystemReconcileDatasetEntriesCommand {
+    maybe_reconciler: Option<Arc<DatasetEntriesReconciler>>,
+}
+
+impl SystemReconcileDatasetEntriesCommand {
+    pub fn new(maybe_reconciler: Option<Arc<DatasetEntriesReconciler>>) -> Self {
+        Self { maybe_reconciler }
+    }
+}
+
+#[async_trait::async_trait(?Send)]
+impl Command for SystemReconcileDatasetEntriesCommand {
+    async fn run(&mut self) -> Result<(), CLIError> {
+        let Some(reconciler) = &self.maybe_reconciler else {
+            return Err(CLIError::usage_error(
+                "Dataset entries are only maintained when the workspace is configured to use a \
+                 database",
+            ));
+        };
+
+        let summary = reconciler.reconcile().await.map_err(CLIError::critical)?;
+
+        eprintln!(
+            "{}",
+            console::style(format!(
+                "Dataset entries reconciled: {} added, {} renamed, {} removed, {} skipped",
+                summary.added, summary.renamed, summary.removed, summary.skipped
+            ))
+            .green()
+            .bold()
+        );
+
+        Ok(())
+    }
+}
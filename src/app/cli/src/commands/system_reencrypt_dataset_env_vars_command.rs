@@ -0,0 +1,51 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::sync::Arc;
+
+use kamu_datasets_services::DatasetEnvVarEncryptionKeyRotator;
+
+use super::{CLIError, Command};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub struct SystemReencryptDatasetEnvVarsCommand {
+    maybe_rotator: Option<Arc<DatasetEnvVarEncryptionKeyRotator>>,
+}
+
+impl SystemReencryptDatasetEnvVarsCommand {
+    pub fn new(maybe_rotator: Option<Arc<DatasetEnvVarEncryptionKeyRotator>>) -> Self {
+        Self { maybe_rotator }
+    }
+}
+
+#[async_trait::async_trait(?Send)]
+impl Command for SystemReencryptDatasetEnvVarsCommand {
+    async fn run(&mut self) -> Result<(), CLIError> {
+        let Some(rotator) = &self.maybe_rotator else {
+            return Err(CLIError::usage_error(
+                "Dataset env vars feature is disabled",
+            ));
+        };
+
+        let summary = rotator.rotate().await.map_err(CLIError::critical)?;
+
+        eprintln!(
+            "{}",
+            console::style(format!(
+                "Dataset env var secrets re-encrypted: {} re-encrypted, {} already up to date",
+                summary.reencrypted, summary.up_to_date
+            ))
+            .green()
+            .bold()
+        );
+
+        Ok(())
+    }
+}
@@ -11,7 +11,7 @@ use std::sync::Arc;
 
 use datafusion::arrow::array::{Int32Array, UInt8Array};
 use datafusion::arrow::datatypes::DataType;
-use kamu::domain::QueryService;
+use kamu::domain::{GetDataOptions, QueryError, QueryService};
 use opendatafabric::*;
 
 use super::{CLIError, Command};
@@ -22,6 +22,7 @@ pub struct TailCommand {
     dataset_ref: DatasetRef,
     skip: u64,
     limit: u64,
+    options: GetDataOptions,
     output_cfg: Arc<OutputConfig>,
 }
 
@@ -31,6 +32,7 @@ impl TailCommand {
         dataset_ref: DatasetRef,
         skip: u64,
         limit: u64,
+        options: GetDataOptions,
         output_cfg: Arc<OutputConfig>,
     ) -> Self {
         Self {
@@ -38,6 +40,7 @@ impl TailCommand {
             dataset_ref,
             skip,
             limit,
+            options,
             output_cfg,
         }
     }
@@ -48,9 +51,17 @@ impl Command for TailCommand {
     async fn run(&mut self) -> Result<(), CLIError> {
         let df = self
             .query_svc
-            .tail(&self.dataset_ref, self.skip, self.limit)
+            .tail(
+                &self.dataset_ref,
+                self.skip,
+                self.limit,
+                self.options.clone(),
+            )
             .await
-            .map_err(CLIError::failure)?;
+            .map_err(|e| match e {
+                QueryError::InvalidDataRange(e) => CLIError::usage_error_from(e),
+                e => CLIError::failure(e),
+            })?;
 
         let mut writer = self.output_cfg.get_records_writer(
             df.schema().as_arrow(),
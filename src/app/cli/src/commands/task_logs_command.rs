@@ -0,0 +1,74 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::sync::Arc;
+
+use kamu_task_system_inmem::domain::{GetTaskError, TaskID, TaskScheduler, TaskStatus};
+
+use super::{CLIError, Command};
+
+pub struct TaskLogsCommand {
+    task_sched: Arc<dyn TaskScheduler>,
+    task_id: TaskID,
+}
+
+impl TaskLogsCommand {
+    pub fn new(task_sched: Arc<dyn TaskScheduler>, task_id: i64) -> Self {
+        Self {
+            task_sched,
+            task_id: TaskID::new(task_id),
+        }
+    }
+}
+
+#[async_trait::async_trait(?Send)]
+impl Command for TaskLogsCommand {
+    async fn run(&mut self) -> Result<(), CLIError> {
+        let map_err = |e: GetTaskError| match e {
+            GetTaskError::NotFound(_) => CLIError::failure(e),
+            GetTaskError::Internal(e) => CLIError::critical(e),
+        };
+
+        let task = self
+            .task_sched
+            .get_task(self.task_id)
+            .await
+            .map_err(map_err)?;
+        let log_entries = self
+            .task_sched
+            .get_task_logs(self.task_id)
+            .await
+            .map_err(map_err)?;
+
+        let status = match &task.status {
+            TaskStatus::Queued => "Queued".to_string(),
+            TaskStatus::Running => "Running".to_string(),
+            TaskStatus::Finished(outcome) => format!("Finished: {outcome:?}"),
+        };
+
+        eprintln!("{} {status}", console::style("Status:").bold());
+        eprintln!(
+            "{} {} bytes fetched, {} records read, {} records written",
+            console::style("Progress:").bold(),
+            task.progress.fetched_bytes,
+            task.progress.records_read,
+            task.progress.records_written,
+        );
+
+        for entry in log_entries {
+            println!(
+                "{} {}",
+                console::style(entry.event_time.to_rfc3339()).dim(),
+                entry.message
+            );
+        }
+
+        Ok(())
+    }
+}
@@ -23,13 +23,6 @@ pub fn configure_database_components(
     raw_db_config: &DatabaseConfig,
     db_connection_settings: DatabaseConnectionSettings,
 ) {
-    // TODO: Remove after adding implementation of FlowEventStore for databases
-    b.add::<kamu_flow_system_inmem::InMemoryFlowEventStore>();
-
-    // TODO: Delete after preparing services for transactional work and replace with
-    //       permanent storage options
-    b.add::<kamu_task_system_inmem::InMemoryTaskSystemEventStore>();
-
     match db_connection_settings.provider {
         DatabaseProvider::Postgres => {
             PostgresPlugin::init_database_components(b);
@@ -38,14 +31,18 @@ pub fn configure_database_components(
             b.add::<kamu_accounts_postgres::PostgresAccessTokenRepository>();
 
             b.add::<kamu_datasets_postgres::PostgresDatasetEnvVarRepository>();
+            b.add::<kamu_datasets_postgres::PostgresDatasetEntryRepository>();
 
             b.add::<kamu_flow_system_postgres::PostgresFlowConfigurationEventStore>();
+            b.add::<kamu_flow_system_postgres::PostgresFlowEventStore>();
+
+            b.add::<kamu_task_system_postgres::PostgresTaskSystemEventStore>();
 
             b.add::<kamu_messaging_outbox_postgres::PostgresOutboxMessageRepository>();
             b.add::<kamu_messaging_outbox_postgres::PostgresOutboxMessageConsumptionRepository>();
+            b.add::<kamu_messaging_outbox_postgres::PostgresOutboxDeadLetterRepository>();
 
-            // TODO: Private Datasets: implement database-related version
-            b.add::<kamu_auth_rebac_inmem::InMemoryRebacRepository>();
+            b.add::<kamu_auth_rebac_postgres::PostgresRebacRepository>();
         }
         DatabaseProvider::MySql | DatabaseProvider::MariaDB => {
             MySqlPlugin::init_database_components(b);
@@ -53,17 +50,19 @@ pub fn configure_database_components(
             b.add::<kamu_accounts_mysql::MySqlAccountRepository>();
             b.add::<kamu_accounts_mysql::MySqlAccessTokenRepository>();
 
-            b.add::<kamu_datasets_inmem::InMemoryDatasetEnvVarRepository>();
+            b.add::<kamu_datasets_mysql::MySqlDatasetEnvVarRepository>();
+            b.add::<kamu_datasets_mysql::MySqlDatasetEntryRepository>();
 
-            b.add::<kamu_flow_system_inmem::InMemoryFlowConfigurationEventStore>();
+            b.add::<kamu_flow_system_mysql::MySqlFlowConfigurationEventStore>();
+            b.add::<kamu_flow_system_mysql::MySqlFlowEventStore>();
 
-            b.add::<kamu_messaging_outbox_inmem::InMemoryOutboxMessageRepository>();
-            b.add::<kamu_messaging_outbox_inmem::InMemoryOutboxMessageConsumptionRepository>();
+            b.add::<kamu_task_system_mysql::MySqlTaskSystemEventStore>();
 
-            // TODO: Private Datasets: implement database-related version
-            b.add::<kamu_auth_rebac_inmem::InMemoryRebacRepository>();
+            b.add::<kamu_messaging_outbox_mysql::MySqlOutboxMessageRepository>();
+            b.add::<kamu_messaging_outbox_mysql::MySqlOutboxMessageConsumptionRepository>();
+            b.add::<kamu_messaging_outbox_mysql::MySqlOutboxDeadLetterRepository>();
 
-            // TODO: Task & Flow System MySQL versions
+            b.add::<kamu_auth_rebac_mysql::MySqlRebacRepository>();
         }
         DatabaseProvider::Sqlite => {
             SqlitePlugin::init_database_components(b);
@@ -72,16 +71,25 @@ pub fn configure_database_components(
             b.add::<kamu_accounts_sqlite::SqliteAccessTokenRepository>();
 
             b.add::<kamu_datasets_sqlite::SqliteDatasetEnvVarRepository>();
+            b.add::<kamu_datasets_sqlite::SqliteDatasetEntryRepository>();
 
             b.add::<kamu_flow_system_sqlite::SqliteFlowSystemEventStore>();
+            b.add::<kamu_flow_system_sqlite::SqliteFlowEventStore>();
+
+            b.add::<kamu_task_system_sqlite::SqliteTaskSystemEventStore>();
 
             b.add::<kamu_messaging_outbox_sqlite::SqliteOutboxMessageRepository>();
             b.add::<kamu_messaging_outbox_sqlite::SqliteOutboxMessageConsumptionRepository>();
+            b.add::<kamu_messaging_outbox_sqlite::SqliteOutboxDeadLetterRepository>();
 
             b.add::<kamu_auth_rebac_sqlite::SqliteRebacRepository>();
         }
     }
 
+    // Dataset entries are only persisted in databases, in-memory mode keeps
+    // resolving datasets by scanning the workspace
+    b.add::<kamu_datasets_services::DatasetEntryServiceImpl>();
+
     b.add_value(db_connection_settings);
 
     init_database_password_provider(b, raw_db_config);
@@ -93,6 +101,7 @@ pub fn configure_database_components(
 pub fn configure_in_memory_components(b: &mut CatalogBuilder) {
     b.add::<kamu_messaging_outbox_inmem::InMemoryOutboxMessageRepository>();
     b.add::<kamu_messaging_outbox_inmem::InMemoryOutboxMessageConsumptionRepository>();
+    b.add::<kamu_messaging_outbox_inmem::InMemoryOutboxDeadLetterRepository>();
     b.add::<kamu_accounts_inmem::InMemoryAccountRepository>();
     b.add::<kamu_accounts_inmem::InMemoryAccessTokenRepository>();
     b.add::<kamu_flow_system_inmem::InMemoryFlowConfigurationEventStore>();
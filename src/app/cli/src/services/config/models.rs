@@ -7,12 +7,15 @@
 // the Business Source License, use of this software will be governed
 // by the Apache License, Version 2.0.
 
+use std::collections::BTreeMap;
+
 use container_runtime::{ContainerRuntimeType, NetworkNamespaceType};
 use database_common::DatabaseProvider;
 use duration_string::DurationString;
 use kamu::utils::docker_images;
 use kamu_accounts::*;
 use kamu_datasets::DatasetEnvVarsConfig;
+use kamu_task_system_inmem::domain::LogicalPlanKind;
 use merge::Merge;
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
@@ -58,6 +61,10 @@ pub struct CLIConfig {
     /// Messaging outbox configuration
     #[merge(strategy = merge_recursive)]
     pub outbox: Option<OutboxConfig>,
+
+    /// Task executor configuration
+    #[merge(strategy = merge_recursive)]
+    pub task_executor: Option<TaskExecutorConfig>,
 }
 
 impl CLIConfig {
@@ -72,6 +79,7 @@ impl CLIConfig {
             uploads: None,
             dataset_env_vars: None,
             outbox: None,
+            task_executor: None,
         }
     }
 
@@ -90,6 +98,7 @@ impl CLIConfig {
             uploads: Some(UploadsConfig::sample()),
             dataset_env_vars: Some(DatasetEnvVarsConfig::sample()),
             outbox: Some(OutboxConfig::sample()),
+            task_executor: Some(TaskExecutorConfig::sample()),
         }
     }
 }
@@ -106,6 +115,7 @@ impl Default for CLIConfig {
             uploads: Some(UploadsConfig::default()),
             dataset_env_vars: Some(DatasetEnvVarsConfig::default()),
             outbox: Some(OutboxConfig::default()),
+            task_executor: Some(TaskExecutorConfig::default()),
         }
     }
 }
@@ -129,6 +139,10 @@ pub struct EngineConfig {
     pub start_timeout: Option<DurationString>,
     /// Timeout for waiting the engine container to stop gracefully
     pub shutdown_timeout: Option<DurationString>,
+    /// Run transformations that use `datafusion` engine within the kamu
+    /// process instead of an engine container. Results of such transformations
+    /// are not guaranteed to be reproducible by the engine image
+    pub datafusion_in_process: Option<bool>,
     /// UNSTABLE: Default engine images
     #[merge(strategy = merge_recursive)]
     pub images: Option<EngineImagesConfig>,
@@ -142,6 +156,7 @@ impl EngineConfig {
             network_ns: None,
             start_timeout: None,
             shutdown_timeout: None,
+            datafusion_in_process: None,
             images: None,
         }
     }
@@ -163,6 +178,7 @@ impl Default for EngineConfig {
             network_ns: Some(NetworkNamespaceType::Private),
             start_timeout: Some(DurationString::from_string("30s".to_owned()).unwrap()),
             shutdown_timeout: Some(DurationString::from_string("5s".to_owned()).unwrap()),
+            datafusion_in_process: Some(false),
             images: Some(EngineImagesConfig::default()),
         }
     }
@@ -372,6 +388,10 @@ pub struct EthereumSourceConfig {
     /// scanned even if we didn't reach the target record number. This is useful
     /// to not lose a lot of scanning progress in case of an RPC error.
     pub commit_after_blocks_scanned: Option<u64>,
+    /// Number of the most recently scanned blocks that are not considered
+    /// final. Hashes of these blocks are kept in the source state and
+    /// re-validated on every fetch to detect chain re-organizations.
+    pub confirmation_depth: Option<u64>,
 }
 
 impl EthereumSourceConfig {
@@ -380,6 +400,7 @@ impl EthereumSourceConfig {
             rpc_endpoints: Vec::new(),
             get_logs_block_stride: None,
             commit_after_blocks_scanned: None,
+            confirmation_depth: None,
         }
     }
 
@@ -396,6 +417,7 @@ impl EthereumSourceConfig {
                 .collect(),
             get_logs_block_stride: self.get_logs_block_stride.unwrap(),
             commit_after_blocks_scanned: self.commit_after_blocks_scanned.unwrap(),
+            confirmation_depth: self.confirmation_depth.unwrap(),
         }
     }
 }
@@ -407,6 +429,7 @@ impl Default for EthereumSourceConfig {
             rpc_endpoints: Vec::new(),
             get_logs_block_stride: Some(infra_cfg.get_logs_block_stride),
             commit_after_blocks_scanned: Some(infra_cfg.commit_after_blocks_scanned),
+            confirmation_depth: Some(infra_cfg.confirmation_depth),
         }
     }
 }
@@ -706,6 +729,14 @@ impl Default for UploadsConfig {
 pub struct OutboxConfig {
     pub awaiting_step_secs: Option<i64>,
     pub batch_size: Option<i64>,
+    /// How many times a consumer is invoked with the same message before the
+    /// message is moved to dead letters
+    pub max_attempts: Option<u32>,
+    /// Delay before the first retry of a failed message, doubled with every
+    /// next attempt
+    pub retry_backoff_base_secs: Option<i64>,
+    /// Upper bound for the delay between retries
+    pub retry_backoff_max_secs: Option<i64>,
 }
 
 impl OutboxConfig {
@@ -719,6 +750,50 @@ impl Default for OutboxConfig {
         Self {
             awaiting_step_secs: Some(1),
             batch_size: Some(20),
+            max_attempts: Some(5),
+            retry_backoff_base_secs: Some(1),
+            retry_backoff_max_secs: Some(300),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[skip_serializing_none]
+#[derive(Debug, Clone, Merge, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+#[serde(rename_all = "camelCase")]
+pub struct TaskExecutorConfig {
+    /// Number of tasks that can be executed concurrently. Tasks modifying the
+    /// same dataset are never executed at the same time
+    pub max_concurrent_tasks: Option<usize>,
+    /// Optional limits of concurrently executed tasks of a certain kind, e.g.
+    /// `HardCompactionDataset: 1`
+    pub max_concurrent_tasks_by_kind: Option<BTreeMap<LogicalPlanKind, usize>>,
+    /// Maximum duration of a single task execution (e.g. `2h`). Tasks running
+    /// longer are interrupted and marked as failed
+    pub task_timeout: Option<DurationString>,
+}
+
+impl TaskExecutorConfig {
+    pub fn sample() -> Self {
+        Self {
+            max_concurrent_tasks_by_kind: Some(BTreeMap::from([(
+                LogicalPlanKind::HardCompactionDataset,
+                1,
+            )])),
+            task_timeout: Some(DurationString::from_string("6h".to_owned()).unwrap()),
+            ..Default::default()
+        }
+    }
+}
+
+impl Default for TaskExecutorConfig {
+    fn default() -> Self {
+        Self {
+            max_concurrent_tasks: Some(4),
+            max_concurrent_tasks_by_kind: None,
+            task_timeout: None,
         }
     }
 }
@@ -95,7 +95,9 @@ impl MessageConsumerT<DatasetLifecycleMessage> for MultiTenantRebacDatasetLifecy
                 self.handle_dataset_lifecycle_deleted_message(message).await
             }
 
-            DatasetLifecycleMessage::DependenciesUpdated(_) => {
+            DatasetLifecycleMessage::DependenciesUpdated(_)
+            | DatasetLifecycleMessage::PollingSourceDisabled(_)
+            | DatasetLifecycleMessage::Renamed(_) => {
                 // No action required
                 Ok(())
             }
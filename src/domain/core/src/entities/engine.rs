@@ -17,7 +17,7 @@ use internal_error::*;
 use opendatafabric::*;
 use thiserror::Error;
 
-use crate::{BlockRef, OwnedFile};
+use crate::{BlockRef, CancellationToken, OperationCancelledError, OwnedFile};
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 // Engine
@@ -25,14 +25,18 @@ use crate::{BlockRef, OwnedFile};
 
 #[async_trait::async_trait]
 pub trait Engine: Send + Sync {
+    /// Cancellation terminates the running engine
     async fn execute_raw_query(
         &self,
         request: RawQueryRequestExt,
+        cancellation_token: &CancellationToken,
     ) -> Result<RawQueryResponseExt, EngineError>;
 
+    /// Cancellation terminates the running engine
     async fn execute_transform(
         &self,
         request: TransformRequestExt,
+        cancellation_token: &CancellationToken,
     ) -> Result<TransformResponseExt, EngineError>;
 }
 
@@ -55,6 +59,8 @@ pub struct RawQueryRequestExt {
 #[derive(Debug, Clone)]
 pub struct RawQueryResponseExt {
     pub output_data: Option<DataFrame>,
+    /// Log files written by the engine while performing the query
+    pub log_files: Vec<PathBuf>,
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -145,6 +151,8 @@ pub struct TransformResponseExt {
     /// Data produced by the operation, if any. Must be `None` if offset
     /// interval is empty.
     pub new_data: Option<OwnedFile>,
+    /// Log files written by the engine while performing the transaction
+    pub log_files: Vec<PathBuf>,
 }
 
 impl From<TransformRequestInputExt> for ExecuteTransformInput {
@@ -173,6 +181,8 @@ pub enum EngineError {
     ContractError(#[from] ContractError),
     #[error(transparent)]
     InternalError(#[from] InternalEngineError),
+    #[error(transparent)]
+    Cancelled(#[from] OperationCancelledError),
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
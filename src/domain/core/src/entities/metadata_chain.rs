@@ -106,6 +106,170 @@ pub trait MetadataChainExt: MetadataChain {
         self.get_block(&h).await.int_err()
     }
 
+    /// Resolves a block pointer (a hash or a named reference) into the block
+    /// hash
+    async fn resolve_pointer(&self, p: &BlockPointer) -> Result<Multihash, GetRefError> {
+        match p {
+            BlockPointer::Hash(hash) => Ok(hash.clone()),
+            BlockPointer::Ref(r) => self.resolve_ref(r).await,
+        }
+    }
+
+    /// Lists all existing references together with the block hashes they
+    /// point to
+    async fn list_refs(&self) -> Result<Vec<(BlockRef, Multihash)>, InternalError> {
+        let refs = self.as_reference_repo().list().await.int_err()?;
+
+        let mut res = Vec::with_capacity(refs.len());
+        for r in refs {
+            // Tolerate references deleted concurrently
+            if let Some(hash) = self.try_get_ref(&r).await? {
+                res.push((r, hash));
+            }
+        }
+
+        Ok(res)
+    }
+
+    /// Checks that user-defined references received from another copy of the
+    /// dataset can be imported: tags are immutable and branches can only be
+    /// fast-forwarded. Branches pointing to blocks that this chain does not
+    /// contain yet are not validated.
+    async fn check_refs_import(
+        &self,
+        refs: &[(BlockRef, Multihash)],
+    ) -> Result<(), ImportRefsError> {
+        for (r, hash) in refs {
+            self.check_ref_import(r, hash).await?;
+        }
+        Ok(())
+    }
+
+    /// Checks a single incoming reference value, returning the current value
+    /// of the reference if it needs to be updated
+    async fn check_ref_import(
+        &self,
+        r: &BlockRef,
+        hash: &Multihash,
+    ) -> Result<Option<Option<Multihash>>, ImportRefsError> {
+        if r.is_head() {
+            return Ok(None);
+        }
+
+        let Some(current) = self.try_get_ref(r).await? else {
+            return Ok(Some(None));
+        };
+
+        if current == *hash {
+            return Ok(None);
+        }
+
+        let is_fast_forward = match r {
+            BlockRef::Head | BlockRef::Tag(_) => false,
+            BlockRef::Branch(_) => {
+                if !self.contains_block(hash).await.int_err()? {
+                    return Ok(Some(Some(current)));
+                }
+                self.is_ancestor_block(&current, hash).await?
+            }
+        };
+
+        if is_fast_forward {
+            Ok(Some(Some(current)))
+        } else {
+            Err(RefConflictError {
+                block_ref: r.clone(),
+                src_hash: hash.clone(),
+                dst_hash: current,
+            }
+            .into())
+        }
+    }
+
+    /// Imports user-defined references (tags and branches) received from
+    /// another copy of the dataset. New references are created and branches
+    /// are fast-forwarded, while references that exist only in this chain are
+    /// kept unless [`ImportRefsOpts::delete_missing`] is set. Moving an
+    /// existing tag or rewinding a branch is rejected before any reference is
+    /// modified. The `head` reference is left untouched and references to
+    /// blocks that this chain does not contain are skipped.
+    async fn import_refs(
+        &self,
+        refs: &[(BlockRef, Multihash)],
+        opts: ImportRefsOpts,
+    ) -> Result<(), ImportRefsError> {
+        let mut updates = Vec::new();
+        for (r, hash) in refs {
+            if let Some(current) = self.check_ref_import(r, hash).await? {
+                updates.push((r, hash, current));
+            }
+        }
+
+        let missing_refs: Vec<_> = if opts.delete_missing {
+            self.as_reference_repo()
+                .list()
+                .await
+                .int_err()?
+                .into_iter()
+                .filter(|r| !r.is_head() && !refs.iter().any(|(i, _)| i == r))
+                .collect()
+        } else {
+            Vec::new()
+        };
+
+        for (r, hash, current) in updates {
+            match self
+                .set_ref(
+                    r,
+                    hash,
+                    SetRefOpts {
+                        validate_block_present: true,
+                        check_ref_is: Some(current.as_ref()),
+                    },
+                )
+                .await
+            {
+                Ok(()) => {}
+                Err(SetRefError::BlockNotFound(_)) => {
+                    tracing::debug!(block_ref = %r, %hash, "Skipping reference to a missing block");
+                }
+                Err(e) => return Err(e.int_err().into()),
+            }
+        }
+
+        for r in missing_refs {
+            tracing::debug!(block_ref = %r, "Deleting reference missing in the source");
+            self.as_reference_repo().delete(&r).await.int_err()?;
+        }
+
+        Ok(())
+    }
+
+    /// Returns true if the `ancestor` block is reachable from the `descendant`
+    /// block by following the previous block links
+    async fn is_ancestor_block(
+        &self,
+        ancestor: &Multihash,
+        descendant: &Multihash,
+    ) -> Result<bool, InternalError> {
+        let ancestor_block = self.get_block(ancestor).await.int_err()?;
+
+        let mut current_hash = Some(descendant.clone());
+        while let Some(hash) = current_hash {
+            if hash == *ancestor {
+                return Ok(true);
+            }
+
+            let block = self.get_block(&hash).await.int_err()?;
+            if block.sequence_number <= ancestor_block.sequence_number {
+                return Ok(false);
+            }
+            current_hash = block.prev_block_hash;
+        }
+
+        Ok(false)
+    }
+
     /// Returns the specified block if it exists
     async fn try_get_block(
         &self,
@@ -394,15 +558,59 @@ impl<T> MetadataChainExt for T where T: MetadataChain + ?Sized {}
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
 /// References are named pointers to metadata blocks
-#[derive(Clone, PartialEq, Eq, Debug)]
+#[derive(Clone, PartialEq, Eq, Ord, PartialOrd, Debug)]
 pub enum BlockRef {
     Head,
+    /// User-defined reference that pins a specific block, e.g. a release
+    Tag(String),
+    /// User-defined reference that can be moved between blocks, e.g. to keep
+    /// an experimental line of history
+    Branch(String),
 }
 
 impl BlockRef {
-    pub fn as_str(&self) -> &str {
+    const TAG_PREFIX: &'static str = "tag.";
+    const BRANCH_PREFIX: &'static str = "branch.";
+
+    pub fn tag(name: impl Into<String>) -> Result<Self, InvalidBlockRefNameError> {
+        let name = name.into();
+        Self::validate_name(&name)?;
+        Ok(Self::Tag(name))
+    }
+
+    pub fn branch(name: impl Into<String>) -> Result<Self, InvalidBlockRefNameError> {
+        let name = name.into();
+        Self::validate_name(&name)?;
+        Ok(Self::Branch(name))
+    }
+
+    pub fn is_head(&self) -> bool {
+        matches!(self, BlockRef::Head)
+    }
+
+    /// Returns the short name of the reference without the kind prefix
+    pub fn name(&self) -> &str {
         match self {
             BlockRef::Head => "head",
+            BlockRef::Tag(name) | BlockRef::Branch(name) => name,
+        }
+    }
+
+    /// User-defined reference names can only contain alphanumerics, dashes,
+    /// underscores and dots
+    fn validate_name(name: &str) -> Result<(), InvalidBlockRefNameError> {
+        let is_valid = !name.is_empty()
+            && !name.starts_with('.')
+            && name
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_' || c == '.');
+
+        if is_valid {
+            Ok(())
+        } else {
+            Err(InvalidBlockRefNameError {
+                name: name.to_string(),
+            })
         }
     }
 }
@@ -411,22 +619,69 @@ impl std::str::FromStr for BlockRef {
     type Err = InternalError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match s {
-            "head" => Ok(Self::Head),
-            _ => Err(format!("Invalid block reference: {s}").int_err()),
+        if s == "head" {
+            Ok(Self::Head)
+        } else if let Some(name) = s.strip_prefix(Self::TAG_PREFIX) {
+            Self::tag(name).int_err()
+        } else if let Some(name) = s.strip_prefix(Self::BRANCH_PREFIX) {
+            Self::branch(name).int_err()
+        } else {
+            Err(format!("Invalid block reference: {s}").int_err())
         }
     }
 }
 
-impl AsRef<str> for BlockRef {
-    fn as_ref(&self) -> &str {
-        self.as_str()
+impl std::fmt::Display for BlockRef {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BlockRef::Head => write!(f, "head"),
+            BlockRef::Tag(name) => write!(f, "{}{name}", Self::TAG_PREFIX),
+            BlockRef::Branch(name) => write!(f, "{}{name}", Self::BRANCH_PREFIX),
+        }
     }
 }
 
-impl std::fmt::Display for BlockRef {
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Points to a metadata block either directly by its hash or indirectly via a
+/// named reference
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum BlockPointer {
+    Hash(Multihash),
+    Ref(BlockRef),
+}
+
+impl std::str::FromStr for BlockPointer {
+    type Err = InternalError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // Multibase prefixes never clash with reference names
+        if let Ok(hash) = Multihash::from_multibase(s) {
+            Ok(Self::Hash(hash))
+        } else {
+            Ok(Self::Ref(BlockRef::from_str(s)?))
+        }
+    }
+}
+
+impl std::fmt::Display for BlockPointer {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.as_str())
+        match self {
+            BlockPointer::Hash(hash) => write!(f, "{hash}"),
+            BlockPointer::Ref(r) => write!(f, "{r}"),
+        }
+    }
+}
+
+impl From<Multihash> for BlockPointer {
+    fn from(value: Multihash) -> Self {
+        Self::Hash(value)
+    }
+}
+
+impl From<BlockRef> for BlockPointer {
+    fn from(value: BlockRef) -> Self {
+        Self::Ref(value)
     }
 }
 
@@ -453,6 +708,14 @@ impl Default for SetRefOpts<'_> {
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
+#[derive(Clone, Debug, Default)]
+pub struct ImportRefsOpts {
+    /// Delete user-defined references that are missing in the imported set
+    pub delete_missing: bool,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
 // TODO: Expand into bitflags to give fine control
 #[repr(u32)]
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
@@ -610,6 +873,20 @@ impl From<SetRefErrorRepo> for SetRefError {
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
+#[derive(Error, Debug)]
+pub enum ImportRefsError {
+    #[error(transparent)]
+    Conflict(#[from] RefConflictError),
+    #[error(transparent)]
+    Internal(
+        #[from]
+        #[backtrace]
+        InternalError,
+    ),
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
 #[derive(Error, Debug)]
 pub enum AppendError {
     #[error(transparent)]
@@ -701,6 +978,44 @@ pub struct RefCASError {
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
+#[derive(Error, Clone, PartialEq, Eq, Debug)]
+pub struct RefConflictError {
+    pub block_ref: BlockRef,
+    pub src_hash: Multihash,
+    pub dst_hash: Multihash,
+}
+
+impl Display for RefConflictError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.block_ref {
+            BlockRef::Tag(_) => write!(
+                f,
+                "Tag {} points at {} in the source and at {} in the destination, tags are \
+                 immutable",
+                self.block_ref, self.src_hash, self.dst_hash,
+            ),
+            _ => write!(
+                f,
+                "Reference {} cannot be fast-forwarded from {} to {} as the histories have \
+                 diverged",
+                self.block_ref, self.dst_hash, self.src_hash,
+            ),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Error, PartialEq, Eq, Debug)]
+#[error(
+    "Invalid reference name '{name}': only alphanumerics, dashes, underscores and dots are allowed"
+)]
+pub struct InvalidBlockRefNameError {
+    pub name: String,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
 #[derive(Error, Debug)]
 pub enum AppendValidationError {
     #[error(transparent)]
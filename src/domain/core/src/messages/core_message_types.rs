@@ -8,7 +8,7 @@
 // by the Apache License, Version 2.0.
 
 use messaging_outbox::Message;
-use opendatafabric::{AccountID, DatasetID};
+use opendatafabric::{AccountID, DatasetID, DatasetName};
 use serde::{Deserialize, Serialize};
 
 use crate::DatasetVisibility;
@@ -20,6 +20,8 @@ pub enum DatasetLifecycleMessage {
     Created(DatasetLifecycleMessageCreated),
     DependenciesUpdated(DatasetLifecycleMessageDependenciesUpdated),
     Deleted(DatasetLifecycleMessageDeleted),
+    PollingSourceDisabled(DatasetLifecycleMessagePollingSourceDisabled),
+    Renamed(DatasetLifecycleMessageRenamed),
 }
 
 impl DatasetLifecycleMessage {
@@ -27,11 +29,13 @@ impl DatasetLifecycleMessage {
         dataset_id: DatasetID,
         owner_account_id: AccountID,
         dataset_visibility: DatasetVisibility,
+        dataset_name: DatasetName,
     ) -> Self {
         Self::Created(DatasetLifecycleMessageCreated {
             dataset_id,
             owner_account_id,
             dataset_visibility,
+            dataset_name: Some(dataset_name),
         })
     }
 
@@ -45,6 +49,17 @@ impl DatasetLifecycleMessage {
     pub fn deleted(dataset_id: DatasetID) -> Self {
         Self::Deleted(DatasetLifecycleMessageDeleted { dataset_id })
     }
+
+    pub fn polling_source_disabled(dataset_id: DatasetID) -> Self {
+        Self::PollingSourceDisabled(DatasetLifecycleMessagePollingSourceDisabled { dataset_id })
+    }
+
+    pub fn renamed(dataset_id: DatasetID, new_dataset_name: DatasetName) -> Self {
+        Self::Renamed(DatasetLifecycleMessageRenamed {
+            dataset_id,
+            new_dataset_name,
+        })
+    }
 }
 
 impl Message for DatasetLifecycleMessage {}
@@ -57,6 +72,9 @@ pub struct DatasetLifecycleMessageCreated {
     pub owner_account_id: AccountID,
     #[serde(default)]
     pub dataset_visibility: DatasetVisibility,
+    /// Absent in messages produced before the name was included
+    #[serde(default)]
+    pub dataset_name: Option<DatasetName>,
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -75,3 +93,18 @@ pub struct DatasetLifecycleMessageDeleted {
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DatasetLifecycleMessagePollingSourceDisabled {
+    pub dataset_id: DatasetID,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DatasetLifecycleMessageRenamed {
+    pub dataset_id: DatasetID,
+    pub new_dataset_name: DatasetName,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
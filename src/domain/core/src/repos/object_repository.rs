@@ -92,6 +92,12 @@ pub trait ObjectRepository: Send + Sync {
     ) -> Result<InsertResult, InsertError>;
 
     async fn delete(&self, hash: &Multihash) -> Result<(), DeleteError>;
+
+    /// Lists all objects currently stored in the repository.
+    ///
+    /// Used primarily by the garbage collection to discover objects that are no
+    /// longer referenced by the metadata.
+    async fn list(&self) -> Result<Vec<ObjectInfo>, ListObjectsError>;
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -139,6 +145,16 @@ pub struct ExternalTransferOpts {
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ObjectInfo {
+    pub hash: Multihash,
+    pub size: u64,
+    /// Time when object was last modified, if supported by the storage
+    pub last_modified: Option<DateTime<Utc>>,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
 #[derive(Debug, Clone)]
 pub struct GetExternalUrlResult {
     pub url: Url,
@@ -270,6 +286,24 @@ pub enum DeleteError {
     ),
 }
 
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Error, Debug)]
+pub enum ListObjectsError {
+    #[error(transparent)]
+    Access(
+        #[from]
+        #[backtrace]
+        AccessError,
+    ),
+    #[error(transparent)]
+    Internal(
+        #[from]
+        #[backtrace]
+        InternalError,
+    ),
+}
+
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 // Individual Errors
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -18,7 +18,7 @@ use crate::entities::BlockRef;
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
 #[async_trait]
-pub trait ReferenceRepository {
+pub trait ReferenceRepository: Send + Sync {
     /// Resolves reference to the object hash it's pointing to
     async fn get(&self, r: &BlockRef) -> Result<Multihash, GetRefError>;
 
@@ -27,6 +27,9 @@ pub trait ReferenceRepository {
 
     /// Deletes specified reference
     async fn delete(&self, r: &BlockRef) -> Result<(), DeleteRefError>;
+
+    /// Lists all references that currently exist
+    async fn list(&self) -> Result<Vec<BlockRef>, ListRefsError>;
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -89,6 +92,24 @@ pub enum DeleteRefError {
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
+#[derive(Error, Debug)]
+pub enum ListRefsError {
+    #[error(transparent)]
+    Access(
+        #[from]
+        #[backtrace]
+        AccessError,
+    ),
+    #[error(transparent)]
+    Internal(
+        #[from]
+        #[backtrace]
+        InternalError,
+    ),
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
 #[derive(Error, Debug)]
 #[error("Reference does not exist: {block_ref:?}")]
 pub struct RefNotFoundError {
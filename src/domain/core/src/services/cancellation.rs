@@ -0,0 +1,38 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use thiserror::Error;
+// Re-exports
+pub use tokio_util::sync::CancellationToken;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub trait CancellationTokenExt {
+    /// Fails if the cancellation was requested. Long-running operations call
+    /// it at safe points only, i.e. where stopping leaves no partial changes
+    fn check_cancelled(&self) -> Result<(), OperationCancelledError>;
+}
+
+impl CancellationTokenExt for CancellationToken {
+    fn check_cancelled(&self) -> Result<(), OperationCancelledError> {
+        if self.is_cancelled() {
+            Err(OperationCancelledError {})
+        } else {
+            Ok(())
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Error, Debug)]
+#[error("Operation was cancelled")]
+pub struct OperationCancelledError {}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
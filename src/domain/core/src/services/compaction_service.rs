@@ -66,6 +66,12 @@ pub enum CompactionError {
         #[backtrace]
         InvalidDatasetKindError,
     ),
+    #[error(transparent)]
+    Cancelled(
+        #[from]
+        #[backtrace]
+        OperationCancelledError,
+    ),
 }
 
 impl From<GetDatasetError> for CompactionError {
@@ -175,11 +181,14 @@ pub struct CompactionResponse {
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
 pub struct CompactionOptions {
     pub max_slice_size: Option<u64>,
     pub max_slice_records: Option<u64>,
     pub keep_metadata_only: bool,
+    /// Stops the compaction before merging the next slice or committing the
+    /// new chain
+    pub cancellation_token: CancellationToken,
 }
 
 impl Default for CompactionOptions {
@@ -188,6 +197,7 @@ impl Default for CompactionOptions {
             max_slice_size: Some(DEFAULT_MAX_SLICE_SIZE),
             max_slice_records: Some(DEFAULT_MAX_SLICE_RECORDS),
             keep_metadata_only: false,
+            cancellation_token: CancellationToken::new(),
         }
     }
 }
@@ -85,6 +85,12 @@ impl MediaType {
     /// See: <https://www.iana.org/assignments/media-types/application/vnd.shp>
     /// See: <https://en.wikipedia.org/wiki/Shapefile>
     pub const ESRI_SHAPEFILE: MediaTypeRef<'static> = MediaTypeRef("application/vnd.shp");
+    /// No standard found
+    pub const AVRO: MediaTypeRef<'static> = MediaTypeRef("application/avro");
+    /// Unofficial but used by several software projects
+    pub const ORC: MediaTypeRef<'static> = MediaTypeRef("application/vnd.apache.orc");
+    pub const XLSX: MediaTypeRef<'static> =
+        MediaTypeRef("application/vnd.openxmlformats-officedocument.spreadsheetml.sheet");
 }
 
 impl<'a> MediaTypeRef<'a> {
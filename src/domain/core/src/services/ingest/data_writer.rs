@@ -74,6 +74,9 @@ pub struct WriteDataOpts {
     pub new_watermark: Option<DateTime<Utc>>,
     /// Data source state to store in the commit
     pub new_source_state: Option<odf::SourceState>,
+    /// SQL WHERE clause selecting the previously written records that are no
+    /// longer valid according to the source. Such records will be retracted.
+    pub retraction_filter: Option<String>,
     // TODO: Find a better way to deal with temporary files
     /// Local FS path to which data slice will be written before committing it
     /// into the data object store of a dataset
@@ -31,10 +31,12 @@ use internal_error::InternalError;
 ///     this column
 /// - Sort order after [`MergeStrategy::merge`] is arbitrary and must be
 ///   restored using [`MergeStrategy::sort_order`] at the end of processing
+#[async_trait::async_trait]
 pub trait MergeStrategy: Send + Sync {
     /// Reduces newly seen data `new` to a minimal update to previously
     /// ledgerized `prev` data.
-    fn merge(&self, prev: Option<DataFrame>, new: DataFrame) -> Result<DataFrame, MergeError>;
+    async fn merge(&self, prev: Option<DataFrame>, new: DataFrame)
+        -> Result<DataFrame, MergeError>;
 
     /// Returns the sort expression best suited for the output of this strategy
     /// to perform before writing the final result.
@@ -45,6 +47,18 @@ pub trait MergeStrategy: Send + Sync {
 
 #[derive(thiserror::Error, Debug)]
 pub enum MergeError {
+    #[error(transparent)]
+    InputNotSuperset(#[from] InputNotSupersetError),
+
     #[error(transparent)]
     Internal(#[from] InternalError),
 }
+
+#[derive(thiserror::Error, Debug)]
+#[error(
+    "Input was expected to contain all previously seen events, but {num_missing} of them are \
+     missing"
+)]
+pub struct InputNotSupersetError {
+    pub num_missing: usize,
+}
@@ -66,6 +66,9 @@ pub struct PollingIngestOptions {
     pub dataset_env_vars: HashMap<String, DatasetEnvVar>,
     /// Schema inference configuration
     pub schema_inference: SchemaInferenceOpts,
+    /// Stops the ingest before fetching the next portion of data or
+    /// committing it
+    pub cancellation_token: CancellationToken,
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -130,6 +133,11 @@ pub trait PollingIngestListener: Send + Sync {
     fn begin(&self) {}
     fn on_cache_hit(&self, created_at: &DateTime<Utc>) {}
     fn on_stage_progress(&self, stage: PollingIngestStage, _progress: u64, _out_of: TotalSteps) {}
+    /// Called with logs of the fetch containers and engines that completed
+    /// successfully. Logs of failed ones are carried by the error
+    fn on_log_files(&self, _log_files: &[PathBuf]) {}
+    /// Called once the records of an iteration are committed
+    fn on_records_written(&self, _num_records: u64) {}
     fn success(&self, result: &PollingIngestResult) {}
     fn error(&self, error: &PollingIngestError) {}
 
@@ -193,6 +201,24 @@ impl InvalidIngestParameterFormat {
     }
 }
 
+#[derive(Error, Debug, Default)]
+#[error("Polling source of the dataset is disabled")]
+pub struct PollingSourceDisabledError {}
+
+#[derive(Error, Debug)]
+#[error("Unsupported source URL scheme '{scheme}'")]
+pub struct UnsupportedSourceSchemeError {
+    pub scheme: String,
+}
+
+impl UnsupportedSourceSchemeError {
+    pub fn new(scheme: impl Into<String>) -> Self {
+        Self {
+            scheme: scheme.into(),
+        }
+    }
+}
+
 // TODO: Revisit error granularity
 #[derive(Debug, Error)]
 pub enum PollingIngestError {
@@ -217,6 +243,20 @@ pub enum PollingIngestError {
         source: Option<BoxedError>,
     },
 
+    #[error(transparent)]
+    SourceDisabled(
+        #[from]
+        #[backtrace]
+        PollingSourceDisabledError,
+    ),
+
+    #[error(transparent)]
+    UnsupportedScheme(
+        #[from]
+        #[backtrace]
+        UnsupportedSourceSchemeError,
+    ),
+
     #[error(transparent)]
     ImagePull(
         #[from]
@@ -294,6 +334,13 @@ pub enum PollingIngestError {
         CommitError,
     ),
 
+    #[error(transparent)]
+    Cancelled(
+        #[from]
+        #[backtrace]
+        OperationCancelledError,
+    ),
+
     #[error(transparent)]
     Access(
         #[from]
@@ -384,6 +431,10 @@ impl PipeError {
             backtrace: Backtrace::capture(),
         }
     }
+
+    pub fn log_files(&self) -> &[PathBuf] {
+        &self.log_files
+    }
 }
 
 impl std::fmt::Display for PipeError {
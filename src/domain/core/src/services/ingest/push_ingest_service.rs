@@ -136,6 +136,13 @@ pub enum PushIngestError {
         PushSourceNotFoundError,
     ),
 
+    #[error(transparent)]
+    SourceDisabled(
+        #[from]
+        #[backtrace]
+        PushSourceDisabledError,
+    ),
+
     #[error(transparent)]
     UnsupportedMediaType(
         #[from]
@@ -246,6 +253,20 @@ impl std::fmt::Display for PushSourceNotFoundError {
     }
 }
 
+#[derive(Debug, Error)]
+#[error("Push source '{source_name}' of the dataset is disabled")]
+pub struct PushSourceDisabledError {
+    pub source_name: String,
+}
+
+impl PushSourceDisabledError {
+    pub fn new(source_name: impl Into<String>) -> Self {
+        Self {
+            source_name: source_name.into(),
+        }
+    }
+}
+
 #[derive(Debug, Error)]
 #[error("Unsupported media type {media_type}")]
 pub struct UnsupportedMediaTypeError {
@@ -10,6 +10,7 @@
 // Re-exports
 pub use container_runtime::{NullPullImageListener, PullImageListener};
 
+pub mod cancellation;
 pub mod compaction_service;
 pub mod dataset_changes_service;
 pub mod dataset_ownership_service;
@@ -28,10 +29,12 @@ pub mod reset_service;
 pub mod resource_loader;
 pub mod search_service;
 pub mod server_url_config;
+pub mod storage_gc_service;
 pub mod sync_service;
 pub mod transform_service;
 pub mod verification_service;
 
+pub use cancellation::*;
 pub use compaction_service::*;
 pub use dataset_changes_service::*;
 pub use dataset_ownership_service::*;
@@ -50,6 +53,7 @@ pub use reset_service::*;
 pub use resource_loader::*;
 pub use search_service::*;
 pub use server_url_config::*;
+pub use storage_gc_service::*;
 pub use sync_service::*;
 pub use transform_service::*;
 pub use verification_service::*;
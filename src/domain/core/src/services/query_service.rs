@@ -9,6 +9,7 @@
 
 use std::collections::BTreeMap;
 
+use chrono::{DateTime, Utc};
 use datafusion::arrow;
 use datafusion::parquet::schema::types::Type;
 use datafusion::prelude::{DataFrame, SessionContext};
@@ -39,11 +40,15 @@ pub trait QueryService: Send + Sync {
     /// )
     /// order by offset
     /// ```
+    ///
+    /// Records can be additionally narrowed down by the ranges in `options`
+    /// before the skip and limit are applied.
     async fn tail(
         &self,
         dataset_ref: &DatasetRef,
         skip: u64,
         limit: u64,
+        options: GetDataOptions,
     ) -> Result<DataFrame, QueryError>;
 
     /// Prepares an execution plan for the SQL statement and returns a
@@ -69,11 +74,14 @@ pub trait QueryService: Send + Sync {
         dataset_ref: &DatasetRef,
     ) -> Result<Option<Type>, QueryError>;
 
-    // TODO: Introduce additional options that could be used to narrow down the
-    // number of files we collect to construct the dataframe.
-    //
-    /// Returns a [DataFrame] representing the contents of an entire dataset
-    async fn get_data(&self, dataset_ref: &DatasetRef) -> Result<DataFrame, QueryError>;
+    /// Returns a [DataFrame] representing the contents of a dataset, narrowed
+    /// down to the ranges specified in the options. Data files that can't
+    /// contain records within the ranges are not considered at all.
+    async fn get_data(
+        &self,
+        dataset_ref: &DatasetRef,
+        options: GetDataOptions,
+    ) -> Result<DataFrame, QueryError>;
 
     /// Lists engines known to the system and recommended for use
     async fn get_known_engines(&self) -> Result<Vec<EngineDesc>, InternalError>;
@@ -95,6 +103,12 @@ pub struct QueryOptions {
     /// updates happen in the datasets - the query will only consider a specific
     /// subset of the data ledger.
     pub as_of_state: Option<QueryState>,
+    /// Pins datasets to blocks pointed to by named references (e.g. tags)
+    /// instead of their current head. References are resolved into block
+    /// hashes before the query executes and the resolved hashes are reported
+    /// back in the resulting [`QueryState`]. Entries of `as_of_state` take
+    /// precedence over these.
+    pub as_of_refs: Option<BTreeMap<DatasetID, BlockRef>>,
     /// Hints that can help the system to minimize metadata scanning. Be extra
     /// careful that your hints don't influence the actual result of the
     /// query, as they are not inlcuded in the [`QueryState`] and thus can
@@ -110,6 +124,69 @@ pub struct DatasetQueryHints {
     /// file contains 150 records - only this file will be considered for the
     /// query and the rest of data will be completely ignored.
     pub last_records_to_consider: Option<u64>,
+    /// Data files that can't contain records with offsets within this range
+    /// will not be considered. Offset filter must still be applied to the
+    /// query, as the files at the range boundaries are included fully.
+    pub offset_range: Option<OffsetRange>,
+    /// Data files that only contain records with event time at or past the end
+    /// of this range (judging by the watermarks preceding them) will not be
+    /// considered. Event time filter must still be applied to the query.
+    pub event_time_range: Option<EventTimeRange>,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Ranges that narrow down the part of a dataset being read. When multiple
+/// ranges are specified - only records satisfying all of them are returned.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct GetDataOptions {
+    /// Only return records with offsets within this range
+    pub offset_range: Option<OffsetRange>,
+    /// Only return records with event time within this range. Data files are
+    /// pruned using watermarks, so late records that were added after the
+    /// watermark has passed the end of the range are not returned
+    pub event_time_range: Option<EventTimeRange>,
+    /// Only return records added by the metadata blocks within this range
+    pub block_range: Option<BlockRange>,
+}
+
+/// Range of record offsets, both bounds are inclusive
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct OffsetRange {
+    pub start: Option<u64>,
+    pub end: Option<u64>,
+}
+
+impl OffsetRange {
+    /// Narrows down this range to records that also belong to the other one
+    pub fn intersect(self, other: OffsetRange) -> OffsetRange {
+        OffsetRange {
+            start: self.start.max(other.start),
+            end: match (self.end, other.end) {
+                (Some(a), Some(b)) => Some(a.min(b)),
+                (a, b) => a.or(b),
+            },
+        }
+    }
+}
+
+/// Range of record event times, the start is inclusive and the end is
+/// exclusive
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct EventTimeRange {
+    pub start: Option<DateTime<Utc>>,
+    pub end: Option<DateTime<Utc>>,
+}
+
+/// Range of metadata blocks, allowing consumers to read only the records that
+/// were added since the block they've seen previously
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BlockRange {
+    /// Exclusive start of the range, e.g. the head block seen during the
+    /// previous read. Defaults to the beginning of the chain
+    pub after: Option<Multihash>,
+    /// Inclusive end of the range. Defaults to the current head
+    pub up_to: Option<Multihash>,
 }
 
 #[derive(Debug, Clone)]
@@ -188,6 +265,18 @@ pub enum QueryError {
         DatasetSchemaNotAvailableError,
     ),
     #[error(transparent)]
+    BlockRefNotFound(
+        #[from]
+        #[backtrace]
+        RefNotFoundError,
+    ),
+    #[error(transparent)]
+    InvalidDataRange(
+        #[from]
+        #[backtrace]
+        InvalidDataRangeError,
+    ),
+    #[error(transparent)]
     DataFusionError(
         #[from]
         #[backtrace]
@@ -238,6 +327,14 @@ pub struct DatasetSchemaNotAvailableError {
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
+#[derive(Error, Clone, PartialEq, Eq, Debug)]
+#[error("Invalid data range: {reason}")]
+pub struct InvalidDataRangeError {
+    pub reason: String,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
 impl From<GetDatasetError> for QueryError {
     fn from(v: GetDatasetError) -> Self {
         match v {
@@ -0,0 +1,136 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use chrono::Duration;
+use internal_error::InternalError;
+use opendatafabric::*;
+use thiserror::Error;
+
+use crate::*;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Default time during which recently written objects are considered to be
+/// in-flight and are never collected, even if they are not yet referenced
+pub const DEFAULT_STORAGE_GC_GRACE_PERIOD_SECS: i64 = 60 * 60;
+
+/// Removes data and checkpoint objects that are not reachable from any of the
+/// dataset references. Such objects accumulate after resets, hard compactions,
+/// and diverged force-pulls.
+#[async_trait::async_trait]
+pub trait StorageGcService: Send + Sync {
+    async fn collect_dataset_garbage(
+        &self,
+        dataset_handle: &DatasetHandle,
+        options: StorageGcOptions,
+    ) -> Result<StorageGcDatasetResult, StorageGcError>;
+
+    /// Collects garbage in all datasets that current user can write to
+    async fn collect_garbage(
+        &self,
+        options: StorageGcOptions,
+    ) -> Result<Vec<StorageGcDatasetResult>, StorageGcError>;
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StorageGcOptions {
+    /// Only report unreferenced objects without deleting them
+    pub dry_run: bool,
+    /// Objects modified more recently than this are never collected to avoid
+    /// racing with commits that are still in progress
+    pub grace_period: Duration,
+}
+
+impl Default for StorageGcOptions {
+    fn default() -> Self {
+        Self {
+            dry_run: false,
+            grace_period: Duration::try_seconds(DEFAULT_STORAGE_GC_GRACE_PERIOD_SECS).unwrap(),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StorageGcDatasetResult {
+    pub dataset_handle: DatasetHandle,
+    /// Objects that were deleted, or would have been deleted in dry-run mode
+    pub unreferenced_objects: Vec<UnreferencedObject>,
+}
+
+impl StorageGcDatasetResult {
+    pub fn bytes_freed(&self) -> u64 {
+        self.unreferenced_objects.iter().map(|o| o.size).sum()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnreferencedObject {
+    pub kind: StorageObjectKind,
+    pub hash: Multihash,
+    pub size: u64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StorageObjectKind {
+    Data,
+    Checkpoint,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// Errors
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Debug, Error)]
+pub enum StorageGcError {
+    #[error(transparent)]
+    Access(
+        #[from]
+        #[backtrace]
+        AccessError,
+    ),
+    #[error(transparent)]
+    Internal(
+        #[from]
+        #[backtrace]
+        InternalError,
+    ),
+}
+
+impl From<auth::DatasetActionUnauthorizedError> for StorageGcError {
+    fn from(v: auth::DatasetActionUnauthorizedError) -> Self {
+        match v {
+            auth::DatasetActionUnauthorizedError::Access(e) => Self::Access(e),
+            auth::DatasetActionUnauthorizedError::Internal(e) => Self::Internal(e),
+        }
+    }
+}
+
+impl From<ListObjectsError> for StorageGcError {
+    fn from(v: ListObjectsError) -> Self {
+        match v {
+            ListObjectsError::Access(e) => Self::Access(e),
+            ListObjectsError::Internal(e) => Self::Internal(e),
+        }
+    }
+}
+
+impl From<DeleteError> for StorageGcError {
+    fn from(v: DeleteError) -> Self {
+        match v {
+            DeleteError::Access(e) => Self::Access(e),
+            DeleteError::Internal(e) => Self::Internal(e),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -60,6 +60,10 @@ pub struct SyncOptions {
 
     /// Force synchronization, even if revisions have diverged
     pub force: bool,
+
+    /// Delete tags and branches of the destination that don't exist in the
+    /// source
+    pub delete_missing_refs: bool,
 }
 
 impl Default for SyncOptions {
@@ -68,6 +72,7 @@ impl Default for SyncOptions {
             trust_source: None,
             create_if_not_exists: true,
             force: false,
+            delete_missing_refs: false,
         }
     }
 }
@@ -188,6 +193,8 @@ pub enum SyncError {
     DestinationAhead(#[from] DestinationAheadError),
     #[error(transparent)]
     Corrupted(#[from] CorruptedSourceError),
+    #[error(transparent)]
+    RefConflict(#[from] RefConflictError),
     #[error("Dataset was updated concurrently")]
     UpdatedConcurrently(#[source] BoxedError),
     #[error(transparent)]
@@ -320,6 +327,15 @@ impl From<BuildDatasetError> for SyncError {
     }
 }
 
+impl From<ImportRefsError> for SyncError {
+    fn from(v: ImportRefsError) -> Self {
+        match v {
+            ImportRefsError::Conflict(e) => Self::RefConflict(e),
+            ImportRefsError::Internal(e) => Self::Internal(e),
+        }
+    }
+}
+
 impl From<CompareChainsError> for SyncError {
     fn from(v: CompareChainsError) -> Self {
         match v {
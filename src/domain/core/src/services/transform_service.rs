@@ -7,6 +7,7 @@
 // the Business Source License, use of this software will be governed
 // by the Apache License, Version 2.0.
 
+use std::path::PathBuf;
 use std::sync::Arc;
 
 use internal_error::InternalError;
@@ -63,11 +64,14 @@ pub enum TransformResult {
     },
 }
 
-#[derive(Clone, Copy, Debug, Default)]
+#[derive(Clone, Debug, Default)]
 pub struct TransformOptions {
     /// Run compaction of derivative datasets without saving data
     /// if transformation fails due to root dataset compaction
     pub reset_derivatives_on_diverged_input: bool,
+    /// Stops the transform before committing its results, terminating the
+    /// engine if it is still running
+    pub cancellation_token: CancellationToken,
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -76,6 +80,11 @@ pub struct TransformOptions {
 
 pub trait TransformListener: Send + Sync {
     fn begin(&self) {}
+    /// Called with logs of the engine that completed successfully. Logs of a
+    /// failed engine are carried by the error
+    fn on_log_files(&self, _log_files: &[PathBuf]) {}
+    /// Called once the records produced from the input slices are committed
+    fn on_records_processed(&self, _records_read: u64, _records_written: u64) {}
     fn success(&self, _result: &TransformResult) {}
     fn error(&self, _error: &TransformError) {}
 
@@ -155,6 +164,12 @@ pub enum TransformError {
         InvalidIntervalError,
     ),
     #[error(transparent)]
+    Cancelled(
+        #[from]
+        #[backtrace]
+        OperationCancelledError,
+    ),
+    #[error(transparent)]
     Internal(
         #[from]
         #[backtrace]
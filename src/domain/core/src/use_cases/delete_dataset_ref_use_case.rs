@@ -0,0 +1,72 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use internal_error::InternalError;
+use opendatafabric::DatasetHandle;
+use thiserror::Error;
+
+use crate::auth::DatasetActionUnauthorizedError;
+use crate::{AccessError, BlockRef, DeleteRefError, ReadOnlyRefError, RefNotFoundError};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[async_trait::async_trait]
+pub trait DeleteDatasetRefUseCase: Send + Sync {
+    /// Deletes a named reference (tag or branch). The blocks it was pointing
+    /// to remain part of the chain.
+    async fn execute(
+        &self,
+        dataset_handle: &DatasetHandle,
+        block_ref: &BlockRef,
+    ) -> Result<(), DeleteDatasetRefError>;
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// Errors
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Error, Debug)]
+pub enum DeleteDatasetRefError {
+    #[error(transparent)]
+    ReadOnlyRef(#[from] ReadOnlyRefError),
+    #[error(transparent)]
+    RefNotFound(#[from] RefNotFoundError),
+    #[error(transparent)]
+    Access(
+        #[from]
+        #[backtrace]
+        AccessError,
+    ),
+    #[error(transparent)]
+    Internal(
+        #[from]
+        #[backtrace]
+        InternalError,
+    ),
+}
+
+impl From<DeleteRefError> for DeleteDatasetRefError {
+    fn from(v: DeleteRefError) -> Self {
+        match v {
+            DeleteRefError::Access(e) => Self::Access(e),
+            DeleteRefError::Internal(e) => Self::Internal(e),
+        }
+    }
+}
+
+impl From<DatasetActionUnauthorizedError> for DeleteDatasetRefError {
+    fn from(v: DatasetActionUnauthorizedError) -> Self {
+        match v {
+            DatasetActionUnauthorizedError::Access(e) => Self::Access(e),
+            DatasetActionUnauthorizedError::Internal(e) => Self::Internal(e),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -11,12 +11,16 @@ mod append_dataset_metadata_batch_use_case;
 mod commit_dataset_event_use_case;
 mod create_dataset_from_snapshot_use_case;
 mod create_dataset_use_case;
+mod delete_dataset_ref_use_case;
 mod delete_dataset_use_case;
 mod rename_dataset_use_case;
+mod set_dataset_ref_use_case;
 
 pub use append_dataset_metadata_batch_use_case::*;
 pub use commit_dataset_event_use_case::*;
 pub use create_dataset_from_snapshot_use_case::*;
 pub use create_dataset_use_case::*;
+pub use delete_dataset_ref_use_case::*;
 pub use delete_dataset_use_case::*;
 pub use rename_dataset_use_case::*;
+pub use set_dataset_ref_use_case::*;
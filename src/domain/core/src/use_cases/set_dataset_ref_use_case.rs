@@ -0,0 +1,84 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use internal_error::{ErrorIntoInternal, InternalError};
+use opendatafabric::{DatasetHandle, Multihash};
+use thiserror::Error;
+
+use crate::auth::DatasetActionUnauthorizedError;
+use crate::{AccessError, BlockNotFoundError, BlockRef, SetRefError};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[async_trait::async_trait]
+pub trait SetDatasetRefUseCase: Send + Sync {
+    /// Creates or moves a named reference (tag or branch) to point at the
+    /// specified block. Tags are immutable and cannot be moved once created.
+    async fn execute(
+        &self,
+        dataset_handle: &DatasetHandle,
+        block_ref: &BlockRef,
+        block_hash: &Multihash,
+    ) -> Result<(), SetDatasetRefError>;
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// Errors
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Error, Debug)]
+pub enum SetDatasetRefError {
+    #[error(transparent)]
+    ReadOnlyRef(#[from] ReadOnlyRefError),
+    #[error(transparent)]
+    BlockNotFound(#[from] BlockNotFoundError),
+    #[error(transparent)]
+    Access(
+        #[from]
+        #[backtrace]
+        AccessError,
+    ),
+    #[error(transparent)]
+    Internal(
+        #[from]
+        #[backtrace]
+        InternalError,
+    ),
+}
+
+impl From<SetRefError> for SetDatasetRefError {
+    fn from(v: SetRefError) -> Self {
+        match v {
+            SetRefError::BlockNotFound(e) => Self::BlockNotFound(e),
+            SetRefError::CASFailed(e) => Self::Internal(e.int_err()),
+            SetRefError::Access(e) => Self::Access(e),
+            SetRefError::Internal(e) => Self::Internal(e),
+        }
+    }
+}
+
+impl From<DatasetActionUnauthorizedError> for SetDatasetRefError {
+    fn from(v: DatasetActionUnauthorizedError) -> Self {
+        match v {
+            DatasetActionUnauthorizedError::Access(e) => Self::Access(e),
+            DatasetActionUnauthorizedError::Internal(e) => Self::Internal(e),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Error, Debug)]
+#[error("Reference {block_ref} cannot be modified: {reason}")]
+pub struct ReadOnlyRefError {
+    pub block_ref: BlockRef,
+    pub reason: String,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
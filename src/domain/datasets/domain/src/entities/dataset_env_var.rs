@@ -26,6 +26,8 @@ use uuid::Uuid;
 
 pub const SAMPLE_DATASET_ENV_VAR_ENCRYPTION_KEY: &str = "QfnEDcnUtGSW2pwVXaFPvZOwxyFm2BOC";
 
+pub const DEFAULT_DATASET_ENV_VAR_ENCRYPTION_KEY_ID: &str = "default";
+
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
 #[derive(Debug, Clone, Eq, PartialEq)]
@@ -34,6 +36,9 @@ pub struct DatasetEnvVar {
     pub key: String,
     pub value: Vec<u8>,
     pub secret_nonce: Option<Vec<u8>>,
+    /// Identifier of the key the secret value was encrypted with. Secrets
+    /// stored before key identifiers were introduced don't have it
+    pub encryption_key_id: Option<String>,
     pub created_at: DateTime<Utc>,
     pub dataset_id: DatasetID,
 }
@@ -44,30 +49,16 @@ impl DatasetEnvVar {
         creation_date: DateTime<Utc>,
         dataset_env_var_value: &DatasetEnvVarValue,
         dataset_id: &DatasetID,
-        encryption_key: &str,
+        encryption_key_ring: &DatasetEnvVarEncryptionKeyRing,
     ) -> Result<Self, DatasetEnvVarEncryptionError> {
-        let dataset_env_var_id = Uuid::new_v4();
-        let mut secret_nonce: Option<Vec<u8>> = None;
-        let final_value: Vec<u8>;
-
-        match dataset_env_var_value {
-            DatasetEnvVarValue::Secret(secret_value) => {
-                let cipher = Self::try_asm_256_gcm_from_str(encryption_key)?;
-                let nonce = Aes256Gcm::generate_nonce(&mut OsRng);
-                secret_nonce = Some(nonce.to_vec());
-                final_value = cipher
-                    .encrypt(&nonce, secret_value.expose_secret().as_ref())
-                    .map_err(|err| DatasetEnvVarEncryptionError::InvalidCipherKeyError {
-                        source: Box::new(AesGcmError(err)),
-                    })?;
-            }
-            DatasetEnvVarValue::Regular(value) => final_value = value.as_bytes().to_vec(),
-        }
+        let (final_value, secret_nonce, encryption_key_id) =
+            Self::encrypt_value(dataset_env_var_value, encryption_key_ring)?;
 
         Ok(DatasetEnvVar {
-            id: dataset_env_var_id,
+            id: Uuid::new_v4(),
             value: final_value,
             secret_nonce,
+            encryption_key_id,
             key: dataset_env_var_key.to_string(),
             created_at: creation_date,
             dataset_id: dataset_id.clone(),
@@ -93,18 +84,30 @@ impl DatasetEnvVar {
 
     pub fn get_exposed_decrypted_value(
         &self,
-        encryption_key: &str,
+        encryption_key_ring: &DatasetEnvVarEncryptionKeyRing,
     ) -> Result<String, DatasetEnvVarEncryptionError> {
         if let Some(secret_nonce) = self.secret_nonce.as_ref() {
-            let cipher = Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(encryption_key.as_bytes()));
-            let decypted_value = cipher
-                .decrypt(
-                    GenericArray::from_slice(secret_nonce.as_slice()),
-                    self.value.as_ref(),
-                )
-                .map_err(|err| DatasetEnvVarEncryptionError::InvalidCipherKeyError {
-                    source: Box::new(AesGcmError(err)),
-                })?;
+            let decypted_value = match self.encryption_key_id.as_ref() {
+                Some(encryption_key_id) => {
+                    let encryption_key = encryption_key_ring
+                        .get_key(encryption_key_id)
+                        .ok_or_else(|| DatasetEnvVarEncryptionError::UnknownEncryptionKey {
+                            encryption_key_id: encryption_key_id.clone(),
+                        })?;
+                    self.decrypt_value(secret_nonce, encryption_key)
+                }
+                // The key is not recorded, so try every known one: AES-GCM
+                // authenticates the ciphertext and fails with a wrong key
+                None => encryption_key_ring
+                    .keys()
+                    .find_map(|encryption_key| {
+                        self.decrypt_value(secret_nonce, encryption_key).ok()
+                    })
+                    .ok_or(aes_gcm::Error),
+            }
+            .map_err(|err| DatasetEnvVarEncryptionError::InvalidCipherKeyError {
+                source: Box::new(AesGcmError(err)),
+            })?;
             return Ok(std::str::from_utf8(decypted_value.as_slice())
                 .map_err(|err| DatasetEnvVarEncryptionError::InternalError(err.int_err()))?
                 .to_string());
@@ -112,20 +115,31 @@ impl DatasetEnvVar {
         Ok(std::str::from_utf8(&self.value).unwrap().to_string())
     }
 
+    /// Returns the new value, its nonce and the identifier of the encryption
+    /// key. Secrets are always encrypted with the active key of the ring
     pub fn generate_new_value(
         &self,
         dataset_env_var_new_value: &DatasetEnvVarValue,
-        encryption_key: &str,
-    ) -> Result<(Vec<u8>, Option<Vec<u8>>), DatasetEnvVarEncryptionError> {
-        let new_value_and_nonce = match dataset_env_var_new_value {
+        encryption_key_ring: &DatasetEnvVarEncryptionKeyRing,
+    ) -> Result<(Vec<u8>, Option<Vec<u8>>, Option<String>), DatasetEnvVarEncryptionError> {
+        Self::encrypt_value(dataset_env_var_new_value, encryption_key_ring)
+    }
+
+    /// Whether the value is a secret which is not encrypted with the active key
+    /// of the ring
+    pub fn needs_reencryption(&self, encryption_key_ring: &DatasetEnvVarEncryptionKeyRing) -> bool {
+        self.secret_nonce.is_some()
+            && self.encryption_key_id.as_deref() != Some(encryption_key_ring.active_key_id())
+    }
+
+    fn encrypt_value(
+        dataset_env_var_value: &DatasetEnvVarValue,
+        encryption_key_ring: &DatasetEnvVarEncryptionKeyRing,
+    ) -> Result<(Vec<u8>, Option<Vec<u8>>, Option<String>), DatasetEnvVarEncryptionError> {
+        let value_nonce_and_key_id = match dataset_env_var_value {
             DatasetEnvVarValue::Secret(secret_value) => {
-                let cipher = Self::try_asm_256_gcm_from_str(encryption_key)?;
-                let nonce = self
-                    .secret_nonce
-                    .as_ref()
-                    .map_or(Aes256Gcm::generate_nonce(&mut OsRng), |nonce_bytes| {
-                        *GenericArray::from_slice(nonce_bytes.as_slice())
-                    });
+                let cipher = Self::try_asm_256_gcm_from_str(encryption_key_ring.active_key())?;
+                let nonce = Aes256Gcm::generate_nonce(&mut OsRng);
                 (
                     cipher
                         .encrypt(&nonce, secret_value.expose_secret().as_ref())
@@ -133,11 +147,21 @@ impl DatasetEnvVar {
                             source: Box::new(AesGcmError(err)),
                         })?,
                     Some(nonce.to_vec()),
+                    Some(encryption_key_ring.active_key_id().to_string()),
                 )
             }
-            DatasetEnvVarValue::Regular(value) => (value.as_bytes().to_vec(), None),
+            DatasetEnvVarValue::Regular(value) => (value.as_bytes().to_vec(), None, None),
         };
-        Ok(new_value_and_nonce)
+        Ok(value_nonce_and_key_id)
+    }
+
+    fn decrypt_value(
+        &self,
+        secret_nonce: &[u8],
+        encryption_key: &str,
+    ) -> Result<Vec<u8>, aes_gcm::Error> {
+        let cipher = Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(encryption_key.as_bytes()));
+        cipher.decrypt(GenericArray::from_slice(secret_nonce), self.value.as_ref())
     }
 }
 
@@ -150,6 +174,7 @@ pub struct DatasetEnvVarRowModel {
     pub key: String,
     pub value: Vec<u8>,
     pub secret_nonce: Option<Vec<u8>>,
+    pub encryption_key_id: Option<String>,
     pub created_at: DateTime<Utc>,
     pub dataset_id: DatasetID,
 }
@@ -162,6 +187,7 @@ impl From<DatasetEnvVarRowModel> for DatasetEnvVar {
             key: value.key,
             value: value.value,
             secret_nonce: value.secret_nonce,
+            encryption_key_id: value.encryption_key_id,
             created_at: value.created_at,
             dataset_id: value.dataset_id,
         }
@@ -192,12 +218,119 @@ impl DatasetEnvVarValue {
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
+/// Set of keys the dataset env var secrets can be decrypted with. New secrets
+/// are always encrypted with the active key, the previous keys are only kept
+/// until all secrets are re-encrypted with the active one
+#[derive(Debug)]
+pub struct DatasetEnvVarEncryptionKeyRing {
+    active_key_id: String,
+    keys: Vec<(String, Secret<String>)>,
+}
+
+impl DatasetEnvVarEncryptionKeyRing {
+    pub fn new(
+        active_key_id: impl Into<String>,
+        active_key: impl Into<String>,
+    ) -> Result<Self, DatasetEnvVarEncryptionKeyRingError> {
+        let active_key_id = active_key_id.into();
+        let mut key_ring = Self {
+            active_key_id: active_key_id.clone(),
+            keys: Vec::new(),
+        };
+        key_ring.add_key(active_key_id, active_key.into())?;
+
+        Ok(key_ring)
+    }
+
+    pub fn with_previous_key(
+        mut self,
+        key_id: impl Into<String>,
+        key: impl Into<String>,
+    ) -> Result<Self, DatasetEnvVarEncryptionKeyRingError> {
+        self.add_key(key_id.into(), key.into())?;
+        Ok(self)
+    }
+
+    pub fn try_from_config(
+        config: &DatasetEnvVarsConfig,
+    ) -> Result<Self, DatasetEnvVarEncryptionKeyRingError> {
+        let Some(encryption_key) = config.encryption_key.as_ref() else {
+            return Err(DatasetEnvVarEncryptionKeyRingError::MissingEncryptionKey);
+        };
+
+        let mut key_ring = Self::new(
+            config
+                .encryption_key_id
+                .as_deref()
+                .unwrap_or(DEFAULT_DATASET_ENV_VAR_ENCRYPTION_KEY_ID),
+            encryption_key.as_str(),
+        )?;
+        for previous_key in config.previous_encryption_keys.iter().flatten() {
+            key_ring = key_ring.with_previous_key(&previous_key.id, &previous_key.key)?;
+        }
+
+        Ok(key_ring)
+    }
+
+    pub fn sample() -> Self {
+        Self::new(
+            DEFAULT_DATASET_ENV_VAR_ENCRYPTION_KEY_ID,
+            SAMPLE_DATASET_ENV_VAR_ENCRYPTION_KEY,
+        )
+        .unwrap()
+    }
+
+    pub fn active_key_id(&self) -> &str {
+        &self.active_key_id
+    }
+
+    fn active_key(&self) -> &str {
+        self.get_key(&self.active_key_id).unwrap()
+    }
+
+    fn get_key(&self, key_id: &str) -> Option<&str> {
+        self.keys
+            .iter()
+            .find(|(id, _)| id == key_id)
+            .map(|(_, key)| key.expose_secret().as_str())
+    }
+
+    fn keys(&self) -> impl Iterator<Item = &str> {
+        self.keys
+            .iter()
+            .map(|(_, key)| key.expose_secret().as_str())
+    }
+
+    fn add_key(
+        &mut self,
+        key_id: String,
+        key: String,
+    ) -> Result<(), DatasetEnvVarEncryptionKeyRingError> {
+        if self.get_key(&key_id).is_some() {
+            return Err(DatasetEnvVarEncryptionKeyRingError::DuplicateKeyId { key_id });
+        }
+        if let Err(err) = DatasetEnvVar::try_asm_256_gcm_from_str(&key) {
+            return Err(DatasetEnvVarEncryptionKeyRingError::InvalidKey {
+                key_id,
+                source: err,
+            });
+        }
+
+        self.keys.push((key_id, Secret::new(key)));
+        Ok(())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
 #[derive(Error, Debug)]
 pub enum DatasetEnvVarEncryptionError {
     #[error("{source}")]
     InvalidCipherKeyError { source: BoxedError },
     #[error("Invalid encryption key")]
     InvalidEncryptionKey,
+    #[error("Unknown encryption key '{encryption_key_id}'")]
+    UnknownEncryptionKey { encryption_key_id: String },
     #[error(transparent)]
     InternalError(#[from] InternalError),
 }
@@ -219,6 +352,19 @@ impl From<ParseEncryptionKey> for DatasetEnvVarEncryptionError {
     }
 }
 
+#[derive(Error, Debug)]
+pub enum DatasetEnvVarEncryptionKeyRingError {
+    #[error("Encryption key is not specified")]
+    MissingEncryptionKey,
+    #[error("Encryption key '{key_id}' is specified more than once")]
+    DuplicateKeyId { key_id: String },
+    #[error("Invalid encryption key '{key_id}': {source}")]
+    InvalidKey {
+        key_id: String,
+        source: ParseEncryptionKey,
+    },
+}
+
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
 #[derive(Debug)]
@@ -253,6 +399,12 @@ pub struct DatasetEnvVarsConfig {
     /// Some(String::from("aBcDeFgHiJkLmNoPqRsTuVwXyZ012345")) };
     /// ```
     pub encryption_key: Option<String>,
+    /// Identifier of `encryption_key`, stored along with every secret
+    /// encrypted with it. Defaults to `default`.
+    pub encryption_key_id: Option<String>,
+    /// Keys that were active before the last rotation. They are only used to
+    /// decrypt secrets that were not re-encrypted with `encryption_key` yet.
+    pub previous_encryption_keys: Option<Vec<DatasetEnvVarEncryptionKeyConfig>>,
 }
 
 impl DatasetEnvVarsConfig {
@@ -260,6 +412,8 @@ impl DatasetEnvVarsConfig {
         Self {
             enabled: Some(true),
             encryption_key: Some(SAMPLE_DATASET_ENV_VAR_ENCRYPTION_KEY.to_string()),
+            encryption_key_id: None,
+            previous_encryption_keys: None,
         }
     }
 
@@ -274,15 +428,28 @@ impl DatasetEnvVarsConfig {
     }
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct DatasetEnvVarEncryptionKeyConfig {
+    pub id: String,
+    pub key: String,
+}
+
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
 #[cfg(test)]
 mod tests {
+    use std::assert_matches::assert_matches;
+
     use chrono::Utc;
     use opendatafabric::DatasetID;
     use secrecy::Secret;
 
-    use crate::{DatasetEnvVar, SAMPLE_DATASET_ENV_VAR_ENCRYPTION_KEY};
+    use crate::{
+        DatasetEnvVar,
+        DatasetEnvVarEncryptionKeyRing,
+        SAMPLE_DATASET_ENV_VAR_ENCRYPTION_KEY,
+    };
 
     #[test]
     fn test_secret_env_var_generation() {
@@ -292,12 +459,12 @@ mod tests {
             Utc::now(),
             &crate::DatasetEnvVarValue::Secret(Secret::new(secret_value.to_string())),
             &DatasetID::new_seeded_ed25519(b"foo"),
-            SAMPLE_DATASET_ENV_VAR_ENCRYPTION_KEY,
+            &DatasetEnvVarEncryptionKeyRing::sample(),
         )
         .unwrap();
 
         let original_value = new_env_var
-            .get_exposed_decrypted_value(SAMPLE_DATASET_ENV_VAR_ENCRYPTION_KEY)
+            .get_exposed_decrypted_value(&DatasetEnvVarEncryptionKeyRing::sample())
             .unwrap();
         assert_eq!(secret_value, original_value.as_str());
     }
@@ -310,13 +477,68 @@ mod tests {
             Utc::now(),
             &crate::DatasetEnvVarValue::Regular(value.to_string()),
             &DatasetID::new_seeded_ed25519(b"foo"),
-            SAMPLE_DATASET_ENV_VAR_ENCRYPTION_KEY,
+            &DatasetEnvVarEncryptionKeyRing::sample(),
         )
         .unwrap();
 
         let original_value = new_env_var
-            .get_exposed_decrypted_value(SAMPLE_DATASET_ENV_VAR_ENCRYPTION_KEY)
+            .get_exposed_decrypted_value(&DatasetEnvVarEncryptionKeyRing::sample())
             .unwrap();
         assert_eq!(value, original_value.as_str());
     }
+
+    #[test]
+    fn test_secret_env_var_decryption_with_previous_key() {
+        let secret_value = "foo";
+        let mut new_env_var = DatasetEnvVar::new(
+            "foo_key",
+            Utc::now(),
+            &crate::DatasetEnvVarValue::Secret(Secret::new(secret_value.to_string())),
+            &DatasetID::new_seeded_ed25519(b"foo"),
+            &DatasetEnvVarEncryptionKeyRing::sample(),
+        )
+        .unwrap();
+        assert_eq!(new_env_var.encryption_key_id.as_deref(), Some("default"));
+
+        let rotated_key_ring =
+            DatasetEnvVarEncryptionKeyRing::new("2024", "aBcDeFgHiJkLmNoPqRsTuVwXyZ012345")
+                .unwrap()
+                .with_previous_key("default", SAMPLE_DATASET_ENV_VAR_ENCRYPTION_KEY)
+                .unwrap();
+        assert!(new_env_var.needs_reencryption(&rotated_key_ring));
+        assert_eq!(
+            new_env_var
+                .get_exposed_decrypted_value(&rotated_key_ring)
+                .unwrap(),
+            secret_value
+        );
+
+        // Secrets stored without a key identifier are decrypted with any known key
+        new_env_var.encryption_key_id = None;
+        assert_eq!(
+            new_env_var
+                .get_exposed_decrypted_value(&rotated_key_ring)
+                .unwrap(),
+            secret_value
+        );
+
+        let (new_value, new_nonce, new_encryption_key_id) = new_env_var
+            .generate_new_value(
+                &crate::DatasetEnvVarValue::Secret(Secret::new(secret_value.to_string())),
+                &rotated_key_ring,
+            )
+            .unwrap();
+        let reencrypted_env_var = DatasetEnvVar {
+            value: new_value,
+            secret_nonce: new_nonce,
+            encryption_key_id: new_encryption_key_id,
+            ..new_env_var
+        };
+        assert!(!reencrypted_env_var.needs_reencryption(&rotated_key_ring));
+        assert_matches!(
+            reencrypted_env_var
+                .get_exposed_decrypted_value(&DatasetEnvVarEncryptionKeyRing::sample()),
+            Err(crate::DatasetEnvVarEncryptionError::UnknownEncryptionKey { .. })
+        );
+    }
 }
@@ -7,6 +7,7 @@
 // the Business Source License, use of this software will be governed
 // by the Apache License, Version 2.0.
 
+use database_common::DatabasePaginationOpts;
 use internal_error::InternalError;
 use opendatafabric::{AccountID, DatasetID, DatasetName};
 use thiserror::Error;
@@ -28,9 +29,22 @@ pub trait DatasetEntryRepository: Send + Sync {
         name: &DatasetName,
     ) -> Result<DatasetEntry, GetDatasetEntryByNameError>;
 
+    async fn dataset_entries_count(&self) -> Result<usize, GetDatasetEntriesError>;
+
+    async fn get_dataset_entries(
+        &self,
+        pagination: &DatabasePaginationOpts,
+    ) -> Result<Vec<DatasetEntry>, GetDatasetEntriesError>;
+
+    async fn dataset_entries_count_by_owner_id(
+        &self,
+        owner_id: &AccountID,
+    ) -> Result<usize, GetDatasetEntriesByOwnerIdError>;
+
     async fn get_dataset_entries_by_owner_id(
         &self,
         owner_id: &AccountID,
+        pagination: &DatabasePaginationOpts,
     ) -> Result<Vec<DatasetEntry>, GetDatasetEntriesByOwnerIdError>;
 
     async fn save_dataset_entry(
@@ -102,6 +116,14 @@ impl DatasetEntryByNameNotFoundError {
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
+#[derive(Error, Debug)]
+pub enum GetDatasetEntriesError {
+    #[error(transparent)]
+    Internal(InternalError),
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
 #[derive(Error, Debug)]
 pub enum GetDatasetEntriesByOwnerIdError {
     #[error(transparent)]
@@ -24,6 +24,13 @@ pub trait DatasetEnvVarRepository: Send + Sync {
         dataset_env_var: &DatasetEnvVar,
     ) -> Result<(), SaveDatasetEnvVarError>;
 
+    /// Lists env vars of all datasets in a stable order, so that pages can
+    /// be iterated while the listed env vars are being modified
+    async fn get_all_dataset_env_vars(
+        &self,
+        pagination: &DatabasePaginationOpts,
+    ) -> Result<Vec<DatasetEnvVar>, GetDatasetEnvVarError>;
+
     async fn get_all_dataset_env_vars_count_by_dataset_id(
         &self,
         dataset_id: &DatasetID,
@@ -56,6 +63,7 @@ pub trait DatasetEnvVarRepository: Send + Sync {
         dataset_env_var_id: &Uuid,
         new_value: Vec<u8>,
         secret_nonce: Option<Vec<u8>>,
+        encryption_key_id: Option<String>,
     ) -> Result<(), ModifyDatasetEnvVarError>;
 }
 
@@ -0,0 +1,54 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use database_common::DatabasePaginationOpts;
+use internal_error::InternalError;
+use opendatafabric::{AccountName, DatasetHandle, DatasetID, DatasetName};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Resolves and lists datasets using the catalog of dataset entries instead of
+/// scanning the dataset storage.
+///
+/// Returned handles always carry the name of the owner account in their alias,
+/// callers operating in single-tenant mode are expected to drop it.
+#[async_trait::async_trait]
+pub trait DatasetEntryService: Sync + Send {
+    async fn find_dataset_handle_by_id(
+        &self,
+        dataset_id: &DatasetID,
+    ) -> Result<Option<DatasetHandle>, InternalError>;
+
+    async fn find_dataset_handle_by_alias(
+        &self,
+        owner_name: &AccountName,
+        dataset_name: &DatasetName,
+    ) -> Result<Option<DatasetHandle>, InternalError>;
+
+    async fn list_all_dataset_handles(
+        &self,
+        pagination: &DatabasePaginationOpts,
+    ) -> Result<DatasetHandlesListing, InternalError>;
+
+    async fn list_dataset_handles_by_owner(
+        &self,
+        owner_name: &AccountName,
+        pagination: &DatabasePaginationOpts,
+    ) -> Result<DatasetHandlesListing, InternalError>;
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Debug, Default)]
+pub struct DatasetHandlesListing {
+    pub list: Vec<DatasetHandle>,
+    pub total_count: usize,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -7,8 +7,10 @@
 // the Business Source License, use of this software will be governed
 // by the Apache License, Version 2.0.
 
+mod dataset_entry_service;
 mod dataset_env_var_service;
 mod dataset_key_value_service;
 
+pub use dataset_entry_service::*;
 pub use dataset_env_var_service::*;
 pub use dataset_key_value_service::*;
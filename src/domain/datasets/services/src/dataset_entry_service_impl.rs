@@ -0,0 +1,333 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use database_common::DatabasePaginationOpts;
+use dill::{component, interface, meta, Catalog};
+use internal_error::{ErrorIntoInternal, InternalError, ResultIntoInternal};
+use kamu_accounts::AuthenticationService;
+use kamu_core::{
+    DatasetLifecycleMessage,
+    DatasetLifecycleMessageCreated,
+    DatasetLifecycleMessageDeleted,
+    DatasetLifecycleMessageRenamed,
+    DatasetRepository,
+    GetDatasetError,
+    MESSAGE_PRODUCER_KAMU_CORE_DATASET_SERVICE,
+};
+use kamu_datasets::{
+    DatasetEntry,
+    DatasetEntryRepository,
+    DatasetEntryService,
+    DatasetHandlesListing,
+    DeleteEntryDatasetError,
+    GetDatasetEntryByNameError,
+    GetDatasetEntryError,
+    UpdateDatasetEntryNameError,
+};
+use messaging_outbox::{
+    MessageConsumer,
+    MessageConsumerMeta,
+    MessageConsumerT,
+    MessageConsumptionDurability,
+};
+use opendatafabric::{AccountName, DatasetAlias, DatasetHandle, DatasetID, DatasetName};
+use time_source::SystemTimeSource;
+
+use crate::MESSAGE_CONSUMER_KAMU_DATASET_ENTRY_SERVICE;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub struct DatasetEntryServiceImpl {
+    dataset_entry_repo: Arc<dyn DatasetEntryRepository>,
+    authentication_service: Arc<dyn AuthenticationService>,
+    time_source: Arc<dyn SystemTimeSource>,
+}
+
+#[component(pub)]
+#[interface(dyn DatasetEntryService)]
+#[interface(dyn MessageConsumer)]
+#[interface(dyn MessageConsumerT<DatasetLifecycleMessage>)]
+#[meta(MessageConsumerMeta {
+    consumer_name: MESSAGE_CONSUMER_KAMU_DATASET_ENTRY_SERVICE,
+    feeding_producers: &[
+        MESSAGE_PRODUCER_KAMU_CORE_DATASET_SERVICE,
+    ],
+    durability: MessageConsumptionDurability::BestEffort,
+})]
+impl DatasetEntryServiceImpl {
+    pub fn new(
+        dataset_entry_repo: Arc<dyn DatasetEntryRepository>,
+        authentication_service: Arc<dyn AuthenticationService>,
+        time_source: Arc<dyn SystemTimeSource>,
+    ) -> Self {
+        Self {
+            dataset_entry_repo,
+            authentication_service,
+            time_source,
+        }
+    }
+
+    async fn entries_into_handles(
+        &self,
+        entries: Vec<DatasetEntry>,
+    ) -> Result<Vec<DatasetHandle>, InternalError> {
+        let mut owner_ids = entries
+            .iter()
+            .map(|entry| entry.owner_id.clone())
+            .collect::<Vec<_>>();
+        owner_ids.sort();
+        owner_ids.dedup();
+
+        let owner_names_by_id = self
+            .authentication_service
+            .accounts_by_ids(owner_ids)
+            .await?
+            .into_iter()
+            .map(|account| (account.id, account.account_name))
+            .collect::<HashMap<_, _>>();
+
+        entries
+            .into_iter()
+            .map(|entry| {
+                let Some(owner_name) = owner_names_by_id.get(&entry.owner_id) else {
+                    return InternalError::bail(format!(
+                        "Owner account '{}' of dataset '{}' not found",
+                        entry.owner_id, entry.id
+                    ));
+                };
+
+                Ok(DatasetHandle::new(
+                    entry.id,
+                    DatasetAlias::new(Some(owner_name.clone()), entry.name),
+                ))
+            })
+            .collect()
+    }
+
+    async fn handle_dataset_lifecycle_created_message(
+        &self,
+        catalog: &Catalog,
+        message: &DatasetLifecycleMessageCreated,
+    ) -> Result<(), InternalError> {
+        let dataset_name = match &message.dataset_name {
+            Some(dataset_name) => dataset_name.clone(),
+            None => {
+                // Older messages don't carry the name, so it is looked up in the storage
+                let dataset_repo = catalog.get_one::<dyn DatasetRepository>().int_err()?;
+                match dataset_repo
+                    .resolve_dataset_ref(&message.dataset_id.as_local_ref())
+                    .await
+                {
+                    Ok(handle) => handle.alias.dataset_name,
+                    Err(GetDatasetError::NotFound(e)) => {
+                        tracing::warn!(error = %e, "Created dataset not found, skipping");
+                        return Ok(());
+                    }
+                    Err(e) => return Err(e.int_err()),
+                }
+            }
+        };
+
+        let entry = DatasetEntry::new(
+            message.dataset_id.clone(),
+            message.owner_account_id.clone(),
+            dataset_name,
+            self.time_source.now(),
+        );
+
+        self.dataset_entry_repo
+            .save_dataset_entry(&entry)
+            .await
+            .int_err()
+    }
+
+    async fn handle_dataset_lifecycle_renamed_message(
+        &self,
+        message: &DatasetLifecycleMessageRenamed,
+    ) -> Result<(), InternalError> {
+        match self
+            .dataset_entry_repo
+            .update_dataset_entry_name(&message.dataset_id, &message.new_dataset_name)
+            .await
+        {
+            Ok(()) => Ok(()),
+            Err(UpdateDatasetEntryNameError::NotFound(e)) => {
+                tracing::warn!(error = %e, "Renamed dataset has no entry, skipping");
+                Ok(())
+            }
+            Err(e) => Err(e.int_err()),
+        }
+    }
+
+    async fn handle_dataset_lifecycle_deleted_message(
+        &self,
+        message: &DatasetLifecycleMessageDeleted,
+    ) -> Result<(), InternalError> {
+        match self
+            .dataset_entry_repo
+            .delete_dataset_entry(&message.dataset_id)
+            .await
+        {
+            Ok(()) => Ok(()),
+            Err(DeleteEntryDatasetError::NotFound(e)) => {
+                tracing::warn!(error = %e, "Deleted dataset has no entry, skipping");
+                Ok(())
+            }
+            Err(e) => Err(e.int_err()),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[async_trait::async_trait]
+impl DatasetEntryService for DatasetEntryServiceImpl {
+    async fn find_dataset_handle_by_id(
+        &self,
+        dataset_id: &DatasetID,
+    ) -> Result<Option<DatasetHandle>, InternalError> {
+        let entry = match self.dataset_entry_repo.get_dataset_entry(dataset_id).await {
+            Ok(entry) => entry,
+            Err(GetDatasetEntryError::NotFound(_)) => return Ok(None),
+            Err(GetDatasetEntryError::Internal(e)) => return Err(e),
+        };
+
+        let mut handles = self.entries_into_handles(vec![entry]).await?;
+
+        Ok(handles.pop())
+    }
+
+    async fn find_dataset_handle_by_alias(
+        &self,
+        owner_name: &AccountName,
+        dataset_name: &DatasetName,
+    ) -> Result<Option<DatasetHandle>, InternalError> {
+        let Some(owner_id) = self
+            .authentication_service
+            .find_account_id_by_name(owner_name)
+            .await?
+        else {
+            return Ok(None);
+        };
+
+        match self
+            .dataset_entry_repo
+            .get_dataset_entry_by_name(&owner_id, dataset_name)
+            .await
+        {
+            Ok(entry) => Ok(Some(DatasetHandle::new(
+                entry.id,
+                DatasetAlias::new(Some(owner_name.clone()), entry.name),
+            ))),
+            Err(GetDatasetEntryByNameError::NotFound(_)) => Ok(None),
+            Err(GetDatasetEntryByNameError::Internal(e)) => Err(e),
+        }
+    }
+
+    async fn list_all_dataset_handles(
+        &self,
+        pagination: &DatabasePaginationOpts,
+    ) -> Result<DatasetHandlesListing, InternalError> {
+        let total_count = self
+            .dataset_entry_repo
+            .dataset_entries_count()
+            .await
+            .int_err()?;
+        let entries = self
+            .dataset_entry_repo
+            .get_dataset_entries(pagination)
+            .await
+            .int_err()?;
+
+        Ok(DatasetHandlesListing {
+            list: self.entries_into_handles(entries).await?,
+            total_count,
+        })
+    }
+
+    async fn list_dataset_handles_by_owner(
+        &self,
+        owner_name: &AccountName,
+        pagination: &DatabasePaginationOpts,
+    ) -> Result<DatasetHandlesListing, InternalError> {
+        let Some(owner_id) = self
+            .authentication_service
+            .find_account_id_by_name(owner_name)
+            .await?
+        else {
+            return Ok(DatasetHandlesListing::default());
+        };
+
+        let total_count = self
+            .dataset_entry_repo
+            .dataset_entries_count_by_owner_id(&owner_id)
+            .await
+            .int_err()?;
+        let entries = self
+            .dataset_entry_repo
+            .get_dataset_entries_by_owner_id(&owner_id, pagination)
+            .await
+            .int_err()?;
+
+        Ok(DatasetHandlesListing {
+            list: entries
+                .into_iter()
+                .map(|entry| {
+                    DatasetHandle::new(
+                        entry.id,
+                        DatasetAlias::new(Some(owner_name.clone()), entry.name),
+                    )
+                })
+                .collect(),
+            total_count,
+        })
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl MessageConsumer for DatasetEntryServiceImpl {}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[async_trait::async_trait]
+impl MessageConsumerT<DatasetLifecycleMessage> for DatasetEntryServiceImpl {
+    #[tracing::instrument(level = "debug", skip_all, fields(?message))]
+    async fn consume_message(
+        &self,
+        target_catalog: &Catalog,
+        message: &DatasetLifecycleMessage,
+    ) -> Result<(), InternalError> {
+        match message {
+            DatasetLifecycleMessage::Created(message) => {
+                self.handle_dataset_lifecycle_created_message(target_catalog, message)
+                    .await
+            }
+
+            DatasetLifecycleMessage::Renamed(message) => {
+                self.handle_dataset_lifecycle_renamed_message(message).await
+            }
+
+            DatasetLifecycleMessage::Deleted(message) => {
+                self.handle_dataset_lifecycle_deleted_message(message).await
+            }
+
+            DatasetLifecycleMessage::DependenciesUpdated(_)
+            | DatasetLifecycleMessage::PollingSourceDisabled(_) => {
+                // No action required
+                Ok(())
+            }
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,120 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::sync::Arc;
+
+use database_common::DatabasePaginationOpts;
+use dill::*;
+use internal_error::{InternalError, ResultIntoInternal};
+use kamu_datasets::{
+    DatasetEnvVarEncryptionKeyRing,
+    DatasetEnvVarRepository,
+    DatasetEnvVarValue,
+    DatasetEnvVarsConfig,
+};
+use secrecy::Secret;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+const DATASET_ENV_VARS_PAGE_SIZE: i64 = 100;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Re-encrypts dataset env var secrets with the active encryption key, so that
+/// the previous keys can be removed from the configuration
+pub struct DatasetEnvVarEncryptionKeyRotator {
+    dataset_env_var_repository: Arc<dyn DatasetEnvVarRepository>,
+    dataset_env_var_encryption_key_ring: DatasetEnvVarEncryptionKeyRing,
+}
+
+#[component(pub)]
+impl DatasetEnvVarEncryptionKeyRotator {
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn new(
+        dataset_env_var_repository: Arc<dyn DatasetEnvVarRepository>,
+        dataset_env_var_config: Arc<DatasetEnvVarsConfig>,
+    ) -> Self {
+        Self {
+            dataset_env_var_repository,
+            dataset_env_var_encryption_key_ring: DatasetEnvVarEncryptionKeyRing::try_from_config(
+                &dataset_env_var_config,
+            )
+            .unwrap(),
+        }
+    }
+
+    #[tracing::instrument(level = "info", skip_all)]
+    pub async fn rotate(&self) -> Result<DatasetEnvVarEncryptionKeyRotationSummary, InternalError> {
+        let mut summary = DatasetEnvVarEncryptionKeyRotationSummary::default();
+
+        let mut offset = 0;
+        loop {
+            let dataset_env_vars = self
+                .dataset_env_var_repository
+                .get_all_dataset_env_vars(&DatabasePaginationOpts {
+                    limit: DATASET_ENV_VARS_PAGE_SIZE,
+                    offset,
+                })
+                .await
+                .int_err()?;
+
+            let page_size = i64::try_from(dataset_env_vars.len()).int_err()?;
+
+            for dataset_env_var in dataset_env_vars {
+                if dataset_env_var.secret_nonce.is_none() {
+                    continue;
+                }
+                if !dataset_env_var.needs_reencryption(&self.dataset_env_var_encryption_key_ring) {
+                    summary.up_to_date += 1;
+                    continue;
+                }
+
+                let exposed_value = dataset_env_var
+                    .get_exposed_decrypted_value(&self.dataset_env_var_encryption_key_ring)
+                    .int_err()?;
+                let (new_value, new_nonce, new_encryption_key_id) = dataset_env_var
+                    .generate_new_value(
+                        &DatasetEnvVarValue::Secret(Secret::new(exposed_value)),
+                        &self.dataset_env_var_encryption_key_ring,
+                    )
+                    .int_err()?;
+
+                self.dataset_env_var_repository
+                    .modify_dataset_env_var(
+                        &dataset_env_var.id,
+                        new_value,
+                        new_nonce,
+                        new_encryption_key_id,
+                    )
+                    .await
+                    .int_err()?;
+                summary.reencrypted += 1;
+            }
+
+            if page_size < DATASET_ENV_VARS_PAGE_SIZE {
+                break;
+            }
+            offset += page_size;
+        }
+
+        tracing::info!(?summary, "Dataset env var secrets re-encrypted");
+
+        Ok(summary)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct DatasetEnvVarEncryptionKeyRotationSummary {
+    pub reencrypted: usize,
+    pub up_to_date: usize,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
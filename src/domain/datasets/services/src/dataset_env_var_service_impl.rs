@@ -14,6 +14,7 @@ use dill::*;
 use internal_error::{ErrorIntoInternal, InternalError, ResultIntoInternal};
 use kamu_datasets::{
     DatasetEnvVar,
+    DatasetEnvVarEncryptionKeyRing,
     DatasetEnvVarListing,
     DatasetEnvVarRepository,
     DatasetEnvVarService,
@@ -25,7 +26,6 @@ use kamu_datasets::{
     SaveDatasetEnvVarError,
 };
 use opendatafabric::DatasetID;
-use secrecy::{ExposeSecret, Secret};
 use time_source::SystemTimeSource;
 use uuid::Uuid;
 
@@ -34,7 +34,7 @@ use uuid::Uuid;
 pub struct DatasetEnvVarServiceImpl {
     dataset_env_var_repository: Arc<dyn DatasetEnvVarRepository>,
     time_source: Arc<dyn SystemTimeSource>,
-    dataset_env_var_encryption_key: Secret<String>,
+    dataset_env_var_encryption_key_ring: DatasetEnvVarEncryptionKeyRing,
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -51,13 +51,10 @@ impl DatasetEnvVarServiceImpl {
         Self {
             dataset_env_var_repository,
             time_source,
-            dataset_env_var_encryption_key: Secret::new(
-                dataset_env_var_config
-                    .encryption_key
-                    .as_ref()
-                    .unwrap()
-                    .clone(),
-            ),
+            dataset_env_var_encryption_key_ring: DatasetEnvVarEncryptionKeyRing::try_from_config(
+                &dataset_env_var_config,
+            )
+            .unwrap(),
         }
     }
 }
@@ -77,7 +74,7 @@ impl DatasetEnvVarService for DatasetEnvVarServiceImpl {
             self.time_source.now(),
             dataset_env_var_value,
             dataset_id,
-            self.dataset_env_var_encryption_key.expose_secret(),
+            &self.dataset_env_var_encryption_key_ring,
         )
         .map_err(|err| SaveDatasetEnvVarError::Internal(err.int_err()))?;
         self.dataset_env_var_repository
@@ -91,7 +88,7 @@ impl DatasetEnvVarService for DatasetEnvVarServiceImpl {
         dataset_env_var: &DatasetEnvVar,
     ) -> Result<String, InternalError> {
         dataset_env_var
-            .get_exposed_decrypted_value(self.dataset_env_var_encryption_key.expose_secret())
+            .get_exposed_decrypted_value(&self.dataset_env_var_encryption_key_ring)
             .int_err()
     }
 
@@ -160,15 +157,15 @@ impl DatasetEnvVarService for DatasetEnvVarServiceImpl {
                 GetDatasetEnvVarError::Internal(e) => ModifyDatasetEnvVarError::Internal(e),
             })?;
 
-        let (new_value, nonce) = existing_dataset_env_var
+        let (new_value, nonce, encryption_key_id) = existing_dataset_env_var
             .generate_new_value(
                 dataset_env_var_new_value,
-                self.dataset_env_var_encryption_key.expose_secret(),
+                &self.dataset_env_var_encryption_key_ring,
             )
             .int_err()
             .map_err(ModifyDatasetEnvVarError::Internal)?;
         self.dataset_env_var_repository
-            .modify_dataset_env_var(dataset_env_var_id, new_value, nonce)
+            .modify_dataset_env_var(dataset_env_var_id, new_value, nonce, encryption_key_id)
             .await
     }
 }
@@ -14,18 +14,19 @@ use dill::*;
 use internal_error::ErrorIntoInternal;
 use kamu_datasets::{
     DatasetEnvVar,
+    DatasetEnvVarEncryptionKeyRing,
     DatasetEnvVarNotFoundError,
     DatasetEnvVarValue,
     DatasetEnvVarsConfig,
     DatasetKeyValueService,
     FindDatasetEnvVarError,
 };
-use secrecy::{ExposeSecret, Secret};
+use secrecy::Secret;
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
 pub struct DatasetKeyValueServiceImpl {
-    dataset_env_var_encryption_key: Secret<String>,
+    dataset_env_var_encryption_key_ring: DatasetEnvVarEncryptionKeyRing,
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -36,13 +37,10 @@ impl DatasetKeyValueServiceImpl {
     #[allow(clippy::needless_pass_by_value)]
     pub fn new(dataset_env_var_config: Arc<DatasetEnvVarsConfig>) -> Self {
         Self {
-            dataset_env_var_encryption_key: Secret::new(
-                dataset_env_var_config
-                    .encryption_key
-                    .as_ref()
-                    .unwrap()
-                    .clone(),
-            ),
+            dataset_env_var_encryption_key_ring: DatasetEnvVarEncryptionKeyRing::try_from_config(
+                &dataset_env_var_config,
+            )
+            .unwrap(),
         }
     }
 }
@@ -57,7 +55,7 @@ impl DatasetKeyValueService for DatasetKeyValueServiceImpl {
     ) -> Result<DatasetEnvVarValue, FindDatasetEnvVarError> {
         if let Some(existing_dataset_env_var) = dataset_env_vars.get(dataset_env_var_key) {
             let exposed_value = existing_dataset_env_var
-                .get_exposed_decrypted_value(self.dataset_env_var_encryption_key.expose_secret())
+                .get_exposed_decrypted_value(&self.dataset_env_var_encryption_key_ring)
                 .map_err(|err| FindDatasetEnvVarError::Internal(err.int_err()))?;
             return if existing_dataset_env_var.secret_nonce.is_some() {
                 Ok(DatasetEnvVarValue::Secret(Secret::new(exposed_value)))
@@ -12,12 +12,18 @@
 // Re-exports
 pub use kamu_datasets as domain;
 
+mod dataset_entry_service_impl;
+mod dataset_env_var_encryption_key_rotator;
 mod dataset_env_var_service_impl;
 mod dataset_env_var_service_null;
 mod dataset_key_value_service_impl;
 mod dataset_key_value_service_sys_env;
+mod messages;
 
+pub use dataset_entry_service_impl::*;
+pub use dataset_env_var_encryption_key_rotator::*;
 pub use dataset_env_var_service_impl::*;
 pub use dataset_env_var_service_null::*;
 pub use dataset_key_value_service_impl::*;
 pub use dataset_key_value_service_sys_env::*;
+pub use messages::*;
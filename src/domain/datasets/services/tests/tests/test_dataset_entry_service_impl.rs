@@ -0,0 +1,238 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::assert_matches::assert_matches;
+use std::sync::Arc;
+
+use database_common::DatabasePaginationOpts;
+use dill::{Catalog, CatalogBuilder};
+use kamu_accounts::{Account, AuthenticationService, MockAuthenticationService};
+use kamu_core::{DatasetLifecycleMessage, DatasetVisibility};
+use kamu_datasets::DatasetEntryService;
+use kamu_datasets_inmem::InMemoryDatasetEntryRepository;
+use kamu_datasets_services::DatasetEntryServiceImpl;
+use messaging_outbox::{consume_deserialized_message, ConsumerFilter, Message};
+use opendatafabric::{AccountID, AccountName, DatasetAlias, DatasetID, DatasetName};
+use time_source::SystemTimeSourceDefault;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_log::test(tokio::test)]
+async fn test_dataset_entry_follows_lifecycle_messages() {
+    let alice = Harness::account("alice");
+    let harness = Harness::new(vec![alice.clone()]);
+
+    let (_, dataset_id) = DatasetID::new_generated_ed25519();
+    let foo = DatasetName::new_unchecked("foo");
+    let bar = DatasetName::new_unchecked("bar");
+
+    harness
+        .consume_message(DatasetLifecycleMessage::created(
+            dataset_id.clone(),
+            alice.id.clone(),
+            DatasetVisibility::Public,
+            foo.clone(),
+        ))
+        .await;
+
+    assert_matches!(
+        harness.dataset_entry_service.find_dataset_handle_by_id(&dataset_id).await,
+        Ok(Some(hdl))
+            if hdl.alias == DatasetAlias::new(Some(alice.account_name.clone()), foo.clone())
+    );
+    assert_matches!(
+        harness
+            .dataset_entry_service
+            .find_dataset_handle_by_alias(&alice.account_name, &foo)
+            .await,
+        Ok(Some(hdl)) if hdl.id == dataset_id
+    );
+
+    harness
+        .consume_message(DatasetLifecycleMessage::renamed(
+            dataset_id.clone(),
+            bar.clone(),
+        ))
+        .await;
+
+    assert_matches!(
+        harness
+            .dataset_entry_service
+            .find_dataset_handle_by_alias(&alice.account_name, &foo)
+            .await,
+        Ok(None)
+    );
+    assert_matches!(
+        harness
+            .dataset_entry_service
+            .find_dataset_handle_by_alias(&alice.account_name, &bar)
+            .await,
+        Ok(Some(hdl)) if hdl.id == dataset_id
+    );
+
+    harness
+        .consume_message(DatasetLifecycleMessage::deleted(dataset_id.clone()))
+        .await;
+
+    assert_matches!(
+        harness
+            .dataset_entry_service
+            .find_dataset_handle_by_id(&dataset_id)
+            .await,
+        Ok(None)
+    );
+
+    // Repeated deletion is tolerated
+    harness
+        .consume_message(DatasetLifecycleMessage::deleted(dataset_id.clone()))
+        .await;
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_log::test(tokio::test)]
+async fn test_list_dataset_handles() {
+    let alice = Harness::account("alice");
+    let bob = Harness::account("bob");
+    let harness = Harness::new(vec![alice.clone(), bob.clone()]);
+
+    for (owner, dataset_name) in [(&alice, "a1"), (&bob, "b1"), (&alice, "a2")] {
+        let (_, dataset_id) = DatasetID::new_generated_ed25519();
+        harness
+            .consume_message(DatasetLifecycleMessage::created(
+                dataset_id,
+                owner.id.clone(),
+                DatasetVisibility::Public,
+                DatasetName::new_unchecked(dataset_name),
+            ))
+            .await;
+    }
+
+    let first_page = DatabasePaginationOpts {
+        limit: 2,
+        offset: 0,
+    };
+
+    let listing = harness
+        .dataset_entry_service
+        .list_all_dataset_handles(&first_page)
+        .await
+        .unwrap();
+    assert_eq!(listing.total_count, 3);
+    assert_eq!(
+        listing
+            .list
+            .iter()
+            .map(|hdl| hdl.alias.to_string())
+            .collect::<Vec<_>>(),
+        vec!["alice/a1", "alice/a2"],
+    );
+
+    let listing = harness
+        .dataset_entry_service
+        .list_dataset_handles_by_owner(&bob.account_name, &first_page)
+        .await
+        .unwrap();
+    assert_eq!(listing.total_count, 1);
+    assert_eq!(
+        listing
+            .list
+            .iter()
+            .map(|hdl| hdl.alias.to_string())
+            .collect::<Vec<_>>(),
+        vec!["bob/b1"],
+    );
+
+    let listing = harness
+        .dataset_entry_service
+        .list_dataset_handles_by_owner(&AccountName::new_unchecked("unknown"), &first_page)
+        .await
+        .unwrap();
+    assert_eq!(listing.total_count, 0);
+    assert!(listing.list.is_empty());
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+struct DatasetEntryServiceHarness {
+    catalog: Catalog,
+    dataset_entry_service: Arc<dyn DatasetEntryService>,
+}
+
+type Harness = DatasetEntryServiceHarness;
+
+impl DatasetEntryServiceHarness {
+    fn new(accounts: Vec<Account>) -> Self {
+        let mut catalog_builder = CatalogBuilder::new();
+
+        catalog_builder
+            .add::<DatasetEntryServiceImpl>()
+            .add::<InMemoryDatasetEntryRepository>()
+            .add::<SystemTimeSourceDefault>()
+            .add_value(Self::mock_authentication_service(accounts))
+            .bind::<dyn AuthenticationService, MockAuthenticationService>();
+
+        let catalog = catalog_builder.build();
+
+        Self {
+            dataset_entry_service: catalog.get_one().unwrap(),
+            catalog,
+        }
+    }
+
+    fn account(account_name: &str) -> Account {
+        let (_, account_id) = AccountID::new_generated_ed25519();
+
+        Account {
+            id: account_id,
+            account_name: AccountName::new_unchecked(account_name),
+            ..Account::dummy()
+        }
+    }
+
+    fn mock_authentication_service(accounts: Vec<Account>) -> MockAuthenticationService {
+        let mut mock_authentication_service = MockAuthenticationService::new();
+
+        let accounts_clone = accounts.clone();
+        mock_authentication_service
+            .expect_find_account_id_by_name()
+            .returning(move |account_name| {
+                Ok(accounts_clone
+                    .iter()
+                    .find(|account| account.account_name == *account_name)
+                    .map(|account| account.id.clone()))
+            });
+
+        mock_authentication_service
+            .expect_accounts_by_ids()
+            .returning(move |account_ids| {
+                Ok(accounts
+                    .iter()
+                    .filter(|account| account_ids.contains(&account.id))
+                    .cloned()
+                    .collect())
+            });
+
+        mock_authentication_service
+    }
+
+    async fn consume_message<TMessage: Message + 'static>(&self, message: TMessage) {
+        let content_json = serde_json::to_string(&message).unwrap();
+
+        consume_deserialized_message::<TMessage>(
+            &self.catalog,
+            ConsumerFilter::AllConsumers,
+            &content_json,
+        )
+        .await
+        .unwrap();
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
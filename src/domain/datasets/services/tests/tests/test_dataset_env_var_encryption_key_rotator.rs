@@ -0,0 +1,149 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::sync::Arc;
+
+use chrono::Utc;
+use dill::CatalogBuilder;
+use kamu_datasets::{
+    DatasetEnvVar,
+    DatasetEnvVarEncryptionKeyConfig,
+    DatasetEnvVarEncryptionKeyRing,
+    DatasetEnvVarRepository,
+    DatasetEnvVarValue,
+    DatasetEnvVarsConfig,
+    DEFAULT_DATASET_ENV_VAR_ENCRYPTION_KEY_ID,
+    SAMPLE_DATASET_ENV_VAR_ENCRYPTION_KEY,
+};
+use kamu_datasets_inmem::InMemoryDatasetEnvVarRepository;
+use kamu_datasets_services::{
+    DatasetEnvVarEncryptionKeyRotationSummary,
+    DatasetEnvVarEncryptionKeyRotator,
+};
+use opendatafabric::DatasetID;
+use secrecy::Secret;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+const NEW_ENCRYPTION_KEY_ID: &str = "2024";
+const NEW_ENCRYPTION_KEY: &str = "aBcDeFgHiJkLmNoPqRsTuVwXyZ012345";
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_log::test(tokio::test)]
+async fn test_rotate_encryption_key() {
+    let harness = DatasetEnvVarEncryptionKeyRotatorHarness::new();
+
+    let foo_id = harness
+        .save_env_var("FOO", DatasetEnvVarValue::Secret(Secret::new("foo".into())))
+        .await;
+    // Secrets stored before key identifiers were introduced
+    let mut legacy_env_var = DatasetEnvVar::new(
+        "BAR",
+        Utc::now(),
+        &DatasetEnvVarValue::Secret(Secret::new("bar".into())),
+        &DatasetID::new_seeded_ed25519(b"foo"),
+        &DatasetEnvVarEncryptionKeyRing::sample(),
+    )
+    .unwrap();
+    legacy_env_var.encryption_key_id = None;
+    harness
+        .dataset_env_var_repo
+        .save_dataset_env_var(&legacy_env_var)
+        .await
+        .unwrap();
+    let baz_id = harness
+        .save_env_var("BAZ", DatasetEnvVarValue::Regular("baz".into()))
+        .await;
+
+    assert_eq!(
+        harness.rotator.rotate().await.unwrap(),
+        DatasetEnvVarEncryptionKeyRotationSummary {
+            reencrypted: 2,
+            up_to_date: 0,
+        }
+    );
+
+    // Secrets can now be decrypted without the previous key
+    let new_key_ring =
+        DatasetEnvVarEncryptionKeyRing::new(NEW_ENCRYPTION_KEY_ID, NEW_ENCRYPTION_KEY).unwrap();
+    for (dataset_env_var_id, expected_value) in
+        [(foo_id, "foo"), (legacy_env_var.id, "bar"), (baz_id, "baz")]
+    {
+        let dataset_env_var = harness
+            .dataset_env_var_repo
+            .get_dataset_env_var_by_id(&dataset_env_var_id)
+            .await
+            .unwrap();
+        assert_eq!(
+            dataset_env_var
+                .get_exposed_decrypted_value(&new_key_ring)
+                .unwrap(),
+            expected_value
+        );
+    }
+
+    // Nothing to do on the second run
+    assert_eq!(
+        harness.rotator.rotate().await.unwrap(),
+        DatasetEnvVarEncryptionKeyRotationSummary {
+            reencrypted: 0,
+            up_to_date: 2,
+        }
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+struct DatasetEnvVarEncryptionKeyRotatorHarness {
+    dataset_env_var_repo: Arc<dyn DatasetEnvVarRepository>,
+    rotator: Arc<DatasetEnvVarEncryptionKeyRotator>,
+}
+
+impl DatasetEnvVarEncryptionKeyRotatorHarness {
+    fn new() -> Self {
+        let catalog = CatalogBuilder::new()
+            .add::<InMemoryDatasetEnvVarRepository>()
+            .add::<DatasetEnvVarEncryptionKeyRotator>()
+            .add_value(DatasetEnvVarsConfig {
+                enabled: Some(true),
+                encryption_key: Some(NEW_ENCRYPTION_KEY.to_string()),
+                encryption_key_id: Some(NEW_ENCRYPTION_KEY_ID.to_string()),
+                previous_encryption_keys: Some(vec![DatasetEnvVarEncryptionKeyConfig {
+                    id: DEFAULT_DATASET_ENV_VAR_ENCRYPTION_KEY_ID.to_string(),
+                    key: SAMPLE_DATASET_ENV_VAR_ENCRYPTION_KEY.to_string(),
+                }]),
+            })
+            .build();
+
+        Self {
+            dataset_env_var_repo: catalog.get_one().unwrap(),
+            rotator: catalog.get_one().unwrap(),
+        }
+    }
+
+    async fn save_env_var(&self, key: &str, value: DatasetEnvVarValue) -> uuid::Uuid {
+        let dataset_env_var = DatasetEnvVar::new(
+            key,
+            Utc::now(),
+            &value,
+            &DatasetID::new_seeded_ed25519(b"foo"),
+            &DatasetEnvVarEncryptionKeyRing::sample(),
+        )
+        .unwrap();
+        self.dataset_env_var_repo
+            .save_dataset_env_var(&dataset_env_var)
+            .await
+            .unwrap();
+
+        dataset_env_var.id
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
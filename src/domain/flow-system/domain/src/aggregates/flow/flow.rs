@@ -26,6 +26,7 @@ impl Flow {
         flow_key: FlowKey,
         trigger: FlowTrigger,
         config_snapshot: Option<FlowConfigurationSnapshot>,
+        retry_policy: Option<RetryPolicy>,
     ) -> Self {
         Self(
             Aggregate::new(
@@ -36,6 +37,7 @@ impl Flow {
                     flow_key,
                     trigger,
                     config_snapshot,
+                    retry_policy,
                 },
             )
             .unwrap(),
@@ -108,18 +110,20 @@ impl Flow {
         self.apply(event)
     }
 
-    /// Task finished
+    /// Task finished. A failed task might be followed by the next attempt
     pub fn on_task_finished(
         &mut self,
         now: DateTime<Utc>,
         task_id: TaskID,
         task_outcome: TaskOutcome,
+        next_attempt_at: Option<DateTime<Utc>>,
     ) -> Result<(), ProjectionError<FlowState>> {
         let event = FlowEventTaskFinished {
             event_time: now,
             flow_id: self.flow_id,
             task_id,
             task_outcome,
+            next_attempt_at,
         };
         self.apply(event)
     }
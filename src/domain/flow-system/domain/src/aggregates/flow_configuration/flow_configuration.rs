@@ -35,6 +35,7 @@ impl FlowConfiguration {
                     flow_key,
                     paused,
                     rule,
+                    retry_policy: None,
                 },
             )
             .unwrap(),
@@ -53,6 +54,7 @@ impl FlowConfiguration {
             flow_key: self.flow_key.clone(),
             paused,
             rule: new_rule,
+            retry_policy: self.retry_policy.clone(),
         };
         self.apply(event)
     }
@@ -68,6 +70,7 @@ impl FlowConfiguration {
                 flow_key: self.flow_key.clone(),
                 paused: true,
                 rule: self.rule.clone(),
+                retry_policy: self.retry_policy.clone(),
             };
             self.apply(event)
         } else {
@@ -88,11 +91,28 @@ impl FlowConfiguration {
                 flow_key: self.flow_key.clone(),
                 paused: false,
                 rule: self.rule.clone(),
+                retry_policy: self.retry_policy.clone(),
             };
             self.apply(event)
         }
     }
 
+    /// Set or reset retry policy
+    pub fn set_retry_policy(
+        &mut self,
+        now: DateTime<Utc>,
+        retry_policy: Option<RetryPolicy>,
+    ) -> Result<(), ProjectionError<FlowConfigurationState>> {
+        let event = FlowConfigurationEventModified {
+            event_time: now,
+            flow_key: self.flow_key.clone(),
+            paused: !self.is_active(),
+            rule: self.rule.clone(),
+            retry_policy,
+        };
+        self.apply(event)
+    }
+
     /// Handle dataset removal
     pub fn notify_dataset_removed(
         &mut self,
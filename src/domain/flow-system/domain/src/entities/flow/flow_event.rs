@@ -10,12 +10,13 @@
 use chrono::{DateTime, Utc};
 use enum_variants::*;
 use kamu_task_system::{TaskID, TaskOutcome};
+use serde::{Deserialize, Serialize};
 
 use crate::*;
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum FlowEvent {
     /// Flow initiated
     Initiated(FlowEventInitiated),
@@ -35,18 +36,19 @@ pub enum FlowEvent {
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct FlowEventInitiated {
     pub event_time: DateTime<Utc>,
     pub flow_id: FlowID,
     pub flow_key: FlowKey,
     pub trigger: FlowTrigger,
     pub config_snapshot: Option<FlowConfigurationSnapshot>,
+    pub retry_policy: Option<RetryPolicy>,
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct FlowEventStartConditionUpdated {
     pub event_time: DateTime<Utc>,
     pub flow_id: FlowID,
@@ -56,7 +58,7 @@ pub struct FlowEventStartConditionUpdated {
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct FlowEventTriggerAdded {
     pub event_time: DateTime<Utc>,
     pub flow_id: FlowID,
@@ -65,7 +67,7 @@ pub struct FlowEventTriggerAdded {
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct FlowEventTaskScheduled {
     pub event_time: DateTime<Utc>,
     pub flow_id: FlowID,
@@ -74,7 +76,7 @@ pub struct FlowEventTaskScheduled {
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct FlowEventTaskRunning {
     pub event_time: DateTime<Utc>,
     pub flow_id: FlowID,
@@ -83,17 +85,19 @@ pub struct FlowEventTaskRunning {
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct FlowEventTaskFinished {
     pub event_time: DateTime<Utc>,
     pub flow_id: FlowID,
     pub task_id: TaskID,
     pub task_outcome: TaskOutcome,
+    /// Defined when the failed task is going to be retried
+    pub next_attempt_at: Option<DateTime<Utc>>,
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct FlowEventAborted {
     pub event_time: DateTime<Utc>,
     pub flow_id: FlowID,
@@ -102,6 +106,18 @@ pub struct FlowEventAborted {
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
 impl FlowEvent {
+    pub fn typename(&self) -> &'static str {
+        match self {
+            FlowEvent::Initiated(_) => "FlowEventInitiated",
+            FlowEvent::StartConditionUpdated(_) => "FlowEventStartConditionUpdated",
+            FlowEvent::TriggerAdded(_) => "FlowEventTriggerAdded",
+            FlowEvent::TaskScheduled(_) => "FlowEventTaskScheduled",
+            FlowEvent::TaskRunning(_) => "FlowEventTaskRunning",
+            FlowEvent::TaskFinished(_) => "FlowEventTaskFinished",
+            FlowEvent::Aborted(_) => "FlowEventAborted",
+        }
+    }
+
     pub fn flow_id(&self) -> FlowID {
         match self {
             FlowEvent::Initiated(e) => e.flow_id,
@@ -133,6 +149,7 @@ impl FlowEvent {
             | FlowEvent::TriggerAdded(_)
             | FlowEvent::TaskScheduled(_) => None,
             FlowEvent::TaskRunning(_) => Some(FlowStatus::Running),
+            FlowEvent::TaskFinished(e) if e.next_attempt_at.is_some() => Some(FlowStatus::Waiting),
             FlowEvent::TaskFinished(_) | FlowEvent::Aborted(_) => Some(FlowStatus::Finished),
         }
     }
@@ -8,11 +8,12 @@
 // by the Apache License, Version 2.0.
 
 use internal_error::InternalError;
+use serde::{Deserialize, Serialize};
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
 /// Uniquely identifies a flow
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 pub struct FlowID(u64);
 
 impl FlowID {
@@ -35,6 +36,14 @@ impl From<FlowID> for u64 {
     }
 }
 
+impl TryFrom<FlowID> for i64 {
+    type Error = std::num::TryFromIntError;
+
+    fn try_from(val: FlowID) -> Result<Self, Self::Error> {
+        i64::try_from(val.0)
+    }
+}
+
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
 pub type FlowIDStream<'a> =
@@ -10,11 +10,12 @@
 use kamu_core::{CompactionResult, PullResult, PullResultUpToDate};
 use kamu_task_system::{self as ts, ResetDatasetTaskError, UpdateDatasetTaskError};
 use opendatafabric::{DatasetID, Multihash};
+use serde::{Deserialize, Serialize};
 use ts::TaskError;
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum FlowOutcome {
     /// Flow succeeded
     Success(FlowResult),
@@ -35,7 +36,7 @@ impl FlowOutcome {
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum FlowResult {
     Empty,
     DatasetUpdate(FlowResultDatasetUpdate),
@@ -54,18 +55,46 @@ impl FlowResult {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum FlowError {
     Failed,
     RootDatasetCompacted(FlowRootDatasetCompactedError),
     ResetHeadNotFound,
+    GarbageCollectionFailed(FlowGarbageCollectionFailedError),
+    TimedOut,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+impl FlowError {
+    pub fn kind(&self) -> FlowErrorKind {
+        match self {
+            Self::Failed => FlowErrorKind::Failed,
+            Self::RootDatasetCompacted(_) => FlowErrorKind::RootDatasetCompacted,
+            Self::ResetHeadNotFound => FlowErrorKind::ResetHeadNotFound,
+            Self::GarbageCollectionFailed(_) => FlowErrorKind::GarbageCollectionFailed,
+            Self::TimedOut => FlowErrorKind::TimedOut,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct FlowRootDatasetCompactedError {
     pub dataset_id: DatasetID,
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FlowGarbageCollectionFailedError {
+    pub message: String,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum FlowErrorKind {
+    Failed,
+    RootDatasetCompacted,
+    ResetHeadNotFound,
+    GarbageCollectionFailed,
+    TimedOut,
+}
+
 impl From<&TaskError> for FlowError {
     fn from(value: &TaskError) -> Self {
         match value {
@@ -80,35 +109,41 @@ impl From<&TaskError> for FlowError {
             TaskError::ResetDatasetError(reset_dataset_error) => match reset_dataset_error {
                 ResetDatasetTaskError::ResetHeadNotFound => Self::ResetHeadNotFound,
             },
+            TaskError::GarbageCollectionError(err) => {
+                Self::GarbageCollectionFailed(FlowGarbageCollectionFailedError {
+                    message: err.message.clone(),
+                })
+            }
+            TaskError::TimedOut => Self::TimedOut,
         }
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum FlowResultDatasetUpdate {
     Changed(FlowResultDatasetUpdateChanged),
     UpToDate(FlowResultDatasetUpdateUpToDate),
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct FlowResultDatasetUpdateChanged {
     pub old_head: Option<Multihash>,
     pub new_head: Multihash,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct FlowResultDatasetUpdateUpToDate {
     pub uncacheable: bool,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct FlowResultDatasetCompact {
     pub new_head: Multihash,
     pub old_num_blocks: usize,
     pub new_num_blocks: usize,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct FlowResultDatasetReset {
     pub new_head: Multihash,
 }
@@ -161,6 +196,7 @@ impl From<ts::TaskResult> for FlowResult {
                     }),
                 }
             }
+            ts::TaskResult::GarbageCollectionResult(_) => Self::Empty,
         }
     }
 }
@@ -9,30 +9,34 @@
 
 use chrono::{DateTime, Duration, Utc};
 use kamu_task_system::TaskID;
+use serde::{Deserialize, Serialize};
 
 use crate::TransformRule;
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum FlowStartCondition {
     Schedule(FlowStartConditionSchedule),
     Throttling(FlowStartConditionThrottling),
     Batching(FlowStartConditionBatching),
     Executor(FlowStartConditionExecutor),
+    Retry(FlowStartConditionRetry),
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub struct FlowStartConditionSchedule {
     pub wake_up_at: DateTime<Utc>,
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[serde_with::serde_as]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub struct FlowStartConditionThrottling {
+    #[serde_as(as = "serde_with::DurationSecondsWithFrac<String>")]
     pub interval: Duration,
     pub wake_up_at: DateTime<Utc>,
     pub shifted_from: DateTime<Utc>,
@@ -40,7 +44,7 @@ pub struct FlowStartConditionThrottling {
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub struct FlowStartConditionBatching {
     pub active_transform_rule: TransformRule,
     pub batching_deadline: DateTime<Utc>,
@@ -48,9 +52,18 @@ pub struct FlowStartConditionBatching {
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub struct FlowStartConditionExecutor {
     pub task_id: TaskID,
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FlowStartConditionRetry {
+    /// Number of the upcoming attempt, starting from 1 for the initial one
+    pub attempt: u32,
+    pub wake_up_at: DateTime<Utc>,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
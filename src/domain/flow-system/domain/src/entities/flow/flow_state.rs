@@ -33,6 +33,8 @@ pub struct FlowState {
     pub outcome: Option<FlowOutcome>,
     /// Flow config snapshot on the moment when flow was initiated
     pub config_snapshot: Option<FlowConfigurationSnapshot>,
+    /// Retry policy on the moment when flow was initiated
+    pub retry_policy: Option<RetryPolicy>,
 }
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
@@ -68,6 +70,25 @@ impl FlowState {
             .as_ref()
             .and_then(|outcome| outcome.try_result_as_ref())
     }
+
+    /// Number of task attempts made so far
+    pub fn attempts(&self) -> u32 {
+        u32::try_from(self.task_ids.len()).unwrap()
+    }
+
+    /// Computes the time of the next attempt, if the task that has just failed
+    /// should be retried according to the retry policy
+    pub fn plan_next_attempt(
+        &self,
+        failed_at: DateTime<Utc>,
+        task_error: &ts::TaskError,
+    ) -> Option<DateTime<Utc>> {
+        self.retry_policy.as_ref().and_then(|retry_policy| {
+            retry_policy
+                .next_attempt_delay(self.attempts(), &task_error.into())
+                .map(|delay| failed_at + delay)
+        })
+    }
 }
 
 impl Projection for FlowState {
@@ -85,6 +106,7 @@ impl Projection for FlowState {
                     flow_key,
                     trigger,
                     config_snapshot,
+                    retry_policy,
                 }) => Ok(Self {
                     flow_id,
                     flow_key,
@@ -97,6 +119,7 @@ impl Projection for FlowState {
                     },
                     task_ids: vec![],
                     config_snapshot,
+                    retry_policy,
                     outcome: None,
                 }),
                 _ => Err(ProjectionError::new(None, event)),
@@ -173,11 +196,14 @@ impl Projection for FlowState {
                         event_time,
                         task_id,
                         ref task_outcome,
+                        next_attempt_at,
                         ..
                     }) => {
                         if !s.task_ids.contains(&task_id)
                             || s.timing.running_since.is_none()
                             || s.start_condition.is_some()
+                            || (next_attempt_at.is_some()
+                                && !matches!(task_outcome, ts::TaskOutcome::Failed(_)))
                         {
                             Err(ProjectionError::new(Some(s), event))
                         } else if s.outcome.is_some() {
@@ -201,11 +227,31 @@ impl Projection for FlowState {
                                     },
                                     ..s
                                 }),
-                                // TODO: support retries
-                                ts::TaskOutcome::Failed(task_error) => Ok(FlowState {
-                                    outcome: Some(FlowOutcome::Failed(task_error.into())),
-                                    ..s
-                                }),
+                                ts::TaskOutcome::Failed(task_error) => {
+                                    if let Some(wake_up_at) = next_attempt_at {
+                                        // Flow goes back to waiting for the next attempt
+                                        let attempt = s.attempts() + 1;
+                                        Ok(FlowState {
+                                            start_condition: Some(FlowStartCondition::Retry(
+                                                FlowStartConditionRetry {
+                                                    attempt,
+                                                    wake_up_at,
+                                                },
+                                            )),
+                                            timing: FlowTimingRecords {
+                                                awaiting_executor_since: None,
+                                                running_since: None,
+                                                finished_at: None,
+                                            },
+                                            ..s
+                                        })
+                                    } else {
+                                        Ok(FlowState {
+                                            outcome: Some(FlowOutcome::Failed(task_error.into())),
+                                            ..s
+                                        })
+                                    }
+                                }
                             }
                         }
                     }
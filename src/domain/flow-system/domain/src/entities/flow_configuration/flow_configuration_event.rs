@@ -30,6 +30,8 @@ pub struct FlowConfigurationEventCreated {
     pub flow_key: FlowKey,
     pub paused: bool,
     pub rule: FlowConfigurationRule,
+    #[serde(default)]
+    pub retry_policy: Option<RetryPolicy>,
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -40,6 +42,8 @@ pub struct FlowConfigurationEventModified {
     pub flow_key: FlowKey,
     pub paused: bool,
     pub rule: FlowConfigurationRule,
+    #[serde(default)]
+    pub retry_policy: Option<RetryPolicy>,
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
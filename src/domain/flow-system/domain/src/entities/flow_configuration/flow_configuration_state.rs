@@ -19,6 +19,8 @@ pub struct FlowConfigurationState {
     pub flow_key: FlowKey,
     /// Flow configuration rule
     pub rule: FlowConfigurationRule,
+    /// Retry policy for failed flows
+    pub retry_policy: Option<RetryPolicy>,
     /// Configuration status
     pub status: FlowConfigurationStatus,
 }
@@ -44,6 +46,7 @@ impl Projection for FlowConfigurationState {
                     flow_key,
                     paused,
                     rule,
+                    retry_policy,
                     ..
                 }) => Ok(Self {
                     flow_key,
@@ -53,6 +56,7 @@ impl Projection for FlowConfigurationState {
                         FlowConfigurationStatus::Active
                     },
                     rule,
+                    retry_policy,
                 }),
                 _ => Err(ProjectionError::new(None, event)),
             },
@@ -62,7 +66,12 @@ impl Projection for FlowConfigurationState {
                 match &event {
                     E::Created(_) => Err(ProjectionError::new(Some(s), event)),
 
-                    E::Modified(FlowConfigurationEventModified { paused, rule, .. }) => {
+                    E::Modified(FlowConfigurationEventModified {
+                        paused,
+                        rule,
+                        retry_policy,
+                        ..
+                    }) => {
                         // Note: when deleted dataset is re-added with the same id, we have to
                         // gracefully react on this, as if it wasn't a terminal state
                         Ok(FlowConfigurationState {
@@ -72,6 +81,7 @@ impl Projection for FlowConfigurationState {
                                 FlowConfigurationStatus::Active
                             },
                             rule: rule.clone(),
+                            retry_policy: retry_policy.clone(),
                             ..s
                         })
                     }
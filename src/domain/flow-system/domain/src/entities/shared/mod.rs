@@ -13,6 +13,7 @@ mod flow_run_snapshot;
 mod flow_type;
 mod ingest_rule;
 mod reset_rule;
+mod retry_policy;
 mod schedule;
 mod transform_rule;
 
@@ -22,5 +23,6 @@ pub use flow_run_snapshot::*;
 pub use flow_type::*;
 pub use ingest_rule::*;
 pub use reset_rule::*;
+pub use retry_policy::*;
 pub use schedule::*;
 pub use transform_rule::*;
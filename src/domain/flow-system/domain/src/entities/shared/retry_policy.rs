@@ -0,0 +1,335 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use chrono::Duration;
+use serde::{Deserialize, Serialize};
+use serde_with::serde_as;
+use thiserror::Error;
+
+use crate::{FlowError, FlowErrorKind};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[serde_as]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RetryPolicy {
+    max_attempts: u32,
+    #[serde_as(as = "serde_with::DurationSecondsWithFrac<String>")]
+    min_delay: Duration,
+    backoff_type: RetryBackoffType,
+    retry_on: RetryErrorFilter,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RetryBackoffType {
+    /// Every next attempt waits for the same delay
+    Fixed,
+    /// Every next attempt waits twice longer than the previous one
+    Exponential,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RetryErrorFilter {
+    /// Retry whatever error the task has failed with
+    AnyError,
+    /// Retry only the listed kinds of errors
+    SelectedErrors(Vec<FlowErrorKind>),
+}
+
+impl RetryPolicy {
+    const MAX_ATTEMPTS: u32 = 10;
+    const MAX_DELAY_HOURS: i64 = 24;
+
+    pub fn new_checked(
+        max_attempts: u32,
+        min_delay: Duration,
+        backoff_type: RetryBackoffType,
+        retry_on: RetryErrorFilter,
+    ) -> Result<Self, RetryPolicyValidationError> {
+        if max_attempts == 0 {
+            return Err(RetryPolicyValidationError::MaxAttemptsNotPositive);
+        }
+        if max_attempts > Self::MAX_ATTEMPTS {
+            return Err(RetryPolicyValidationError::MaxAttemptsAboveLimit);
+        }
+
+        let lower_delay_bound = Duration::try_seconds(0).unwrap();
+        if lower_delay_bound >= min_delay {
+            return Err(RetryPolicyValidationError::MinDelayNotPositive);
+        }
+
+        let upper_delay_bound = Duration::try_hours(Self::MAX_DELAY_HOURS).unwrap();
+        if min_delay > upper_delay_bound {
+            return Err(RetryPolicyValidationError::MinDelayAboveLimit);
+        }
+
+        if let RetryErrorFilter::SelectedErrors(error_kinds) = &retry_on
+            && error_kinds.is_empty()
+        {
+            return Err(RetryPolicyValidationError::NoErrorsSelected);
+        }
+
+        Ok(Self {
+            max_attempts,
+            min_delay,
+            backoff_type,
+            retry_on,
+        })
+    }
+
+    /// Maximum number of task attempts within a flow, including the first one
+    #[inline]
+    pub fn max_attempts(&self) -> u32 {
+        self.max_attempts
+    }
+
+    #[inline]
+    pub fn min_delay(&self) -> &Duration {
+        &self.min_delay
+    }
+
+    #[inline]
+    pub fn backoff_type(&self) -> RetryBackoffType {
+        self.backoff_type
+    }
+
+    #[inline]
+    pub fn retry_on(&self) -> &RetryErrorFilter {
+        &self.retry_on
+    }
+
+    /// Decides whether another attempt should follow after the given number of
+    /// failed attempts, with the last one failing with the given error.
+    /// Returns the delay before the next attempt, if it should happen
+    pub fn next_attempt_delay(&self, failed_attempts: u32, error: &FlowError) -> Option<Duration> {
+        if failed_attempts == 0 || failed_attempts >= self.max_attempts {
+            return None;
+        }
+
+        let error_matches = match &self.retry_on {
+            RetryErrorFilter::AnyError => true,
+            RetryErrorFilter::SelectedErrors(error_kinds) => error_kinds.contains(&error.kind()),
+        };
+        if !error_matches {
+            return None;
+        }
+
+        let max_delay = Duration::try_hours(Self::MAX_DELAY_HOURS).unwrap();
+        let delay = match self.backoff_type {
+            RetryBackoffType::Fixed => self.min_delay,
+            RetryBackoffType::Exponential => 2_i32
+                .checked_pow(failed_attempts - 1)
+                .and_then(|multiplier| self.min_delay.checked_mul(multiplier))
+                .map_or(max_delay, |delay| std::cmp::min(delay, max_delay)),
+        };
+
+        Some(delay)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Error, Debug)]
+pub enum RetryPolicyValidationError {
+    #[error("Maximum number of attempts must be a positive number")]
+    MaxAttemptsNotPositive,
+
+    #[error(
+        "Maximum number of attempts should not exceed {}",
+        RetryPolicy::MAX_ATTEMPTS
+    )]
+    MaxAttemptsAboveLimit,
+
+    #[error("Minimum delay between attempts should be positive")]
+    MinDelayNotPositive,
+
+    #[error(
+        "Minimum delay between attempts should not exceed {} hours",
+        RetryPolicy::MAX_DELAY_HOURS
+    )]
+    MinDelayAboveLimit,
+
+    #[error("At least one kind of errors to retry must be selected")]
+    NoErrorsSelected,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use std::assert_matches::assert_matches;
+
+    use chrono::TimeDelta;
+
+    use crate::{
+        FlowError,
+        FlowErrorKind,
+        RetryBackoffType,
+        RetryErrorFilter,
+        RetryPolicy,
+        RetryPolicyValidationError,
+    };
+
+    #[test]
+    fn test_good_retry_policy() {
+        assert_matches!(
+            RetryPolicy::new_checked(
+                3,
+                TimeDelta::try_minutes(1).unwrap(),
+                RetryBackoffType::Fixed,
+                RetryErrorFilter::AnyError
+            ),
+            Ok(_)
+        );
+        assert_matches!(
+            RetryPolicy::new_checked(
+                10,
+                TimeDelta::try_hours(24).unwrap(),
+                RetryBackoffType::Exponential,
+                RetryErrorFilter::SelectedErrors(vec![FlowErrorKind::Failed])
+            ),
+            Ok(_)
+        );
+    }
+
+    #[test]
+    fn test_bad_max_attempts() {
+        assert_matches!(
+            RetryPolicy::new_checked(
+                0,
+                TimeDelta::try_minutes(1).unwrap(),
+                RetryBackoffType::Fixed,
+                RetryErrorFilter::AnyError
+            ),
+            Err(RetryPolicyValidationError::MaxAttemptsNotPositive)
+        );
+        assert_matches!(
+            RetryPolicy::new_checked(
+                11,
+                TimeDelta::try_minutes(1).unwrap(),
+                RetryBackoffType::Fixed,
+                RetryErrorFilter::AnyError
+            ),
+            Err(RetryPolicyValidationError::MaxAttemptsAboveLimit)
+        );
+    }
+
+    #[test]
+    fn test_bad_min_delay() {
+        assert_matches!(
+            RetryPolicy::new_checked(
+                3,
+                TimeDelta::try_minutes(0).unwrap(),
+                RetryBackoffType::Fixed,
+                RetryErrorFilter::AnyError
+            ),
+            Err(RetryPolicyValidationError::MinDelayNotPositive)
+        );
+        assert_matches!(
+            RetryPolicy::new_checked(
+                3,
+                TimeDelta::try_hours(24).unwrap() + TimeDelta::nanoseconds(1),
+                RetryBackoffType::Fixed,
+                RetryErrorFilter::AnyError
+            ),
+            Err(RetryPolicyValidationError::MinDelayAboveLimit)
+        );
+    }
+
+    #[test]
+    fn test_empty_error_selection() {
+        assert_matches!(
+            RetryPolicy::new_checked(
+                3,
+                TimeDelta::try_minutes(1).unwrap(),
+                RetryBackoffType::Fixed,
+                RetryErrorFilter::SelectedErrors(vec![])
+            ),
+            Err(RetryPolicyValidationError::NoErrorsSelected)
+        );
+    }
+
+    #[test]
+    fn test_fixed_delays() {
+        let policy = RetryPolicy::new_checked(
+            3,
+            TimeDelta::try_minutes(5).unwrap(),
+            RetryBackoffType::Fixed,
+            RetryErrorFilter::AnyError,
+        )
+        .unwrap();
+
+        let five_minutes = TimeDelta::try_minutes(5).unwrap();
+        assert_eq!(
+            policy.next_attempt_delay(1, &FlowError::Failed),
+            Some(five_minutes)
+        );
+        assert_eq!(
+            policy.next_attempt_delay(2, &FlowError::Failed),
+            Some(five_minutes)
+        );
+        assert_eq!(policy.next_attempt_delay(3, &FlowError::Failed), None);
+    }
+
+    #[test]
+    fn test_exponential_delays() {
+        let policy = RetryPolicy::new_checked(
+            10,
+            TimeDelta::try_hours(4).unwrap(),
+            RetryBackoffType::Exponential,
+            RetryErrorFilter::AnyError,
+        )
+        .unwrap();
+
+        assert_eq!(
+            policy.next_attempt_delay(1, &FlowError::Failed),
+            TimeDelta::try_hours(4)
+        );
+        assert_eq!(
+            policy.next_attempt_delay(2, &FlowError::Failed),
+            TimeDelta::try_hours(8)
+        );
+        assert_eq!(
+            policy.next_attempt_delay(3, &FlowError::Failed),
+            TimeDelta::try_hours(16)
+        );
+        // Capped by the maximum delay
+        assert_eq!(
+            policy.next_attempt_delay(4, &FlowError::Failed),
+            TimeDelta::try_hours(24)
+        );
+        assert_eq!(
+            policy.next_attempt_delay(9, &FlowError::Failed),
+            TimeDelta::try_hours(24)
+        );
+        assert_eq!(policy.next_attempt_delay(10, &FlowError::Failed), None);
+    }
+
+    #[test]
+    fn test_selected_errors_only() {
+        let policy = RetryPolicy::new_checked(
+            3,
+            TimeDelta::try_minutes(1).unwrap(),
+            RetryBackoffType::Fixed,
+            RetryErrorFilter::SelectedErrors(vec![FlowErrorKind::Failed]),
+        )
+        .unwrap();
+
+        assert_eq!(
+            policy.next_attempt_delay(1, &FlowError::Failed),
+            TimeDelta::try_minutes(1)
+        );
+        assert_eq!(
+            policy.next_attempt_delay(1, &FlowError::ResetHeadNotFound),
+            None
+        );
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
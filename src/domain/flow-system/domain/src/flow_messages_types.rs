@@ -11,7 +11,7 @@ use chrono::{DateTime, Utc};
 use messaging_outbox::Message;
 use serde::{Deserialize, Serialize};
 
-use crate::{FlowConfigurationRule, FlowKey};
+use crate::{FlowConfigurationRule, FlowKey, RetryPolicy};
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
@@ -21,6 +21,8 @@ pub struct FlowConfigurationUpdatedMessage {
     pub flow_key: FlowKey,
     pub paused: bool,
     pub rule: FlowConfigurationRule,
+    #[serde(default)]
+    pub retry_policy: Option<RetryPolicy>,
 }
 
 impl Message for FlowConfigurationUpdatedMessage {}
@@ -37,11 +39,12 @@ impl Message for FlowServiceUpdatedMessage {}
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum FlowServiceUpdateDetails {
     Loaded,
     ExecutedTimeslot,
     FlowRunning,
+    FlowRetryScheduled,
     FlowFinished,
 }
 
@@ -20,7 +20,7 @@ use crate::*;
 #[async_trait::async_trait]
 pub trait FlowEventStore: EventStore<FlowState> {
     /// Generates new unique flow identifier
-    fn new_flow_id(&self) -> FlowID;
+    async fn new_flow_id(&self) -> Result<FlowID, InternalError>;
 
     /// Returns last run statistics for the dataset flow of certain type
     async fn get_dataset_flow_run_stats(
@@ -90,6 +90,10 @@ pub trait FlowEventStore: EventStore<FlowState> {
 
     /// Returns number of all flows
     async fn get_count_all_flows(&self) -> Result<usize, InternalError>;
+
+    /// Returns IDs of the flows, which are not finished yet, in chronological
+    /// order based on creation time
+    fn get_unfinished_flow_ids(&self) -> FlowIDStream<'_>;
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -18,6 +18,7 @@ use crate::{
     FlowConfigurationRule,
     FlowConfigurationState,
     FlowKey,
+    RetryPolicy,
     SystemFlowType,
 };
 
@@ -46,6 +47,14 @@ pub trait FlowConfigurationService: Sync + Send {
         rule: FlowConfigurationRule,
     ) -> Result<FlowConfigurationState, SetFlowConfigurationError>;
 
+    /// Set or reset retry policy of an existing flow configuration
+    async fn set_retry_policy(
+        &self,
+        request_time: DateTime<Utc>,
+        flow_key: FlowKey,
+        retry_policy: Option<RetryPolicy>,
+    ) -> Result<FlowConfigurationState, SetFlowRetryPolicyError>;
+
     /// Lists all flow configurations, which are currently enabled
     fn list_enabled_configurations(&self) -> FlowConfigurationStateStream;
 
@@ -107,6 +116,21 @@ pub enum SetFlowConfigurationError {
     Internal(#[from] InternalError),
 }
 
+#[derive(thiserror::Error, Debug)]
+pub enum SetFlowRetryPolicyError {
+    #[error(transparent)]
+    NotFound(#[from] FlowConfigurationNotFoundError),
+
+    #[error(transparent)]
+    Internal(#[from] InternalError),
+}
+
+#[derive(thiserror::Error, Debug)]
+#[error("Flow configuration {flow_key:?} not found")]
+pub struct FlowConfigurationNotFoundError {
+    pub flow_key: FlowKey,
+}
+
 #[derive(thiserror::Error, Debug)]
 pub enum FindFlowConfigurationError {
     #[error(transparent)]
@@ -130,6 +154,15 @@ impl From<TryLoadError<FlowConfigurationState>> for FindFlowConfigurationError {
     }
 }
 
+impl From<TryLoadError<FlowConfigurationState>> for SetFlowRetryPolicyError {
+    fn from(value: TryLoadError<FlowConfigurationState>) -> Self {
+        match value {
+            TryLoadError::ProjectionError(err) => Self::Internal(err.int_err()),
+            TryLoadError::Internal(err) => Self::Internal(err),
+        }
+    }
+}
+
 impl From<TryLoadError<FlowConfigurationState>> for SetFlowConfigurationError {
     fn from(value: TryLoadError<FlowConfigurationState>) -> Self {
         match value {
@@ -21,6 +21,7 @@ pub(crate) struct ActiveConfigsState {
     dataset_reset_rules: HashMap<FlowKeyDataset, ResetRule>,
     dataset_compaction_rules: HashMap<FlowKeyDataset, CompactionRule>,
     dataset_ingest_rules: HashMap<FlowKeyDataset, IngestRule>,
+    dataset_retry_policies: HashMap<FlowKeyDataset, RetryPolicy>,
 }
 
 impl ActiveConfigsState {
@@ -49,6 +50,20 @@ impl ActiveConfigsState {
         }
     }
 
+    pub fn set_dataset_retry_policy(
+        &mut self,
+        flow_key: &FlowKeyDataset,
+        retry_policy: Option<RetryPolicy>,
+    ) {
+        if let Some(retry_policy) = retry_policy {
+            self.dataset_retry_policies
+                .insert(flow_key.clone(), retry_policy);
+        } else {
+            self.dataset_retry_policies
+                .remove(flow_key.borrowed_key().as_trait());
+        }
+    }
+
     pub fn add_system_flow_config(&mut self, flow_type: SystemFlowType, schedule: Schedule) {
         self.system_schedules.insert(flow_type, schedule);
     }
@@ -75,6 +90,7 @@ impl ActiveConfigsState {
         self.dataset_transform_rules.remove(flow_key.as_trait());
         self.dataset_compaction_rules.remove(flow_key.as_trait());
         self.dataset_reset_rules.remove(flow_key.as_trait());
+        self.dataset_retry_policies.remove(flow_key.as_trait());
     }
 
     pub fn try_get_flow_schedule(&self, flow_key: &FlowKey) -> Option<Schedule> {
@@ -130,6 +146,19 @@ impl ActiveConfigsState {
             .cloned()
     }
 
+    pub fn try_get_retry_policy_by_key(&self, flow_key: &FlowKey) -> Option<RetryPolicy> {
+        match flow_key {
+            FlowKey::Dataset(flow_key) => self
+                .dataset_retry_policies
+                .get(
+                    BorrowedFlowKeyDataset::new(&flow_key.dataset_id, flow_key.flow_type)
+                        .as_trait(),
+                )
+                .cloned(),
+            FlowKey::System(_) => None,
+        }
+    }
+
     pub fn try_get_config_snapshot_by_key(
         &self,
         flow_key: &FlowKey,
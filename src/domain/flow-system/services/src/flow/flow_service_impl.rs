@@ -10,7 +10,7 @@
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
 use std::collections::HashSet;
-use std::sync::{Arc, Mutex};
+use std::sync::{Arc, Mutex, MutexGuard};
 
 use chrono::{DateTime, DurationRound, Utc};
 use database_common::DatabaseTransactionRunner;
@@ -51,11 +51,9 @@ use crate::{
 
 pub struct FlowServiceImpl {
     catalog: Catalog,
-    state: Arc<Mutex<State>>,
+    state: Arc<FlowServiceState>,
     run_config: Arc<FlowServiceRunConfig>,
-    flow_event_store: Arc<dyn FlowEventStore>,
     time_source: Arc<dyn SystemTimeSource>,
-    task_scheduler: Arc<dyn TaskScheduler>,
     dataset_changes_service: Arc<dyn DatasetChangesService>,
     dependency_graph_service: Arc<dyn DependencyGraphService>,
     dataset_ownership_service: Arc<dyn DatasetOwnershipService>,
@@ -63,6 +61,13 @@ pub struct FlowServiceImpl {
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
+/// State of the flow service, which is shared by its instances: the service
+/// itself is created within every transaction to access the transactional
+/// event store and task scheduler
+pub struct FlowServiceState {
+    state: Mutex<State>,
+}
+
 #[derive(Default)]
 struct State {
     active_configs: ActiveConfigsState,
@@ -71,6 +76,20 @@ struct State {
     running: bool,
 }
 
+#[component(pub)]
+#[scope(Singleton)]
+impl FlowServiceState {
+    pub fn new() -> Self {
+        Self {
+            state: Mutex::new(State::default()),
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, State> {
+        self.state.lock().unwrap()
+    }
+}
+
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
 #[component(pub)]
@@ -89,31 +108,35 @@ struct State {
     ],
     durability: MessageConsumptionDurability::Durable,
 })]
-#[scope(Singleton)]
 impl FlowServiceImpl {
     pub fn new(
         catalog: Catalog,
+        state: Arc<FlowServiceState>,
         run_config: Arc<FlowServiceRunConfig>,
-        flow_event_store: Arc<dyn FlowEventStore>,
         time_source: Arc<dyn SystemTimeSource>,
-        task_scheduler: Arc<dyn TaskScheduler>,
         dataset_changes_service: Arc<dyn DatasetChangesService>,
         dependency_graph_service: Arc<dyn DependencyGraphService>,
         dataset_ownership_service: Arc<dyn DatasetOwnershipService>,
     ) -> Self {
         Self {
             catalog,
-            state: Arc::new(Mutex::new(State::default())),
+            state,
             run_config,
-            flow_event_store,
             time_source,
-            task_scheduler,
             dataset_changes_service,
             dependency_graph_service,
             dataset_ownership_service,
         }
     }
 
+    fn flow_event_store(&self) -> Result<Arc<dyn FlowEventStore>, InternalError> {
+        self.catalog.get_one().int_err()
+    }
+
+    fn task_scheduler(&self) -> Result<Arc<dyn TaskScheduler>, InternalError> {
+        self.catalog.get_one().int_err()
+    }
+
     fn round_time(&self, time: DateTime<Utc>) -> Result<DateTime<Utc>, InternalError> {
         let rounded_time = time
             .duration_round(self.run_config.awaiting_step)
@@ -126,15 +149,18 @@ impl FlowServiceImpl {
         &self,
         timeslot_time: DateTime<Utc>,
     ) -> Result<(), InternalError> {
+        let flow_event_store = self.flow_event_store()?;
+
         let planned_flow_ids: Vec<_> = {
-            let mut state = self.state.lock().unwrap();
+            let mut state = self.state.lock();
             state.time_wheel.take_nearest_planned_flows()
         };
 
         let mut planned_task_futures = Vec::new();
         for planned_flow_id in planned_flow_ids {
+            let flow_event_store = flow_event_store.clone();
             planned_task_futures.push(async move {
-                let mut flow = Flow::load(planned_flow_id, self.flow_event_store.as_ref())
+                let mut flow = Flow::load(planned_flow_id, flow_event_store.as_ref())
                     .await
                     .int_err()?;
                 self.schedule_flow_task(&mut flow, timeslot_time).await?;
@@ -154,6 +180,69 @@ impl FlowServiceImpl {
         Ok(())
     }
 
+    #[tracing::instrument(level = "debug", skip_all)]
+    async fn restore_pending_flows(&self, start_time: DateTime<Utc>) -> Result<(), InternalError> {
+        let flow_event_store = self.flow_event_store()?;
+
+        let unfinished_flow_ids: Vec<_> = flow_event_store
+            .get_unfinished_flow_ids()
+            .try_collect()
+            .await?;
+
+        for flow_id in &unfinished_flow_ids {
+            let mut flow = Flow::load(*flow_id, flow_event_store.as_ref())
+                .await
+                .int_err()?;
+
+            self.state
+                .lock()
+                .pending_flows
+                .add_pending_flow(flow.flow_key.clone(), flow.flow_id);
+
+            // Flow with a scheduled task waits for the task progress
+            if flow.timing.awaiting_executor_since.is_some() {
+                let task_id = *flow.task_ids.last().unwrap();
+                self.state
+                    .lock()
+                    .pending_flows
+                    .track_flow_task(flow.flow_id, task_id);
+                continue;
+            }
+
+            match flow.start_condition {
+                Some(FlowStartCondition::Batching(batching)) => {
+                    self.evaluate_flow_transform_rule(
+                        start_time,
+                        &mut flow,
+                        &batching.active_transform_rule,
+                        start_time,
+                    )
+                    .await?;
+                    flow.save(flow_event_store.as_ref()).await.int_err()?;
+                }
+                Some(
+                    FlowStartCondition::Schedule(FlowStartConditionSchedule { wake_up_at })
+                    | FlowStartCondition::Throttling(FlowStartConditionThrottling {
+                        wake_up_at, ..
+                    })
+                    | FlowStartCondition::Retry(FlowStartConditionRetry { wake_up_at, .. }),
+                ) => {
+                    self.enqueue_flow(flow.flow_id, wake_up_at)?;
+                }
+                Some(FlowStartCondition::Executor(_)) | None => {
+                    self.enqueue_flow(flow.flow_id, start_time)?;
+                }
+            }
+        }
+
+        tracing::info!(
+            num_flows = unfinished_flow_ids.len(),
+            "Restored pending flows"
+        );
+
+        Ok(())
+    }
+
     #[tracing::instrument(level = "debug", skip_all)]
     async fn initialize_auto_polling_flows_from_configurations(
         &self,
@@ -180,10 +269,21 @@ impl FlowServiceImpl {
             .into_iter()
             .chain(non_schedule_configs.into_iter())
         {
+            // Flows restored from the database keep their plans
+            if self.find_pending_flow(&enabled_config.flow_key).is_some() {
+                self.register_flow_configuration(
+                    &enabled_config.flow_key,
+                    &enabled_config.rule,
+                    enabled_config.retry_policy,
+                );
+                continue;
+            }
+
             self.activate_flow_configuration(
                 start_time,
                 enabled_config.flow_key,
                 enabled_config.rule,
+                enabled_config.retry_policy,
             )
             .await?;
         }
@@ -191,21 +291,44 @@ impl FlowServiceImpl {
         Ok(())
     }
 
-    #[tracing::instrument(level = "trace", skip_all, fields(?flow_key, ?rule))]
+    fn register_flow_configuration(
+        &self,
+        flow_key: &FlowKey,
+        rule: &FlowConfigurationRule,
+        retry_policy: Option<RetryPolicy>,
+    ) {
+        let mut state = self.state.lock();
+        match flow_key {
+            FlowKey::Dataset(dataset_flow_key) => {
+                state
+                    .active_configs
+                    .add_dataset_flow_config(dataset_flow_key, rule.clone());
+                state
+                    .active_configs
+                    .set_dataset_retry_policy(dataset_flow_key, retry_policy);
+            }
+            FlowKey::System(system_flow_key) => {
+                if let FlowConfigurationRule::Schedule(schedule) = rule {
+                    state
+                        .active_configs
+                        .add_system_flow_config(system_flow_key.flow_type, schedule.clone());
+                }
+            }
+        }
+    }
+
+    #[tracing::instrument(level = "trace", skip_all, fields(?flow_key, ?rule, ?retry_policy))]
     async fn activate_flow_configuration(
         &self,
         start_time: DateTime<Utc>,
         flow_key: FlowKey,
         rule: FlowConfigurationRule,
+        retry_policy: Option<RetryPolicy>,
     ) -> Result<(), InternalError> {
-        match &flow_key {
-            FlowKey::Dataset(dataset_flow_key) => {
-                self.state
-                    .lock()
-                    .unwrap()
-                    .active_configs
-                    .add_dataset_flow_config(dataset_flow_key, rule.clone());
+        self.register_flow_configuration(&flow_key, &rule, retry_policy);
 
+        match &flow_key {
+            FlowKey::Dataset(_) => {
                 match &rule {
                     FlowConfigurationRule::TransformRule(_) => {
                         self.enqueue_auto_polling_flow_unconditionally(start_time, &flow_key)
@@ -227,14 +350,8 @@ impl FlowServiceImpl {
                     }
                 }
             }
-            FlowKey::System(system_flow_key) => {
+            FlowKey::System(_) => {
                 if let FlowConfigurationRule::Schedule(schedule) = &rule {
-                    self.state
-                        .lock()
-                        .unwrap()
-                        .active_configs
-                        .add_system_flow_config(system_flow_key.flow_type, schedule.clone());
-
                     self.enqueue_scheduled_auto_polling_flow(start_time, &flow_key, schedule)
                         .await?;
                 } else {
@@ -257,7 +374,6 @@ impl FlowServiceImpl {
         let maybe_active_schedule = self
             .state
             .lock()
-            .unwrap()
             .active_configs
             .try_get_flow_schedule(flow_key);
 
@@ -350,6 +466,8 @@ impl FlowServiceImpl {
         context: FlowTriggerContext,
         config_snapshot_maybe: Option<FlowConfigurationSnapshot>,
     ) -> Result<FlowState, InternalError> {
+        let flow_event_store = self.flow_event_store()?;
+
         // Query previous runs stats to determine activation time
         let flow_run_stats = self.flow_run_stats(flow_key).await?;
 
@@ -370,7 +488,7 @@ impl FlowServiceImpl {
             // Already pending flow
             Some(flow_id) => {
                 // Load, merge triggers, update activation time
-                let mut flow = Flow::load(flow_id, self.flow_event_store.as_ref())
+                let mut flow = Flow::load(flow_id, flow_event_store.as_ref())
                     .await
                     .int_err()?;
 
@@ -395,11 +513,15 @@ impl FlowServiceImpl {
                     }
                     FlowTriggerContext::Scheduled(_) | FlowTriggerContext::Unconditional => {
                         // Evaluate throttling condition: is new time earlier than planned?
-                        let planned_time = self
-                            .find_planned_flow_activation_time(flow.flow_id)
-                            .expect("Flow expected to have activation time by now");
-
-                        if throttling_boundary_time < planned_time {
+                        // Flows already waiting for the executor, as well as flows restored
+                        // while waiting for the batching condition, have no planned time
+                        let enqueue_earlier =
+                            match self.find_planned_flow_activation_time(flow.flow_id) {
+                                Some(planned_time) => throttling_boundary_time < planned_time,
+                                None => flow.timing.awaiting_executor_since.is_none(),
+                            };
+
+                        if enqueue_earlier {
                             // If so, enqueue the flow earlier
                             self.enqueue_flow(flow.flow_id, throttling_boundary_time)?;
 
@@ -415,24 +537,33 @@ impl FlowServiceImpl {
                     }
                 }
 
-                flow.save(self.flow_event_store.as_ref()).await.int_err()?;
+                flow.save(flow_event_store.as_ref()).await.int_err()?;
                 Ok(flow.into())
             }
 
             // Otherwise, initiate a new flow, and enqueue it in the time wheel
             None => {
                 // Initiate new flow
-                let config_snapshot_maybe = if config_snapshot_maybe.is_some() {
-                    config_snapshot_maybe
-                } else {
-                    self.state
-                        .lock()
-                        .unwrap()
-                        .active_configs
-                        .try_get_config_snapshot_by_key(flow_key)
+                let (config_snapshot_maybe, retry_policy_maybe) = {
+                    let state = self.state.lock();
+                    (
+                        if config_snapshot_maybe.is_some() {
+                            config_snapshot_maybe
+                        } else {
+                            state
+                                .active_configs
+                                .try_get_config_snapshot_by_key(flow_key)
+                        },
+                        state.active_configs.try_get_retry_policy_by_key(flow_key),
+                    )
                 };
                 let mut flow = self
-                    .make_new_flow(flow_key.clone(), trigger, config_snapshot_maybe)
+                    .make_new_flow(
+                        flow_key.clone(),
+                        trigger,
+                        config_snapshot_maybe,
+                        retry_policy_maybe,
+                    )
                     .await?;
 
                 match context {
@@ -493,7 +624,7 @@ impl FlowServiceImpl {
                     }
                 }
 
-                flow.save(self.flow_event_store.as_ref()).await.int_err()?;
+                flow.save(flow_event_store.as_ref()).await.int_err()?;
                 Ok(flow.into())
             }
         }
@@ -635,14 +766,13 @@ impl FlowServiceImpl {
     }
 
     fn find_pending_flow(&self, flow_key: &FlowKey) -> Option<FlowID> {
-        let state = self.state.lock().unwrap();
+        let state = self.state.lock();
         state.pending_flows.try_get_pending_flow(flow_key)
     }
 
     fn find_planned_flow_activation_time(&self, flow_id: FlowID) -> Option<DateTime<Utc>> {
         self.state
             .lock()
-            .unwrap()
             .time_wheel
             .get_planned_flow_activation_time(flow_id)
     }
@@ -653,16 +783,20 @@ impl FlowServiceImpl {
         flow_key: FlowKey,
         trigger: FlowTrigger,
         config_snapshot: Option<FlowConfigurationSnapshot>,
+        retry_policy: Option<RetryPolicy>,
     ) -> Result<Flow, InternalError> {
+        let flow_event_store = self.flow_event_store()?;
+
         let flow = Flow::new(
             self.time_source.now(),
-            self.flow_event_store.new_flow_id(),
+            flow_event_store.new_flow_id().await?,
             flow_key,
             trigger,
             config_snapshot,
+            retry_policy,
         );
 
-        let mut state = self.state.lock().unwrap();
+        let mut state = self.state.lock();
         state
             .pending_flows
             .add_pending_flow(flow.flow_key.clone(), flow.flow_id);
@@ -671,14 +805,16 @@ impl FlowServiceImpl {
     }
 
     async fn flow_run_stats(&self, flow_key: &FlowKey) -> Result<FlowRunStats, InternalError> {
+        let flow_event_store = self.flow_event_store()?;
+
         match flow_key {
             FlowKey::Dataset(fk_dataset) => {
-                self.flow_event_store
+                flow_event_store
                     .get_dataset_flow_run_stats(&fk_dataset.dataset_id, fk_dataset.flow_type)
                     .await
             }
             FlowKey::System(fk_system) => {
-                self.flow_event_store
+                flow_event_store
                     .get_system_flow_run_stats(fk_system.flow_type)
                     .await
             }
@@ -693,7 +829,6 @@ impl FlowServiceImpl {
     ) -> Result<(), InternalError> {
         self.state
             .lock()
-            .unwrap()
             .time_wheel
             .activate_at(activation_time, flow_id);
         Ok(())
@@ -705,11 +840,13 @@ impl FlowServiceImpl {
         flow: &mut Flow,
         schedule_time: DateTime<Utc>,
     ) -> Result<TaskID, InternalError> {
+        let flow_event_store = self.flow_event_store()?;
+
         let logical_plan =
             self.make_task_logical_plan(&flow.flow_key, flow.config_snapshot.as_ref())?;
 
         let task = self
-            .task_scheduler
+            .task_scheduler()?
             .create_task(logical_plan)
             .await
             .int_err()?;
@@ -724,9 +861,9 @@ impl FlowServiceImpl {
 
         flow.on_task_scheduled(schedule_time, task.task_id)
             .int_err()?;
-        flow.save(self.flow_event_store.as_ref()).await.int_err()?;
+        flow.save(flow_event_store.as_ref()).await.int_err()?;
 
-        let mut state = self.state.lock().unwrap();
+        let mut state = self.state.lock();
         state
             .pending_flows
             .track_flow_task(flow.flow_id, task.task_id);
@@ -735,8 +872,10 @@ impl FlowServiceImpl {
     }
 
     async fn abort_flow(&self, flow_id: FlowID) -> Result<(), InternalError> {
+        let flow_event_store = self.flow_event_store()?;
+
         // Mark flow as aborted
-        let mut flow = Flow::load(flow_id, self.flow_event_store.as_ref())
+        let mut flow = Flow::load(flow_id, flow_event_store.as_ref())
             .await
             .int_err()?;
 
@@ -744,19 +883,22 @@ impl FlowServiceImpl {
     }
 
     async fn abort_flow_impl(&self, flow: &mut Flow) -> Result<(), InternalError> {
+        let flow_event_store = self.flow_event_store()?;
+
         // Abort flow itself
         flow.abort(self.time_source.now()).int_err()?;
-        flow.save(self.flow_event_store.as_ref()).await.int_err()?;
+        flow.save(flow_event_store.as_ref()).await.int_err()?;
 
         // Cancel associated tasks, but first drop task -> flow associations
         {
-            let mut state = self.state.lock().unwrap();
+            let mut state = self.state.lock();
             for task_id in &flow.task_ids {
                 state.pending_flows.untrack_flow_by_task(*task_id);
             }
         }
+        let task_scheduler = self.task_scheduler()?;
         for task_id in &flow.task_ids {
-            self.task_scheduler.cancel_task(*task_id).await.int_err()?;
+            task_scheduler.cancel_task(*task_id).await.int_err()?;
         }
 
         Ok(())
@@ -818,16 +960,11 @@ impl FlowServiceImpl {
                     InternalError::bail("Reset flow cannot be called without configuration")
                 }
             },
-            FlowKey::System(flow_key) => {
-                match flow_key.flow_type {
-                    // TODO: replace on correct logical plan
-                    SystemFlowType::GC => Ok(LogicalPlan::Probe(Probe {
-                        dataset_id: None,
-                        busy_time: Some(std::time::Duration::from_secs(20)),
-                        end_with_outcome: Some(TaskOutcome::Success(TaskResult::Empty)),
-                    })),
-                }
-            }
+            FlowKey::System(flow_key) => match flow_key.flow_type {
+                SystemFlowType::GC => Ok(LogicalPlan::GarbageCollection(GarbageCollection {
+                    dry_run: false,
+                })),
+            },
         }
     }
 
@@ -853,7 +990,7 @@ impl FlowServiceImpl {
 
         match self.classify_dependent_trigger_type(fk_dataset.flow_type, maybe_config_snapshot) {
             DownstreamDependencyTriggerType::TriggerAllEnabledExecuteTransform => {
-                let guard = self.state.lock().unwrap();
+                let guard = self.state.lock();
                 for dataset_id in dependent_dataset_ids {
                     if let Some(transform_rule) =
                         guard.active_configs.try_get_dataset_transform_rule(
@@ -958,34 +1095,43 @@ impl FlowService for FlowServiceImpl {
     #[tracing::instrument(level = "info", skip_all)]
     async fn run(&self, planned_start_time: DateTime<Utc>) -> Result<(), InternalError> {
         // Mark running started
-        self.state.lock().unwrap().running = true;
+        self.state.lock().running = true;
 
-        // Initial scheduling
+        // Restore flows, which were pending before the restart, and make initial
+        // scheduling. The work is delegated to the service instance created within
+        // the transaction to access transactional repositories
+        let start_time = self.round_time(planned_start_time)?;
         DatabaseTransactionRunner::new(self.catalog.clone())
-            .transactional_with2(
-                |flow_configuration_service: Arc<dyn FlowConfigurationService>,
-                 outbox: Arc<dyn Outbox>| async move {
-                    let start_time = self.round_time(planned_start_time)?;
-                    self.initialize_auto_polling_flows_from_configurations(
+            .transactional(|transactional_catalog| async move {
+                let flow_service = transactional_catalog
+                    .get_one::<FlowServiceImpl>()
+                    .int_err()?;
+                let flow_configuration_service = transactional_catalog
+                    .get_one::<dyn FlowConfigurationService>()
+                    .int_err()?;
+                let outbox = transactional_catalog.get_one::<dyn Outbox>().int_err()?;
+
+                flow_service.restore_pending_flows(start_time).await?;
+                flow_service
+                    .initialize_auto_polling_flows_from_configurations(
                         flow_configuration_service.as_ref(),
                         start_time,
                     )
                     .await?;
 
-                    // Publish progress event
-                    outbox
-                        .post_message(
-                            MESSAGE_PRODUCER_KAMU_FLOW_SERVICE,
-                            FlowServiceUpdatedMessage {
-                                update_time: start_time,
-                                update_details: FlowServiceUpdateDetails::Loaded,
-                            },
-                        )
-                        .await?;
+                // Publish progress event
+                outbox
+                    .post_message(
+                        MESSAGE_PRODUCER_KAMU_FLOW_SERVICE,
+                        FlowServiceUpdatedMessage {
+                            update_time: start_time,
+                            update_details: FlowServiceUpdateDetails::Loaded,
+                        },
+                    )
+                    .await?;
 
-                    Ok(())
-                },
-            )
+                Ok::<_, InternalError>(())
+            })
             .await?;
 
         // Main scanning loop
@@ -997,7 +1143,7 @@ impl FlowService for FlowServiceImpl {
 
             // Do we have a timeslot scheduled?
             let maybe_nearest_activation_time = {
-                let state = self.state.lock().unwrap();
+                let state = self.state.lock();
                 state.time_wheel.nearest_activation_moment()
             };
 
@@ -1005,12 +1151,19 @@ impl FlowService for FlowServiceImpl {
             if let Some(nearest_activation_time) = maybe_nearest_activation_time
                 && nearest_activation_time <= current_time
             {
-                // Run scheduling for current time slot. Should not throw any errors
-                self.run_current_timeslot(nearest_activation_time).await?;
-
-                // Publish progress event
                 DatabaseTransactionRunner::new(self.catalog.clone())
-                    .transactional_with(|outbox: Arc<dyn Outbox>| async move {
+                    .transactional(|transactional_catalog| async move {
+                        let flow_service = transactional_catalog
+                            .get_one::<FlowServiceImpl>()
+                            .int_err()?;
+                        let outbox = transactional_catalog.get_one::<dyn Outbox>().int_err()?;
+
+                        // Run scheduling for current time slot. Should not throw any errors
+                        flow_service
+                            .run_current_timeslot(nearest_activation_time)
+                            .await?;
+
+                        // Publish progress event
                         outbox
                             .post_message(
                                 MESSAGE_PRODUCER_KAMU_FLOW_SERVICE,
@@ -1019,7 +1172,9 @@ impl FlowService for FlowServiceImpl {
                                     update_details: FlowServiceUpdateDetails::ExecutedTimeslot,
                                 },
                             )
-                            .await
+                            .await?;
+
+                        Ok::<_, InternalError>(())
                     })
                     .await?;
             }
@@ -1066,23 +1221,23 @@ impl FlowService for FlowServiceImpl {
         filters: DatasetFlowFilters,
         pagination: FlowPaginationOpts,
     ) -> Result<FlowStateListing, ListFlowsByDatasetError> {
-        let total_count = self
-            .flow_event_store
+        let flow_event_store = self.flow_event_store()?;
+
+        let total_count = flow_event_store
             .get_count_flows_by_dataset(dataset_id, &filters)
             .await?;
 
         let dataset_id = dataset_id.clone();
 
         let matched_stream = Box::pin(async_stream::try_stream! {
-            let relevant_flow_ids: Vec<_> = self
-                .flow_event_store
+            let relevant_flow_ids: Vec<_> = flow_event_store
                 .get_all_flow_ids_by_dataset(&dataset_id, filters, pagination)
                 .try_collect()
                 .await?;
 
             // TODO: implement batch loading
             for flow_id in relevant_flow_ids {
-                let flow = Flow::load(flow_id, self.flow_event_store.as_ref()).await.int_err()?;
+                let flow = Flow::load(flow_id, flow_event_store.as_ref()).await.int_err()?;
                 yield flow.into();
             }
         });
@@ -1100,11 +1255,20 @@ impl FlowService for FlowServiceImpl {
         &self,
         dataset_id: &DatasetID,
     ) -> Result<FlowInitiatorListing, ListFlowsByDatasetError> {
-        Ok(FlowInitiatorListing {
-            matched_stream: self
-                .flow_event_store
-                .get_unique_flow_initiator_ids_by_dataset(dataset_id),
-        })
+        let flow_event_store = self.flow_event_store()?;
+
+        let dataset_id = dataset_id.clone();
+
+        let matched_stream = Box::pin(async_stream::try_stream! {
+            let mut initiators_stream =
+                flow_event_store.get_unique_flow_initiator_ids_by_dataset(&dataset_id);
+
+            while let Some(initiator_id) = initiators_stream.try_next().await? {
+                yield initiator_id;
+            }
+        });
+
+        Ok(FlowInitiatorListing { matched_stream })
     }
 
     /// Returns states of flows associated with a given account
@@ -1117,6 +1281,8 @@ impl FlowService for FlowServiceImpl {
         filters: AccountFlowFilters,
         pagination: FlowPaginationOpts,
     ) -> Result<FlowStateListing, ListFlowsByDatasetError> {
+        let flow_event_store = self.flow_event_store()?;
+
         let owned_dataset_ids = self
             .dataset_ownership_service
             .get_owned_datasets(account_id)
@@ -1140,8 +1306,7 @@ impl FlowService for FlowServiceImpl {
         };
 
         for dataset_id in &filtered_dataset_ids {
-            total_count += self
-                .flow_event_store
+            total_count += flow_event_store
                 .get_count_flows_by_dataset(dataset_id, &dataset_flow_filters)
                 .await?;
         }
@@ -1149,8 +1314,7 @@ impl FlowService for FlowServiceImpl {
         let account_dataset_ids: HashSet<DatasetID> = HashSet::from_iter(filtered_dataset_ids);
 
         let matched_stream = Box::pin(async_stream::try_stream! {
-            let relevant_flow_ids: Vec<_> = self
-                .flow_event_store
+            let relevant_flow_ids: Vec<_> = flow_event_store
                 .get_all_flow_ids_by_datasets(account_dataset_ids, &dataset_flow_filters, pagination)
                 .try_collect()
                 .await
@@ -1158,7 +1322,7 @@ impl FlowService for FlowServiceImpl {
 
             // TODO: implement batch loading
             for flow_id in relevant_flow_ids {
-                let flow = Flow::load(flow_id, self.flow_event_store.as_ref()).await.int_err()?;
+                let flow = Flow::load(flow_id, flow_event_store.as_ref()).await.int_err()?;
                 yield flow.into();
             }
         });
@@ -1176,6 +1340,8 @@ impl FlowService for FlowServiceImpl {
         &self,
         account_id: &AccountID,
     ) -> Result<FlowDatasetListing, ListFlowsByDatasetError> {
+        let flow_event_store = self.flow_event_store()?;
+
         let owned_dataset_ids = self
             .dataset_ownership_service
             .get_owned_datasets(account_id)
@@ -1184,8 +1350,7 @@ impl FlowService for FlowServiceImpl {
 
         let matched_stream = Box::pin(async_stream::try_stream! {
             for dataset_id in &owned_dataset_ids {
-                let dataset_flows_count = self
-                    .flow_event_store
+                let dataset_flows_count = flow_event_store
                     .get_count_flows_by_dataset(dataset_id, &Default::default())
                     .await?;
 
@@ -1207,22 +1372,22 @@ impl FlowService for FlowServiceImpl {
         filters: SystemFlowFilters,
         pagination: FlowPaginationOpts,
     ) -> Result<FlowStateListing, ListSystemFlowsError> {
-        let total_count = self
-            .flow_event_store
+        let flow_event_store = self.flow_event_store()?;
+
+        let total_count = flow_event_store
             .get_count_system_flows(&filters)
             .await
             .int_err()?;
 
         let matched_stream = Box::pin(async_stream::try_stream! {
-            let relevant_flow_ids: Vec<_> = self
-                .flow_event_store
+            let relevant_flow_ids: Vec<_> = flow_event_store
                 .get_all_system_flow_ids(filters, pagination)
                 .try_collect()
                 .await?;
 
             // TODO: implement batch loading
             for flow_id in relevant_flow_ids {
-                let flow = Flow::load(flow_id, self.flow_event_store.as_ref()).await.int_err()?;
+                let flow = Flow::load(flow_id, flow_event_store.as_ref()).await.int_err()?;
                 yield flow.into();
             }
         });
@@ -1240,18 +1405,19 @@ impl FlowService for FlowServiceImpl {
         &self,
         pagination: FlowPaginationOpts,
     ) -> Result<FlowStateListing, ListFlowsError> {
-        let total_count = self.flow_event_store.get_count_all_flows().await?;
+        let flow_event_store = self.flow_event_store()?;
+
+        let total_count = flow_event_store.get_count_all_flows().await?;
 
         let matched_stream = Box::pin(async_stream::try_stream! {
-            let all_flows: Vec<_> = self
-                .flow_event_store
+            let all_flows: Vec<_> = flow_event_store
                 .get_all_flow_ids(pagination)
                 .try_collect()
                 .await?;
 
             // TODO: implement batch loading
             for flow_id in all_flows {
-                let flow = Flow::load(flow_id, self.flow_event_store.as_ref()).await.int_err()?;
+                let flow = Flow::load(flow_id, flow_event_store.as_ref()).await.int_err()?;
                 yield flow.into();
             }
         });
@@ -1265,7 +1431,9 @@ impl FlowService for FlowServiceImpl {
     /// Returns current state of a given flow
     #[tracing::instrument(level = "debug", skip_all, fields(%flow_id))]
     async fn get_flow(&self, flow_id: FlowID) -> Result<FlowState, GetFlowError> {
-        let flow = Flow::load(flow_id, self.flow_event_store.as_ref()).await?;
+        let flow_event_store = self.flow_event_store()?;
+
+        let flow = Flow::load(flow_id, flow_event_store.as_ref()).await?;
         Ok(flow.into())
     }
 
@@ -1279,7 +1447,9 @@ impl FlowService for FlowServiceImpl {
         &self,
         flow_id: FlowID,
     ) -> Result<FlowState, CancelScheduledTasksError> {
-        let mut flow = Flow::load(flow_id, self.flow_event_store.as_ref()).await?;
+        let flow_event_store = self.flow_event_store()?;
+
+        let mut flow = Flow::load(flow_id, flow_event_store.as_ref()).await?;
 
         // Cancel tasks for flows in Waiting/Running state.
         // Ignore in Finished state
@@ -1301,7 +1471,7 @@ impl FlowService for FlowServiceImpl {
 impl FlowServiceTestDriver for FlowServiceImpl {
     /// Pretends running started
     fn mimic_running_started(&self) {
-        let mut state = self.state.lock().unwrap();
+        let mut state = self.state.lock();
         state.running = true;
     }
 
@@ -1312,12 +1482,14 @@ impl FlowServiceTestDriver for FlowServiceImpl {
         flow_id: FlowID,
         schedule_time: DateTime<Utc>,
     ) -> Result<TaskID, InternalError> {
+        let flow_event_store = self.flow_event_store()?;
+
         {
-            let mut state = self.state.lock().unwrap();
+            let mut state = self.state.lock();
             state.time_wheel.cancel_flow_activation(flow_id).int_err()?;
         }
 
-        let mut flow = Flow::load(flow_id, self.flow_event_store.as_ref())
+        let mut flow = Flow::load(flow_id, flow_event_store.as_ref())
             .await
             .int_err()?;
         let task_id = self.schedule_flow_task(&mut flow, schedule_time).await?;
@@ -1339,11 +1511,13 @@ impl MessageConsumerT<TaskProgressMessage> for FlowServiceImpl {
         target_catalog: &Catalog,
         message: &TaskProgressMessage,
     ) -> Result<(), InternalError> {
+        let flow_event_store = self.flow_event_store()?;
+
         match message {
             TaskProgressMessage::Running(message) => {
                 // Is this a task associated with flows?
                 let maybe_flow_id = {
-                    let state = self.state.lock().unwrap();
+                    let state = self.state.lock();
                     if !state.running {
                         // Abort if running hasn't started yet
                         return Ok(());
@@ -1352,12 +1526,12 @@ impl MessageConsumerT<TaskProgressMessage> for FlowServiceImpl {
                 };
 
                 if let Some(flow_id) = maybe_flow_id {
-                    let mut flow = Flow::load(flow_id, self.flow_event_store.as_ref())
+                    let mut flow = Flow::load(flow_id, flow_event_store.as_ref())
                         .await
                         .int_err()?;
                     flow.on_task_running(message.event_time, message.task_id)
                         .int_err()?;
-                    flow.save(self.flow_event_store.as_ref()).await.int_err()?;
+                    flow.save(flow_event_store.as_ref()).await.int_err()?;
 
                     let outbox = target_catalog.get_one::<dyn Outbox>().unwrap();
                     outbox
@@ -1374,7 +1548,7 @@ impl MessageConsumerT<TaskProgressMessage> for FlowServiceImpl {
             TaskProgressMessage::Finished(message) => {
                 // Is this a task associated with flows?
                 let maybe_flow_id = {
-                    let state = self.state.lock().unwrap();
+                    let state = self.state.lock();
                     if !state.running {
                         // Abort if running hasn't started yet
                         return Ok(());
@@ -1385,21 +1559,48 @@ impl MessageConsumerT<TaskProgressMessage> for FlowServiceImpl {
                 let finish_time = self.round_time(message.event_time)?;
 
                 if let Some(flow_id) = maybe_flow_id {
-                    let mut flow = Flow::load(flow_id, self.flow_event_store.as_ref())
+                    let mut flow = Flow::load(flow_id, flow_event_store.as_ref())
                         .await
                         .int_err()?;
+
+                    // In case of failure, consult the retry policy of the flow
+                    let next_attempt_at = match &message.outcome {
+                        TaskOutcome::Failed(task_error) => {
+                            flow.plan_next_attempt(finish_time, task_error)
+                        }
+                        TaskOutcome::Success(_) | TaskOutcome::Cancelled => None,
+                    };
+
                     flow.on_task_finished(
                         message.event_time,
                         message.task_id,
                         message.outcome.clone(),
+                        next_attempt_at,
                     )
                     .int_err()?;
-                    flow.save(self.flow_event_store.as_ref()).await.int_err()?;
+                    flow.save(flow_event_store.as_ref()).await.int_err()?;
 
                     {
-                        let mut state = self.state.lock().unwrap();
+                        let mut state = self.state.lock();
                         state.pending_flows.untrack_flow_by_task(message.task_id);
-                        state.pending_flows.drop_pending_flow(&flow.flow_key);
+
+                        if let Some(next_attempt_at) = next_attempt_at {
+                            // The flow stays pending until the next attempt.
+                            // Its former activation has already been consumed
+                            if state
+                                .time_wheel
+                                .get_planned_flow_activation_time(flow.flow_id)
+                                .is_some()
+                            {
+                                state
+                                    .time_wheel
+                                    .cancel_flow_activation(flow.flow_id)
+                                    .int_err()?;
+                            }
+                            state.time_wheel.activate_at(next_attempt_at, flow.flow_id);
+                        } else {
+                            state.pending_flows.drop_pending_flow(&flow.flow_key);
+                        }
                     }
 
                     // In case of success:
@@ -1426,18 +1627,23 @@ impl MessageConsumerT<TaskProgressMessage> for FlowServiceImpl {
                         .await?;
                     }
 
+                    // A flow with a scheduled retry is not finished yet
+                    let update_details = if next_attempt_at.is_some() {
+                        FlowServiceUpdateDetails::FlowRetryScheduled
+                    } else {
+                        FlowServiceUpdateDetails::FlowFinished
+                    };
+
                     let outbox = target_catalog.get_one::<dyn Outbox>().unwrap();
                     outbox
                         .post_message(
                             MESSAGE_PRODUCER_KAMU_FLOW_SERVICE,
                             FlowServiceUpdatedMessage {
                                 update_time: message.event_time,
-                                update_details: FlowServiceUpdateDetails::FlowFinished,
+                                update_details,
                             },
                         )
                         .await?;
-
-                    // TODO: retry logic in case of failed outcome
                 }
             }
         }
@@ -1458,7 +1664,7 @@ impl MessageConsumerT<FlowConfigurationUpdatedMessage> for FlowServiceImpl {
     ) -> Result<(), InternalError> {
         if message.paused {
             let maybe_pending_flow_id = {
-                let mut state = self.state.lock().unwrap();
+                let mut state = self.state.lock();
                 if !state.running {
                     // Abort if running hasn't started yet
                     return Ok(());
@@ -1482,7 +1688,7 @@ impl MessageConsumerT<FlowConfigurationUpdatedMessage> for FlowServiceImpl {
             }
         } else {
             {
-                let state = self.state.lock().unwrap();
+                let state = self.state.lock();
                 if !state.running {
                     // Abort if running hasn't started yet
                     return Ok(());
@@ -1494,6 +1700,7 @@ impl MessageConsumerT<FlowConfigurationUpdatedMessage> for FlowServiceImpl {
                 activation_time,
                 message.flow_key.clone(),
                 message.rule.clone(),
+                message.retry_policy.clone(),
             )
             .await?;
         }
@@ -1512,10 +1719,12 @@ impl MessageConsumerT<DatasetLifecycleMessage> for FlowServiceImpl {
         _: &Catalog,
         message: &DatasetLifecycleMessage,
     ) -> Result<(), InternalError> {
+        let flow_event_store = self.flow_event_store()?;
+
         match message {
             DatasetLifecycleMessage::Deleted(message) => {
                 let flow_ids_2_abort = {
-                    let mut state = self.state.lock().unwrap();
+                    let mut state = self.state.lock();
                     if !state.running {
                         // Abort if running hasn't started yet
                         return Ok(());
@@ -1545,11 +1754,11 @@ impl MessageConsumerT<DatasetLifecycleMessage> for FlowServiceImpl {
 
                 // Abort matched flows
                 for flow_id in flow_ids_2_abort {
-                    let mut flow = Flow::load(flow_id, self.flow_event_store.as_ref())
+                    let mut flow = Flow::load(flow_id, flow_event_store.as_ref())
                         .await
                         .int_err()?;
                     flow.abort(self.time_source.now()).int_err()?;
-                    flow.save(self.flow_event_store.as_ref()).await.int_err()?;
+                    flow.save(flow_event_store.as_ref()).await.int_err()?;
                 }
 
                 // Not deleting task->update association, it should be safe.
@@ -1564,7 +1773,9 @@ impl MessageConsumerT<DatasetLifecycleMessage> for FlowServiceImpl {
             }
 
             DatasetLifecycleMessage::Created(_)
-            | DatasetLifecycleMessage::DependenciesUpdated(_) => {
+            | DatasetLifecycleMessage::DependenciesUpdated(_)
+            | DatasetLifecycleMessage::PollingSourceDisabled(_)
+            | DatasetLifecycleMessage::Renamed(_) => {
                 // No action required
             }
         }
@@ -72,6 +72,7 @@ impl FlowConfigurationServiceImpl {
             flow_key: state.flow_key.clone(),
             paused: !state.is_active(),
             rule: state.rule.clone(),
+            retry_policy: state.retry_policy.clone(),
         };
 
         self.outbox
@@ -190,6 +191,39 @@ impl FlowConfigurationService for FlowConfigurationServiceImpl {
         Ok(flow_configuration.into())
     }
 
+    /// Set or reset retry policy of an existing flow configuration
+    #[tracing::instrument(level = "info", skip_all, fields(?flow_key, ?retry_policy))]
+    async fn set_retry_policy(
+        &self,
+        request_time: DateTime<Utc>,
+        flow_key: FlowKey,
+        retry_policy: Option<RetryPolicy>,
+    ) -> Result<FlowConfigurationState, SetFlowRetryPolicyError> {
+        let mut flow_configuration =
+            match FlowConfiguration::try_load(flow_key.clone(), self.event_store.as_ref()).await? {
+                Some(flow_configuration) => flow_configuration,
+                None => {
+                    return Err(SetFlowRetryPolicyError::NotFound(
+                        FlowConfigurationNotFoundError { flow_key },
+                    ))
+                }
+            };
+
+        flow_configuration
+            .set_retry_policy(self.time_source.now(), retry_policy)
+            .int_err()?;
+
+        flow_configuration
+            .save(self.event_store.as_ref())
+            .await
+            .int_err()?;
+
+        self.publish_flow_configuration_modified(&flow_configuration, request_time)
+            .await?;
+
+        Ok(flow_configuration.into())
+    }
+
     /// Lists all enabled configurations
     fn list_enabled_configurations(&self) -> FlowConfigurationStateStream {
         // Note: terribly inefficient - walks over events multiple times
@@ -376,8 +410,19 @@ impl MessageConsumerT<DatasetLifecycleMessage> for FlowConfigurationServiceImpl
                 }
             }
 
+            DatasetLifecycleMessage::PollingSourceDisabled(message) => {
+                // Ingest flows would only keep failing without a source
+                self.pause_dataset_flows(
+                    self.time_source.now(),
+                    &message.dataset_id,
+                    Some(DatasetFlowType::Ingest),
+                )
+                .await?;
+            }
+
             DatasetLifecycleMessage::Created(_)
-            | DatasetLifecycleMessage::DependenciesUpdated(_) => {
+            | DatasetLifecycleMessage::DependenciesUpdated(_)
+            | DatasetLifecycleMessage::Renamed(_) => {
                 // no action required
             }
         }
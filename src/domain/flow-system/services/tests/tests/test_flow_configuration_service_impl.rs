@@ -372,6 +372,91 @@ async fn test_modify() {
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
+#[test_log::test(tokio::test)]
+async fn test_set_retry_policy() {
+    let harness = FlowConfigurationHarness::new();
+
+    // Retry policy cannot be set for a flow without configuration
+    let foo_id = harness.create_root_dataset("foo").await;
+    let retry_policy = RetryPolicy::new_checked(
+        3,
+        Duration::try_minutes(1).unwrap(),
+        RetryBackoffType::Exponential,
+        RetryErrorFilter::AnyError,
+    )
+    .unwrap();
+    let res = harness
+        .flow_configuration_service
+        .set_retry_policy(
+            Utc::now(),
+            FlowKeyDataset::new(foo_id.clone(), DatasetFlowType::Ingest).into(),
+            Some(retry_policy.clone()),
+        )
+        .await;
+    assert_matches!(res, Err(SetFlowRetryPolicyError::NotFound(_)));
+
+    // Configure daily ingestion schedule, and attach the policy
+    let foo_ingest_schedule: Schedule = Duration::try_days(1).unwrap().into();
+    harness
+        .set_dataset_flow_schedule(
+            foo_id.clone(),
+            DatasetFlowType::Ingest,
+            foo_ingest_schedule.clone(),
+        )
+        .await;
+    harness
+        .set_dataset_flow_retry_policy(
+            foo_id.clone(),
+            DatasetFlowType::Ingest,
+            Some(retry_policy.clone()),
+        )
+        .await;
+    assert_eq!(2, harness.configuration_events_count());
+
+    let flow_config_state = harness
+        .get_dataset_flow_config_from_store(foo_id.clone(), DatasetFlowType::Ingest)
+        .await;
+    assert_eq!(flow_config_state.retry_policy, Some(retry_policy.clone()));
+    assert_eq!(
+        flow_config_state.rule,
+        FlowConfigurationRule::Schedule(foo_ingest_schedule.clone())
+    );
+
+    // Policy survives pausing, resuming and modifying the rule
+    harness
+        .pause_dataset_flow(foo_id.clone(), DatasetFlowType::Ingest)
+        .await;
+    harness
+        .resume_dataset_flow(foo_id.clone(), DatasetFlowType::Ingest)
+        .await;
+    let foo_ingest_schedule_2: Schedule = Duration::try_weeks(1).unwrap().into();
+    harness
+        .set_dataset_flow_schedule(
+            foo_id.clone(),
+            DatasetFlowType::Ingest,
+            foo_ingest_schedule_2,
+        )
+        .await;
+
+    let flow_config_state = harness
+        .get_dataset_flow_config_from_store(foo_id.clone(), DatasetFlowType::Ingest)
+        .await;
+    assert_eq!(flow_config_state.retry_policy, Some(retry_policy));
+
+    // Reset the policy
+    harness
+        .set_dataset_flow_retry_policy(foo_id.clone(), DatasetFlowType::Ingest, None)
+        .await;
+
+    let flow_config_state = harness
+        .get_dataset_flow_config_from_store(foo_id, DatasetFlowType::Ingest)
+        .await;
+    assert_eq!(flow_config_state.retry_policy, None);
+    assert_eq!(6, harness.configuration_events_count());
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
 #[test_log::test(tokio::test)]
 async fn test_dataset_deleted() {
     let harness = FlowConfigurationHarness::new();
@@ -536,6 +621,22 @@ impl FlowConfigurationHarness {
             .unwrap();
     }
 
+    async fn set_dataset_flow_retry_policy(
+        &self,
+        dataset_id: DatasetID,
+        dataset_flow_type: DatasetFlowType,
+        retry_policy: Option<RetryPolicy>,
+    ) {
+        self.flow_configuration_service
+            .set_retry_policy(
+                Utc::now(),
+                FlowKeyDataset::new(dataset_id, dataset_flow_type).into(),
+                retry_policy,
+            )
+            .await
+            .unwrap();
+    }
+
     fn expect_system_flow_schedule(
         &self,
         enabled_configurations: &HashMap<FlowKey, FlowConfigurationState>,
@@ -2927,6 +2927,155 @@ async fn test_task_completions_trigger_next_loop_on_success() {
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
+#[test_log::test(tokio::test)]
+async fn test_failed_task_retried_according_to_retry_policy() {
+    let harness = FlowHarness::new().await;
+
+    let foo_create_result = harness
+        .create_root_dataset(DatasetAlias {
+            dataset_name: DatasetName::new_unchecked("foo"),
+            account_name: None,
+        })
+        .await;
+    let foo_id = foo_create_result.dataset_handle.id;
+
+    harness
+        .set_dataset_flow_ingest(
+            harness.now_datetime(),
+            foo_id.clone(),
+            DatasetFlowType::Ingest,
+            IngestRule {
+                fetch_uncacheable: false,
+                schedule_condition: Duration::try_milliseconds(100).unwrap().into(),
+            },
+        )
+        .await;
+    harness
+        .set_dataset_flow_retry_policy(
+            harness.now_datetime(),
+            foo_id.clone(),
+            DatasetFlowType::Ingest,
+            RetryPolicy::new_checked(
+                2,
+                Duration::try_milliseconds(20).unwrap(),
+                RetryBackoffType::Fixed,
+                RetryErrorFilter::AnyError,
+            )
+            .unwrap(),
+        )
+        .await;
+
+    // Enforce dependency graph initialization
+    harness.eager_initialization().await;
+
+    // Flow listener will collect snapshots at important moments of time
+    let test_flow_listener = harness.catalog.get_one::<FlowSystemTestListener>().unwrap();
+    test_flow_listener.define_dataset_display_name(foo_id.clone(), "foo".to_string());
+
+    // Remember start time
+    let start_time = harness
+        .now_datetime()
+        .duration_round(Duration::try_milliseconds(SCHEDULING_ALIGNMENT_MS).unwrap())
+        .unwrap();
+
+    // Run scheduler concurrently with manual triggers script
+    tokio::select! {
+        // Run API service
+        res = harness.flow_service.run(start_time) => res.int_err(),
+
+        // Run simulation script and task drivers
+        _ = async {
+            // Task 0: "foo" start running at 10ms, finish at 20ms with failure
+            let task0_driver = harness.task_driver(TaskDriverArgs {
+                task_id: TaskID::new(0),
+                dataset_id: Some(foo_id.clone()),
+                run_since_start: Duration::try_milliseconds(10).unwrap(),
+                finish_in_with: Some((Duration::try_milliseconds(10).unwrap(), TaskOutcome::Failed(TaskError::Empty))),
+                expected_logical_plan: LogicalPlan::UpdateDataset(UpdateDataset {
+                  dataset_id: foo_id.clone(),
+                  fetch_uncacheable: false
+                }),
+            });
+            let task0_handle = task0_driver.run();
+
+            // Task 1: "foo" retry start running at 50ms, finish at 60ms with failure again
+            let task1_driver = harness.task_driver(TaskDriverArgs {
+                task_id: TaskID::new(1),
+                dataset_id: Some(foo_id.clone()),
+                run_since_start: Duration::try_milliseconds(50).unwrap(),
+                finish_in_with: Some((Duration::try_milliseconds(10).unwrap(), TaskOutcome::Failed(TaskError::Empty))),
+                expected_logical_plan: LogicalPlan::UpdateDataset(UpdateDataset {
+                  dataset_id: foo_id.clone(),
+                  fetch_uncacheable: false
+                }),
+            });
+            let task1_handle = task1_driver.run();
+
+            // Main simulation script
+            let main_handle = async {
+                // 0ms: "foo" flow 0 is scheduled immediately
+                //  - task 0 starts at 10ms, finishes at 20ms with failure
+                //  - 2nd attempt is planned for 20ms + 20ms delay = 40ms
+                //  - task 1 is scheduled at 40ms, starts at 50ms, finishes at 60ms with failure
+                //  - attempts are exhausted, flow 0 fails, next flow not enqueued
+
+                // 80ms: nothing else happens
+                harness.advance_time(Duration::try_milliseconds(80).unwrap()).await;
+            };
+
+            tokio::join!(task0_handle, task1_handle, main_handle)
+
+         } => Ok(()),
+    }
+    .unwrap();
+
+    pretty_assertions::assert_eq!(
+        format!("{}", test_flow_listener.as_ref()),
+        indoc::indoc!(
+            r#"
+            #0: +0ms:
+              "foo" Ingest:
+                Flow ID = 0 Waiting AutoPolling
+
+            #1: +0ms:
+              "foo" Ingest:
+                Flow ID = 0 Waiting AutoPolling Executor(task=0, since=0ms)
+
+            #2: +10ms:
+              "foo" Ingest:
+                Flow ID = 0 Running(task=0)
+
+            #3: +20ms:
+              "foo" Ingest:
+                Flow ID = 0 Waiting AutoPolling Retry(attempt=2, wakeup=40ms)
+
+            #4: +40ms:
+              "foo" Ingest:
+                Flow ID = 0 Waiting AutoPolling Executor(task=1, since=40ms)
+
+            #5: +50ms:
+              "foo" Ingest:
+                Flow ID = 0 Running(task=0,1)
+
+            #6: +60ms:
+              "foo" Ingest:
+                Flow ID = 0 Finished Failed
+
+            "#
+        )
+    );
+
+    // The first failure only schedules a retry, the flow finishes after the 2nd one
+    let update_details = test_flow_listener.update_details();
+    let count_of = |details: FlowServiceUpdateDetails| {
+        update_details.iter().filter(|d| **d == details).count()
+    };
+    assert_eq!(count_of(FlowServiceUpdateDetails::FlowRetryScheduled), 1);
+    assert_eq!(count_of(FlowServiceUpdateDetails::FlowFinished), 1);
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
 #[test_log::test(tokio::test)]
 async fn test_derived_dataset_triggered_initially_and_after_input_change() {
     let harness = FlowHarness::with_overrides(FlowHarnessOverrides {
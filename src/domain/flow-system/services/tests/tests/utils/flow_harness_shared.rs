@@ -34,7 +34,7 @@ use kamu_flow_system_inmem::*;
 use kamu_flow_system_services::*;
 use kamu_task_system::{TaskProgressMessage, MESSAGE_PRODUCER_KAMU_TASK_EXECUTOR};
 use kamu_task_system_inmem::InMemoryTaskSystemEventStore;
-use kamu_task_system_services::TaskSchedulerImpl;
+use kamu_task_system_services::{TaskQueue, TaskSchedulerImpl};
 use messaging_outbox::{register_message_dispatcher, Outbox, OutboxImmediateImpl};
 use opendatafabric::*;
 use time_source::{FakeSystemTimeSource, SystemTimeSource};
@@ -122,6 +122,7 @@ impl FlowHarness {
                 awaiting_step,
                 mandatory_throttling_period,
             ))
+            .add::<FlowServiceState>()
             .add::<FlowServiceImpl>()
             .add::<InMemoryFlowEventStore>()
             .add::<FlowConfigurationServiceImpl>()
@@ -150,6 +151,7 @@ impl FlowHarness {
             .add::<DependencyGraphServiceInMemory>()
             .add::<DatasetOwnershipServiceInMemory>()
             .add::<DatasetOwnershipServiceInMemoryStateInitializer>()
+            .add::<TaskQueue>()
             .add::<TaskSchedulerImpl>()
             .add::<InMemoryTaskSystemEventStore>()
             .add::<LoginPasswordAuthProvider>()
@@ -307,6 +309,23 @@ impl FlowHarness {
             .unwrap();
     }
 
+    pub async fn set_dataset_flow_retry_policy(
+        &self,
+        request_time: DateTime<Utc>,
+        dataset_id: DatasetID,
+        dataset_flow_type: DatasetFlowType,
+        retry_policy: RetryPolicy,
+    ) {
+        self.flow_configuration_service
+            .set_retry_policy(
+                request_time,
+                FlowKeyDataset::new(dataset_id, dataset_flow_type).into(),
+                Some(retry_policy),
+            )
+            .await
+            .unwrap();
+    }
+
     pub async fn set_dataset_flow_reset_rule(
         &self,
         request_time: DateTime<Utc>,
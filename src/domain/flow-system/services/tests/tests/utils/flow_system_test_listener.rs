@@ -37,6 +37,7 @@ type FlowSnapshot = (DateTime<Utc>, HashMap<FlowKey, Vec<FlowState>>);
 #[derive(Default)]
 struct FlowSystemTestListenerState {
     snapshots: Vec<FlowSnapshot>,
+    update_details: Vec<FlowServiceUpdateDetails>,
     dataset_display_names: HashMap<DatasetID, String>,
 }
 
@@ -88,6 +89,11 @@ impl FlowSystemTestListener {
         state.snapshots.push((update_time, flow_states_map));
     }
 
+    pub(crate) fn update_details(&self) -> Vec<FlowServiceUpdateDetails> {
+        let state = self.state.lock().unwrap();
+        state.update_details.clone()
+    }
+
     pub(crate) fn define_dataset_display_name(&self, id: DatasetID, display_name: String) {
         let mut state = self.state.lock().unwrap();
         state.dataset_display_names.insert(id, display_name);
@@ -208,6 +214,14 @@ impl std::fmt::Display for FlowSystemTestListener {
                                     (s.wake_up_at - initial_time).num_milliseconds(),
                                 )?;
                             }
+                            FlowStartCondition::Retry(r) => {
+                                write!(
+                                    f,
+                                    " Retry(attempt={}, wakeup={}ms)",
+                                    r.attempt,
+                                    (r.wake_up_at - initial_time).num_milliseconds(),
+                                )?;
+                            }
                         }
                     }
 
@@ -241,6 +255,10 @@ impl MessageConsumerT<FlowServiceUpdatedMessage> for FlowSystemTestListener {
         _: &Catalog,
         message: &FlowServiceUpdatedMessage,
     ) -> Result<(), InternalError> {
+        {
+            let mut state = self.state.lock().unwrap();
+            state.update_details.push(message.update_details.clone());
+        }
         self.make_a_snapshot(message.update_time).await;
         Ok(())
     }
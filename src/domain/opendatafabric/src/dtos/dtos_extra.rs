@@ -161,6 +161,9 @@ impl ReadStep {
             ReadStep::NdGeoJson(v) => v.schema.as_ref(),
             ReadStep::EsriShapefile(v) => v.schema.as_ref(),
             ReadStep::Parquet(v) => v.schema.as_ref(),
+            ReadStep::Avro(v) => v.schema.as_ref(),
+            ReadStep::Orc(v) => v.schema.as_ref(),
+            ReadStep::Excel(v) => v.schema.as_ref(),
         }
     }
 }
@@ -230,6 +233,21 @@ impl Default for ReadStepEsriShapefile {
     }
 }
 
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// ReadStepExcel
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Default for ReadStepExcel {
+    fn default() -> Self {
+        Self {
+            schema: None,
+            sheet: None,
+            header: None,
+            skip_rows: None,
+        }
+    }
+}
+
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 // RawQueryResponse
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
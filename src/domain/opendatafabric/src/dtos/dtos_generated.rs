@@ -73,6 +73,9 @@ pub struct AddPushSource {
     /// Determines how newly-ingested data should be merged with existing
     /// history.
     pub merge: MergeStrategy,
+    /// Determines how the watermark is advanced as new data is ingested.
+    /// Defaults to the maximum event time seen so far.
+    pub watermark: Option<WatermarkStrategy>,
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -462,19 +465,47 @@ impl_enum_variant!(MergeStrategy::Append(MergeStrategyAppend));
 /// Ledger merge strategy.
 ///
 /// This strategy should be used for data sources containing ledgers of events.
-/// Currently this strategy will only perform deduplication of events using
+/// By default this strategy will perform deduplication of events using
 /// user-specified primary key columns. This means that the source data can
 /// contain partially overlapping set of records and only those records that
-/// were not previously seen will be appended.
+/// were not previously seen will be appended. See [`LedgerMergeMode`] for other
+/// ways of comparing the new data against previously seen events.
 #[derive(Clone, PartialEq, Eq, Debug)]
 pub struct MergeStrategyLedger {
     /// Names of the columns that uniquely identify the record throughout its
     /// lifetime
     pub primary_key: Vec<String>,
+    /// Defines how the new data is compared against previously seen events.
+    /// When not specified, events whose primary key was not previously seen
+    /// are appended.
+    pub mode: Option<LedgerMergeMode>,
+    /// Limits the comparison to the given number of most recent previously
+    /// seen records (by offset).
+    pub dedupe_window_records: Option<u64>,
+    /// Limits the comparison to previously seen events whose event time is
+    /// within the given number of seconds from the latest one.
+    pub dedupe_window_seconds: Option<u64>,
 }
 
 impl_enum_variant!(MergeStrategy::Ledger(MergeStrategyLedger));
 
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum LedgerMergeMode {
+    /// Input may contain previously seen events and duplicate events. Only
+    /// one event per primary key is appended, and only if it was not
+    /// previously seen.
+    Deduplicate,
+    /// Input only contains new events, so no comparison with previously seen
+    /// events is performed.
+    NewEventsOnly,
+    /// Input contains all previously seen events within the dedupe window.
+    /// Events that are missing from the input will be retracted.
+    SupersetRetractMissing,
+    /// Input contains all previously seen events within the dedupe window.
+    /// Events that are missing from the input will result in an error.
+    SupersetStrict,
+}
+
 /// Snapshot merge strategy.
 ///
 /// This strategy can be used for data state snapshots that are taken
@@ -742,6 +773,9 @@ pub enum ReadStep {
     Json(ReadStepJson),
     NdJson(ReadStepNdJson),
     NdGeoJson(ReadStepNdGeoJson),
+    Avro(ReadStepAvro),
+    Orc(ReadStepOrc),
+    Excel(ReadStepExcel),
 }
 
 impl_enum_with_variants!(ReadStep);
@@ -873,6 +907,46 @@ pub struct ReadStepNdGeoJson {
 
 impl_enum_variant!(ReadStep::NdGeoJson(ReadStepNdGeoJson));
 
+/// Reader for Apache Avro Object Container Files. The schema embedded into the
+/// file is used to read the data.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct ReadStepAvro {
+    /// A DDL-formatted schema. Schema can be used to coerce values into more
+    /// appropriate data types.
+    pub schema: Option<Vec<String>>,
+}
+
+impl_enum_variant!(ReadStep::Avro(ReadStepAvro));
+
+/// Reader for Apache ORC format.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct ReadStepOrc {
+    /// A DDL-formatted schema. Schema can be used to coerce values into more
+    /// appropriate data types.
+    pub schema: Option<Vec<String>>,
+}
+
+impl_enum_variant!(ReadStep::Orc(ReadStepOrc));
+
+/// Reader for Microsoft Excel workbooks (`.xlsx`). Reads a single sheet of the
+/// workbook creating a record per each row.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct ReadStepExcel {
+    /// A DDL-formatted schema. Schema can be used to coerce values into more
+    /// appropriate data types.
+    pub schema: Option<Vec<String>>,
+    /// Name of the sheet to read. If not specified the first sheet of the
+    /// workbook is used.
+    pub sheet: Option<String>,
+    /// Use the first row (after the skipped ones) as names of columns.
+    pub header: Option<bool>,
+    /// Number of rows to skip at the beginning of the sheet, e.g. to ignore
+    /// titles and notes that precede the table.
+    pub skip_rows: Option<u64>,
+}
+
+impl_enum_variant!(ReadStep::Excel(ReadStepExcel));
+
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 // RequestHeader
 // https://github.com/kamu-data/open-data-fabric/blob/master/open-data-fabric.md#requestheader-schema
@@ -978,6 +1052,9 @@ pub struct SetPollingSource {
     /// Determines how newly-ingested data should be merged with existing
     /// history.
     pub merge: MergeStrategy,
+    /// Determines how the watermark is advanced as new data is ingested.
+    /// Defaults to the maximum event time seen so far.
+    pub watermark: Option<WatermarkStrategy>,
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -1253,3 +1330,58 @@ pub struct Watermark {
     pub system_time: DateTime<Utc>,
     pub event_time: DateTime<Utc>,
 }
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// WatermarkStrategy
+// https://github.com/kamu-data/open-data-fabric/blob/master/open-data-fabric.md#watermarkstrategy-schema
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum WatermarkStrategy {
+    MaxEventTime(WatermarkStrategyMaxEventTime),
+    BoundedOutOfOrderness(WatermarkStrategyBoundedOutOfOrderness),
+    SystemTime(WatermarkStrategySystemTime),
+    Manual(WatermarkStrategyManual),
+}
+
+impl_enum_with_variants!(WatermarkStrategy);
+
+/// Advances the watermark to the maximum event time seen so far.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct WatermarkStrategyMaxEventTime {}
+
+impl_enum_variant!(WatermarkStrategy::MaxEventTime(
+    WatermarkStrategyMaxEventTime
+));
+
+/// Advances the watermark to the maximum event time seen so far minus the
+/// specified lag, allowing events to arrive out of order within that bound.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct WatermarkStrategyBoundedOutOfOrderness {
+    /// Maximum expected lateness of events in seconds.
+    pub lag_seconds: u64,
+}
+
+impl_enum_variant!(WatermarkStrategy::BoundedOutOfOrderness(
+    WatermarkStrategyBoundedOutOfOrderness
+));
+
+/// Advances the watermark to the system time of ingestion minus the specified
+/// delay, regardless of the event times in the data.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct WatermarkStrategySystemTime {
+    /// Delay relative to the system time in seconds.
+    pub delay_seconds: u64,
+    /// Minimal advance of the watermark in seconds for an ingest that produced
+    /// no data to result in a new block. Defaults to 60 seconds.
+    pub min_idle_advance_seconds: Option<u64>,
+}
+
+impl_enum_variant!(WatermarkStrategy::SystemTime(WatermarkStrategySystemTime));
+
+/// Watermark is never advanced automatically and can only be set explicitly
+/// when ingesting data.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct WatermarkStrategyManual {}
+
+impl_enum_variant!(WatermarkStrategy::Manual(WatermarkStrategyManual));
@@ -108,6 +108,7 @@ impl<'fb> FlatbuffersSerializable<'fb> for odf::AddPushSource {
         let read_offset = { self.read.serialize(fb) };
         let preprocess_offset = self.preprocess.as_ref().map(|v| v.serialize(fb));
         let merge_offset = { self.merge.serialize(fb) };
+        let watermark_offset = self.watermark.as_ref().map(|v| v.serialize(fb));
         let mut builder = fb::AddPushSourceBuilder::new(fb);
         builder.add_source_name(source_name_offset);
         builder.add_read_type(read_offset.0);
@@ -118,6 +119,10 @@ impl<'fb> FlatbuffersSerializable<'fb> for odf::AddPushSource {
         });
         builder.add_merge_type(merge_offset.0);
         builder.add_merge(merge_offset.1);
+        watermark_offset.map(|(e, off)| {
+            builder.add_watermark_type(e);
+            builder.add_watermark(off)
+        });
         builder.finish()
     }
 }
@@ -137,6 +142,9 @@ impl<'fb> FlatbuffersDeserializable<fb::AddPushSource<'fb>> for odf::AddPushSour
                 .merge()
                 .map(|v| odf::MergeStrategy::deserialize(v, proxy.merge_type()))
                 .unwrap(),
+            watermark: proxy
+                .watermark()
+                .map(|v| odf::WatermarkStrategy::deserialize(v, proxy.watermark_type())),
         }
     }
 }
@@ -997,6 +1005,11 @@ impl<'fb> FlatbuffersSerializable<'fb> for odf::MergeStrategyLedger {
         };
         let mut builder = fb::MergeStrategyLedgerBuilder::new(fb);
         builder.add_primary_key(primary_key_offset);
+        self.mode.map(|v| builder.add_mode(v.into()));
+        self.dedupe_window_records
+            .map(|v| builder.add_dedupe_window_records(v));
+        self.dedupe_window_seconds
+            .map(|v| builder.add_dedupe_window_seconds(v));
         builder.finish()
     }
 }
@@ -1008,6 +1021,36 @@ impl<'fb> FlatbuffersDeserializable<fb::MergeStrategyLedger<'fb>> for odf::Merge
                 .primary_key()
                 .map(|v| v.iter().map(|i| i.to_owned()).collect())
                 .unwrap(),
+            mode: proxy.mode().map(|v| v.into()),
+            dedupe_window_records: proxy.dedupe_window_records().map(|v| v),
+            dedupe_window_seconds: proxy.dedupe_window_seconds().map(|v| v),
+        }
+    }
+}
+
+impl From<odf::LedgerMergeMode> for fb::LedgerMergeMode {
+    fn from(v: odf::LedgerMergeMode) -> Self {
+        match v {
+            odf::LedgerMergeMode::Deduplicate => fb::LedgerMergeMode::Deduplicate,
+            odf::LedgerMergeMode::NewEventsOnly => fb::LedgerMergeMode::NewEventsOnly,
+            odf::LedgerMergeMode::SupersetRetractMissing => {
+                fb::LedgerMergeMode::SupersetRetractMissing
+            }
+            odf::LedgerMergeMode::SupersetStrict => fb::LedgerMergeMode::SupersetStrict,
+        }
+    }
+}
+
+impl Into<odf::LedgerMergeMode> for fb::LedgerMergeMode {
+    fn into(self) -> odf::LedgerMergeMode {
+        match self {
+            fb::LedgerMergeMode::Deduplicate => odf::LedgerMergeMode::Deduplicate,
+            fb::LedgerMergeMode::NewEventsOnly => odf::LedgerMergeMode::NewEventsOnly,
+            fb::LedgerMergeMode::SupersetRetractMissing => {
+                odf::LedgerMergeMode::SupersetRetractMissing
+            }
+            fb::LedgerMergeMode::SupersetStrict => odf::LedgerMergeMode::SupersetStrict,
+            _ => panic!("Invalid enum value: {}", self.0),
         }
     }
 }
@@ -1628,6 +1671,14 @@ impl<'fb> FlatbuffersEnumSerializable<'fb, fb::ReadStep> for odf::ReadStep {
                 fb::ReadStep::ReadStepNdGeoJson,
                 v.serialize(fb).as_union_value(),
             ),
+            odf::ReadStep::Avro(v) => {
+                (fb::ReadStep::ReadStepAvro, v.serialize(fb).as_union_value())
+            }
+            odf::ReadStep::Orc(v) => (fb::ReadStep::ReadStepOrc, v.serialize(fb).as_union_value()),
+            odf::ReadStep::Excel(v) => (
+                fb::ReadStep::ReadStepExcel,
+                v.serialize(fb).as_union_value(),
+            ),
         }
     }
 }
@@ -1670,6 +1721,21 @@ impl<'fb> FlatbuffersEnumDeserializable<'fb, fb::ReadStep> for odf::ReadStep {
                     fb::ReadStepNdGeoJson::init_from_table(table)
                 }))
             }
+            fb::ReadStep::ReadStepAvro => {
+                odf::ReadStep::Avro(odf::ReadStepAvro::deserialize(unsafe {
+                    fb::ReadStepAvro::init_from_table(table)
+                }))
+            }
+            fb::ReadStep::ReadStepOrc => {
+                odf::ReadStep::Orc(odf::ReadStepOrc::deserialize(unsafe {
+                    fb::ReadStepOrc::init_from_table(table)
+                }))
+            }
+            fb::ReadStep::ReadStepExcel => {
+                odf::ReadStep::Excel(odf::ReadStepExcel::deserialize(unsafe {
+                    fb::ReadStepExcel::init_from_table(table)
+                }))
+            }
             _ => panic!("Invalid enum value: {}", t.0),
         }
     }
@@ -1892,6 +1958,85 @@ impl<'fb> FlatbuffersDeserializable<fb::ReadStepNdGeoJson<'fb>> for odf::ReadSte
     }
 }
 
+impl<'fb> FlatbuffersSerializable<'fb> for odf::ReadStepAvro {
+    type OffsetT = WIPOffset<fb::ReadStepAvro<'fb>>;
+
+    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
+        let schema_offset = self.schema.as_ref().map(|v| {
+            let offsets: Vec<_> = v.iter().map(|i| fb.create_string(&i)).collect();
+            fb.create_vector(&offsets)
+        });
+        let mut builder = fb::ReadStepAvroBuilder::new(fb);
+        schema_offset.map(|off| builder.add_schema(off));
+        builder.finish()
+    }
+}
+
+impl<'fb> FlatbuffersDeserializable<fb::ReadStepAvro<'fb>> for odf::ReadStepAvro {
+    fn deserialize(proxy: fb::ReadStepAvro<'fb>) -> Self {
+        odf::ReadStepAvro {
+            schema: proxy
+                .schema()
+                .map(|v| v.iter().map(|i| i.to_owned()).collect()),
+        }
+    }
+}
+
+impl<'fb> FlatbuffersSerializable<'fb> for odf::ReadStepOrc {
+    type OffsetT = WIPOffset<fb::ReadStepOrc<'fb>>;
+
+    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
+        let schema_offset = self.schema.as_ref().map(|v| {
+            let offsets: Vec<_> = v.iter().map(|i| fb.create_string(&i)).collect();
+            fb.create_vector(&offsets)
+        });
+        let mut builder = fb::ReadStepOrcBuilder::new(fb);
+        schema_offset.map(|off| builder.add_schema(off));
+        builder.finish()
+    }
+}
+
+impl<'fb> FlatbuffersDeserializable<fb::ReadStepOrc<'fb>> for odf::ReadStepOrc {
+    fn deserialize(proxy: fb::ReadStepOrc<'fb>) -> Self {
+        odf::ReadStepOrc {
+            schema: proxy
+                .schema()
+                .map(|v| v.iter().map(|i| i.to_owned()).collect()),
+        }
+    }
+}
+
+impl<'fb> FlatbuffersSerializable<'fb> for odf::ReadStepExcel {
+    type OffsetT = WIPOffset<fb::ReadStepExcel<'fb>>;
+
+    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
+        let schema_offset = self.schema.as_ref().map(|v| {
+            let offsets: Vec<_> = v.iter().map(|i| fb.create_string(&i)).collect();
+            fb.create_vector(&offsets)
+        });
+        let sheet_offset = self.sheet.as_ref().map(|v| fb.create_string(&v));
+        let mut builder = fb::ReadStepExcelBuilder::new(fb);
+        schema_offset.map(|off| builder.add_schema(off));
+        sheet_offset.map(|off| builder.add_sheet(off));
+        self.header.map(|v| builder.add_header(v));
+        self.skip_rows.map(|v| builder.add_skip_rows(v));
+        builder.finish()
+    }
+}
+
+impl<'fb> FlatbuffersDeserializable<fb::ReadStepExcel<'fb>> for odf::ReadStepExcel {
+    fn deserialize(proxy: fb::ReadStepExcel<'fb>) -> Self {
+        odf::ReadStepExcel {
+            schema: proxy
+                .schema()
+                .map(|v| v.iter().map(|i| i.to_owned()).collect()),
+            sheet: proxy.sheet().map(|v| v.to_owned()),
+            header: proxy.header().map(|v| v),
+            skip_rows: proxy.skip_rows().map(|v| v),
+        }
+    }
+}
+
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 // RequestHeader
 // https://github.com/kamu-data/open-data-fabric/blob/master/open-data-fabric.md#requestheader-schema
@@ -2091,6 +2236,7 @@ impl<'fb> FlatbuffersSerializable<'fb> for odf::SetPollingSource {
         let read_offset = { self.read.serialize(fb) };
         let preprocess_offset = self.preprocess.as_ref().map(|v| v.serialize(fb));
         let merge_offset = { self.merge.serialize(fb) };
+        let watermark_offset = self.watermark.as_ref().map(|v| v.serialize(fb));
         let mut builder = fb::SetPollingSourceBuilder::new(fb);
         builder.add_fetch_type(fetch_offset.0);
         builder.add_fetch(fetch_offset.1);
@@ -2103,6 +2249,10 @@ impl<'fb> FlatbuffersSerializable<'fb> for odf::SetPollingSource {
         });
         builder.add_merge_type(merge_offset.0);
         builder.add_merge(merge_offset.1);
+        watermark_offset.map(|(e, off)| {
+            builder.add_watermark_type(e);
+            builder.add_watermark(off)
+        });
         builder.finish()
     }
 }
@@ -2130,6 +2280,9 @@ impl<'fb> FlatbuffersDeserializable<fb::SetPollingSource<'fb>> for odf::SetPolli
                 .merge()
                 .map(|v| odf::MergeStrategy::deserialize(v, proxy.merge_type()))
                 .unwrap(),
+            watermark: proxy
+                .watermark()
+                .map(|v| odf::WatermarkStrategy::deserialize(v, proxy.watermark_type())),
         }
     }
 }
@@ -2796,6 +2949,146 @@ impl<'fb> FlatbuffersDeserializable<fb::Watermark<'fb>> for odf::Watermark {
     }
 }
 
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// WatermarkStrategy
+// https://github.com/kamu-data/open-data-fabric/blob/master/open-data-fabric.md#watermarkstrategy-schema
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl<'fb> FlatbuffersEnumSerializable<'fb, fb::WatermarkStrategy> for odf::WatermarkStrategy {
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> (fb::WatermarkStrategy, WIPOffset<UnionWIPOffset>) {
+        match self {
+            odf::WatermarkStrategy::MaxEventTime(v) => (
+                fb::WatermarkStrategy::WatermarkStrategyMaxEventTime,
+                v.serialize(fb).as_union_value(),
+            ),
+            odf::WatermarkStrategy::BoundedOutOfOrderness(v) => (
+                fb::WatermarkStrategy::WatermarkStrategyBoundedOutOfOrderness,
+                v.serialize(fb).as_union_value(),
+            ),
+            odf::WatermarkStrategy::SystemTime(v) => (
+                fb::WatermarkStrategy::WatermarkStrategySystemTime,
+                v.serialize(fb).as_union_value(),
+            ),
+            odf::WatermarkStrategy::Manual(v) => (
+                fb::WatermarkStrategy::WatermarkStrategyManual,
+                v.serialize(fb).as_union_value(),
+            ),
+        }
+    }
+}
+
+impl<'fb> FlatbuffersEnumDeserializable<'fb, fb::WatermarkStrategy> for odf::WatermarkStrategy {
+    fn deserialize(table: flatbuffers::Table<'fb>, t: fb::WatermarkStrategy) -> Self {
+        match t {
+            fb::WatermarkStrategy::WatermarkStrategyMaxEventTime => {
+                odf::WatermarkStrategy::MaxEventTime(
+                    odf::WatermarkStrategyMaxEventTime::deserialize(unsafe {
+                        fb::WatermarkStrategyMaxEventTime::init_from_table(table)
+                    }),
+                )
+            }
+            fb::WatermarkStrategy::WatermarkStrategyBoundedOutOfOrderness => {
+                odf::WatermarkStrategy::BoundedOutOfOrderness(
+                    odf::WatermarkStrategyBoundedOutOfOrderness::deserialize(unsafe {
+                        fb::WatermarkStrategyBoundedOutOfOrderness::init_from_table(table)
+                    }),
+                )
+            }
+            fb::WatermarkStrategy::WatermarkStrategySystemTime => {
+                odf::WatermarkStrategy::SystemTime(odf::WatermarkStrategySystemTime::deserialize(
+                    unsafe { fb::WatermarkStrategySystemTime::init_from_table(table) },
+                ))
+            }
+            fb::WatermarkStrategy::WatermarkStrategyManual => {
+                odf::WatermarkStrategy::Manual(odf::WatermarkStrategyManual::deserialize(unsafe {
+                    fb::WatermarkStrategyManual::init_from_table(table)
+                }))
+            }
+            _ => panic!("Invalid enum value: {}", t.0),
+        }
+    }
+}
+
+impl<'fb> FlatbuffersSerializable<'fb> for odf::WatermarkStrategyMaxEventTime {
+    type OffsetT = WIPOffset<fb::WatermarkStrategyMaxEventTime<'fb>>;
+
+    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
+        let mut builder = fb::WatermarkStrategyMaxEventTimeBuilder::new(fb);
+        builder.finish()
+    }
+}
+
+impl<'fb> FlatbuffersDeserializable<fb::WatermarkStrategyMaxEventTime<'fb>>
+    for odf::WatermarkStrategyMaxEventTime
+{
+    fn deserialize(proxy: fb::WatermarkStrategyMaxEventTime<'fb>) -> Self {
+        odf::WatermarkStrategyMaxEventTime {}
+    }
+}
+
+impl<'fb> FlatbuffersSerializable<'fb> for odf::WatermarkStrategyBoundedOutOfOrderness {
+    type OffsetT = WIPOffset<fb::WatermarkStrategyBoundedOutOfOrderness<'fb>>;
+
+    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
+        let mut builder = fb::WatermarkStrategyBoundedOutOfOrdernessBuilder::new(fb);
+        builder.add_lag_seconds(self.lag_seconds);
+        builder.finish()
+    }
+}
+
+impl<'fb> FlatbuffersDeserializable<fb::WatermarkStrategyBoundedOutOfOrderness<'fb>>
+    for odf::WatermarkStrategyBoundedOutOfOrderness
+{
+    fn deserialize(proxy: fb::WatermarkStrategyBoundedOutOfOrderness<'fb>) -> Self {
+        odf::WatermarkStrategyBoundedOutOfOrderness {
+            lag_seconds: proxy.lag_seconds(),
+        }
+    }
+}
+
+impl<'fb> FlatbuffersSerializable<'fb> for odf::WatermarkStrategySystemTime {
+    type OffsetT = WIPOffset<fb::WatermarkStrategySystemTime<'fb>>;
+
+    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
+        let mut builder = fb::WatermarkStrategySystemTimeBuilder::new(fb);
+        builder.add_delay_seconds(self.delay_seconds);
+        self.min_idle_advance_seconds
+            .map(|v| builder.add_min_idle_advance_seconds(v));
+        builder.finish()
+    }
+}
+
+impl<'fb> FlatbuffersDeserializable<fb::WatermarkStrategySystemTime<'fb>>
+    for odf::WatermarkStrategySystemTime
+{
+    fn deserialize(proxy: fb::WatermarkStrategySystemTime<'fb>) -> Self {
+        odf::WatermarkStrategySystemTime {
+            delay_seconds: proxy.delay_seconds(),
+            min_idle_advance_seconds: proxy.min_idle_advance_seconds().map(|v| v),
+        }
+    }
+}
+
+impl<'fb> FlatbuffersSerializable<'fb> for odf::WatermarkStrategyManual {
+    type OffsetT = WIPOffset<fb::WatermarkStrategyManual<'fb>>;
+
+    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
+        let mut builder = fb::WatermarkStrategyManualBuilder::new(fb);
+        builder.finish()
+    }
+}
+
+impl<'fb> FlatbuffersDeserializable<fb::WatermarkStrategyManual<'fb>>
+    for odf::WatermarkStrategyManual
+{
+    fn deserialize(proxy: fb::WatermarkStrategyManual<'fb>) -> Self {
+        odf::WatermarkStrategyManual {}
+    }
+}
+
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 // Helpers
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
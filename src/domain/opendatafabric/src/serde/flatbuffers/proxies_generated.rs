@@ -28,13 +28,13 @@ pub const ENUM_MIN_READ_STEP: u8 = 0;
     since = "2.0.0",
     note = "Use associated constants instead. This will no longer be generated in 2021."
 )]
-pub const ENUM_MAX_READ_STEP: u8 = 7;
+pub const ENUM_MAX_READ_STEP: u8 = 10;
 #[deprecated(
     since = "2.0.0",
     note = "Use associated constants instead. This will no longer be generated in 2021."
 )]
 #[allow(non_camel_case_types)]
-pub const ENUM_VALUES_READ_STEP: [ReadStep; 8] = [
+pub const ENUM_VALUES_READ_STEP: [ReadStep; 11] = [
     ReadStep::NONE,
     ReadStep::ReadStepCsv,
     ReadStep::ReadStepGeoJson,
@@ -43,6 +43,9 @@ pub const ENUM_VALUES_READ_STEP: [ReadStep; 8] = [
     ReadStep::ReadStepJson,
     ReadStep::ReadStepNdJson,
     ReadStep::ReadStepNdGeoJson,
+    ReadStep::ReadStepAvro,
+    ReadStep::ReadStepOrc,
+    ReadStep::ReadStepExcel,
 ];
 
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
@@ -58,9 +61,12 @@ impl ReadStep {
     pub const ReadStepJson: Self = Self(5);
     pub const ReadStepNdJson: Self = Self(6);
     pub const ReadStepNdGeoJson: Self = Self(7);
+    pub const ReadStepAvro: Self = Self(8);
+    pub const ReadStepOrc: Self = Self(9);
+    pub const ReadStepExcel: Self = Self(10);
 
     pub const ENUM_MIN: u8 = 0;
-    pub const ENUM_MAX: u8 = 7;
+    pub const ENUM_MAX: u8 = 10;
     pub const ENUM_VALUES: &'static [Self] = &[
         Self::NONE,
         Self::ReadStepCsv,
@@ -70,6 +76,9 @@ impl ReadStep {
         Self::ReadStepJson,
         Self::ReadStepNdJson,
         Self::ReadStepNdGeoJson,
+        Self::ReadStepAvro,
+        Self::ReadStepOrc,
+        Self::ReadStepExcel,
     ];
     /// Returns the variant's name or "" if unknown.
     pub fn variant_name(self) -> Option<&'static str> {
@@ -82,6 +91,9 @@ impl ReadStep {
             Self::ReadStepJson => Some("ReadStepJson"),
             Self::ReadStepNdJson => Some("ReadStepNdJson"),
             Self::ReadStepNdGeoJson => Some("ReadStepNdGeoJson"),
+            Self::ReadStepAvro => Some("ReadStepAvro"),
+            Self::ReadStepOrc => Some("ReadStepOrc"),
+            Self::ReadStepExcel => Some("ReadStepExcel"),
             _ => None,
         }
     }
@@ -231,6 +243,109 @@ impl<'a> flatbuffers::Verifiable for Transform {
 impl flatbuffers::SimpleToVerifyInSlice for Transform {}
 pub struct TransformUnionTableOffset {}
 
+#[deprecated(
+    since = "2.0.0",
+    note = "Use associated constants instead. This will no longer be generated in 2021."
+)]
+pub const ENUM_MIN_LEDGER_MERGE_MODE: i32 = 0;
+#[deprecated(
+    since = "2.0.0",
+    note = "Use associated constants instead. This will no longer be generated in 2021."
+)]
+pub const ENUM_MAX_LEDGER_MERGE_MODE: i32 = 3;
+#[deprecated(
+    since = "2.0.0",
+    note = "Use associated constants instead. This will no longer be generated in 2021."
+)]
+#[allow(non_camel_case_types)]
+pub const ENUM_VALUES_LEDGER_MERGE_MODE: [LedgerMergeMode; 4] = [
+    LedgerMergeMode::Deduplicate,
+    LedgerMergeMode::NewEventsOnly,
+    LedgerMergeMode::SupersetRetractMissing,
+    LedgerMergeMode::SupersetStrict,
+];
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(transparent)]
+pub struct LedgerMergeMode(pub i32);
+#[allow(non_upper_case_globals)]
+impl LedgerMergeMode {
+    pub const Deduplicate: Self = Self(0);
+    pub const NewEventsOnly: Self = Self(1);
+    pub const SupersetRetractMissing: Self = Self(2);
+    pub const SupersetStrict: Self = Self(3);
+
+    pub const ENUM_MIN: i32 = 0;
+    pub const ENUM_MAX: i32 = 3;
+    pub const ENUM_VALUES: &'static [Self] = &[
+        Self::Deduplicate,
+        Self::NewEventsOnly,
+        Self::SupersetRetractMissing,
+        Self::SupersetStrict,
+    ];
+    /// Returns the variant's name or "" if unknown.
+    pub fn variant_name(self) -> Option<&'static str> {
+        match self {
+            Self::Deduplicate => Some("Deduplicate"),
+            Self::NewEventsOnly => Some("NewEventsOnly"),
+            Self::SupersetRetractMissing => Some("SupersetRetractMissing"),
+            Self::SupersetStrict => Some("SupersetStrict"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Debug for LedgerMergeMode {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        if let Some(name) = self.variant_name() {
+            f.write_str(name)
+        } else {
+            f.write_fmt(format_args!("<UNKNOWN {:?}>", self.0))
+        }
+    }
+}
+impl<'a> flatbuffers::Follow<'a> for LedgerMergeMode {
+    type Inner = Self;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        let b = flatbuffers::read_scalar_at::<i32>(buf, loc);
+        Self(b)
+    }
+}
+
+impl flatbuffers::Push for LedgerMergeMode {
+    type Output = LedgerMergeMode;
+    #[inline]
+    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+        flatbuffers::emplace_scalar::<i32>(dst, self.0);
+    }
+}
+
+impl flatbuffers::EndianScalar for LedgerMergeMode {
+    type Scalar = i32;
+    #[inline]
+    fn to_little_endian(self) -> i32 {
+        self.0.to_le()
+    }
+    #[inline]
+    #[allow(clippy::wrong_self_convention)]
+    fn from_little_endian(v: i32) -> Self {
+        let b = i32::from_le(v);
+        Self(b)
+    }
+}
+
+impl<'a> flatbuffers::Verifiable for LedgerMergeMode {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use self::flatbuffers::Verifiable;
+        i32::run_verifier(v, pos)
+    }
+}
+
+impl flatbuffers::SimpleToVerifyInSlice for LedgerMergeMode {}
 #[deprecated(
     since = "2.0.0",
     note = "Use associated constants instead. This will no longer be generated in 2021."
@@ -335,6 +450,116 @@ impl<'a> flatbuffers::Verifiable for MergeStrategy {
 
 impl flatbuffers::SimpleToVerifyInSlice for MergeStrategy {}
 pub struct MergeStrategyUnionTableOffset {}
+#[deprecated(
+    since = "2.0.0",
+    note = "Use associated constants instead. This will no longer be generated in 2021."
+)]
+pub const ENUM_MIN_WATERMARK_STRATEGY: u8 = 0;
+#[deprecated(
+    since = "2.0.0",
+    note = "Use associated constants instead. This will no longer be generated in 2021."
+)]
+pub const ENUM_MAX_WATERMARK_STRATEGY: u8 = 4;
+#[deprecated(
+    since = "2.0.0",
+    note = "Use associated constants instead. This will no longer be generated in 2021."
+)]
+#[allow(non_camel_case_types)]
+pub const ENUM_VALUES_WATERMARK_STRATEGY: [WatermarkStrategy; 5] = [
+    WatermarkStrategy::NONE,
+    WatermarkStrategy::WatermarkStrategyMaxEventTime,
+    WatermarkStrategy::WatermarkStrategyBoundedOutOfOrderness,
+    WatermarkStrategy::WatermarkStrategySystemTime,
+    WatermarkStrategy::WatermarkStrategyManual,
+];
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(transparent)]
+pub struct WatermarkStrategy(pub u8);
+#[allow(non_upper_case_globals)]
+impl WatermarkStrategy {
+    pub const NONE: Self = Self(0);
+    pub const WatermarkStrategyMaxEventTime: Self = Self(1);
+    pub const WatermarkStrategyBoundedOutOfOrderness: Self = Self(2);
+    pub const WatermarkStrategySystemTime: Self = Self(3);
+    pub const WatermarkStrategyManual: Self = Self(4);
+
+    pub const ENUM_MIN: u8 = 0;
+    pub const ENUM_MAX: u8 = 4;
+    pub const ENUM_VALUES: &'static [Self] = &[
+        Self::NONE,
+        Self::WatermarkStrategyMaxEventTime,
+        Self::WatermarkStrategyBoundedOutOfOrderness,
+        Self::WatermarkStrategySystemTime,
+        Self::WatermarkStrategyManual,
+    ];
+    /// Returns the variant's name or "" if unknown.
+    pub fn variant_name(self) -> Option<&'static str> {
+        match self {
+            Self::NONE => Some("NONE"),
+            Self::WatermarkStrategyMaxEventTime => Some("WatermarkStrategyMaxEventTime"),
+            Self::WatermarkStrategyBoundedOutOfOrderness => {
+                Some("WatermarkStrategyBoundedOutOfOrderness")
+            }
+            Self::WatermarkStrategySystemTime => Some("WatermarkStrategySystemTime"),
+            Self::WatermarkStrategyManual => Some("WatermarkStrategyManual"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Debug for WatermarkStrategy {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        if let Some(name) = self.variant_name() {
+            f.write_str(name)
+        } else {
+            f.write_fmt(format_args!("<UNKNOWN {:?}>", self.0))
+        }
+    }
+}
+impl<'a> flatbuffers::Follow<'a> for WatermarkStrategy {
+    type Inner = Self;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        let b = flatbuffers::read_scalar_at::<u8>(buf, loc);
+        Self(b)
+    }
+}
+
+impl flatbuffers::Push for WatermarkStrategy {
+    type Output = WatermarkStrategy;
+    #[inline]
+    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+        flatbuffers::emplace_scalar::<u8>(dst, self.0);
+    }
+}
+
+impl flatbuffers::EndianScalar for WatermarkStrategy {
+    type Scalar = u8;
+    #[inline]
+    fn to_little_endian(self) -> u8 {
+        self.0.to_le()
+    }
+    #[inline]
+    #[allow(clippy::wrong_self_convention)]
+    fn from_little_endian(v: u8) -> Self {
+        let b = u8::from_le(v);
+        Self(b)
+    }
+}
+
+impl<'a> flatbuffers::Verifiable for WatermarkStrategy {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use self::flatbuffers::Verifiable;
+        u8::run_verifier(v, pos)
+    }
+}
+
+impl flatbuffers::SimpleToVerifyInSlice for WatermarkStrategy {}
+pub struct WatermarkStrategyUnionTableOffset {}
 
 #[deprecated(
     since = "2.0.0",
@@ -3884,16 +4109,15 @@ impl core::fmt::Debug for ReadStepNdGeoJson<'_> {
         ds.finish()
     }
 }
-pub enum SqlQueryStepOffset {}
+pub enum ReadStepAvroOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
-pub struct SqlQueryStep<'a> {
+pub struct ReadStepAvro<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for SqlQueryStep<'a> {
-    type Inner = SqlQueryStep<'a>;
+impl<'a> flatbuffers::Follow<'a> for ReadStepAvro<'a> {
+    type Inner = ReadStepAvro<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -3902,52 +4126,39 @@ impl<'a> flatbuffers::Follow<'a> for SqlQueryStep<'a> {
     }
 }
 
-impl<'a> SqlQueryStep<'a> {
-    pub const VT_ALIAS: flatbuffers::VOffsetT = 4;
-    pub const VT_QUERY: flatbuffers::VOffsetT = 6;
+impl<'a> ReadStepAvro<'a> {
+    pub const VT_SCHEMA: flatbuffers::VOffsetT = 4;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        SqlQueryStep { _tab: table }
+        ReadStepAvro { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
-        args: &'args SqlQueryStepArgs<'args>,
-    ) -> flatbuffers::WIPOffset<SqlQueryStep<'bldr>> {
-        let mut builder = SqlQueryStepBuilder::new(_fbb);
-        if let Some(x) = args.query {
-            builder.add_query(x);
-        }
-        if let Some(x) = args.alias {
-            builder.add_alias(x);
+        args: &'args ReadStepAvroArgs<'args>,
+    ) -> flatbuffers::WIPOffset<ReadStepAvro<'bldr>> {
+        let mut builder = ReadStepAvroBuilder::new(_fbb);
+        if let Some(x) = args.schema {
+            builder.add_schema(x);
         }
         builder.finish()
     }
 
     #[inline]
-    pub fn alias(&self) -> Option<&'a str> {
-        // Safety:
-        // Created from valid Table for this object
-        // which contains a valid value in this slot
-        unsafe {
-            self._tab
-                .get::<flatbuffers::ForwardsUOffset<&str>>(SqlQueryStep::VT_ALIAS, None)
-        }
-    }
-    #[inline]
-    pub fn query(&self) -> Option<&'a str> {
+    pub fn schema(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>> {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
-            self._tab
-                .get::<flatbuffers::ForwardsUOffset<&str>>(SqlQueryStep::VT_QUERY, None)
+            self._tab.get::<flatbuffers::ForwardsUOffset<
+                flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>,
+            >>(ReadStepAvro::VT_SCHEMA, None)
         }
     }
 }
 
-impl flatbuffers::Verifiable for SqlQueryStep<'_> {
+impl flatbuffers::Verifiable for ReadStepAvro<'_> {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
@@ -3955,74 +4166,71 @@ impl flatbuffers::Verifiable for SqlQueryStep<'_> {
     ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
         use self::flatbuffers::Verifiable;
         v.visit_table(pos)?
-            .visit_field::<flatbuffers::ForwardsUOffset<&str>>("alias", Self::VT_ALIAS, false)?
-            .visit_field::<flatbuffers::ForwardsUOffset<&str>>("query", Self::VT_QUERY, false)?
+            .visit_field::<flatbuffers::ForwardsUOffset<
+                flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<&'_ str>>,
+            >>("schema", Self::VT_SCHEMA, false)?
             .finish();
         Ok(())
     }
 }
-pub struct SqlQueryStepArgs<'a> {
-    pub alias: Option<flatbuffers::WIPOffset<&'a str>>,
-    pub query: Option<flatbuffers::WIPOffset<&'a str>>,
+pub struct ReadStepAvroArgs<'a> {
+    pub schema: Option<
+        flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>,
+    >,
 }
-impl<'a> Default for SqlQueryStepArgs<'a> {
+impl<'a> Default for ReadStepAvroArgs<'a> {
     #[inline]
     fn default() -> Self {
-        SqlQueryStepArgs {
-            alias: None,
-            query: None,
-        }
+        ReadStepAvroArgs { schema: None }
     }
 }
 
-pub struct SqlQueryStepBuilder<'a: 'b, 'b> {
+pub struct ReadStepAvroBuilder<'a: 'b, 'b> {
     fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
     start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b> SqlQueryStepBuilder<'a, 'b> {
+impl<'a: 'b, 'b> ReadStepAvroBuilder<'a, 'b> {
     #[inline]
-    pub fn add_alias(&mut self, alias: flatbuffers::WIPOffset<&'b str>) {
-        self.fbb_
-            .push_slot_always::<flatbuffers::WIPOffset<_>>(SqlQueryStep::VT_ALIAS, alias);
-    }
-    #[inline]
-    pub fn add_query(&mut self, query: flatbuffers::WIPOffset<&'b str>) {
+    pub fn add_schema(
+        &mut self,
+        schema: flatbuffers::WIPOffset<
+            flatbuffers::Vector<'b, flatbuffers::ForwardsUOffset<&'b str>>,
+        >,
+    ) {
         self.fbb_
-            .push_slot_always::<flatbuffers::WIPOffset<_>>(SqlQueryStep::VT_QUERY, query);
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(ReadStepAvro::VT_SCHEMA, schema);
     }
     #[inline]
-    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> SqlQueryStepBuilder<'a, 'b> {
+    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> ReadStepAvroBuilder<'a, 'b> {
         let start = _fbb.start_table();
-        SqlQueryStepBuilder {
+        ReadStepAvroBuilder {
             fbb_: _fbb,
             start_: start,
         }
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<SqlQueryStep<'a>> {
+    pub fn finish(self) -> flatbuffers::WIPOffset<ReadStepAvro<'a>> {
         let o = self.fbb_.end_table(self.start_);
         flatbuffers::WIPOffset::new(o.value())
     }
 }
 
-impl core::fmt::Debug for SqlQueryStep<'_> {
+impl core::fmt::Debug for ReadStepAvro<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("SqlQueryStep");
-        ds.field("alias", &self.alias());
-        ds.field("query", &self.query());
+        let mut ds = f.debug_struct("ReadStepAvro");
+        ds.field("schema", &self.schema());
         ds.finish()
     }
 }
-pub enum TemporalTableOffset {}
+pub enum ReadStepOrcOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
-pub struct TemporalTable<'a> {
+pub struct ReadStepOrc<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for TemporalTable<'a> {
-    type Inner = TemporalTable<'a>;
+impl<'a> flatbuffers::Follow<'a> for ReadStepOrc<'a> {
+    type Inner = ReadStepOrc<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -4031,55 +4239,39 @@ impl<'a> flatbuffers::Follow<'a> for TemporalTable<'a> {
     }
 }
 
-impl<'a> TemporalTable<'a> {
-    pub const VT_NAME: flatbuffers::VOffsetT = 4;
-    pub const VT_PRIMARY_KEY: flatbuffers::VOffsetT = 6;
+impl<'a> ReadStepOrc<'a> {
+    pub const VT_SCHEMA: flatbuffers::VOffsetT = 4;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        TemporalTable { _tab: table }
+        ReadStepOrc { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
-        args: &'args TemporalTableArgs<'args>,
-    ) -> flatbuffers::WIPOffset<TemporalTable<'bldr>> {
-        let mut builder = TemporalTableBuilder::new(_fbb);
-        if let Some(x) = args.primary_key {
-            builder.add_primary_key(x);
-        }
-        if let Some(x) = args.name {
-            builder.add_name(x);
+        args: &'args ReadStepOrcArgs<'args>,
+    ) -> flatbuffers::WIPOffset<ReadStepOrc<'bldr>> {
+        let mut builder = ReadStepOrcBuilder::new(_fbb);
+        if let Some(x) = args.schema {
+            builder.add_schema(x);
         }
         builder.finish()
     }
 
     #[inline]
-    pub fn name(&self) -> Option<&'a str> {
+    pub fn schema(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>> {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
-            self._tab
-                .get::<flatbuffers::ForwardsUOffset<&str>>(TemporalTable::VT_NAME, None)
-        }
-    }
-    #[inline]
-    pub fn primary_key(
-        &self,
-    ) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>> {
-        // Safety:
-        // Created from valid Table for this object
-        // which contains a valid value in this slot
-        unsafe {
-            self._tab.get::<flatbuffers::ForwardsUOffset<
-                flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>,
-            >>(TemporalTable::VT_PRIMARY_KEY, None)
+            self._tab.get::<flatbuffers::ForwardsUOffset<
+                flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>,
+            >>(ReadStepOrc::VT_SCHEMA, None)
         }
     }
 }
 
-impl flatbuffers::Verifiable for TemporalTable<'_> {
+impl flatbuffers::Verifiable for ReadStepOrc<'_> {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
@@ -4087,85 +4279,71 @@ impl flatbuffers::Verifiable for TemporalTable<'_> {
     ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
         use self::flatbuffers::Verifiable;
         v.visit_table(pos)?
-            .visit_field::<flatbuffers::ForwardsUOffset<&str>>("name", Self::VT_NAME, false)?
             .visit_field::<flatbuffers::ForwardsUOffset<
                 flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<&'_ str>>,
-            >>("primary_key", Self::VT_PRIMARY_KEY, false)?
+            >>("schema", Self::VT_SCHEMA, false)?
             .finish();
         Ok(())
     }
 }
-pub struct TemporalTableArgs<'a> {
-    pub name: Option<flatbuffers::WIPOffset<&'a str>>,
-    pub primary_key: Option<
+pub struct ReadStepOrcArgs<'a> {
+    pub schema: Option<
         flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>,
     >,
 }
-impl<'a> Default for TemporalTableArgs<'a> {
+impl<'a> Default for ReadStepOrcArgs<'a> {
     #[inline]
     fn default() -> Self {
-        TemporalTableArgs {
-            name: None,
-            primary_key: None,
-        }
+        ReadStepOrcArgs { schema: None }
     }
 }
 
-pub struct TemporalTableBuilder<'a: 'b, 'b> {
+pub struct ReadStepOrcBuilder<'a: 'b, 'b> {
     fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
     start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b> TemporalTableBuilder<'a, 'b> {
-    #[inline]
-    pub fn add_name(&mut self, name: flatbuffers::WIPOffset<&'b str>) {
-        self.fbb_
-            .push_slot_always::<flatbuffers::WIPOffset<_>>(TemporalTable::VT_NAME, name);
-    }
+impl<'a: 'b, 'b> ReadStepOrcBuilder<'a, 'b> {
     #[inline]
-    pub fn add_primary_key(
+    pub fn add_schema(
         &mut self,
-        primary_key: flatbuffers::WIPOffset<
+        schema: flatbuffers::WIPOffset<
             flatbuffers::Vector<'b, flatbuffers::ForwardsUOffset<&'b str>>,
         >,
     ) {
-        self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(
-            TemporalTable::VT_PRIMARY_KEY,
-            primary_key,
-        );
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(ReadStepOrc::VT_SCHEMA, schema);
     }
     #[inline]
-    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> TemporalTableBuilder<'a, 'b> {
+    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> ReadStepOrcBuilder<'a, 'b> {
         let start = _fbb.start_table();
-        TemporalTableBuilder {
+        ReadStepOrcBuilder {
             fbb_: _fbb,
             start_: start,
         }
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<TemporalTable<'a>> {
+    pub fn finish(self) -> flatbuffers::WIPOffset<ReadStepOrc<'a>> {
         let o = self.fbb_.end_table(self.start_);
         flatbuffers::WIPOffset::new(o.value())
     }
 }
 
-impl core::fmt::Debug for TemporalTable<'_> {
+impl core::fmt::Debug for ReadStepOrc<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("TemporalTable");
-        ds.field("name", &self.name());
-        ds.field("primary_key", &self.primary_key());
+        let mut ds = f.debug_struct("ReadStepOrc");
+        ds.field("schema", &self.schema());
         ds.finish()
     }
 }
-pub enum TransformSqlOffset {}
+pub enum ReadStepExcelOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
-pub struct TransformSql<'a> {
+pub struct ReadStepExcel<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for TransformSql<'a> {
-    type Inner = TransformSql<'a>;
+impl<'a> flatbuffers::Follow<'a> for ReadStepExcel<'a> {
+    type Inner = ReadStepExcel<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -4174,100 +4352,75 @@ impl<'a> flatbuffers::Follow<'a> for TransformSql<'a> {
     }
 }
 
-impl<'a> TransformSql<'a> {
-    pub const VT_ENGINE: flatbuffers::VOffsetT = 4;
-    pub const VT_VERSION: flatbuffers::VOffsetT = 6;
-    pub const VT_QUERY: flatbuffers::VOffsetT = 8;
-    pub const VT_QUERIES: flatbuffers::VOffsetT = 10;
-    pub const VT_TEMPORAL_TABLES: flatbuffers::VOffsetT = 12;
+impl<'a> ReadStepExcel<'a> {
+    pub const VT_SCHEMA: flatbuffers::VOffsetT = 4;
+    pub const VT_SHEET: flatbuffers::VOffsetT = 6;
+    pub const VT_HEADER: flatbuffers::VOffsetT = 8;
+    pub const VT_SKIP_ROWS: flatbuffers::VOffsetT = 10;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        TransformSql { _tab: table }
+        ReadStepExcel { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
-        args: &'args TransformSqlArgs<'args>,
-    ) -> flatbuffers::WIPOffset<TransformSql<'bldr>> {
-        let mut builder = TransformSqlBuilder::new(_fbb);
-        if let Some(x) = args.temporal_tables {
-            builder.add_temporal_tables(x);
-        }
-        if let Some(x) = args.queries {
-            builder.add_queries(x);
+        args: &'args ReadStepExcelArgs<'args>,
+    ) -> flatbuffers::WIPOffset<ReadStepExcel<'bldr>> {
+        let mut builder = ReadStepExcelBuilder::new(_fbb);
+        if let Some(x) = args.skip_rows {
+            builder.add_skip_rows(x);
         }
-        if let Some(x) = args.query {
-            builder.add_query(x);
+        if let Some(x) = args.sheet {
+            builder.add_sheet(x);
         }
-        if let Some(x) = args.version {
-            builder.add_version(x);
+        if let Some(x) = args.schema {
+            builder.add_schema(x);
         }
-        if let Some(x) = args.engine {
-            builder.add_engine(x);
+        if let Some(x) = args.header {
+            builder.add_header(x);
         }
         builder.finish()
     }
 
     #[inline]
-    pub fn engine(&self) -> Option<&'a str> {
-        // Safety:
-        // Created from valid Table for this object
-        // which contains a valid value in this slot
-        unsafe {
-            self._tab
-                .get::<flatbuffers::ForwardsUOffset<&str>>(TransformSql::VT_ENGINE, None)
-        }
-    }
-    #[inline]
-    pub fn version(&self) -> Option<&'a str> {
+    pub fn schema(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>> {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
-            self._tab
-                .get::<flatbuffers::ForwardsUOffset<&str>>(TransformSql::VT_VERSION, None)
+            self._tab.get::<flatbuffers::ForwardsUOffset<
+                flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>,
+            >>(ReadStepExcel::VT_SCHEMA, None)
         }
     }
     #[inline]
-    pub fn query(&self) -> Option<&'a str> {
+    pub fn sheet(&self) -> Option<&'a str> {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<flatbuffers::ForwardsUOffset<&str>>(TransformSql::VT_QUERY, None)
+                .get::<flatbuffers::ForwardsUOffset<&str>>(ReadStepExcel::VT_SHEET, None)
         }
     }
     #[inline]
-    pub fn queries(
-        &self,
-    ) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<SqlQueryStep<'a>>>> {
+    pub fn header(&self) -> Option<bool> {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
-        unsafe {
-            self._tab.get::<flatbuffers::ForwardsUOffset<
-                flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<SqlQueryStep>>,
-            >>(TransformSql::VT_QUERIES, None)
-        }
+        unsafe { self._tab.get::<bool>(ReadStepExcel::VT_HEADER, None) }
     }
     #[inline]
-    pub fn temporal_tables(
-        &self,
-    ) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<TemporalTable<'a>>>> {
+    pub fn skip_rows(&self) -> Option<u64> {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
-        unsafe {
-            self._tab.get::<flatbuffers::ForwardsUOffset<
-                flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<TemporalTable>>,
-            >>(TransformSql::VT_TEMPORAL_TABLES, None)
-        }
+        unsafe { self._tab.get::<u64>(ReadStepExcel::VT_SKIP_ROWS, None) }
     }
 }
 
-impl flatbuffers::Verifiable for TransformSql<'_> {
+impl flatbuffers::Verifiable for ReadStepExcel<'_> {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
@@ -4275,125 +4428,101 @@ impl flatbuffers::Verifiable for TransformSql<'_> {
     ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
         use self::flatbuffers::Verifiable;
         v.visit_table(pos)?
-            .visit_field::<flatbuffers::ForwardsUOffset<&str>>("engine", Self::VT_ENGINE, false)?
-            .visit_field::<flatbuffers::ForwardsUOffset<&str>>("version", Self::VT_VERSION, false)?
-            .visit_field::<flatbuffers::ForwardsUOffset<&str>>("query", Self::VT_QUERY, false)?
-            .visit_field::<flatbuffers::ForwardsUOffset<
-                flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<SqlQueryStep>>,
-            >>("queries", Self::VT_QUERIES, false)?
             .visit_field::<flatbuffers::ForwardsUOffset<
-                flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<TemporalTable>>,
-            >>("temporal_tables", Self::VT_TEMPORAL_TABLES, false)?
+                flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<&'_ str>>,
+            >>("schema", Self::VT_SCHEMA, false)?
+            .visit_field::<flatbuffers::ForwardsUOffset<&str>>("sheet", Self::VT_SHEET, false)?
+            .visit_field::<bool>("header", Self::VT_HEADER, false)?
+            .visit_field::<u64>("skip_rows", Self::VT_SKIP_ROWS, false)?
             .finish();
         Ok(())
     }
 }
-pub struct TransformSqlArgs<'a> {
-    pub engine: Option<flatbuffers::WIPOffset<&'a str>>,
-    pub version: Option<flatbuffers::WIPOffset<&'a str>>,
-    pub query: Option<flatbuffers::WIPOffset<&'a str>>,
-    pub queries: Option<
-        flatbuffers::WIPOffset<
-            flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<SqlQueryStep<'a>>>,
-        >,
-    >,
-    pub temporal_tables: Option<
-        flatbuffers::WIPOffset<
-            flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<TemporalTable<'a>>>,
-        >,
+pub struct ReadStepExcelArgs<'a> {
+    pub schema: Option<
+        flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>,
     >,
+    pub sheet: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub header: Option<bool>,
+    pub skip_rows: Option<u64>,
 }
-impl<'a> Default for TransformSqlArgs<'a> {
+impl<'a> Default for ReadStepExcelArgs<'a> {
     #[inline]
     fn default() -> Self {
-        TransformSqlArgs {
-            engine: None,
-            version: None,
-            query: None,
-            queries: None,
-            temporal_tables: None,
+        ReadStepExcelArgs {
+            schema: None,
+            sheet: None,
+            header: None,
+            skip_rows: None,
         }
     }
 }
 
-pub struct TransformSqlBuilder<'a: 'b, 'b> {
+pub struct ReadStepExcelBuilder<'a: 'b, 'b> {
     fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
     start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b> TransformSqlBuilder<'a, 'b> {
+impl<'a: 'b, 'b> ReadStepExcelBuilder<'a, 'b> {
     #[inline]
-    pub fn add_engine(&mut self, engine: flatbuffers::WIPOffset<&'b str>) {
+    pub fn add_schema(
+        &mut self,
+        schema: flatbuffers::WIPOffset<
+            flatbuffers::Vector<'b, flatbuffers::ForwardsUOffset<&'b str>>,
+        >,
+    ) {
         self.fbb_
-            .push_slot_always::<flatbuffers::WIPOffset<_>>(TransformSql::VT_ENGINE, engine);
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(ReadStepExcel::VT_SCHEMA, schema);
     }
     #[inline]
-    pub fn add_version(&mut self, version: flatbuffers::WIPOffset<&'b str>) {
+    pub fn add_sheet(&mut self, sheet: flatbuffers::WIPOffset<&'b str>) {
         self.fbb_
-            .push_slot_always::<flatbuffers::WIPOffset<_>>(TransformSql::VT_VERSION, version);
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(ReadStepExcel::VT_SHEET, sheet);
     }
     #[inline]
-    pub fn add_query(&mut self, query: flatbuffers::WIPOffset<&'b str>) {
+    pub fn add_header(&mut self, header: bool) {
         self.fbb_
-            .push_slot_always::<flatbuffers::WIPOffset<_>>(TransformSql::VT_QUERY, query);
+            .push_slot_always::<bool>(ReadStepExcel::VT_HEADER, header);
     }
     #[inline]
-    pub fn add_queries(
-        &mut self,
-        queries: flatbuffers::WIPOffset<
-            flatbuffers::Vector<'b, flatbuffers::ForwardsUOffset<SqlQueryStep<'b>>>,
-        >,
-    ) {
+    pub fn add_skip_rows(&mut self, skip_rows: u64) {
         self.fbb_
-            .push_slot_always::<flatbuffers::WIPOffset<_>>(TransformSql::VT_QUERIES, queries);
-    }
-    #[inline]
-    pub fn add_temporal_tables(
-        &mut self,
-        temporal_tables: flatbuffers::WIPOffset<
-            flatbuffers::Vector<'b, flatbuffers::ForwardsUOffset<TemporalTable<'b>>>,
-        >,
-    ) {
-        self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(
-            TransformSql::VT_TEMPORAL_TABLES,
-            temporal_tables,
-        );
+            .push_slot_always::<u64>(ReadStepExcel::VT_SKIP_ROWS, skip_rows);
     }
     #[inline]
-    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> TransformSqlBuilder<'a, 'b> {
+    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> ReadStepExcelBuilder<'a, 'b> {
         let start = _fbb.start_table();
-        TransformSqlBuilder {
+        ReadStepExcelBuilder {
             fbb_: _fbb,
             start_: start,
         }
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<TransformSql<'a>> {
+    pub fn finish(self) -> flatbuffers::WIPOffset<ReadStepExcel<'a>> {
         let o = self.fbb_.end_table(self.start_);
         flatbuffers::WIPOffset::new(o.value())
     }
 }
 
-impl core::fmt::Debug for TransformSql<'_> {
+impl core::fmt::Debug for ReadStepExcel<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("TransformSql");
-        ds.field("engine", &self.engine());
-        ds.field("version", &self.version());
-        ds.field("query", &self.query());
-        ds.field("queries", &self.queries());
-        ds.field("temporal_tables", &self.temporal_tables());
+        let mut ds = f.debug_struct("ReadStepExcel");
+        ds.field("schema", &self.schema());
+        ds.field("sheet", &self.sheet());
+        ds.field("header", &self.header());
+        ds.field("skip_rows", &self.skip_rows());
         ds.finish()
     }
 }
-pub enum MergeStrategyAppendOffset {}
+pub enum SqlQueryStepOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
-pub struct MergeStrategyAppend<'a> {
+pub struct SqlQueryStep<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for MergeStrategyAppend<'a> {
-    type Inner = MergeStrategyAppend<'a>;
+impl<'a> flatbuffers::Follow<'a> for SqlQueryStep<'a> {
+    type Inner = SqlQueryStep<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -4402,77 +4531,127 @@ impl<'a> flatbuffers::Follow<'a> for MergeStrategyAppend<'a> {
     }
 }
 
-impl<'a> MergeStrategyAppend<'a> {
+impl<'a> SqlQueryStep<'a> {
+    pub const VT_ALIAS: flatbuffers::VOffsetT = 4;
+    pub const VT_QUERY: flatbuffers::VOffsetT = 6;
+
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        MergeStrategyAppend { _tab: table }
+        SqlQueryStep { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
-        _args: &'args MergeStrategyAppendArgs,
-    ) -> flatbuffers::WIPOffset<MergeStrategyAppend<'bldr>> {
-        let mut builder = MergeStrategyAppendBuilder::new(_fbb);
+        args: &'args SqlQueryStepArgs<'args>,
+    ) -> flatbuffers::WIPOffset<SqlQueryStep<'bldr>> {
+        let mut builder = SqlQueryStepBuilder::new(_fbb);
+        if let Some(x) = args.query {
+            builder.add_query(x);
+        }
+        if let Some(x) = args.alias {
+            builder.add_alias(x);
+        }
         builder.finish()
     }
+
+    #[inline]
+    pub fn alias(&self) -> Option<&'a str> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<&str>>(SqlQueryStep::VT_ALIAS, None)
+        }
+    }
+    #[inline]
+    pub fn query(&self) -> Option<&'a str> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<&str>>(SqlQueryStep::VT_QUERY, None)
+        }
+    }
 }
 
-impl flatbuffers::Verifiable for MergeStrategyAppend<'_> {
+impl flatbuffers::Verifiable for SqlQueryStep<'_> {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
         pos: usize,
     ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
         use self::flatbuffers::Verifiable;
-        v.visit_table(pos)?.finish();
+        v.visit_table(pos)?
+            .visit_field::<flatbuffers::ForwardsUOffset<&str>>("alias", Self::VT_ALIAS, false)?
+            .visit_field::<flatbuffers::ForwardsUOffset<&str>>("query", Self::VT_QUERY, false)?
+            .finish();
         Ok(())
     }
 }
-pub struct MergeStrategyAppendArgs {}
-impl<'a> Default for MergeStrategyAppendArgs {
-    #[inline]
+pub struct SqlQueryStepArgs<'a> {
+    pub alias: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub query: Option<flatbuffers::WIPOffset<&'a str>>,
+}
+impl<'a> Default for SqlQueryStepArgs<'a> {
+    #[inline]
     fn default() -> Self {
-        MergeStrategyAppendArgs {}
+        SqlQueryStepArgs {
+            alias: None,
+            query: None,
+        }
     }
 }
 
-pub struct MergeStrategyAppendBuilder<'a: 'b, 'b> {
+pub struct SqlQueryStepBuilder<'a: 'b, 'b> {
     fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
     start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b> MergeStrategyAppendBuilder<'a, 'b> {
+impl<'a: 'b, 'b> SqlQueryStepBuilder<'a, 'b> {
     #[inline]
-    pub fn new(
-        _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
-    ) -> MergeStrategyAppendBuilder<'a, 'b> {
+    pub fn add_alias(&mut self, alias: flatbuffers::WIPOffset<&'b str>) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(SqlQueryStep::VT_ALIAS, alias);
+    }
+    #[inline]
+    pub fn add_query(&mut self, query: flatbuffers::WIPOffset<&'b str>) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(SqlQueryStep::VT_QUERY, query);
+    }
+    #[inline]
+    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> SqlQueryStepBuilder<'a, 'b> {
         let start = _fbb.start_table();
-        MergeStrategyAppendBuilder {
+        SqlQueryStepBuilder {
             fbb_: _fbb,
             start_: start,
         }
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<MergeStrategyAppend<'a>> {
+    pub fn finish(self) -> flatbuffers::WIPOffset<SqlQueryStep<'a>> {
         let o = self.fbb_.end_table(self.start_);
         flatbuffers::WIPOffset::new(o.value())
     }
 }
 
-impl core::fmt::Debug for MergeStrategyAppend<'_> {
+impl core::fmt::Debug for SqlQueryStep<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("MergeStrategyAppend");
+        let mut ds = f.debug_struct("SqlQueryStep");
+        ds.field("alias", &self.alias());
+        ds.field("query", &self.query());
         ds.finish()
     }
 }
-pub enum MergeStrategyLedgerOffset {}
+pub enum TemporalTableOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct MergeStrategyLedger<'a> {
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+pub struct TemporalTable<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for MergeStrategyLedger<'a> {
-    type Inner = MergeStrategyLedger<'a>;
+impl<'a> flatbuffers::Follow<'a> for TemporalTable<'a> {
+    type Inner = TemporalTable<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -4481,25 +4660,39 @@ impl<'a> flatbuffers::Follow<'a> for MergeStrategyLedger<'a> {
     }
 }
 
-impl<'a> MergeStrategyLedger<'a> {
-    pub const VT_PRIMARY_KEY: flatbuffers::VOffsetT = 4;
+impl<'a> TemporalTable<'a> {
+    pub const VT_NAME: flatbuffers::VOffsetT = 4;
+    pub const VT_PRIMARY_KEY: flatbuffers::VOffsetT = 6;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        MergeStrategyLedger { _tab: table }
+        TemporalTable { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
-        args: &'args MergeStrategyLedgerArgs<'args>,
-    ) -> flatbuffers::WIPOffset<MergeStrategyLedger<'bldr>> {
-        let mut builder = MergeStrategyLedgerBuilder::new(_fbb);
+        args: &'args TemporalTableArgs<'args>,
+    ) -> flatbuffers::WIPOffset<TemporalTable<'bldr>> {
+        let mut builder = TemporalTableBuilder::new(_fbb);
         if let Some(x) = args.primary_key {
             builder.add_primary_key(x);
         }
+        if let Some(x) = args.name {
+            builder.add_name(x);
+        }
         builder.finish()
     }
 
+    #[inline]
+    pub fn name(&self) -> Option<&'a str> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<&str>>(TemporalTable::VT_NAME, None)
+        }
+    }
     #[inline]
     pub fn primary_key(
         &self,
@@ -4508,14 +4701,1012 @@ impl<'a> MergeStrategyLedger<'a> {
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
-            self._tab.get::<flatbuffers::ForwardsUOffset<
-                flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>,
-            >>(MergeStrategyLedger::VT_PRIMARY_KEY, None)
+            self._tab.get::<flatbuffers::ForwardsUOffset<
+                flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>,
+            >>(TemporalTable::VT_PRIMARY_KEY, None)
+        }
+    }
+}
+
+impl flatbuffers::Verifiable for TemporalTable<'_> {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use self::flatbuffers::Verifiable;
+        v.visit_table(pos)?
+            .visit_field::<flatbuffers::ForwardsUOffset<&str>>("name", Self::VT_NAME, false)?
+            .visit_field::<flatbuffers::ForwardsUOffset<
+                flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<&'_ str>>,
+            >>("primary_key", Self::VT_PRIMARY_KEY, false)?
+            .finish();
+        Ok(())
+    }
+}
+pub struct TemporalTableArgs<'a> {
+    pub name: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub primary_key: Option<
+        flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>,
+    >,
+}
+impl<'a> Default for TemporalTableArgs<'a> {
+    #[inline]
+    fn default() -> Self {
+        TemporalTableArgs {
+            name: None,
+            primary_key: None,
+        }
+    }
+}
+
+pub struct TemporalTableBuilder<'a: 'b, 'b> {
+    fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> TemporalTableBuilder<'a, 'b> {
+    #[inline]
+    pub fn add_name(&mut self, name: flatbuffers::WIPOffset<&'b str>) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(TemporalTable::VT_NAME, name);
+    }
+    #[inline]
+    pub fn add_primary_key(
+        &mut self,
+        primary_key: flatbuffers::WIPOffset<
+            flatbuffers::Vector<'b, flatbuffers::ForwardsUOffset<&'b str>>,
+        >,
+    ) {
+        self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(
+            TemporalTable::VT_PRIMARY_KEY,
+            primary_key,
+        );
+    }
+    #[inline]
+    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> TemporalTableBuilder<'a, 'b> {
+        let start = _fbb.start_table();
+        TemporalTableBuilder {
+            fbb_: _fbb,
+            start_: start,
+        }
+    }
+    #[inline]
+    pub fn finish(self) -> flatbuffers::WIPOffset<TemporalTable<'a>> {
+        let o = self.fbb_.end_table(self.start_);
+        flatbuffers::WIPOffset::new(o.value())
+    }
+}
+
+impl core::fmt::Debug for TemporalTable<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut ds = f.debug_struct("TemporalTable");
+        ds.field("name", &self.name());
+        ds.field("primary_key", &self.primary_key());
+        ds.finish()
+    }
+}
+pub enum TransformSqlOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+pub struct TransformSql<'a> {
+    pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for TransformSql<'a> {
+    type Inner = TransformSql<'a>;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: flatbuffers::Table::new(buf, loc),
+        }
+    }
+}
+
+impl<'a> TransformSql<'a> {
+    pub const VT_ENGINE: flatbuffers::VOffsetT = 4;
+    pub const VT_VERSION: flatbuffers::VOffsetT = 6;
+    pub const VT_QUERY: flatbuffers::VOffsetT = 8;
+    pub const VT_QUERIES: flatbuffers::VOffsetT = 10;
+    pub const VT_TEMPORAL_TABLES: flatbuffers::VOffsetT = 12;
+
+    #[inline]
+    pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        TransformSql { _tab: table }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+        args: &'args TransformSqlArgs<'args>,
+    ) -> flatbuffers::WIPOffset<TransformSql<'bldr>> {
+        let mut builder = TransformSqlBuilder::new(_fbb);
+        if let Some(x) = args.temporal_tables {
+            builder.add_temporal_tables(x);
+        }
+        if let Some(x) = args.queries {
+            builder.add_queries(x);
+        }
+        if let Some(x) = args.query {
+            builder.add_query(x);
+        }
+        if let Some(x) = args.version {
+            builder.add_version(x);
+        }
+        if let Some(x) = args.engine {
+            builder.add_engine(x);
+        }
+        builder.finish()
+    }
+
+    #[inline]
+    pub fn engine(&self) -> Option<&'a str> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<&str>>(TransformSql::VT_ENGINE, None)
+        }
+    }
+    #[inline]
+    pub fn version(&self) -> Option<&'a str> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<&str>>(TransformSql::VT_VERSION, None)
+        }
+    }
+    #[inline]
+    pub fn query(&self) -> Option<&'a str> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<&str>>(TransformSql::VT_QUERY, None)
+        }
+    }
+    #[inline]
+    pub fn queries(
+        &self,
+    ) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<SqlQueryStep<'a>>>> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab.get::<flatbuffers::ForwardsUOffset<
+                flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<SqlQueryStep>>,
+            >>(TransformSql::VT_QUERIES, None)
+        }
+    }
+    #[inline]
+    pub fn temporal_tables(
+        &self,
+    ) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<TemporalTable<'a>>>> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab.get::<flatbuffers::ForwardsUOffset<
+                flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<TemporalTable>>,
+            >>(TransformSql::VT_TEMPORAL_TABLES, None)
+        }
+    }
+}
+
+impl flatbuffers::Verifiable for TransformSql<'_> {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use self::flatbuffers::Verifiable;
+        v.visit_table(pos)?
+            .visit_field::<flatbuffers::ForwardsUOffset<&str>>("engine", Self::VT_ENGINE, false)?
+            .visit_field::<flatbuffers::ForwardsUOffset<&str>>("version", Self::VT_VERSION, false)?
+            .visit_field::<flatbuffers::ForwardsUOffset<&str>>("query", Self::VT_QUERY, false)?
+            .visit_field::<flatbuffers::ForwardsUOffset<
+                flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<SqlQueryStep>>,
+            >>("queries", Self::VT_QUERIES, false)?
+            .visit_field::<flatbuffers::ForwardsUOffset<
+                flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<TemporalTable>>,
+            >>("temporal_tables", Self::VT_TEMPORAL_TABLES, false)?
+            .finish();
+        Ok(())
+    }
+}
+pub struct TransformSqlArgs<'a> {
+    pub engine: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub version: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub query: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub queries: Option<
+        flatbuffers::WIPOffset<
+            flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<SqlQueryStep<'a>>>,
+        >,
+    >,
+    pub temporal_tables: Option<
+        flatbuffers::WIPOffset<
+            flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<TemporalTable<'a>>>,
+        >,
+    >,
+}
+impl<'a> Default for TransformSqlArgs<'a> {
+    #[inline]
+    fn default() -> Self {
+        TransformSqlArgs {
+            engine: None,
+            version: None,
+            query: None,
+            queries: None,
+            temporal_tables: None,
+        }
+    }
+}
+
+pub struct TransformSqlBuilder<'a: 'b, 'b> {
+    fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> TransformSqlBuilder<'a, 'b> {
+    #[inline]
+    pub fn add_engine(&mut self, engine: flatbuffers::WIPOffset<&'b str>) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(TransformSql::VT_ENGINE, engine);
+    }
+    #[inline]
+    pub fn add_version(&mut self, version: flatbuffers::WIPOffset<&'b str>) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(TransformSql::VT_VERSION, version);
+    }
+    #[inline]
+    pub fn add_query(&mut self, query: flatbuffers::WIPOffset<&'b str>) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(TransformSql::VT_QUERY, query);
+    }
+    #[inline]
+    pub fn add_queries(
+        &mut self,
+        queries: flatbuffers::WIPOffset<
+            flatbuffers::Vector<'b, flatbuffers::ForwardsUOffset<SqlQueryStep<'b>>>,
+        >,
+    ) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(TransformSql::VT_QUERIES, queries);
+    }
+    #[inline]
+    pub fn add_temporal_tables(
+        &mut self,
+        temporal_tables: flatbuffers::WIPOffset<
+            flatbuffers::Vector<'b, flatbuffers::ForwardsUOffset<TemporalTable<'b>>>,
+        >,
+    ) {
+        self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(
+            TransformSql::VT_TEMPORAL_TABLES,
+            temporal_tables,
+        );
+    }
+    #[inline]
+    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> TransformSqlBuilder<'a, 'b> {
+        let start = _fbb.start_table();
+        TransformSqlBuilder {
+            fbb_: _fbb,
+            start_: start,
+        }
+    }
+    #[inline]
+    pub fn finish(self) -> flatbuffers::WIPOffset<TransformSql<'a>> {
+        let o = self.fbb_.end_table(self.start_);
+        flatbuffers::WIPOffset::new(o.value())
+    }
+}
+
+impl core::fmt::Debug for TransformSql<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut ds = f.debug_struct("TransformSql");
+        ds.field("engine", &self.engine());
+        ds.field("version", &self.version());
+        ds.field("query", &self.query());
+        ds.field("queries", &self.queries());
+        ds.field("temporal_tables", &self.temporal_tables());
+        ds.finish()
+    }
+}
+pub enum MergeStrategyAppendOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+pub struct MergeStrategyAppend<'a> {
+    pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for MergeStrategyAppend<'a> {
+    type Inner = MergeStrategyAppend<'a>;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: flatbuffers::Table::new(buf, loc),
+        }
+    }
+}
+
+impl<'a> MergeStrategyAppend<'a> {
+    #[inline]
+    pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        MergeStrategyAppend { _tab: table }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+        _args: &'args MergeStrategyAppendArgs,
+    ) -> flatbuffers::WIPOffset<MergeStrategyAppend<'bldr>> {
+        let mut builder = MergeStrategyAppendBuilder::new(_fbb);
+        builder.finish()
+    }
+}
+
+impl flatbuffers::Verifiable for MergeStrategyAppend<'_> {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use self::flatbuffers::Verifiable;
+        v.visit_table(pos)?.finish();
+        Ok(())
+    }
+}
+pub struct MergeStrategyAppendArgs {}
+impl<'a> Default for MergeStrategyAppendArgs {
+    #[inline]
+    fn default() -> Self {
+        MergeStrategyAppendArgs {}
+    }
+}
+
+pub struct MergeStrategyAppendBuilder<'a: 'b, 'b> {
+    fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> MergeStrategyAppendBuilder<'a, 'b> {
+    #[inline]
+    pub fn new(
+        _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    ) -> MergeStrategyAppendBuilder<'a, 'b> {
+        let start = _fbb.start_table();
+        MergeStrategyAppendBuilder {
+            fbb_: _fbb,
+            start_: start,
+        }
+    }
+    #[inline]
+    pub fn finish(self) -> flatbuffers::WIPOffset<MergeStrategyAppend<'a>> {
+        let o = self.fbb_.end_table(self.start_);
+        flatbuffers::WIPOffset::new(o.value())
+    }
+}
+
+impl core::fmt::Debug for MergeStrategyAppend<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut ds = f.debug_struct("MergeStrategyAppend");
+        ds.finish()
+    }
+}
+pub enum MergeStrategyLedgerOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct MergeStrategyLedger<'a> {
+    pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for MergeStrategyLedger<'a> {
+    type Inner = MergeStrategyLedger<'a>;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: flatbuffers::Table::new(buf, loc),
+        }
+    }
+}
+
+impl<'a> MergeStrategyLedger<'a> {
+    pub const VT_PRIMARY_KEY: flatbuffers::VOffsetT = 4;
+    pub const VT_MODE: flatbuffers::VOffsetT = 6;
+    pub const VT_DEDUPE_WINDOW_RECORDS: flatbuffers::VOffsetT = 8;
+    pub const VT_DEDUPE_WINDOW_SECONDS: flatbuffers::VOffsetT = 10;
+
+    #[inline]
+    pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        MergeStrategyLedger { _tab: table }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+        args: &'args MergeStrategyLedgerArgs<'args>,
+    ) -> flatbuffers::WIPOffset<MergeStrategyLedger<'bldr>> {
+        let mut builder = MergeStrategyLedgerBuilder::new(_fbb);
+        if let Some(x) = args.dedupe_window_seconds {
+            builder.add_dedupe_window_seconds(x);
+        }
+        if let Some(x) = args.dedupe_window_records {
+            builder.add_dedupe_window_records(x);
+        }
+        if let Some(x) = args.mode {
+            builder.add_mode(x);
+        }
+        if let Some(x) = args.primary_key {
+            builder.add_primary_key(x);
+        }
+        builder.finish()
+    }
+
+    #[inline]
+    pub fn primary_key(
+        &self,
+    ) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab.get::<flatbuffers::ForwardsUOffset<
+                flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>,
+            >>(MergeStrategyLedger::VT_PRIMARY_KEY, None)
+        }
+    }
+    #[inline]
+    pub fn mode(&self) -> Option<LedgerMergeMode> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<LedgerMergeMode>(MergeStrategyLedger::VT_MODE, None)
+        }
+    }
+    #[inline]
+    pub fn dedupe_window_records(&self) -> Option<u64> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<u64>(MergeStrategyLedger::VT_DEDUPE_WINDOW_RECORDS, None)
+        }
+    }
+    #[inline]
+    pub fn dedupe_window_seconds(&self) -> Option<u64> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<u64>(MergeStrategyLedger::VT_DEDUPE_WINDOW_SECONDS, None)
+        }
+    }
+}
+
+impl flatbuffers::Verifiable for MergeStrategyLedger<'_> {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use self::flatbuffers::Verifiable;
+        v.visit_table(pos)?
+            .visit_field::<flatbuffers::ForwardsUOffset<
+                flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<&'_ str>>,
+            >>("primary_key", Self::VT_PRIMARY_KEY, false)?
+            .visit_field::<LedgerMergeMode>("mode", Self::VT_MODE, false)?
+            .visit_field::<u64>(
+                "dedupe_window_records",
+                Self::VT_DEDUPE_WINDOW_RECORDS,
+                false,
+            )?
+            .visit_field::<u64>(
+                "dedupe_window_seconds",
+                Self::VT_DEDUPE_WINDOW_SECONDS,
+                false,
+            )?
+            .finish();
+        Ok(())
+    }
+}
+pub struct MergeStrategyLedgerArgs<'a> {
+    pub primary_key: Option<
+        flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>,
+    >,
+    pub mode: Option<LedgerMergeMode>,
+    pub dedupe_window_records: Option<u64>,
+    pub dedupe_window_seconds: Option<u64>,
+}
+impl<'a> Default for MergeStrategyLedgerArgs<'a> {
+    #[inline]
+    fn default() -> Self {
+        MergeStrategyLedgerArgs {
+            primary_key: None,
+            mode: None,
+            dedupe_window_records: None,
+            dedupe_window_seconds: None,
+        }
+    }
+}
+
+pub struct MergeStrategyLedgerBuilder<'a: 'b, 'b> {
+    fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> MergeStrategyLedgerBuilder<'a, 'b> {
+    #[inline]
+    pub fn add_primary_key(
+        &mut self,
+        primary_key: flatbuffers::WIPOffset<
+            flatbuffers::Vector<'b, flatbuffers::ForwardsUOffset<&'b str>>,
+        >,
+    ) {
+        self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(
+            MergeStrategyLedger::VT_PRIMARY_KEY,
+            primary_key,
+        );
+    }
+    #[inline]
+    pub fn add_mode(&mut self, mode: LedgerMergeMode) {
+        self.fbb_
+            .push_slot_always::<LedgerMergeMode>(MergeStrategyLedger::VT_MODE, mode);
+    }
+    #[inline]
+    pub fn add_dedupe_window_records(&mut self, dedupe_window_records: u64) {
+        self.fbb_.push_slot_always::<u64>(
+            MergeStrategyLedger::VT_DEDUPE_WINDOW_RECORDS,
+            dedupe_window_records,
+        );
+    }
+    #[inline]
+    pub fn add_dedupe_window_seconds(&mut self, dedupe_window_seconds: u64) {
+        self.fbb_.push_slot_always::<u64>(
+            MergeStrategyLedger::VT_DEDUPE_WINDOW_SECONDS,
+            dedupe_window_seconds,
+        );
+    }
+    #[inline]
+    pub fn new(
+        _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    ) -> MergeStrategyLedgerBuilder<'a, 'b> {
+        let start = _fbb.start_table();
+        MergeStrategyLedgerBuilder {
+            fbb_: _fbb,
+            start_: start,
+        }
+    }
+    #[inline]
+    pub fn finish(self) -> flatbuffers::WIPOffset<MergeStrategyLedger<'a>> {
+        let o = self.fbb_.end_table(self.start_);
+        flatbuffers::WIPOffset::new(o.value())
+    }
+}
+
+impl core::fmt::Debug for MergeStrategyLedger<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut ds = f.debug_struct("MergeStrategyLedger");
+        ds.field("primary_key", &self.primary_key());
+        ds.field("mode", &self.mode());
+        ds.field("dedupe_window_records", &self.dedupe_window_records());
+        ds.field("dedupe_window_seconds", &self.dedupe_window_seconds());
+        ds.finish()
+    }
+}
+pub enum MergeStrategySnapshotOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct MergeStrategySnapshot<'a> {
+    pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for MergeStrategySnapshot<'a> {
+    type Inner = MergeStrategySnapshot<'a>;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: flatbuffers::Table::new(buf, loc),
+        }
+    }
+}
+
+impl<'a> MergeStrategySnapshot<'a> {
+    pub const VT_PRIMARY_KEY: flatbuffers::VOffsetT = 4;
+    pub const VT_COMPARE_COLUMNS: flatbuffers::VOffsetT = 6;
+
+    #[inline]
+    pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        MergeStrategySnapshot { _tab: table }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+        args: &'args MergeStrategySnapshotArgs<'args>,
+    ) -> flatbuffers::WIPOffset<MergeStrategySnapshot<'bldr>> {
+        let mut builder = MergeStrategySnapshotBuilder::new(_fbb);
+        if let Some(x) = args.compare_columns {
+            builder.add_compare_columns(x);
+        }
+        if let Some(x) = args.primary_key {
+            builder.add_primary_key(x);
+        }
+        builder.finish()
+    }
+
+    #[inline]
+    pub fn primary_key(
+        &self,
+    ) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab.get::<flatbuffers::ForwardsUOffset<
+                flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>,
+            >>(MergeStrategySnapshot::VT_PRIMARY_KEY, None)
+        }
+    }
+    #[inline]
+    pub fn compare_columns(
+        &self,
+    ) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab.get::<flatbuffers::ForwardsUOffset<
+                flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>,
+            >>(MergeStrategySnapshot::VT_COMPARE_COLUMNS, None)
+        }
+    }
+}
+
+impl flatbuffers::Verifiable for MergeStrategySnapshot<'_> {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use self::flatbuffers::Verifiable;
+        v.visit_table(pos)?
+            .visit_field::<flatbuffers::ForwardsUOffset<
+                flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<&'_ str>>,
+            >>("primary_key", Self::VT_PRIMARY_KEY, false)?
+            .visit_field::<flatbuffers::ForwardsUOffset<
+                flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<&'_ str>>,
+            >>("compare_columns", Self::VT_COMPARE_COLUMNS, false)?
+            .finish();
+        Ok(())
+    }
+}
+pub struct MergeStrategySnapshotArgs<'a> {
+    pub primary_key: Option<
+        flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>,
+    >,
+    pub compare_columns: Option<
+        flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>,
+    >,
+}
+impl<'a> Default for MergeStrategySnapshotArgs<'a> {
+    #[inline]
+    fn default() -> Self {
+        MergeStrategySnapshotArgs {
+            primary_key: None,
+            compare_columns: None,
+        }
+    }
+}
+
+pub struct MergeStrategySnapshotBuilder<'a: 'b, 'b> {
+    fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> MergeStrategySnapshotBuilder<'a, 'b> {
+    #[inline]
+    pub fn add_primary_key(
+        &mut self,
+        primary_key: flatbuffers::WIPOffset<
+            flatbuffers::Vector<'b, flatbuffers::ForwardsUOffset<&'b str>>,
+        >,
+    ) {
+        self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(
+            MergeStrategySnapshot::VT_PRIMARY_KEY,
+            primary_key,
+        );
+    }
+    #[inline]
+    pub fn add_compare_columns(
+        &mut self,
+        compare_columns: flatbuffers::WIPOffset<
+            flatbuffers::Vector<'b, flatbuffers::ForwardsUOffset<&'b str>>,
+        >,
+    ) {
+        self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(
+            MergeStrategySnapshot::VT_COMPARE_COLUMNS,
+            compare_columns,
+        );
+    }
+    #[inline]
+    pub fn new(
+        _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    ) -> MergeStrategySnapshotBuilder<'a, 'b> {
+        let start = _fbb.start_table();
+        MergeStrategySnapshotBuilder {
+            fbb_: _fbb,
+            start_: start,
+        }
+    }
+    #[inline]
+    pub fn finish(self) -> flatbuffers::WIPOffset<MergeStrategySnapshot<'a>> {
+        let o = self.fbb_.end_table(self.start_);
+        flatbuffers::WIPOffset::new(o.value())
+    }
+}
+
+impl core::fmt::Debug for MergeStrategySnapshot<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut ds = f.debug_struct("MergeStrategySnapshot");
+        ds.field("primary_key", &self.primary_key());
+        ds.field("compare_columns", &self.compare_columns());
+        ds.finish()
+    }
+}
+pub enum WatermarkStrategyMaxEventTimeOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+pub struct WatermarkStrategyMaxEventTime<'a> {
+    pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for WatermarkStrategyMaxEventTime<'a> {
+    type Inner = WatermarkStrategyMaxEventTime<'a>;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: flatbuffers::Table::new(buf, loc),
+        }
+    }
+}
+
+impl<'a> WatermarkStrategyMaxEventTime<'a> {
+    #[inline]
+    pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        WatermarkStrategyMaxEventTime { _tab: table }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+        _args: &'args WatermarkStrategyMaxEventTimeArgs,
+    ) -> flatbuffers::WIPOffset<WatermarkStrategyMaxEventTime<'bldr>> {
+        let mut builder = WatermarkStrategyMaxEventTimeBuilder::new(_fbb);
+        builder.finish()
+    }
+}
+
+impl flatbuffers::Verifiable for WatermarkStrategyMaxEventTime<'_> {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use self::flatbuffers::Verifiable;
+        v.visit_table(pos)?.finish();
+        Ok(())
+    }
+}
+pub struct WatermarkStrategyMaxEventTimeArgs {}
+impl<'a> Default for WatermarkStrategyMaxEventTimeArgs {
+    #[inline]
+    fn default() -> Self {
+        WatermarkStrategyMaxEventTimeArgs {}
+    }
+}
+
+pub struct WatermarkStrategyMaxEventTimeBuilder<'a: 'b, 'b> {
+    fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> WatermarkStrategyMaxEventTimeBuilder<'a, 'b> {
+    #[inline]
+    pub fn new(
+        _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    ) -> WatermarkStrategyMaxEventTimeBuilder<'a, 'b> {
+        let start = _fbb.start_table();
+        WatermarkStrategyMaxEventTimeBuilder {
+            fbb_: _fbb,
+            start_: start,
+        }
+    }
+    #[inline]
+    pub fn finish(self) -> flatbuffers::WIPOffset<WatermarkStrategyMaxEventTime<'a>> {
+        let o = self.fbb_.end_table(self.start_);
+        flatbuffers::WIPOffset::new(o.value())
+    }
+}
+
+impl core::fmt::Debug for WatermarkStrategyMaxEventTime<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut ds = f.debug_struct("WatermarkStrategyMaxEventTime");
+        ds.finish()
+    }
+}
+pub enum WatermarkStrategyBoundedOutOfOrdernessOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct WatermarkStrategyBoundedOutOfOrderness<'a> {
+    pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for WatermarkStrategyBoundedOutOfOrderness<'a> {
+    type Inner = WatermarkStrategyBoundedOutOfOrderness<'a>;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: flatbuffers::Table::new(buf, loc),
+        }
+    }
+}
+
+impl<'a> WatermarkStrategyBoundedOutOfOrderness<'a> {
+    pub const VT_LAG_SECONDS: flatbuffers::VOffsetT = 4;
+
+    #[inline]
+    pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        WatermarkStrategyBoundedOutOfOrderness { _tab: table }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+        args: &'args WatermarkStrategyBoundedOutOfOrdernessArgs,
+    ) -> flatbuffers::WIPOffset<WatermarkStrategyBoundedOutOfOrderness<'bldr>> {
+        let mut builder = WatermarkStrategyBoundedOutOfOrdernessBuilder::new(_fbb);
+        builder.add_lag_seconds(args.lag_seconds);
+        builder.finish()
+    }
+
+    #[inline]
+    pub fn lag_seconds(&self) -> u64 {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<u64>(
+                    WatermarkStrategyBoundedOutOfOrderness::VT_LAG_SECONDS,
+                    Some(0),
+                )
+                .unwrap()
+        }
+    }
+}
+
+impl flatbuffers::Verifiable for WatermarkStrategyBoundedOutOfOrderness<'_> {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use self::flatbuffers::Verifiable;
+        v.visit_table(pos)?
+            .visit_field::<u64>("lag_seconds", Self::VT_LAG_SECONDS, false)?
+            .finish();
+        Ok(())
+    }
+}
+pub struct WatermarkStrategyBoundedOutOfOrdernessArgs {
+    pub lag_seconds: u64,
+}
+impl<'a> Default for WatermarkStrategyBoundedOutOfOrdernessArgs {
+    #[inline]
+    fn default() -> Self {
+        WatermarkStrategyBoundedOutOfOrdernessArgs { lag_seconds: 0 }
+    }
+}
+
+pub struct WatermarkStrategyBoundedOutOfOrdernessBuilder<'a: 'b, 'b> {
+    fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> WatermarkStrategyBoundedOutOfOrdernessBuilder<'a, 'b> {
+    #[inline]
+    pub fn add_lag_seconds(&mut self, lag_seconds: u64) {
+        self.fbb_.push_slot::<u64>(
+            WatermarkStrategyBoundedOutOfOrderness::VT_LAG_SECONDS,
+            lag_seconds,
+            0,
+        );
+    }
+    #[inline]
+    pub fn new(
+        _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    ) -> WatermarkStrategyBoundedOutOfOrdernessBuilder<'a, 'b> {
+        let start = _fbb.start_table();
+        WatermarkStrategyBoundedOutOfOrdernessBuilder {
+            fbb_: _fbb,
+            start_: start,
+        }
+    }
+    #[inline]
+    pub fn finish(self) -> flatbuffers::WIPOffset<WatermarkStrategyBoundedOutOfOrderness<'a>> {
+        let o = self.fbb_.end_table(self.start_);
+        flatbuffers::WIPOffset::new(o.value())
+    }
+}
+
+impl core::fmt::Debug for WatermarkStrategyBoundedOutOfOrderness<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut ds = f.debug_struct("WatermarkStrategyBoundedOutOfOrderness");
+        ds.field("lag_seconds", &self.lag_seconds());
+        ds.finish()
+    }
+}
+pub enum WatermarkStrategySystemTimeOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct WatermarkStrategySystemTime<'a> {
+    pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for WatermarkStrategySystemTime<'a> {
+    type Inner = WatermarkStrategySystemTime<'a>;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: flatbuffers::Table::new(buf, loc),
+        }
+    }
+}
+
+impl<'a> WatermarkStrategySystemTime<'a> {
+    pub const VT_DELAY_SECONDS: flatbuffers::VOffsetT = 4;
+    pub const VT_MIN_IDLE_ADVANCE_SECONDS: flatbuffers::VOffsetT = 6;
+
+    #[inline]
+    pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        WatermarkStrategySystemTime { _tab: table }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+        args: &'args WatermarkStrategySystemTimeArgs,
+    ) -> flatbuffers::WIPOffset<WatermarkStrategySystemTime<'bldr>> {
+        let mut builder = WatermarkStrategySystemTimeBuilder::new(_fbb);
+        if let Some(x) = args.min_idle_advance_seconds {
+            builder.add_min_idle_advance_seconds(x);
+        }
+        builder.add_delay_seconds(args.delay_seconds);
+        builder.finish()
+    }
+
+    #[inline]
+    pub fn delay_seconds(&self) -> u64 {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<u64>(WatermarkStrategySystemTime::VT_DELAY_SECONDS, Some(0))
+                .unwrap()
+        }
+    }
+    #[inline]
+    pub fn min_idle_advance_seconds(&self) -> Option<u64> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab.get::<u64>(
+                WatermarkStrategySystemTime::VT_MIN_IDLE_ADVANCE_SECONDS,
+                None,
+            )
         }
     }
 }
 
-impl flatbuffers::Verifiable for MergeStrategyLedger<'_> {
+impl flatbuffers::Verifiable for WatermarkStrategySystemTime<'_> {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
@@ -4523,75 +5714,84 @@ impl flatbuffers::Verifiable for MergeStrategyLedger<'_> {
     ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
         use self::flatbuffers::Verifiable;
         v.visit_table(pos)?
-            .visit_field::<flatbuffers::ForwardsUOffset<
-                flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<&'_ str>>,
-            >>("primary_key", Self::VT_PRIMARY_KEY, false)?
+            .visit_field::<u64>("delay_seconds", Self::VT_DELAY_SECONDS, false)?
+            .visit_field::<u64>(
+                "min_idle_advance_seconds",
+                Self::VT_MIN_IDLE_ADVANCE_SECONDS,
+                false,
+            )?
             .finish();
         Ok(())
     }
 }
-pub struct MergeStrategyLedgerArgs<'a> {
-    pub primary_key: Option<
-        flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>,
-    >,
+pub struct WatermarkStrategySystemTimeArgs {
+    pub delay_seconds: u64,
+    pub min_idle_advance_seconds: Option<u64>,
 }
-impl<'a> Default for MergeStrategyLedgerArgs<'a> {
+impl<'a> Default for WatermarkStrategySystemTimeArgs {
     #[inline]
     fn default() -> Self {
-        MergeStrategyLedgerArgs { primary_key: None }
+        WatermarkStrategySystemTimeArgs {
+            delay_seconds: 0,
+            min_idle_advance_seconds: None,
+        }
     }
 }
 
-pub struct MergeStrategyLedgerBuilder<'a: 'b, 'b> {
+pub struct WatermarkStrategySystemTimeBuilder<'a: 'b, 'b> {
     fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
     start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b> MergeStrategyLedgerBuilder<'a, 'b> {
+impl<'a: 'b, 'b> WatermarkStrategySystemTimeBuilder<'a, 'b> {
     #[inline]
-    pub fn add_primary_key(
-        &mut self,
-        primary_key: flatbuffers::WIPOffset<
-            flatbuffers::Vector<'b, flatbuffers::ForwardsUOffset<&'b str>>,
-        >,
-    ) {
-        self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(
-            MergeStrategyLedger::VT_PRIMARY_KEY,
-            primary_key,
+    pub fn add_delay_seconds(&mut self, delay_seconds: u64) {
+        self.fbb_.push_slot::<u64>(
+            WatermarkStrategySystemTime::VT_DELAY_SECONDS,
+            delay_seconds,
+            0,
+        );
+    }
+    #[inline]
+    pub fn add_min_idle_advance_seconds(&mut self, min_idle_advance_seconds: u64) {
+        self.fbb_.push_slot_always::<u64>(
+            WatermarkStrategySystemTime::VT_MIN_IDLE_ADVANCE_SECONDS,
+            min_idle_advance_seconds,
         );
     }
     #[inline]
     pub fn new(
         _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
-    ) -> MergeStrategyLedgerBuilder<'a, 'b> {
+    ) -> WatermarkStrategySystemTimeBuilder<'a, 'b> {
         let start = _fbb.start_table();
-        MergeStrategyLedgerBuilder {
+        WatermarkStrategySystemTimeBuilder {
             fbb_: _fbb,
             start_: start,
         }
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<MergeStrategyLedger<'a>> {
+    pub fn finish(self) -> flatbuffers::WIPOffset<WatermarkStrategySystemTime<'a>> {
         let o = self.fbb_.end_table(self.start_);
         flatbuffers::WIPOffset::new(o.value())
     }
 }
 
-impl core::fmt::Debug for MergeStrategyLedger<'_> {
+impl core::fmt::Debug for WatermarkStrategySystemTime<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("MergeStrategyLedger");
-        ds.field("primary_key", &self.primary_key());
+        let mut ds = f.debug_struct("WatermarkStrategySystemTime");
+        ds.field("delay_seconds", &self.delay_seconds());
+        ds.field("min_idle_advance_seconds", &self.min_idle_advance_seconds());
         ds.finish()
     }
 }
-pub enum MergeStrategySnapshotOffset {}
+pub enum WatermarkStrategyManualOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct MergeStrategySnapshot<'a> {
+pub struct WatermarkStrategyManual<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for MergeStrategySnapshot<'a> {
-    type Inner = MergeStrategySnapshot<'a>;
+impl<'a> flatbuffers::Follow<'a> for WatermarkStrategyManual<'a> {
+    type Inner = WatermarkStrategyManual<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -4600,144 +5800,65 @@ impl<'a> flatbuffers::Follow<'a> for MergeStrategySnapshot<'a> {
     }
 }
 
-impl<'a> MergeStrategySnapshot<'a> {
-    pub const VT_PRIMARY_KEY: flatbuffers::VOffsetT = 4;
-    pub const VT_COMPARE_COLUMNS: flatbuffers::VOffsetT = 6;
-
+impl<'a> WatermarkStrategyManual<'a> {
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        MergeStrategySnapshot { _tab: table }
+        WatermarkStrategyManual { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
-        args: &'args MergeStrategySnapshotArgs<'args>,
-    ) -> flatbuffers::WIPOffset<MergeStrategySnapshot<'bldr>> {
-        let mut builder = MergeStrategySnapshotBuilder::new(_fbb);
-        if let Some(x) = args.compare_columns {
-            builder.add_compare_columns(x);
-        }
-        if let Some(x) = args.primary_key {
-            builder.add_primary_key(x);
-        }
+        _args: &'args WatermarkStrategyManualArgs,
+    ) -> flatbuffers::WIPOffset<WatermarkStrategyManual<'bldr>> {
+        let mut builder = WatermarkStrategyManualBuilder::new(_fbb);
         builder.finish()
     }
-
-    #[inline]
-    pub fn primary_key(
-        &self,
-    ) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>> {
-        // Safety:
-        // Created from valid Table for this object
-        // which contains a valid value in this slot
-        unsafe {
-            self._tab.get::<flatbuffers::ForwardsUOffset<
-                flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>,
-            >>(MergeStrategySnapshot::VT_PRIMARY_KEY, None)
-        }
-    }
-    #[inline]
-    pub fn compare_columns(
-        &self,
-    ) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>> {
-        // Safety:
-        // Created from valid Table for this object
-        // which contains a valid value in this slot
-        unsafe {
-            self._tab.get::<flatbuffers::ForwardsUOffset<
-                flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>,
-            >>(MergeStrategySnapshot::VT_COMPARE_COLUMNS, None)
-        }
-    }
 }
 
-impl flatbuffers::Verifiable for MergeStrategySnapshot<'_> {
+impl flatbuffers::Verifiable for WatermarkStrategyManual<'_> {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
         pos: usize,
     ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
         use self::flatbuffers::Verifiable;
-        v.visit_table(pos)?
-            .visit_field::<flatbuffers::ForwardsUOffset<
-                flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<&'_ str>>,
-            >>("primary_key", Self::VT_PRIMARY_KEY, false)?
-            .visit_field::<flatbuffers::ForwardsUOffset<
-                flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<&'_ str>>,
-            >>("compare_columns", Self::VT_COMPARE_COLUMNS, false)?
-            .finish();
+        v.visit_table(pos)?.finish();
         Ok(())
     }
 }
-pub struct MergeStrategySnapshotArgs<'a> {
-    pub primary_key: Option<
-        flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>,
-    >,
-    pub compare_columns: Option<
-        flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>,
-    >,
-}
-impl<'a> Default for MergeStrategySnapshotArgs<'a> {
+pub struct WatermarkStrategyManualArgs {}
+impl<'a> Default for WatermarkStrategyManualArgs {
     #[inline]
     fn default() -> Self {
-        MergeStrategySnapshotArgs {
-            primary_key: None,
-            compare_columns: None,
-        }
+        WatermarkStrategyManualArgs {}
     }
 }
 
-pub struct MergeStrategySnapshotBuilder<'a: 'b, 'b> {
+pub struct WatermarkStrategyManualBuilder<'a: 'b, 'b> {
     fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
     start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b> MergeStrategySnapshotBuilder<'a, 'b> {
-    #[inline]
-    pub fn add_primary_key(
-        &mut self,
-        primary_key: flatbuffers::WIPOffset<
-            flatbuffers::Vector<'b, flatbuffers::ForwardsUOffset<&'b str>>,
-        >,
-    ) {
-        self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(
-            MergeStrategySnapshot::VT_PRIMARY_KEY,
-            primary_key,
-        );
-    }
-    #[inline]
-    pub fn add_compare_columns(
-        &mut self,
-        compare_columns: flatbuffers::WIPOffset<
-            flatbuffers::Vector<'b, flatbuffers::ForwardsUOffset<&'b str>>,
-        >,
-    ) {
-        self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(
-            MergeStrategySnapshot::VT_COMPARE_COLUMNS,
-            compare_columns,
-        );
-    }
+impl<'a: 'b, 'b> WatermarkStrategyManualBuilder<'a, 'b> {
     #[inline]
     pub fn new(
         _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
-    ) -> MergeStrategySnapshotBuilder<'a, 'b> {
+    ) -> WatermarkStrategyManualBuilder<'a, 'b> {
         let start = _fbb.start_table();
-        MergeStrategySnapshotBuilder {
+        WatermarkStrategyManualBuilder {
             fbb_: _fbb,
             start_: start,
         }
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<MergeStrategySnapshot<'a>> {
+    pub fn finish(self) -> flatbuffers::WIPOffset<WatermarkStrategyManual<'a>> {
         let o = self.fbb_.end_table(self.start_);
         flatbuffers::WIPOffset::new(o.value())
     }
 }
 
-impl core::fmt::Debug for MergeStrategySnapshot<'_> {
+impl core::fmt::Debug for WatermarkStrategyManual<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("MergeStrategySnapshot");
-        ds.field("primary_key", &self.primary_key());
-        ds.field("compare_columns", &self.compare_columns());
+        let mut ds = f.debug_struct("WatermarkStrategyManual");
         ds.finish()
     }
 }
@@ -4767,6 +5888,8 @@ impl<'a> AddPushSource<'a> {
     pub const VT_PREPROCESS: flatbuffers::VOffsetT = 12;
     pub const VT_MERGE_TYPE: flatbuffers::VOffsetT = 14;
     pub const VT_MERGE: flatbuffers::VOffsetT = 16;
+    pub const VT_WATERMARK_TYPE: flatbuffers::VOffsetT = 18;
+    pub const VT_WATERMARK: flatbuffers::VOffsetT = 20;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
@@ -4778,6 +5901,9 @@ impl<'a> AddPushSource<'a> {
         args: &'args AddPushSourceArgs<'args>,
     ) -> flatbuffers::WIPOffset<AddPushSource<'bldr>> {
         let mut builder = AddPushSourceBuilder::new(_fbb);
+        if let Some(x) = args.watermark {
+            builder.add_watermark(x);
+        }
         if let Some(x) = args.merge {
             builder.add_merge(x);
         }
@@ -4790,6 +5916,7 @@ impl<'a> AddPushSource<'a> {
         if let Some(x) = args.source_name {
             builder.add_source_name(x);
         }
+        builder.add_watermark_type(args.watermark_type);
         builder.add_merge_type(args.merge_type);
         builder.add_preprocess_type(args.preprocess_type);
         builder.add_read_type(args.read_type);
@@ -4879,6 +6006,33 @@ impl<'a> AddPushSource<'a> {
         }
     }
     #[inline]
+    pub fn watermark_type(&self) -> WatermarkStrategy {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<WatermarkStrategy>(
+                    AddPushSource::VT_WATERMARK_TYPE,
+                    Some(WatermarkStrategy::NONE),
+                )
+                .unwrap()
+        }
+    }
+    #[inline]
+    pub fn watermark(&self) -> Option<flatbuffers::Table<'a>> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Table<'a>>>(
+                    AddPushSource::VT_WATERMARK,
+                    None,
+                )
+        }
+    }
+    #[inline]
     #[allow(non_snake_case)]
     pub fn read_as_read_step_csv(&self) -> Option<ReadStepCsv<'a>> {
         if self.read_type() == ReadStep::ReadStepCsv {
@@ -4976,7 +6130,112 @@ impl<'a> AddPushSource<'a> {
                 // Safety:
                 // Created from a valid Table for this object
                 // Which contains a valid union in this slot
-                unsafe { ReadStepNdGeoJson::init_from_table(t) }
+                unsafe { ReadStepNdGeoJson::init_from_table(t) }
+            })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn read_as_read_step_avro(&self) -> Option<ReadStepAvro<'a>> {
+        if self.read_type() == ReadStep::ReadStepAvro {
+            self.read().map(|t| {
+                // Safety:
+                // Created from a valid Table for this object
+                // Which contains a valid union in this slot
+                unsafe { ReadStepAvro::init_from_table(t) }
+            })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn read_as_read_step_orc(&self) -> Option<ReadStepOrc<'a>> {
+        if self.read_type() == ReadStep::ReadStepOrc {
+            self.read().map(|t| {
+                // Safety:
+                // Created from a valid Table for this object
+                // Which contains a valid union in this slot
+                unsafe { ReadStepOrc::init_from_table(t) }
+            })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn read_as_read_step_excel(&self) -> Option<ReadStepExcel<'a>> {
+        if self.read_type() == ReadStep::ReadStepExcel {
+            self.read().map(|t| {
+                // Safety:
+                // Created from a valid Table for this object
+                // Which contains a valid union in this slot
+                unsafe { ReadStepExcel::init_from_table(t) }
+            })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn preprocess_as_transform_sql(&self) -> Option<TransformSql<'a>> {
+        if self.preprocess_type() == Transform::TransformSql {
+            self.preprocess().map(|t| {
+                // Safety:
+                // Created from a valid Table for this object
+                // Which contains a valid union in this slot
+                unsafe { TransformSql::init_from_table(t) }
+            })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn merge_as_merge_strategy_append(&self) -> Option<MergeStrategyAppend<'a>> {
+        if self.merge_type() == MergeStrategy::MergeStrategyAppend {
+            self.merge().map(|t| {
+                // Safety:
+                // Created from a valid Table for this object
+                // Which contains a valid union in this slot
+                unsafe { MergeStrategyAppend::init_from_table(t) }
+            })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn merge_as_merge_strategy_ledger(&self) -> Option<MergeStrategyLedger<'a>> {
+        if self.merge_type() == MergeStrategy::MergeStrategyLedger {
+            self.merge().map(|t| {
+                // Safety:
+                // Created from a valid Table for this object
+                // Which contains a valid union in this slot
+                unsafe { MergeStrategyLedger::init_from_table(t) }
+            })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn merge_as_merge_strategy_snapshot(&self) -> Option<MergeStrategySnapshot<'a>> {
+        if self.merge_type() == MergeStrategy::MergeStrategySnapshot {
+            self.merge().map(|t| {
+                // Safety:
+                // Created from a valid Table for this object
+                // Which contains a valid union in this slot
+                unsafe { MergeStrategySnapshot::init_from_table(t) }
             })
         } else {
             None
@@ -4985,13 +6244,15 @@ impl<'a> AddPushSource<'a> {
 
     #[inline]
     #[allow(non_snake_case)]
-    pub fn preprocess_as_transform_sql(&self) -> Option<TransformSql<'a>> {
-        if self.preprocess_type() == Transform::TransformSql {
-            self.preprocess().map(|t| {
+    pub fn watermark_as_watermark_strategy_max_event_time(
+        &self,
+    ) -> Option<WatermarkStrategyMaxEventTime<'a>> {
+        if self.watermark_type() == WatermarkStrategy::WatermarkStrategyMaxEventTime {
+            self.watermark().map(|t| {
                 // Safety:
                 // Created from a valid Table for this object
                 // Which contains a valid union in this slot
-                unsafe { TransformSql::init_from_table(t) }
+                unsafe { WatermarkStrategyMaxEventTime::init_from_table(t) }
             })
         } else {
             None
@@ -5000,13 +6261,15 @@ impl<'a> AddPushSource<'a> {
 
     #[inline]
     #[allow(non_snake_case)]
-    pub fn merge_as_merge_strategy_append(&self) -> Option<MergeStrategyAppend<'a>> {
-        if self.merge_type() == MergeStrategy::MergeStrategyAppend {
-            self.merge().map(|t| {
+    pub fn watermark_as_watermark_strategy_bounded_out_of_orderness(
+        &self,
+    ) -> Option<WatermarkStrategyBoundedOutOfOrderness<'a>> {
+        if self.watermark_type() == WatermarkStrategy::WatermarkStrategyBoundedOutOfOrderness {
+            self.watermark().map(|t| {
                 // Safety:
                 // Created from a valid Table for this object
                 // Which contains a valid union in this slot
-                unsafe { MergeStrategyAppend::init_from_table(t) }
+                unsafe { WatermarkStrategyBoundedOutOfOrderness::init_from_table(t) }
             })
         } else {
             None
@@ -5015,13 +6278,15 @@ impl<'a> AddPushSource<'a> {
 
     #[inline]
     #[allow(non_snake_case)]
-    pub fn merge_as_merge_strategy_ledger(&self) -> Option<MergeStrategyLedger<'a>> {
-        if self.merge_type() == MergeStrategy::MergeStrategyLedger {
-            self.merge().map(|t| {
+    pub fn watermark_as_watermark_strategy_system_time(
+        &self,
+    ) -> Option<WatermarkStrategySystemTime<'a>> {
+        if self.watermark_type() == WatermarkStrategy::WatermarkStrategySystemTime {
+            self.watermark().map(|t| {
                 // Safety:
                 // Created from a valid Table for this object
                 // Which contains a valid union in this slot
-                unsafe { MergeStrategyLedger::init_from_table(t) }
+                unsafe { WatermarkStrategySystemTime::init_from_table(t) }
             })
         } else {
             None
@@ -5030,13 +6295,13 @@ impl<'a> AddPushSource<'a> {
 
     #[inline]
     #[allow(non_snake_case)]
-    pub fn merge_as_merge_strategy_snapshot(&self) -> Option<MergeStrategySnapshot<'a>> {
-        if self.merge_type() == MergeStrategy::MergeStrategySnapshot {
-            self.merge().map(|t| {
+    pub fn watermark_as_watermark_strategy_manual(&self) -> Option<WatermarkStrategyManual<'a>> {
+        if self.watermark_type() == WatermarkStrategy::WatermarkStrategyManual {
+            self.watermark().map(|t| {
                 // Safety:
                 // Created from a valid Table for this object
                 // Which contains a valid union in this slot
-                unsafe { MergeStrategySnapshot::init_from_table(t) }
+                unsafe { WatermarkStrategyManual::init_from_table(t) }
             })
         } else {
             None
@@ -5062,6 +6327,9 @@ impl flatbuffers::Verifiable for AddPushSource<'_> {
           ReadStep::ReadStepJson => v.verify_union_variant::<flatbuffers::ForwardsUOffset<ReadStepJson>>("ReadStep::ReadStepJson", pos),
           ReadStep::ReadStepNdJson => v.verify_union_variant::<flatbuffers::ForwardsUOffset<ReadStepNdJson>>("ReadStep::ReadStepNdJson", pos),
           ReadStep::ReadStepNdGeoJson => v.verify_union_variant::<flatbuffers::ForwardsUOffset<ReadStepNdGeoJson>>("ReadStep::ReadStepNdGeoJson", pos),
+          ReadStep::ReadStepAvro => v.verify_union_variant::<flatbuffers::ForwardsUOffset<ReadStepAvro>>("ReadStep::ReadStepAvro", pos),
+          ReadStep::ReadStepOrc => v.verify_union_variant::<flatbuffers::ForwardsUOffset<ReadStepOrc>>("ReadStep::ReadStepOrc", pos),
+          ReadStep::ReadStepExcel => v.verify_union_variant::<flatbuffers::ForwardsUOffset<ReadStepExcel>>("ReadStep::ReadStepExcel", pos),
           _ => Ok(()),
         }
      })?
@@ -5079,6 +6347,15 @@ impl flatbuffers::Verifiable for AddPushSource<'_> {
           _ => Ok(()),
         }
      })?
+     .visit_union::<WatermarkStrategy, _>("watermark_type", Self::VT_WATERMARK_TYPE, "watermark", Self::VT_WATERMARK, false, |key, v, pos| {
+        match key {
+          WatermarkStrategy::WatermarkStrategyMaxEventTime => v.verify_union_variant::<flatbuffers::ForwardsUOffset<WatermarkStrategyMaxEventTime>>("WatermarkStrategy::WatermarkStrategyMaxEventTime", pos),
+          WatermarkStrategy::WatermarkStrategyBoundedOutOfOrderness => v.verify_union_variant::<flatbuffers::ForwardsUOffset<WatermarkStrategyBoundedOutOfOrderness>>("WatermarkStrategy::WatermarkStrategyBoundedOutOfOrderness", pos),
+          WatermarkStrategy::WatermarkStrategySystemTime => v.verify_union_variant::<flatbuffers::ForwardsUOffset<WatermarkStrategySystemTime>>("WatermarkStrategy::WatermarkStrategySystemTime", pos),
+          WatermarkStrategy::WatermarkStrategyManual => v.verify_union_variant::<flatbuffers::ForwardsUOffset<WatermarkStrategyManual>>("WatermarkStrategy::WatermarkStrategyManual", pos),
+          _ => Ok(()),
+        }
+     })?
      .finish();
         Ok(())
     }
@@ -5091,6 +6368,8 @@ pub struct AddPushSourceArgs<'a> {
     pub preprocess: Option<flatbuffers::WIPOffset<flatbuffers::UnionWIPOffset>>,
     pub merge_type: MergeStrategy,
     pub merge: Option<flatbuffers::WIPOffset<flatbuffers::UnionWIPOffset>>,
+    pub watermark_type: WatermarkStrategy,
+    pub watermark: Option<flatbuffers::WIPOffset<flatbuffers::UnionWIPOffset>>,
 }
 impl<'a> Default for AddPushSourceArgs<'a> {
     #[inline]
@@ -5103,6 +6382,8 @@ impl<'a> Default for AddPushSourceArgs<'a> {
             preprocess: None,
             merge_type: MergeStrategy::NONE,
             merge: None,
+            watermark_type: WatermarkStrategy::NONE,
+            watermark: None,
         }
     }
 }
@@ -5161,6 +6442,22 @@ impl<'a: 'b, 'b> AddPushSourceBuilder<'a, 'b> {
             .push_slot_always::<flatbuffers::WIPOffset<_>>(AddPushSource::VT_MERGE, merge);
     }
     #[inline]
+    pub fn add_watermark_type(&mut self, watermark_type: WatermarkStrategy) {
+        self.fbb_.push_slot::<WatermarkStrategy>(
+            AddPushSource::VT_WATERMARK_TYPE,
+            watermark_type,
+            WatermarkStrategy::NONE,
+        );
+    }
+    #[inline]
+    pub fn add_watermark(
+        &mut self,
+        watermark: flatbuffers::WIPOffset<flatbuffers::UnionWIPOffset>,
+    ) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(AddPushSource::VT_WATERMARK, watermark);
+    }
+    #[inline]
     pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> AddPushSourceBuilder<'a, 'b> {
         let start = _fbb.start_table();
         AddPushSourceBuilder {
@@ -5251,6 +6548,36 @@ impl core::fmt::Debug for AddPushSource<'_> {
                     )
                 }
             }
+            ReadStep::ReadStepAvro => {
+                if let Some(x) = self.read_as_read_step_avro() {
+                    ds.field("read", &x)
+                } else {
+                    ds.field(
+                        "read",
+                        &"InvalidFlatbuffer: Union discriminant does not match value.",
+                    )
+                }
+            }
+            ReadStep::ReadStepOrc => {
+                if let Some(x) = self.read_as_read_step_orc() {
+                    ds.field("read", &x)
+                } else {
+                    ds.field(
+                        "read",
+                        &"InvalidFlatbuffer: Union discriminant does not match value.",
+                    )
+                }
+            }
+            ReadStep::ReadStepExcel => {
+                if let Some(x) = self.read_as_read_step_excel() {
+                    ds.field("read", &x)
+                } else {
+                    ds.field(
+                        "read",
+                        &"InvalidFlatbuffer: Union discriminant does not match value.",
+                    )
+                }
+            }
             _ => {
                 let x: Option<()> = None;
                 ds.field("read", &x)
@@ -5310,6 +6637,53 @@ impl core::fmt::Debug for AddPushSource<'_> {
                 ds.field("merge", &x)
             }
         };
+        ds.field("watermark_type", &self.watermark_type());
+        match self.watermark_type() {
+            WatermarkStrategy::WatermarkStrategyMaxEventTime => {
+                if let Some(x) = self.watermark_as_watermark_strategy_max_event_time() {
+                    ds.field("watermark", &x)
+                } else {
+                    ds.field(
+                        "watermark",
+                        &"InvalidFlatbuffer: Union discriminant does not match value.",
+                    )
+                }
+            }
+            WatermarkStrategy::WatermarkStrategyBoundedOutOfOrderness => {
+                if let Some(x) = self.watermark_as_watermark_strategy_bounded_out_of_orderness() {
+                    ds.field("watermark", &x)
+                } else {
+                    ds.field(
+                        "watermark",
+                        &"InvalidFlatbuffer: Union discriminant does not match value.",
+                    )
+                }
+            }
+            WatermarkStrategy::WatermarkStrategySystemTime => {
+                if let Some(x) = self.watermark_as_watermark_strategy_system_time() {
+                    ds.field("watermark", &x)
+                } else {
+                    ds.field(
+                        "watermark",
+                        &"InvalidFlatbuffer: Union discriminant does not match value.",
+                    )
+                }
+            }
+            WatermarkStrategy::WatermarkStrategyManual => {
+                if let Some(x) = self.watermark_as_watermark_strategy_manual() {
+                    ds.field("watermark", &x)
+                } else {
+                    ds.field(
+                        "watermark",
+                        &"InvalidFlatbuffer: Union discriminant does not match value.",
+                    )
+                }
+            }
+            _ => {
+                let x: Option<()> = None;
+                ds.field("watermark", &x)
+            }
+        };
         ds.finish()
     }
 }
@@ -8812,6 +10186,8 @@ impl<'a> SetPollingSource<'a> {
     pub const VT_PREPROCESS: flatbuffers::VOffsetT = 16;
     pub const VT_MERGE_TYPE: flatbuffers::VOffsetT = 18;
     pub const VT_MERGE: flatbuffers::VOffsetT = 20;
+    pub const VT_WATERMARK_TYPE: flatbuffers::VOffsetT = 22;
+    pub const VT_WATERMARK: flatbuffers::VOffsetT = 24;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
@@ -8823,6 +10199,9 @@ impl<'a> SetPollingSource<'a> {
         args: &'args SetPollingSourceArgs<'args>,
     ) -> flatbuffers::WIPOffset<SetPollingSource<'bldr>> {
         let mut builder = SetPollingSourceBuilder::new(_fbb);
+        if let Some(x) = args.watermark {
+            builder.add_watermark(x);
+        }
         if let Some(x) = args.merge {
             builder.add_merge(x);
         }
@@ -8838,6 +10217,7 @@ impl<'a> SetPollingSource<'a> {
         if let Some(x) = args.fetch {
             builder.add_fetch(x);
         }
+        builder.add_watermark_type(args.watermark_type);
         builder.add_merge_type(args.merge_type);
         builder.add_preprocess_type(args.preprocess_type);
         builder.add_read_type(args.read_type);
@@ -8955,6 +10335,33 @@ impl<'a> SetPollingSource<'a> {
         }
     }
     #[inline]
+    pub fn watermark_type(&self) -> WatermarkStrategy {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<WatermarkStrategy>(
+                    SetPollingSource::VT_WATERMARK_TYPE,
+                    Some(WatermarkStrategy::NONE),
+                )
+                .unwrap()
+        }
+    }
+    #[inline]
+    pub fn watermark(&self) -> Option<flatbuffers::Table<'a>> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Table<'a>>>(
+                    SetPollingSource::VT_WATERMARK,
+                    None,
+                )
+        }
+    }
+    #[inline]
     #[allow(non_snake_case)]
     pub fn fetch_as_fetch_step_url(&self) -> Option<FetchStepUrl<'a>> {
         if self.fetch_type() == FetchStep::FetchStepUrl {
@@ -9134,6 +10541,51 @@ impl<'a> SetPollingSource<'a> {
         }
     }
 
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn read_as_read_step_avro(&self) -> Option<ReadStepAvro<'a>> {
+        if self.read_type() == ReadStep::ReadStepAvro {
+            self.read().map(|t| {
+                // Safety:
+                // Created from a valid Table for this object
+                // Which contains a valid union in this slot
+                unsafe { ReadStepAvro::init_from_table(t) }
+            })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn read_as_read_step_orc(&self) -> Option<ReadStepOrc<'a>> {
+        if self.read_type() == ReadStep::ReadStepOrc {
+            self.read().map(|t| {
+                // Safety:
+                // Created from a valid Table for this object
+                // Which contains a valid union in this slot
+                unsafe { ReadStepOrc::init_from_table(t) }
+            })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn read_as_read_step_excel(&self) -> Option<ReadStepExcel<'a>> {
+        if self.read_type() == ReadStep::ReadStepExcel {
+            self.read().map(|t| {
+                // Safety:
+                // Created from a valid Table for this object
+                // Which contains a valid union in this slot
+                unsafe { ReadStepExcel::init_from_table(t) }
+            })
+        } else {
+            None
+        }
+    }
+
     #[inline]
     #[allow(non_snake_case)]
     pub fn preprocess_as_transform_sql(&self) -> Option<TransformSql<'a>> {
@@ -9193,6 +10645,72 @@ impl<'a> SetPollingSource<'a> {
             None
         }
     }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn watermark_as_watermark_strategy_max_event_time(
+        &self,
+    ) -> Option<WatermarkStrategyMaxEventTime<'a>> {
+        if self.watermark_type() == WatermarkStrategy::WatermarkStrategyMaxEventTime {
+            self.watermark().map(|t| {
+                // Safety:
+                // Created from a valid Table for this object
+                // Which contains a valid union in this slot
+                unsafe { WatermarkStrategyMaxEventTime::init_from_table(t) }
+            })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn watermark_as_watermark_strategy_bounded_out_of_orderness(
+        &self,
+    ) -> Option<WatermarkStrategyBoundedOutOfOrderness<'a>> {
+        if self.watermark_type() == WatermarkStrategy::WatermarkStrategyBoundedOutOfOrderness {
+            self.watermark().map(|t| {
+                // Safety:
+                // Created from a valid Table for this object
+                // Which contains a valid union in this slot
+                unsafe { WatermarkStrategyBoundedOutOfOrderness::init_from_table(t) }
+            })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn watermark_as_watermark_strategy_system_time(
+        &self,
+    ) -> Option<WatermarkStrategySystemTime<'a>> {
+        if self.watermark_type() == WatermarkStrategy::WatermarkStrategySystemTime {
+            self.watermark().map(|t| {
+                // Safety:
+                // Created from a valid Table for this object
+                // Which contains a valid union in this slot
+                unsafe { WatermarkStrategySystemTime::init_from_table(t) }
+            })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn watermark_as_watermark_strategy_manual(&self) -> Option<WatermarkStrategyManual<'a>> {
+        if self.watermark_type() == WatermarkStrategy::WatermarkStrategyManual {
+            self.watermark().map(|t| {
+                // Safety:
+                // Created from a valid Table for this object
+                // Which contains a valid union in this slot
+                unsafe { WatermarkStrategyManual::init_from_table(t) }
+            })
+        } else {
+            None
+        }
+    }
 }
 
 impl flatbuffers::Verifiable for SetPollingSource<'_> {
@@ -9223,6 +10741,9 @@ impl flatbuffers::Verifiable for SetPollingSource<'_> {
           ReadStep::ReadStepJson => v.verify_union_variant::<flatbuffers::ForwardsUOffset<ReadStepJson>>("ReadStep::ReadStepJson", pos),
           ReadStep::ReadStepNdJson => v.verify_union_variant::<flatbuffers::ForwardsUOffset<ReadStepNdJson>>("ReadStep::ReadStepNdJson", pos),
           ReadStep::ReadStepNdGeoJson => v.verify_union_variant::<flatbuffers::ForwardsUOffset<ReadStepNdGeoJson>>("ReadStep::ReadStepNdGeoJson", pos),
+          ReadStep::ReadStepAvro => v.verify_union_variant::<flatbuffers::ForwardsUOffset<ReadStepAvro>>("ReadStep::ReadStepAvro", pos),
+          ReadStep::ReadStepOrc => v.verify_union_variant::<flatbuffers::ForwardsUOffset<ReadStepOrc>>("ReadStep::ReadStepOrc", pos),
+          ReadStep::ReadStepExcel => v.verify_union_variant::<flatbuffers::ForwardsUOffset<ReadStepExcel>>("ReadStep::ReadStepExcel", pos),
           _ => Ok(()),
         }
      })?
@@ -9240,6 +10761,15 @@ impl flatbuffers::Verifiable for SetPollingSource<'_> {
           _ => Ok(()),
         }
      })?
+     .visit_union::<WatermarkStrategy, _>("watermark_type", Self::VT_WATERMARK_TYPE, "watermark", Self::VT_WATERMARK, false, |key, v, pos| {
+        match key {
+          WatermarkStrategy::WatermarkStrategyMaxEventTime => v.verify_union_variant::<flatbuffers::ForwardsUOffset<WatermarkStrategyMaxEventTime>>("WatermarkStrategy::WatermarkStrategyMaxEventTime", pos),
+          WatermarkStrategy::WatermarkStrategyBoundedOutOfOrderness => v.verify_union_variant::<flatbuffers::ForwardsUOffset<WatermarkStrategyBoundedOutOfOrderness>>("WatermarkStrategy::WatermarkStrategyBoundedOutOfOrderness", pos),
+          WatermarkStrategy::WatermarkStrategySystemTime => v.verify_union_variant::<flatbuffers::ForwardsUOffset<WatermarkStrategySystemTime>>("WatermarkStrategy::WatermarkStrategySystemTime", pos),
+          WatermarkStrategy::WatermarkStrategyManual => v.verify_union_variant::<flatbuffers::ForwardsUOffset<WatermarkStrategyManual>>("WatermarkStrategy::WatermarkStrategyManual", pos),
+          _ => Ok(()),
+        }
+     })?
      .finish();
         Ok(())
     }
@@ -9258,6 +10788,8 @@ pub struct SetPollingSourceArgs<'a> {
     pub preprocess: Option<flatbuffers::WIPOffset<flatbuffers::UnionWIPOffset>>,
     pub merge_type: MergeStrategy,
     pub merge: Option<flatbuffers::WIPOffset<flatbuffers::UnionWIPOffset>>,
+    pub watermark_type: WatermarkStrategy,
+    pub watermark: Option<flatbuffers::WIPOffset<flatbuffers::UnionWIPOffset>>,
 }
 impl<'a> Default for SetPollingSourceArgs<'a> {
     #[inline]
@@ -9272,6 +10804,8 @@ impl<'a> Default for SetPollingSourceArgs<'a> {
             preprocess: None,
             merge_type: MergeStrategy::NONE,
             merge: None,
+            watermark_type: WatermarkStrategy::NONE,
+            watermark: None,
         }
     }
 }
@@ -9346,6 +10880,24 @@ impl<'a: 'b, 'b> SetPollingSourceBuilder<'a, 'b> {
             .push_slot_always::<flatbuffers::WIPOffset<_>>(SetPollingSource::VT_MERGE, merge);
     }
     #[inline]
+    pub fn add_watermark_type(&mut self, watermark_type: WatermarkStrategy) {
+        self.fbb_.push_slot::<WatermarkStrategy>(
+            SetPollingSource::VT_WATERMARK_TYPE,
+            watermark_type,
+            WatermarkStrategy::NONE,
+        );
+    }
+    #[inline]
+    pub fn add_watermark(
+        &mut self,
+        watermark: flatbuffers::WIPOffset<flatbuffers::UnionWIPOffset>,
+    ) {
+        self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(
+            SetPollingSource::VT_WATERMARK,
+            watermark,
+        );
+    }
+    #[inline]
     pub fn new(
         _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
     ) -> SetPollingSourceBuilder<'a, 'b> {
@@ -9495,6 +11047,36 @@ impl core::fmt::Debug for SetPollingSource<'_> {
                     )
                 }
             }
+            ReadStep::ReadStepAvro => {
+                if let Some(x) = self.read_as_read_step_avro() {
+                    ds.field("read", &x)
+                } else {
+                    ds.field(
+                        "read",
+                        &"InvalidFlatbuffer: Union discriminant does not match value.",
+                    )
+                }
+            }
+            ReadStep::ReadStepOrc => {
+                if let Some(x) = self.read_as_read_step_orc() {
+                    ds.field("read", &x)
+                } else {
+                    ds.field(
+                        "read",
+                        &"InvalidFlatbuffer: Union discriminant does not match value.",
+                    )
+                }
+            }
+            ReadStep::ReadStepExcel => {
+                if let Some(x) = self.read_as_read_step_excel() {
+                    ds.field("read", &x)
+                } else {
+                    ds.field(
+                        "read",
+                        &"InvalidFlatbuffer: Union discriminant does not match value.",
+                    )
+                }
+            }
             _ => {
                 let x: Option<()> = None;
                 ds.field("read", &x)
@@ -9554,6 +11136,53 @@ impl core::fmt::Debug for SetPollingSource<'_> {
                 ds.field("merge", &x)
             }
         };
+        ds.field("watermark_type", &self.watermark_type());
+        match self.watermark_type() {
+            WatermarkStrategy::WatermarkStrategyMaxEventTime => {
+                if let Some(x) = self.watermark_as_watermark_strategy_max_event_time() {
+                    ds.field("watermark", &x)
+                } else {
+                    ds.field(
+                        "watermark",
+                        &"InvalidFlatbuffer: Union discriminant does not match value.",
+                    )
+                }
+            }
+            WatermarkStrategy::WatermarkStrategyBoundedOutOfOrderness => {
+                if let Some(x) = self.watermark_as_watermark_strategy_bounded_out_of_orderness() {
+                    ds.field("watermark", &x)
+                } else {
+                    ds.field(
+                        "watermark",
+                        &"InvalidFlatbuffer: Union discriminant does not match value.",
+                    )
+                }
+            }
+            WatermarkStrategy::WatermarkStrategySystemTime => {
+                if let Some(x) = self.watermark_as_watermark_strategy_system_time() {
+                    ds.field("watermark", &x)
+                } else {
+                    ds.field(
+                        "watermark",
+                        &"InvalidFlatbuffer: Union discriminant does not match value.",
+                    )
+                }
+            }
+            WatermarkStrategy::WatermarkStrategyManual => {
+                if let Some(x) = self.watermark_as_watermark_strategy_manual() {
+                    ds.field("watermark", &x)
+                } else {
+                    ds.field(
+                        "watermark",
+                        &"InvalidFlatbuffer: Union discriminant does not match value.",
+                    )
+                }
+            }
+            _ => {
+                let x: Option<()> = None;
+                ds.field("watermark", &x)
+            }
+        };
         ds.finish()
     }
 }
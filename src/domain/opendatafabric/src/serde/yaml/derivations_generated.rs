@@ -95,6 +95,9 @@ pub struct AddPushSourceDef {
     pub preprocess: Option<Transform>,
     #[serde_as(as = "MergeStrategyDef")]
     pub merge: MergeStrategy,
+    #[serde_as(as = "Option<WatermarkStrategyDef>")]
+    #[serde(default)]
+    pub watermark: Option<WatermarkStrategy>,
 }
 
 implement_serde_as!(AddPushSource, AddPushSourceDef, "AddPushSourceDef");
@@ -593,8 +596,29 @@ pub struct MergeStrategyAppendDef {}
 #[serde(deny_unknown_fields, rename_all = "camelCase")]
 pub struct MergeStrategyLedgerDef {
     pub primary_key: Vec<String>,
+    #[serde_as(as = "Option<LedgerMergeModeDef>")]
+    #[serde(default)]
+    pub mode: Option<LedgerMergeMode>,
+    pub dedupe_window_records: Option<u64>,
+    pub dedupe_window_seconds: Option<u64>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(remote = "LedgerMergeMode")]
+#[serde(deny_unknown_fields)]
+pub enum LedgerMergeModeDef {
+    #[serde(alias = "deduplicate")]
+    Deduplicate,
+    #[serde(alias = "newEventsOnly", alias = "neweventsonly")]
+    NewEventsOnly,
+    #[serde(alias = "supersetRetractMissing", alias = "supersetretractmissing")]
+    SupersetRetractMissing,
+    #[serde(alias = "supersetStrict", alias = "supersetstrict")]
+    SupersetStrict,
 }
 
+implement_serde_as!(LedgerMergeMode, LedgerMergeModeDef, "LedgerMergeModeDef");
+
 #[serde_as]
 #[skip_serializing_none]
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -907,6 +931,12 @@ pub enum ReadStepDef {
     NdJson(#[serde_as(as = "ReadStepNdJsonDef")] ReadStepNdJson),
     #[serde(alias = "ndGeoJson", alias = "ndgeojson")]
     NdGeoJson(#[serde_as(as = "ReadStepNdGeoJsonDef")] ReadStepNdGeoJson),
+    #[serde(alias = "avro")]
+    Avro(#[serde_as(as = "ReadStepAvroDef")] ReadStepAvro),
+    #[serde(alias = "orc")]
+    Orc(#[serde_as(as = "ReadStepOrcDef")] ReadStepOrc),
+    #[serde(alias = "excel")]
+    Excel(#[serde_as(as = "ReadStepExcelDef")] ReadStepExcel),
 }
 
 implement_serde_as!(ReadStep, ReadStepDef, "ReadStepDef");
@@ -925,6 +955,9 @@ implement_serde_as!(
     "ReadStepEsriShapefileDef"
 );
 implement_serde_as!(ReadStepParquet, ReadStepParquetDef, "ReadStepParquetDef");
+implement_serde_as!(ReadStepAvro, ReadStepAvroDef, "ReadStepAvroDef");
+implement_serde_as!(ReadStepOrc, ReadStepOrcDef, "ReadStepOrcDef");
+implement_serde_as!(ReadStepExcel, ReadStepExcelDef, "ReadStepExcelDef");
 
 #[serde_as]
 #[skip_serializing_none]
@@ -1006,6 +1039,36 @@ pub struct ReadStepNdGeoJsonDef {
     pub schema: Option<Vec<String>>,
 }
 
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(remote = "ReadStepAvro")]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct ReadStepAvroDef {
+    pub schema: Option<Vec<String>>,
+}
+
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(remote = "ReadStepOrc")]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct ReadStepOrcDef {
+    pub schema: Option<Vec<String>>,
+}
+
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(remote = "ReadStepExcel")]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct ReadStepExcelDef {
+    pub schema: Option<Vec<String>>,
+    pub sheet: Option<String>,
+    pub header: Option<bool>,
+    pub skip_rows: Option<u64>,
+}
+
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 // RequestHeader
 // https://github.com/kamu-data/open-data-fabric/blob/master/open-data-fabric.md#requestheader-schema
@@ -1134,6 +1197,9 @@ pub struct SetPollingSourceDef {
     pub preprocess: Option<Transform>,
     #[serde_as(as = "MergeStrategyDef")]
     pub merge: MergeStrategy,
+    #[serde_as(as = "Option<WatermarkStrategyDef>")]
+    #[serde(default)]
+    pub watermark: Option<WatermarkStrategy>,
 }
 
 implement_serde_as!(SetPollingSource, SetPollingSourceDef, "SetPollingSourceDef");
@@ -1474,3 +1540,87 @@ pub struct WatermarkDef {
 }
 
 implement_serde_as!(Watermark, WatermarkDef, "WatermarkDef");
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// WatermarkStrategy
+// https://github.com/kamu-data/open-data-fabric/blob/master/open-data-fabric.md#watermarkstrategy-schema
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[serde_as]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(remote = "WatermarkStrategy")]
+#[serde(deny_unknown_fields, tag = "kind")]
+pub enum WatermarkStrategyDef {
+    #[serde(alias = "maxEventTime", alias = "maxeventtime")]
+    MaxEventTime(
+        #[serde_as(as = "WatermarkStrategyMaxEventTimeDef")] WatermarkStrategyMaxEventTime,
+    ),
+    #[serde(alias = "boundedOutOfOrderness", alias = "boundedoutoforderness")]
+    BoundedOutOfOrderness(
+        #[serde_as(as = "WatermarkStrategyBoundedOutOfOrdernessDef")]
+        WatermarkStrategyBoundedOutOfOrderness,
+    ),
+    #[serde(alias = "systemTime", alias = "systemtime")]
+    SystemTime(#[serde_as(as = "WatermarkStrategySystemTimeDef")] WatermarkStrategySystemTime),
+    #[serde(alias = "manual")]
+    Manual(#[serde_as(as = "WatermarkStrategyManualDef")] WatermarkStrategyManual),
+}
+
+implement_serde_as!(
+    WatermarkStrategy,
+    WatermarkStrategyDef,
+    "WatermarkStrategyDef"
+);
+implement_serde_as!(
+    WatermarkStrategyMaxEventTime,
+    WatermarkStrategyMaxEventTimeDef,
+    "WatermarkStrategyMaxEventTimeDef"
+);
+implement_serde_as!(
+    WatermarkStrategyBoundedOutOfOrderness,
+    WatermarkStrategyBoundedOutOfOrdernessDef,
+    "WatermarkStrategyBoundedOutOfOrdernessDef"
+);
+implement_serde_as!(
+    WatermarkStrategySystemTime,
+    WatermarkStrategySystemTimeDef,
+    "WatermarkStrategySystemTimeDef"
+);
+implement_serde_as!(
+    WatermarkStrategyManual,
+    WatermarkStrategyManualDef,
+    "WatermarkStrategyManualDef"
+);
+
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(remote = "WatermarkStrategyMaxEventTime")]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct WatermarkStrategyMaxEventTimeDef {}
+
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(remote = "WatermarkStrategyBoundedOutOfOrderness")]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct WatermarkStrategyBoundedOutOfOrdernessDef {
+    pub lag_seconds: u64,
+}
+
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(remote = "WatermarkStrategySystemTime")]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct WatermarkStrategySystemTimeDef {
+    pub delay_seconds: u64,
+    pub min_idle_advance_seconds: Option<u64>,
+}
+
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(remote = "WatermarkStrategyManual")]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct WatermarkStrategyManualDef {}
@@ -45,7 +45,11 @@ fn get_test_events() -> [(MetadataEvent, &'static str); 6] {
                 })),
                 merge: MergeStrategy::Ledger(MergeStrategyLedger {
                     primary_key: vec!["a".to_owned()],
+                    mode: None,
+                    dedupe_window_records: None,
+                    dedupe_window_seconds: None,
                 }),
+                watermark: None,
             }),
             "18cc1680b3d36f63358b59d469d76dcbf71ddac3ea66a693ce4158cfc5dfb28d",
         ),
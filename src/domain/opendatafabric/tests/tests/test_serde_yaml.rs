@@ -254,6 +254,7 @@ fn serde_dataset_snapshot_root() {
                 primary_key: vec!["id".to_owned()],
                 compare_columns: None,
             }),
+            watermark: None,
         })],
     };
 
@@ -587,3 +588,92 @@ fn serde_transform() {
 
     assert_eq!(serde_yaml::to_string(&Helper(actual)).unwrap(), data);
 }
+
+#[test]
+fn serde_merge_strategy_ledger() {
+    let data = indoc!(
+        "
+        kind: Ledger
+        primaryKey:
+        - id
+        mode: SupersetRetractMissing
+        dedupeWindowRecords: 1000
+        dedupeWindowSeconds: 86400
+        "
+    );
+
+    #[derive(Serialize, Deserialize)]
+    struct Helper(#[serde(with = "MergeStrategyDef")] MergeStrategy);
+    let hlp: Helper = serde_yaml::from_str(data).unwrap();
+    let actual = hlp.0;
+
+    let expected = MergeStrategy::Ledger(MergeStrategyLedger {
+        primary_key: vec!["id".to_owned()],
+        mode: Some(LedgerMergeMode::SupersetRetractMissing),
+        dedupe_window_records: Some(1000),
+        dedupe_window_seconds: Some(86400),
+    });
+
+    assert_eq!(expected, actual);
+
+    assert_eq!(serde_yaml::to_string(&Helper(actual)).unwrap(), data);
+}
+
+#[test]
+fn serde_watermark_strategy() {
+    let data = indoc!(
+        "
+        kind: SystemTime
+        delaySeconds: 3600
+        minIdleAdvanceSeconds: 600
+        "
+    );
+
+    #[derive(Serialize, Deserialize)]
+    struct Helper(#[serde(with = "WatermarkStrategyDef")] WatermarkStrategy);
+    let hlp: Helper = serde_yaml::from_str(data).unwrap();
+    let actual = hlp.0;
+
+    let expected = WatermarkStrategy::SystemTime(WatermarkStrategySystemTime {
+        delay_seconds: 3600,
+        min_idle_advance_seconds: Some(600),
+    });
+
+    assert_eq!(expected, actual);
+
+    assert_eq!(serde_yaml::to_string(&Helper(actual)).unwrap(), data);
+}
+
+#[test]
+fn serde_read_step_excel() {
+    let data = indoc!(
+        "
+        kind: Excel
+        schema:
+        - city STRING
+        - population BIGINT
+        sheet: Cities
+        header: true
+        skipRows: 2
+        "
+    );
+
+    #[derive(Serialize, Deserialize)]
+    struct Helper(#[serde(with = "ReadStepDef")] ReadStep);
+    let hlp: Helper = serde_yaml::from_str(data).unwrap();
+    let actual = hlp.0;
+
+    let expected = ReadStep::Excel(ReadStepExcel {
+        schema: Some(vec![
+            "city STRING".to_owned(),
+            "population BIGINT".to_owned(),
+        ]),
+        sheet: Some("Cities".to_owned()),
+        header: Some(true),
+        skip_rows: Some(2),
+    });
+
+    assert_eq!(expected, actual);
+
+    assert_eq!(serde_yaml::to_string(&Helper(actual)).unwrap(), data);
+}
@@ -42,6 +42,15 @@ impl Task {
         self.apply(event)
     }
 
+    /// Return the interrupted running task back to a `Queued` state
+    pub fn requeue(&mut self, now: DateTime<Utc>) -> Result<(), ProjectionError<TaskState>> {
+        let event = TaskEventRequeued {
+            event_time: now,
+            task_id: self.task_id,
+        };
+        self.apply(event)
+    }
+
     /// Task is queued or running and cancellation was not already requested
     pub fn can_cancel(&self) -> bool {
         matches!(self.status, TaskStatus::Queued | TaskStatus::Running if !self.cancellation_requested)
@@ -60,6 +69,34 @@ impl Task {
         self.apply(event)
     }
 
+    /// Record output produced by the running task
+    pub fn append_logs(
+        &mut self,
+        now: DateTime<Utc>,
+        entries: Vec<TaskLogEntry>,
+    ) -> Result<(), ProjectionError<TaskState>> {
+        let event = TaskEventLogsAppended {
+            event_time: now,
+            task_id: self.task_id,
+            entries,
+        };
+        self.apply(event)
+    }
+
+    /// Record the amount of work done by the running task so far
+    pub fn report_progress(
+        &mut self,
+        now: DateTime<Utc>,
+        progress: TaskProgress,
+    ) -> Result<(), ProjectionError<TaskState>> {
+        let event = TaskEventProgressReported {
+            event_time: now,
+            task_id: self.task_id,
+            progress,
+        };
+        self.apply(event)
+    }
+
     /// Transition task to a `Finished` state with the specified outcome
     pub fn finish(
         &mut self,
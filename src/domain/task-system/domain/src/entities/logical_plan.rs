@@ -27,6 +27,8 @@ pub enum LogicalPlan {
     HardCompactionDataset(HardCompactionDataset),
     /// Perform a dataset resetting
     Reset(ResetDataset),
+    /// Perform a storage-level garbage collection of unreferenced objects
+    GarbageCollection(GarbageCollection),
 }
 
 impl LogicalPlan {
@@ -39,8 +41,31 @@ impl LogicalPlan {
                 Some(&hard_compaction.dataset_id)
             }
             LogicalPlan::Reset(reset) => Some(&reset.dataset_id),
+            LogicalPlan::GarbageCollection(_) => None,
         }
     }
+
+    /// Returns the kind of this plan
+    pub fn kind(&self) -> LogicalPlanKind {
+        match self {
+            LogicalPlan::UpdateDataset(_) => LogicalPlanKind::UpdateDataset,
+            LogicalPlan::Probe(_) => LogicalPlanKind::Probe,
+            LogicalPlan::HardCompactionDataset(_) => LogicalPlanKind::HardCompactionDataset,
+            LogicalPlan::Reset(_) => LogicalPlanKind::Reset,
+            LogicalPlan::GarbageCollection(_) => LogicalPlanKind::GarbageCollection,
+        }
+    }
+}
+
+/// Kinds of logical plans, used where the plan arguments are irrelevant, e.g.
+/// to limit concurrency of certain kinds of tasks
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum LogicalPlanKind {
+    UpdateDataset,
+    Probe,
+    HardCompactionDataset,
+    Reset,
+    GarbageCollection,
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -89,6 +114,16 @@ pub struct ResetDataset {
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
+/// A task to delete data and checkpoint objects that are not referenced by
+/// any dataset
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GarbageCollection {
+    /// Only report unreferenced objects without deleting them
+    pub dry_run: bool,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
 // TODO: Replace with derive macro
 impl_enum_with_variants!(LogicalPlan);
 impl_enum_variant!(LogicalPlan::UpdateDataset(UpdateDataset));
@@ -10,11 +10,13 @@
 mod logical_plan;
 mod task_event;
 mod task_id;
+mod task_log;
 mod task_state;
 mod task_status;
 
 pub use logical_plan::*;
 pub use task_event::*;
 pub use task_id::*;
+pub use task_log::*;
 pub use task_state::*;
 pub use task_status::*;
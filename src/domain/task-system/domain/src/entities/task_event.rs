@@ -23,11 +23,17 @@ pub enum TaskEvent {
     TaskCreated(TaskEventCreated),
     /// Task execution had started
     TaskRunning(TaskEventRunning),
+    /// Interrupted task was returned to the queue to be executed again
+    TaskRequeued(TaskEventRequeued),
     /// Cancellation of task was requested (this is not immediate and task may
     /// still finish with a different outcome than cancelled)
     TaskCancelled(TaskEventCancelled),
     /// Task has reached a final outcome
     TaskFinished(TaskEventFinished),
+    /// Running task has produced some output
+    TaskLogsAppended(TaskEventLogsAppended),
+    /// Running task has reported the amount of work done so far
+    TaskProgressReported(TaskEventProgressReported),
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -49,6 +55,14 @@ pub struct TaskEventRunning {
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TaskEventRequeued {
+    pub event_time: DateTime<Utc>,
+    pub task_id: TaskID,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct TaskEventCancelled {
     pub event_time: DateTime<Utc>,
@@ -66,13 +80,34 @@ pub struct TaskEventFinished {
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TaskEventLogsAppended {
+    pub event_time: DateTime<Utc>,
+    pub task_id: TaskID,
+    pub entries: Vec<TaskLogEntry>,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TaskEventProgressReported {
+    pub event_time: DateTime<Utc>,
+    pub task_id: TaskID,
+    pub progress: TaskProgress,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
 impl TaskEvent {
     pub fn typename(&self) -> &'static str {
         match self {
             TaskEvent::TaskCreated(_) => "TaskEventCreated",
             TaskEvent::TaskRunning(_) => "TaskEventRunning",
+            TaskEvent::TaskRequeued(_) => "TaskEventRequeued",
             TaskEvent::TaskCancelled(_) => "TaskEventCancelled",
             TaskEvent::TaskFinished(_) => "TaskEventFinished",
+            TaskEvent::TaskLogsAppended(_) => "TaskEventLogsAppended",
+            TaskEvent::TaskProgressReported(_) => "TaskEventProgressReported",
         }
     }
 
@@ -80,8 +115,11 @@ impl TaskEvent {
         match self {
             TaskEvent::TaskCreated(e) => e.task_id,
             TaskEvent::TaskRunning(e) => e.task_id,
+            TaskEvent::TaskRequeued(e) => e.task_id,
             TaskEvent::TaskCancelled(e) => e.task_id,
             TaskEvent::TaskFinished(e) => e.task_id,
+            TaskEvent::TaskLogsAppended(e) => e.task_id,
+            TaskEvent::TaskProgressReported(e) => e.task_id,
         }
     }
 
@@ -89,8 +127,11 @@ impl TaskEvent {
         match self {
             TaskEvent::TaskCreated(e) => e.event_time,
             TaskEvent::TaskRunning(e) => e.event_time,
+            TaskEvent::TaskRequeued(e) => e.event_time,
             TaskEvent::TaskCancelled(e) => e.event_time,
             TaskEvent::TaskFinished(e) => e.event_time,
+            TaskEvent::TaskLogsAppended(e) => e.event_time,
+            TaskEvent::TaskProgressReported(e) => e.event_time,
         }
     }
 
@@ -107,7 +148,10 @@ impl TaskEvent {
 impl_enum_with_variants!(TaskEvent);
 impl_enum_variant!(TaskEvent::TaskCreated(TaskEventCreated));
 impl_enum_variant!(TaskEvent::TaskRunning(TaskEventRunning));
+impl_enum_variant!(TaskEvent::TaskRequeued(TaskEventRequeued));
 impl_enum_variant!(TaskEvent::TaskCancelled(TaskEventCancelled));
 impl_enum_variant!(TaskEvent::TaskFinished(TaskEventFinished));
+impl_enum_variant!(TaskEvent::TaskLogsAppended(TaskEventLogsAppended));
+impl_enum_variant!(TaskEvent::TaskProgressReported(TaskEventProgressReported));
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
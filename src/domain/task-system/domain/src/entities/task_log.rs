@@ -0,0 +1,35 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// A single line of output captured during task execution
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TaskLogEntry {
+    pub event_time: DateTime<Utc>,
+    pub message: String,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Cumulative amount of work done by the task so far
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TaskProgress {
+    /// Number of bytes fetched from external sources
+    pub fetched_bytes: u64,
+    /// Number of records read from input datasets
+    pub records_read: u64,
+    /// Number of records written into the target dataset
+    pub records_written: u64,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -25,6 +25,8 @@ pub struct TaskState {
     pub cancellation_requested: bool,
     /// Execution plan of the task
     pub logical_plan: LogicalPlan,
+    /// Amount of work done by the task, as last reported
+    pub progress: TaskProgress,
 
     /// Time when task was originally created and placed in a queue
     pub created_at: DateTime<Utc>,
@@ -56,6 +58,7 @@ impl Projection for TaskState {
                     status: TaskStatus::Queued,
                     cancellation_requested: false,
                     logical_plan,
+                    progress: TaskProgress::default(),
                     created_at: event_time,
                     ran_at: None,
                     cancellation_requested_at: None,
@@ -77,6 +80,11 @@ impl Projection for TaskState {
                             ..s
                         })
                     }
+                    E::TaskRequeued(_) if s.status == TaskStatus::Running => Ok(Self {
+                        status: TaskStatus::Queued,
+                        ran_at: None,
+                        ..s
+                    }),
                     E::TaskCancelled(TaskEventCancelled { event_time, .. })
                         if s.status == TaskStatus::Queued
                             || s.status == TaskStatus::Running && !s.cancellation_requested =>
@@ -98,9 +106,18 @@ impl Projection for TaskState {
                             ..s
                         })
                     }
-                    E::TaskRunning(_) | E::TaskCancelled(_) | E::TaskFinished(_) => {
-                        Err(ProjectionError::new(Some(s), event))
+                    E::TaskLogsAppended(_) if s.status == TaskStatus::Running => Ok(s),
+                    E::TaskProgressReported(TaskEventProgressReported { progress, .. })
+                        if s.status == TaskStatus::Running =>
+                    {
+                        Ok(Self { progress, ..s })
                     }
+                    E::TaskRunning(_)
+                    | E::TaskRequeued(_)
+                    | E::TaskCancelled(_)
+                    | E::TaskFinished(_)
+                    | E::TaskLogsAppended(_)
+                    | E::TaskProgressReported(_) => Err(ProjectionError::new(Some(s), event)),
                 }
             }
         }
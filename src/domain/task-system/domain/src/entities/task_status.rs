@@ -51,6 +51,7 @@ pub enum TaskResult {
     UpdateDatasetResult(TaskUpdateDatasetResult),
     ResetDatasetResult(TaskResetDatasetResult),
     CompactionDatasetResult(TaskCompactionDatasetResult),
+    GarbageCollectionResult(TaskGarbageCollectionResult),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -76,6 +77,12 @@ impl From<CompactionResult> for TaskCompactionDatasetResult {
     }
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TaskGarbageCollectionResult {
+    pub objects_deleted: usize,
+    pub bytes_freed: u64,
+}
+
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -83,6 +90,9 @@ pub enum TaskError {
     Empty,
     UpdateDatasetError(UpdateDatasetTaskError),
     ResetDatasetError(ResetDatasetTaskError),
+    GarbageCollectionError(GarbageCollectionTaskError),
+    /// Task was interrupted after exceeding the execution time limit
+    TimedOut,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -100,4 +110,9 @@ pub enum ResetDatasetTaskError {
     ResetHeadNotFound,
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GarbageCollectionTaskError {
+    pub message: String,
+}
+
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
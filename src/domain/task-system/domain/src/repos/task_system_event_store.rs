@@ -32,6 +32,10 @@ pub trait TaskSystemEventStore: EventStore<TaskState> {
         &self,
         dataset_id: &DatasetID,
     ) -> Result<usize, InternalError>;
+
+    /// Returns the tasks that have not reached a final outcome yet, in the
+    /// order of their creation
+    async fn get_unfinished_tasks(&self) -> TaskIDStream;
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -7,6 +7,8 @@
 // the Business Source License, use of this software will be governed
 // by the Apache License, Version 2.0.
 
+use std::collections::HashMap;
+
 use crate::*;
 
 #[async_trait::async_trait]
@@ -14,3 +16,49 @@ pub trait TaskExecutor: Sync + Send {
     /// Runs the executor main loop
     async fn run(&self) -> Result<(), InternalError>;
 }
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Debug)]
+pub struct TaskExecutorConfig {
+    /// Defines how many tasks may be executed at the same time
+    pub max_concurrent_tasks: usize,
+    /// Defines how many tasks of a certain kind may be executed at the same
+    /// time. Kinds without a limit are restricted only by the total number
+    pub max_concurrent_tasks_by_kind: HashMap<LogicalPlanKind, usize>,
+    /// Maximum duration of a single task execution, after which the task is
+    /// interrupted and considered failed. No limit is applied when unset
+    pub task_timeout: Option<chrono::Duration>,
+}
+
+impl TaskExecutorConfig {
+    pub fn new(
+        max_concurrent_tasks: usize,
+        max_concurrent_tasks_by_kind: HashMap<LogicalPlanKind, usize>,
+        task_timeout: Option<chrono::Duration>,
+    ) -> Self {
+        assert!(
+            max_concurrent_tasks > 0,
+            "At least one concurrent task must be allowed"
+        );
+        if let Some(task_timeout) = task_timeout {
+            assert!(
+                task_timeout > chrono::Duration::zero(),
+                "Task timeout must be positive"
+            );
+        }
+        Self {
+            max_concurrent_tasks,
+            max_concurrent_tasks_by_kind,
+            task_timeout,
+        }
+    }
+}
+
+impl Default for TaskExecutorConfig {
+    fn default() -> Self {
+        Self::new(1, HashMap::new(), None)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -7,6 +7,8 @@
 // the Business Source License, use of this software will be governed
 // by the Apache License, Version 2.0.
 
+use std::collections::HashSet;
+
 use event_sourcing::LoadError;
 use kamu_core::DatasetNotFoundError;
 use opendatafabric::DatasetID;
@@ -32,6 +34,10 @@ pub trait TaskScheduler: Sync + Send {
     /// Returns current state of a given task
     async fn get_task(&self, task_id: TaskID) -> Result<TaskState, GetTaskError>;
 
+    /// Returns output captured during execution of a given task in
+    /// chronological order
+    async fn get_task_logs(&self, task_id: TaskID) -> Result<Vec<TaskLogEntry>, GetTaskError>;
+
     /// Attempts to cancel the given task
     async fn cancel_task(&self, task_id: TaskID) -> Result<TaskState, CancelTaskError>;
 
@@ -41,6 +47,37 @@ pub trait TaskScheduler: Sync + Send {
 
     /// A non-blocking version of [TaskScheduler::take()]
     async fn try_take(&self) -> Result<Option<TaskID>, TakeTaskError>;
+
+    /// A non-blocking version of [TaskScheduler::take()] that skips queued
+    /// tasks which cannot be executed under the given constraints. Skipped
+    /// tasks keep their position in the queue
+    async fn try_take_constrained(
+        &self,
+        constraints: &TaskTakeConstraints,
+    ) -> Result<Option<TaskID>, TakeTaskError>;
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Describes which of the queued tasks cannot be taken at the moment
+#[derive(Debug, Default, Clone)]
+pub struct TaskTakeConstraints {
+    /// Datasets that are already being modified by other running tasks
+    pub busy_datasets: HashSet<DatasetID>,
+    /// Kinds of tasks that have reached their concurrency limit
+    pub saturated_plan_kinds: HashSet<LogicalPlanKind>,
+}
+
+impl TaskTakeConstraints {
+    pub fn allows(&self, logical_plan: &LogicalPlan) -> bool {
+        if self.saturated_plan_kinds.contains(&logical_plan.kind()) {
+            return false;
+        }
+        match logical_plan.dataset_id() {
+            Some(dataset_id) => !self.busy_datasets.contains(dataset_id),
+            None => true,
+        }
+    }
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
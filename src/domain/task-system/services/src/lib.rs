@@ -10,6 +10,7 @@
 // Re-exports
 pub use kamu_task_system as domain;
 
+mod task_activity_recorder;
 mod task_executor_impl;
 mod task_scheduler_impl;
 
@@ -0,0 +1,299 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+
+use chrono::{DateTime, Utc};
+use kamu_core::*;
+use kamu_task_system::{TaskLogEntry, TaskProgress};
+use opendatafabric::DatasetHandle;
+use time_source::SystemTimeSource;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// Only the tail of large log files is attached to the task logs
+const MAX_LOG_FILE_LINES: usize = 500;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Collects output and progress of a running task via the listeners of the
+/// services it calls. Collected activity is periodically taken by the
+/// executor and saved into the task event store
+#[derive(Clone)]
+pub(crate) struct TaskActivityRecorder {
+    time_source: Arc<dyn SystemTimeSource>,
+    state: Arc<Mutex<State>>,
+}
+
+#[derive(Default)]
+struct State {
+    pending_entries: Vec<TaskLogEntry>,
+    progress: TaskProgress,
+    progress_changed: bool,
+    // Bytes fetched by completed fetch steps, as fetch progress is reported per step
+    fetched_bytes_completed: u64,
+    current_ingest_stage: Option<PollingIngestStage>,
+}
+
+impl TaskActivityRecorder {
+    pub fn new(time_source: Arc<dyn SystemTimeSource>) -> Self {
+        Self {
+            time_source,
+            state: Arc::new(Mutex::new(State::default())),
+        }
+    }
+
+    pub fn log(&self, message: impl Into<String>) {
+        let entry = TaskLogEntry {
+            event_time: self.time_source.now(),
+            message: message.into(),
+        };
+        self.state.lock().unwrap().pending_entries.push(entry);
+    }
+
+    /// Attaches contents of log files written by engines and external
+    /// processes
+    pub fn log_files(&self, log_files: &[PathBuf]) {
+        for path in log_files {
+            let contents = match std::fs::read_to_string(path) {
+                Ok(contents) => contents,
+                Err(err) => {
+                    tracing::warn!(path = %path.display(), error = ?err, "Failed to read log file");
+                    continue;
+                }
+            };
+
+            let lines: Vec<_> = contents.lines().collect();
+            let skipped = lines.len().saturating_sub(MAX_LOG_FILE_LINES);
+
+            if skipped > 0 {
+                self.log(format!(
+                    "Contents of {} ({skipped} lines skipped):",
+                    path.display()
+                ));
+            } else {
+                self.log(format!("Contents of {}:", path.display()));
+            }
+
+            for line in &lines[skipped..] {
+                self.log(*line);
+            }
+        }
+    }
+
+    pub fn add_records(&self, records_read: u64, records_written: u64) {
+        let mut state = self.state.lock().unwrap();
+        state.progress.records_read += records_read;
+        state.progress.records_written += records_written;
+        state.progress_changed = true;
+    }
+
+    pub fn has_pending(&self) -> bool {
+        let state = self.state.lock().unwrap();
+        !state.pending_entries.is_empty() || state.progress_changed
+    }
+
+    /// Takes the entries collected since the previous call, along with the
+    /// progress, if it has changed since then
+    pub fn take_pending(&self) -> (Vec<TaskLogEntry>, Option<TaskProgress>) {
+        let mut state = self.state.lock().unwrap();
+
+        let entries = std::mem::take(&mut state.pending_entries);
+        let progress = if state.progress_changed {
+            state.progress_changed = false;
+            Some(state.progress.clone())
+        } else {
+            None
+        };
+
+        (entries, progress)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl PullMultiListener for TaskActivityRecorder {
+    fn get_ingest_listener(self: Arc<Self>) -> Option<Arc<dyn PollingIngestMultiListener>> {
+        Some(self)
+    }
+
+    fn get_transform_listener(self: Arc<Self>) -> Option<Arc<dyn TransformMultiListener>> {
+        Some(self)
+    }
+
+    fn get_sync_listener(self: Arc<Self>) -> Option<Arc<dyn SyncMultiListener>> {
+        None
+    }
+}
+
+impl PollingIngestMultiListener for TaskActivityRecorder {
+    fn begin_ingest(&self, dataset: &DatasetHandle) -> Option<Arc<dyn PollingIngestListener>> {
+        self.log(format!("Ingesting data into {}", dataset.alias));
+        Some(Arc::new(self.clone()))
+    }
+}
+
+impl PollingIngestListener for TaskActivityRecorder {
+    fn begin(&self) {
+        self.state.lock().unwrap().current_ingest_stage = None;
+    }
+
+    fn on_cache_hit(&self, created_at: &DateTime<Utc>) {
+        self.log(format!(
+            "Using cached fetch results from {}",
+            created_at.to_rfc3339()
+        ));
+    }
+
+    fn on_stage_progress(&self, stage: PollingIngestStage, progress: u64, _out_of: TotalSteps) {
+        let stage_changed = {
+            let mut state = self.state.lock().unwrap();
+
+            if stage == PollingIngestStage::Fetch {
+                state.progress.fetched_bytes = state.fetched_bytes_completed + progress;
+                state.progress_changed = true;
+            } else if state.current_ingest_stage == Some(PollingIngestStage::Fetch) {
+                state.fetched_bytes_completed = state.progress.fetched_bytes;
+            }
+
+            let stage_changed = state.current_ingest_stage != Some(stage);
+            state.current_ingest_stage = Some(stage);
+            stage_changed
+        };
+
+        if stage_changed {
+            self.log(format!("Ingest stage: {stage:?}"));
+        }
+    }
+
+    fn success(&self, result: &PollingIngestResult) {
+        let message = match result {
+            PollingIngestResult::UpToDate { .. } => "Ingest finished: dataset is up-to-date".into(),
+            PollingIngestResult::Updated {
+                new_head, has_more, ..
+            } => format!("Ingest finished: new head {new_head}, has more data: {has_more}"),
+        };
+        self.log(message);
+    }
+
+    fn on_log_files(&self, log_files: &[PathBuf]) {
+        self.log_files(log_files);
+    }
+
+    fn on_records_written(&self, num_records: u64) {
+        self.add_records(0, num_records);
+    }
+
+    fn error(&self, error: &PollingIngestError) {
+        self.log(format!("Ingest failed: {error}"));
+
+        let log_files = match error {
+            PollingIngestError::ProcessError(e) => &e.log_files,
+            PollingIngestError::PipeError(e) => e.log_files(),
+            PollingIngestError::EngineError(e) => engine_error_log_files(e),
+            _ => &[],
+        };
+        self.log_files(log_files);
+    }
+
+    fn get_engine_provisioning_listener(
+        self: Arc<Self>,
+    ) -> Option<Arc<dyn EngineProvisioningListener>> {
+        Some(self)
+    }
+}
+
+impl TransformMultiListener for TaskActivityRecorder {
+    fn begin_transform(&self, dataset: &DatasetHandle) -> Option<Arc<dyn TransformListener>> {
+        self.log(format!("Transforming data of {}", dataset.alias));
+        Some(Arc::new(self.clone()))
+    }
+}
+
+impl TransformListener for TaskActivityRecorder {
+    fn on_log_files(&self, log_files: &[PathBuf]) {
+        self.log_files(log_files);
+    }
+
+    fn on_records_processed(&self, records_read: u64, records_written: u64) {
+        self.add_records(records_read, records_written);
+    }
+
+    fn success(&self, result: &TransformResult) {
+        let message = match result {
+            TransformResult::UpToDate => "Transform finished: dataset is up-to-date".into(),
+            TransformResult::Updated { new_head, .. } => {
+                format!("Transform finished: new head {new_head}")
+            }
+        };
+        self.log(message);
+    }
+
+    fn error(&self, error: &TransformError) {
+        self.log(format!("Transform failed: {error}"));
+
+        if let TransformError::EngineError(e) = error {
+            self.log_files(engine_error_log_files(e));
+        }
+    }
+
+    fn get_engine_provisioning_listener(
+        self: Arc<Self>,
+    ) -> Option<Arc<dyn EngineProvisioningListener>> {
+        Some(self)
+    }
+}
+
+impl EngineProvisioningListener for TaskActivityRecorder {
+    fn begin(&self, engine_id: &str) {
+        self.log(format!("Provisioning engine {engine_id}"));
+    }
+
+    fn success(&self) {
+        self.log("Engine provisioned");
+    }
+}
+
+impl CompactionListener for TaskActivityRecorder {
+    fn success(&self, result: &CompactionResult) {
+        let message = match result {
+            CompactionResult::NothingToDo => "Compaction finished: nothing to do".into(),
+            CompactionResult::Success {
+                old_num_blocks,
+                new_num_blocks,
+                ..
+            } => format!("Compaction finished: {old_num_blocks} blocks -> {new_num_blocks}"),
+        };
+        self.log(message);
+    }
+
+    fn error(&self, error: &CompactionError) {
+        self.log(format!("Compaction failed: {error}"));
+    }
+
+    fn begin_phase(&self, phase: CompactionPhase) {
+        self.log(format!("Compaction phase: {phase:?}"));
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Log files of the engine that caused the error
+fn engine_error_log_files(error: &EngineError) -> &[PathBuf] {
+    match error {
+        EngineError::InvalidQuery(e) => &e.log_files,
+        EngineError::ProcessError(e) => &e.log_files,
+        EngineError::ContractError(e) => &e.log_files,
+        EngineError::InternalError(e) => &e.log_files,
+        EngineError::Cancelled(_) => &[],
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
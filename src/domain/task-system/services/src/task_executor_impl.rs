@@ -7,12 +7,16 @@
 // the Business Source License, use of this software will be governed
 // by the Apache License, Version 2.0.
 
-use std::collections::HashMap;
-use std::sync::Arc;
+use std::collections::{HashMap, HashSet};
+use std::panic::AssertUnwindSafe;
+use std::sync::{Arc, Mutex};
 
 use database_common::DatabaseTransactionRunner;
 use dill::*;
+use futures::future::Either;
+use futures::{FutureExt, TryStreamExt};
 use kamu_core::{
+    CancellationToken,
     CompactionOptions,
     CompactionService,
     DatasetRepository,
@@ -22,19 +26,82 @@ use kamu_core::{
     PullService,
     ResetError,
     ResetService,
+    StorageGcDatasetResult,
+    StorageGcOptions,
+    StorageGcService,
     TransformError,
 };
 use kamu_datasets::{DatasetEnvVar, DatasetEnvVarService};
 use kamu_task_system::*;
 use messaging_outbox::{Outbox, OutboxExt};
+use opendatafabric::DatasetID;
 use time_source::SystemTimeSource;
+use tokio::task::JoinSet;
+
+use crate::task_activity_recorder::TaskActivityRecorder;
+use crate::TaskQueue;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// How often the queue is checked for new tasks when workers are available
+const TAKE_TASK_INTERVAL: std::time::Duration = std::time::Duration::from_secs(1);
+
+// How often logs and progress captured from running tasks are saved
+const RUNNING_TASK_SAVE_INTERVAL: std::time::Duration = std::time::Duration::from_secs(1);
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
+// Cheap to clone, so that every task can be executed by a separate tokio task
+#[derive(Clone)]
 pub struct TaskExecutorImpl {
     catalog: Catalog,
-    task_sched: Arc<dyn TaskScheduler>,
+    task_queue: Arc<TaskQueue>,
     time_source: Arc<dyn SystemTimeSource>,
+    config: Arc<TaskExecutorConfig>,
+    state: Arc<Mutex<State>>,
+}
+
+#[derive(Default)]
+struct State {
+    busy_datasets: HashSet<DatasetID>,
+    running_by_kind: HashMap<LogicalPlanKind, usize>,
+}
+
+impl State {
+    fn take_constraints(&self, config: &TaskExecutorConfig) -> TaskTakeConstraints {
+        TaskTakeConstraints {
+            busy_datasets: self.busy_datasets.clone(),
+            saturated_plan_kinds: config
+                .max_concurrent_tasks_by_kind
+                .iter()
+                .filter_map(|(kind, limit)| {
+                    let running = self.running_by_kind.get(kind).copied().unwrap_or_default();
+                    (running >= *limit).then_some(*kind)
+                })
+                .collect(),
+        }
+    }
+
+    fn on_task_started(&mut self, logical_plan: &LogicalPlan) {
+        if let Some(dataset_id) = logical_plan.dataset_id() {
+            self.busy_datasets.insert(dataset_id.clone());
+        }
+        *self.running_by_kind.entry(logical_plan.kind()).or_default() += 1;
+    }
+
+    fn on_task_stopped(&mut self, logical_plan: &LogicalPlan) {
+        if let Some(dataset_id) = logical_plan.dataset_id() {
+            self.busy_datasets.remove(dataset_id);
+        }
+        if let Some(running) = self.running_by_kind.get_mut(&logical_plan.kind()) {
+            *running = running.saturating_sub(1);
+        }
+    }
+}
+
+enum TaskInterruption {
+    Cancelled,
+    TimedOut,
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -45,62 +112,383 @@ pub struct TaskExecutorImpl {
 impl TaskExecutorImpl {
     pub fn new(
         catalog: Catalog,
-        task_sched: Arc<dyn TaskScheduler>,
+        task_queue: Arc<TaskQueue>,
         time_source: Arc<dyn SystemTimeSource>,
+        config: Arc<TaskExecutorConfig>,
     ) -> Self {
         Self {
             catalog,
-            task_sched,
+            task_queue,
             time_source,
+            config,
+            state: Arc::new(Mutex::new(State::default())),
         }
     }
 
-    async fn take_task(&self) -> Result<Task, InternalError> {
-        let task_id = self.task_sched.take().await.int_err()?;
-
+    /// Restores the queue after a restart: the tasks that were interrupted
+    /// while running are queued again, unless their cancellation was
+    /// requested, in which case they are finished as cancelled
+    async fn recover_tasks(&self) -> Result<(), InternalError> {
         DatabaseTransactionRunner::new(self.catalog.clone())
             .transactional_with2(
                 |event_store: Arc<dyn TaskSystemEventStore>, outbox: Arc<dyn Outbox>| async move {
-                    let task = Task::load(task_id, event_store.as_ref()).await.int_err()?;
+                    let task_ids: Vec<_> = event_store
+                        .get_unfinished_tasks()
+                        .await
+                        .try_collect()
+                        .await?;
+
+                    for task_id in task_ids {
+                        let mut task = Task::load(task_id, event_store.as_ref()).await.int_err()?;
+
+                        if task.cancellation_requested {
+                            task.finish(self.time_source.now(), TaskOutcome::Cancelled)
+                                .int_err()?;
+                            task.save(event_store.as_ref()).await.int_err()?;
+
+                            outbox
+                                .post_message(
+                                    MESSAGE_PRODUCER_KAMU_TASK_EXECUTOR,
+                                    TaskProgressMessage::finished(
+                                        self.time_source.now(),
+                                        task_id,
+                                        TaskOutcome::Cancelled,
+                                    ),
+                                )
+                                .await?;
+                            continue;
+                        }
+
+                        if task.status == TaskStatus::Running {
+                            task.requeue(self.time_source.now()).int_err()?;
+                            task.save(event_store.as_ref()).await.int_err()?;
+                        }
+
+                        let queue_len = self.task_queue.push(task_id, task.logical_plan.clone());
+
+                        tracing::info!(
+                            %task_id,
+                            queue_len,
+                            "Task recovered",
+                        );
+                    }
+
+                    Ok(())
+                },
+            )
+            .await
+    }
+
+    /// Takes the next task, which does not conflict with running tasks: it
+    /// should not touch a dataset that is already being modified, and should
+    /// not exceed the concurrency limit of its kind
+    async fn try_take_task(&self) -> Result<Option<Task>, InternalError> {
+        let constraints = {
+            let state = self.state.lock().unwrap();
+            state.take_constraints(&self.config)
+        };
+
+        let maybe_task = DatabaseTransactionRunner::new(self.catalog.clone())
+            .transactional(|transactional_catalog| async move {
+                let task_sched = transactional_catalog
+                    .get_one::<dyn TaskScheduler>()
+                    .int_err()?;
+
+                let Some(task_id) = task_sched
+                    .try_take_constrained(&constraints)
+                    .await
+                    .int_err()?
+                else {
+                    return Ok(None);
+                };
+
+                let event_store = transactional_catalog
+                    .get_one::<dyn TaskSystemEventStore>()
+                    .int_err()?;
+                let outbox = transactional_catalog.get_one::<dyn Outbox>().int_err()?;
+
+                let task = Task::load(task_id, event_store.as_ref()).await.int_err()?;
+
+                tracing::info!(
+                    %task_id,
+                    logical_plan = ?task.logical_plan,
+                    "Executing task",
+                );
 
-                    tracing::info!(
-                        %task_id,
-                        logical_plan = ?task.logical_plan,
-                        "Executing task",
-                    );
+                outbox
+                    .post_message(
+                        MESSAGE_PRODUCER_KAMU_TASK_EXECUTOR,
+                        TaskProgressMessage::running(self.time_source.now(), task_id),
+                    )
+                    .await?;
+
+                Ok::<_, InternalError>(Some(task))
+            })
+            .await?;
+
+        let Some(task) = maybe_task else {
+            return Ok(None);
+        };
+
+        self.state
+            .lock()
+            .unwrap()
+            .on_task_started(&task.logical_plan);
+
+        Ok(Some(task))
+    }
+
+    /// Runs the task in a separate tokio task. The result is reported with
+    /// the ID of the task, even if its execution has panicked
+    fn spawn_task(
+        &self,
+        running_tasks: &mut JoinSet<(TaskID, Result<(), InternalError>)>,
+        task: Task,
+    ) {
+        let executor = self.clone();
+        running_tasks.spawn(async move {
+            let task_id = task.task_id;
+            let logical_plan = task.logical_plan.clone();
+
+            let res = AssertUnwindSafe(executor.run_task(task))
+                .catch_unwind()
+                .await
+                .unwrap_or_else(|_| InternalError::bail("Task execution panicked"));
+
+            executor
+                .state
+                .lock()
+                .unwrap()
+                .on_task_stopped(&logical_plan);
+
+            (task_id, res)
+        });
+    }
+
+    async fn run_task(&self, task: Task) -> Result<(), InternalError> {
+        let recorder = TaskActivityRecorder::new(self.time_source.clone());
+
+        let task_outcome = self.execute_task_interruptible(&task, &recorder).await?;
+
+        self.process_task_outcome(task, task_outcome, &recorder)
+            .await
+    }
+
+    /// Finishes the task as failed when its execution broke down with an
+    /// internal error, so that it does not stay running forever
+    async fn on_task_run_failed(&self, task_id: TaskID, error: InternalError) {
+        tracing::error!(
+            %task_id,
+            error = ?error,
+            error_msg = %error,
+            "Task execution failed",
+        );
+
+        let res = DatabaseTransactionRunner::new(self.catalog.clone())
+            .transactional_with2(
+                |event_store: Arc<dyn TaskSystemEventStore>, outbox: Arc<dyn Outbox>| async move {
+                    let mut task = Task::load(task_id, event_store.as_ref()).await.int_err()?;
+                    if task.status == TaskStatus::Finished {
+                        return Ok(());
+                    }
+
+                    let task_outcome = TaskOutcome::Failed(TaskError::Empty);
+                    task.finish(self.time_source.now(), task_outcome.clone())
+                        .int_err()?;
+                    task.save(event_store.as_ref()).await.int_err()?;
 
                     outbox
                         .post_message(
                             MESSAGE_PRODUCER_KAMU_TASK_EXECUTOR,
-                            TaskProgressMessage::running(self.time_source.now(), task_id),
+                            TaskProgressMessage::finished(
+                                self.time_source.now(),
+                                task_id,
+                                task_outcome,
+                            ),
                         )
-                        .await?;
-
-                    Ok(task)
+                        .await
                 },
             )
+            .await;
+
+        if let Err(err) = res {
+            tracing::error!(
+                %task_id,
+                error = ?err,
+                error_msg = %err,
+                "Failed to record the failure of the task",
+            );
+        }
+    }
+
+    /// Executes the task until it either completes, gets cancelled, or exceeds
+    /// the execution timeout. Interruption only signals the execution to stop:
+    /// services check the token at safe points, e.g. before committing, and
+    /// terminate their engine containers, so the execution is always awaited
+    /// to its end
+    async fn execute_task_interruptible(
+        &self,
+        task: &Task,
+        recorder: &TaskActivityRecorder,
+    ) -> Result<TaskOutcome, InternalError> {
+        let cancellation_token = self.task_queue.cancellation_token(task.task_id);
+
+        let res = self
+            .execute_task_watched(task, &cancellation_token, recorder)
+            .await;
+
+        self.task_queue.release(task.task_id);
+
+        let (task_outcome, interruption) = res?;
+        let Some(interruption) = interruption else {
+            return Ok(task_outcome);
+        };
+
+        // The task might have completed before reaching a safe point
+        if let TaskOutcome::Success(_) = task_outcome {
+            return Ok(task_outcome);
+        }
+
+        let task_outcome = match interruption {
+            TaskInterruption::Cancelled => TaskOutcome::Cancelled,
+            TaskInterruption::TimedOut => TaskOutcome::Failed(TaskError::TimedOut),
+        };
+
+        recorder.log(match task_outcome {
+            TaskOutcome::Cancelled => "Task was interrupted due to cancellation",
+            _ => "Task was interrupted after exceeding the execution timeout",
+        });
+
+        tracing::warn!(
+            task_id = %task.task_id,
+            logical_plan = ?task.logical_plan,
+            ?task_outcome,
+            "Task interrupted",
+        );
+
+        Ok(task_outcome)
+    }
+
+    /// Runs the execution to its end while periodically saving the captured
+    /// activity. Cancels the token once the execution timeout is exceeded
+    async fn execute_task_watched(
+        &self,
+        task: &Task,
+        cancellation_token: &CancellationToken,
+        recorder: &TaskActivityRecorder,
+    ) -> Result<(TaskOutcome, Option<TaskInterruption>), InternalError> {
+        let execution = self.execute_task(task, cancellation_token, recorder);
+        tokio::pin!(execution);
+
+        let timeout = match self.config.task_timeout {
+            Some(task_timeout) => {
+                Either::Left(tokio::time::sleep(task_timeout.to_std().int_err()?))
+            }
+            None => Either::Right(std::future::pending::<()>()),
+        };
+        tokio::pin!(timeout);
+
+        let mut save_interval = tokio::time::interval(RUNNING_TASK_SAVE_INTERVAL);
+        let mut interruption = None;
+
+        loop {
+            tokio::select! {
+                task_outcome = &mut execution => return Ok((task_outcome?, interruption)),
+                () = cancellation_token.cancelled(), if interruption.is_none() => {
+                    interruption = Some(TaskInterruption::Cancelled);
+                }
+                () = &mut timeout, if interruption.is_none() => {
+                    interruption = Some(TaskInterruption::TimedOut);
+                    cancellation_token.cancel();
+                }
+                _ = save_interval.tick() => {
+                    self.save_running_task_activity(task.task_id, recorder).await?;
+                }
+            }
+        }
+    }
+
+    /// Saves the activity captured from the running task, if there is any
+    async fn save_running_task_activity(
+        &self,
+        task_id: TaskID,
+        recorder: &TaskActivityRecorder,
+    ) -> Result<(), InternalError> {
+        if !recorder.has_pending() {
+            return Ok(());
+        }
+
+        DatabaseTransactionRunner::new(self.catalog.clone())
+            .transactional_with(|event_store: Arc<dyn TaskSystemEventStore>| async move {
+                let mut task = Task::load(task_id, event_store.as_ref()).await.int_err()?;
+                self.save_task_activity(&mut task, recorder, event_store.as_ref())
+                    .await
+            })
             .await
     }
 
-    async fn execute_task(&self, task: &Task) -> Result<TaskOutcome, InternalError> {
+    async fn save_task_activity(
+        &self,
+        task: &mut Task,
+        recorder: &TaskActivityRecorder,
+        event_store: &dyn TaskSystemEventStore,
+    ) -> Result<(), InternalError> {
+        let (entries, progress) = recorder.take_pending();
+
+        if !entries.is_empty() {
+            task.append_logs(self.time_source.now(), entries)
+                .int_err()?;
+        }
+        if let Some(progress) = progress {
+            task.report_progress(self.time_source.now(), progress)
+                .int_err()?;
+        }
+
+        task.save(event_store).await.int_err()
+    }
+
+    async fn execute_task(
+        &self,
+        task: &Task,
+        cancellation_token: &CancellationToken,
+        recorder: &TaskActivityRecorder,
+    ) -> Result<TaskOutcome, InternalError> {
         let task_outcome = match &task.logical_plan {
-            LogicalPlan::UpdateDataset(upd) => self.update_dataset_logical_plan(upd).await?,
+            LogicalPlan::UpdateDataset(upd) => {
+                self.update_dataset_logical_plan(upd, cancellation_token, recorder)
+                    .await?
+            }
             LogicalPlan::Probe(Probe {
                 busy_time,
                 end_with_outcome,
                 ..
             }) => {
-                if let Some(busy_time) = busy_time {
-                    tokio::time::sleep(*busy_time).await;
+                let interrupted = match busy_time {
+                    Some(busy_time) => tokio::select! {
+                        () = tokio::time::sleep(*busy_time) => false,
+                        () = cancellation_token.cancelled() => true,
+                    },
+                    None => false,
+                };
+                if interrupted {
+                    TaskOutcome::Failed(TaskError::Empty)
+                } else {
+                    end_with_outcome
+                        .clone()
+                        .unwrap_or(TaskOutcome::Success(TaskResult::Empty))
                 }
-                end_with_outcome
-                    .clone()
-                    .unwrap_or(TaskOutcome::Success(TaskResult::Empty))
             }
             LogicalPlan::Reset(reset_args) => self.reset_dataset_logical_plan(reset_args).await?,
             LogicalPlan::HardCompactionDataset(hard_compaction_args) => {
-                self.hard_compaction_logical_plan(hard_compaction_args)
-                    .await?
+                self.hard_compaction_logical_plan(
+                    hard_compaction_args,
+                    cancellation_token,
+                    recorder,
+                )
+                .await?
+            }
+            LogicalPlan::GarbageCollection(gc_args) => {
+                self.garbage_collection_logical_plan(gc_args).await?
             }
         };
 
@@ -118,12 +506,15 @@ impl TaskExecutorImpl {
         &self,
         mut task: Task,
         task_outcome: TaskOutcome,
+        recorder: &TaskActivityRecorder,
     ) -> Result<(), InternalError> {
         DatabaseTransactionRunner::new(self.catalog.clone())
             .transactional_with2(
                 |event_store: Arc<dyn TaskSystemEventStore>, outbox: Arc<dyn Outbox>| async move {
                     // Refresh the task in case it was updated concurrently (e.g. late cancellation)
                     task.update(event_store.as_ref()).await.int_err()?;
+                    self.save_task_activity(&mut task, recorder, event_store.as_ref())
+                        .await?;
                     task.finish(self.time_source.now(), task_outcome.clone())
                         .int_err()?;
                     task.save(event_store.as_ref()).await.int_err()?;
@@ -148,6 +539,8 @@ impl TaskExecutorImpl {
     async fn update_dataset_logical_plan(
         &self,
         update_dataset_args: &UpdateDataset,
+        cancellation_token: &CancellationToken,
+        recorder: &TaskActivityRecorder,
     ) -> Result<TaskOutcome, InternalError> {
         let dataset_env_vars = DatabaseTransactionRunner::new(self.catalog.clone())
             .transactional_with(
@@ -171,6 +564,7 @@ impl TaskExecutorImpl {
             ingest_options: PollingIngestOptions {
                 dataset_env_vars: dataset_env_vars_hash_map,
                 fetch_uncacheable: update_dataset_args.fetch_uncacheable,
+                cancellation_token: cancellation_token.clone(),
                 ..Default::default()
             },
             ..Default::default()
@@ -181,7 +575,7 @@ impl TaskExecutorImpl {
             .pull(
                 &update_dataset_args.dataset_id.as_any_ref(),
                 pull_options,
-                None,
+                Some(Arc::new(recorder.clone())),
             )
             .await;
 
@@ -189,16 +583,22 @@ impl TaskExecutorImpl {
             Ok(pull_result) => Ok(TaskOutcome::Success(TaskResult::UpdateDatasetResult(
                 TaskUpdateDatasetResult { pull_result },
             ))),
-            Err(err) => match err {
-                PullError::TransformError(TransformError::InvalidInterval(_)) => {
-                    Ok(TaskOutcome::Failed(TaskError::UpdateDatasetError(
-                        UpdateDatasetTaskError::RootDatasetCompacted(RootDatasetCompactedError {
-                            dataset_id: update_dataset_args.dataset_id.clone(),
-                        }),
-                    )))
+            Err(err) => {
+                recorder.log(format!("Update failed: {err}"));
+
+                match err {
+                    PullError::TransformError(TransformError::InvalidInterval(_)) => {
+                        Ok(TaskOutcome::Failed(TaskError::UpdateDatasetError(
+                            UpdateDatasetTaskError::RootDatasetCompacted(
+                                RootDatasetCompactedError {
+                                    dataset_id: update_dataset_args.dataset_id.clone(),
+                                },
+                            ),
+                        )))
+                    }
+                    _ => Ok(TaskOutcome::Failed(TaskError::Empty)),
                 }
-                _ => Ok(TaskOutcome::Failed(TaskError::Empty)),
-            },
+            }
         }
     }
 
@@ -233,9 +633,45 @@ impl TaskExecutorImpl {
         }
     }
 
+    async fn garbage_collection_logical_plan(
+        &self,
+        gc_args: &GarbageCollection,
+    ) -> Result<TaskOutcome, InternalError> {
+        let storage_gc_svc = self.catalog.get_one::<dyn StorageGcService>().int_err()?;
+
+        let gc_result = storage_gc_svc
+            .collect_garbage(StorageGcOptions {
+                dry_run: gc_args.dry_run,
+                ..Default::default()
+            })
+            .await;
+
+        match gc_result {
+            Ok(results) => Ok(TaskOutcome::Success(TaskResult::GarbageCollectionResult(
+                TaskGarbageCollectionResult {
+                    objects_deleted: results.iter().map(|r| r.unreferenced_objects.len()).sum(),
+                    bytes_freed: results
+                        .iter()
+                        .map(StorageGcDatasetResult::bytes_freed)
+                        .sum(),
+                },
+            ))),
+            Err(err) => {
+                tracing::error!(error = ?err, "Garbage collection failed");
+                Ok(TaskOutcome::Failed(TaskError::GarbageCollectionError(
+                    GarbageCollectionTaskError {
+                        message: err.to_string(),
+                    },
+                )))
+            }
+        }
+    }
+
     async fn hard_compaction_logical_plan(
         &self,
         hard_compaction_args: &HardCompactionDataset,
+        cancellation_token: &CancellationToken,
+        recorder: &TaskActivityRecorder,
     ) -> Result<TaskOutcome, InternalError> {
         let compaction_svc = self.catalog.get_one::<dyn CompactionService>().int_err()?;
         let dataset_repo = self.catalog.get_one::<dyn DatasetRepository>().int_err()?;
@@ -251,8 +687,9 @@ impl TaskExecutorImpl {
                     max_slice_size: hard_compaction_args.max_slice_size,
                     max_slice_records: hard_compaction_args.max_slice_records,
                     keep_metadata_only: hard_compaction_args.keep_metadata_only,
+                    cancellation_token: cancellation_token.clone(),
                 },
-                None,
+                Some(Arc::new(recorder.clone())),
             )
             .await;
 
@@ -269,12 +706,37 @@ impl TaskExecutorImpl {
 
 #[async_trait::async_trait]
 impl TaskExecutor for TaskExecutorImpl {
-    // TODO: Error and panic handling strategy
     async fn run(&self) -> Result<(), InternalError> {
+        self.recover_tasks().await?;
+
+        let mut running_tasks = JoinSet::new();
+
         loop {
-            let task = self.take_task().await?;
-            let task_outcome = self.execute_task(&task).await?;
-            self.process_task_outcome(task, task_outcome).await?;
+            // Occupy free workers with tasks that can be executed right now
+            while running_tasks.len() < self.config.max_concurrent_tasks {
+                let Some(task) = self.try_take_task().await? else {
+                    break;
+                };
+                self.spawn_task(&mut running_tasks, task);
+            }
+
+            if running_tasks.is_empty() {
+                tokio::time::sleep(TAKE_TASK_INTERVAL).await;
+                continue;
+            }
+
+            // Wait for some task to complete, but not for too long, as newly queued tasks
+            // might be executable by idle workers
+            // A failure of a single task must not stop the executor
+            if let Ok(Some(joined)) =
+                tokio::time::timeout(TAKE_TASK_INTERVAL, running_tasks.join_next()).await
+            {
+                // Spawned tasks are never aborted and catch their panics
+                let (task_id, res) = joined.int_err()?;
+                if let Err(err) = res {
+                    self.on_task_run_failed(task_id, err).await;
+                }
+            }
         }
     }
 }
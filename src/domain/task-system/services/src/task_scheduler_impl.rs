@@ -7,10 +7,11 @@
 // the Business Source License, use of this software will be governed
 // by the Apache License, Version 2.0.
 
-use std::collections::VecDeque;
+use std::collections::{HashMap, VecDeque};
 use std::sync::{Arc, Mutex};
 
 use dill::*;
+use kamu_core::CancellationToken;
 use kamu_task_system::*;
 use opendatafabric::DatasetID;
 use time_source::SystemTimeSource;
@@ -18,30 +19,21 @@ use time_source::SystemTimeSource;
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
 pub struct TaskSchedulerImpl {
-    state: Arc<Mutex<State>>,
-    // TODO: EventStore is transaction-dependent, it can't be instantiated in a singleton
+    task_queue: Arc<TaskQueue>,
     event_store: Arc<dyn TaskSystemEventStore>,
     time_source: Arc<dyn SystemTimeSource>,
 }
 
-#[derive(Default)]
-struct State {
-    // TODO: store in DB or something like Redis
-    task_queue: VecDeque<TaskID>,
-}
-
-////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
-
 #[component(pub)]
 #[interface(dyn TaskScheduler)]
-#[scope(Singleton)]
 impl TaskSchedulerImpl {
     pub fn new(
+        task_queue: Arc<TaskQueue>,
         event_store: Arc<dyn TaskSystemEventStore>,
         time_source: Arc<dyn SystemTimeSource>,
     ) -> Self {
         Self {
-            state: Arc::new(Mutex::new(State::default())),
+            task_queue,
             event_store,
             time_source,
         }
@@ -50,6 +42,90 @@ impl TaskSchedulerImpl {
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
+/// Queue of the tasks awaiting execution. Unlike the scheduler, which works
+/// within a transaction, the queue outlives transactions and is restored from
+/// the event store by the executor on startup.
+///
+/// The queue also keeps the cancellation tokens of the tasks taken from it, so
+/// that cancelling a running task reaches its execution without polling the
+/// event store
+pub struct TaskQueue {
+    // TODO: store in DB or something like Redis
+    state: Mutex<TaskQueueState>,
+}
+
+#[derive(Default)]
+struct TaskQueueState {
+    queued: VecDeque<QueuedTask>,
+    running: HashMap<TaskID, CancellationToken>,
+}
+
+struct QueuedTask {
+    task_id: TaskID,
+    // Kept to decide whether the task can be taken without loading it
+    logical_plan: LogicalPlan,
+}
+
+#[component(pub)]
+#[scope(Singleton)]
+impl TaskQueue {
+    pub fn new() -> Self {
+        Self {
+            state: Mutex::new(TaskQueueState::default()),
+        }
+    }
+
+    /// Places the task at the end of the queue, returning the queue length
+    pub fn push(&self, task_id: TaskID, logical_plan: LogicalPlan) -> usize {
+        let mut state = self.state.lock().unwrap();
+        state.queued.push_back(QueuedTask {
+            task_id,
+            logical_plan,
+        });
+        state.queued.len()
+    }
+
+    /// Removes the task from the queue if it is still awaiting execution, or
+    /// signals its execution to stop otherwise
+    pub fn cancel(&self, task_id: TaskID) {
+        let mut state = self.state.lock().unwrap();
+        state
+            .queued
+            .retain(|queued_task| queued_task.task_id != task_id);
+        if let Some(cancellation_token) = state.running.get(&task_id) {
+            cancellation_token.cancel();
+        }
+    }
+
+    /// Removes the first task, which satisfies the constraints, from the queue
+    pub fn take_constrained(&self, constraints: &TaskTakeConstraints) -> Option<TaskID> {
+        let mut state = self.state.lock().unwrap();
+        let task_id = state
+            .queued
+            .iter()
+            .position(|queued_task| constraints.allows(&queued_task.logical_plan))
+            .and_then(|index| state.queued.remove(index))
+            .map(|queued_task| queued_task.task_id)?;
+        state.running.insert(task_id, CancellationToken::new());
+        Some(task_id)
+    }
+
+    /// Returns the token, which is cancelled once the cancellation of the
+    /// taken task is requested
+    pub fn cancellation_token(&self, task_id: TaskID) -> CancellationToken {
+        let mut state = self.state.lock().unwrap();
+        state.running.entry(task_id).or_default().clone()
+    }
+
+    /// Forgets the taken task once its execution is over
+    pub fn release(&self, task_id: TaskID) {
+        let mut state = self.state.lock().unwrap();
+        state.running.remove(&task_id);
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
 #[async_trait::async_trait]
 impl TaskScheduler for TaskSchedulerImpl {
     #[tracing::instrument(level = "info", skip_all, fields(?logical_plan))]
@@ -57,15 +133,11 @@ impl TaskScheduler for TaskSchedulerImpl {
         let mut task = Task::new(
             self.time_source.now(),
             self.event_store.new_task_id().await?,
-            logical_plan,
+            logical_plan.clone(),
         );
         task.save(self.event_store.as_ref()).await.int_err()?;
 
-        let queue_len = {
-            let mut state = self.state.lock().unwrap();
-            state.task_queue.push_back(task.task_id);
-            state.task_queue.len()
-        };
+        let queue_len = self.task_queue.push(task.task_id, logical_plan);
 
         tracing::info!(
             task_id = %task.task_id,
@@ -82,6 +154,33 @@ impl TaskScheduler for TaskSchedulerImpl {
         Ok(task.into())
     }
 
+    #[tracing::instrument(level = "info", skip_all, fields(%task_id))]
+    async fn get_task_logs(&self, task_id: TaskID) -> Result<Vec<TaskLogEntry>, GetTaskError> {
+        use futures::TryStreamExt;
+
+        // Ensures the task exists
+        Task::load(task_id, self.event_store.as_ref()).await?;
+
+        let events: Vec<_> = self
+            .event_store
+            .get_events(&task_id, GetEventsOpts::default())
+            .await
+            .try_collect()
+            .await
+            .int_err()?;
+
+        let entries = events
+            .into_iter()
+            .filter_map(|(_, event)| match event {
+                TaskEvent::TaskLogsAppended(e) => Some(e.entries),
+                _ => None,
+            })
+            .flatten()
+            .collect();
+
+        Ok(entries)
+    }
+
     #[tracing::instrument(level = "info", skip_all, fields(%task_id))]
     async fn cancel_task(&self, task_id: TaskID) -> Result<TaskState, CancelTaskError> {
         let mut task = Task::load(task_id, self.event_store.as_ref()).await?;
@@ -90,8 +189,7 @@ impl TaskScheduler for TaskSchedulerImpl {
             task.cancel(self.time_source.now()).int_err()?;
             task.save(self.event_store.as_ref()).await.int_err()?;
 
-            let mut state = self.state.lock().unwrap();
-            state.task_queue.retain(|task_id| *task_id != task.task_id);
+            self.task_queue.cancel(task.task_id);
         }
 
         Ok(task.into())
@@ -144,14 +242,16 @@ impl TaskScheduler for TaskSchedulerImpl {
         }
     }
 
-    // TODO: How to prevent tasks from being lost if executor crashes
     async fn try_take(&self) -> Result<Option<TaskID>, TakeTaskError> {
-        let task_id = {
-            let mut s = self.state.lock().unwrap();
-            s.task_queue.pop_front()
-        };
+        self.try_take_constrained(&TaskTakeConstraints::default())
+            .await
+    }
 
-        let Some(task_id) = task_id else {
+    async fn try_take_constrained(
+        &self,
+        constraints: &TaskTakeConstraints,
+    ) -> Result<Option<TaskID>, TakeTaskError> {
+        let Some(task_id) = self.task_queue.take_constrained(constraints) else {
             return Ok(None);
         };
 
@@ -84,6 +84,95 @@ async fn test_task_save_load_update() {
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
+#[test_log::test(tokio::test)]
+async fn test_task_agg_logs_and_progress() {
+    let event_store = InMemoryTaskSystemEventStore::new();
+    let task_id = event_store.new_task_id().await.unwrap();
+
+    let mut task = Task::new(Utc::now(), task_id, Probe::default().into());
+
+    // Only running tasks produce output
+    assert_matches!(
+        task.append_logs(Utc::now(), vec![]),
+        Err(ProjectionError { .. })
+    );
+
+    task.run(Utc::now()).unwrap();
+    task.append_logs(
+        Utc::now(),
+        vec![TaskLogEntry {
+            event_time: Utc::now(),
+            message: "Fetching".to_string(),
+        }],
+    )
+    .unwrap();
+    task.report_progress(
+        Utc::now(),
+        TaskProgress {
+            fetched_bytes: 100,
+            records_read: 0,
+            records_written: 0,
+        },
+    )
+    .unwrap();
+    task.report_progress(
+        Utc::now(),
+        TaskProgress {
+            fetched_bytes: 200,
+            records_read: 0,
+            records_written: 5,
+        },
+    )
+    .unwrap();
+    task.save(&event_store).await.unwrap();
+    assert_eq!(event_store.len().await.unwrap(), 5);
+
+    let mut task = Task::load(task_id, &event_store).await.unwrap();
+    assert_eq!(
+        task.progress,
+        TaskProgress {
+            fetched_bytes: 200,
+            records_read: 0,
+            records_written: 5,
+        }
+    );
+
+    task.finish(Utc::now(), TaskOutcome::Success(TaskResult::Empty))
+        .unwrap();
+    assert_matches!(
+        task.report_progress(Utc::now(), TaskProgress::default()),
+        Err(ProjectionError { .. })
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_log::test(tokio::test)]
+async fn test_task_agg_requeue() {
+    let event_store = InMemoryTaskSystemEventStore::new();
+
+    let mut task = Task::new(
+        Utc::now(),
+        event_store.new_task_id().await.unwrap(),
+        Probe::default().into(),
+    );
+    assert_matches!(task.requeue(Utc::now()), Err(ProjectionError { .. }));
+
+    task.run(Utc::now()).unwrap();
+    task.requeue(Utc::now()).unwrap();
+    task.save(&event_store).await.unwrap();
+
+    let mut task = Task::load(task.task_id, &event_store).await.unwrap();
+    assert_eq!(task.status, TaskStatus::Queued);
+    assert_eq!(task.ran_at, None);
+
+    // Requeued task can be taken again
+    task.run(Utc::now()).unwrap();
+    assert_eq!(task.status, TaskStatus::Running);
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
 #[test_log::test(tokio::test)]
 async fn test_task_agg_illegal_transition() {
     let event_store = InMemoryTaskSystemEventStore::new();
@@ -0,0 +1,198 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::assert_matches::assert_matches;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+use database_common::{DatabaseTransactionRunner, NoOpDatabasePlugin};
+use dill::{Catalog, CatalogBuilder};
+use kamu_task_system::{
+    Probe,
+    TaskError,
+    TaskExecutor,
+    TaskExecutorConfig,
+    TaskID,
+    TaskOutcome,
+    TaskResult,
+    TaskScheduler,
+    TaskState,
+    TaskStatus,
+    MESSAGE_PRODUCER_KAMU_TASK_EXECUTOR,
+};
+use kamu_task_system_inmem::InMemoryTaskSystemEventStore;
+use kamu_task_system_services::{TaskExecutorImpl, TaskQueue, TaskSchedulerImpl};
+use messaging_outbox::{register_message_dispatcher, Outbox, OutboxImmediateImpl};
+use time_source::SystemTimeSourceDefault;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+const LONG_BUSY_TIME: Duration = Duration::from_secs(60);
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_log::test(tokio::test)]
+async fn test_completes_task() {
+    let harness = TaskExecutorHarness::new(None);
+    let _executor = harness.run_executor();
+
+    let task_id = harness.create_probe_task(None).await;
+
+    assert_matches!(
+        harness.wait_finished(task_id).await.status,
+        TaskStatus::Finished(TaskOutcome::Success(TaskResult::Empty))
+    );
+}
+
+#[test_log::test(tokio::test)]
+async fn test_cancels_running_task() {
+    let harness = TaskExecutorHarness::new(None);
+    let _executor = harness.run_executor();
+
+    let task_id = harness.create_probe_task(Some(LONG_BUSY_TIME)).await;
+    harness.wait_running(task_id).await;
+
+    harness.task_scheduler().cancel_task(task_id).await.unwrap();
+
+    // Interrupted long before the probe would have completed
+    let task_state = harness.wait_finished(task_id).await;
+    assert_matches!(
+        task_state.status,
+        TaskStatus::Finished(TaskOutcome::Cancelled)
+    );
+    assert!(task_state.cancellation_requested);
+}
+
+#[test_log::test(tokio::test)]
+async fn test_interrupts_task_exceeding_timeout() {
+    let harness = TaskExecutorHarness::new(Some(chrono::Duration::milliseconds(100)));
+    let _executor = harness.run_executor();
+
+    let task_id = harness.create_probe_task(Some(LONG_BUSY_TIME)).await;
+
+    let task_state = harness.wait_finished(task_id).await;
+    assert_matches!(
+        task_state.status,
+        TaskStatus::Finished(TaskOutcome::Failed(TaskError::TimedOut))
+    );
+    assert!(!task_state.cancellation_requested);
+}
+
+#[test_log::test(tokio::test)]
+async fn test_timeout_does_not_affect_fast_task() {
+    let harness = TaskExecutorHarness::new(Some(chrono::Duration::seconds(30)));
+    let _executor = harness.run_executor();
+
+    let task_id = harness
+        .create_probe_task(Some(Duration::from_millis(50)))
+        .await;
+
+    assert_matches!(
+        harness.wait_finished(task_id).await.status,
+        TaskStatus::Finished(TaskOutcome::Success(TaskResult::Empty))
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+struct TaskExecutorHarness {
+    catalog: Catalog,
+}
+
+impl TaskExecutorHarness {
+    fn new(task_timeout: Option<chrono::Duration>) -> Self {
+        let mut b = CatalogBuilder::new();
+
+        b.add_builder(
+            OutboxImmediateImpl::builder()
+                .with_consumer_filter(messaging_outbox::ConsumerFilter::AllConsumers),
+        )
+        .bind::<dyn Outbox, OutboxImmediateImpl>()
+        .add::<SystemTimeSourceDefault>()
+        .add::<TaskQueue>()
+        .add::<TaskSchedulerImpl>()
+        .add::<InMemoryTaskSystemEventStore>()
+        .add::<TaskExecutorImpl>()
+        .add_value(TaskExecutorConfig::new(1, HashMap::new(), task_timeout))
+        .add::<DatabaseTransactionRunner>();
+
+        NoOpDatabasePlugin::init_database_components(&mut b);
+
+        register_message_dispatcher::<kamu_task_system::TaskProgressMessage>(
+            &mut b,
+            MESSAGE_PRODUCER_KAMU_TASK_EXECUTOR,
+        );
+
+        Self { catalog: b.build() }
+    }
+
+    fn task_scheduler(&self) -> Arc<dyn TaskScheduler> {
+        self.catalog.get_one::<dyn TaskScheduler>().unwrap()
+    }
+
+    fn run_executor(&self) -> AbortOnDrop {
+        let task_executor = self.catalog.get_one::<dyn TaskExecutor>().unwrap();
+        AbortOnDrop(tokio::spawn(async move { task_executor.run().await }))
+    }
+
+    async fn create_probe_task(&self, busy_time: Option<Duration>) -> TaskID {
+        self.task_scheduler()
+            .create_task(
+                Probe {
+                    busy_time,
+                    ..Probe::default()
+                }
+                .into(),
+            )
+            .await
+            .unwrap()
+            .task_id
+    }
+
+    async fn wait_running(&self, task_id: TaskID) -> TaskState {
+        self.wait_for(task_id, |task_state| {
+            task_state.status != TaskStatus::Queued
+        })
+        .await
+    }
+
+    async fn wait_finished(&self, task_id: TaskID) -> TaskState {
+        self.wait_for(task_id, |task_state| {
+            matches!(task_state.status, TaskStatus::Finished(_))
+        })
+        .await
+    }
+
+    async fn wait_for(&self, task_id: TaskID, predicate: impl Fn(&TaskState) -> bool) -> TaskState {
+        let task_scheduler = self.task_scheduler();
+
+        tokio::time::timeout(Duration::from_secs(10), async {
+            loop {
+                let task_state = task_scheduler.get_task(task_id).await.unwrap();
+                if predicate(&task_state) {
+                    return task_state;
+                }
+                tokio::time::sleep(Duration::from_millis(10)).await;
+            }
+        })
+        .await
+        .expect("Task did not reach the expected state in time")
+    }
+}
+
+struct AbortOnDrop(tokio::task::JoinHandle<Result<(), internal_error::InternalError>>);
+
+impl Drop for AbortOnDrop {
+    fn drop(&mut self) {
+        self.0.abort();
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -10,9 +10,19 @@
 use std::assert_matches::assert_matches;
 use std::sync::Arc;
 
-use kamu_task_system::{LogicalPlan, Probe, TaskScheduler, TaskState, TaskStatus};
+use kamu_task_system::{
+    GarbageCollection,
+    LogicalPlan,
+    LogicalPlanKind,
+    Probe,
+    TaskScheduler,
+    TaskState,
+    TaskStatus,
+    TaskTakeConstraints,
+};
 use kamu_task_system_inmem::InMemoryTaskSystemEventStore;
-use kamu_task_system_services::TaskSchedulerImpl;
+use kamu_task_system_services::{TaskQueue, TaskSchedulerImpl};
+use opendatafabric::DatasetID;
 use time_source::SystemTimeSourceStub;
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -82,13 +92,56 @@ async fn test_task_cancellation() {
     assert_eq!(task_sched.try_take().await.unwrap(), None);
 }
 
+#[test_log::test(tokio::test)]
+async fn test_constrained_take() {
+    let task_sched = create_task_scheduler();
+
+    let foo_id = DatasetID::new_seeded_ed25519(b"foo");
+    let bar_id = DatasetID::new_seeded_ed25519(b"bar");
+
+    let mut task_ids = Vec::new();
+    for logical_plan in [
+        LogicalPlan::from(Probe {
+            dataset_id: Some(foo_id.clone()),
+            ..Probe::default()
+        }),
+        LogicalPlan::GarbageCollection(GarbageCollection { dry_run: false }),
+        LogicalPlan::from(Probe {
+            dataset_id: Some(bar_id.clone()),
+            ..Probe::default()
+        }),
+    ] {
+        let task_id = task_sched.create_task(logical_plan).await.unwrap().task_id;
+        task_ids.push(task_id);
+    }
+
+    // "foo" is busy and garbage collection is saturated, only "bar" can be taken
+    let constraints = TaskTakeConstraints {
+        busy_datasets: [foo_id].into(),
+        saturated_plan_kinds: [LogicalPlanKind::GarbageCollection].into(),
+    };
+    assert_eq!(
+        task_sched.try_take_constrained(&constraints).await.unwrap(),
+        Some(task_ids[2])
+    );
+    assert_eq!(
+        task_sched.try_take_constrained(&constraints).await.unwrap(),
+        None
+    );
+
+    // Skipped tasks retain their order in the queue
+    assert_eq!(task_sched.try_take().await.unwrap(), Some(task_ids[0]));
+    assert_eq!(task_sched.try_take().await.unwrap(), Some(task_ids[1]));
+    assert_eq!(task_sched.try_take().await.unwrap(), None);
+}
+
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
 fn create_task_scheduler() -> impl TaskScheduler {
     let event_store = Arc::new(InMemoryTaskSystemEventStore::new());
     let time_source = Arc::new(SystemTimeSourceStub::new());
 
-    TaskSchedulerImpl::new(event_store, time_source)
+    TaskSchedulerImpl::new(Arc::new(TaskQueue::new()), event_store, time_source)
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -9,6 +9,7 @@
 
 mod test_add_command;
 mod test_complete_command;
+mod test_export_command;
 mod test_ingest_command;
 mod test_repo_alias_command;
 mod test_sql_command;
@@ -16,6 +17,7 @@ mod test_system_generate_token_command;
 
 pub use test_add_command::*;
 pub use test_complete_command::*;
+pub use test_export_command::*;
 pub use test_ingest_command::*;
 pub use test_repo_alias_command::*;
 pub use test_sql_command::*;
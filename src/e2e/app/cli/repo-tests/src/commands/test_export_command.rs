@@ -0,0 +1,120 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::path::Path;
+
+use chrono::{TimeZone, Utc};
+use indoc::indoc;
+use kamu_cli_puppet::extensions::KamuCliPuppetExt;
+use kamu_cli_puppet::KamuCliPuppet;
+use opendatafabric::*;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub async fn test_export_csv_partitioned_by_records(mut kamu: KamuCliPuppet) {
+    kamu.set_system_time(Some(Utc.with_ymd_and_hms(2000, 1, 1, 0, 0, 0).unwrap()));
+
+    kamu.add_dataset(DatasetSnapshot {
+        name: "population".try_into().unwrap(),
+        kind: DatasetKind::Root,
+        metadata: vec![AddPushSource {
+            source_name: SourceState::DEFAULT_SOURCE_NAME.to_string(),
+            read: ReadStepNdJson {
+                schema: Some(vec![
+                    "event_time TIMESTAMP".to_owned(),
+                    "city STRING".to_owned(),
+                    "population BIGINT".to_owned(),
+                ]),
+                ..Default::default()
+            }
+            .into(),
+            preprocess: None,
+            merge: MergeStrategyLedger {
+                primary_key: vec!["event_time".to_owned(), "city".to_owned()],
+                mode: None,
+                dedupe_window_records: None,
+                dedupe_window_seconds: None,
+            }
+            .into(),
+            watermark: None,
+        }
+        .into()],
+    })
+    .await;
+
+    let data_path = kamu.workspace_path().join("data.csv");
+    std::fs::write(
+        &data_path,
+        indoc!(
+            "
+            2020-01-01,A,1000
+            2020-01-01,B,2000
+            2020-01-01,C,3000
+            "
+        ),
+    )
+    .unwrap();
+
+    kamu.execute([
+        "ingest",
+        "population",
+        "--input-format",
+        "csv",
+        path(&data_path),
+    ])
+    .await
+    .success();
+
+    let export_path = kamu.workspace_path().join("export");
+
+    kamu.execute([
+        "export",
+        "population",
+        "--output",
+        path(&export_path),
+        "--format",
+        "csv",
+        "--records-per-file",
+        "2",
+    ])
+    .await
+    .success();
+
+    assert_eq!(
+        std::fs::read_to_string(export_path.join("part-00000.csv")).unwrap(),
+        indoc!(
+            "
+            event_time,city,population
+            2020-01-01T00:00:00Z,A,1000
+            2020-01-01T00:00:00Z,B,2000"
+        )
+    );
+    assert_eq!(
+        std::fs::read_to_string(export_path.join("part-00001.csv")).unwrap(),
+        indoc!(
+            "
+            event_time,city,population
+            2020-01-01T00:00:00Z,C,3000"
+        )
+    );
+    assert!(!export_path.join("part-00002.csv").exists());
+
+    // Output directory must be empty
+    kamu.execute(["export", "population", "--output", path(&export_path)])
+        .await
+        .failure();
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+fn path(p: &Path) -> &str {
+    p.as_os_str().to_str().unwrap()
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
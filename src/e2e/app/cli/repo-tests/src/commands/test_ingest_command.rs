@@ -37,8 +37,12 @@ pub async fn test_push_ingest_from_file_ledger(mut kamu: KamuCliPuppet) {
             preprocess: None,
             merge: MergeStrategyLedger {
                 primary_key: vec!["event_time".to_owned(), "city".to_owned()],
+                mode: None,
+                dedupe_window_records: None,
+                dedupe_window_seconds: None,
             }
             .into(),
+            watermark: None,
         }
         .into()],
     })
@@ -118,6 +122,7 @@ pub async fn test_push_ingest_from_file_snapshot_with_event_time(mut kamu: KamuC
                 compare_columns: None,
             }
             .into(),
+            watermark: None,
         }
         .into()],
     })
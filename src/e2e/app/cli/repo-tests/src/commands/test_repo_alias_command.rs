@@ -30,8 +30,12 @@ pub async fn test_repository_pull_aliases_commands(kamu: KamuCliPuppet) {
             preprocess: None,
             merge: MergeStrategyLedger {
                 primary_key: vec!["event_time".to_owned(), "foo_string".to_owned()],
+                mode: None,
+                dedupe_window_records: None,
+                dedupe_window_seconds: None,
             }
             .into(),
+            watermark: None,
         }
         .into()],
     })
@@ -92,8 +96,12 @@ pub async fn test_repository_push_aliases_commands(kamu: KamuCliPuppet) {
             preprocess: None,
             merge: MergeStrategyLedger {
                 primary_key: vec!["event_time".to_owned(), "foo_string".to_owned()],
+                mode: None,
+                dedupe_window_records: None,
+                dedupe_window_seconds: None,
             }
             .into(),
+            watermark: None,
         }
         .into()],
     })
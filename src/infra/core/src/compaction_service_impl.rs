@@ -49,6 +49,7 @@ use time_source::SystemTimeSource;
 use url::Url;
 
 use crate::utils::datasets_filtering::filter_datasets_by_local_pattern;
+use crate::utils::operation_dir::OperationDirGuard;
 use crate::*;
 
 pub struct CompactionServiceImpl {
@@ -266,11 +267,14 @@ impl CompactionServiceImpl {
         data_slice_batches: &mut [DataSliceBatch],
         offset_column: &str,
         compaction_dir_path: &Path,
+        cancellation_token: &CancellationToken,
     ) -> Result<(), CompactionError> {
         let ctx = new_session_context(self.object_store_registry.clone());
 
         for (index, data_slice_batch) in data_slice_batches.iter_mut().enumerate() {
             if let DataSliceBatch::CompactedBatch(data_slice_batch_info) = data_slice_batch {
+                cancellation_token.check_cancelled()?;
+
                 let data_frame = ctx
                     .read_parquet(
                         data_slice_batch_info.data_slices_batch.clone(),
@@ -401,9 +405,10 @@ impl CompactionServiceImpl {
         max_slice_size: u64,
         max_slice_records: u64,
         keep_metadata_only: bool,
+        cancellation_token: &CancellationToken,
         listener: Arc<dyn CompactionListener>,
     ) -> Result<CompactionResult, CompactionError> {
-        let compaction_dir_path = self.create_run_compaction_dir()?;
+        let compaction_dir = OperationDirGuard::new(self.create_run_compaction_dir()?);
 
         listener.begin_phase(CompactionPhase::GatherChainInfo);
         let mut chain_files_info = self
@@ -425,7 +430,8 @@ impl CompactionServiceImpl {
         self.merge_files(
             &mut chain_files_info.data_slice_batches,
             chain_files_info.offset_column.as_str(),
-            &compaction_dir_path,
+            compaction_dir.path(),
+            cancellation_token,
         )
         .await?;
 
@@ -434,6 +440,10 @@ impl CompactionServiceImpl {
             .commit_new_blocks(dataset.clone(), &chain_files_info)
             .await?;
 
+        // New blocks stay unreachable until the head is moved, so stopping here leaves
+        // the dataset intact
+        cancellation_token.check_cancelled()?;
+
         dataset
             .as_metadata_chain()
             .set_ref(
@@ -453,6 +463,7 @@ impl CompactionServiceImpl {
             new_num_blocks,
         };
 
+        compaction_dir.finish();
         listener.success(&res);
 
         Ok(res)
@@ -501,6 +512,7 @@ impl CompactionService for CompactionServiceImpl {
                 max_slice_size,
                 max_slice_records,
                 options.keep_metadata_only,
+                &options.cancellation_token,
                 listener.clone(),
             )
             .await
@@ -0,0 +1,207 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use database_common::DatabasePaginationOpts;
+use dill::*;
+use internal_error::{InternalError, ResultIntoInternal};
+use kamu_accounts::{AuthenticationService, CurrentAccountSubject};
+use kamu_datasets::{DatasetEntry, DatasetEntryRepository};
+use opendatafabric::{AccountID, AccountName, DatasetID};
+use time_source::SystemTimeSource;
+
+use crate::DatasetStorageScanner;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+const DATASET_ENTRIES_PAGE_SIZE: i64 = 100;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Brings the catalog of dataset entries in line with the datasets that are
+/// actually present in the storage: adds missing entries, updates renamed ones
+/// and removes entries of datasets that no longer exist
+pub struct DatasetEntriesReconciler {
+    current_account_subject: Arc<CurrentAccountSubject>,
+    dataset_storage_scanner: Arc<dyn DatasetStorageScanner>,
+    dataset_entry_repo: Arc<dyn DatasetEntryRepository>,
+    authentication_service: Arc<dyn AuthenticationService>,
+    time_source: Arc<dyn SystemTimeSource>,
+}
+
+#[component(pub)]
+impl DatasetEntriesReconciler {
+    pub fn new(
+        current_account_subject: Arc<CurrentAccountSubject>,
+        dataset_storage_scanner: Arc<dyn DatasetStorageScanner>,
+        dataset_entry_repo: Arc<dyn DatasetEntryRepository>,
+        authentication_service: Arc<dyn AuthenticationService>,
+        time_source: Arc<dyn SystemTimeSource>,
+    ) -> Self {
+        Self {
+            current_account_subject,
+            dataset_storage_scanner,
+            dataset_entry_repo,
+            authentication_service,
+            time_source,
+        }
+    }
+
+    /// Performs the reconciliation only when the number of entries in the
+    /// catalog differs from the number of datasets in the storage, e.g. right
+    /// after the database was created for an existing workspace or when
+    /// datasets were added to or removed from the storage directly
+    #[tracing::instrument(level = "debug", skip_all)]
+    pub async fn reconcile_if_out_of_date(
+        &self,
+    ) -> Result<Option<DatasetEntriesReconciliationSummary>, InternalError> {
+        let entries_count = self
+            .dataset_entry_repo
+            .dataset_entries_count()
+            .await
+            .int_err()?;
+
+        let datasets_count = self.dataset_storage_scanner.count_all_datasets().await?;
+
+        if entries_count == datasets_count {
+            return Ok(None);
+        }
+
+        tracing::info!(
+            entries_count,
+            datasets_count,
+            "Catalog of dataset entries is out of date"
+        );
+
+        self.reconcile().await.map(Some)
+    }
+
+    #[tracing::instrument(level = "info", skip_all)]
+    pub async fn reconcile(&self) -> Result<DatasetEntriesReconciliationSummary, InternalError> {
+        use futures::TryStreamExt;
+
+        let mut summary = DatasetEntriesReconciliationSummary::default();
+
+        let mut entries_by_id = self.load_all_entries().await?;
+        let mut account_ids_by_name: HashMap<AccountName, Option<AccountID>> = HashMap::new();
+
+        let mut datasets_stream = self.dataset_storage_scanner.scan_all_datasets();
+        while let Some(dataset_handle) = datasets_stream.try_next().await? {
+            let maybe_existing_entry = entries_by_id.remove(&dataset_handle.id);
+            let dataset_name = dataset_handle.alias.dataset_name;
+
+            if let Some(existing_entry) = maybe_existing_entry {
+                if existing_entry.name != dataset_name {
+                    self.dataset_entry_repo
+                        .update_dataset_entry_name(&dataset_handle.id, &dataset_name)
+                        .await
+                        .int_err()?;
+                    summary.renamed += 1;
+                }
+                continue;
+            }
+
+            let owner_name = match dataset_handle.alias.account_name {
+                Some(account_name) => account_name,
+                None => match self.current_account_subject.as_ref() {
+                    CurrentAccountSubject::Anonymous(_) => {
+                        panic!("Reconciling dataset entries without authorization")
+                    }
+                    CurrentAccountSubject::Logged(l) => l.account_name.clone(),
+                },
+            };
+
+            let maybe_owner_id = if let Some(maybe_owner_id) = account_ids_by_name.get(&owner_name)
+            {
+                maybe_owner_id.clone()
+            } else {
+                let maybe_owner_id = self
+                    .authentication_service
+                    .find_account_id_by_name(&owner_name)
+                    .await?;
+                account_ids_by_name.insert(owner_name.clone(), maybe_owner_id.clone());
+                maybe_owner_id
+            };
+
+            let Some(owner_id) = maybe_owner_id else {
+                tracing::warn!(
+                    dataset_id = %dataset_handle.id,
+                    %owner_name,
+                    "Owner account of the dataset is unknown, skipping",
+                );
+                summary.skipped += 1;
+                continue;
+            };
+
+            let entry = DatasetEntry::new(
+                dataset_handle.id,
+                owner_id,
+                dataset_name,
+                self.time_source.now(),
+            );
+            self.dataset_entry_repo
+                .save_dataset_entry(&entry)
+                .await
+                .int_err()?;
+            summary.added += 1;
+        }
+
+        for dataset_id in entries_by_id.into_keys() {
+            self.dataset_entry_repo
+                .delete_dataset_entry(&dataset_id)
+                .await
+                .int_err()?;
+            summary.removed += 1;
+        }
+
+        tracing::info!(?summary, "Dataset entries reconciled");
+
+        Ok(summary)
+    }
+
+    async fn load_all_entries(&self) -> Result<HashMap<DatasetID, DatasetEntry>, InternalError> {
+        let mut entries_by_id = HashMap::new();
+
+        let mut offset = 0;
+        loop {
+            let entries = self
+                .dataset_entry_repo
+                .get_dataset_entries(&DatabasePaginationOpts {
+                    limit: DATASET_ENTRIES_PAGE_SIZE,
+                    offset,
+                })
+                .await
+                .int_err()?;
+
+            let page_size = i64::try_from(entries.len()).int_err()?;
+            entries_by_id.extend(entries.into_iter().map(|entry| (entry.id.clone(), entry)));
+
+            if page_size < DATASET_ENTRIES_PAGE_SIZE {
+                break;
+            }
+            offset += page_size;
+        }
+
+        Ok(entries_by_id)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct DatasetEntriesReconciliationSummary {
+    pub added: usize,
+    pub renamed: usize,
+    pub removed: usize,
+    pub skipped: usize,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -429,6 +429,11 @@ impl MessageConsumerT<DatasetLifecycleMessage> for DependencyGraphServiceInMemor
                     self.add_dependency(&mut state, added_id, &message.dataset_id);
                 }
             }
+
+            DatasetLifecycleMessage::PollingSourceDisabled(_)
+            | DatasetLifecycleMessage::Renamed(_) => {
+                // No action required
+            }
         }
 
         Ok(())
@@ -7,25 +7,62 @@
 // the Business Source License, use of this software will be governed
 // by the Apache License, Version 2.0.
 
+use std::collections::HashMap;
+use std::path::Path;
 use std::sync::Arc;
 
+use chrono::{DateTime, Utc};
+use datafusion::arrow::datatypes::{DataType, SchemaRef, TimeUnit};
+use datafusion::config::{ParquetColumnOptions, ParquetOptions, TableParquetOptions};
 use datafusion::prelude::*;
 use internal_error::*;
 use kamu_core::engine::*;
+use kamu_core::*;
 use opendatafabric::*;
 
+use crate::new_session_context;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
 /// An in-process engine using Apache Arrow Datafusion framework.
 ///
 /// Being in-process, this engine is not properly versioned and ODF-compliant.
-/// We use it only for ingest preprocessing queries, as ingestion is
-/// fundamentally non-verifiable / non-reproducible.
-pub struct EngineDatafusionInproc;
+/// We use it for ingest preprocessing queries, as ingestion is fundamentally
+/// non-verifiable / non-reproducible. When created with transform support it
+/// can also execute derivative transformations - in this case the results are
+/// not guaranteed to be reproducible by the versioned container engine, so
+/// this mode is meant for environments without a container runtime.
+pub struct EngineDatafusionInproc {
+    transform_env: Option<TransformEnv>,
+}
+
+struct TransformEnv {
+    dataset_repo: Arc<dyn DatasetRepository>,
+    object_store_registry: Arc<dyn ObjectStoreRegistry>,
+    run_info_dir: Arc<RunInfoDir>,
+}
 
 impl EngineDatafusionInproc {
     const OUTPUT_VIEW_ALIAS: &'static str = "__output__";
 
     pub fn new() -> Self {
-        Self {}
+        Self {
+            transform_env: None,
+        }
+    }
+
+    pub fn new_with_transform_support(
+        dataset_repo: Arc<dyn DatasetRepository>,
+        object_store_registry: Arc<dyn ObjectStoreRegistry>,
+        run_info_dir: Arc<RunInfoDir>,
+    ) -> Self {
+        Self {
+            transform_env: Some(TransformEnv {
+                dataset_repo,
+                object_store_registry,
+                run_info_dir,
+            }),
+        }
     }
 
     async fn register_view(
@@ -65,6 +102,304 @@ impl EngineDatafusionInproc {
         ctx.execute_logical_plan(create_view).await.int_err()?;
         Ok(())
     }
+
+    /// Reads the `(prevOffset, newOffset]` interval of the input's records
+    async fn read_input(
+        env: &TransformEnv,
+        ctx: &SessionContext,
+        input: &TransformRequestInputExt,
+    ) -> Result<DataFrame, EngineError> {
+        use futures::StreamExt;
+
+        let new_offset = match input.new_offset {
+            Some(new_offset) if !input.data_slices.is_empty() => new_offset,
+            _ => {
+                let empty = datafusion::datasource::MemTable::try_new(
+                    input.schema.clone(),
+                    vec![Vec::new()],
+                )
+                .int_err()?;
+                return Ok(ctx.read_table(Arc::new(empty)).int_err()?);
+            }
+        };
+
+        let dataset = env
+            .dataset_repo
+            .get_dataset_by_handle(&input.dataset_handle);
+        let data_repo = dataset.as_data_repo();
+
+        let data_paths: Vec<_> = futures::stream::iter(input.data_slices.iter())
+            .then(|hash| data_repo.get_internal_url(hash))
+            .map(|url| url.to_string())
+            .collect()
+            .await;
+
+        let offset_col = || col(Column::from_name(&input.vocab.offset_column));
+
+        let df = ctx
+            .read_parquet(
+                data_paths,
+                ParquetReadOptions {
+                    schema: Some(input.schema.as_ref()),
+                    file_extension: "",
+                    ..Default::default()
+                },
+            )
+            .await
+            .int_err()?
+            .filter(offset_col().lt_eq(lit(i64::try_from(new_offset).unwrap())))
+            .int_err()?;
+
+        let df = if let Some(prev_offset) = input.prev_offset {
+            df.filter(offset_col().gt(lit(i64::try_from(prev_offset).unwrap())))
+                .int_err()?
+        } else {
+            df
+        };
+
+        Ok(df.sort(vec![offset_col().sort(true, false)]).int_err()?)
+    }
+
+    fn validate_output(df: &DataFrame, vocab: &DatasetVocabulary) -> Result<(), EngineError> {
+        let schema = df.schema();
+
+        for system_column in [&vocab.offset_column, &vocab.system_time_column] {
+            if schema.has_column_with_unqualified_name(system_column) {
+                return Err(EngineError::invalid_query(
+                    format!(
+                        "Transformed data contains a column that conflicts with the system column \
+                         name, you should either rename the data column or configure the dataset \
+                         vocabulary to use a different name: {system_column}"
+                    ),
+                    Vec::new(),
+                ));
+            }
+        }
+
+        let Ok(event_time) = schema.field_with_unqualified_name(&vocab.event_time_column) else {
+            return Err(EngineError::invalid_query(
+                format!(
+                    "Event time column {} was not found amongst: {}",
+                    vocab.event_time_column,
+                    schema
+                        .fields()
+                        .iter()
+                        .map(|f| f.name().as_str())
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ),
+                Vec::new(),
+            ));
+        };
+
+        match event_time.data_type() {
+            DataType::Date32 | DataType::Date64 | DataType::Timestamp(_, _) => Ok(()),
+            typ => Err(EngineError::invalid_query(
+                format!(
+                    "Event time column {} should be either Date or Timestamp, but found: {typ}",
+                    vocab.event_time_column
+                ),
+                Vec::new(),
+            )),
+        }
+    }
+
+    // TODO: Similarly to the ingest, all timestamps in the output are represented
+    // as `Timestamp(Millis, "UTC")` for compatibility with other engines
+    fn normalize_output(
+        df: DataFrame,
+        vocab: &DatasetVocabulary,
+        system_time: DateTime<Utc>,
+        prev_offset: Option<u64>,
+    ) -> Result<DataFrame, InternalError> {
+        use datafusion::logical_expr as expr;
+        use datafusion::logical_expr::expr::WindowFunction;
+        use datafusion::scalar::ScalarValue;
+
+        let utc_tz: Arc<str> = Arc::from("UTC");
+
+        let select: Vec<Expr> = df
+            .schema()
+            .fields()
+            .iter()
+            .map(|field| match field.data_type() {
+                DataType::Timestamp(TimeUnit::Millisecond, Some(tz)) if tz.as_ref() == "UTC" => {
+                    col(Column::from_name(field.name()))
+                }
+                DataType::Timestamp(_, _) => cast(
+                    col(Column::from_name(field.name())),
+                    DataType::Timestamp(TimeUnit::Millisecond, Some(utc_tz.clone())),
+                )
+                .alias(field.name()),
+                _ => col(Column::from_name(field.name())),
+            })
+            .collect();
+
+        let df = df.select(select).int_err()?;
+
+        // Queries that don't produce retractions and corrections may omit the
+        // operation type column
+        let df = if df
+            .schema()
+            .has_column_with_unqualified_name(&vocab.operation_type_column)
+        {
+            df
+        } else {
+            df.with_column(
+                &vocab.operation_type_column,
+                lit(OperationType::Append as i32),
+            )
+            .int_err()?
+        };
+
+        let mut data_columns: Vec<_> = df
+            .schema()
+            .fields()
+            .iter()
+            .map(|f| f.name().clone())
+            .filter(|n| *n != vocab.event_time_column && *n != vocab.operation_type_column)
+            .collect();
+
+        let df = df
+            .with_column(
+                &vocab.system_time_column,
+                Expr::Literal(ScalarValue::TimestampMillisecond(
+                    Some(system_time.timestamp_millis()),
+                    Some(utc_tz),
+                )),
+            )
+            .int_err()?;
+
+        // Note: Data is held in one partition to assign offsets in the order in which
+        // the query produced the records and to avoid reordering when saving to
+        // parquet
+        let df = df
+            .repartition(Partitioning::RoundRobinBatch(1))
+            .int_err()?
+            .with_column(
+                &vocab.offset_column,
+                Expr::WindowFunction(WindowFunction {
+                    fun: expr::WindowFunctionDefinition::BuiltInWindowFunction(
+                        expr::BuiltInWindowFunction::RowNumber,
+                    ),
+                    args: vec![],
+                    partition_by: vec![],
+                    order_by: vec![],
+                    window_frame: expr::WindowFrame::new(Some(false)),
+                    null_treatment: None,
+                }),
+            )
+            .int_err()?;
+
+        let start_offset = prev_offset.map_or(0, |o| o + 1);
+        let df = df
+            .with_column(
+                &vocab.offset_column,
+                cast(
+                    col(Column::from_name(&vocab.offset_column))
+                        + lit(i64::try_from(start_offset).unwrap() - 1),
+                    // TODO: Replace with UInt64 after Spark is updated
+                    // See: https://github.com/kamu-data/kamu-cli/issues/445
+                    DataType::Int64,
+                ),
+            )
+            .int_err()?;
+
+        let mut full_columns = vec![
+            vocab.offset_column.clone(),
+            vocab.operation_type_column.clone(),
+            vocab.system_time_column.clone(),
+            vocab.event_time_column.clone(),
+        ];
+        full_columns.append(&mut data_columns);
+        let full_columns_str: Vec<_> = full_columns.iter().map(String::as_str).collect();
+
+        df.select_columns(&full_columns_str)
+            .int_err()?
+            .sort(vec![
+                col(Column::from_name(&vocab.offset_column)).sort(true, false)
+            ])
+            .int_err()
+    }
+
+    /// Output watermark is the minimum of the latest watermarks of all inputs
+    fn compute_output_watermark(inputs: &[TransformRequestInputExt]) -> Option<DateTime<Utc>> {
+        inputs
+            .iter()
+            .map(|input| {
+                input
+                    .explicit_watermarks
+                    .iter()
+                    .map(|wm| wm.event_time)
+                    .max()
+            })
+            .min()
+            .flatten()
+    }
+
+    fn get_write_properties(vocab: &DatasetVocabulary) -> TableParquetOptions {
+        TableParquetOptions {
+            global: ParquetOptions {
+                writer_version: "1.0".into(),
+                compression: Some("snappy".into()),
+                ..Default::default()
+            },
+            column_specific_options: HashMap::from([
+                (
+                    vocab.operation_type_column.clone(),
+                    ParquetColumnOptions {
+                        dictionary_enabled: Some(true),
+                        ..Default::default()
+                    },
+                ),
+                (
+                    vocab.system_time_column.clone(),
+                    ParquetColumnOptions {
+                        dictionary_enabled: Some(true),
+                        ..Default::default()
+                    },
+                ),
+            ]),
+            key_value_metadata: HashMap::new(),
+        }
+    }
+
+    /// Writes the output and returns the number of records written along with
+    /// the schema of the file
+    async fn write_output(
+        df: DataFrame,
+        path: &Path,
+        vocab: &DatasetVocabulary,
+    ) -> Result<(u64, SchemaRef), InternalError> {
+        use datafusion::arrow::array::UInt64Array;
+        use datafusion::dataframe::DataFrameWriteOptions;
+        use datafusion::parquet::arrow::arrow_reader::ArrowReaderMetadata;
+
+        let res = df
+            .write_parquet(
+                path.as_os_str().to_str().unwrap(),
+                DataFrameWriteOptions::new().with_single_file_output(true),
+                Some(Self::get_write_properties(vocab)),
+            )
+            .await
+            .int_err()?;
+
+        assert_eq!(res.len(), 1);
+        let num_records = res[0]
+            .column(0)
+            .as_any()
+            .downcast_ref::<UInt64Array>()
+            .unwrap()
+            .value(0);
+
+        let schema =
+            ArrowReaderMetadata::load(&std::fs::File::open(path).int_err()?, Default::default())
+                .int_err()?
+                .schema()
+                .clone();
+
+        Ok((num_records, schema))
+    }
 }
 
 #[async_trait::async_trait]
@@ -73,7 +408,10 @@ impl Engine for EngineDatafusionInproc {
     async fn execute_raw_query(
         &self,
         request: RawQueryRequestExt,
+        cancellation_token: &CancellationToken,
     ) -> Result<RawQueryResponseExt, EngineError> {
+        cancellation_token.check_cancelled()?;
+
         let Transform::Sql(transform) = request.transform;
         assert_eq!(transform.engine.to_lowercase(), "datafusion");
 
@@ -107,16 +445,104 @@ impl Engine for EngineDatafusionInproc {
 
         Ok(RawQueryResponseExt {
             output_data: Some(output_data),
+            log_files: Vec::new(),
         })
     }
 
     #[tracing::instrument(level = "info", skip_all)]
     async fn execute_transform(
         &self,
-        _request: TransformRequestExt,
+        request: TransformRequestExt,
+        cancellation_token: &CancellationToken,
     ) -> Result<TransformResponseExt, EngineError> {
-        unimplemented!(
-            "Derivative transformations must be executed by a versioned out-of-process engine"
-        )
+        let Some(env) = &self.transform_env else {
+            return Err(
+                "Derivative transformations must be executed by a versioned out-of-process engine"
+                    .int_err()
+                    .into(),
+            );
+        };
+
+        let Transform::Sql(transform) = &request.transform;
+        assert_eq!(transform.engine.to_lowercase(), "datafusion");
+
+        let ctx = new_session_context(env.object_store_registry.clone());
+
+        // Setup inputs
+        for input in &request.inputs {
+            let df = Self::read_input(env, &ctx, input).await?;
+            ctx.register_table(
+                datafusion::sql::TableReference::bare(input.alias.as_str()),
+                df.into_view(),
+            )
+            .int_err()?;
+        }
+
+        // Setup queries
+        for query_step in transform.queries.clone().unwrap_or_default() {
+            self.register_view(
+                &ctx,
+                query_step
+                    .alias
+                    .as_deref()
+                    .unwrap_or(Self::OUTPUT_VIEW_ALIAS),
+                query_step.query.as_str(),
+            )
+            .await?;
+        }
+
+        let output_data = ctx.table(Self::OUTPUT_VIEW_ALIAS).await.int_err()?;
+
+        Self::validate_output(&output_data, &request.vocab)?;
+
+        let output_data = Self::normalize_output(
+            output_data,
+            &request.vocab,
+            request.system_time,
+            request.prev_offset,
+        )?;
+
+        tracing::debug!(
+            schema = ?output_data.schema(),
+            logical_plan = ?output_data.logical_plan(),
+            "Prepared transform plan",
+        );
+
+        cancellation_token.check_cancelled()?;
+
+        let operation_dir = env
+            .run_info_dir
+            .join(format!("transform-{}", &request.operation_id));
+        std::fs::create_dir(&operation_dir).int_err()?;
+
+        let new_data_path = operation_dir.join("output");
+        let (num_records, output_schema) =
+            Self::write_output(output_data, &new_data_path, &request.vocab).await?;
+
+        // Data file is dropped when output is empty - it was produced only to get the
+        // schema
+        let new_data = OwnedFile::new(new_data_path);
+        let (new_offset_interval, new_data) = if num_records == 0 {
+            (None, None)
+        } else {
+            let start = request.prev_offset.map_or(0, |o| o + 1);
+            (
+                Some(OffsetInterval {
+                    start,
+                    end: start + num_records - 1,
+                }),
+                Some(new_data),
+            )
+        };
+
+        Ok(TransformResponseExt {
+            new_offset_interval,
+            new_watermark: Self::compute_output_watermark(&request.inputs),
+            output_schema: Some(output_schema),
+            // Stateless SQL queries don't need checkpoints
+            new_checkpoint: None,
+            new_data,
+            log_files: Vec::new(),
+        })
     }
 }
@@ -179,12 +179,13 @@ impl ODFEngine {
         engine_response: TransformResponseSuccess,
         new_data_path: PathBuf,
         new_checkpoint_path: PathBuf,
+        log_files: Vec<PathBuf>,
     ) -> Result<TransformResponseExt, EngineError> {
         let (new_data, output_schema) = if new_data_path.exists() {
             if new_data_path.is_symlink() || !new_data_path.is_file() {
                 return Err(EngineError::contract_error(
                     "Engine wrote data not as a plain file",
-                    Vec::new(),
+                    log_files,
                 ));
             }
 
@@ -213,7 +214,7 @@ impl ODFEngine {
             if engine_response.new_offset_interval.is_some() {
                 return Err(EngineError::contract_error(
                     "Engine did not write a response data file",
-                    Vec::new(),
+                    log_files,
                 ));
             }
 
@@ -229,7 +230,7 @@ impl ODFEngine {
             if new_checkpoint_path.is_symlink() || !new_checkpoint_path.is_file() {
                 return Err(EngineError::contract_error(
                     "Engine wrote checkpoint not as a plain file",
-                    Vec::new(),
+                    log_files,
                 ));
             }
             Some(OwnedFile::new(new_checkpoint_path))
@@ -243,6 +244,7 @@ impl ODFEngine {
             output_schema,
             new_checkpoint,
             new_data,
+            log_files,
         })
     }
 }
@@ -252,6 +254,7 @@ impl Engine for ODFEngine {
     async fn execute_raw_query(
         &self,
         request: RawQueryRequestExt,
+        cancellation_token: &CancellationToken,
     ) -> Result<RawQueryResponseExt, EngineError> {
         let operation_id = request.operation_id.clone();
         let operation_dir = self
@@ -325,10 +328,18 @@ impl Engine for ODFEngine {
 
         let mut engine_client = engine_container.connect_client().await?;
 
-        let engine_response = self
-            .execute_raw_query(&engine_container, &mut engine_client, materialized_request)
-            .await;
+        // Container is terminated explicitly in both cases, cancellation only stops
+        // waiting for the response
+        let engine_response = tokio::select! {
+            res = self.execute_raw_query(
+                &engine_container,
+                &mut engine_client,
+                materialized_request,
+            ) => res,
+            () = cancellation_token.cancelled() => Err(OperationCancelledError {}.into()),
+        };
 
+        let log_files = engine_container.log_files();
         engine_container.terminate().await?;
 
         let output_data = if engine_response?.num_records == 0 {
@@ -349,12 +360,16 @@ impl Engine for ODFEngine {
             )
         };
 
-        Ok(RawQueryResponseExt { output_data })
+        Ok(RawQueryResponseExt {
+            output_data,
+            log_files,
+        })
     }
 
     async fn execute_transform(
         &self,
         request: TransformRequestExt,
+        cancellation_token: &CancellationToken,
     ) -> Result<TransformResponseExt, EngineError> {
         let dataset = self
             .dataset_repo
@@ -387,20 +402,25 @@ impl Engine for ODFEngine {
 
         let mut engine_client = engine_container.connect_client().await?;
 
-        let engine_response = self
-            .execute_transform(
+        // Container is terminated explicitly in both cases, cancellation only stops
+        // waiting for the response
+        let engine_response = tokio::select! {
+            res = self.execute_transform(
                 &engine_container,
                 &mut engine_client,
                 materialized_request.engine_request,
-            )
-            .await;
+            ) => res,
+            () = cancellation_token.cancelled() => Err(OperationCancelledError {}.into()),
+        };
 
+        let log_files = engine_container.log_files();
         engine_container.terminate().await?;
 
         self.materialize_response(
             engine_response?,
             materialized_request.out_data_path,
             materialized_request.out_checkpoint_path,
+            log_files,
         )
     }
 }
@@ -27,6 +27,7 @@ pub struct EngineProvisionerLocal {
     spark_engine: Arc<dyn Engine>,
     flink_engine: Arc<dyn Engine>,
     datafusion_engine: Arc<dyn Engine>,
+    datafusion_inproc_engine: Arc<dyn Engine>,
     risingwave_engine: Arc<dyn Engine>,
     container_runtime: Arc<ContainerRuntime>,
     inner: Arc<Inner>,
@@ -50,6 +51,7 @@ impl EngineProvisionerLocal {
         config: EngineProvisionerLocalConfig,
         container_runtime: Arc<ContainerRuntime>,
         dataset_repo: Arc<dyn DatasetRepository>,
+        object_store_registry: Arc<dyn ObjectStoreRegistry>,
         run_info_dir: Arc<RunInfoDir>,
     ) -> Self {
         let engine_config = ODFEngineConfig {
@@ -79,6 +81,11 @@ impl EngineProvisionerLocal {
                 run_info_dir.clone(),
                 dataset_repo.clone(),
             )),
+            datafusion_inproc_engine: Arc::new(EngineDatafusionInproc::new_with_transform_support(
+                dataset_repo.clone(),
+                object_store_registry,
+                run_info_dir.clone(),
+            )),
             risingwave_engine: Arc::new(ODFEngine::new(
                 container_runtime.clone(),
                 engine_config.clone(),
@@ -199,6 +206,14 @@ impl EngineProvisioner for EngineProvisionerLocal {
     ) -> Result<Arc<dyn Engine>, EngineProvisioningError> {
         let listener = maybe_listener.unwrap_or_else(|| Arc::new(NullEngineProvisioningListener));
 
+        // In-process engine does not need an image and is not subject to the
+        // concurrency limit that exists to bound the number of containers
+        if engine_id == "datafusion" && self.config.datafusion_in_process {
+            listener.begin(engine_id);
+            listener.success();
+            return Ok(self.datafusion_inproc_engine.clone());
+        }
+
         let (engine, image) = match engine_id {
             "spark" => Ok((self.spark_engine.clone(), &self.config.spark_image)),
             "flink" => Ok((self.flink_engine.clone(), &self.config.flink_image)),
@@ -235,6 +250,9 @@ pub struct EngineProvisionerLocalConfig {
     pub start_timeout: Duration,
     /// Timeout for waiting for engine container to shutdown cleanly
     pub shutdown_timeout: Duration,
+    /// Whether to run `datafusion` transformations in-process instead of
+    /// using the engine container
+    pub datafusion_in_process: bool,
 
     // TODO: Remove in favor of explicit images in ODF protocol
     pub spark_image: String,
@@ -250,6 +268,7 @@ impl Default for EngineProvisionerLocalConfig {
             max_concurrency: None,
             start_timeout: Duration::from_secs(30),
             shutdown_timeout: Duration::from_secs(5),
+            datafusion_in_process: false,
             spark_image: docker_images::SPARK.to_owned(),
             flink_image: docker_images::FLINK.to_owned(),
             datafusion_image: docker_images::DATAFUSION.to_owned(),
@@ -299,15 +318,21 @@ impl Engine for EngineHandle {
     async fn execute_raw_query(
         &self,
         request: RawQueryRequestExt,
+        cancellation_token: &CancellationToken,
     ) -> Result<RawQueryResponseExt, EngineError> {
-        self.engine.execute_raw_query(request).await
+        self.engine
+            .execute_raw_query(request, cancellation_token)
+            .await
     }
 
     async fn execute_transform(
         &self,
         request: TransformRequestExt,
+        cancellation_token: &CancellationToken,
     ) -> Result<TransformResponseExt, EngineError> {
-        self.engine.execute_transform(request).await
+        self.engine
+            .execute_transform(request, cancellation_token)
+            .await
     }
 }
 
@@ -57,6 +57,21 @@ impl DataFormatRegistryImpl {
         media_type: MediaType::ESRI_SHAPEFILE,
         file_extensions: &["shp", "shx"],
     };
+    pub const FMT_AVRO: DataFormatDesc = DataFormatDesc {
+        short_name: "Avro",
+        media_type: MediaType::AVRO,
+        file_extensions: &["avro"],
+    };
+    pub const FMT_ORC: DataFormatDesc = DataFormatDesc {
+        short_name: "ORC",
+        media_type: MediaType::ORC,
+        file_extensions: &["orc"],
+    };
+    pub const FMT_EXCEL: DataFormatDesc = DataFormatDesc {
+        short_name: "Excel",
+        media_type: MediaType::XLSX,
+        file_extensions: &["xlsx"],
+    };
 
     pub fn new() -> Self {
         Self {}
@@ -74,6 +89,9 @@ impl DataFormatRegistry for DataFormatRegistryImpl {
             Self::FMT_NDGEOJSON,
             Self::FMT_PARQUET,
             Self::FMT_ESRI_SHAPEFILE,
+            Self::FMT_AVRO,
+            Self::FMT_ORC,
+            Self::FMT_EXCEL,
         ]
     }
 
@@ -98,6 +116,9 @@ impl DataFormatRegistry for DataFormatRegistryImpl {
             ReadStep::NdGeoJson(_) => Self::FMT_NDGEOJSON,
             ReadStep::Parquet(_) => Self::FMT_PARQUET,
             ReadStep::EsriShapefile(_) => Self::FMT_ESRI_SHAPEFILE,
+            ReadStep::Avro(_) => Self::FMT_AVRO,
+            ReadStep::Orc(_) => Self::FMT_ORC,
+            ReadStep::Excel(_) => Self::FMT_EXCEL,
         }
     }
 
@@ -109,16 +130,17 @@ impl DataFormatRegistry for DataFormatRegistryImpl {
     ) -> Result<Arc<dyn Reader>, ReadError> {
         let reader: Arc<dyn Reader> = match conf {
             ReadStep::Csv(conf) => Arc::new(ReaderCsv::new(ctx, conf).await?),
-            ReadStep::Json(conf) => Arc::new(ReaderJson::new(ctx, conf, temp_path).await?),
+            ReadStep::Json(conf) => Arc::new(ReaderJson::new(ctx, conf).await?),
             ReadStep::NdJson(conf) => Arc::new(ReaderNdJson::new(ctx, conf).await?),
-            ReadStep::GeoJson(conf) => Arc::new(ReaderGeoJson::new(ctx, conf, temp_path).await?),
-            ReadStep::NdGeoJson(conf) => {
-                Arc::new(ReaderNdGeoJson::new(ctx, conf, temp_path).await?)
-            }
+            ReadStep::GeoJson(conf) => Arc::new(ReaderGeoJson::new(ctx, conf).await?),
+            ReadStep::NdGeoJson(conf) => Arc::new(ReaderNdGeoJson::new(ctx, conf).await?),
             ReadStep::EsriShapefile(conf) => {
                 Arc::new(ReaderEsriShapefile::new(ctx, conf, temp_path).await?)
             }
             ReadStep::Parquet(conf) => Arc::new(ReaderParquet::new(ctx, conf).await?),
+            ReadStep::Avro(conf) => Arc::new(ReaderAvro::new(ctx, conf).await?),
+            ReadStep::Orc(conf) => Arc::new(ReaderOrc::new(ctx, conf).await?),
+            ReadStep::Excel(conf) => Arc::new(ReaderExcel::new(ctx, conf).await?),
         };
 
         Ok(reader)
@@ -175,6 +197,15 @@ impl DataFormatRegistry for DataFormatRegistryImpl {
                 }
                 .into())
             }
+            MediaType::AVRO | MediaTypeRef("avro/binary") => Ok(ReadStepAvro { schema }.into()),
+            MediaType::ORC => Ok(ReadStepOrc { schema }.into()),
+            MediaType::XLSX => Ok(ReadStepExcel {
+                // Same assumption as for CSV above
+                header: Some(schema.is_none()),
+                schema,
+                ..Default::default()
+            }
+            .into()),
             _ => Err(UnsupportedMediaTypeError::new(media_type.clone())),
         }
     }
@@ -82,6 +82,10 @@ pub struct EthereumSourceConfig {
     /// scanned even if we didn't reach the target record number. This is useful
     /// to not lose a lot of scanning progress in case of an RPC error.
     pub commit_after_blocks_scanned: u64,
+    /// Number of the most recently scanned blocks that are not considered
+    /// final. Hashes of these blocks are kept in the source state and
+    /// re-validated on every fetch to detect chain re-organizations.
+    pub confirmation_depth: u64,
 }
 
 impl Default for EthereumSourceConfig {
@@ -90,6 +94,7 @@ impl Default for EthereumSourceConfig {
             rpc_endpoints: Vec::new(),
             get_logs_block_stride: 100_000,
             commit_after_blocks_scanned: 1_000_000,
+            confirmation_depth: 12,
         }
     }
 }
@@ -67,7 +67,7 @@ impl FetchService {
         let new_has_more_data_path = out_dir.join("new-has-more-data");
 
         let (prev_etag, prev_last_modified) = match prev_source_state {
-            None => (String::new(), String::new()),
+            None | Some(PollingSourceState::MqttClientId(_)) => (String::new(), String::new()),
             Some(PollingSourceState::ETag(etag)) => (etag.clone(), String::new()),
             Some(PollingSourceState::LastModified(last_modified)) => (
                 String::new(),
@@ -134,7 +134,7 @@ impl FetchService {
 
         // Handle output in a task
         let mut stdout = container.take_stdout().unwrap();
-        let listener = listener.clone();
+        let output_listener = listener.clone();
 
         let output_task = tokio::spawn(async move {
             use tokio::io::{AsyncReadExt, AsyncWriteExt};
@@ -151,7 +151,7 @@ impl FetchService {
                 }
 
                 fetched_bytes += read as u64;
-                listener.on_progress(&FetchProgress {
+                output_listener.on_progress(&FetchProgress {
                     fetched_bytes,
                     total_bytes: TotalBytes::Unknown,
                 });
@@ -194,6 +194,8 @@ impl FetchService {
             return Err(ProcessError::new(status.code(), vec![stderr_path]).into());
         }
 
+        listener.on_log_files(&[stderr_path]);
+
         let source_state = if new_etag_path.exists() {
             let s = std::fs::read_to_string(new_etag_path).int_err()?;
             Some(PollingSourceState::ETag(s))
@@ -222,6 +224,7 @@ impl FetchService {
                 source_event_time: None,
                 has_more,
                 zero_copy_path: None,
+                retraction_filter: None,
             }))
         }
     }
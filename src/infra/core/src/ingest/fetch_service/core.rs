@@ -27,6 +27,17 @@ use crate::PollingSourceState;
 
 pub const ODF_BATCH_SIZE: &str = "ODF_BATCH_SIZE";
 
+/// Dataset environment variables holding the private key (in PEM format) and
+/// its optional passphrase that are used to authenticate with SFTP servers
+/// when the source URL does not contain a password
+pub const SFTP_PRIVATE_KEY: &str = "SFTP_PRIVATE_KEY";
+pub const SFTP_PRIVATE_KEY_PASSPHRASE: &str = "SFTP_PRIVATE_KEY_PASSPHRASE";
+
+/// Column of the fetched Ethereum logs that must be preserved in the output of
+/// the source, as logs from blocks orphaned by a chain re-organization are
+/// retracted by their block number
+pub const ETH_LOGS_BLOCK_NUMBER_COLUMN: &str = "block_number";
+
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
 pub struct FetchService {
@@ -42,6 +53,11 @@ pub struct FetchService {
 
     #[cfg_attr(not(feature = "ingest-mqtt"), allow(dead_code))]
     pub(super) mqtt_source_config: Arc<MqttSourceConfig>,
+
+    /// MQTT connections of the fetch operations whose messages will be
+    /// acknowledged once the data is committed, keyed by operation ID
+    #[cfg(feature = "ingest-mqtt")]
+    pub(super) mqtt_pending_acks: std::sync::Mutex<HashMap<String, super::mqtt::MqttPendingAcks>>,
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -66,6 +82,8 @@ impl FetchService {
             eth_source_config: eth_source_config.unwrap_or_default(),
             dataset_key_value_svc,
             run_info_dir,
+            #[cfg(feature = "ingest-mqtt")]
+            mqtt_pending_acks: std::sync::Mutex::default(),
         }
     }
 
@@ -75,6 +93,7 @@ impl FetchService {
         dataset_handle: &DatasetHandle,
         operation_id: &str,
         fetch_step: &FetchStep,
+        read_step: Option<&ReadStep>,
         prev_source_state: Option<&PollingSourceState>,
         target_path: &Path,
         system_time: &DateTime<Utc>,
@@ -110,17 +129,47 @@ impl FetchService {
                         )
                         .await
                     }
+                    "s3" | "s3+http" | "s3+https" => {
+                        Self::fetch_s3(
+                            url,
+                            furl.event_time.as_ref(),
+                            prev_source_state,
+                            target_path,
+                            system_time,
+                            &listener,
+                        )
+                        .await
+                    }
                     "ftp" | "ftps" => {
                         cfg_if::cfg_if! {
                             if #[cfg(feature = "ingest-ftp")] {
                                 Self::fetch_ftp(url, target_path, system_time, &listener).await
                             } else {
-                                unimplemented!("Kamu was compiled without FTP support")
+                                // Kamu was compiled without FTP support
+                                Err(UnsupportedSourceSchemeError::new(url.scheme()).into())
+                            }
+                        }
+                    }
+                    "sftp" => {
+                        cfg_if::cfg_if! {
+                            if #[cfg(feature = "ingest-sftp")] {
+                                self.fetch_sftp(
+                                    url,
+                                    furl.event_time.as_ref(),
+                                    prev_source_state,
+                                    target_path,
+                                    system_time,
+                                    dataset_env_vars,
+                                    &listener,
+                                )
+                                .await
+                            } else {
+                                // Kamu was compiled without SFTP support
+                                Err(UnsupportedSourceSchemeError::new(url.scheme()).into())
                             }
                         }
                     }
-                    // TODO: Replace with proper error type
-                    scheme => unimplemented!("Unsupported scheme: {}", scheme),
+                    scheme => Err(UnsupportedSourceSchemeError::new(scheme).into()),
                 }
             }
             FetchStep::Container(fetch) => {
@@ -134,6 +183,16 @@ impl FetchService {
                 )
                 .await
             }
+            FetchStep::FilesGlob(fglob) if Self::is_s3_url(&fglob.path) => {
+                Self::fetch_s3_files_glob(
+                    fglob,
+                    prev_source_state,
+                    target_path,
+                    system_time,
+                    &listener,
+                )
+                .await
+            }
             FetchStep::FilesGlob(fglob) => Self::fetch_files_glob(
                 fglob,
                 prev_source_state,
@@ -146,7 +205,10 @@ impl FetchService {
                     if #[cfg(feature = "ingest-mqtt")] {
                     self.fetch_mqtt(
                         dataset_handle,
+                        operation_id,
                         fetch,
+                        read_step,
+                        prev_source_state,
                         target_path,
                         dataset_env_vars,
                         &listener,
@@ -176,6 +238,29 @@ impl FetchService {
         }
     }
 
+    /// Acknowledges the data received by the fetch operation to the source.
+    /// Must be called only after the fetched data was committed, as sources
+    /// like MQTT brokers will not re-deliver the acknowledged messages.
+    #[allow(unused_variables)]
+    pub async fn acknowledge(&self, operation_id: &str) -> Result<(), PollingIngestError> {
+        cfg_if::cfg_if! {
+            if #[cfg(feature = "ingest-mqtt")] {
+                self.acknowledge_mqtt(operation_id).await
+            } else {
+                Ok(())
+            }
+        }
+    }
+
+    /// Releases the resources held by the fetch operation without
+    /// acknowledging the received data, so that it will be re-delivered by the
+    /// source on the next fetch
+    #[allow(unused_variables)]
+    pub fn release(&self, operation_id: &str) {
+        #[cfg(feature = "ingest-mqtt")]
+        self.release_mqtt(operation_id);
+    }
+
     pub(super) fn template_url(
         &self,
         url_tpl: &str,
@@ -259,6 +344,10 @@ pub struct FetchResultUpdated {
     pub source_event_time: Option<DateTime<Utc>>,
     pub has_more: bool,
     pub zero_copy_path: Option<PathBuf>,
+    /// SQL WHERE clause selecting the previously ingested records that the
+    /// source has invalidated, e.g. logs from blocks orphaned by a chain
+    /// re-organization
+    pub retraction_filter: Option<String>,
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -278,6 +367,9 @@ pub enum TotalBytes {
 pub trait FetchProgressListener: Send + Sync {
     fn on_progress(&self, _progress: &FetchProgress) {}
 
+    /// Called with logs of the fetch container that completed successfully
+    fn on_log_files(&self, _log_files: &[PathBuf]) {}
+
     fn get_pull_image_listener(self: Arc<Self>) -> Option<Arc<dyn PullImageListener>> {
         None
     }
@@ -11,8 +11,9 @@ use std::collections::HashMap;
 use std::path::Path;
 use std::sync::Arc;
 
+use alloy::primitives::B256;
 use futures::TryStreamExt;
-use internal_error::{ErrorIntoInternal, ResultIntoInternal};
+use internal_error::{ErrorIntoInternal, InternalError, ResultIntoInternal};
 use kamu_core::*;
 use kamu_datasets::DatasetEnvVar;
 use opendatafabric::*;
@@ -35,7 +36,10 @@ impl FetchService {
     // convert the WHERE clause into a filter, and then calls ETH RPC directly
     // to scan through block ranges.
     //
-    // TODO: Account for re-orgs
+    // Chain re-organizations are detected by comparing the hashes of the most
+    // recently scanned blocks stored in the source state with the canonical
+    // chain. Upon a re-org the scanning resumes from the last block that was not
+    // orphaned and logs that were ingested from orphaned blocks are retracted.
     pub(crate) async fn fetch_ethereum_logs(
         &self,
         fetch: &FetchStepEthereumLogs,
@@ -63,16 +67,9 @@ impl FetchService {
         };
 
         // Get last state
-        let resume_from_state = match prev_source_state {
-            None => None,
-            Some(PollingSourceState::ETag(s)) => {
-                let Some((num, _hash)) = s.split_once('@') else {
-                    panic!("Malformed ETag: {s}");
-                };
-                Some(StreamState {
-                    last_seen_block: num.parse().unwrap(),
-                })
-            }
+        let prev_blocks = match prev_source_state {
+            None => Vec::new(),
+            Some(PollingSourceState::ETag(s)) => ScannedBlock::parse_etag(s)?,
             _ => panic!("EthereumLogs should only use ETag state"),
         };
 
@@ -109,6 +106,54 @@ impl FetchService {
             .int_err())?;
         }
 
+        // Detect re-orgs by finding the newest previously scanned block that is
+        // still on the canonical chain
+        let mut orphaned_blocks = 0;
+        for block in &prev_blocks {
+            let Some(canonical_block) = rpc_client
+                .get_block(block.number.into(), BlockTransactionsKind::Hashes)
+                .await
+                .int_err()?
+            else {
+                tracing::warn!(
+                    last_seen_block = block.number,
+                    "Node does not have the last seen block yet - considering up-to-date",
+                );
+                return Ok(FetchResult::UpToDate);
+            };
+
+            if canonical_block.header.hash == Some(block.hash) {
+                break;
+            }
+            orphaned_blocks += 1;
+        }
+
+        if orphaned_blocks != 0 && orphaned_blocks == prev_blocks.len() {
+            Err(EthereumReorgTooDeepError {
+                block_number: prev_blocks.last().unwrap().number,
+                tracked_blocks: prev_blocks.len(),
+            }
+            .int_err())?;
+        }
+
+        let known_blocks = &prev_blocks[orphaned_blocks..];
+
+        let retraction_filter = if orphaned_blocks != 0 {
+            let fork_block = known_blocks[0].number;
+            tracing::warn!(
+                fork_block,
+                orphaned_blocks,
+                "Detected chain re-organization, retracting logs from orphaned blocks",
+            );
+            Some(format!("{ETH_LOGS_BLOCK_NUMBER_COLUMN} > {fork_block}"))
+        } else {
+            None
+        };
+
+        let resume_from_state = known_blocks.first().map(|b| StreamState {
+            last_seen_block: b.number,
+        });
+
         // Setup Datafusion context
         let mut cfg = SessionConfig::new()
             .with_target_partitions(1)
@@ -230,19 +275,40 @@ impl FetchService {
         }
 
         // Have we made any progress?
-        if resume_from_state == state {
+        if resume_from_state == state && retraction_filter.is_none() {
             return Ok(FetchResult::UpToDate);
         }
 
         let state = state.unwrap();
 
-        // Record scanning state
-        // TODO: Reorg tolerance
-        let last_seen_block = rpc_client
-            .get_block(state.last_seen_block.into(), BlockTransactionsKind::Hashes)
-            .await
-            .int_err()?
-            .unwrap();
+        // Record hashes of the blocks that are not considered final yet, reusing
+        // the ones that were not affected by a re-org
+        let confirmation_depth = self.eth_source_config.confirmation_depth.max(1);
+        let lowest_tracked_block = (state.last_seen_block + 1).saturating_sub(confirmation_depth);
+        let first_new_block = known_blocks
+            .first()
+            .map_or(lowest_tracked_block, |b| b.number + 1)
+            .max(lowest_tracked_block);
+
+        let mut scanned_blocks = Vec::new();
+        for number in (first_new_block..=state.last_seen_block).rev() {
+            let block = rpc_client
+                .get_block(number.into(), BlockTransactionsKind::Hashes)
+                .await
+                .int_err()?
+                .unwrap();
+
+            scanned_blocks.push(ScannedBlock {
+                number,
+                hash: block.header.hash.unwrap(),
+            });
+        }
+        scanned_blocks.extend(
+            known_blocks
+                .iter()
+                .filter(|b| b.number >= lowest_tracked_block)
+                .copied(),
+        );
 
         tracing::info!(
             blocks_scanned = state.last_seen_block - block_range_unprocessed.0 + 1,
@@ -255,8 +321,9 @@ impl FetchService {
         // Did we exhaust the source? (not accounting for new transactions)
         let has_more = state.last_seen_block < block_range_unprocessed.1;
 
-        // Write data, if any, to parquet file
-        if coder.len() > 0 {
+        // Write data, if any, to parquet file. Empty file is still written when
+        // retracting to carry the schema.
+        if coder.len() > 0 || retraction_filter.is_some() {
             let batch = coder.finish();
             {
                 let mut writer = datafusion::parquet::arrow::ArrowWriter::try_new(
@@ -271,20 +338,66 @@ impl FetchService {
         }
 
         Ok(FetchResult::Updated(FetchResultUpdated {
-            source_state: Some(PollingSourceState::ETag(format!(
-                "{}@{:x}",
-                last_seen_block.header.number.unwrap(),
-                last_seen_block.header.hash.unwrap(),
-            ))),
+            source_state: Some(ScannedBlock::to_etag(&scanned_blocks)),
             source_event_time: None,
             has_more,
             zero_copy_path: None,
+            retraction_filter,
         }))
     }
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
+/// Block stored in the source state to detect whether it was orphaned by a
+/// chain re-organization. The state is stored as an `ETag` listing the blocks
+/// from newest to oldest, e.g. `101@<hash>,100@<hash>`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct ScannedBlock {
+    number: u64,
+    hash: B256,
+}
+
+impl ScannedBlock {
+    fn parse_etag(etag: &str) -> Result<Vec<Self>, InternalError> {
+        etag.split(',')
+            .map(|block| {
+                let Some((number, hash)) = block.split_once('@') else {
+                    return InternalError::bail(format!("Malformed ETag: {etag}"));
+                };
+                Ok(Self {
+                    number: number.parse().int_err()?,
+                    hash: hash.parse().int_err()?,
+                })
+            })
+            .collect()
+    }
+
+    fn to_etag(blocks: &[Self]) -> PollingSourceState {
+        let etag = blocks
+            .iter()
+            .map(|b| format!("{}@{:x}", b.number, b.hash))
+            .collect::<Vec<_>>()
+            .join(",");
+
+        PollingSourceState::ETag(etag)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(thiserror::Error, Debug)]
+#[error(
+    "Chain re-organization is deeper than {tracked_blocks} blocks tracked in the source state, \
+     block {block_number} is no longer on the canonical chain"
+)]
+struct EthereumReorgTooDeepError {
+    pub block_number: u64,
+    pub tracked_blocks: usize,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
 #[derive(thiserror::Error, Debug)]
 #[error("Ethereum RPC error: {message}")]
 struct EthereumRpcError {
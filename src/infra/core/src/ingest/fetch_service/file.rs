@@ -100,6 +100,7 @@ impl FetchService {
             source_event_time,
             has_more: !matched_files.is_empty(),
             zero_copy_path: fetch_res.zero_copy_path,
+            retraction_filter: None,
         }))
     }
 
@@ -151,10 +152,11 @@ impl FetchService {
             source_event_time,
             has_more: false,
             zero_copy_path: Some(path.to_path_buf()),
+            retraction_filter: None,
         }))
     }
 
-    fn extract_event_time_from_path(
+    pub(super) fn extract_event_time_from_path(
         filename: &str,
         src: &EventTimeSourceFromPath,
     ) -> Result<DateTime<Utc>, PollingIngestError> {
@@ -102,6 +102,7 @@ impl FetchService {
             source_event_time: Some(system_time),
             has_more: false,
             zero_copy_path: None,
+            retraction_filter: None,
         }))
     }
 }
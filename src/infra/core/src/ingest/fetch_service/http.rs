@@ -60,7 +60,7 @@ impl FetchService {
             .collect();
 
         match prev_source_state {
-            None => (),
+            None | Some(PollingSourceState::MqttClientId(_)) => (),
             Some(PollingSourceState::ETag(etag)) => {
                 headers.insert(header::IF_NONE_MATCH, HeaderValue::try_from(etag).unwrap());
             }
@@ -147,6 +147,7 @@ impl FetchService {
             source_event_time,
             has_more: false,
             zero_copy_path: None,
+            retraction_filter: None,
         }))
     }
 
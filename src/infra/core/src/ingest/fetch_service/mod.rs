@@ -23,3 +23,6 @@ mod ftp;
 mod http;
 #[cfg(feature = "ingest-mqtt")]
 mod mqtt;
+mod s3;
+#[cfg(feature = "ingest-sftp")]
+mod sftp;
@@ -11,12 +11,75 @@ use std::collections::HashMap;
 use std::path::Path;
 use std::sync::Arc;
 
-use internal_error::ResultIntoInternal;
+use internal_error::{InternalError, ResultIntoInternal};
 use kamu_core::*;
 use kamu_datasets::DatasetEnvVar;
 use opendatafabric::*;
+use rumqttc::{AsyncClient, ConnectionError, Event, MqttOptions, Outgoing, Packet, Publish, QoS};
+use tokio::sync::mpsc;
 
 use super::*;
+use crate::PollingSourceState;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+type MqttEventResult = Result<Event, ConnectionError>;
+
+/// Connection to the MQTT broker that is kept open after the fetch until the
+/// received data is committed. Messages received with QoS 1 and 2 are
+/// acknowledged only after that, so that the broker re-delivers them into the
+/// persistent session if ingestion fails or the process is terminated.
+pub(super) struct MqttPendingAcks {
+    client: AsyncClient,
+    events: mpsc::UnboundedReceiver<MqttEventResult>,
+    /// Keeps polling the event loop to maintain the connection alive
+    event_loop_task: tokio::task::JoinHandle<()>,
+    unacked: Vec<Publish>,
+}
+
+impl MqttPendingAcks {
+    async fn acknowledge(mut self) -> Result<(), InternalError> {
+        for publish in &self.unacked {
+            self.client.ack(publish).await.int_err()?;
+        }
+
+        // QoS 1 messages are confirmed with PUBACK and QoS 2 messages complete
+        // their handshake with PUBCOMP, which event loop sends upon PUBREL.
+        // Messages received after the fetch has finished are ignored and will
+        // be re-delivered by the broker.
+        let mut remaining = self.unacked.len();
+        while remaining != 0 {
+            match self.next_event().await? {
+                Event::Outgoing(Outgoing::PubAck(_) | Outgoing::PubComp(_)) => remaining -= 1,
+                event => tracing::debug!(?event, "Received"),
+            }
+        }
+
+        tracing::debug!(
+            acknowledged = self.unacked.len(),
+            "Disconnecting from the MQTT broker"
+        );
+
+        self.client.disconnect().await.int_err()?;
+        (&mut self.event_loop_task).await.int_err()?;
+        Ok(())
+    }
+
+    async fn next_event(&mut self) -> Result<Event, InternalError> {
+        match self.events.recv().await {
+            Some(res) => res.int_err(),
+            None => InternalError::bail("MQTT event loop has terminated unexpectedly"),
+        }
+    }
+}
+
+impl Drop for MqttPendingAcks {
+    fn drop(&mut self) {
+        // Dropping the connection without a graceful disconnect leaves the
+        // unacknowledged messages in the broker session
+        self.event_loop_task.abort();
+    }
+}
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
@@ -24,21 +87,32 @@ impl FetchService {
     pub(crate) async fn fetch_mqtt(
         &self,
         dataset_handle: &DatasetHandle,
+        operation_id: &str,
         fetch: &FetchStepMqtt,
+        read_step: Option<&ReadStep>,
+        prev_source_state: Option<&PollingSourceState>,
         target_path: &Path,
         dataset_env_vars: &HashMap<String, DatasetEnvVar>,
         listener: &Arc<dyn FetchProgressListener>,
     ) -> Result<FetchResult, PollingIngestError> {
-        use std::io::Write as _;
-
-        use rumqttc::{AsyncClient, Event, MqttOptions, Packet, QoS};
+        // Fail early if the messages can't be combined for the read step
+        let framing = MqttPayloadFraming::for_read_step(read_step)?;
 
-        // TODO: Reconsider assignment of client identity on which QoS session data
-        // rentention is based
-        let client_id = format!("kamu-ingest-{}", dataset_handle.id.as_multibase());
+        // Broker retains the session data (subscriptions and unacknowledged
+        // messages) by the client identity, so once assigned it is persisted
+        // in the source state to resume the same session after restarts
+        let client_id = match prev_source_state {
+            Some(PollingSourceState::MqttClientId(client_id)) => client_id.clone(),
+            _ => format!("kamu-ingest-{}", dataset_handle.id.as_multibase()),
+        };
 
-        let mut opts = MqttOptions::new(client_id, &fetch.host, u16::try_from(fetch.port).unwrap());
+        let mut opts = MqttOptions::new(
+            client_id.clone(),
+            &fetch.host,
+            u16::try_from(fetch.port).unwrap(),
+        );
         opts.set_clean_session(false);
+        opts.set_manual_acks(true);
 
         // TODO: Reconsider password propagation
         if let (Some(username), Some(password)) = (&fetch.username, &fetch.password) {
@@ -46,7 +120,10 @@ impl FetchService {
             opts.set_credentials(username, password);
         }
 
-        tracing::debug!("Connecting to the MQTT broker and subscribing to the topic");
+        tracing::debug!(
+            client_id,
+            "Connecting to the MQTT broker and subscribing to the topic"
+        );
 
         let (client, mut event_loop) = AsyncClient::new(opts, 1000);
         client
@@ -63,9 +140,27 @@ impl FetchService {
             .await
             .int_err()?;
 
+        let (events_tx, events) = mpsc::unbounded_channel();
+        let event_loop_task = tokio::spawn(async move {
+            loop {
+                let event = event_loop.poll().await;
+                let done = matches!(event, Err(_) | Ok(Event::Outgoing(Outgoing::Disconnect)));
+                if events_tx.send(event).is_err() || done {
+                    break;
+                }
+            }
+        });
+
+        let mut pending = MqttPendingAcks {
+            client,
+            events,
+            event_loop_task,
+            unacked: Vec::new(),
+        };
+
         let mut fetched_bytes = 0;
         let mut fetched_records = 0;
-        let mut file = std::fs::File::create(target_path).int_err()?;
+        let mut writer = MqttPayloadWriter::new(framing, target_path)?;
 
         let max_records = self.source_config.target_records_per_slice;
         let poll_timeout =
@@ -73,55 +168,238 @@ impl FetchService {
 
         loop {
             // Limit number of records read if they keep flowing faster that we timeout
-            // TODO: Manual ACKs to prevent lost records
             if fetched_records >= max_records {
                 break;
             }
 
-            if let Ok(poll) = tokio::time::timeout(poll_timeout, event_loop.poll()).await {
-                match poll.int_err()? {
-                    Event::Incoming(Packet::Publish(publish)) => {
-                        // TODO: Assuming that payload is JSON and formatting it as line-delimited
-                        if fetched_bytes != 0 {
-                            file.write_all(b" ").int_err()?;
-                        }
-                        let json = std::str::from_utf8(&publish.payload).int_err()?.trim();
-                        file.write_all(json.as_bytes()).int_err()?;
+            let Ok(event) = tokio::time::timeout(poll_timeout, pending.next_event()).await else {
+                break;
+            };
 
-                        fetched_bytes += publish.payload.len() as u64 + 1;
-                        fetched_records += 1;
+            match event? {
+                Event::Incoming(Packet::Publish(publish)) => {
+                    fetched_bytes += writer.write(&publish.payload)?;
+                    fetched_records += 1;
 
-                        listener.on_progress(&FetchProgress {
-                            fetched_bytes,
-                            total_bytes: TotalBytes::Unknown,
-                        });
+                    listener.on_progress(&FetchProgress {
+                        fetched_bytes,
+                        total_bytes: TotalBytes::Unknown,
+                    });
+
+                    if publish.qos != QoS::AtMostOnce {
+                        pending.unacked.push(publish);
                     }
-                    event => tracing::debug!(?event, "Received"),
                 }
-            } else {
-                break;
-            };
+                event => tracing::debug!(?event, "Received"),
+            }
         }
 
         tracing::debug!(
             fetched_bytes,
             fetched_records,
-            "Disconnecting from the MQTT broker"
+            unacked = pending.unacked.len(),
+            "Finished fetching from the MQTT broker"
         );
 
-        file.flush().int_err()?;
-
-        if fetched_bytes == 0 {
-            Ok(FetchResult::UpToDate)
-        } else {
-            // TODO: Store last packet ID / client ID in the source state?
-            Ok(FetchResult::Updated(FetchResultUpdated {
-                source_state: None,
-                source_event_time: None,
-                has_more: false,
-                zero_copy_path: None,
-            }))
+        writer.finish()?;
+
+        if fetched_records == 0 {
+            return Ok(FetchResult::UpToDate);
+        }
+
+        self.mqtt_pending_acks
+            .lock()
+            .unwrap()
+            .insert(operation_id.to_string(), pending);
+
+        Ok(FetchResult::Updated(FetchResultUpdated {
+            source_state: Some(PollingSourceState::MqttClientId(client_id)),
+            source_event_time: None,
+            has_more: false,
+            zero_copy_path: None,
+            retraction_filter: None,
+        }))
+    }
+
+    pub(super) async fn acknowledge_mqtt(
+        &self,
+        operation_id: &str,
+    ) -> Result<(), PollingIngestError> {
+        let pending = self.mqtt_pending_acks.lock().unwrap().remove(operation_id);
+
+        if let Some(pending) = pending {
+            pending.acknowledge().await?;
+        }
+
+        Ok(())
+    }
+
+    pub(super) fn release_mqtt(&self, operation_id: &str) {
+        if let Some(pending) = self.mqtt_pending_acks.lock().unwrap().remove(operation_id) {
+            tracing::debug!(
+                unacked = pending.unacked.len(),
+                "Dropping MQTT connection without acknowledging the messages"
+            );
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Defines how payloads of multiple messages are combined into a single file
+/// that the read step of the source can read
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum MqttPayloadFraming {
+    /// Payloads are written as-is, each terminated by a newline
+    Lines,
+    /// Every JSON value of a payload is written compactly on its own line, so
+    /// that pretty-printed payloads remain valid NDJSON
+    JsonLines,
+    /// Records of all payloads are collected into a single JSON array nested
+    /// under the sub-path of the read step. Payloads that contain the sub-path
+    /// or an array contribute all of its elements.
+    JsonArray { sub_path: Vec<String> },
+}
+
+impl MqttPayloadFraming {
+    fn for_read_step(read_step: Option<&ReadStep>) -> Result<Self, ReadError> {
+        match read_step {
+            None | Some(ReadStep::Csv(_)) => Ok(Self::Lines),
+            Some(ReadStep::NdJson(_) | ReadStep::NdGeoJson(_)) => Ok(Self::JsonLines),
+            Some(ReadStep::Json(conf)) => Ok(Self::JsonArray {
+                sub_path: conf
+                    .sub_path
+                    .as_deref()
+                    .map(|p| p.split('.').map(ToString::to_string).collect())
+                    .unwrap_or_default(),
+            }),
+            Some(ReadStep::GeoJson(_)) => Err(Self::unsupported("GeoJson")),
+            Some(ReadStep::EsriShapefile(_)) => Err(Self::unsupported("EsriShapefile")),
+            Some(ReadStep::Parquet(_)) => Err(Self::unsupported("Parquet")),
+            Some(ReadStep::Avro(_)) => Err(Self::unsupported("Avro")),
+            Some(ReadStep::Orc(_)) => Err(Self::unsupported("Orc")),
+            Some(ReadStep::Excel(_)) => Err(Self::unsupported("Excel")),
+        }
+    }
+
+    fn unsupported(read_step: &str) -> ReadError {
+        UnsupportedError::new(format!(
+            "MQTT source can't combine messages for the {read_step} read step, use NdJson, \
+             NdGeoJson, Json or Csv instead"
+        ))
+        .into()
+    }
+}
+
+struct MqttPayloadWriter {
+    framing: MqttPayloadFraming,
+    file: std::io::BufWriter<std::fs::File>,
+    num_written: usize,
+}
+
+impl MqttPayloadWriter {
+    fn new(framing: MqttPayloadFraming, path: &Path) -> Result<Self, InternalError> {
+        Ok(Self {
+            framing,
+            file: std::io::BufWriter::new(std::fs::File::create(path).int_err()?),
+            num_written: 0,
+        })
+    }
+
+    /// Writes the payload of one message returning the number of bytes written
+    fn write(&mut self, payload: &[u8]) -> Result<u64, PollingIngestError> {
+        use std::io::Write as _;
+
+        let mut buf = Vec::with_capacity(payload.len() + 1);
+
+        match &self.framing {
+            MqttPayloadFraming::Lines => {
+                buf.extend_from_slice(payload);
+                if !payload.ends_with(b"\n") {
+                    buf.push(b'\n');
+                }
+            }
+            MqttPayloadFraming::JsonLines => {
+                for value in Self::parse_json(payload)? {
+                    serde_json::to_writer(&mut buf, &value).int_err()?;
+                    buf.push(b'\n');
+                }
+            }
+            MqttPayloadFraming::JsonArray { sub_path } => {
+                for value in Self::parse_json(payload)? {
+                    let value = sub_path
+                        .iter()
+                        .try_fold(value, |mut v, key| {
+                            v.get_mut(key).map(serde_json::Value::take)
+                        })
+                        .ok_or_else(|| {
+                            BadInputError::new(format!(
+                                "Sub-path not found in the message: {}",
+                                sub_path.join(".")
+                            ))
+                        })
+                        .map_err(ReadError::from)?;
+
+                    let records = match value {
+                        serde_json::Value::Array(records) => records,
+                        record => vec![record],
+                    };
+
+                    for record in records {
+                        if self.num_written == 0 && buf.is_empty() {
+                            buf.extend(Self::json_array_prefix(sub_path).bytes());
+                        } else {
+                            buf.push(b',');
+                        }
+                        serde_json::to_writer(&mut buf, &record).int_err()?;
+                    }
+                }
+            }
+        }
+
+        self.file.write_all(&buf).int_err()?;
+
+        if !buf.is_empty() {
+            self.num_written += 1;
+        }
+
+        Ok(buf.len() as u64)
+    }
+
+    fn finish(mut self) -> Result<(), InternalError> {
+        use std::io::Write as _;
+
+        if let MqttPayloadFraming::JsonArray { sub_path } = &self.framing {
+            if self.num_written != 0 {
+                let suffix = format!("]{}", "}".repeat(sub_path.len()));
+                self.file.write_all(suffix.as_bytes()).int_err()?;
+            }
+        }
+
+        // Important: Ensures file is closed immediately when dropped
+        self.file.flush().int_err()?;
+        Ok(())
+    }
+
+    /// A payload may contain several whitespace-separated JSON values
+    fn parse_json(payload: &[u8]) -> Result<Vec<serde_json::Value>, ReadError> {
+        serde_json::Deserializer::from_slice(payload)
+            .into_iter::<serde_json::Value>()
+            .collect::<Result<_, _>>()
+            .map_err(|e| BadInputError::new(format!("Message is not valid JSON: {e}")).into())
+    }
+
+    /// Opens the objects along the sub-path and the array of records, e.g.
+    /// `{"a":{"b":[` for the `a.b` sub-path
+    fn json_array_prefix(sub_path: &[String]) -> String {
+        let mut prefix = String::new();
+        for key in sub_path {
+            prefix.push('{');
+            prefix.push_str(&serde_json::Value::String(key.clone()).to_string());
+            prefix.push(':');
         }
+        prefix.push('[');
+        prefix
     }
 }
 
@@ -0,0 +1,252 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::path::Path;
+use std::sync::Arc;
+
+use aws_sdk_s3::operation::get_object::GetObjectError;
+use aws_sdk_s3::operation::head_object::HeadObjectError;
+use chrono::{DateTime, Utc};
+use internal_error::ResultIntoInternal;
+use kamu_core::*;
+use opendatafabric::*;
+use url::Url;
+
+use super::*;
+use crate::utils::s3_context::S3Context;
+use crate::PollingSourceState;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl FetchService {
+    pub(super) fn is_s3_url(path: &str) -> bool {
+        ["s3://", "s3+http://", "s3+https://"]
+            .iter()
+            .any(|scheme| path.starts_with(scheme))
+    }
+
+    // TODO: PERF: S3 client is re-created on every fetch
+    pub(super) async fn fetch_s3(
+        url: Url,
+        event_time_source: Option<&EventTimeSource>,
+        prev_source_state: Option<&PollingSourceState>,
+        target_path: &Path,
+        system_time: &DateTime<Utc>,
+        listener: &Arc<dyn FetchProgressListener>,
+    ) -> Result<FetchResult, PollingIngestError> {
+        let (endpoint, bucket, key) = S3Context::split_url(&url);
+        let s3_context = S3Context::from_items(endpoint, bucket, String::new()).await;
+
+        let head = match s3_context.head_object(key.clone()).await {
+            Ok(head) => head,
+            Err(err) => {
+                return Err(match err.into_service_error() {
+                    HeadObjectError::NotFound(e) => {
+                        PollingIngestError::not_found(url.as_str(), Some(e.into()))
+                    }
+                    e => PollingIngestError::unreachable(url.as_str(), Some(e.into())),
+                })
+            }
+        };
+
+        let last_modified_time = head
+            .last_modified()
+            .and_then(|t| DateTime::<Utc>::from_timestamp(t.secs(), t.subsec_nanos()));
+
+        let source_state = if let Some(etag) = head.e_tag() {
+            Some(PollingSourceState::ETag(etag.to_string()))
+        } else {
+            last_modified_time.map(PollingSourceState::LastModified)
+        };
+
+        if source_state.is_some() && source_state.as_ref() == prev_source_state {
+            return Ok(FetchResult::UpToDate);
+        }
+
+        let source_event_time = match event_time_source {
+            None | Some(EventTimeSource::FromMetadata(_)) => last_modified_time,
+            Some(EventTimeSource::FromSystemTime(_)) => Some(*system_time),
+            Some(EventTimeSource::FromPath(_)) => {
+                return Err(EventTimeSourceError::incompatible(
+                    "Url source does not support fromPath event time source",
+                )
+                .into());
+            }
+        };
+
+        Self::download_s3_object(&s3_context, key, url.as_str(), target_path, listener).await?;
+
+        Ok(FetchResult::Updated(FetchResultUpdated {
+            source_state,
+            source_event_time,
+            has_more: false,
+            zero_copy_path: None,
+            retraction_filter: None,
+        }))
+    }
+
+    pub(super) async fn fetch_s3_files_glob(
+        fglob: &FetchStepFilesGlob,
+        prev_source_state: Option<&PollingSourceState>,
+        target_path: &Path,
+        system_time: &DateTime<Utc>,
+        listener: &Arc<dyn FetchProgressListener>,
+    ) -> Result<FetchResult, PollingIngestError> {
+        match &fglob.order {
+            None | Some(SourceOrdering::ByName) => (),
+            Some(ord) => panic!("Files glob source can only be ordered by name, found: {ord:?}"),
+        }
+
+        // Progress is tracked by the key relative to the listing prefix, as the
+        // pattern may match objects with the same name under different prefixes
+        let last_relative_key = match prev_source_state {
+            Some(PollingSourceState::ETag(etag)) => Some(etag),
+            _ => None,
+        };
+
+        // Objects are listed under the longest prefix that does not contain
+        // any wildcards, and the rest of the key is matched against the pattern
+        let (prefix_url, pattern) = Self::split_glob_prefix(&fglob.path);
+        let prefix_url = Url::parse(prefix_url).int_err()?;
+        let pattern = glob::Pattern::new(pattern).int_err()?;
+        let match_options = glob::MatchOptions {
+            require_literal_separator: true,
+            ..Default::default()
+        };
+
+        let (endpoint, bucket, key_prefix) = S3Context::split_url(&prefix_url);
+        let s3_context = S3Context::from_items(endpoint, bucket, key_prefix).await;
+
+        let objects = s3_context
+            .list_objects("")
+            .await
+            .map_err(|e| PollingIngestError::unreachable(prefix_url.as_str(), Some(e.into())))?;
+
+        let mut matched_keys: Vec<String> = objects
+            .into_iter()
+            .filter_map(|obj| obj.key)
+            .filter_map(|key| key.strip_prefix(&s3_context.key_prefix).map(str::to_owned))
+            .filter(|relative_key| pattern.matches_with(relative_key, match_options))
+            .filter(|relative_key| {
+                if let Some(lrk) = last_relative_key {
+                    relative_key > lrk
+                } else {
+                    true
+                }
+            })
+            .collect();
+
+        matched_keys.sort_by(|a, b| b.cmp(a));
+
+        tracing::info!(
+            pattern = fglob.path.as_str(),
+            ?last_relative_key,
+            matches = ?matched_keys,
+            "Matched the glob pattern"
+        );
+
+        if matched_keys.is_empty() {
+            return if prev_source_state.is_some() {
+                Ok(FetchResult::UpToDate)
+            } else {
+                Err(PollingIngestError::not_found(&fglob.path, None))
+            };
+        }
+
+        let first_relative_key = matched_keys.pop().unwrap();
+
+        let source_event_time = match &fglob.event_time {
+            None | Some(EventTimeSource::FromSystemTime(_)) => Some(*system_time),
+            Some(EventTimeSource::FromPath(src)) => Some(Self::extract_event_time_from_path(
+                &first_relative_key,
+                src,
+            )?),
+            Some(EventTimeSource::FromMetadata(_)) => {
+                return Err(EventTimeSourceError::incompatible(
+                    "Files glob source does not support extracting event time fromMetadata, you \
+                     should use fromPath instead",
+                )
+                .into());
+            }
+        };
+
+        let first_url = format!("{prefix_url}{first_relative_key}");
+        Self::download_s3_object(
+            &s3_context,
+            s3_context.get_key(&first_relative_key),
+            &first_url,
+            target_path,
+            listener,
+        )
+        .await?;
+
+        Ok(FetchResult::Updated(FetchResultUpdated {
+            source_state: Some(PollingSourceState::ETag(first_relative_key)),
+            source_event_time,
+            has_more: !matched_keys.is_empty(),
+            zero_copy_path: None,
+            retraction_filter: None,
+        }))
+    }
+
+    async fn download_s3_object(
+        s3_context: &S3Context,
+        key: String,
+        url: &str,
+        target_path: &Path,
+        listener: &Arc<dyn FetchProgressListener>,
+    ) -> Result<(), PollingIngestError> {
+        use tokio::io::AsyncWriteExt;
+
+        let mut response = match s3_context.get_object(key).await {
+            Ok(response) => response,
+            Err(err) => {
+                return Err(match err.into_service_error() {
+                    GetObjectError::NoSuchKey(e) => {
+                        PollingIngestError::not_found(url, Some(e.into()))
+                    }
+                    e => PollingIngestError::unreachable(url, Some(e.into())),
+                })
+            }
+        };
+
+        let total_bytes =
+            u64::try_from(response.content_length).map_or(TotalBytes::Unknown, TotalBytes::Exact);
+        let mut fetched_bytes = 0;
+        let mut file = tokio::fs::File::create(target_path).await.int_err()?;
+
+        while let Some(chunk) = response.body.next().await {
+            let chunk = chunk.int_err()?;
+            file.write_all(&chunk).await.int_err()?;
+
+            fetched_bytes += chunk.len() as u64;
+
+            listener.on_progress(&FetchProgress {
+                fetched_bytes,
+                total_bytes,
+            });
+        }
+
+        // Important: Ensures file is closed immediately when dropped
+        file.flush().await.int_err()?;
+
+        Ok(())
+    }
+
+    /// Splits the glob at the last path separator preceding the first
+    /// wildcard, e.g. `s3://bucket/data/*.csv` into `s3://bucket/data/` and
+    /// `*.csv`
+    fn split_glob_prefix(path: &str) -> (&str, &str) {
+        let wildcard_pos = path.find(['*', '?', '[']).unwrap_or(path.len());
+        let prefix_len = path[..wildcard_pos].rfind('/').map_or(0, |pos| pos + 1);
+        path.split_at(prefix_len)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,233 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::Arc;
+
+use chrono::{DateTime, TimeZone as _, Utc};
+use internal_error::{ErrorIntoInternal, ResultIntoInternal};
+use kamu_core::*;
+use kamu_datasets::{DatasetEnvVar, FindDatasetEnvVarError};
+use opendatafabric::*;
+use url::Url;
+
+use super::*;
+use crate::PollingSourceState;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+const SFTP_DEFAULT_PORT: u16 = 22;
+
+// See LIBSSH2_FX_NO_SUCH_FILE
+const SFTP_NO_SUCH_FILE: i32 = 2;
+
+enum SftpAuth {
+    Password(String),
+    PrivateKey {
+        private_key: String,
+        passphrase: Option<String>,
+    },
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl FetchService {
+    pub(super) async fn fetch_sftp(
+        &self,
+        url: Url,
+        event_time_source: Option<&EventTimeSource>,
+        prev_source_state: Option<&PollingSourceState>,
+        target_path: &Path,
+        system_time: &DateTime<Utc>,
+        dataset_env_vars: &HashMap<String, DatasetEnvVar>,
+        listener: &Arc<dyn FetchProgressListener>,
+    ) -> Result<FetchResult, PollingIngestError> {
+        let auth = self.sftp_auth(&url, dataset_env_vars)?;
+
+        let event_time_source = event_time_source.cloned();
+        let prev_source_state = prev_source_state.cloned();
+        let target_path = target_path.to_owned();
+        let system_time = *system_time;
+        let listener = listener.clone();
+
+        tokio::task::spawn_blocking(move || {
+            Self::fetch_sftp_impl(
+                &url,
+                auth,
+                event_time_source.as_ref(),
+                prev_source_state.as_ref(),
+                &target_path,
+                system_time,
+                listener.as_ref(),
+            )
+        })
+        .await
+        .int_err()?
+    }
+
+    /// Password specified in the URL takes precedence, otherwise the private
+    /// key is taken from the dataset environment variables
+    fn sftp_auth(
+        &self,
+        url: &Url,
+        dataset_env_vars: &HashMap<String, DatasetEnvVar>,
+    ) -> Result<SftpAuth, PollingIngestError> {
+        if let Some(password) = url.password() {
+            return Ok(SftpAuth::Password(password.to_string()));
+        }
+
+        let private_key = self
+            .dataset_key_value_svc
+            .find_dataset_env_var_value_by_key(SFTP_PRIVATE_KEY, dataset_env_vars)?
+            .into_exposed_value();
+
+        let passphrase = match self
+            .dataset_key_value_svc
+            .find_dataset_env_var_value_by_key(SFTP_PRIVATE_KEY_PASSPHRASE, dataset_env_vars)
+        {
+            Ok(value) => Some(value.into_exposed_value()),
+            Err(FindDatasetEnvVarError::NotFound(_)) => None,
+            Err(err) => return Err(err.into()),
+        };
+
+        Ok(SftpAuth::PrivateKey {
+            private_key,
+            passphrase,
+        })
+    }
+
+    // TODO: convert to non-blocking
+    fn fetch_sftp_impl(
+        url: &Url,
+        auth: SftpAuth,
+        event_time_source: Option<&EventTimeSource>,
+        prev_source_state: Option<&PollingSourceState>,
+        target_path: &Path,
+        system_time: DateTime<Utc>,
+        listener: &dyn FetchProgressListener,
+    ) -> Result<FetchResult, PollingIngestError> {
+        use std::io::prelude::*;
+
+        // Make sure credentials never end up in errors and logs
+        let mut display_url = url.clone();
+        display_url.set_password(None).unwrap();
+        let display_url = display_url.as_str();
+
+        let Some(host) = url.host_str() else {
+            return Err(format!("SFTP url does not specify a host: {display_url}")
+                .int_err()
+                .into());
+        };
+        if url.username().is_empty() {
+            return Err(
+                format!("SFTP url does not specify a user name: {display_url}")
+                    .int_err()
+                    .into(),
+            );
+        }
+
+        let tcp = std::net::TcpStream::connect((host, url.port().unwrap_or(SFTP_DEFAULT_PORT)))
+            .map_err(|e| PollingIngestError::unreachable(display_url, Some(e.into())))?;
+
+        let mut session = ssh2::Session::new().int_err()?;
+        session.set_tcp_stream(tcp);
+        session.set_timeout(30_000);
+        session
+            .handshake()
+            .map_err(|e| PollingIngestError::unreachable(display_url, Some(e.into())))?;
+
+        match &auth {
+            SftpAuth::Password(password) => session.userauth_password(url.username(), password),
+            SftpAuth::PrivateKey {
+                private_key,
+                passphrase,
+            } => session.userauth_pubkey_memory(
+                url.username(),
+                None,
+                private_key,
+                passphrase.as_deref(),
+            ),
+        }
+        .int_err()?;
+
+        let sftp = session.sftp().int_err()?;
+        let remote_path = Path::new(url.path());
+
+        let stat = sftp.stat(remote_path).map_err(|e| match e.code() {
+            ssh2::ErrorCode::SFTP(SFTP_NO_SUCH_FILE) => {
+                PollingIngestError::not_found(display_url, Some(e.into()))
+            }
+            _ => e.int_err().into(),
+        })?;
+
+        let mod_time = stat
+            .mtime
+            .and_then(|t| Utc.timestamp_opt(i64::try_from(t).ok()?, 0).single());
+
+        if let (Some(PollingSourceState::LastModified(last_modified)), Some(mod_time)) =
+            (prev_source_state, mod_time)
+        {
+            if *last_modified == mod_time {
+                return Ok(FetchResult::UpToDate);
+            }
+        }
+
+        let source_event_time = match event_time_source {
+            None | Some(EventTimeSource::FromMetadata(_)) => mod_time,
+            Some(EventTimeSource::FromSystemTime(_)) => Some(system_time),
+            Some(EventTimeSource::FromPath(_)) => {
+                return Err(EventTimeSourceError::incompatible(
+                    "Url source does not support fromPath event time source",
+                )
+                .into());
+            }
+        };
+
+        let total_bytes = stat.size.map_or(TotalBytes::Unknown, TotalBytes::Exact);
+        let target_path_tmp = target_path.with_extension("tmp");
+
+        {
+            let mut remote_file = sftp.open(remote_path).int_err()?;
+            let mut target_file = std::fs::File::create(&target_path_tmp).int_err()?;
+
+            let mut buf = vec![0; 64 * 1024];
+            let mut fetched_bytes = 0;
+
+            loop {
+                let read = remote_file.read(&mut buf).int_err()?;
+                if read == 0 {
+                    break;
+                }
+
+                target_file.write_all(&buf[..read]).int_err()?;
+                fetched_bytes += read as u64;
+
+                listener.on_progress(&FetchProgress {
+                    fetched_bytes,
+                    total_bytes,
+                });
+            }
+
+            target_file.flush().int_err()?;
+        }
+
+        std::fs::rename(target_path_tmp, target_path).int_err()?;
+
+        Ok(FetchResult::Updated(FetchResultUpdated {
+            source_state: mod_time.map(PollingSourceState::LastModified),
+            source_event_time,
+            has_more: false,
+            zero_copy_path: None,
+            retraction_filter: None,
+        }))
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
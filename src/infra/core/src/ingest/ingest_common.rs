@@ -28,7 +28,8 @@ pub(crate) async fn preprocess(
     transform: &Transform,
     input_data: DataFrame,
     maybe_listener: Option<Arc<dyn EngineProvisioningListener>>,
-) -> Result<Option<DataFrame>, EngineError> {
+    cancellation_token: &CancellationToken,
+) -> Result<RawQueryResponseExt, EngineError> {
     let engine = match transform.engine().to_lowercase().as_str() {
         "datafusion" => Arc::new(EngineDatafusionInproc::new()),
         engine_id => engine_provisioner
@@ -37,16 +38,17 @@ pub(crate) async fn preprocess(
             .int_err()?,
     };
 
-    let response = engine
-        .execute_raw_query(RawQueryRequestExt {
-            operation_id: operation_id.to_string(),
-            ctx: ctx.clone(),
-            input_data,
-            transform: transform.clone(),
-        })
-        .await?;
-
-    Ok(response.output_data)
+    engine
+        .execute_raw_query(
+            RawQueryRequestExt {
+                operation_id: operation_id.to_string(),
+                ctx: ctx.clone(),
+                input_data,
+                transform: transform.clone(),
+            },
+            cancellation_token,
+        )
+        .await
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
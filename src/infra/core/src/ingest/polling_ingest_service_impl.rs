@@ -12,6 +12,7 @@ use std::sync::Arc;
 
 use chrono::{DateTime, Utc};
 use datafusion::arrow::array::RecordBatch;
+use datafusion::arrow::datatypes::SchemaRef;
 use datafusion::prelude::{DataFrame, SessionContext};
 use internal_error::{InternalError, ResultIntoInternal};
 use kamu_core::ingest::*;
@@ -23,6 +24,7 @@ use random_names::get_random_name;
 use time_source::SystemTimeSource;
 
 use super::*;
+use crate::utils::operation_dir::OperationDirGuard;
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
@@ -111,19 +113,29 @@ impl PollingIngestServiceImpl {
             .int_err()?
             .build();
 
-        let Some(MetadataEvent::SetPollingSource(polling_source)) =
-            data_writer.source_event().cloned()
-        else {
-            tracing::warn!("Dataset does not define a polling source - considering up-to-date",);
+        let polling_source = match data_writer.source_event() {
+            Some(MetadataEvent::SetPollingSource(polling_source)) => polling_source.clone(),
+            Some(MetadataEvent::DisablePollingSource(_)) => {
+                tracing::warn!("Polling source of the dataset is disabled");
 
-            let result = PollingIngestResult::UpToDate {
-                no_source_defined: true,
-                uncacheable: false,
-            };
+                let err = PollingIngestError::SourceDisabled(PollingSourceDisabledError {});
+
+                args.listener.begin();
+                args.listener.error(&err);
+                return Err(err);
+            }
+            _ => {
+                tracing::warn!("Dataset does not define a polling source - considering up-to-date",);
+
+                let result = PollingIngestResult::UpToDate {
+                    no_source_defined: true,
+                    uncacheable: false,
+                };
 
-            args.listener.begin();
-            args.listener.success(&result);
-            return Ok(result);
+                args.listener.begin();
+                args.listener.success(&result);
+                return Ok(result);
+            }
         };
 
         let mut iteration = 0;
@@ -134,6 +146,7 @@ impl PollingIngestServiceImpl {
 
             let operation_dir = self.run_info_dir.join(format!("ingest-{operation_id}"));
             std::fs::create_dir_all(&operation_dir).int_err()?;
+            let operation_dir_guard = OperationDirGuard::new(operation_dir.clone());
 
             let new_ctx = ingest_common::new_session_context(self.object_store_registry.clone());
             data_writer.set_session_context(new_ctx.clone());
@@ -152,8 +165,11 @@ impl PollingIngestServiceImpl {
                 data_writer: &mut data_writer,
             };
 
-            match self.ingest_iteration(iteration_args).await {
+            let iteration_res = self.ingest_iteration(iteration_args).await;
+
+            match iteration_res {
                 Ok(res) => {
+                    operation_dir_guard.finish();
                     combined_result = Some(Self::merge_results(combined_result, res));
 
                     let has_more = match combined_result {
@@ -192,7 +208,14 @@ impl PollingIngestServiceImpl {
         let listener = args.listener.clone();
         listener.begin();
 
-        match self.ingest_iteration_inner(args).await {
+        let operation_id = args.operation_id.clone();
+        let res = self.ingest_iteration_inner(args).await;
+
+        // Data that was fetched but not acknowledged due to an error will be
+        // re-delivered by the source
+        self.fetch_service.release(&operation_id);
+
+        match res {
             Ok(res) => {
                 tracing::info!(result = ?res, "Ingest iteration successful");
                 listener.success(&res);
@@ -210,6 +233,8 @@ impl PollingIngestServiceImpl {
         &self,
         args: IngestIterationArgs<'_>,
     ) -> Result<PollingIngestResult, PollingIngestError> {
+        args.options.cancellation_token.check_cancelled()?;
+
         args.listener
             .on_stage_progress(PollingIngestStage::CheckCache, 0, TotalSteps::Exact(1));
 
@@ -235,6 +260,9 @@ impl PollingIngestServiceImpl {
             }
         };
 
+        // Fetched data is kept in the cache and will be reused by the next attempt
+        args.options.cancellation_token.check_cancelled()?;
+
         args.listener
             .on_stage_progress(PollingIngestStage::Prepare, 0, TotalSteps::Exact(1));
 
@@ -255,15 +283,19 @@ impl PollingIngestServiceImpl {
                     TotalSteps::Exact(1),
                 );
 
-                ingest_common::preprocess(
+                let response = ingest_common::preprocess(
                     &args.operation_id,
                     self.engine_provisioner.as_ref(),
                     &args.ctx,
                     transform,
                     df,
                     args.listener.clone().get_engine_provisioning_listener(),
+                    &args.options.cancellation_token,
                 )
-                .await?
+                .await?;
+
+                args.listener.on_log_files(&response.log_files);
+                response.output_data
             } else {
                 Some(
                     ingest_common::preprocess_default(
@@ -280,6 +312,12 @@ impl PollingIngestServiceImpl {
             None
         };
 
+        if let FetchStep::EthereumLogs(_) = &args.polling_source.fetch
+            && let Some(df) = &df
+        {
+            Self::validate_ethereum_logs_output(df)?;
+        }
+
         let new_source_state = savepoint.source_state.map(|ss| ss.to_source_state());
 
         let out_dir = args.operation_dir.join("out");
@@ -295,6 +333,7 @@ impl PollingIngestServiceImpl {
                     source_event_time: savepoint.source_event_time.unwrap_or(args.system_time),
                     new_watermark: None,
                     new_source_state,
+                    retraction_filter: savepoint.retraction_filter,
                     data_staging_path,
                 },
             )
@@ -312,13 +351,24 @@ impl PollingIngestServiceImpl {
 
         match stage_result {
             Ok(staged) => {
+                args.options.cancellation_token.check_cancelled()?;
+
                 args.listener.on_stage_progress(
                     PollingIngestStage::Commit,
                     0,
                     TotalSteps::Exact(1),
                 );
 
+                let num_records = staged
+                    .add_data
+                    .as_ref()
+                    .and_then(|add_data| add_data.new_offset_interval.as_ref())
+                    .map_or(0, |iv| iv.end - iv.start + 1);
+
                 let res = args.data_writer.commit(staged).await?;
+                args.listener.on_records_written(num_records);
+
+                self.acknowledge_fetched(&args.operation_id).await;
 
                 Ok(PollingIngestResult::Updated {
                     old_head: res.old_head,
@@ -330,10 +380,14 @@ impl PollingIngestServiceImpl {
             Err(StageDataError::BadInputSchema(e)) => Err(e.into()),
             Err(StageDataError::IncompatibleSchema(e)) => Err(e.into()),
             Err(StageDataError::MergeError(e)) => Err(e.into()),
-            Err(StageDataError::EmptyCommit(_)) => Ok(PollingIngestResult::UpToDate {
-                no_source_defined: false,
-                uncacheable,
-            }),
+            Err(StageDataError::EmptyCommit(_)) => {
+                self.acknowledge_fetched(&args.operation_id).await;
+
+                Ok(PollingIngestResult::UpToDate {
+                    no_source_defined: false,
+                    uncacheable,
+                })
+            }
             Err(StageDataError::Internal(e)) => Err(e.into()),
         }
     }
@@ -359,6 +413,8 @@ impl PollingIngestServiceImpl {
                     "Ignoring savepoint due to --fetch-uncacheable"
                 );
             } else if let FetchStep::Mqtt(_) = fetch_step {
+                // Unacknowledged messages are re-delivered by the broker
+                tracing::info!(?savepoint_path, "Ignoring savepoint of MQTT source");
             } else {
                 tracing::info!(?savepoint_path, "Resuming from savepoint");
                 args.listener.on_cache_hit(&savepoint.created_at);
@@ -380,6 +436,7 @@ impl PollingIngestServiceImpl {
                 &args.dataset_handle,
                 &args.operation_id,
                 fetch_step,
+                Some(&args.polling_source.read),
                 prev_source_state.as_ref(),
                 &target_path,
                 &args.system_time,
@@ -407,6 +464,7 @@ impl PollingIngestServiceImpl {
                     source_event_time: upd.source_event_time,
                     data,
                     has_more: upd.has_more,
+                    retraction_filter: upd.retraction_filter,
                 };
                 self.write_fetch_savepoint(&savepoint_path, &savepoint)?;
                 Ok(FetchStepResult::Updated(savepoint))
@@ -518,6 +576,18 @@ impl PollingIngestServiceImpl {
         }
     }
 
+    /// The data is already committed at this point, so failure to acknowledge
+    /// it doesn't fail the iteration. The source will re-deliver such data on
+    /// the next fetch.
+    async fn acknowledge_fetched(&self, operation_id: &str) {
+        if let Err(err) = self.fetch_service.acknowledge(operation_id).await {
+            tracing::warn!(
+                error = ?err,
+                "Failed to acknowledge the fetched data to the source"
+            );
+        }
+    }
+
     #[tracing::instrument(level = "info", skip_all)]
     async fn read(
         &self,
@@ -567,6 +637,29 @@ impl PollingIngestServiceImpl {
     }
 
     // TODO: Introduce intermediate structs to avoid full unpacking
+    /// Ensures the output of an Ethereum logs source preserves the block
+    /// number, as it is used to retract the logs from blocks orphaned by a
+    /// chain re-organization
+    fn validate_ethereum_logs_output(df: &DataFrame) -> Result<(), BadInputSchemaError> {
+        let has_block_number = df
+            .schema()
+            .field_with_unqualified_name(ETH_LOGS_BLOCK_NUMBER_COLUMN)
+            .is_ok_and(|f| f.data_type().is_integer());
+
+        if !has_block_number {
+            return Err(BadInputSchemaError::new(
+                format!(
+                    "Output of the Ethereum logs source must contain an integer \
+                     `{ETH_LOGS_BLOCK_NUMBER_COLUMN}` column to retract logs from blocks orphaned \
+                     by chain re-organizations"
+                ),
+                SchemaRef::new(df.schema().into()),
+            ));
+        }
+
+        Ok(())
+    }
+
     fn merge_results(
         combined_result: Option<PollingIngestResult>,
         new_result: PollingIngestResult,
@@ -724,6 +817,10 @@ impl FetchProgressListener for FetchProgressListenerBridge {
         );
     }
 
+    fn on_log_files(&self, log_files: &[PathBuf]) {
+        self.listener.on_log_files(log_files);
+    }
+
     fn get_pull_image_listener(self: Arc<Self>) -> Option<Arc<dyn PullImageListener>> {
         self.listener.clone().get_pull_image_listener()
     }
@@ -22,9 +22,14 @@ use serde_with::skip_serializing_none;
 pub enum PollingSourceState {
     ETag(String),
     LastModified(DateTime<Utc>),
+    /// Identity of the MQTT client whose persistent broker session holds the
+    /// messages that were not acknowledged yet
+    MqttClientId(String),
 }
 
 impl PollingSourceState {
+    pub const KIND_MQTT_CLIENT_ID: &'static str = "kamu/mqtt-client-id";
+
     pub fn from_source_state(source_state: &SourceState) -> Result<Option<Self>, InternalError> {
         if source_state.kind == SourceState::KIND_ETAG {
             Ok(Some(Self::ETag(source_state.value.clone())))
@@ -33,6 +38,8 @@ impl PollingSourceState {
                 .map(Into::into)
                 .int_err()?;
             Ok(Some(Self::LastModified(dt)))
+        } else if source_state.kind == Self::KIND_MQTT_CLIENT_ID {
+            Ok(Some(Self::MqttClientId(source_state.value.clone())))
         } else {
             tracing::debug!(kind = %source_state.kind, "Ignoring unsupported source state kind");
             Ok(None)
@@ -60,6 +67,9 @@ impl PollingSourceState {
                 SourceState::KIND_LAST_MODIFIED.to_owned(),
                 last_modified.to_rfc3339_opts(SecondsFormat::AutoSi, true),
             ),
+            Self::MqttClientId(client_id) => {
+                (Self::KIND_MQTT_CLIENT_ID.to_owned(), client_id.clone())
+            }
         };
         SourceState {
             source_name: SourceState::DEFAULT_SOURCE_NAME.to_string(),
@@ -105,6 +115,7 @@ pub(crate) struct FetchSavepoint {
     pub source_event_time: Option<DateTime<Utc>>,
     pub data: SavepointData,
     pub has_more: bool,
+    pub retraction_filter: Option<String>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
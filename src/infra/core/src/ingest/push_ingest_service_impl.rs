@@ -23,6 +23,7 @@ use time_source::SystemTimeSource;
 use tokio::io::AsyncRead;
 
 use super::ingest_common;
+use crate::utils::operation_dir::OperationDirGuard;
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
@@ -80,6 +81,7 @@ impl PushIngestServiceImpl {
         let operation_id = get_random_name(None, 10);
         let operation_dir = self.run_info_dir.join(format!("ingest-{operation_id}"));
         std::fs::create_dir_all(&operation_dir).int_err()?;
+        let operation_dir_guard = OperationDirGuard::new(operation_dir.clone());
 
         let ctx: SessionContext =
             ingest_common::new_session_context(self.object_store_registry.clone());
@@ -105,6 +107,11 @@ impl PushIngestServiceImpl {
             // Got existing push source
             (Some(MetadataEvent::AddPushSource(e)), _) => Ok(e.clone()),
 
+            // Source was disabled
+            (Some(MetadataEvent::DisablePushSource(e)), _) => Err(PushIngestError::SourceDisabled(
+                PushSourceDisabledError::new(&e.source_name),
+            )),
+
             // No push source and not allowed to create
             _ => Err(PushIngestError::SourceNotFound(
                 PushSourceNotFoundError::new(source_name),
@@ -125,8 +132,11 @@ impl PushIngestServiceImpl {
         let listener = args.listener.clone();
         listener.begin();
 
-        match self.do_ingest_inner(source, args).await {
+        let ingest_res = self.do_ingest_inner(source, args).await;
+
+        match ingest_res {
             Ok(res) => {
+                operation_dir_guard.finish();
                 tracing::info!(result = ?res, "Ingest iteration successful");
                 listener.success(&res);
                 Ok(res)
@@ -183,6 +193,7 @@ impl PushIngestServiceImpl {
             read,
             preprocess: None,
             merge: opendatafabric::MergeStrategy::Append(opendatafabric::MergeStrategyAppend {}),
+            watermark: None,
         };
 
         let commit_result = dataset
@@ -232,8 +243,10 @@ impl PushIngestServiceImpl {
                     transform,
                     df,
                     args.listener.clone().get_engine_provisioning_listener(),
+                    &CancellationToken::new(),
                 )
                 .await?
+                .output_data
             } else {
                 Some(
                     ingest_common::preprocess_default(
@@ -263,6 +276,7 @@ impl PushIngestServiceImpl {
                     source_event_time: args.opts.source_event_time.unwrap_or(args.system_time),
                     new_watermark: None,
                     new_source_state: None, // TODO: Support storing ingest source state
+                    retraction_filter: None,
                     data_staging_path,
                 },
             )
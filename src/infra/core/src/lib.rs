@@ -29,6 +29,7 @@ pub mod utils;
 mod compaction_service_impl;
 mod dataset_changes_service_impl;
 mod dataset_config;
+mod dataset_entries_reconciler;
 mod dataset_layout;
 mod dataset_ownership_service_inmem;
 mod dependency_graph_repository_inmem;
@@ -42,6 +43,7 @@ mod remote_repository_registry_impl;
 mod reset_service_impl;
 mod resource_loader_impl;
 mod search_service_impl;
+mod storage_gc_service_impl;
 mod sync_service_impl;
 mod transform_service_impl;
 mod verification_service_impl;
@@ -49,6 +51,7 @@ mod verification_service_impl;
 pub use compaction_service_impl::*;
 pub use dataset_changes_service_impl::*;
 pub use dataset_config::*;
+pub use dataset_entries_reconciler::*;
 pub use dataset_layout::*;
 pub use dataset_ownership_service_inmem::*;
 pub use dependency_graph_repository_inmem::*;
@@ -65,6 +68,7 @@ pub use repos::*;
 pub use reset_service_impl::*;
 pub use resource_loader_impl::*;
 pub use search_service_impl::*;
+pub use storage_gc_service_impl::*;
 pub use sync_service_impl::*;
 pub use transform_service_impl::*;
 pub use use_cases::*;
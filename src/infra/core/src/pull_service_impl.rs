@@ -422,8 +422,8 @@ impl PullServiceImpl {
     async fn transform_multi(
         &self,
         batch: &[PullItem], // TODO: Move to avoid cloning
+        options: &PullMultiOptions,
         transform_listener: Option<Arc<dyn TransformMultiListener>>,
-        reset_derivatives_on_diverged_input: bool,
     ) -> Result<Vec<PullResponse>, InternalError> {
         let transform_requests = batch.iter().map(|pi| pi.local_ref.clone()).collect();
 
@@ -432,7 +432,10 @@ impl PullServiceImpl {
             .transform_multi(
                 transform_requests,
                 TransformOptions {
-                    reset_derivatives_on_diverged_input,
+                    reset_derivatives_on_diverged_input: options
+                        .reset_derivatives_on_diverged_input,
+                    // Ingest and transform batches of one pull are stopped by the same token
+                    cancellation_token: options.ingest_options.cancellation_token.clone(),
                 },
                 transform_listener,
             )
@@ -589,10 +592,10 @@ impl PullService for PullServiceImpl {
                 tracing::info!(%depth, ?batch, "Running transform batch");
                 self.transform_multi(
                     batch,
+                    &options,
                     listener
                         .as_ref()
                         .and_then(|l| l.clone().get_transform_listener()),
-                    options.reset_derivatives_on_diverged_input,
                 )
                 .await?
             };
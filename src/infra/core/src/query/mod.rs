@@ -352,13 +352,19 @@ impl KamuTable {
 
     #[tracing::instrument(level="info", skip_all, fields(dataset_handle = ?self.dataset_handle))]
     async fn init_table_schema(&self) -> Result<SchemaRef, InternalError> {
-        let maybe_set_data_schema = self
-            .dataset
-            .as_metadata_chain()
-            .accept_one(SearchSetDataSchemaVisitor::new())
-            .await
-            .int_err()?
-            .into_event();
+        // Data files written under older versions of the schema are read under the
+        // latest one as of the queried block, as schema can only evolve in a
+        // compatible way
+        let chain = self.dataset.as_metadata_chain();
+        let visitor = match &self.as_of {
+            Some(as_of) => {
+                chain
+                    .accept_one_by_hash(as_of, SearchSetDataSchemaVisitor::new())
+                    .await
+            }
+            None => chain.accept_one(SearchSetDataSchemaVisitor::new()).await,
+        };
+        let maybe_set_data_schema = visitor.int_err()?.into_event();
 
         if let Some(set_data_schema) = maybe_set_data_schema {
             set_data_schema.schema_as_arrow().int_err()
@@ -461,7 +467,7 @@ impl KamuTable {
 
         tracing::debug!(?as_of, "Collecting data slices");
 
-        let last_records_to_consider = self.hints.as_ref().and_then(|o| o.last_records_to_consider);
+        let hints = self.hints.clone().unwrap_or_default();
 
         type Flag = MetadataEventTypeFlags;
         type Decision = MetadataVisitorDecision;
@@ -469,10 +475,12 @@ impl KamuTable {
         struct DataSliceCollectorVisitorState {
             files: Vec<Multihash>,
             num_records: u64,
-            last_records_to_consider: Option<u64>,
+            hints: DatasetQueryHints,
+            // The most recently visited slice that awaits the watermark preceding it
+            pending_file: Option<Multihash>,
         }
 
-        let final_state = self
+        let mut final_state = self
             .dataset
             .as_metadata_chain()
             .reduce_by_hash(
@@ -480,23 +488,58 @@ impl KamuTable {
                 DataSliceCollectorVisitorState {
                     files: Vec::new(),
                     num_records: 0,
-                    last_records_to_consider,
+                    hints,
+                    pending_file: None,
                 },
                 Decision::NextOfType(Flag::DATA_BLOCK),
                 |state, _hash, block| {
-                    let new_data = match &block.event {
-                        MetadataEvent::AddData(e) => e.new_data.as_ref(),
-                        MetadataEvent::ExecuteTransform(e) => e.new_data.as_ref(),
+                    let (new_data, new_watermark) = match &block.event {
+                        MetadataEvent::AddData(e) => (e.new_data.as_ref(), e.new_watermark),
+                        MetadataEvent::ExecuteTransform(e) => {
+                            (e.new_data.as_ref(), e.new_watermark)
+                        }
                         _ => unreachable!(),
                     };
+
+                    // Records of the pending slice arrived after this watermark, so
+                    // none of them precede it in event time
+                    if let Some(file) = state.pending_file.take() {
+                        let end_time = state.hints.event_time_range.and_then(|r| r.end);
+                        let past_time_range = matches!(
+                            (new_watermark, end_time),
+                            (Some(watermark), Some(end)) if watermark >= end
+                        );
+                        if !past_time_range {
+                            state.files.push(file);
+                        }
+                    }
+
                     let Some(slice) = new_data else {
                         return Decision::NextOfType(Flag::DATA_BLOCK);
                     };
 
-                    state.num_records += slice.num_records();
-                    state.files.push(slice.physical_hash.clone());
+                    let mut num_records = slice.num_records();
+
+                    if let Some(range) = &state.hints.offset_range {
+                        let (start, end) = (slice.offset_interval.start, slice.offset_interval.end);
+
+                        // Slices are visited in the reverse order of offsets
+                        if range.start.is_some_and(|range_start| end < range_start) {
+                            return Decision::Stop;
+                        }
+                        if range.end.is_some_and(|range_end| start > range_end) {
+                            return Decision::NextOfType(Flag::DATA_BLOCK);
+                        }
+
+                        let start = range.start.map_or(start, |s| start.max(s));
+                        let end = range.end.map_or(end, |e| end.min(e));
+                        num_records = end - start + 1;
+                    }
+
+                    state.num_records += num_records;
+                    state.pending_file = Some(slice.physical_hash.clone());
 
-                    if let Some(last_records_to_consider) = &state.last_records_to_consider
+                    if let Some(last_records_to_consider) = &state.hints.last_records_to_consider
                         && *last_records_to_consider <= state.num_records
                     {
                         return Decision::Stop;
@@ -508,6 +551,11 @@ impl KamuTable {
             .await
             .int_err()?;
 
+        // The earliest collected slice has no preceding watermark to be checked against
+        if let Some(file) = final_state.pending_file.take() {
+            final_state.files.push(file);
+        }
+
         tracing::debug!(num_slices = final_state.files.len(), "Slices collected");
         Ok(final_state.files)
     }
@@ -11,7 +11,9 @@ use std::collections::BTreeMap;
 use std::convert::TryFrom;
 use std::sync::Arc;
 
+use chrono::{DateTime, Utc};
 use datafusion::arrow;
+use datafusion::common::ScalarValue;
 use datafusion::error::DataFusionError;
 use datafusion::execution::runtime_env::{RuntimeConfig, RuntimeEnv};
 use datafusion::parquet::arrow::async_reader::ParquetObjectReader;
@@ -95,8 +97,19 @@ impl QueryServiceImpl {
         use datafusion::sql::parser::Statement;
 
         // If options already specify state - use it
-        if options.as_of_state.is_some() {
-            return Ok(options);
+        if let Some(mut as_of_state) = options.as_of_state.clone() {
+            // Named references only fill in datasets not pinned explicitly
+            for id in options.as_of_refs.iter().flat_map(BTreeMap::keys) {
+                if !as_of_state.inputs.contains_key(id) {
+                    let dataset = self.dataset_repo.find_dataset_by_ref(&id.into()).await?;
+                    let hash = Self::resolve_dataset_head(&options, id, dataset.as_ref()).await?;
+                    as_of_state.inputs.insert(id.clone(), hash);
+                }
+            }
+            return Ok(QueryOptions {
+                as_of_state: Some(as_of_state),
+                ..options
+            });
         }
 
         // If options specify aliases - resolve state just for those datasets
@@ -108,11 +121,7 @@ impl QueryServiceImpl {
                 let dataset = self.dataset_repo.find_dataset_by_ref(&id.into()).await?;
 
                 // TODO: Do we leak any info by not checking read permissions here?
-                let hash = dataset
-                    .as_metadata_chain()
-                    .resolve_ref(&BlockRef::Head)
-                    .await
-                    .int_err()?;
+                let hash = Self::resolve_dataset_head(&options, id, dataset.as_ref()).await?;
 
                 as_of_state.inputs.insert(id.clone(), hash);
             }
@@ -163,11 +172,7 @@ impl QueryServiceImpl {
                     continue;
                 };
                 let dataset = self.dataset_repo.get_dataset_by_handle(&hdl);
-                let hash = dataset
-                    .as_metadata_chain()
-                    .resolve_ref(&BlockRef::Head)
-                    .await
-                    .int_err()?;
+                let hash = Self::resolve_dataset_head(&options, &hdl.id, dataset.as_ref()).await?;
                 aliases.insert(alias, hdl.id.clone());
                 as_of_state.inputs.insert(hdl.id, hash);
             }
@@ -176,14 +181,37 @@ impl QueryServiceImpl {
         Ok(QueryOptions {
             aliases: Some(aliases),
             as_of_state: Some(as_of_state),
+            as_of_refs: options.as_of_refs,
             hints: options.hints,
         })
     }
 
+    /// Resolves the block that dataset should be queried at, respecting the
+    /// named reference requested in the options and defaulting to `head`
+    async fn resolve_dataset_head(
+        options: &QueryOptions,
+        id: &DatasetID,
+        dataset: &dyn Dataset,
+    ) -> Result<Multihash, QueryError> {
+        let block_ref = options
+            .as_of_refs
+            .as_ref()
+            .and_then(|refs| refs.get(id))
+            .unwrap_or(&BlockRef::Head);
+
+        match dataset.as_metadata_chain().resolve_ref(block_ref).await {
+            Ok(hash) => Ok(hash),
+            Err(GetRefError::NotFound(e)) => Err(e.into()),
+            Err(GetRefError::Access(e)) => Err(e.into()),
+            Err(GetRefError::Internal(e)) => Err(e.into()),
+        }
+    }
+
     async fn single_dataset(
         &self,
         dataset_ref: &DatasetRef,
         last_records_to_consider: Option<u64>,
+        options: GetDataOptions,
     ) -> Result<(Arc<dyn Dataset>, DataFrame), QueryError> {
         let dataset_handle = self.dataset_repo.resolve_dataset_ref(dataset_ref).await?;
 
@@ -193,27 +221,141 @@ impl QueryServiceImpl {
 
         let dataset = self.dataset_repo.get_dataset_by_handle(&dataset_handle);
 
+        let (as_of, offset_range) = Self::resolve_data_ranges(dataset.as_ref(), &options).await?;
+
+        // Event time filter makes the number of records in data files an unreliable
+        // indicator of how many records will end up in the result
+        let last_records_to_consider = if options.event_time_range.is_some() {
+            None
+        } else {
+            last_records_to_consider
+        };
+
         let ctx = self.session_context(QueryOptions {
             aliases: Some(BTreeMap::from([(
                 dataset_handle.alias.to_string(),
                 dataset_handle.id.clone(),
             )])),
-            as_of_state: None,
+            as_of_state: as_of.map(|hash| QueryState {
+                inputs: BTreeMap::from([(dataset_handle.id.clone(), hash)]),
+            }),
+            as_of_refs: None,
             hints: Some(BTreeMap::from([(
                 dataset_handle.id,
                 DatasetQueryHints {
                     last_records_to_consider,
+                    offset_range,
+                    event_time_range: options.event_time_range,
                 },
             )])),
         });
 
-        let df = ctx
+        let mut df = ctx
             .table(TableReference::bare(dataset_handle.alias.to_string()))
             .await?;
 
+        // Hints only prune the data files, so the ranges still need to be applied to
+        // the records within them
+        if offset_range.is_some() || options.event_time_range.is_some() {
+            let vocab: DatasetVocabulary = dataset
+                .as_metadata_chain()
+                .accept_one(SearchSetVocabVisitor::new())
+                .await
+                .int_err()?
+                .into_event()
+                .unwrap_or_default()
+                .into();
+
+            let offset_col = || col(Column::from_name(&vocab.offset_column));
+            let event_time_col = || col(Column::from_name(&vocab.event_time_column));
+
+            if let Some(range) = offset_range {
+                if let Some(start) = range.start {
+                    df = df.filter(offset_col().gt_eq(lit(start)))?;
+                }
+                if let Some(end) = range.end {
+                    df = df.filter(offset_col().lt_eq(lit(end)))?;
+                }
+            }
+            if let Some(range) = options.event_time_range {
+                if let Some(start) = range.start {
+                    df = df.filter(event_time_col().gt_eq(lit_timestamp(start)))?;
+                }
+                if let Some(end) = range.end {
+                    df = df.filter(event_time_col().lt(lit_timestamp(end)))?;
+                }
+            }
+        }
+
         Ok((dataset, df))
     }
 
+    /// Translates the block range into the block the dataset should be queried
+    /// at and the range of offsets added by the blocks within the range,
+    /// combined with the explicitly requested offset range
+    async fn resolve_data_ranges(
+        dataset: &dyn Dataset,
+        options: &GetDataOptions,
+    ) -> Result<(Option<Multihash>, Option<OffsetRange>), QueryError> {
+        let Some(block_range) = &options.block_range else {
+            return Ok((None, options.offset_range));
+        };
+
+        let chain = dataset.as_metadata_chain();
+
+        let up_to = match &block_range.up_to {
+            Some(hash) => hash.clone(),
+            None => chain.resolve_ref(&BlockRef::Head).await.int_err()?,
+        };
+        Self::get_range_boundary_block(chain, &up_to).await?;
+
+        let mut offset_range = options.offset_range.unwrap_or_default();
+
+        if let Some(after) = &block_range.after {
+            Self::get_range_boundary_block(chain, after).await?;
+
+            // Blocks on a different branch can have lower sequence numbers, so only the
+            // ancestry defines whether the range is valid
+            if !chain.is_ancestor_block(after, &up_to).await.int_err()? {
+                return Err(InvalidDataRangeError {
+                    reason: format!("Block {after} is not an ancestor of {up_to}"),
+                }
+                .into());
+            }
+
+            // Records added before and by the `after` block are excluded
+            let last_offset_before_range = chain
+                .accept_one_by_hash(after, SearchSingleDataBlockVisitor::next())
+                .await
+                .int_err()?
+                .into_event()
+                .and_then(|e| e.new_data.map(|d| d.offset_interval.end).or(e.prev_offset));
+
+            if let Some(last_offset) = last_offset_before_range {
+                offset_range = offset_range.intersect(OffsetRange {
+                    start: Some(last_offset + 1),
+                    end: None,
+                });
+            }
+        }
+
+        Ok((Some(up_to), Some(offset_range)))
+    }
+
+    async fn get_range_boundary_block(
+        chain: &dyn MetadataChain,
+        hash: &Multihash,
+    ) -> Result<MetadataBlock, QueryError> {
+        match chain.get_block(hash).await {
+            Ok(block) => Ok(block),
+            Err(GetBlockError::NotFound(_)) => Err(InvalidDataRangeError {
+                reason: format!("Block {hash} not found"),
+            }
+            .into()),
+            Err(e) => Err(e.int_err().into()),
+        }
+    }
+
     #[tracing::instrument(level = "debug", skip_all)]
     async fn get_schema_impl(
         &self,
@@ -314,8 +456,11 @@ impl QueryService for QueryServiceImpl {
         dataset_ref: &DatasetRef,
         skip: u64,
         limit: u64,
+        options: GetDataOptions,
     ) -> Result<DataFrame, QueryError> {
-        let (dataset, df) = self.single_dataset(dataset_ref, Some(skip + limit)).await?;
+        let (dataset, df) = self
+            .single_dataset(dataset_ref, Some(skip + limit), options)
+            .await?;
 
         // Our custom catalog provider resolves schemas lazily, so the dataset will be
         // found even if it's empty and its schema will be empty, but we decide not to
@@ -391,9 +536,13 @@ impl QueryService for QueryServiceImpl {
     }
 
     #[tracing::instrument(level = "info", skip_all, fields(dataset_ref))]
-    async fn get_data(&self, dataset_ref: &DatasetRef) -> Result<DataFrame, QueryError> {
+    async fn get_data(
+        &self,
+        dataset_ref: &DatasetRef,
+        options: GetDataOptions,
+    ) -> Result<DataFrame, QueryError> {
         // TODO: PERF: Limit push-down opportunity
-        let (_dataset, df) = self.single_dataset(dataset_ref, None).await?;
+        let (_dataset, df) = self.single_dataset(dataset_ref, None, options).await?;
         Ok(df)
     }
 
@@ -425,6 +574,15 @@ impl QueryService for QueryServiceImpl {
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
+fn lit_timestamp(time: DateTime<Utc>) -> Expr {
+    lit(ScalarValue::TimestampMillisecond(
+        Some(time.timestamp_millis()),
+        Some("UTC".into()),
+    ))
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
 #[tracing::instrument(level = "debug", skip_all, fields(data_slice_store_path))]
 async fn read_data_slice_metadata(
     object_store: Arc<dyn object_store::ObjectStore>,
@@ -8,8 +8,11 @@
 // by the Apache License, Version 2.0.
 
 use chrono::{DateTime, Utc};
+use database_common::DatabasePaginationOpts;
 use internal_error::*;
+use kamu_accounts::DEFAULT_ACCOUNT_NAME;
 use kamu_core::*;
+use kamu_datasets::DatasetEntryService;
 use opendatafabric::*;
 use random_names::get_random_name;
 
@@ -267,3 +270,93 @@ async fn resolve_transform_inputs(
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// Dataset entries
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+const DATASET_ENTRIES_PAGE_SIZE: i64 = 100;
+
+/// Resolves a canonical alias using the catalog of dataset entries
+pub(crate) async fn resolve_dataset_alias_via_entries(
+    dataset_entry_service: &dyn DatasetEntryService,
+    dataset_alias: &DatasetAlias,
+    multi_tenant: bool,
+) -> Result<Option<DatasetHandle>, InternalError> {
+    let owner_name = dataset_alias
+        .account_name
+        .as_ref()
+        .unwrap_or(&DEFAULT_ACCOUNT_NAME);
+
+    let maybe_handle = dataset_entry_service
+        .find_dataset_handle_by_alias(owner_name, &dataset_alias.dataset_name)
+        .await?;
+
+    Ok(maybe_handle.map(|hdl| adapt_entry_handle(hdl, multi_tenant)))
+}
+
+/// Resolves a dataset ID using the catalog of dataset entries
+pub(crate) async fn resolve_dataset_id_via_entries(
+    dataset_entry_service: &dyn DatasetEntryService,
+    dataset_id: &DatasetID,
+    multi_tenant: bool,
+) -> Result<Option<DatasetHandle>, InternalError> {
+    let maybe_handle = dataset_entry_service
+        .find_dataset_handle_by_id(dataset_id)
+        .await?;
+
+    Ok(maybe_handle.map(|hdl| adapt_entry_handle(hdl, multi_tenant)))
+}
+
+/// Streams handles from the catalog of dataset entries page by page, either
+/// all of them or only the ones belonging to the specified owner
+pub(crate) fn stream_datasets_via_entries(
+    dataset_entry_service: &dyn DatasetEntryService,
+    maybe_owner_name: Option<AccountName>,
+    multi_tenant: bool,
+) -> DatasetHandleStream<'_> {
+    Box::pin(async_stream::try_stream! {
+        let mut offset = 0;
+        loop {
+            let pagination = DatabasePaginationOpts {
+                limit: DATASET_ENTRIES_PAGE_SIZE,
+                offset,
+            };
+
+            let listing = if let Some(owner_name) = &maybe_owner_name {
+                dataset_entry_service
+                    .list_dataset_handles_by_owner(owner_name, &pagination)
+                    .await?
+            } else {
+                dataset_entry_service
+                    .list_all_dataset_handles(&pagination)
+                    .await?
+            };
+
+            let page_size = i64::try_from(listing.list.len()).int_err()?;
+            for hdl in listing.list {
+                yield adapt_entry_handle(hdl, multi_tenant);
+            }
+
+            offset += page_size;
+            if page_size < DATASET_ENTRIES_PAGE_SIZE
+                || offset >= i64::try_from(listing.total_count).int_err()?
+            {
+                break;
+            }
+        }
+    })
+}
+
+/// Entries always carry the owner name, which is not a part of aliases in
+/// single-tenant workspaces
+fn adapt_entry_handle(dataset_handle: DatasetHandle, multi_tenant: bool) -> DatasetHandle {
+    if multi_tenant {
+        dataset_handle
+    } else {
+        DatasetHandle::new(
+            dataset_handle.id,
+            DatasetAlias::new(None, dataset_handle.alias.dataset_name),
+        )
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -13,8 +13,9 @@ use std::sync::Arc;
 use async_trait::async_trait;
 use dill::*;
 use internal_error::{ErrorIntoInternal, InternalError, ResultIntoInternal};
-use kamu_accounts::{CurrentAccountSubject, DEFAULT_ACCOUNT_NAME_STR};
+use kamu_accounts::{CurrentAccountSubject, DEFAULT_ACCOUNT_NAME, DEFAULT_ACCOUNT_NAME_STR};
 use kamu_core::*;
+use kamu_datasets::DatasetEntryService;
 use opendatafabric::*;
 use time_source::SystemTimeSource;
 use url::Url;
@@ -27,17 +28,24 @@ pub struct DatasetRepositoryLocalFs {
     storage_strategy: Box<dyn DatasetStorageStrategy>,
     thrash_lock: tokio::sync::Mutex<()>,
     system_time_source: Arc<dyn SystemTimeSource>,
+    dataset_entry_service: Option<Arc<dyn DatasetEntryService>>,
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
 #[component(pub)]
 impl DatasetRepositoryLocalFs {
+    /// # Arguments
+    ///
+    /// * `dataset_entry_service` - when present in the catalog enables
+    ///   resolution and listing of datasets via the catalog of dataset entries,
+    ///   allowing to avoid expensive scanning of the workspace
     pub fn new(
         root: PathBuf,
         current_account_subject: Arc<CurrentAccountSubject>,
         multi_tenant: bool,
         system_time_source: Arc<dyn SystemTimeSource>,
+        dataset_entry_service: Option<Arc<dyn DatasetEntryService>>,
     ) -> Self {
         Self {
             storage_strategy: if multi_tenant {
@@ -50,6 +58,7 @@ impl DatasetRepositoryLocalFs {
             },
             thrash_lock: tokio::sync::Mutex::new(()),
             system_time_source,
+            dataset_entry_service,
         }
     }
 
@@ -90,6 +99,32 @@ impl DatasetRepositoryLocalFs {
 
         Ok((canonical_dataset_path, dataset_name_str))
     }
+
+    async fn resolve_dataset_ref_via_entries(
+        &self,
+        dataset_entry_service: &dyn DatasetEntryService,
+        dataset_ref: &DatasetRef,
+    ) -> Result<Option<DatasetHandle>, InternalError> {
+        match dataset_ref {
+            DatasetRef::Handle(h) => Ok(Some(h.clone())),
+            DatasetRef::Alias(alias) => {
+                let owned_alias = DatasetAlias::new(
+                    Some(self.storage_strategy.dataset_owner_name(alias)),
+                    alias.dataset_name.clone(),
+                );
+                resolve_dataset_alias_via_entries(
+                    dataset_entry_service,
+                    &owned_alias,
+                    self.is_multi_tenant(),
+                )
+                .await
+            }
+            DatasetRef::ID(id) => {
+                resolve_dataset_id_via_entries(dataset_entry_service, id, self.is_multi_tenant())
+                    .await
+            }
+        }
+    }
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -124,6 +159,19 @@ impl DatasetRepository for DatasetRepositoryLocalFs {
         &self,
         dataset_ref: &DatasetRef,
     ) -> Result<DatasetHandle, GetDatasetError> {
+        if let Some(dataset_entry_service) = &self.dataset_entry_service {
+            if let Some(dataset_handle) = self
+                .resolve_dataset_ref_via_entries(dataset_entry_service.as_ref(), dataset_ref)
+                .await?
+            {
+                return Ok(dataset_handle);
+            }
+
+            // Datasets that were placed into the workspace directly are not in the catalog
+            // until it is reconciled, but they can still be found in the storage
+            tracing::debug!(%dataset_ref, "Dataset entry not found, resolving via storage");
+        }
+
         // Anti-thrashing lock (see comment above)
         let _lock_guard = self.thrash_lock.lock().await;
 
@@ -150,10 +198,30 @@ impl DatasetRepository for DatasetRepositoryLocalFs {
 
     // TODO: PERF: Resolving handles currently involves reading summary files
     fn get_all_datasets(&self) -> DatasetHandleStream<'_> {
+        if let Some(dataset_entry_service) = &self.dataset_entry_service {
+            return stream_datasets_via_entries(
+                dataset_entry_service.as_ref(),
+                None,
+                self.is_multi_tenant(),
+            );
+        }
+
         self.storage_strategy.get_all_datasets()
     }
 
     fn get_datasets_by_owner(&self, account_name: &AccountName) -> DatasetHandleStream<'_> {
+        if let Some(dataset_entry_service) = &self.dataset_entry_service {
+            if !self.is_multi_tenant() && *account_name != DEFAULT_ACCOUNT_NAME_STR {
+                return Box::pin(futures::stream::empty());
+            }
+
+            return stream_datasets_via_entries(
+                dataset_entry_service.as_ref(),
+                Some(account_name.clone()),
+                self.is_multi_tenant(),
+            );
+        }
+
         self.storage_strategy.get_datasets_by_owner(account_name)
     }
 
@@ -172,6 +240,17 @@ impl DatasetRepository for DatasetRepositoryLocalFs {
     }
 }
 
+#[async_trait]
+impl DatasetStorageScanner for DatasetRepositoryLocalFs {
+    fn scan_all_datasets(&self) -> DatasetHandleStream<'_> {
+        self.storage_strategy.get_all_datasets()
+    }
+
+    async fn count_all_datasets(&self) -> Result<usize, InternalError> {
+        self.storage_strategy.count_all_datasets()
+    }
+}
+
 #[async_trait]
 impl DatasetRepositoryWriter for DatasetRepositoryLocalFs {
     async fn create_dataset(
@@ -354,6 +433,8 @@ trait DatasetStorageStrategy: Sync + Send {
 
     fn get_datasets_by_owner(&self, account_name: &AccountName) -> DatasetHandleStream<'_>;
 
+    fn count_all_datasets(&self) -> Result<usize, InternalError>;
+
     async fn resolve_dataset_alias(
         &self,
         dataset_alias: &DatasetAlias,
@@ -380,6 +461,8 @@ trait DatasetStorageStrategy: Sync + Send {
         &self,
         raw_alias: &DatasetAlias,
     ) -> Result<DatasetAlias, ResolveDatasetError>;
+
+    fn dataset_owner_name(&self, dataset_alias: &DatasetAlias) -> AccountName;
 }
 
 #[derive(thiserror::Error, Debug)]
@@ -505,6 +588,10 @@ impl DatasetStorageStrategy for DatasetSingleTenantStorageStrategy {
         }
     }
 
+    fn count_all_datasets(&self) -> Result<usize, InternalError> {
+        list_visible_dirs(&self.root).map(|dirs| dirs.len())
+    }
+
     async fn resolve_dataset_alias(
         &self,
         dataset_alias: &DatasetAlias,
@@ -601,6 +688,10 @@ impl DatasetStorageStrategy for DatasetSingleTenantStorageStrategy {
     ) -> Result<DatasetAlias, ResolveDatasetError> {
         Ok(raw_alias.clone())
     }
+
+    fn dataset_owner_name(&self, _dataset_alias: &DatasetAlias) -> AccountName {
+        DEFAULT_ACCOUNT_NAME.clone()
+    }
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -806,6 +897,14 @@ impl DatasetStorageStrategy for DatasetMultiTenantStorageStrategy {
         })
     }
 
+    fn count_all_datasets(&self) -> Result<usize, InternalError> {
+        let mut count = 0;
+        for account_dir_path in list_visible_dirs(&self.root)? {
+            count += list_visible_dirs(&account_dir_path)?.len();
+        }
+        Ok(count)
+    }
+
     async fn resolve_dataset_alias(
         &self,
         dataset_alias: &DatasetAlias,
@@ -927,6 +1026,29 @@ impl DatasetStorageStrategy for DatasetMultiTenantStorageStrategy {
             raw_alias.clone()
         })
     }
+
+    fn dataset_owner_name(&self, dataset_alias: &DatasetAlias) -> AccountName {
+        self.effective_account_name(dataset_alias).clone()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Lists the subdirectories, skipping the hidden ones
+fn list_visible_dirs(dir: &Path) -> Result<Vec<PathBuf>, InternalError> {
+    let mut dirs = Vec::new();
+    for r_entry in std::fs::read_dir(dir).int_err()? {
+        let entry = r_entry.int_err()?;
+        if let Some(s) = entry.file_name().to_str() {
+            if s.starts_with('.') {
+                continue;
+            }
+        }
+        if entry.path().is_dir() {
+            dirs.push(entry.path());
+        }
+    }
+    Ok(dirs)
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -16,6 +16,7 @@ use dill::*;
 use internal_error::{ErrorIntoInternal, InternalError, ResultIntoInternal};
 use kamu_accounts::{CurrentAccountSubject, DEFAULT_ACCOUNT_NAME_STR};
 use kamu_core::*;
+use kamu_datasets::DatasetEntryService;
 use opendatafabric::*;
 use time_source::SystemTimeSource;
 use tokio::sync::Mutex;
@@ -33,6 +34,7 @@ pub struct DatasetRepositoryS3 {
     registry_cache: Option<Arc<S3RegistryCache>>,
     metadata_cache_local_fs_path: Option<Arc<PathBuf>>,
     system_time_source: Arc<dyn SystemTimeSource>,
+    dataset_entry_service: Option<Arc<dyn DatasetEntryService>>,
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -48,6 +50,10 @@ impl DatasetRepositoryS3 {
     /// * `metadata_cache_local_fs_path` - when specified enables the local FS
     ///   cache of metadata blocks, allowing to dramatically reduce the number
     ///   of requests to S3
+    ///
+    /// * `dataset_entry_service` - when present in the catalog enables
+    ///   resolution and listing of datasets via the catalog of dataset entries,
+    ///   taking precedence over the registry cache and bucket scanning
     pub fn new(
         s3_context: S3Context,
         current_account_subject: Arc<CurrentAccountSubject>,
@@ -55,6 +61,7 @@ impl DatasetRepositoryS3 {
         registry_cache: Option<Arc<S3RegistryCache>>,
         metadata_cache_local_fs_path: Option<Arc<PathBuf>>,
         system_time_source: Arc<dyn SystemTimeSource>,
+        dataset_entry_service: Option<Arc<dyn DatasetEntryService>>,
     ) -> Self {
         Self {
             s3_context,
@@ -63,6 +70,7 @@ impl DatasetRepositoryS3 {
             registry_cache,
             metadata_cache_local_fs_path,
             system_time_source,
+            dataset_entry_service,
         }
     }
 
@@ -294,6 +302,34 @@ impl DatasetRepository for DatasetRepositoryS3 {
         &self,
         dataset_ref: &DatasetRef,
     ) -> Result<DatasetHandle, GetDatasetError> {
+        if let Some(dataset_entry_service) = &self.dataset_entry_service {
+            let maybe_handle = match dataset_ref {
+                DatasetRef::Handle(h) => return Ok(h.clone()),
+                DatasetRef::Alias(alias) => {
+                    resolve_dataset_alias_via_entries(
+                        dataset_entry_service.as_ref(),
+                        &self.normalize_alias(alias),
+                        self.multi_tenant,
+                    )
+                    .await?
+                }
+                DatasetRef::ID(id) => {
+                    resolve_dataset_id_via_entries(
+                        dataset_entry_service.as_ref(),
+                        id,
+                        self.multi_tenant,
+                    )
+                    .await?
+                }
+            };
+
+            return maybe_handle.ok_or_else(|| {
+                GetDatasetError::NotFound(DatasetNotFoundError {
+                    dataset_ref: dataset_ref.clone(),
+                })
+            });
+        }
+
         match dataset_ref {
             DatasetRef::Handle(h) => Ok(h.clone()),
             DatasetRef::Alias(alias) => {
@@ -333,6 +369,14 @@ impl DatasetRepository for DatasetRepositoryS3 {
     }
 
     fn get_all_datasets(&self) -> DatasetHandleStream<'_> {
+        if let Some(dataset_entry_service) = &self.dataset_entry_service {
+            return stream_datasets_via_entries(
+                dataset_entry_service.as_ref(),
+                None,
+                self.multi_tenant,
+            );
+        }
+
         self.stream_datasets_if(|_| true)
     }
 
@@ -341,6 +385,14 @@ impl DatasetRepository for DatasetRepositoryS3 {
             return Box::pin(futures::stream::empty());
         }
 
+        if let Some(dataset_entry_service) = &self.dataset_entry_service {
+            return stream_datasets_via_entries(
+                dataset_entry_service.as_ref(),
+                Some(account_name.clone()),
+                self.multi_tenant,
+            );
+        }
+
         let account_name = account_name.clone();
         self.stream_datasets_if(move |dataset_alias| {
             if let Some(dataset_account_name) = &dataset_alias.account_name {
@@ -365,6 +417,17 @@ impl DatasetRepository for DatasetRepositoryS3 {
     }
 }
 
+#[async_trait]
+impl DatasetStorageScanner for DatasetRepositoryS3 {
+    fn scan_all_datasets(&self) -> DatasetHandleStream<'_> {
+        self.stream_datasets_if(|_| true)
+    }
+
+    async fn count_all_datasets(&self) -> Result<usize, InternalError> {
+        Ok(self.list_datasets_maybe_cached().await?.len())
+    }
+}
+
 #[async_trait]
 impl DatasetRepositoryWriter for DatasetRepositoryS3 {
     async fn create_dataset(
@@ -0,0 +1,28 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use internal_error::InternalError;
+use kamu_core::DatasetHandleStream;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Lists datasets by scanning the underlying storage, regardless of whether the
+/// catalog of dataset entries is available. Used to reconcile that catalog with
+/// the actual state of the storage.
+#[async_trait::async_trait]
+pub trait DatasetStorageScanner: Sync + Send {
+    fn scan_all_datasets(&self) -> DatasetHandleStream<'_>;
+
+    /// Counts the datasets present in the storage. Unlike scanning, this does
+    /// not require resolving their handles where the storage allows it, so it
+    /// can be used to cheaply detect that the catalog is out of date.
+    async fn count_all_datasets(&self) -> Result<usize, InternalError>;
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -216,6 +216,9 @@ where
                 &mut ValidateOffsetsAreSequentialVisitor::new(&block)?,
                 &mut ValidateAddPushSourceVisitor::new(&block)?,
                 &mut ValidateSetPollingSourceVisitor::new(&block)?,
+                &mut ValidateDisablePollingSourceVisitor::new(&block),
+                &mut ValidateDisablePushSourceVisitor::new(&block),
+                &mut ValidateSetDataSchemaVisitor::new(&block),
                 &mut ValidateSetTransformVisitor::new(&block)?,
             ];
 
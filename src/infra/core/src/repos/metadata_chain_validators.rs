@@ -7,6 +7,8 @@
 // the Business Source License, use of this software will be governed
 // by the Apache License, Version 2.0.
 
+use std::collections::HashSet;
+
 use chrono::{DateTime, Utc};
 use kamu_core::{
     AppendValidationError,
@@ -16,8 +18,11 @@ use kamu_core::{
     OffsetsNotSequentialError,
     SequenceIntegrityError,
 };
+use kamu_data_utils::schema::cmp::diff_schemas;
 use opendatafabric::{
     AddData,
+    DisablePollingSource,
+    DisablePushSource,
     ExecuteTransform,
     FetchStep,
     IntoDataStreamBlock,
@@ -296,15 +301,6 @@ pub struct ValidateUnimplementedEventsVisitor {}
 impl ValidateUnimplementedEventsVisitor {
     pub fn new(block: &MetadataBlock) -> Self {
         match &block.event {
-            MetadataEvent::DisablePollingSource(_) => {
-                // TODO: Ensure has previously active polling source
-                unimplemented!("Disabling sources is not yet fully supported")
-            }
-            MetadataEvent::DisablePushSource(_) => {
-                // TODO: Ensure has previous push source with matching name
-                unimplemented!("Disabling sources is not yet fully supported")
-            }
-            // TODO: Consider schema evolution rules
             // TODO: Consider what happens with previously defined sources
             MetadataEvent::SetDataSchema(_)
             | MetadataEvent::Seed(_)
@@ -315,7 +311,9 @@ impl ValidateUnimplementedEventsVisitor {
             | MetadataEvent::SetLicense(_)
             | MetadataEvent::ExecuteTransform(_)
             | MetadataEvent::SetPollingSource(_)
+            | MetadataEvent::DisablePollingSource(_)
             | MetadataEvent::AddPushSource(_)
+            | MetadataEvent::DisablePushSource(_)
             | MetadataEvent::SetTransform(_) => {}
         };
 
@@ -366,21 +364,21 @@ impl MetadataChainVisitor for ValidateAddPushSourceVisitor {
 
     fn initial_decision(&self) -> Decision {
         if self.is_push_source_appended {
-            Decision::NextOfType(Flag::SET_POLLING_SOURCE)
+            Decision::NextOfType(Flag::SET_POLLING_SOURCE | Flag::DISABLE_POLLING_SOURCE)
         } else {
             Decision::Stop
         }
     }
 
     fn visit(&mut self, (_, block): HashedMetadataBlockRef) -> Result<Decision, Self::Error> {
-        let MetadataEvent::SetPollingSource(e) = &block.event else {
-            unreachable!()
-        };
-
-        invalid_event!(
-            e.clone(),
-            "Cannot add a push source while polling source is still active",
-        );
+        match &block.event {
+            MetadataEvent::SetPollingSource(e) => invalid_event!(
+                e.clone(),
+                "Cannot add a push source while polling source is still active",
+            ),
+            MetadataEvent::DisablePollingSource(_) => Ok(Decision::Stop),
+            _ => unreachable!(),
+        }
     }
 }
 
@@ -388,6 +386,7 @@ impl MetadataChainVisitor for ValidateAddPushSourceVisitor {
 
 pub struct ValidateSetPollingSourceVisitor {
     is_set_polling_source_appended: bool,
+    disabled_push_sources: HashSet<String>,
 }
 
 impl ValidateSetPollingSourceVisitor {
@@ -413,6 +412,7 @@ impl ValidateSetPollingSourceVisitor {
 
         Ok(Self {
             is_set_polling_source_appended,
+            disabled_push_sources: HashSet::new(),
         })
     }
 }
@@ -422,21 +422,229 @@ impl MetadataChainVisitor for ValidateSetPollingSourceVisitor {
 
     fn initial_decision(&self) -> Decision {
         if self.is_set_polling_source_appended {
-            Decision::NextOfType(Flag::ADD_PUSH_SOURCE)
+            Decision::NextOfType(Flag::ADD_PUSH_SOURCE | Flag::DISABLE_PUSH_SOURCE)
+        } else {
+            Decision::Stop
+        }
+    }
+
+    fn visit(&mut self, (_, block): HashedMetadataBlockRef) -> Result<Decision, Self::Error> {
+        match &block.event {
+            MetadataEvent::AddPushSource(e)
+                if !self.disabled_push_sources.contains(&e.source_name) =>
+            {
+                invalid_event!(
+                    e.clone(),
+                    "Cannot add a polling source while some push sources are still active",
+                );
+            }
+            MetadataEvent::AddPushSource(_) => {}
+            MetadataEvent::DisablePushSource(e) => {
+                self.disabled_push_sources.insert(e.source_name.clone());
+            }
+            _ => unreachable!(),
+        }
+
+        Ok(Decision::NextOfType(
+            Flag::ADD_PUSH_SOURCE | Flag::DISABLE_PUSH_SOURCE,
+        ))
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub struct ValidateDisablePollingSourceVisitor<'a> {
+    appended_disable_polling_source: Option<&'a DisablePollingSource>,
+    has_active_polling_source: bool,
+}
+
+impl<'a> ValidateDisablePollingSourceVisitor<'a> {
+    pub fn new(block: &'a MetadataBlock) -> Self {
+        let appended_disable_polling_source = match &block.event {
+            MetadataEvent::DisablePollingSource(e) => Some(e),
+            _ => None,
+        };
+
+        Self {
+            appended_disable_polling_source,
+            has_active_polling_source: false,
+        }
+    }
+}
+
+impl<'a> MetadataChainVisitor for ValidateDisablePollingSourceVisitor<'a> {
+    type Error = AppendValidationError;
+
+    fn initial_decision(&self) -> Decision {
+        if self.appended_disable_polling_source.is_some() {
+            Decision::NextOfType(Flag::SET_POLLING_SOURCE | Flag::DISABLE_POLLING_SOURCE)
         } else {
             Decision::Stop
         }
     }
 
     fn visit(&mut self, (_, block): HashedMetadataBlockRef) -> Result<Decision, Self::Error> {
-        let MetadataEvent::AddPushSource(e) = &block.event else {
+        match &block.event {
+            MetadataEvent::SetPollingSource(_) => {
+                self.has_active_polling_source = true;
+                Ok(Decision::Stop)
+            }
+            MetadataEvent::DisablePollingSource(e) => {
+                invalid_event!(e.clone(), "Polling source is already disabled")
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    fn finish(&self) -> Result<(), Self::Error> {
+        if let Some(e) = self.appended_disable_polling_source {
+            if !self.has_active_polling_source {
+                invalid_event!(
+                    e.clone(),
+                    "Dataset does not have a polling source to disable"
+                );
+            }
+        }
+        Ok(())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub struct ValidateDisablePushSourceVisitor<'a> {
+    appended_disable_push_source: Option<&'a DisablePushSource>,
+    has_active_push_source: bool,
+}
+
+impl<'a> ValidateDisablePushSourceVisitor<'a> {
+    pub fn new(block: &'a MetadataBlock) -> Self {
+        let appended_disable_push_source = match &block.event {
+            MetadataEvent::DisablePushSource(e) => Some(e),
+            _ => None,
+        };
+
+        Self {
+            appended_disable_push_source,
+            has_active_push_source: false,
+        }
+    }
+}
+
+impl<'a> MetadataChainVisitor for ValidateDisablePushSourceVisitor<'a> {
+    type Error = AppendValidationError;
+
+    fn initial_decision(&self) -> Decision {
+        if self.appended_disable_push_source.is_some() {
+            Decision::NextOfType(Flag::ADD_PUSH_SOURCE | Flag::DISABLE_PUSH_SOURCE)
+        } else {
+            Decision::Stop
+        }
+    }
+
+    fn visit(&mut self, (_, block): HashedMetadataBlockRef) -> Result<Decision, Self::Error> {
+        let Some(appended) = self.appended_disable_push_source else {
             unreachable!()
         };
 
-        invalid_event!(
-            e.clone(),
-            "Cannot add a polling source while some push sources are still active",
-        );
+        match &block.event {
+            MetadataEvent::AddPushSource(e) if e.source_name == appended.source_name => {
+                self.has_active_push_source = true;
+                return Ok(Decision::Stop);
+            }
+            MetadataEvent::DisablePushSource(e) if e.source_name == appended.source_name => {
+                invalid_event!(
+                    appended.clone(),
+                    format!("Push source '{}' is already disabled", e.source_name),
+                );
+            }
+            MetadataEvent::AddPushSource(_) | MetadataEvent::DisablePushSource(_) => {}
+            _ => unreachable!(),
+        }
+
+        Ok(Decision::NextOfType(
+            Flag::ADD_PUSH_SOURCE | Flag::DISABLE_PUSH_SOURCE,
+        ))
+    }
+
+    fn finish(&self) -> Result<(), Self::Error> {
+        if let Some(e) = self.appended_disable_push_source {
+            if !self.has_active_push_source {
+                invalid_event!(
+                    e.clone(),
+                    format!("Dataset does not have a push source '{}'", e.source_name),
+                );
+            }
+        }
+        Ok(())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub struct ValidateSetDataSchemaVisitor<'a> {
+    appended_set_data_schema: Option<&'a SetDataSchema>,
+    prev_set_data_schema: Option<SetDataSchema>,
+}
+
+impl<'a> ValidateSetDataSchemaVisitor<'a> {
+    pub fn new(block: &'a MetadataBlock) -> Self {
+        let appended_set_data_schema = match &block.event {
+            MetadataEvent::SetDataSchema(e) => Some(e),
+            _ => None,
+        };
+
+        Self {
+            appended_set_data_schema,
+            prev_set_data_schema: None,
+        }
+    }
+}
+
+impl<'a> MetadataChainVisitor for ValidateSetDataSchemaVisitor<'a> {
+    type Error = AppendValidationError;
+
+    fn initial_decision(&self) -> Decision {
+        if self.appended_set_data_schema.is_some() {
+            Decision::NextOfType(Flag::SET_DATA_SCHEMA)
+        } else {
+            Decision::Stop
+        }
+    }
+
+    fn visit(&mut self, (_, block): HashedMetadataBlockRef) -> Result<Decision, Self::Error> {
+        let MetadataEvent::SetDataSchema(e) = &block.event else {
+            unreachable!()
+        };
+
+        self.prev_set_data_schema = Some(e.clone());
+
+        Ok(Decision::Stop)
+    }
+
+    fn finish(&self) -> Result<(), Self::Error> {
+        let (Some(appended), Some(prev)) = (
+            self.appended_set_data_schema,
+            self.prev_set_data_schema.as_ref(),
+        ) else {
+            return Ok(());
+        };
+
+        let (Ok(new_schema), Ok(prev_schema)) =
+            (appended.schema_as_arrow(), prev.schema_as_arrow())
+        else {
+            invalid_event!(appended.clone(), "Schema cannot be decoded");
+        };
+
+        // Schema can only evolve in a way that keeps previously written data readable
+        let diff = diff_schemas(&prev_schema, &new_schema);
+        if !diff.is_compatible() {
+            invalid_event!(
+                appended.clone(),
+                format!("Schema is not compatible with the previous schema:\n{diff}"),
+            );
+        }
+
+        Ok(())
     }
 }
 
@@ -13,6 +13,7 @@ mod dataset_repository_helpers;
 mod dataset_repository_local_fs;
 mod dataset_repository_s3;
 mod dataset_repository_writer;
+mod dataset_storage_scanner;
 mod metadata_block_repository_caching_inmem;
 mod metadata_block_repository_helpers;
 mod metadata_block_repository_impl;
@@ -39,6 +40,7 @@ pub use dataset_repository_helpers::*;
 pub use dataset_repository_local_fs::*;
 pub use dataset_repository_s3::*;
 pub use dataset_repository_writer::*;
+pub use dataset_storage_scanner::*;
 pub use metadata_block_repository_caching_inmem::*;
 pub use metadata_block_repository_helpers::*;
 pub use metadata_block_repository_impl::*;
@@ -175,4 +175,8 @@ where
         }?;
         self.wrapped.delete(hash).await
     }
+
+    async fn list(&self) -> Result<Vec<ObjectInfo>, ListObjectsError> {
+        self.wrapped.list().await
+    }
 }
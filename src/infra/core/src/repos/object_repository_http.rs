@@ -225,4 +225,10 @@ impl ObjectRepository for ObjectRepositoryHttp {
     async fn delete(&self, _hash: &Multihash) -> Result<(), DeleteError> {
         Err(AccessError::ReadOnly(None).into())
     }
+
+    async fn list(&self) -> Result<Vec<ObjectInfo>, ListObjectsError> {
+        Err("Listing objects is not supported by HTTP repositories"
+            .int_err()
+            .into())
+    }
 }
@@ -12,6 +12,7 @@ use std::path::{Path, PathBuf};
 
 use async_trait::async_trait;
 use bytes::Bytes;
+use chrono::{DateTime, Utc};
 use internal_error::ResultIntoInternal;
 use kamu_core::*;
 use opendatafabric::{Multicodec, Multihash};
@@ -321,4 +322,38 @@ where
         }
         Ok(())
     }
+
+    async fn list(&self) -> Result<Vec<ObjectInfo>, ListObjectsError> {
+        let mut objects = Vec::new();
+
+        if !self.root.exists() {
+            return Ok(objects);
+        }
+
+        let mut entries = tokio::fs::read_dir(&self.root).await.int_err()?;
+        while let Some(entry) = entries.next_entry().await.int_err()? {
+            let file_name = entry.file_name();
+            let Some(name) = file_name.to_str() else {
+                continue;
+            };
+
+            // Skip staging files and anything else that is not an object
+            let Ok(hash) = Multihash::from_multibase(name) else {
+                continue;
+            };
+
+            let metadata = entry.metadata().await.int_err()?;
+            if !metadata.is_file() {
+                continue;
+            }
+
+            objects.push(ObjectInfo {
+                hash,
+                size: metadata.len(),
+                last_modified: metadata.modified().ok().map(DateTime::<Utc>::from),
+            });
+        }
+
+        Ok(objects)
+    }
 }
@@ -16,6 +16,7 @@ use aws_sdk_s3::operation::get_object::GetObjectError;
 use aws_sdk_s3::operation::head_object::HeadObjectError;
 use aws_sdk_s3::presigning::PresigningConfig;
 use bytes::Bytes;
+use chrono::{DateTime, Utc};
 use internal_error::{ErrorIntoInternal, ResultIntoInternal};
 use kamu_core::*;
 use opendatafabric::{Multicodec, Multihash};
@@ -315,4 +316,24 @@ where
 
         Ok(())
     }
+
+    async fn list(&self) -> Result<Vec<ObjectInfo>, ListObjectsError> {
+        let listing = self.s3_context.list_objects("").await?;
+
+        Ok(listing
+            .into_iter()
+            .filter_map(|obj| {
+                let key = obj.key()?;
+                let name = key.rsplit('/').next()?;
+                let hash = Multihash::from_multibase(name).ok()?;
+                Some(ObjectInfo {
+                    hash,
+                    size: u64::try_from(obj.size()).unwrap_or(0),
+                    last_modified: obj
+                        .last_modified()
+                        .and_then(|t| DateTime::<Utc>::from_timestamp(t.secs(), t.subsec_nanos())),
+                })
+            })
+            .collect())
+    }
 }
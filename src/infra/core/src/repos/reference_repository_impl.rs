@@ -7,24 +7,80 @@
 // the Business Source License, use of this software will be governed
 // by the Apache License, Version 2.0.
 
+use std::str::FromStr;
+
 use async_trait::async_trait;
-use internal_error::ResultIntoInternal;
+use internal_error::{ErrorIntoInternal, InternalError, ResultIntoInternal};
 use kamu_core::repos::reference_repository::SetRefError;
 use kamu_core::*;
 use opendatafabric::Multihash;
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
+/// Name of the object that lists all user-defined references (tags and
+/// branches). Named object repositories don't support listing, so the index
+/// is kept alongside the references themselves, which also allows it to be
+/// read over the simple transfer protocol.
+pub const REFS_INDEX_NAME: &str = "index";
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
 pub struct ReferenceRepositoryImpl<R> {
     repo: R,
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
-impl<R> ReferenceRepositoryImpl<R> {
+impl<R> ReferenceRepositoryImpl<R>
+where
+    R: NamedObjectRepository + Send + Sync,
+{
     pub fn new(repo: R) -> Self {
         Self { repo }
     }
+
+    async fn read_index(&self) -> Result<Vec<BlockRef>, GetNamedError> {
+        let data = match self.repo.get(REFS_INDEX_NAME).await {
+            Ok(data) => data,
+            Err(GetNamedError::NotFound(_)) => return Ok(Vec::new()),
+            Err(e) => return Err(e),
+        };
+
+        let text = std::str::from_utf8(&data[..]).int_err()?;
+
+        let mut refs = Vec::new();
+        for line in text.lines().map(str::trim).filter(|l| !l.is_empty()) {
+            refs.push(BlockRef::from_str(line)?);
+        }
+
+        Ok(refs)
+    }
+
+    // TODO: CONCURRENCY: Concurrent updates of different references may race on
+    // the index
+    async fn write_index(&self, refs: &[BlockRef]) -> Result<(), SetNamedError> {
+        let text: String = refs.iter().map(|r| format!("{r}\n")).collect();
+        self.repo.set(REFS_INDEX_NAME, text.as_bytes()).await
+    }
+
+    async fn add_to_index(&self, r: &BlockRef) -> Result<(), InternalError> {
+        let mut refs = self.read_index().await.int_err()?;
+        if !refs.contains(r) {
+            refs.push(r.clone());
+            refs.sort();
+            self.write_index(&refs).await.int_err()?;
+        }
+        Ok(())
+    }
+
+    async fn remove_from_index(&self, r: &BlockRef) -> Result<(), InternalError> {
+        let mut refs = self.read_index().await.int_err()?;
+        if refs.contains(r) {
+            refs.retain(|i| i != r);
+            self.write_index(&refs).await.int_err()?;
+        }
+        Ok(())
+    }
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -35,7 +91,7 @@ where
     R: NamedObjectRepository + Send + Sync,
 {
     async fn get(&self, r: &BlockRef) -> Result<Multihash, GetRefError> {
-        let data = match self.repo.get(r.as_str()).await {
+        let data = match self.repo.get(&r.to_string()).await {
             Ok(data) => Ok(data),
             Err(GetNamedError::NotFound(_)) => Err(GetRefError::NotFound(RefNotFoundError {
                 block_ref: r.clone(),
@@ -50,18 +106,49 @@ where
 
     async fn set(&self, r: &BlockRef, hash: &Multihash) -> Result<(), SetRefError> {
         let multibase = hash.as_multibase().to_stack_string();
-        match self.repo.set(r.as_str(), multibase.as_bytes()).await {
+        match self.repo.set(&r.to_string(), multibase.as_bytes()).await {
             Ok(()) => Ok(()),
             Err(SetNamedError::Access(e)) => Err(SetRefError::Access(e)),
             Err(SetNamedError::Internal(e)) => Err(SetRefError::Internal(e)),
+        }?;
+
+        if !r.is_head() {
+            self.add_to_index(r).await?;
         }
+
+        Ok(())
     }
 
     async fn delete(&self, r: &BlockRef) -> Result<(), DeleteRefError> {
-        match self.repo.delete(r.as_str()).await {
+        match self.repo.delete(&r.to_string()).await {
             Ok(()) => Ok(()),
             Err(DeleteNamedError::Access(e)) => Err(DeleteRefError::Access(e)),
             Err(DeleteNamedError::Internal(e)) => Err(DeleteRefError::Internal(e)),
+        }?;
+
+        if !r.is_head() {
+            self.remove_from_index(r).await?;
         }
+
+        Ok(())
+    }
+
+    async fn list(&self) -> Result<Vec<BlockRef>, ListRefsError> {
+        let mut refs = Vec::new();
+
+        match self.repo.get(&BlockRef::Head.to_string()).await {
+            Ok(_) => refs.push(BlockRef::Head),
+            Err(GetNamedError::NotFound(_)) => {}
+            Err(GetNamedError::Access(e)) => return Err(ListRefsError::Access(e)),
+            Err(GetNamedError::Internal(e)) => return Err(ListRefsError::Internal(e)),
+        }
+
+        match self.read_index().await {
+            Ok(mut index) => refs.append(&mut index),
+            Err(GetNamedError::Access(e)) => return Err(ListRefsError::Access(e)),
+            Err(e) => return Err(ListRefsError::Internal(e.int_err())),
+        }
+
+        Ok(refs)
     }
 }
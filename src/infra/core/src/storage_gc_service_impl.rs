@@ -0,0 +1,197 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use dill::*;
+use futures::TryStreamExt;
+use internal_error::ResultIntoInternal;
+use kamu_core::*;
+use opendatafabric::*;
+use time_source::SystemTimeSource;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub struct StorageGcServiceImpl {
+    dataset_repo: Arc<dyn DatasetRepository>,
+    dataset_action_authorizer: Arc<dyn auth::DatasetActionAuthorizer>,
+    time_source: Arc<dyn SystemTimeSource>,
+}
+
+#[component(pub)]
+#[interface(dyn StorageGcService)]
+impl StorageGcServiceImpl {
+    pub fn new(
+        dataset_repo: Arc<dyn DatasetRepository>,
+        dataset_action_authorizer: Arc<dyn auth::DatasetActionAuthorizer>,
+        time_source: Arc<dyn SystemTimeSource>,
+    ) -> Self {
+        Self {
+            dataset_repo,
+            dataset_action_authorizer,
+            time_source,
+        }
+    }
+
+    /// Walks the chain from every reference and collects physical hashes of
+    /// all data slices and checkpoints that are still in use
+    async fn collect_reachable_objects(
+        &self,
+        dataset: &dyn Dataset,
+    ) -> Result<(HashSet<Multihash>, HashSet<Multihash>), InternalError> {
+        let chain = dataset.as_metadata_chain();
+
+        let mut visited_blocks = HashSet::new();
+        let mut data = HashSet::new();
+        let mut checkpoints = HashSet::new();
+
+        for (_, head) in chain.list_refs().await? {
+            let mut blocks = chain.iter_blocks_interval(&head, None, false);
+
+            while let Some((hash, block)) = blocks.try_next().await.int_err()? {
+                // Ancestors of an already visited block were visited too
+                if !visited_blocks.insert(hash) {
+                    break;
+                }
+
+                let (new_data, new_checkpoint) = match block.event {
+                    MetadataEvent::AddData(e) => (e.new_data, e.new_checkpoint),
+                    MetadataEvent::ExecuteTransform(e) => (e.new_data, e.new_checkpoint),
+                    _ => continue,
+                };
+
+                if let Some(slice) = new_data {
+                    data.insert(slice.physical_hash);
+                }
+                if let Some(checkpoint) = new_checkpoint {
+                    checkpoints.insert(checkpoint.physical_hash);
+                }
+            }
+        }
+
+        Ok((data, checkpoints))
+    }
+
+    async fn collect_unreferenced(
+        &self,
+        repo: &dyn ObjectRepository,
+        kind: StorageObjectKind,
+        reachable: &HashSet<Multihash>,
+        options: &StorageGcOptions,
+    ) -> Result<Vec<UnreferencedObject>, StorageGcError> {
+        let cutoff = self.time_source.now() - options.grace_period;
+
+        let mut unreferenced = Vec::new();
+
+        for object in repo.list().await? {
+            if reachable.contains(&object.hash) {
+                continue;
+            }
+
+            // Object may belong to a commit that is still in progress
+            if let Some(last_modified) = object.last_modified
+                && last_modified > cutoff
+            {
+                tracing::debug!(
+                    hash = %object.hash,
+                    %last_modified,
+                    "Skipping unreferenced object within grace period",
+                );
+                continue;
+            }
+
+            if !options.dry_run {
+                tracing::info!(hash = %object.hash, ?kind, "Deleting unreferenced object");
+                repo.delete(&object.hash).await?;
+            }
+
+            unreferenced.push(UnreferencedObject {
+                kind,
+                hash: object.hash,
+                size: object.size,
+            });
+        }
+
+        Ok(unreferenced)
+    }
+}
+
+#[async_trait::async_trait]
+impl StorageGcService for StorageGcServiceImpl {
+    #[tracing::instrument(level = "info", skip_all, fields(%dataset_handle, ?options))]
+    async fn collect_dataset_garbage(
+        &self,
+        dataset_handle: &DatasetHandle,
+        options: StorageGcOptions,
+    ) -> Result<StorageGcDatasetResult, StorageGcError> {
+        self.dataset_action_authorizer
+            .check_action_allowed(dataset_handle, auth::DatasetAction::Write)
+            .await?;
+
+        let dataset = self.dataset_repo.get_dataset_by_handle(dataset_handle);
+
+        let (reachable_data, reachable_checkpoints) =
+            self.collect_reachable_objects(dataset.as_ref()).await?;
+
+        let mut unreferenced_objects = self
+            .collect_unreferenced(
+                dataset.as_data_repo(),
+                StorageObjectKind::Data,
+                &reachable_data,
+                &options,
+            )
+            .await?;
+
+        unreferenced_objects.extend(
+            self.collect_unreferenced(
+                dataset.as_checkpoint_repo(),
+                StorageObjectKind::Checkpoint,
+                &reachable_checkpoints,
+                &options,
+            )
+            .await?,
+        );
+
+        Ok(StorageGcDatasetResult {
+            dataset_handle: dataset_handle.clone(),
+            unreferenced_objects,
+        })
+    }
+
+    #[tracing::instrument(level = "info", skip_all, fields(?options))]
+    async fn collect_garbage(
+        &self,
+        options: StorageGcOptions,
+    ) -> Result<Vec<StorageGcDatasetResult>, StorageGcError> {
+        let dataset_handles: Vec<_> = self.dataset_repo.get_all_datasets().try_collect().await?;
+
+        let mut results = Vec::new();
+
+        for dataset_handle in dataset_handles {
+            let allowed_actions = self
+                .dataset_action_authorizer
+                .get_allowed_actions(&dataset_handle)
+                .await;
+
+            if !allowed_actions.contains(&auth::DatasetAction::Write) {
+                continue;
+            }
+
+            results.push(
+                self.collect_dataset_garbage(&dataset_handle, options.clone())
+                    .await?,
+            );
+        }
+
+        Ok(results)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -21,14 +21,13 @@ use super::utils::smart_transfer_protocol::SmartTransferProtocolClient;
 use crate::utils::ipfs_wrapper::*;
 use crate::utils::simple_transfer_protocol::{DatasetFactoryFn, SimpleTransferProtocol};
 use crate::utils::smart_transfer_protocol::TransferOptions;
-use crate::DatasetRepositoryWriter;
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
 pub struct SyncServiceImpl {
     remote_repo_reg: Arc<dyn RemoteRepositoryRegistry>,
     dataset_repo: Arc<dyn DatasetRepository>,
-    dataset_repo_writer: Arc<dyn DatasetRepositoryWriter>,
+    create_dataset_use_case: Arc<dyn CreateDatasetUseCase>,
     dataset_action_authorizer: Arc<dyn auth::DatasetActionAuthorizer>,
     dataset_factory: Arc<dyn DatasetFactory>,
     smart_transfer_protocol: Arc<dyn SmartTransferProtocolClient>,
@@ -43,7 +42,7 @@ impl SyncServiceImpl {
     pub fn new(
         remote_repo_reg: Arc<dyn RemoteRepositoryRegistry>,
         dataset_repo: Arc<dyn DatasetRepository>,
-        dataset_repo_writer: Arc<dyn DatasetRepositoryWriter>,
+        create_dataset_use_case: Arc<dyn CreateDatasetUseCase>,
         dataset_action_authorizer: Arc<dyn auth::DatasetActionAuthorizer>,
         dataset_factory: Arc<dyn DatasetFactory>,
         smart_transfer_protocol: Arc<dyn SmartTransferProtocolClient>,
@@ -52,7 +51,7 @@ impl SyncServiceImpl {
         Self {
             remote_repo_reg,
             dataset_repo,
-            dataset_repo_writer,
+            create_dataset_use_case,
             dataset_action_authorizer,
             dataset_factory,
             smart_transfer_protocol,
@@ -145,12 +144,19 @@ impl SyncServiceImpl {
                     }
                     Err(GetDatasetError::NotFound(_)) if create_if_not_exists => {
                         let alias = local_ref.alias().unwrap().clone();
-                        let repo_writer = self.dataset_repo_writer.clone();
+                        let create_dataset_use_case = self.create_dataset_use_case.clone();
                         Ok((
                             None,
                             Some(Box::new(move |seed_block| {
                                 Box::pin(async move {
-                                    repo_writer.create_dataset(&alias, seed_block).await
+                                    // TODO: Private Datasets: Read the visibility parameter
+                                    create_dataset_use_case
+                                        .execute(
+                                            &alias,
+                                            seed_block,
+                                            CreateDatasetUseCaseOptions::default(),
+                                        )
+                                        .await
                                 })
                             })),
                         ))
@@ -219,6 +225,7 @@ impl SyncServiceImpl {
                 validation,
                 trust_source_hashes,
                 opts.force,
+                opts.delete_missing_refs,
                 listener,
             )
             .await
@@ -247,6 +254,7 @@ impl SyncServiceImpl {
                 listener,
                 TransferOptions {
                     force_update_if_diverged: opts.force,
+                    delete_missing_refs: opts.delete_missing_refs,
                     ..Default::default()
                 },
             )
@@ -257,7 +265,7 @@ impl SyncServiceImpl {
         &'a self,
         src: &SyncRef,
         dst_url: &Url,
-        force: bool,
+        opts: SyncOptions,
         listener: Arc<dyn SyncListener>,
     ) -> Result<SyncResult, SyncError> {
         let src_dataset = self.get_dataset_reader(src).await?;
@@ -291,7 +299,8 @@ impl SyncServiceImpl {
                 maybe_dst_head.as_ref(),
                 listener,
                 TransferOptions {
-                    force_update_if_diverged: force,
+                    force_update_if_diverged: opts.force,
+                    delete_missing_refs: opts.delete_missing_refs,
                     ..Default::default()
                 },
             )
@@ -600,7 +609,7 @@ impl SyncServiceImpl {
             }
             // * -> odf
             (_, SyncRef::Remote(dst_url)) if dst_url.is_odf_protocol() => {
-                self.sync_smart_push_transfer_protocol(&src, dst_url.as_ref(), opts.force, listener)
+                self.sync_smart_push_transfer_protocol(&src, dst_url.as_ref(), opts, listener)
                     .await
             }
             // * -> *
@@ -249,6 +249,7 @@ impl SetPollingSourceBuilder {
                 read: ReadStep::GeoJson(ReadStepGeoJson { schema: None }),
                 preprocess: None,
                 merge: MergeStrategy::Append(MergeStrategyAppend {}),
+                watermark: None,
             },
         }
     }
@@ -294,6 +295,14 @@ impl SetPollingSourceBuilder {
         self
     }
 
+    pub fn watermark(mut self, watermark_strategy: impl Into<WatermarkStrategy>) -> Self {
+        self.v = SetPollingSource {
+            watermark: Some(watermark_strategy.into()),
+            ..self.v
+        };
+        self
+    }
+
     pub fn build(self) -> SetPollingSource {
         self.v
     }
@@ -319,6 +328,7 @@ impl AddPushSourceBuilder {
                 .into(),
                 preprocess: None,
                 merge: MergeStrategy::Append(MergeStrategyAppend {}),
+                watermark: None,
             },
         }
     }
@@ -365,6 +375,14 @@ impl AddPushSourceBuilder {
         self
     }
 
+    pub fn watermark(mut self, watermark_strategy: impl Into<WatermarkStrategy>) -> Self {
+        self.v = AddPushSource {
+            watermark: Some(watermark_strategy.into()),
+            ..self.v
+        };
+        self
+    }
+
     pub fn build(self) -> AddPushSource {
         self.v
     }
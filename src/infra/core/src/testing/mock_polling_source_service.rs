@@ -98,6 +98,7 @@ impl MockPollingIngestService {
                             }),
                             preprocess: None,
                             merge: MergeStrategy::Append(MergeStrategyAppend {}),
+                            watermark: None,
                         },
                         sequence_number: 0,
                     },
@@ -56,6 +56,7 @@ impl TransformServiceImpl {
         request: TransformRequestExt,
         commit_fn: CommitFn,
         listener: Arc<dyn TransformListener>,
+        cancellation_token: &CancellationToken,
     ) -> Result<TransformResult, TransformError>
     where
         CommitFn: FnOnce(TransformRequestExt, TransformResponseExt) -> Fut,
@@ -65,8 +66,14 @@ impl TransformServiceImpl {
 
         listener.begin();
 
-        match Self::do_transform_inner(engine_provisioner, request, commit_fn, listener.clone())
-            .await
+        match Self::do_transform_inner(
+            engine_provisioner,
+            request,
+            commit_fn,
+            listener.clone(),
+            cancellation_token,
+        )
+        .await
         {
             Ok(res) => {
                 tracing::info!("Transform successful");
@@ -87,11 +94,14 @@ impl TransformServiceImpl {
         request: TransformRequestExt,
         commit_fn: CommitFn,
         listener: Arc<dyn TransformListener>,
+        cancellation_token: &CancellationToken,
     ) -> Result<TransformResult, TransformError>
     where
         CommitFn: FnOnce(TransformRequestExt, TransformResponseExt) -> Fut,
         Fut: futures::Future<Output = Result<TransformResult, TransformError>>,
     {
+        cancellation_token.check_cancelled()?;
+
         let engine = engine_provisioner
             .provision_engine(
                 match request.transform {
@@ -101,13 +111,37 @@ impl TransformServiceImpl {
             )
             .await?;
 
-        let response = engine.execute_transform(request.clone()).await?;
+        let response = engine
+            .execute_transform(request.clone(), cancellation_token)
+            .await?;
         assert_eq!(
             response.new_offset_interval.is_some(),
             response.new_data.is_some()
         );
 
-        commit_fn(request, response).await
+        listener.on_log_files(&response.log_files);
+
+        // Last point where the transform can be stopped without leaving a partial
+        // commit behind
+        cancellation_token.check_cancelled()?;
+
+        let records_read = request
+            .inputs
+            .iter()
+            .filter_map(|input| {
+                let new_offset = input.new_offset?;
+                Some(new_offset - input.prev_offset.map_or(0, |prev| prev + 1) + 1)
+            })
+            .sum();
+        let records_written = response
+            .new_offset_interval
+            .as_ref()
+            .map_or(0, |iv| iv.end - iv.start + 1);
+
+        let res = commit_fn(request, response).await?;
+        listener.on_records_processed(records_read, records_written);
+
+        Ok(res)
     }
 
     async fn commit_execute_transform(
@@ -124,31 +158,33 @@ impl TransformServiceImpl {
             tracing::warn!("Engine did not produce a schema. In future this will become an error.");
         };
 
-        if let Some(prev_schema) = request.schema {
-            // Validate schema
-            if let Some(new_schema) = response.output_schema {
-                DataWriterDataFusion::validate_output_schema_equivalence(&prev_schema, &new_schema)
-                    .int_err()?;
+        // Set schema upon first transform or evolve it when the output schema
+        // changes in a compatible way, e.g. when an input got a new column
+        let new_schema = match (request.schema, response.output_schema) {
+            (_, None) => None,
+            (None, Some(output_schema)) => Some(output_schema),
+            (Some(prev_schema), Some(output_schema)) => {
+                DataWriterDataFusion::evolve_output_schema(&prev_schema, &output_schema)
+                    .int_err()?
             }
-        } else {
-            // Set schema upon first transform
-            if let Some(new_schema) = response.output_schema {
-                // TODO: make schema commit atomic with data
-                let commit_schema_result = dataset
-                    .commit_event(
-                        SetDataSchema::new(&new_schema).into(),
-                        CommitOpts {
-                            block_ref: &request.block_ref,
-                            system_time: Some(request.system_time),
-                            prev_block_hash: Some(Some(&new_head)),
-                            check_object_refs: false,
-                            update_block_ref: true,
-                        },
-                    )
-                    .await?;
+        };
 
-                new_head = commit_schema_result.new_head;
-            }
+        if let Some(new_schema) = new_schema {
+            // TODO: make schema commit atomic with data
+            let commit_schema_result = dataset
+                .commit_event(
+                    SetDataSchema::new(&new_schema).into(),
+                    CommitOpts {
+                        block_ref: &request.block_ref,
+                        system_time: Some(request.system_time),
+                        prev_block_hash: Some(Some(&new_head)),
+                        check_object_refs: false,
+                        update_block_ref: true,
+                    },
+                )
+                .await?;
+
+            new_head = commit_schema_result.new_head;
         }
 
         let params = ExecuteTransformParams {
@@ -372,12 +408,26 @@ impl TransformServiceImpl {
         let dataset = self.dataset_repo.get_dataset_by_handle(&dataset_handle);
         let input_chain = dataset.as_metadata_chain();
 
-        // Find schema
+        // Find schema as of the last block being processed, so that the same slices
+        // are read under the same schema when the transform is verified later
         // TODO: Make single-pass via multi-visitor
-        let schema = dataset
-            .as_metadata_chain()
-            .accept_one(SearchSetDataSchemaVisitor::new())
-            .await
+        let schema_visitor = match query_input
+            .new_block_hash
+            .as_ref()
+            .or(query_input.prev_block_hash.as_ref())
+        {
+            Some(hash) => {
+                input_chain
+                    .accept_one_by_hash(hash, SearchSetDataSchemaVisitor::new())
+                    .await
+            }
+            None => {
+                input_chain
+                    .accept_one(SearchSetDataSchemaVisitor::new())
+                    .await
+            }
+        };
+        let schema = schema_visitor
             .int_err()?
             .into_event()
             .map(|e| e.schema_as_arrow())
@@ -491,11 +541,10 @@ impl TransformServiceImpl {
             None => None,
         };
 
-        let (source, set_vocab, schema, blocks, finished_range) = {
+        let (source, set_vocab, schemas, blocks, finished_range) = {
             // TODO: Support dataset evolution
             let mut set_transform_visitor = SearchSetTransformVisitor::new();
             let mut set_vocab_visitor = SearchSetVocabVisitor::new();
-            let mut set_data_schema_visitor = SearchSetDataSchemaVisitor::new();
 
             type Flag = MetadataEventTypeFlags;
             type Decision = MetadataVisitorDecision;
@@ -503,6 +552,8 @@ impl TransformServiceImpl {
             struct ExecuteTransformCollectorVisitor {
                 tail_sequence_number: Option<u64>,
                 blocks: Vec<(Multihash, MetadataBlock)>,
+                // Newest first, including the schema in effect at the start of the range
+                schemas: Vec<(u64, SetDataSchema)>,
                 finished_range: bool,
             }
 
@@ -510,14 +561,30 @@ impl TransformServiceImpl {
                 ExecuteTransformCollectorVisitor {
                     tail_sequence_number,
                     blocks: Vec::new(),
+                    schemas: Vec::new(),
                     finished_range: false,
                 },
-                Decision::NextOfType(Flag::EXECUTE_TRANSFORM),
+                Decision::NextOfType(Flag::EXECUTE_TRANSFORM | Flag::SET_DATA_SCHEMA),
                 |state, hash, block| {
+                    if let MetadataEvent::SetDataSchema(e) = &block.event {
+                        state.schemas.push((block.sequence_number, e.clone()));
+
+                        return if state.finished_range
+                            || Some(block.sequence_number) <= state.tail_sequence_number
+                        {
+                            state.finished_range = true;
+
+                            Decision::Stop
+                        } else {
+                            Decision::NextOfType(Flag::EXECUTE_TRANSFORM | Flag::SET_DATA_SCHEMA)
+                        };
+                    }
+
+                    // Past the range we only look for the schema in effect at its start
                     if Some(block.sequence_number) < state.tail_sequence_number {
                         state.finished_range = true;
 
-                        return Decision::Stop;
+                        return Decision::NextOfType(Flag::SET_DATA_SCHEMA);
                     };
 
                     let block_flag = Flag::from(&block.event);
@@ -529,9 +596,9 @@ impl TransformServiceImpl {
                     if Some(block.sequence_number) == state.tail_sequence_number {
                         state.finished_range = true;
 
-                        Decision::Stop
+                        Decision::NextOfType(Flag::SET_DATA_SCHEMA)
                     } else {
-                        Decision::NextOfType(Flag::EXECUTE_TRANSFORM)
+                        Decision::NextOfType(Flag::EXECUTE_TRANSFORM | Flag::SET_DATA_SCHEMA)
                     }
                 },
             );
@@ -540,7 +607,6 @@ impl TransformServiceImpl {
                 .accept(&mut [
                     &mut set_transform_visitor,
                     &mut set_vocab_visitor,
-                    &mut set_data_schema_visitor,
                     &mut execute_transform_collector_visitor,
                 ])
                 .await
@@ -548,6 +614,7 @@ impl TransformServiceImpl {
 
             let ExecuteTransformCollectorVisitor {
                 blocks,
+                schemas,
                 finished_range,
                 ..
             } = execute_transform_collector_visitor.into_state();
@@ -555,12 +622,7 @@ impl TransformServiceImpl {
             (
                 set_transform_visitor.into_event(),
                 set_vocab_visitor.into_event(),
-                set_data_schema_visitor
-                    .into_event()
-                    .as_ref()
-                    .map(SetDataSchema::schema_as_arrow)
-                    .transpose() // Option<Result<SchemaRef, E>> -> Result<Option<SchemaRef>, E>
-                    .int_err()?,
+                schemas,
                 blocks,
                 finished_range,
             )
@@ -612,6 +674,15 @@ impl TransformServiceImpl {
         for (block_hash, block) in blocks.into_iter().rev() {
             let block_t = block.as_typed::<ExecuteTransform>().unwrap();
 
+            // Output schema may have evolved, so we take the one that was in effect
+            // when the block was produced
+            let schema = schemas
+                .iter()
+                .find(|(sequence_number, _)| *sequence_number < block.sequence_number)
+                .map(|(_, e)| e.schema_as_arrow())
+                .transpose()
+                .int_err()?;
+
             let inputs = futures::stream::iter(&block_t.event.query_inputs)
                 .then(|slice| {
                     let alias = input_aliases.get(&slice.dataset_id).unwrap();
@@ -640,7 +711,7 @@ impl TransformServiceImpl {
                     head: block_t.prev_block_hash.unwrap().clone(),
                     transform: source.transform.clone(),
                     system_time: block.system_time,
-                    schema: schema.clone(),
+                    schema,
                     prev_offset: block_t.event.prev_offset,
                     inputs,
                     vocab: set_vocab.clone().unwrap_or_default().into(),
@@ -685,6 +756,7 @@ impl TransformServiceImpl {
                         Self::commit_execute_transform(dataset_repo, request, response).await
                     },
                     listener,
+                    &options.cancellation_token,
                 )
                 .await
             }
@@ -718,6 +790,7 @@ impl TransformServiceImpl {
                         &dataset_handle,
                         CompactionOptions {
                             keep_metadata_only: true,
+                            cancellation_token: options.cancellation_token.clone(),
                             ..Default::default()
                         },
                         None,
@@ -731,6 +804,7 @@ impl TransformServiceImpl {
                         dataset_ref.clone(),
                         TransformOptions {
                             reset_derivatives_on_diverged_input: false,
+                            cancellation_token: options.cancellation_token,
                         },
                         Some(listener),
                     )
@@ -791,10 +865,10 @@ impl TransformService for TransformServiceImpl {
             let f = match self.dataset_repo.resolve_dataset_ref(dataset_ref).await {
                 Ok(hdl) => {
                     let maybe_listener = multi_listener.begin_transform(&hdl);
-                    self.transform_impl(hdl.into(), options, maybe_listener)
+                    self.transform_impl(hdl.into(), options.clone(), maybe_listener)
                 }
                 // Relying on this call to fail to avoid boxing the futures
-                Err(_) => self.transform_impl(dataset_ref.clone(), options, None),
+                Err(_) => self.transform_impl(dataset_ref.clone(), options.clone(), None),
             };
             futures.push(f);
         }
@@ -890,6 +964,7 @@ impl TransformService for TransformServiceImpl {
                     Ok(result)
                 },
                 transform_listener,
+                &CancellationToken::new(),
             )
             .await?;
 
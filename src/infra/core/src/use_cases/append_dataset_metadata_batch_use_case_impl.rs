@@ -58,6 +58,7 @@ impl AppendDatasetMetadataBatchUseCase for AppendDatasetMetadataBatchUseCaseImpl
         let metadata_chain = dataset.as_metadata_chain();
 
         let mut new_upstream_ids: Vec<opendatafabric::DatasetID> = vec![];
+        let mut is_polling_source_disabled = false;
 
         for (hash, block) in new_blocks {
             tracing::debug!(sequence_numer = %block.sequence_number, hash = %hash, "Appending block");
@@ -76,6 +77,17 @@ impl AppendDatasetMetadataBatchUseCase for AppendDatasetMetadataBatchUseCaseImpl
                 }
             }
 
+            // Only the latest polling source state of the batch matters
+            match &block.event {
+                opendatafabric::MetadataEvent::DisablePollingSource(_) => {
+                    is_polling_source_disabled = true;
+                }
+                opendatafabric::MetadataEvent::SetPollingSource(_) => {
+                    is_polling_source_disabled = false;
+                }
+                _ => {}
+            }
+
             metadata_chain
                 .append(
                     block,
@@ -103,12 +115,16 @@ impl AppendDatasetMetadataBatchUseCase for AppendDatasetMetadataBatchUseCaseImpl
             )
             .await?;
 
-        if !new_upstream_ids.is_empty() {
-            let summary = dataset
-                .get_summary(GetSummaryOpts::default())
-                .await
-                .int_err()?;
+        if new_upstream_ids.is_empty() && !is_polling_source_disabled {
+            return Ok(());
+        }
+
+        let summary = dataset
+            .get_summary(GetSummaryOpts::default())
+            .await
+            .int_err()?;
 
+        if !new_upstream_ids.is_empty() {
             self.outbox
                 .post_message(
                     MESSAGE_PRODUCER_KAMU_CORE_DATASET_SERVICE,
@@ -120,6 +136,15 @@ impl AppendDatasetMetadataBatchUseCase for AppendDatasetMetadataBatchUseCaseImpl
                 .await?;
         }
 
+        if is_polling_source_disabled {
+            self.outbox
+                .post_message(
+                    MESSAGE_PRODUCER_KAMU_CORE_DATASET_SERVICE,
+                    DatasetLifecycleMessage::polling_source_disabled(summary.id),
+                )
+                .await?;
+        }
+
         Ok(())
     }
 }
@@ -61,6 +61,8 @@ impl CommitDatasetEventUseCase for CommitDatasetEventUseCaseImpl {
 
         let dataset = self.dataset_repo.get_dataset_by_handle(dataset_handle);
 
+        let is_polling_source_disabled = matches!(event, MetadataEvent::DisablePollingSource(_));
+
         let commit_result = dataset.commit_event(event, opts).await?;
 
         if !commit_result.new_upstream_ids.is_empty() {
@@ -75,6 +77,15 @@ impl CommitDatasetEventUseCase for CommitDatasetEventUseCaseImpl {
                 .await?;
         }
 
+        if is_polling_source_disabled {
+            self.outbox
+                .post_message(
+                    MESSAGE_PRODUCER_KAMU_CORE_DATASET_SERVICE,
+                    DatasetLifecycleMessage::polling_source_disabled(dataset_handle.id.clone()),
+                )
+                .await?;
+        }
+
         Ok(commit_result)
     }
 }
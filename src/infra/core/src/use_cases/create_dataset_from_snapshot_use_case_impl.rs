@@ -76,6 +76,11 @@ impl CreateDatasetFromSnapshotUseCase for CreateDatasetFromSnapshotUseCaseImpl {
                         CurrentAccountSubject::Logged(l) => l.account_id.clone(),
                     },
                     options.dataset_visibility,
+                    create_dataset_result
+                        .dataset_handle
+                        .alias
+                        .dataset_name
+                        .clone(),
                 ),
             )
             .await?;
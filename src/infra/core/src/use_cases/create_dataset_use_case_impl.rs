@@ -73,6 +73,7 @@ impl CreateDatasetUseCase for CreateDatasetUseCaseImpl {
                         CurrentAccountSubject::Logged(l) => l.account_id.clone(),
                     },
                     options.dataset_visibility,
+                    create_result.dataset_handle.alias.dataset_name.clone(),
                 ),
             )
             .await?;
@@ -0,0 +1,81 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::sync::Arc;
+
+use dill::{component, interface};
+use kamu_core::auth::{DatasetAction, DatasetActionAuthorizer};
+use kamu_core::{
+    BlockRef,
+    DatasetRepository,
+    DeleteDatasetRefError,
+    DeleteDatasetRefUseCase,
+    MetadataChainExt,
+    ReadOnlyRefError,
+    RefNotFoundError,
+};
+use opendatafabric::DatasetHandle;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[component(pub)]
+#[interface(dyn DeleteDatasetRefUseCase)]
+pub struct DeleteDatasetRefUseCaseImpl {
+    dataset_repo: Arc<dyn DatasetRepository>,
+    dataset_action_authorizer: Arc<dyn DatasetActionAuthorizer>,
+}
+
+impl DeleteDatasetRefUseCaseImpl {
+    pub fn new(
+        dataset_repo: Arc<dyn DatasetRepository>,
+        dataset_action_authorizer: Arc<dyn DatasetActionAuthorizer>,
+    ) -> Self {
+        Self {
+            dataset_repo,
+            dataset_action_authorizer,
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl DeleteDatasetRefUseCase for DeleteDatasetRefUseCaseImpl {
+    async fn execute(
+        &self,
+        dataset_handle: &DatasetHandle,
+        block_ref: &BlockRef,
+    ) -> Result<(), DeleteDatasetRefError> {
+        if block_ref.is_head() {
+            return Err(ReadOnlyRefError {
+                block_ref: block_ref.clone(),
+                reason: "head cannot be deleted".to_string(),
+            }
+            .into());
+        }
+
+        self.dataset_action_authorizer
+            .check_action_allowed(dataset_handle, DatasetAction::Write)
+            .await?;
+
+        let dataset = self.dataset_repo.get_dataset_by_handle(dataset_handle);
+        let chain = dataset.as_metadata_chain();
+
+        if chain.try_get_ref(block_ref).await?.is_none() {
+            return Err(RefNotFoundError {
+                block_ref: block_ref.clone(),
+            }
+            .into());
+        }
+
+        chain.as_reference_repo().delete(block_ref).await?;
+
+        Ok(())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
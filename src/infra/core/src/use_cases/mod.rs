@@ -11,12 +11,16 @@ mod append_dataset_metadata_batch_use_case_impl;
 mod commit_dataset_event_use_case_impl;
 mod create_dataset_from_snapshot_use_case_impl;
 mod create_dataset_use_case_impl;
+mod delete_dataset_ref_use_case_impl;
 mod delete_dataset_use_case_impl;
 mod rename_dataset_use_case_impl;
+mod set_dataset_ref_use_case_impl;
 
 pub use append_dataset_metadata_batch_use_case_impl::*;
 pub use commit_dataset_event_use_case_impl::*;
 pub use create_dataset_from_snapshot_use_case_impl::*;
 pub use create_dataset_use_case_impl::*;
+pub use delete_dataset_ref_use_case_impl::*;
 pub use delete_dataset_use_case_impl::*;
 pub use rename_dataset_use_case_impl::*;
+pub use set_dataset_ref_use_case_impl::*;
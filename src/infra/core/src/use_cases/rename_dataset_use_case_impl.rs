@@ -11,7 +11,15 @@ use std::sync::Arc;
 
 use dill::{component, interface};
 use kamu_core::auth::{DatasetAction, DatasetActionAuthorizer};
-use kamu_core::{DatasetRepository, GetDatasetError, RenameDatasetError, RenameDatasetUseCase};
+use kamu_core::{
+    DatasetLifecycleMessage,
+    DatasetRepository,
+    GetDatasetError,
+    RenameDatasetError,
+    RenameDatasetUseCase,
+    MESSAGE_PRODUCER_KAMU_CORE_DATASET_SERVICE,
+};
+use messaging_outbox::{Outbox, OutboxExt};
 use opendatafabric::{DatasetName, DatasetRef};
 
 use crate::DatasetRepositoryWriter;
@@ -22,6 +30,7 @@ pub struct RenameDatasetUseCaseImpl {
     dataset_repo: Arc<dyn DatasetRepository>,
     dataset_repo_writer: Arc<dyn DatasetRepositoryWriter>,
     dataset_action_authorizer: Arc<dyn DatasetActionAuthorizer>,
+    outbox: Arc<dyn Outbox>,
 }
 
 #[component(pub)]
@@ -31,11 +40,13 @@ impl RenameDatasetUseCaseImpl {
         dataset_repo: Arc<dyn DatasetRepository>,
         dataset_repo_writer: Arc<dyn DatasetRepositoryWriter>,
         dataset_action_authorizer: Arc<dyn DatasetActionAuthorizer>,
+        outbox: Arc<dyn Outbox>,
     ) -> Self {
         Self {
             dataset_repo,
             dataset_repo_writer,
             dataset_action_authorizer,
+            outbox,
         }
     }
 }
@@ -61,6 +72,13 @@ impl RenameDatasetUseCase for RenameDatasetUseCaseImpl {
             .rename_dataset(&dataset_handle, new_name)
             .await?;
 
+        self.outbox
+            .post_message(
+                MESSAGE_PRODUCER_KAMU_CORE_DATASET_SERVICE,
+                DatasetLifecycleMessage::renamed(dataset_handle.id.clone(), new_name.clone()),
+            )
+            .await?;
+
         Ok(())
     }
 }
@@ -0,0 +1,100 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::sync::Arc;
+
+use dill::{component, interface};
+use kamu_core::auth::{DatasetAction, DatasetActionAuthorizer};
+use kamu_core::{
+    BlockRef,
+    DatasetRepository,
+    ReadOnlyRefError,
+    SetDatasetRefError,
+    SetDatasetRefUseCase,
+    SetRefError,
+    SetRefOpts,
+};
+use opendatafabric::{DatasetHandle, Multihash};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[component(pub)]
+#[interface(dyn SetDatasetRefUseCase)]
+pub struct SetDatasetRefUseCaseImpl {
+    dataset_repo: Arc<dyn DatasetRepository>,
+    dataset_action_authorizer: Arc<dyn DatasetActionAuthorizer>,
+}
+
+impl SetDatasetRefUseCaseImpl {
+    pub fn new(
+        dataset_repo: Arc<dyn DatasetRepository>,
+        dataset_action_authorizer: Arc<dyn DatasetActionAuthorizer>,
+    ) -> Self {
+        Self {
+            dataset_repo,
+            dataset_action_authorizer,
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl SetDatasetRefUseCase for SetDatasetRefUseCaseImpl {
+    async fn execute(
+        &self,
+        dataset_handle: &DatasetHandle,
+        block_ref: &BlockRef,
+        block_hash: &Multihash,
+    ) -> Result<(), SetDatasetRefError> {
+        if block_ref.is_head() {
+            return Err(ReadOnlyRefError {
+                block_ref: block_ref.clone(),
+                reason: "head can only be moved by commits or resets".to_string(),
+            }
+            .into());
+        }
+
+        self.dataset_action_authorizer
+            .check_action_allowed(dataset_handle, DatasetAction::Write)
+            .await?;
+
+        let dataset = self.dataset_repo.get_dataset_by_handle(dataset_handle);
+        let chain = dataset.as_metadata_chain();
+
+        // Tags are immutable, so they are only set when they don't exist yet
+        let is_tag = matches!(block_ref, BlockRef::Tag(_));
+
+        match chain
+            .set_ref(
+                block_ref,
+                block_hash,
+                SetRefOpts {
+                    validate_block_present: true,
+                    check_ref_is: if is_tag { Some(None) } else { None },
+                },
+            )
+            .await
+        {
+            Ok(()) => {}
+            // Re-creating a tag pointing at the same block is a no-op
+            Err(SetRefError::CASFailed(e)) if is_tag && e.actual.as_ref() == Some(block_hash) => {}
+            Err(SetRefError::CASFailed(_)) if is_tag => {
+                return Err(ReadOnlyRefError {
+                    block_ref: block_ref.clone(),
+                    reason: "tags are immutable, delete the tag first to re-create it".to_string(),
+                }
+                .into())
+            }
+            Err(e) => return Err(e.into()),
+        }
+
+        Ok(())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
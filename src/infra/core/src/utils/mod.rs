@@ -11,6 +11,7 @@ pub mod cached_object;
 pub mod datasets_filtering;
 pub mod docker_images;
 pub mod ipfs_wrapper;
+pub mod operation_dir;
 pub mod s3_context;
 pub mod simple_transfer_protocol;
 pub mod smart_transfer_protocol;
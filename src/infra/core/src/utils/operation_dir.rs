@@ -0,0 +1,60 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::path::{Path, PathBuf};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Owns a directory with files staged by an operation and removes it unless
+/// the operation is marked as finished, so that files staged by an operation
+/// that failed or stopped early on cancellation are discarded
+#[derive(Debug)]
+pub struct OperationDirGuard {
+    path: PathBuf,
+    finished: bool,
+}
+
+impl OperationDirGuard {
+    pub fn new(path: PathBuf) -> Self {
+        Self {
+            path,
+            finished: false,
+        }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Marks the operation as successfully finished, leaving the directory in
+    /// place
+    pub fn finish(mut self) {
+        self.finished = true;
+    }
+}
+
+impl Drop for OperationDirGuard {
+    fn drop(&mut self) {
+        if self.finished || !self.path.exists() {
+            return;
+        }
+
+        tracing::info!(path = %self.path.display(), "Discarding files of unfinished operation");
+
+        if let Err(err) = std::fs::remove_dir_all(&self.path) {
+            tracing::warn!(
+                path = %self.path.display(),
+                error = ?err,
+                "Failed to discard files of unfinished operation",
+            );
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -15,7 +15,7 @@ use aws_sdk_s3::operation::delete_object::{DeleteObjectError, DeleteObjectOutput
 use aws_sdk_s3::operation::get_object::{GetObjectError, GetObjectOutput};
 use aws_sdk_s3::operation::head_object::{HeadObjectError, HeadObjectOutput};
 use aws_sdk_s3::operation::put_object::{PutObjectError, PutObjectOutput};
-use aws_sdk_s3::types::{CommonPrefix, Delete, ObjectIdentifier};
+use aws_sdk_s3::types::{CommonPrefix, Delete, Object, ObjectIdentifier};
 use aws_sdk_s3::Client;
 use internal_error::{ErrorIntoInternal, InternalError, ResultIntoInternal};
 use tokio::io::AsyncRead;
@@ -280,6 +280,35 @@ impl S3Context {
         Ok(list_objects_resp.common_prefixes.unwrap_or_default())
     }
 
+    pub async fn list_objects(&self, key_prefix: &str) -> Result<Vec<Object>, InternalError> {
+        let mut objects = Vec::new();
+        let mut continuation_token = None;
+
+        // ListObjectsV2Request returns at most S3Context::MAX_LISTED_OBJECTS=1000 items
+        loop {
+            let list_response = self
+                .client
+                .list_objects_v2()
+                .bucket(&self.bucket)
+                .prefix(self.get_key(key_prefix))
+                .max_keys(Self::MAX_LISTED_OBJECTS)
+                .set_continuation_token(continuation_token)
+                .send()
+                .await
+                .int_err()?;
+
+            objects.extend(list_response.contents.unwrap_or_default());
+
+            if list_response.is_truncated {
+                continuation_token = list_response.next_continuation_token;
+            } else {
+                break;
+            }
+        }
+
+        Ok(objects)
+    }
+
     pub async fn recursive_delete(&self, key_prefix: String) -> Result<(), InternalError> {
         // ListObjectsV2Request returns at most S3Context::MAX_LISTED_OBJECTS=1000 items
         let mut has_next_page = true;
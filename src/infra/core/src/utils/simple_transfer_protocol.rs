@@ -71,6 +71,7 @@ impl SimpleTransferProtocol {
         validation: AppendValidation,
         trust_source_hashes: bool,
         force: bool,
+        delete_missing_refs: bool,
         listener: Arc<dyn SyncListener + 'static>,
     ) -> Result<SyncResult, SyncError> {
         listener.begin();
@@ -105,7 +106,20 @@ impl SimpleTransferProtocol {
         .await?;
 
         match chains_comparison {
-            CompareChainsResult::Equal => return Ok(SyncResult::UpToDate),
+            CompareChainsResult::Equal => {
+                if let Some(dst) = &maybe_dst {
+                    let src_refs = src_chain.list_refs().await?;
+                    dst.as_metadata_chain()
+                        .import_refs(
+                            &src_refs,
+                            ImportRefsOpts {
+                                delete_missing: delete_missing_refs,
+                            },
+                        )
+                        .await?;
+                }
+                return Ok(SyncResult::UpToDate);
+            }
             CompareChainsResult::LhsAhead { .. } => { /* Skip */ }
             CompareChainsResult::LhsBehind {
                 ref rhs_ahead_blocks,
@@ -135,6 +149,12 @@ impl SimpleTransferProtocol {
             }
         };
 
+        // Reject conflicting references before any blocks are transferred
+        let src_refs = src_chain.list_refs().await?;
+        if let Some(dst) = &maybe_dst {
+            dst.as_metadata_chain().check_refs_import(&src_refs).await?;
+        }
+
         let mut blocks = match chains_comparison {
             CompareChainsResult::Equal => unreachable!(),
             CompareChainsResult::LhsAhead {
@@ -182,6 +202,17 @@ impl SimpleTransferProtocol {
         )
         .await?;
 
+        // References are imported after the blocks, as references to blocks
+        // missing in the destination are skipped
+        dst.as_metadata_chain()
+            .import_refs(
+                &src_refs,
+                ImportRefsOpts {
+                    delete_missing: delete_missing_refs,
+                },
+            )
+            .await?;
+
         Ok(SyncResult::Updated {
             old_head,
             new_head: src_head,
@@ -21,6 +21,7 @@ pub use super::simple_transfer_protocol::DatasetFactoryFn;
 pub struct TransferOptions {
     pub max_parallel_transfers: usize,
     pub force_update_if_diverged: bool,
+    pub delete_missing_refs: bool,
 }
 
 impl Default for TransferOptions {
@@ -35,6 +36,7 @@ impl Default for TransferOptions {
         Self {
             max_parallel_transfers,
             force_update_if_diverged: false,
+            delete_missing_refs: false,
         }
     }
 }
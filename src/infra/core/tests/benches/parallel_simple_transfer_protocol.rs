@@ -23,6 +23,7 @@ use kamu::testing::{
 use kamu::utils::ipfs_wrapper::IpfsClient;
 use kamu::utils::simple_transfer_protocol::ENV_VAR_SIMPLE_PROTOCOL_MAX_PARALLEL_TRANSFERS;
 use kamu::{
+    CreateDatasetUseCaseImpl,
     DatasetFactoryImpl,
     DatasetRepositoryLocalFs,
     DatasetRepositoryWriter,
@@ -33,6 +34,7 @@ use kamu::{
     SyncServiceImpl,
 };
 use kamu_accounts::CurrentAccountSubject;
+use messaging_outbox::DummyOutboxImpl;
 use opendatafabric::*;
 use url::Url;
 
@@ -61,12 +63,15 @@ async fn setup_dataset(
                 .with_multi_tenant(false),
         )
         .bind::<dyn DatasetRepository, DatasetRepositoryLocalFs>()
+        .bind::<dyn DatasetRepositoryWriter, DatasetRepositoryLocalFs>()
         .add_value(RemoteReposDir::new(tmp_workspace_dir.join("repos")))
         .add::<RemoteRepositoryRegistryImpl>()
         .add::<auth::DummyOdfServerAccessTokenResolver>()
         .add::<DatasetFactoryImpl>()
         .add::<SyncServiceImpl>()
         .add::<DummySmartTransferProtocolClient>()
+        .add::<CreateDatasetUseCaseImpl>()
+        .add::<DummyOutboxImpl>()
         .add::<auth::AlwaysHappyDatasetActionAuthorizer>()
         .build();
 
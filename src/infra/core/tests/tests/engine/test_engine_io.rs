@@ -34,15 +34,17 @@ async fn test_engine_io_common<
     let run_info_dir = Arc::new(RunInfoDir::new(run_info_dir.to_path_buf()));
     let cache_dir = Arc::new(CacheDir::new(cache_dir.to_path_buf()));
 
+    let object_store_registry = Arc::new(ObjectStoreRegistryImpl::new(object_stores));
+
     let engine_provisioner = Arc::new(EngineProvisionerLocal::new(
         EngineProvisionerLocalConfig::default(),
         Arc::new(ContainerRuntime::default()),
         dataset_repo.clone(),
+        object_store_registry.clone(),
         run_info_dir.clone(),
     ));
 
     let dataset_action_authorizer = Arc::new(auth::AlwaysHappyDatasetActionAuthorizer::new());
-    let object_store_registry = Arc::new(ObjectStoreRegistryImpl::new(object_stores));
     let time_source = Arc::new(SystemTimeSourceDefault);
     let dataset_env_var_sys_env = Arc::new(DatasetKeyValueServiceSysEnv::new());
 
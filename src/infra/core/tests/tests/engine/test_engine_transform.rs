@@ -224,6 +224,10 @@ struct TestHarness {
 
 impl TestHarness {
     fn new() -> Self {
+        Self::new_with_engine_config(EngineProvisionerLocalConfig::default())
+    }
+
+    fn new_with_engine_config(engine_config: EngineProvisionerLocalConfig) -> Self {
         let tempdir = tempfile::tempdir().unwrap();
         let run_info_dir = tempdir.path().join("run");
         let cache_dir = tempdir.path().join("cache");
@@ -245,7 +249,7 @@ impl TestHarness {
                     .with_multi_tenant(false),
             )
             .bind::<dyn DatasetRepository, DatasetRepositoryLocalFs>()
-            .add_value(EngineProvisionerLocalConfig::default())
+            .add_value(engine_config)
             .add::<EngineProvisionerLocal>()
             .add_value(ObjectStoreRegistryImpl::new(vec![Arc::new(
                 ObjectStoreBuilderLocalFs::new(),
@@ -280,8 +284,14 @@ impl TestHarness {
 // TODO: Remove `test_retractions` flag once RisingWave can handle them without
 // crashing
 async fn test_transform_common(transform: Transform, test_retractions: bool) {
-    let harness = TestHarness::new();
+    test_transform_common_with_harness(TestHarness::new(), transform, test_retractions).await;
+}
 
+async fn test_transform_common_with_harness(
+    harness: TestHarness,
+    transform: Transform,
+    test_retractions: bool,
+) {
     ///////////////////////////////////////////////////////////////////////////
     // Root setup
     ///////////////////////////////////////////////////////////////////////////
@@ -607,6 +617,32 @@ async fn test_transform_with_engine_datafusion() {
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
+#[test_group::group(engine, transform, datafusion)]
+#[test_log::test(tokio::test)]
+async fn test_transform_with_engine_datafusion_in_process() {
+    test_transform_common_with_harness(
+        TestHarness::new_with_engine_config(EngineProvisionerLocalConfig {
+            datafusion_in_process: true,
+            ..Default::default()
+        }),
+        MetadataFactory::transform()
+            .engine("datafusion")
+            .query(
+                "SELECT
+                    op,
+                    event_time,
+                    city,
+                    cast(population * 10 as int) as population_x10
+                FROM root",
+            )
+            .build(),
+        true,
+    )
+    .await;
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
 // See: https://github.com/kamu-data/kamu-cli/issues/599
 #[test_group::group(containerized, engine, transform, risingwave)]
 #[ignore = "#599 Disabled for disk space issues reason"]
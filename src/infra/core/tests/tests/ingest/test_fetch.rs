@@ -17,7 +17,9 @@ use container_runtime::ContainerRuntime;
 use indoc::indoc;
 use kamu::domain::*;
 use kamu::ingest::*;
+use kamu::testing::LocalS3Server;
 use kamu::utils::docker_images::BUSYBOX;
+use kamu::utils::s3_context::S3Context;
 use kamu_datasets_services::DatasetKeyValueServiceSysEnv;
 use opendatafabric::*;
 use url::Url;
@@ -49,6 +51,7 @@ async fn test_fetch_url_file() {
                 &generate_unique_operation_id(),
                 &fetch_step,
                 None,
+                None,
                 &target_path,
                 &Utc::now(),
                 &HashMap::new(),
@@ -69,6 +72,7 @@ async fn test_fetch_url_file() {
             &generate_unique_operation_id(),
             &fetch_step,
             None,
+            None,
             &target_path,
             &Utc::now(),
             &HashMap::new(),
@@ -89,6 +93,7 @@ async fn test_fetch_url_file() {
             &mock_dataset_handle(),
             &generate_unique_operation_id(),
             &fetch_step,
+            None,
             update.source_state.as_ref(),
             &target_path,
             &Utc::now(),
@@ -107,6 +112,7 @@ async fn test_fetch_url_file() {
             &mock_dataset_handle(),
             &generate_unique_operation_id(),
             &fetch_step,
+            None,
             update.source_state.as_ref(),
             &target_path,
             &Utc::now(),
@@ -142,6 +148,7 @@ async fn test_fetch_url_http_unreachable() {
                 &generate_unique_operation_id(),
                 &fetch_step,
                 None,
+                None,
                 &target_path,
                 &Utc::now(),
                 &HashMap::new(),
@@ -177,6 +184,7 @@ async fn test_fetch_url_http_not_found() {
                 &generate_unique_operation_id(),
                 &fetch_step,
                 None,
+                None,
                 &target_path,
                 &Utc::now(),
                 &HashMap::new(),
@@ -219,6 +227,7 @@ async fn test_fetch_url_http_ok() {
             &generate_unique_operation_id(),
             &fetch_step,
             None,
+            None,
             &target_path,
             &Utc::now(),
             &HashMap::new(),
@@ -252,6 +261,7 @@ async fn test_fetch_url_http_ok() {
             &mock_dataset_handle(),
             &generate_unique_operation_id(),
             &fetch_step,
+            None,
             update.source_state.as_ref(),
             &target_path,
             &Utc::now(),
@@ -271,6 +281,7 @@ async fn test_fetch_url_http_ok() {
             &mock_dataset_handle(),
             &generate_unique_operation_id(),
             &fetch_step,
+            None,
             update.source_state.as_ref(),
             &target_path,
             &Utc::now(),
@@ -291,6 +302,7 @@ async fn test_fetch_url_http_ok() {
                 &mock_dataset_handle(),
                 &generate_unique_operation_id(),
                 &fetch_step,
+                None,
                 update.source_state.as_ref(),
                 &target_path,
                 &Utc::now(),
@@ -339,6 +351,7 @@ async fn test_fetch_url_http_env_interpolation() {
                 &generate_unique_operation_id(),
                 &fetch_step,
                 None,
+                None,
                 &target_path,
                 &Utc::now(),
                 &HashMap::new(),
@@ -357,6 +370,7 @@ async fn test_fetch_url_http_env_interpolation() {
             &generate_unique_operation_id(),
             &fetch_step,
             None,
+            None,
             &target_path,
             &Utc::now(),
             &HashMap::new(),
@@ -380,6 +394,168 @@ async fn test_fetch_url_http_env_interpolation() {
     );
 }
 
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// URL: s3
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_group::group(containerized)]
+#[tokio::test]
+async fn test_fetch_url_s3() {
+    let harness = FetchTestHarness::new();
+    let target_path = harness.temp_dir.path().join("fetched.bin");
+
+    let s3 = LocalS3Server::new().await;
+    let s3_context = S3Context::from_url(&s3.url).await;
+
+    let fetch_step = FetchStep::Url(FetchStepUrl {
+        url: s3.url.join("data.csv").unwrap().to_string(),
+        event_time: None,
+        cache: None,
+        headers: None,
+    });
+
+    // No file to fetch
+    assert_matches!(
+        harness
+            .fetch_svc
+            .fetch(
+                &mock_dataset_handle(),
+                &generate_unique_operation_id(),
+                &fetch_step,
+                None,
+                None,
+                &target_path,
+                &Utc::now(),
+                &HashMap::new(),
+                None
+            )
+            .await,
+        Err(PollingIngestError::NotFound { .. })
+    );
+    assert!(!target_path.exists());
+
+    s3_context
+        .put_object(s3_context.get_key("data.csv"), CSV_BATCH_OUTPUT.as_bytes())
+        .await
+        .unwrap();
+
+    // Normal fetch
+    let listener = Arc::new(TestListener::new());
+
+    let res = harness
+        .fetch_svc
+        .fetch(
+            &mock_dataset_handle(),
+            &generate_unique_operation_id(),
+            &fetch_step,
+            None,
+            None,
+            &target_path,
+            &Utc::now(),
+            &HashMap::new(),
+            Some(listener.clone()),
+        )
+        .await
+        .unwrap();
+    let FetchResult::Updated(update) = res else {
+        panic!("Unexpected result: {res:#?}");
+    };
+    assert_matches!(update.source_state, Some(PollingSourceState::ETag(_)));
+    assert!(update.source_event_time.is_some());
+    assert_eq!(
+        std::fs::read_to_string(&target_path).unwrap(),
+        CSV_BATCH_OUTPUT
+    );
+    assert_eq!(
+        listener.get_last_progress(),
+        Some(FetchProgress {
+            fetched_bytes: 37,
+            total_bytes: TotalBytes::Exact(37),
+        })
+    );
+
+    // No modifications
+    let res2 = harness
+        .fetch_svc
+        .fetch(
+            &mock_dataset_handle(),
+            &generate_unique_operation_id(),
+            &fetch_step,
+            None,
+            update.source_state.as_ref(),
+            &target_path,
+            &Utc::now(),
+            &HashMap::new(),
+            None,
+        )
+        .await
+        .unwrap();
+    assert_matches!(res2, FetchResult::UpToDate);
+
+    // Fetches again if object changed
+    s3_context
+        .put_object(s3_context.get_key("data.csv"), b"city,population\n")
+        .await
+        .unwrap();
+
+    let res3 = harness
+        .fetch_svc
+        .fetch(
+            &mock_dataset_handle(),
+            &generate_unique_operation_id(),
+            &fetch_step,
+            None,
+            update.source_state.as_ref(),
+            &target_path,
+            &Utc::now(),
+            &HashMap::new(),
+            None,
+        )
+        .await
+        .unwrap();
+    assert_matches!(res3, FetchResult::Updated(_));
+    assert_eq!(
+        std::fs::read_to_string(&target_path).unwrap(),
+        "city,population\n"
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// URL: unsupported
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[tokio::test]
+async fn test_fetch_url_unsupported_scheme() {
+    let harness = FetchTestHarness::new();
+    let target_path = harness.temp_dir.path().join("fetched.bin");
+
+    let fetch_step = FetchStep::Url(FetchStepUrl {
+        url: "gopher://example.com/data.csv".to_owned(),
+        event_time: None,
+        cache: None,
+        headers: None,
+    });
+
+    assert_matches!(
+        harness
+            .fetch_svc
+            .fetch(
+                &mock_dataset_handle(),
+                &generate_unique_operation_id(),
+                &fetch_step,
+                None,
+                None,
+                &target_path,
+                &Utc::now(),
+                &HashMap::new(),
+                None
+            )
+            .await,
+        Err(PollingIngestError::UnsupportedScheme(e)) if e.scheme == "gopher"
+    );
+    assert!(!target_path.exists());
+}
+
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 // URL: ftp
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -416,6 +592,7 @@ async fn test_fetch_url_ftp_ok() {
             &generate_unique_operation_id(),
             &fetch_step,
             None,
+            None,
             &target_path,
             &Utc::now(),
             &HashMap::new(),
@@ -475,6 +652,7 @@ async fn test_fetch_files_glob() {
                 &generate_unique_operation_id(),
                 &fetch_step,
                 None,
+                None,
                 &target_path,
                 &Utc::now(),
                 &HashMap::new(),
@@ -495,6 +673,7 @@ async fn test_fetch_files_glob() {
             &generate_unique_operation_id(),
             &fetch_step,
             None,
+            None,
             &target_path,
             &Utc::now(),
             &HashMap::new(),
@@ -523,6 +702,7 @@ async fn test_fetch_files_glob() {
             &mock_dataset_handle(),
             &generate_unique_operation_id(),
             &fetch_step,
+            None,
             update.source_state.as_ref(),
             &target_path,
             &Utc::now(),
@@ -541,6 +721,7 @@ async fn test_fetch_files_glob() {
             &mock_dataset_handle(),
             &generate_unique_operation_id(),
             &fetch_step,
+            None,
             update.source_state.as_ref(),
             &target_path,
             &Utc::now(),
@@ -562,6 +743,7 @@ async fn test_fetch_files_glob() {
             &mock_dataset_handle(),
             &generate_unique_operation_id(),
             &fetch_step,
+            None,
             update.source_state.as_ref(),
             &target_path,
             &Utc::now(),
@@ -597,42 +779,259 @@ async fn test_fetch_files_glob() {
     )
     .unwrap();
 
-    let res5 = harness
+    let res5 = harness
+        .fetch_svc
+        .fetch(
+            &mock_dataset_handle(),
+            &generate_unique_operation_id(),
+            &fetch_step,
+            None,
+            update.source_state.as_ref(),
+            &target_path,
+            &Utc::now(),
+            &HashMap::new(),
+            None,
+        )
+        .await
+        .unwrap();
+    let FetchResult::Updated(update5) = res5 else {
+        panic!("Unexpected result: {res5:#?}");
+    };
+    assert!(!target_path.exists()); // Uses zero-copy path
+    assert_eq!(update5.zero_copy_path.as_ref(), Some(&src_path_2));
+    assert_matches!(
+        &update5.source_state,
+        Some(PollingSourceState::ETag(etag)) if etag == "data-2020-10-05.csv"
+    );
+    assert_eq!(
+        update5.source_event_time,
+        Some(Utc.with_ymd_and_hms(2020, 10, 5, 0, 0, 0).unwrap())
+    );
+    assert!(update5.has_more);
+
+    let res6 = harness
+        .fetch_svc
+        .fetch(
+            &mock_dataset_handle(),
+            &generate_unique_operation_id(),
+            &fetch_step,
+            None,
+            update5.source_state.as_ref(),
+            &target_path,
+            &Utc::now(),
+            &HashMap::new(),
+            None,
+        )
+        .await
+        .unwrap();
+    let FetchResult::Updated(update6) = res6 else {
+        panic!("Unexpected result: {res6:#?}");
+    };
+    assert!(!target_path.exists()); // Uses zero-copy path
+    assert_eq!(update6.zero_copy_path.as_ref(), Some(&src_path_3));
+    assert_matches!(
+        update6.source_state,
+        Some(PollingSourceState::ETag(etag)) if etag == "data-2020-10-10.csv"
+    );
+    assert_eq!(
+        update6.source_event_time,
+        Some(Utc.with_ymd_and_hms(2020, 10, 10, 0, 0, 0).unwrap())
+    );
+    assert!(!update6.has_more);
+}
+
+#[test_group::group(containerized)]
+#[tokio::test]
+async fn test_fetch_files_glob_s3() {
+    let harness = FetchTestHarness::new();
+    let target_path = harness.temp_dir.path().join("fetched.bin");
+
+    let s3 = LocalS3Server::new().await;
+    let s3_context = S3Context::from_url(&s3.url).await;
+
+    let fetch_step = FetchStep::FilesGlob(FetchStepFilesGlob {
+        path: s3.url.join("data/data-*.csv").unwrap().to_string(),
+        event_time: Some(EventTimeSource::FromPath(EventTimeSourceFromPath {
+            pattern: r"data-(\d+-\d+-\d+)\.csv".to_owned(),
+            timestamp_format: Some("%Y-%m-%d".to_owned()),
+        })),
+        cache: None,
+        order: None,
+    });
+
+    // No file to fetch
+    assert_matches!(
+        harness
+            .fetch_svc
+            .fetch(
+                &mock_dataset_handle(),
+                &generate_unique_operation_id(),
+                &fetch_step,
+                None,
+                None,
+                &target_path,
+                &Utc::now(),
+                &HashMap::new(),
+                None
+            )
+            .await,
+        Err(PollingIngestError::NotFound { .. })
+    );
+
+    for key in [
+        "data/data-2020-10-02.csv",
+        "data/data-2020-10-01.csv",
+        "data/other.csv",
+        "data/nested/data-2020-10-03.csv",
+    ] {
+        s3_context
+            .put_object(s3_context.get_key(key), CSV_BATCH_OUTPUT.as_bytes())
+            .await
+            .unwrap();
+    }
+
+    // Fetches files one by one ordered by name
+    let res = harness
+        .fetch_svc
+        .fetch(
+            &mock_dataset_handle(),
+            &generate_unique_operation_id(),
+            &fetch_step,
+            None,
+            None,
+            &target_path,
+            &Utc::now(),
+            &HashMap::new(),
+            None,
+        )
+        .await
+        .unwrap();
+    let FetchResult::Updated(update) = res else {
+        panic!("Unexpected result: {res:#?}");
+    };
+    assert_matches!(
+        &update.source_state,
+        Some(PollingSourceState::ETag(etag)) if etag == "data-2020-10-01.csv"
+    );
+    assert_eq!(
+        update.source_event_time,
+        Some(Utc.with_ymd_and_hms(2020, 10, 1, 0, 0, 0).unwrap())
+    );
+    assert!(update.has_more);
+    assert_eq!(
+        std::fs::read_to_string(&target_path).unwrap(),
+        CSV_BATCH_OUTPUT
+    );
+
+    let res2 = harness
+        .fetch_svc
+        .fetch(
+            &mock_dataset_handle(),
+            &generate_unique_operation_id(),
+            &fetch_step,
+            None,
+            update.source_state.as_ref(),
+            &target_path,
+            &Utc::now(),
+            &HashMap::new(),
+            None,
+        )
+        .await
+        .unwrap();
+    let FetchResult::Updated(update2) = res2 else {
+        panic!("Unexpected result: {res2:#?}");
+    };
+    assert_matches!(
+        &update2.source_state,
+        Some(PollingSourceState::ETag(etag)) if etag == "data-2020-10-02.csv"
+    );
+    assert!(!update2.has_more);
+
+    // No new files
+    let res3 = harness
+        .fetch_svc
+        .fetch(
+            &mock_dataset_handle(),
+            &generate_unique_operation_id(),
+            &fetch_step,
+            None,
+            update2.source_state.as_ref(),
+            &target_path,
+            &Utc::now(),
+            &HashMap::new(),
+            None,
+        )
+        .await
+        .unwrap();
+    assert_matches!(res3, FetchResult::UpToDate);
+}
+
+#[test_group::group(containerized)]
+#[tokio::test]
+async fn test_fetch_files_glob_s3_same_name_under_different_prefixes() {
+    let harness = FetchTestHarness::new();
+    let target_path = harness.temp_dir.path().join("fetched.bin");
+
+    let s3 = LocalS3Server::new().await;
+    let s3_context = S3Context::from_url(&s3.url).await;
+
+    let fetch_step = FetchStep::FilesGlob(FetchStepFilesGlob {
+        path: s3.url.join("data/*/data.csv").unwrap().to_string(),
+        event_time: Some(EventTimeSource::FromPath(EventTimeSourceFromPath {
+            pattern: r"(\d+-\d+-\d+)/data\.csv".to_owned(),
+            timestamp_format: Some("%Y-%m-%d".to_owned()),
+        })),
+        cache: None,
+        order: None,
+    });
+
+    for key in ["data/2020-10-02/data.csv", "data/2020-10-01/data.csv"] {
+        s3_context
+            .put_object(s3_context.get_key(key), CSV_BATCH_OUTPUT.as_bytes())
+            .await
+            .unwrap();
+    }
+
+    let mut prev_source_state = None;
+
+    for expected_date in ["2020-10-01", "2020-10-02"] {
+        let res = harness
+            .fetch_svc
+            .fetch(
+                &mock_dataset_handle(),
+                &generate_unique_operation_id(),
+                &fetch_step,
+                None,
+                prev_source_state.as_ref(),
+                &target_path,
+                &Utc::now(),
+                &HashMap::new(),
+                None,
+            )
+            .await
+            .unwrap();
+        let FetchResult::Updated(update) = res else {
+            panic!("Unexpected result: {res:#?}");
+        };
+        assert_matches!(
+            &update.source_state,
+            Some(PollingSourceState::ETag(etag)) if *etag == format!("{expected_date}/data.csv")
+        );
+        assert_eq!(
+            update.source_event_time.map(|t| t.date_naive().to_string()),
+            Some(expected_date.to_string())
+        );
+        prev_source_state = update.source_state;
+    }
+
+    let res = harness
         .fetch_svc
         .fetch(
             &mock_dataset_handle(),
             &generate_unique_operation_id(),
             &fetch_step,
-            update.source_state.as_ref(),
-            &target_path,
-            &Utc::now(),
-            &HashMap::new(),
             None,
-        )
-        .await
-        .unwrap();
-    let FetchResult::Updated(update5) = res5 else {
-        panic!("Unexpected result: {res5:#?}");
-    };
-    assert!(!target_path.exists()); // Uses zero-copy path
-    assert_eq!(update5.zero_copy_path.as_ref(), Some(&src_path_2));
-    assert_matches!(
-        &update5.source_state,
-        Some(PollingSourceState::ETag(etag)) if etag == "data-2020-10-05.csv"
-    );
-    assert_eq!(
-        update5.source_event_time,
-        Some(Utc.with_ymd_and_hms(2020, 10, 5, 0, 0, 0).unwrap())
-    );
-    assert!(update5.has_more);
-
-    let res6 = harness
-        .fetch_svc
-        .fetch(
-            &mock_dataset_handle(),
-            &generate_unique_operation_id(),
-            &fetch_step,
-            update5.source_state.as_ref(),
+            prev_source_state.as_ref(),
             &target_path,
             &Utc::now(),
             &HashMap::new(),
@@ -640,20 +1039,7 @@ async fn test_fetch_files_glob() {
         )
         .await
         .unwrap();
-    let FetchResult::Updated(update6) = res6 else {
-        panic!("Unexpected result: {res6:#?}");
-    };
-    assert!(!target_path.exists()); // Uses zero-copy path
-    assert_eq!(update6.zero_copy_path.as_ref(), Some(&src_path_3));
-    assert_matches!(
-        update6.source_state,
-        Some(PollingSourceState::ETag(etag)) if etag == "data-2020-10-10.csv"
-    );
-    assert_eq!(
-        update6.source_event_time,
-        Some(Utc.with_ymd_and_hms(2020, 10, 10, 0, 0, 0).unwrap())
-    );
-    assert!(!update6.has_more);
+    assert_matches!(res, FetchResult::UpToDate);
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -690,6 +1076,7 @@ async fn test_fetch_mqtt_empty() {
             &generate_unique_operation_id(),
             &fetch_step,
             None,
+            None,
             &target_path,
             &Utc::now(),
             &HashMap::new(),
@@ -746,14 +1133,16 @@ async fn test_fetch_mqtt_one_record() {
     });
 
     let listener = Arc::new(TestListener::new());
+    let operation_id = generate_unique_operation_id();
 
     let res = harness
         .fetch_svc
         .fetch(
             &mock_dataset_handle(),
-            &generate_unique_operation_id(),
+            &operation_id,
             &fetch_step,
             None,
+            None,
             &target_path,
             &Utc::now(),
             &HashMap::new(),
@@ -766,10 +1155,384 @@ async fn test_fetch_mqtt_one_record() {
         panic!("Unexpected result: {res:#?}");
     };
     assert!(target_path.exists());
-    assert_matches!(update.source_state, None);
+    assert_matches!(
+        update.source_state,
+        Some(PollingSourceState::MqttClientId(client_id))
+            if client_id == format!("kamu-ingest-{}", mock_dataset_handle().id.as_multibase())
+    );
     assert_eq!(update.source_event_time, None);
     assert!(!update.has_more);
-    assert_eq!(std::fs::read(target_path).unwrap(), data);
+    assert_eq!(
+        std::fs::read_to_string(target_path).unwrap(),
+        "{\"data\": 123}\n"
+    );
+
+    harness.fetch_svc.acknowledge(&operation_id).await.unwrap();
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(feature = "ingest-mqtt")]
+#[test_group::group(containerized)]
+#[test_log::test(tokio::test)]
+async fn test_fetch_mqtt_redelivers_unacknowledged() {
+    let harness = FetchTestHarness::new();
+
+    let target_path = harness.temp_dir.path().join("fetched.bin");
+
+    let broker = crate::MqttBroker::new().await;
+    let topic = "test-topic";
+
+    let fetch_step = FetchStep::Mqtt(FetchStepMqtt {
+        host: "localhost".to_string(),
+        port: i32::from(broker.host_port),
+        username: None,
+        password: None,
+        topics: vec![MqttTopicSubscription {
+            path: topic.to_string(),
+            qos: Some(MqttQos::AtLeastOnce),
+        }],
+    });
+
+    let fetch = |operation_id: String, prev_source_state: Option<PollingSourceState>| {
+        let harness = &harness;
+        let fetch_step = &fetch_step;
+        let target_path = &target_path;
+        async move {
+            harness
+                .fetch_svc
+                .fetch(
+                    &mock_dataset_handle(),
+                    &operation_id,
+                    fetch_step,
+                    None,
+                    prev_source_state.as_ref(),
+                    target_path,
+                    &Utc::now(),
+                    &HashMap::new(),
+                    None,
+                )
+                .await
+                .unwrap()
+        }
+    };
+
+    // Establish the persistent session
+    let res = fetch(generate_unique_operation_id(), None).await;
+    assert_matches!(res, FetchResult::UpToDate);
+
+    // Publish a non-retained event that is queued in the session
+    let (client, mut eventloop) = rumqttc::AsyncClient::new(
+        rumqttc::MqttOptions::new("kamu-publisher", "localhost", broker.host_port),
+        1,
+    );
+    client
+        .publish(topic, rumqttc::QoS::AtLeastOnce, false, "a,1")
+        .await
+        .unwrap();
+
+    loop {
+        let event = eventloop.poll().await.unwrap();
+        if let rumqttc::Event::Incoming(rumqttc::Packet::PubAck(_)) = event {
+            break;
+        }
+    }
+
+    // Fetch without acknowledging, e.g. when ingestion fails to commit
+    let operation_id = generate_unique_operation_id();
+    let FetchResult::Updated(update) = fetch(operation_id.clone(), None).await else {
+        panic!("Expected an update");
+    };
+    assert_eq!(std::fs::read_to_string(&target_path).unwrap(), "a,1\n");
+    harness.fetch_svc.release(&operation_id);
+
+    // Event is re-delivered into the same session
+    let operation_id = generate_unique_operation_id();
+    let res = fetch(operation_id.clone(), update.source_state).await;
+    let FetchResult::Updated(update) = res else {
+        panic!("Unexpected result: {res:#?}");
+    };
+    assert_eq!(std::fs::read_to_string(&target_path).unwrap(), "a,1\n");
+    harness.fetch_svc.acknowledge(&operation_id).await.unwrap();
+
+    // Acknowledged event is not delivered again
+    let res = fetch(generate_unique_operation_id(), update.source_state).await;
+    assert_matches!(res, FetchResult::UpToDate);
+}
+
+#[cfg(feature = "ingest-mqtt")]
+#[test_group::group(containerized)]
+#[test_log::test(tokio::test)]
+async fn test_fetch_mqtt_frames_pretty_printed_json_as_ndjson() {
+    let harness = FetchTestHarness::new();
+
+    let target_path = harness.temp_dir.path().join("fetched.bin");
+
+    let broker = crate::MqttBroker::new().await;
+
+    publish_retained_mqtt(
+        broker.host_port,
+        &[
+            ("topic-a", "{\n  \"data\": 1\n}"),
+            ("topic-b", "{\n  \"data\": 2\n}\n{\"data\": 3}"),
+        ],
+    )
+    .await;
+
+    let operation_id = generate_unique_operation_id();
+    let res = harness
+        .fetch_svc
+        .fetch(
+            &mock_dataset_handle(),
+            &operation_id,
+            &mqtt_fetch_step(broker.host_port, &["topic-a", "topic-b"]),
+            Some(&ReadStepNdJson::default().into()),
+            None,
+            &target_path,
+            &Utc::now(),
+            &HashMap::new(),
+            None,
+        )
+        .await
+        .unwrap();
+    assert_matches!(res, FetchResult::Updated(_));
+
+    let mut lines: Vec<_> = std::fs::read_to_string(&target_path)
+        .unwrap()
+        .lines()
+        .map(ToString::to_string)
+        .collect();
+    lines.sort();
+    assert_eq!(lines, ["{\"data\":1}", "{\"data\":2}", "{\"data\":3}"]);
+
+    harness.fetch_svc.acknowledge(&operation_id).await.unwrap();
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(feature = "ingest-mqtt")]
+#[test_group::group(containerized)]
+#[test_log::test(tokio::test)]
+async fn test_fetch_mqtt_frames_json_records_under_sub_path() {
+    let harness = FetchTestHarness::new();
+
+    let target_path = harness.temp_dir.path().join("fetched.bin");
+
+    let broker = crate::MqttBroker::new().await;
+
+    publish_retained_mqtt(
+        broker.host_port,
+        &[
+            (
+                "topic-a",
+                r#"{"page": {"records": [{"data": 1}, {"data": 2}]}}"#,
+            ),
+            ("topic-b", r#"{"page": {"records": {"data": 3}}}"#),
+        ],
+    )
+    .await;
+
+    let operation_id = generate_unique_operation_id();
+    let res = harness
+        .fetch_svc
+        .fetch(
+            &mock_dataset_handle(),
+            &operation_id,
+            &mqtt_fetch_step(broker.host_port, &["topic-a", "topic-b"]),
+            Some(
+                &ReadStepJson {
+                    sub_path: Some("page.records".to_string()),
+                    ..Default::default()
+                }
+                .into(),
+            ),
+            None,
+            &target_path,
+            &Utc::now(),
+            &HashMap::new(),
+            None,
+        )
+        .await
+        .unwrap();
+    assert_matches!(res, FetchResult::Updated(_));
+
+    // Records of all messages are combined into a single document
+    let doc: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(&target_path).unwrap()).unwrap();
+    let mut data: Vec<_> = doc["page"]["records"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|r| r["data"].as_i64().unwrap())
+        .collect();
+    data.sort_unstable();
+    assert_eq!(data, [1, 2, 3]);
+
+    harness.fetch_svc.acknowledge(&operation_id).await.unwrap();
+}
+
+#[cfg(feature = "ingest-mqtt")]
+fn mqtt_fetch_step(port: u16, topics: &[&str]) -> FetchStep {
+    FetchStep::Mqtt(FetchStepMqtt {
+        host: "localhost".to_string(),
+        port: i32::from(port),
+        username: None,
+        password: None,
+        topics: topics
+            .iter()
+            .map(|topic| MqttTopicSubscription {
+                path: (*topic).to_string(),
+                qos: Some(MqttQos::AtLeastOnce),
+            })
+            .collect(),
+    })
+}
+
+#[cfg(feature = "ingest-mqtt")]
+async fn publish_retained_mqtt(port: u16, messages: &[(&str, &str)]) {
+    let (client, mut eventloop) = rumqttc::AsyncClient::new(
+        rumqttc::MqttOptions::new("kamu-publisher", "localhost", port),
+        messages.len(),
+    );
+
+    for (topic, payload) in messages {
+        client
+            .publish(*topic, rumqttc::QoS::AtLeastOnce, true, *payload)
+            .await
+            .unwrap();
+    }
+
+    let mut remaining = messages.len();
+    while remaining != 0 {
+        let event = eventloop.poll().await.unwrap();
+        if let rumqttc::Event::Incoming(rumqttc::Packet::PubAck(_)) = event {
+            remaining -= 1;
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// Ethereum
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(feature = "ingest-evm")]
+#[test_log::test(tokio::test)]
+async fn test_fetch_ethereum_logs_reorg() {
+    use datafusion::prelude::*;
+
+    let harness = FetchTestHarness::new();
+
+    let node = crate::EthNodeStub::new();
+    let log = |data: &str| crate::StubLog {
+        address: format!("0x{}", "aa".repeat(20)),
+        topics: vec![format!("0x{}", "bb".repeat(32))],
+        data: data.to_string(),
+    };
+
+    node.mine(vec![log("0x01")]);
+    node.mine(vec![]);
+    node.mine(vec![log("0x03")]);
+
+    let fetch_step = FetchStep::EthereumLogs(FetchStepEthereumLogs {
+        chain_id: Some(crate::EthNodeStub::CHAIN_ID),
+        node_url: Some(node.url.clone()),
+        filter: None,
+        signature: None,
+    });
+
+    let fetch = |prev_source_state: Option<PollingSourceState>, target_name: &str| {
+        let harness = &harness;
+        let fetch_step = &fetch_step;
+        let target_path = harness.temp_dir.path().join(target_name);
+        async move {
+            let res = harness
+                .fetch_svc
+                .fetch(
+                    &mock_dataset_handle(),
+                    &generate_unique_operation_id(),
+                    fetch_step,
+                    None,
+                    prev_source_state.as_ref(),
+                    &target_path,
+                    &Utc::now(),
+                    &HashMap::new(),
+                    None,
+                )
+                .await
+                .unwrap();
+            (res, target_path)
+        }
+    };
+
+    let read_block_numbers = |path: std::path::PathBuf| async move {
+        let ctx = SessionContext::new();
+        let df = ctx
+            .read_parquet(path.to_str().unwrap(), ParquetReadOptions::default())
+            .await
+            .unwrap()
+            .select_columns(&["block_number"])
+            .unwrap();
+
+        let mut block_numbers: Vec<u64> = df
+            .collect()
+            .await
+            .unwrap()
+            .iter()
+            .flat_map(|batch| {
+                datafusion::arrow::compute::cast(
+                    batch.column(0),
+                    &datafusion::arrow::datatypes::DataType::UInt64,
+                )
+                .unwrap()
+                .as_any()
+                .downcast_ref::<datafusion::arrow::array::UInt64Array>()
+                .unwrap()
+                .values()
+                .to_vec()
+            })
+            .collect();
+        block_numbers.sort_unstable();
+        block_numbers
+    };
+
+    let tracked_blocks = |source_state: &Option<PollingSourceState>| {
+        let Some(PollingSourceState::ETag(etag)) = source_state else {
+            panic!("Unexpected source state: {source_state:?}");
+        };
+        etag.split(',')
+            .map(|b| b.split_once('@').unwrap().0.parse().unwrap())
+            .collect::<Vec<u64>>()
+    };
+
+    // Initial scan
+    let (res, target_path) = fetch(None, "fetched-1.parquet").await;
+    let FetchResult::Updated(update) = res else {
+        panic!("Unexpected result: {res:#?}");
+    };
+    assert_eq!(update.retraction_filter, None);
+    assert_eq!(tracked_blocks(&update.source_state), vec![3, 2, 1, 0]);
+    assert_eq!(read_block_numbers(target_path).await, vec![1, 3]);
+
+    // Replace the two most recent blocks
+    node.reorg(2);
+    node.mine(vec![log("0x12")]);
+    node.mine(vec![]);
+    node.mine(vec![log("0x14")]);
+
+    let (res, target_path) = fetch(update.source_state, "fetched-2.parquet").await;
+    let FetchResult::Updated(update) = res else {
+        panic!("Unexpected result: {res:#?}");
+    };
+    assert_eq!(
+        update.retraction_filter.as_deref(),
+        Some("block_number > 1")
+    );
+    assert_eq!(tracked_blocks(&update.source_state), vec![4, 3, 2, 1, 0]);
+    assert_eq!(read_block_numbers(target_path).await, vec![2, 4]);
+
+    // No changes
+    let (res, _) = fetch(update.source_state, "fetched-3.parquet").await;
+    assert_matches!(res, FetchResult::UpToDate);
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -800,6 +1563,7 @@ async fn test_fetch_container_ok() {
             &generate_unique_operation_id(),
             &fetch_step,
             None,
+            None,
             &target_path,
             &Utc::now(),
             &HashMap::new(),
@@ -857,6 +1621,7 @@ async fn test_fetch_container_env_vars() {
             &generate_unique_operation_id(),
             &fetch_step,
             None,
+            None,
             &target_path,
             &Utc::now(),
             &HashMap::from([(
@@ -866,7 +1631,7 @@ async fn test_fetch_container_env_vars() {
                     Utc::now(),
                     &kamu_datasets::DatasetEnvVarValue::Regular("foobar".to_owned()),
                     &DatasetID::new_seeded_ed25519(b"doesnt-matter"),
-                    "",
+                    &kamu_datasets::DatasetEnvVarEncryptionKeyRing::sample(),
                 )
                 .unwrap(),
             )]),
@@ -918,6 +1683,7 @@ async fn test_fetch_container_batch_size_default() {
             &generate_unique_operation_id(),
             &fetch_step,
             None,
+            None,
             &target_path,
             &Utc::now(),
             &HashMap::new(),
@@ -965,6 +1731,7 @@ async fn test_fetch_container_batch_size_set() {
             &generate_unique_operation_id(),
             &fetch_step,
             None,
+            None,
             &target_path,
             &Utc::now(),
             &HashMap::new(),
@@ -1009,6 +1776,7 @@ async fn test_fetch_container_batch_size_invalid_format() {
             &generate_unique_operation_id(),
             &fetch_step,
             None,
+            None,
             &target_path,
             &Utc::now(),
             &HashMap::new(),
@@ -1045,6 +1813,7 @@ async fn test_fetch_container_has_more_no_data() {
             &generate_unique_operation_id(),
             &fetch_step,
             None,
+            None,
             &target_path,
             &Utc::now(),
             &HashMap::new(),
@@ -1089,6 +1858,7 @@ async fn test_fetch_container_has_more_data_is_less_than_a_batch() {
             &generate_unique_operation_id(),
             &fetch_step,
             None,
+            None,
             &target_path,
             &Utc::now(),
             &HashMap::new(),
@@ -1152,6 +1922,7 @@ async fn test_fetch_container_has_more_data_is_more_than_a_batch() {
                 &generate_unique_operation_id(),
                 &fetch_step_1,
                 None,
+                None,
                 &target_path,
                 &Utc::now(),
                 &HashMap::new(),
@@ -1207,6 +1978,7 @@ async fn test_fetch_container_has_more_data_is_more_than_a_batch() {
                 &mock_dataset_handle(),
                 &generate_unique_operation_id(),
                 &fetch_step_2,
+                None,
                 prev_source_state.as_ref(),
                 &target_path,
                 &Utc::now(),
@@ -1262,6 +2034,7 @@ async fn test_fetch_container_has_more_data_is_more_than_a_batch() {
                 &mock_dataset_handle(),
                 &generate_unique_operation_id(),
                 &fetch_step_3,
+                None,
                 prev_source_state.as_ref(),
                 &target_path,
                 &Utc::now(),
@@ -1313,6 +2086,7 @@ async fn test_fetch_container_has_more_data_is_more_than_a_batch() {
                 &mock_dataset_handle(),
                 &generate_unique_operation_id(),
                 &fetch_step_4,
+                None,
                 prev_source_state.as_ref(),
                 &target_path,
                 &Utc::now(),
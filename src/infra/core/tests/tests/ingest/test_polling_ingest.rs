@@ -260,6 +260,9 @@ async fn test_ingest_polling_ledger() {
                 }))
                 .merge(MergeStrategyLedger {
                     primary_key: vec!["date".to_string(), "city".to_string()],
+                    mode: None,
+                    dedupe_window_records: None,
+                    dedupe_window_seconds: None,
                 })
                 .build(),
         )
@@ -473,6 +476,9 @@ async fn test_ingest_polling_empty_data() {
                 })
                 .merge(MergeStrategyLedger {
                     primary_key: vec!["date".to_string(), "city".to_string()],
+                    mode: None,
+                    dedupe_window_records: None,
+                    dedupe_window_seconds: None,
                 })
                 .build(),
         )
@@ -840,6 +846,57 @@ async fn test_ingest_polling_bad_column_names_rename() {
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
+#[cfg(feature = "ingest-evm")]
+#[test_group::group(engine, ingest, datafusion)]
+#[test_log::test(tokio::test)]
+async fn test_ingest_polling_ethereum_logs_requires_block_number() {
+    let harness = IngestTestHarness::new();
+
+    let node = crate::EthNodeStub::new();
+    node.mine(vec![crate::StubLog {
+        address: format!("0x{}", "aa".repeat(20)),
+        topics: vec![format!("0x{}", "bb".repeat(32))],
+        data: "0x01".to_string(),
+    }]);
+
+    let dataset_snapshot = MetadataFactory::dataset_snapshot()
+        .name("foo.bar")
+        .kind(DatasetKind::Root)
+        .push_event(
+            MetadataFactory::set_polling_source()
+                .fetch(FetchStep::EthereumLogs(FetchStepEthereumLogs {
+                    chain_id: Some(crate::EthNodeStub::CHAIN_ID),
+                    node_url: Some(node.url.clone()),
+                    filter: None,
+                    signature: None,
+                }))
+                .read(ReadStepParquet { schema: None })
+                .preprocess(TransformSql {
+                    engine: "datafusion".to_string(),
+                    version: None,
+                    query: Some("select log_index, address, data from input".to_string()),
+                    queries: None,
+                    temporal_tables: None,
+                })
+                .build(),
+        )
+        .build();
+
+    let dataset_alias = dataset_snapshot.name.clone();
+
+    harness.create_dataset(dataset_snapshot).await;
+
+    // Logs from orphaned blocks could not be retracted without the block number
+    let res = harness.ingest(&dataset_alias).await;
+    assert_matches!(
+        res,
+        Err(PollingIngestError::BadInputSchema(e))
+            if e.to_string().contains("block_number")
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
 #[test_group::group(containerized, engine, ingest, spark)]
 #[test_log::test(tokio::test)]
 async fn test_ingest_polling_preprocess_with_spark() {
@@ -45,6 +45,9 @@ async fn test_ingest_push_url_stream() {
                 })
                 .merge(MergeStrategyLedger {
                     primary_key: vec!["date".to_string(), "city".to_string()],
+                    mode: None,
+                    dedupe_window_records: None,
+                    dedupe_window_seconds: None,
                 })
                 .build(),
         )
@@ -193,6 +196,9 @@ async fn test_ingest_push_media_type_override() {
                 })
                 .merge(MergeStrategyLedger {
                     primary_key: vec!["date".to_string(), "city".to_string()],
+                    mode: None,
+                    dedupe_window_records: None,
+                    dedupe_window_seconds: None,
                 })
                 .build(),
         )
@@ -372,6 +378,152 @@ async fn test_ingest_push_media_type_override() {
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
+#[test_group::group(engine, ingest, datafusion)]
+#[test_log::test(tokio::test)]
+async fn test_ingest_push_media_type_orc() {
+    use datafusion::arrow::array;
+    use datafusion::arrow::datatypes::{DataType, Field, Schema};
+    use datafusion::arrow::record_batch::RecordBatch;
+
+    let harness = IngestTestHarness::new();
+
+    let dataset_snapshot = MetadataFactory::dataset_snapshot()
+        .name("foo.bar")
+        .kind(DatasetKind::Root)
+        .push_event(
+            MetadataFactory::add_push_source()
+                .read(ReadStepCsv {
+                    schema: Some(
+                        ["date TIMESTAMP", "city STRING", "population BIGINT"]
+                            .iter()
+                            .map(|s| (*s).to_string())
+                            .collect(),
+                    ),
+                    ..Default::default()
+                })
+                .merge(MergeStrategyLedger {
+                    primary_key: vec!["date".to_string(), "city".to_string()],
+                    mode: None,
+                    dedupe_window_records: None,
+                    dedupe_window_seconds: None,
+                })
+                .build(),
+        )
+        .push_event(SetVocab {
+            event_time_column: Some("date".to_string()),
+            ..Default::default()
+        })
+        .build();
+
+    let dataset_alias = dataset_snapshot.name.clone();
+    let dataset_ref = dataset_alias.as_local_ref();
+
+    harness.create_dataset(dataset_snapshot).await;
+    let data_helper = harness.dataset_data_helper(&dataset_alias).await;
+
+    // ORC is read by media type and coerced to the schema of the source
+    let src_path = harness.temp_dir.path().join("data.orc");
+    {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("date", DataType::Utf8, false),
+            Field::new("city", DataType::Utf8, false),
+            Field::new("population", DataType::Int32, false),
+        ]));
+
+        let record_batch = RecordBatch::try_new(
+            schema.clone(),
+            vec![
+                Arc::new(array::StringArray::from(vec!["2020-01-01"])),
+                Arc::new(array::StringArray::from(vec!["A"])),
+                Arc::new(array::Int32Array::from(vec![1000])),
+            ],
+        )
+        .unwrap();
+
+        let mut writer =
+            orc_rust::ArrowWriterBuilder::new(std::fs::File::create(&src_path).unwrap(), schema)
+                .try_build()
+                .unwrap();
+        writer.write(&record_batch).unwrap();
+        writer.close().unwrap();
+    }
+
+    harness
+        .push_ingest_svc
+        .ingest_from_url(
+            &dataset_ref,
+            None,
+            url::Url::from_file_path(&src_path).unwrap(),
+            PushIngestOpts {
+                media_type: Some(MediaType::ORC.to_owned()),
+                ..Default::default()
+            },
+            None,
+        )
+        .await
+        .unwrap();
+
+    data_helper
+        .assert_last_data_eq(
+            indoc!(
+                r#"
+                message arrow_schema {
+                  OPTIONAL INT64 offset;
+                  REQUIRED INT32 op;
+                  REQUIRED INT64 system_time (TIMESTAMP(MILLIS,true));
+                  OPTIONAL INT64 date (TIMESTAMP(MILLIS,true));
+                  OPTIONAL BYTE_ARRAY city (STRING);
+                  OPTIONAL INT64 population;
+                }
+                "#
+            ),
+            indoc!(
+                r#"
+                +--------+----+----------------------+----------------------+------+------------+
+                | offset | op | system_time          | date                 | city | population |
+                +--------+----+----------------------+----------------------+------+------------+
+                | 0      | 0  | 2050-01-01T12:00:00Z | 2020-01-01T00:00:00Z | A    | 1000       |
+                +--------+----+----------------------+----------------------+------+------------+
+                "#
+            ),
+        )
+        .await;
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_group::group(engine, ingest, datafusion)]
+#[test_log::test(tokio::test)]
+async fn test_ingest_push_failure_discards_operation_dir() {
+    let harness = IngestTestHarness::new();
+
+    let dataset_snapshot = MetadataFactory::dataset_snapshot()
+        .name("foo.bar")
+        .kind(DatasetKind::Root)
+        .push_event(MetadataFactory::add_push_source().some_read().build())
+        .build();
+
+    let dataset_alias = dataset_snapshot.name.clone();
+    harness.create_dataset(dataset_snapshot).await;
+
+    let res = harness
+        .push_ingest_svc
+        .ingest_from_url(
+            &dataset_alias.as_local_ref(),
+            None,
+            url::Url::from_file_path(harness.temp_dir.path().join("missing.json")).unwrap(),
+            PushIngestOpts::default(),
+            None,
+        )
+        .await;
+    assert!(res.is_err());
+
+    let run_info_dir = harness.temp_dir.path().join("run");
+    assert_eq!(std::fs::read_dir(run_info_dir).unwrap().count(), 0);
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
 #[test_group::group(engine, ingest, datafusion)]
 #[test_log::test(tokio::test)]
 async fn test_ingest_push_schema_stability() {
@@ -490,6 +490,180 @@ async fn test_data_writer_ledger_orders_by_event_time() {
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
+#[test_group::group(engine, ingest, datafusion)]
+#[test_log::test(tokio::test)]
+async fn test_data_writer_watermark_bounded_out_of_orderness() {
+    let mut harness = Harness::new(vec![MetadataFactory::set_polling_source()
+        .merge(odf::MergeStrategyAppend {})
+        .watermark(odf::WatermarkStrategyBoundedOutOfOrderness {
+            lag_seconds: 24 * 60 * 60,
+        })
+        .build()
+        .into()])
+    .await;
+
+    let res = harness
+        .write(
+            indoc!(
+                r#"
+                event_time,city,population
+                2021-01-01,A,1000
+                2021-01-05,B,2000
+                "#
+            ),
+            "event_time DATE, city STRING, population BIGINT",
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(
+        res.add_data_block
+            .unwrap()
+            .event
+            .new_watermark
+            .as_ref()
+            .map(DateTime::to_rfc3339),
+        Some("2021-01-04T00:00:00+00:00".to_string())
+    );
+
+    // Late data within the bound does not move the watermark back
+    let res = harness
+        .write(
+            indoc!(
+                r#"
+                event_time,city,population
+                2021-01-04,C,3000
+                "#
+            ),
+            "event_time DATE, city STRING, population BIGINT",
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(
+        res.add_data_block
+            .unwrap()
+            .event
+            .new_watermark
+            .as_ref()
+            .map(DateTime::to_rfc3339),
+        Some("2021-01-04T00:00:00+00:00".to_string())
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_group::group(engine, ingest, datafusion)]
+#[test_log::test(tokio::test)]
+async fn test_data_writer_watermark_system_time() {
+    let mut harness = Harness::new(vec![MetadataFactory::set_polling_source()
+        .merge(odf::MergeStrategyAppend {})
+        .watermark(odf::WatermarkStrategySystemTime {
+            delay_seconds: 60 * 60,
+            min_idle_advance_seconds: Some(10 * 60),
+        })
+        .build()
+        .into()])
+    .await;
+
+    let res = harness
+        .write(
+            indoc!(
+                r#"
+                event_time,city,population
+                2021-01-01,A,1000
+                "#
+            ),
+            "event_time DATE, city STRING, population BIGINT",
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(
+        res.add_data_block
+            .unwrap()
+            .event
+            .new_watermark
+            .as_ref()
+            .map(DateTime::to_rfc3339),
+        Some("2010-01-01T11:00:00+00:00".to_string())
+    );
+
+    // Empty ingest shortly after does not produce a data-less block
+    harness.set_system_time(Utc.with_ymd_and_hms(2010, 1, 1, 12, 5, 0).unwrap());
+
+    let res = harness.write("", "").await;
+    assert_matches!(res, Err(WriteDataError::EmptyCommit(_)));
+
+    // Empty ingest advances the watermark once it has moved far enough
+    harness.set_system_time(Utc.with_ymd_and_hms(2010, 1, 1, 12, 10, 0).unwrap());
+
+    let res = harness.write("", "").await.unwrap();
+
+    let add_data = res.add_data_block.unwrap().event;
+    assert_eq!(add_data.new_offset_interval, None);
+    assert_eq!(
+        add_data.new_watermark.as_ref().map(DateTime::to_rfc3339),
+        Some("2010-01-01T11:10:00+00:00".to_string())
+    );
+
+    // New data advances the watermark regardless of the idle threshold
+    harness.set_system_time(Utc.with_ymd_and_hms(2010, 1, 1, 12, 11, 0).unwrap());
+
+    let res = harness
+        .write(
+            indoc!(
+                r#"
+                event_time,city,population
+                2021-01-02,B,2000
+                "#
+            ),
+            "event_time DATE, city STRING, population BIGINT",
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(
+        res.add_data_block
+            .unwrap()
+            .event
+            .new_watermark
+            .as_ref()
+            .map(DateTime::to_rfc3339),
+        Some("2010-01-01T11:11:00+00:00".to_string())
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_group::group(engine, ingest, datafusion)]
+#[test_log::test(tokio::test)]
+async fn test_data_writer_watermark_manual() {
+    let mut harness = Harness::new(vec![MetadataFactory::set_polling_source()
+        .merge(odf::MergeStrategyAppend {})
+        .watermark(odf::WatermarkStrategyManual {})
+        .build()
+        .into()])
+    .await;
+
+    let res = harness
+        .write(
+            indoc!(
+                r#"
+                event_time,city,population
+                2021-01-01,A,1000
+                "#
+            ),
+            "event_time DATE, city STRING, population BIGINT",
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(res.add_data_block.unwrap().event.new_watermark, None);
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
 #[test_group::group(engine, ingest, datafusion)]
 #[test_log::test(tokio::test)]
 async fn test_data_writer_snapshot_orders_by_pk_and_operation_type() {
@@ -631,12 +805,210 @@ async fn test_data_writer_snapshot_orders_by_pk_and_operation_type() {
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
+#[test_group::group(engine, ingest, datafusion)]
+#[test_log::test(tokio::test)]
+async fn test_data_writer_retraction_filter() {
+    let mut harness = Harness::new(vec![MetadataFactory::set_polling_source()
+        .merge(odf::MergeStrategyAppend {})
+        .build()
+        .into()])
+    .await;
+
+    let schema = "event_time DATE, block_number BIGINT, value STRING";
+
+    harness
+        .write(
+            indoc!(
+                r#"
+                event_time,block_number,value
+                2021-01-01,1,a
+                2021-01-02,2,b
+                2021-01-03,3,c
+                "#
+            ),
+            schema,
+        )
+        .await
+        .unwrap();
+
+    // Records matching the filter are retracted
+    harness.set_retraction_filter(Some("block_number > 1"));
+    harness
+        .write(
+            indoc!(
+                r#"
+                event_time,block_number,value
+                2021-01-04,2,b2
+                "#
+            ),
+            schema,
+        )
+        .await
+        .unwrap();
+
+    assert_data_eq(
+        harness.get_last_data().await,
+        indoc!(
+            r#"
+            +--------+----+----------------------+------------+--------------+-------+
+            | offset | op | system_time          | event_time | block_number | value |
+            +--------+----+----------------------+------------+--------------+-------+
+            | 3      | 1  | 2010-01-01T12:00:00Z | 2021-01-02 | 2            | b     |
+            | 4      | 1  | 2010-01-01T12:00:00Z | 2021-01-03 | 3            | c     |
+            | 5      | 0  | 2010-01-01T12:00:00Z | 2021-01-04 | 2            | b2    |
+            +--------+----+----------------------+------------+--------------+-------+
+            "#
+        ),
+    )
+    .await;
+
+    // Records that were already retracted are not retracted again
+    harness
+        .write(
+            indoc!(
+                r#"
+                event_time,block_number,value
+                2021-01-05,2,b3
+                "#
+            ),
+            schema,
+        )
+        .await
+        .unwrap();
+
+    assert_data_eq(
+        harness.get_last_data().await,
+        indoc!(
+            r#"
+            +--------+----+----------------------+------------+--------------+-------+
+            | offset | op | system_time          | event_time | block_number | value |
+            +--------+----+----------------------+------------+--------------+-------+
+            | 6      | 1  | 2010-01-01T12:00:00Z | 2021-01-04 | 2            | b2    |
+            | 7      | 0  | 2010-01-01T12:00:00Z | 2021-01-05 | 2            | b3    |
+            +--------+----+----------------------+------------+--------------+-------+
+            "#
+        ),
+    )
+    .await;
+
+    // Filter that does not apply to the dataset is rejected
+    harness.set_retraction_filter(Some("log_index > 1"));
+    let res = harness
+        .write(
+            indoc!(
+                r#"
+                event_time,block_number,value
+                2021-01-06,4,d
+                "#
+            ),
+            schema,
+        )
+        .await;
+    assert_matches!(
+        res,
+        Err(WriteDataError::BadInputSchema(e))
+            if e.to_string().contains("Retraction filter is not applicable")
+    );
+}
+
+#[test_group::group(engine, ingest, datafusion)]
+#[test_log::test(tokio::test)]
+async fn test_data_writer_retraction_filter_identical_records() {
+    let mut harness = Harness::new(vec![MetadataFactory::set_polling_source()
+        .merge(odf::MergeStrategyAppend {})
+        .build()
+        .into()])
+    .await;
+
+    let schema = "event_time DATE, block_number BIGINT, value STRING";
+
+    harness
+        .write(
+            indoc!(
+                r#"
+                event_time,block_number,value
+                2021-01-01,1,a
+                2021-01-02,2,b
+                2021-01-02,2,b
+                "#
+            ),
+            schema,
+        )
+        .await
+        .unwrap();
+
+    // Every copy of the identical records is retracted
+    harness.set_retraction_filter(Some("block_number > 1"));
+    harness
+        .write(
+            indoc!(
+                r#"
+                event_time,block_number,value
+                2021-01-03,2,c
+                "#
+            ),
+            schema,
+        )
+        .await
+        .unwrap();
+
+    assert_data_eq(
+        harness.get_last_data().await,
+        indoc!(
+            r#"
+            +--------+----+----------------------+------------+--------------+-------+
+            | offset | op | system_time          | event_time | block_number | value |
+            +--------+----+----------------------+------------+--------------+-------+
+            | 3      | 1  | 2010-01-01T12:00:00Z | 2021-01-02 | 2            | b     |
+            | 4      | 1  | 2010-01-01T12:00:00Z | 2021-01-02 | 2            | b     |
+            | 5      | 0  | 2010-01-01T12:00:00Z | 2021-01-03 | 2            | c     |
+            +--------+----+----------------------+------------+--------------+-------+
+            "#
+        ),
+    )
+    .await;
+
+    // Copies that were all retracted are not retracted again
+    harness
+        .write(
+            indoc!(
+                r#"
+                event_time,block_number,value
+                2021-01-04,2,d
+                "#
+            ),
+            schema,
+        )
+        .await
+        .unwrap();
+
+    assert_data_eq(
+        harness.get_last_data().await,
+        indoc!(
+            r#"
+            +--------+----+----------------------+------------+--------------+-------+
+            | offset | op | system_time          | event_time | block_number | value |
+            +--------+----+----------------------+------------+--------------+-------+
+            | 6      | 1  | 2010-01-01T12:00:00Z | 2021-01-03 | 2            | c     |
+            | 7      | 0  | 2010-01-01T12:00:00Z | 2021-01-04 | 2            | d     |
+            +--------+----+----------------------+------------+--------------+-------+
+            "#
+        ),
+    )
+    .await;
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
 #[test_group::group(engine, ingest, datafusion)]
 #[test_log::test(tokio::test)]
 async fn test_data_writer_normalizes_timestamps_to_utc_millis() {
     let mut harness = Harness::new(vec![MetadataFactory::set_polling_source()
         .merge(odf::MergeStrategyLedger {
             primary_key: vec!["event_time".to_string(), "city".to_string()],
+            mode: None,
+            dedupe_window_records: None,
+            dedupe_window_seconds: None,
         })
         .build()
         .into()])
@@ -703,6 +1075,9 @@ async fn test_data_writer_optimal_parquet_encoding() {
     let mut harness = Harness::new(vec![MetadataFactory::set_polling_source()
         .merge(odf::MergeStrategyLedger {
             primary_key: vec!["event_time".to_string(), "city".to_string()],
+            mode: None,
+            dedupe_window_records: None,
+            dedupe_window_seconds: None,
         })
         .build()
         .into()])
@@ -812,6 +1187,9 @@ async fn test_data_writer_builder_scan_polling_source() {
     let harness = Harness::new(vec![MetadataFactory::set_polling_source()
         .merge(odf::MergeStrategyLedger {
             primary_key: vec!["event_time".to_string(), "city".to_string()],
+            mode: None,
+            dedupe_window_records: None,
+            dedupe_window_seconds: None,
         })
         .build()
         .into()])
@@ -853,6 +1231,9 @@ async fn test_data_writer_builder_scan_push_source() {
         })
         .merge(odf::MergeStrategyLedger {
             primary_key: vec!["event_time".to_string(), "city".to_string()],
+            mode: None,
+            dedupe_window_records: None,
+            dedupe_window_seconds: None,
         })
         .build()
         .into()])
@@ -895,6 +1276,9 @@ async fn test_data_writer_builder_scan_push_source_with_extra_events() {
             })
             .merge(odf::MergeStrategyLedger {
                 primary_key: vec!["event_time".to_string(), "city".to_string()],
+                mode: None,
+                dedupe_window_records: None,
+                dedupe_window_seconds: None,
             })
             .build()
             .into(),
@@ -939,6 +1323,7 @@ struct Harness {
 
     system_time: DateTime<Utc>,
     source_event_time: DateTime<Utc>,
+    retraction_filter: Option<String>,
 }
 
 impl Harness {
@@ -1003,6 +1388,7 @@ impl Harness {
             ctx,
             system_time,
             source_event_time: Utc.with_ymd_and_hms(2000, 1, 1, 12, 0, 0).unwrap(),
+            retraction_filter: None,
         }
     }
 
@@ -1014,6 +1400,10 @@ impl Harness {
         self.source_event_time = t;
     }
 
+    fn set_retraction_filter(&mut self, filter: Option<&str>) {
+        self.retraction_filter = filter.map(ToString::to_string);
+    }
+
     async fn reset_writer(&mut self) {
         self.writer = DataWriterDataFusion::builder(self.dataset.clone(), self.ctx.clone())
             .with_metadata_state_scanned(None)
@@ -1059,6 +1449,7 @@ impl Harness {
                     source_event_time: self.source_event_time,
                     new_watermark: None,
                     new_source_state,
+                    retraction_filter: self.retraction_filter.clone(),
                     data_staging_path: self.temp_dir.path().join("write.tmp"),
                 },
             )
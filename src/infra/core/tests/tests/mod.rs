@@ -12,10 +12,12 @@ mod ingest;
 mod repos;
 mod test_compact_service_impl;
 mod test_dataset_changes_service_impl;
+mod test_dataset_entries_reconciler;
 mod test_dataset_ownership_service_inmem;
 mod test_datasets_filtering;
 mod test_dependency_graph_inmem;
 mod test_metadata_chain_comparator;
+mod test_operation_dir;
 mod test_pull_service_impl;
 mod test_query_service_impl;
 mod test_reset_service_impl;
@@ -24,6 +26,7 @@ mod test_schema_utils;
 mod test_search_service_impl;
 mod test_serde_yaml;
 mod test_setup;
+mod test_storage_gc_service_impl;
 mod test_sync_service_impl;
 mod test_transform_service_impl;
 mod test_verification_service_impl;
@@ -646,6 +646,168 @@ async fn test_append_add_push_source_does_not_require_explicit_schema() {
     assert_matches!(res, Ok(_));
 }
 
+#[test_log::test(tokio::test)]
+async fn test_append_disable_polling_source() {
+    let tmp_dir = tempfile::tempdir().unwrap();
+    let chain = init_chain(tmp_dir.path());
+
+    let head = chain
+        .append(
+            MetadataFactory::metadata_block(MetadataFactory::seed(DatasetKind::Root).build())
+                .build(),
+            AppendOpts::default(),
+        )
+        .await
+        .unwrap();
+
+    // Rejects disabling a source that was never defined
+    assert_matches!(
+        chain
+            .append(
+                MetadataFactory::metadata_block(DisablePollingSource {})
+                    .prev(&head, 0)
+                    .build(),
+                AppendOpts::default(),
+            )
+            .await,
+        Err(AppendError::InvalidBlock(
+            AppendValidationError::InvalidEvent(..)
+        ))
+    );
+
+    let head = chain
+        .append(
+            MetadataFactory::metadata_block(MetadataFactory::set_polling_source().build())
+                .prev(&head, 0)
+                .build(),
+            AppendOpts::default(),
+        )
+        .await
+        .unwrap();
+
+    let head = chain
+        .append(
+            MetadataFactory::metadata_block(DisablePollingSource {})
+                .prev(&head, 1)
+                .build(),
+            AppendOpts::default(),
+        )
+        .await
+        .unwrap();
+
+    // Rejects disabling the source twice
+    assert_matches!(
+        chain
+            .append(
+                MetadataFactory::metadata_block(DisablePollingSource {})
+                    .prev(&head, 2)
+                    .build(),
+                AppendOpts::default(),
+            )
+            .await,
+        Err(AppendError::InvalidBlock(
+            AppendValidationError::InvalidEvent(..)
+        ))
+    );
+
+    // Push source can be added once the polling source is disabled
+    let res = chain
+        .append(
+            MetadataFactory::metadata_block(MetadataFactory::add_push_source().build())
+                .prev(&head, 2)
+                .build(),
+            AppendOpts::default(),
+        )
+        .await;
+    assert_matches!(res, Ok(_));
+}
+
+#[test_log::test(tokio::test)]
+async fn test_append_disable_push_source() {
+    let tmp_dir = tempfile::tempdir().unwrap();
+    let chain = init_chain(tmp_dir.path());
+
+    let head = chain
+        .append(
+            MetadataFactory::metadata_block(MetadataFactory::seed(DatasetKind::Root).build())
+                .build(),
+            AppendOpts::default(),
+        )
+        .await
+        .unwrap();
+
+    let head = chain
+        .append(
+            MetadataFactory::metadata_block(
+                MetadataFactory::add_push_source()
+                    .source_name("foo")
+                    .build(),
+            )
+            .prev(&head, 0)
+            .build(),
+            AppendOpts::default(),
+        )
+        .await
+        .unwrap();
+
+    // Rejects disabling an unknown source
+    assert_matches!(
+        chain
+            .append(
+                MetadataFactory::metadata_block(DisablePushSource {
+                    source_name: "bar".to_string(),
+                })
+                .prev(&head, 1)
+                .build(),
+                AppendOpts::default(),
+            )
+            .await,
+        Err(AppendError::InvalidBlock(
+            AppendValidationError::InvalidEvent(..)
+        ))
+    );
+
+    let head = chain
+        .append(
+            MetadataFactory::metadata_block(DisablePushSource {
+                source_name: "foo".to_string(),
+            })
+            .prev(&head, 1)
+            .build(),
+            AppendOpts::default(),
+        )
+        .await
+        .unwrap();
+
+    // Rejects disabling the source twice
+    assert_matches!(
+        chain
+            .append(
+                MetadataFactory::metadata_block(DisablePushSource {
+                    source_name: "foo".to_string(),
+                })
+                .prev(&head, 2)
+                .build(),
+                AppendOpts::default(),
+            )
+            .await,
+        Err(AppendError::InvalidBlock(
+            AppendValidationError::InvalidEvent(..)
+        ))
+    );
+
+    // Polling source can be set once all push sources are disabled
+    let res = chain
+        .append(
+            MetadataFactory::metadata_block(MetadataFactory::set_polling_source().build())
+                .prev(&head, 2)
+                .build(),
+            AppendOpts::default(),
+        )
+        .await;
+    assert_matches!(res, Ok(_));
+}
+
 #[test_log::test(tokio::test)]
 async fn test_append_add_data_must_be_preseeded_by_schema() {
     let tmp_dir = tempfile::tempdir().unwrap();
@@ -722,6 +884,82 @@ async fn test_append_add_data_must_be_preseeded_by_schema() {
         .unwrap();
 }
 
+#[test_log::test(tokio::test)]
+async fn test_append_set_data_schema_evolution() {
+    use datafusion::arrow::datatypes::{DataType, Field, Schema};
+
+    let tmp_dir = tempfile::tempdir().unwrap();
+    let chain = init_chain(tmp_dir.path());
+
+    let head = chain
+        .append(
+            MetadataFactory::metadata_block(MetadataFactory::seed(DatasetKind::Root).build())
+                .build(),
+            AppendOpts::default(),
+        )
+        .await
+        .unwrap();
+
+    let head = chain
+        .append(
+            MetadataFactory::metadata_block(
+                MetadataFactory::set_data_schema()
+                    .schema(&Schema::new(vec![
+                        Field::new("city", DataType::Utf8, false),
+                        Field::new("population", DataType::Int32, false),
+                    ]))
+                    .build(),
+            )
+            .prev(&head, 0)
+            .build(),
+            AppendOpts::default(),
+        )
+        .await
+        .unwrap();
+
+    // Rejects removed columns and narrowed types
+    assert_matches!(
+        chain
+            .append(
+                MetadataFactory::metadata_block(
+                    MetadataFactory::set_data_schema()
+                        .schema(&Schema::new(vec![Field::new(
+                            "population",
+                            DataType::Int16,
+                            false
+                        )]))
+                        .build(),
+                )
+                .prev(&head, 1)
+                .build(),
+                AppendOpts::default(),
+            )
+            .await,
+        Err(AppendError::InvalidBlock(
+            AppendValidationError::InvalidEvent(..)
+        ))
+    );
+
+    // Accepts widened types, relaxed nullability and new nullable columns
+    chain
+        .append(
+            MetadataFactory::metadata_block(
+                MetadataFactory::set_data_schema()
+                    .schema(&Schema::new(vec![
+                        Field::new("city", DataType::Utf8, true),
+                        Field::new("population", DataType::Int64, false),
+                        Field::new("area", DataType::Float64, true),
+                    ]))
+                    .build(),
+            )
+            .prev(&head, 1)
+            .build(),
+            AppendOpts::default(),
+        )
+        .await
+        .unwrap();
+}
+
 #[test_log::test(tokio::test)]
 async fn test_append_execute_transform_must_be_preseeded_by_schema() {
     let tmp_dir = tempfile::tempdir().unwrap();
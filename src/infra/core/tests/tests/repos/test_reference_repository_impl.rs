@@ -47,3 +47,67 @@ async fn test_basics() {
 
     repo.delete(&BlockRef::Head).await.unwrap();
 }
+
+#[tokio::test]
+async fn test_named_refs() {
+    let tmp_repo_dir = tempfile::tempdir().unwrap();
+    let repo = ReferenceRepositoryImpl::new(NamedObjectRepositoryLocalFS::new(tmp_repo_dir.path()));
+
+    assert_eq!(repo.list().await.unwrap(), Vec::<BlockRef>::new());
+
+    let tag = BlockRef::tag("v1").unwrap();
+    let branch = BlockRef::branch("dev").unwrap();
+
+    repo.set(&BlockRef::Head, &Multihash::from_digest_sha3_256(b"foo"))
+        .await
+        .unwrap();
+    repo.set(&tag, &Multihash::from_digest_sha3_256(b"foo"))
+        .await
+        .unwrap();
+    repo.set(&branch, &Multihash::from_digest_sha3_256(b"bar"))
+        .await
+        .unwrap();
+
+    assert_eq!(
+        repo.list().await.unwrap(),
+        vec![BlockRef::Head, tag.clone(), branch.clone()]
+    );
+    assert_eq!(
+        repo.get(&branch).await.unwrap(),
+        Multihash::from_digest_sha3_256(b"bar")
+    );
+
+    repo.delete(&branch).await.unwrap();
+    assert_matches!(repo.get(&branch).await, Err(GetRefError::NotFound(_)));
+    assert_eq!(repo.list().await.unwrap(), vec![BlockRef::Head, tag]);
+}
+
+#[test]
+fn test_block_ref_parsing() {
+    use std::str::FromStr;
+
+    assert_eq!(BlockRef::from_str("head").unwrap(), BlockRef::Head);
+    assert_eq!(
+        BlockRef::from_str("tag.v2024-Q3").unwrap(),
+        BlockRef::tag("v2024-Q3").unwrap()
+    );
+    assert_eq!(
+        BlockRef::from_str("branch.exp.1").unwrap(),
+        BlockRef::branch("exp.1").unwrap()
+    );
+    assert_eq!(BlockRef::tag("v1").unwrap().to_string(), "tag.v1");
+
+    assert_matches!(BlockRef::from_str("v1"), Err(_));
+    assert_matches!(BlockRef::from_str("tag."), Err(_));
+    assert_matches!(BlockRef::from_str("branch.a/b"), Err(_));
+
+    assert_matches!(
+        BlockPointer::from_str("tag.v1").unwrap(),
+        BlockPointer::Ref(BlockRef::Tag(_))
+    );
+    let hash = Multihash::from_digest_sha3_256(b"foo");
+    assert_eq!(
+        BlockPointer::from_str(&hash.to_string()).unwrap(),
+        BlockPointer::Hash(hash)
+    );
+}
@@ -1231,6 +1231,9 @@ impl CompactTestHarness {
                         })
                         .merge(MergeStrategyLedger {
                             primary_key: vec!["date".to_string(), "city".to_string()],
+                            mode: None,
+                            dedupe_window_records: None,
+                            dedupe_window_seconds: None,
                         })
                         .build(),
                 )
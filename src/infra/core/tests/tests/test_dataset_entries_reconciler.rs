@@ -0,0 +1,276 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::assert_matches::assert_matches;
+use std::sync::Arc;
+
+use chrono::Utc;
+use database_common::DatabasePaginationOpts;
+use dill::Component;
+use kamu::domain::{
+    DatasetLifecycleMessage,
+    DatasetRepository,
+    DatasetVisibility,
+    GetDatasetError,
+};
+use kamu::testing::MetadataFactory;
+use kamu::{
+    DatasetEntriesReconciler,
+    DatasetEntriesReconciliationSummary,
+    DatasetRepositoryLocalFs,
+    DatasetRepositoryWriter,
+    DatasetStorageScanner,
+};
+use kamu_accounts::{
+    AuthenticationService,
+    CurrentAccountSubject,
+    MockAuthenticationService,
+    DEFAULT_ACCOUNT_ID,
+    DEFAULT_ACCOUNT_NAME,
+};
+use kamu_datasets::{DatasetEntry, DatasetEntryRepository};
+use kamu_datasets_inmem::InMemoryDatasetEntryRepository;
+use kamu_datasets_services::DatasetEntryServiceImpl;
+use messaging_outbox::{consume_deserialized_message, ConsumerFilter};
+use opendatafabric::{DatasetAlias, DatasetID, DatasetKind, DatasetName, DatasetRef};
+use tempfile::TempDir;
+use time_source::SystemTimeSourceDefault;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_log::test(tokio::test)]
+async fn test_reconcile_dataset_entries() {
+    let harness = DatasetEntriesReconcilerHarness::new();
+
+    let foo_id = harness.create_root_dataset("foo").await;
+    let bar_id = harness.create_root_dataset("bar").await;
+    let baz_id = harness.create_root_dataset("baz").await;
+
+    // Up-to-date entry
+    harness.save_entry(&foo_id, "foo").await;
+    // Outdated name
+    harness.save_entry(&bar_id, "bar-old").await;
+    // Dataset that no longer exists in the storage
+    let (_, stale_id) = DatasetID::new_generated_ed25519();
+    harness.save_entry(&stale_id, "stale").await;
+
+    let summary = harness.reconciler.reconcile().await.unwrap();
+    assert_eq!(
+        summary,
+        DatasetEntriesReconciliationSummary {
+            added: 1,
+            renamed: 1,
+            removed: 1,
+            skipped: 0,
+        }
+    );
+
+    let mut entries = harness
+        .dataset_entry_repo
+        .get_dataset_entries(&DatabasePaginationOpts {
+            limit: 100,
+            offset: 0,
+        })
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|entry| (entry.id, entry.owner_id, entry.name.to_string()))
+        .collect::<Vec<_>>();
+    entries.sort_by(|a, b| a.2.cmp(&b.2));
+
+    assert_eq!(
+        entries,
+        vec![
+            (bar_id, DEFAULT_ACCOUNT_ID.clone(), "bar".to_string()),
+            (baz_id, DEFAULT_ACCOUNT_ID.clone(), "baz".to_string()),
+            (foo_id, DEFAULT_ACCOUNT_ID.clone(), "foo".to_string()),
+        ]
+    );
+
+    // Nothing to do on the second run
+    assert_eq!(
+        harness.reconciler.reconcile_if_out_of_date().await.unwrap(),
+        None
+    );
+    assert_eq!(
+        harness.reconciler.reconcile().await.unwrap(),
+        DatasetEntriesReconciliationSummary::default()
+    );
+
+    // Dataset placed into the storage directly makes the catalog out of date
+    harness.create_root_dataset("qux").await;
+    assert_eq!(
+        harness.reconciler.reconcile_if_out_of_date().await.unwrap(),
+        Some(DatasetEntriesReconciliationSummary {
+            added: 1,
+            ..DatasetEntriesReconciliationSummary::default()
+        })
+    );
+}
+
+#[test_log::test(tokio::test)]
+async fn test_resolve_dataset_missing_from_entries() {
+    let harness = DatasetEntriesReconcilerHarness::new();
+
+    // Dataset is in the storage, but not in the catalog yet
+    let foo_id = harness.create_root_dataset("foo").await;
+
+    let hdl = harness
+        .dataset_repo
+        .resolve_dataset_ref(&DatasetRef::from(DatasetName::new_unchecked("foo")))
+        .await
+        .unwrap();
+    assert_eq!(hdl.id, foo_id);
+
+    let hdl = harness
+        .dataset_repo
+        .resolve_dataset_ref(&foo_id.as_local_ref())
+        .await
+        .unwrap();
+    assert_eq!(hdl.alias.dataset_name.as_str(), "foo");
+
+    assert_matches!(
+        harness
+            .dataset_repo
+            .resolve_dataset_ref(&DatasetRef::from(DatasetName::new_unchecked("bar")))
+            .await,
+        Err(GetDatasetError::NotFound(_))
+    );
+}
+
+#[test_log::test(tokio::test)]
+async fn test_created_message_without_name_resolves_it_from_storage() {
+    let harness = DatasetEntriesReconcilerHarness::new();
+
+    let foo_id = harness.create_root_dataset("foo").await;
+    let (_, missing_id) = DatasetID::new_generated_ed25519();
+
+    for dataset_id in [&foo_id, &missing_id] {
+        // Messages produced before the name was included don't have the field
+        let mut content = serde_json::to_value(DatasetLifecycleMessage::created(
+            dataset_id.clone(),
+            DEFAULT_ACCOUNT_ID.clone(),
+            DatasetVisibility::Private,
+            DatasetName::new_unchecked("unused"),
+        ))
+        .unwrap();
+        content["Created"]
+            .as_object_mut()
+            .unwrap()
+            .remove("dataset_name");
+
+        consume_deserialized_message::<DatasetLifecycleMessage>(
+            &harness.catalog,
+            ConsumerFilter::AllConsumers,
+            &content.to_string(),
+        )
+        .await
+        .unwrap();
+    }
+
+    let entries = harness
+        .dataset_entry_repo
+        .get_dataset_entries(&DatabasePaginationOpts {
+            limit: 100,
+            offset: 0,
+        })
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|entry| (entry.id, entry.name.to_string()))
+        .collect::<Vec<_>>();
+
+    assert_eq!(entries, vec![(foo_id, "foo".to_string())]);
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+struct DatasetEntriesReconcilerHarness {
+    _workdir: TempDir,
+    catalog: dill::Catalog,
+    dataset_repo: Arc<dyn DatasetRepository>,
+    dataset_repo_writer: Arc<dyn DatasetRepositoryWriter>,
+    dataset_entry_repo: Arc<dyn DatasetEntryRepository>,
+    reconciler: Arc<DatasetEntriesReconciler>,
+}
+
+impl DatasetEntriesReconcilerHarness {
+    fn new() -> Self {
+        let workdir = tempfile::tempdir().unwrap();
+        let datasets_dir = workdir.path().join("datasets");
+        std::fs::create_dir(&datasets_dir).unwrap();
+
+        let mut mock_authentication_service = MockAuthenticationService::new();
+        mock_authentication_service
+            .expect_find_account_id_by_name()
+            .returning(|account_name| {
+                Ok((*account_name == *DEFAULT_ACCOUNT_NAME).then(|| DEFAULT_ACCOUNT_ID.clone()))
+            });
+
+        let catalog = dill::CatalogBuilder::new()
+            .add::<SystemTimeSourceDefault>()
+            .add_builder(
+                DatasetRepositoryLocalFs::builder()
+                    .with_root(datasets_dir)
+                    .with_multi_tenant(false),
+            )
+            .bind::<dyn DatasetRepository, DatasetRepositoryLocalFs>()
+            .bind::<dyn DatasetRepositoryWriter, DatasetRepositoryLocalFs>()
+            .bind::<dyn DatasetStorageScanner, DatasetRepositoryLocalFs>()
+            .add_value(CurrentAccountSubject::new_test())
+            .add_value(mock_authentication_service)
+            .bind::<dyn AuthenticationService, MockAuthenticationService>()
+            .add::<InMemoryDatasetEntryRepository>()
+            .add::<DatasetEntryServiceImpl>()
+            .add::<DatasetEntriesReconciler>()
+            .build();
+
+        Self {
+            _workdir: workdir,
+            dataset_repo: catalog.get_one().unwrap(),
+            dataset_repo_writer: catalog.get_one().unwrap(),
+            dataset_entry_repo: catalog.get_one().unwrap(),
+            reconciler: catalog.get_one().unwrap(),
+            catalog,
+        }
+    }
+
+    async fn create_root_dataset(&self, dataset_name: &str) -> DatasetID {
+        let alias = DatasetAlias::new(None, DatasetName::new_unchecked(dataset_name));
+        let create_result = self
+            .dataset_repo_writer
+            .create_dataset(
+                &alias,
+                MetadataFactory::metadata_block(
+                    MetadataFactory::seed(DatasetKind::Root)
+                        .id_from(alias.dataset_name.as_str())
+                        .build(),
+                )
+                .build_typed(),
+            )
+            .await
+            .unwrap();
+
+        create_result.dataset_handle.id
+    }
+
+    async fn save_entry(&self, dataset_id: &DatasetID, dataset_name: &str) {
+        self.dataset_entry_repo
+            .save_dataset_entry(&DatasetEntry::new(
+                dataset_id.clone(),
+                DEFAULT_ACCOUNT_ID.clone(),
+                DatasetName::new_unchecked(dataset_name),
+                Utc::now(),
+            ))
+            .await
+            .unwrap();
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,42 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use kamu::utils::operation_dir::OperationDirGuard;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn test_unfinished_operation_dir_is_removed() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let operation_dir = temp_dir.path().join("ingest-abc");
+    std::fs::create_dir_all(&operation_dir).unwrap();
+    std::fs::write(operation_dir.join("staged.parquet"), b"data").unwrap();
+
+    let guard = OperationDirGuard::new(operation_dir.clone());
+    drop(guard);
+
+    assert!(!operation_dir.exists());
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn test_finished_operation_dir_is_kept() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let operation_dir = temp_dir.path().join("ingest-abc");
+    std::fs::create_dir_all(&operation_dir).unwrap();
+    std::fs::write(operation_dir.join("staged.parquet"), b"data").unwrap();
+
+    let guard = OperationDirGuard::new(operation_dir.clone());
+    guard.finish();
+
+    assert!(operation_dir.join("staged.parquet").exists());
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
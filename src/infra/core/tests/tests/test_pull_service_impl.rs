@@ -19,6 +19,7 @@ use kamu::domain::*;
 use kamu::testing::*;
 use kamu::*;
 use kamu_accounts::{CurrentAccountSubject, DEFAULT_ACCOUNT_NAME_STR};
+use messaging_outbox::DummyOutboxImpl;
 use opendatafabric::*;
 use time_source::SystemTimeSourceDefault;
 
@@ -153,6 +154,7 @@ async fn create_graph_remote(
         Arc::new(CurrentAccountSubject::new_test()),
         false,
         Arc::new(SystemTimeSourceDefault),
+        None,
     );
 
     create_graph(&remote_dataset_repo, datasets).await;
@@ -168,7 +170,11 @@ async fn create_graph_remote(
     let sync_service = SyncServiceImpl::new(
         reg.clone(),
         dataset_repo,
-        dataset_repo_writer,
+        Arc::new(CreateDatasetUseCaseImpl::new(
+            Arc::new(CurrentAccountSubject::new_test()),
+            dataset_repo_writer,
+            Arc::new(DummyOutboxImpl {}),
+        )),
         Arc::new(auth::AlwaysHappyDatasetActionAuthorizer::new()),
         Arc::new(DatasetFactoryImpl::new(
             IpfsGateway::default(),
@@ -345,7 +345,10 @@ async fn test_dataset_tail_common(catalog: dill::Catalog, tempdir: &TempDir) {
 
     // Within last block
     let query_svc = catalog.get_one::<dyn QueryService>().unwrap();
-    let df = query_svc.tail(&dataset_ref, 1, 1).await.unwrap();
+    let df = query_svc
+        .tail(&dataset_ref, 1, 1, GetDataOptions::default())
+        .await
+        .unwrap();
 
     kamu_data_utils::testing::assert_data_eq(
         df,
@@ -362,7 +365,10 @@ async fn test_dataset_tail_common(catalog: dill::Catalog, tempdir: &TempDir) {
     .await;
 
     // Crosses block boundary
-    let df = query_svc.tail(&dataset_ref, 1, 2).await.unwrap();
+    let df = query_svc
+        .tail(&dataset_ref, 1, 2, GetDataOptions::default())
+        .await
+        .unwrap();
 
     kamu_data_utils::testing::assert_data_eq(
         df,
@@ -405,6 +411,163 @@ async fn test_dataset_tail_s3() {
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
+#[test_group::group(engine, datafusion)]
+#[test_log::test(tokio::test)]
+async fn test_dataset_get_data_ranges() {
+    use ::datafusion::prelude::*;
+
+    let tempdir = tempfile::tempdir().unwrap();
+    let catalog = create_catalog_with_local_workspace(
+        tempdir.path(),
+        MockDatasetActionAuthorizer::new().expect_check_read_a_dataset(8, true),
+    );
+
+    let create_result = create_test_dataset(&catalog, tempdir.path()).await;
+    let dataset_ref = DatasetRef::from(create_result.dataset_handle.alias);
+
+    let chain = create_result.dataset.as_metadata_chain();
+    let second_add_data = chain.resolve_ref(&BlockRef::Head).await.unwrap();
+    let first_add_data = chain
+        .get_block(&second_add_data)
+        .await
+        .unwrap()
+        .prev_block_hash
+        .unwrap();
+
+    let query_svc = catalog.get_one::<dyn QueryService>().unwrap();
+
+    let get_data = |options: GetDataOptions| {
+        let query_svc = query_svc.clone();
+        let dataset_ref = dataset_ref.clone();
+        async move {
+            query_svc
+                .get_data(&dataset_ref, options)
+                .await
+                .map(|df| df.sort(vec![col("offset").sort(true, false)]).unwrap())
+        }
+    };
+
+    // Offset range crossing the block boundary
+    let df = get_data(GetDataOptions {
+        offset_range: Some(OffsetRange {
+            start: Some(1),
+            end: Some(2),
+        }),
+        ..GetDataOptions::default()
+    })
+    .await
+    .unwrap();
+
+    kamu_data_utils::testing::assert_data_eq(
+        df,
+        indoc::indoc!(
+            r#"
+            +--------+------+
+            | offset | blah |
+            +--------+------+
+            | 1      | b    |
+            | 2      | c    |
+            +--------+------+
+            "#
+        ),
+    )
+    .await;
+
+    // Records added since the block seen previously
+    let df = get_data(GetDataOptions {
+        block_range: Some(BlockRange {
+            after: Some(first_add_data.clone()),
+            up_to: None,
+        }),
+        ..GetDataOptions::default()
+    })
+    .await
+    .unwrap();
+
+    kamu_data_utils::testing::assert_data_eq(
+        df,
+        indoc::indoc!(
+            r#"
+            +--------+------+
+            | offset | blah |
+            +--------+------+
+            | 2      | c    |
+            | 3      | d    |
+            +--------+------+
+            "#
+        ),
+    )
+    .await;
+
+    // Records as of the past block
+    let df = get_data(GetDataOptions {
+        block_range: Some(BlockRange {
+            after: None,
+            up_to: Some(first_add_data.clone()),
+        }),
+        ..GetDataOptions::default()
+    })
+    .await
+    .unwrap();
+
+    kamu_data_utils::testing::assert_data_eq(
+        df,
+        indoc::indoc!(
+            r#"
+            +--------+------+
+            | offset | blah |
+            +--------+------+
+            | 0      | a    |
+            | 1      | b    |
+            +--------+------+
+            "#
+        ),
+    )
+    .await;
+
+    // Reversed block range
+    let res = get_data(GetDataOptions {
+        block_range: Some(BlockRange {
+            after: Some(second_add_data.clone()),
+            up_to: Some(first_add_data.clone()),
+        }),
+        ..GetDataOptions::default()
+    })
+    .await;
+
+    assert_matches!(res, Err(QueryError::InvalidDataRange(_)));
+
+    // Block on a different branch with the same sequence number
+    let first_add_data_block = chain.get_block(&first_add_data).await.unwrap();
+    let diverged_block = chain
+        .append(
+            MetadataFactory::metadata_block(
+                MetadataFactory::set_info().description("diverged").build(),
+            )
+            .prev(&first_add_data, first_add_data_block.sequence_number)
+            .build(),
+            AppendOpts {
+                update_ref: None,
+                ..AppendOpts::default()
+            },
+        )
+        .await
+        .unwrap();
+
+    let res = get_data(GetDataOptions {
+        block_range: Some(BlockRange {
+            after: Some(diverged_block),
+            up_to: Some(second_add_data),
+        }),
+        ..GetDataOptions::default()
+    })
+    .await;
+
+    assert_matches!(res, Err(QueryError::InvalidDataRange(_)));
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
 #[test_log::test(tokio::test)]
 async fn test_dataset_tail_empty_dataset() {
     let tempdir = tempfile::tempdir().unwrap();
@@ -428,7 +591,9 @@ async fn test_dataset_tail_empty_dataset() {
         .unwrap();
 
     let query_svc = catalog.get_one::<dyn QueryService>().unwrap();
-    let res = query_svc.tail(&"foo".try_into().unwrap(), 0, 10).await;
+    let res = query_svc
+        .tail(&"foo".try_into().unwrap(), 0, 10, GetDataOptions::default())
+        .await;
     assert_matches!(res, Err(QueryError::DatasetSchemaNotAvailable(_)));
 }
 
@@ -441,7 +606,14 @@ async fn test_dataset_tail_unauthorized_common(catalog: dill::Catalog, tempdir:
         .alias;
 
     let query_svc = catalog.get_one::<dyn QueryService>().unwrap();
-    let result = query_svc.tail(&dataset_alias.as_local_ref(), 1, 1).await;
+    let result = query_svc
+        .tail(
+            &dataset_alias.as_local_ref(),
+            1,
+            1,
+            GetDataOptions::default(),
+        )
+        .await;
     assert_matches!(result, Err(QueryError::Access(_)));
 }
 
@@ -729,6 +901,7 @@ async fn test_sql_statement_with_state_simple() {
                 source_event_time: Utc::now(),
                 new_watermark: None,
                 new_source_state: None,
+                retraction_filter: None,
                 data_staging_path: tempdir.path().join(".temp-data"),
             },
         )
@@ -805,6 +978,7 @@ async fn test_sql_statement_with_state_simple() {
                 source_event_time: Utc::now(),
                 new_watermark: None,
                 new_source_state: None,
+                retraction_filter: None,
                 data_staging_path: tempdir.path().join(".temp-data"),
             },
         )
@@ -941,6 +1115,7 @@ async fn test_sql_statement_with_state_cte() {
                 source_event_time: Utc::now(),
                 new_watermark: None,
                 new_source_state: None,
+                retraction_filter: None,
                 data_staging_path: tempdir.path().join(".temp-data"),
             },
         )
@@ -991,6 +1166,7 @@ async fn test_sql_statement_with_state_cte() {
                 source_event_time: Utc::now(),
                 new_watermark: None,
                 new_source_state: None,
+                retraction_filter: None,
                 data_staging_path: tempdir.path().join(".temp-data"),
             },
         )
@@ -1083,6 +1259,7 @@ async fn test_sql_statement_with_state_cte() {
                 source_event_time: Utc::now(),
                 new_watermark: None,
                 new_source_state: None,
+                retraction_filter: None,
                 data_staging_path: tempdir.path().join(".temp-data"),
             },
         )
@@ -1112,6 +1289,7 @@ async fn test_sql_statement_with_state_cte() {
                 source_event_time: Utc::now(),
                 new_watermark: None,
                 new_source_state: None,
+                retraction_filter: None,
                 data_staging_path: tempdir.path().join(".temp-data"),
             },
         )
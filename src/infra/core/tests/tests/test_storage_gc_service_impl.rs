@@ -0,0 +1,300 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::sync::Arc;
+
+use chrono::Duration;
+use kamu::domain::*;
+use kamu::testing::*;
+use kamu::*;
+use kamu_accounts::CurrentAccountSubject;
+use opendatafabric::*;
+use tempfile::TempDir;
+use time_source::SystemTimeSourceDefault;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_log::test(tokio::test)]
+async fn test_gc_keeps_referenced_objects() {
+    let harness = StorageGcTestHarness::new();
+    let test_case = harness.a_dataset_with_data().await;
+
+    let result = harness
+        .storage_gc_svc
+        .collect_dataset_garbage(&test_case.dataset_handle, harness.no_grace_period(false))
+        .await
+        .unwrap();
+
+    assert_eq!(result.unreferenced_objects, vec![]);
+    assert!(
+        harness
+            .data_object_exists(&test_case.dataset_handle, &test_case.data_hash)
+            .await
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_log::test(tokio::test)]
+async fn test_gc_deletes_unreferenced_objects() {
+    let harness = StorageGcTestHarness::new();
+    let test_case = harness.a_dataset_with_data().await;
+
+    let orphan_hash = harness
+        .insert_data_object(&test_case.dataset_handle, b"orphan")
+        .await;
+
+    // Dry run only reports the object
+    let result = harness
+        .storage_gc_svc
+        .collect_dataset_garbage(&test_case.dataset_handle, harness.no_grace_period(true))
+        .await
+        .unwrap();
+
+    assert_eq!(
+        result.unreferenced_objects,
+        vec![UnreferencedObject {
+            kind: StorageObjectKind::Data,
+            hash: orphan_hash.clone(),
+            size: 6,
+        }]
+    );
+    assert!(
+        harness
+            .data_object_exists(&test_case.dataset_handle, &orphan_hash)
+            .await
+    );
+
+    // Real run deletes it
+    let result = harness
+        .storage_gc_svc
+        .collect_dataset_garbage(&test_case.dataset_handle, harness.no_grace_period(false))
+        .await
+        .unwrap();
+
+    assert_eq!(result.unreferenced_objects.len(), 1);
+    assert!(
+        !harness
+            .data_object_exists(&test_case.dataset_handle, &orphan_hash)
+            .await
+    );
+    assert!(
+        harness
+            .data_object_exists(&test_case.dataset_handle, &test_case.data_hash)
+            .await
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_log::test(tokio::test)]
+async fn test_gc_respects_grace_period() {
+    let harness = StorageGcTestHarness::new();
+    let test_case = harness.a_dataset_with_data().await;
+
+    let orphan_hash = harness
+        .insert_data_object(&test_case.dataset_handle, b"orphan")
+        .await;
+
+    let result = harness
+        .storage_gc_svc
+        .collect_dataset_garbage(&test_case.dataset_handle, StorageGcOptions::default())
+        .await
+        .unwrap();
+
+    assert_eq!(result.unreferenced_objects, vec![]);
+    assert!(
+        harness
+            .data_object_exists(&test_case.dataset_handle, &orphan_hash)
+            .await
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_log::test(tokio::test)]
+async fn test_gc_keeps_objects_reachable_from_tags() {
+    let harness = StorageGcTestHarness::new();
+    let test_case = harness.a_dataset_with_data().await;
+
+    let dataset = harness
+        .dataset_repo
+        .get_dataset_by_handle(&test_case.dataset_handle);
+    let chain = dataset.as_metadata_chain();
+
+    // Tag the block with data and then move head back to the seed
+    chain
+        .set_ref(
+            &BlockRef::tag("v1").unwrap(),
+            &test_case.add_data_block_hash,
+            SetRefOpts::default(),
+        )
+        .await
+        .unwrap();
+    chain
+        .set_ref(&BlockRef::Head, &test_case.seed_hash, SetRefOpts::default())
+        .await
+        .unwrap();
+
+    let result = harness
+        .storage_gc_svc
+        .collect_dataset_garbage(&test_case.dataset_handle, harness.no_grace_period(false))
+        .await
+        .unwrap();
+
+    assert_eq!(result.unreferenced_objects, vec![]);
+
+    // Once the tag is gone the data becomes garbage
+    chain
+        .as_reference_repo()
+        .delete(&BlockRef::tag("v1").unwrap())
+        .await
+        .unwrap();
+
+    let result = harness
+        .storage_gc_svc
+        .collect_dataset_garbage(&test_case.dataset_handle, harness.no_grace_period(false))
+        .await
+        .unwrap();
+
+    assert_eq!(
+        result
+            .unreferenced_objects
+            .into_iter()
+            .map(|o| o.hash)
+            .collect::<Vec<_>>(),
+        vec![test_case.data_hash]
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+struct DatasetWithDataTestCase {
+    dataset_handle: DatasetHandle,
+    seed_hash: Multihash,
+    add_data_block_hash: Multihash,
+    data_hash: Multihash,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+struct StorageGcTestHarness {
+    _temp_dir: TempDir,
+    dataset_repo: Arc<dyn DatasetRepository>,
+    dataset_repo_writer: Arc<dyn DatasetRepositoryWriter>,
+    storage_gc_svc: Arc<dyn StorageGcService>,
+}
+
+impl StorageGcTestHarness {
+    fn new() -> Self {
+        let tempdir = tempfile::tempdir().unwrap();
+        let datasets_dir = tempdir.path().join("datasets");
+        std::fs::create_dir(&datasets_dir).unwrap();
+
+        let catalog = dill::CatalogBuilder::new()
+            .add::<SystemTimeSourceDefault>()
+            .add_value(CurrentAccountSubject::new_test())
+            .add_value(MockDatasetActionAuthorizer::allowing())
+            .bind::<dyn auth::DatasetActionAuthorizer, MockDatasetActionAuthorizer>()
+            .add_builder(
+                DatasetRepositoryLocalFs::builder()
+                    .with_root(datasets_dir)
+                    .with_multi_tenant(false),
+            )
+            .bind::<dyn DatasetRepository, DatasetRepositoryLocalFs>()
+            .bind::<dyn DatasetRepositoryWriter, DatasetRepositoryLocalFs>()
+            .add::<StorageGcServiceImpl>()
+            .build();
+
+        Self {
+            _temp_dir: tempdir,
+            dataset_repo: catalog.get_one().unwrap(),
+            dataset_repo_writer: catalog.get_one().unwrap(),
+            storage_gc_svc: catalog.get_one().unwrap(),
+        }
+    }
+
+    fn no_grace_period(&self, dry_run: bool) -> StorageGcOptions {
+        StorageGcOptions {
+            dry_run,
+            grace_period: Duration::zero(),
+        }
+    }
+
+    async fn data_object_exists(&self, dataset_handle: &DatasetHandle, hash: &Multihash) -> bool {
+        self.dataset_repo
+            .get_dataset_by_handle(dataset_handle)
+            .as_data_repo()
+            .contains(hash)
+            .await
+            .unwrap()
+    }
+
+    async fn insert_data_object(&self, dataset_handle: &DatasetHandle, data: &[u8]) -> Multihash {
+        self.dataset_repo
+            .get_dataset_by_handle(dataset_handle)
+            .as_data_repo()
+            .insert_bytes(data, InsertOpts::default())
+            .await
+            .unwrap()
+            .hash
+    }
+
+    async fn a_dataset_with_data(&self) -> DatasetWithDataTestCase {
+        let dataset_name = DatasetName::try_from("foo").unwrap();
+
+        let seed_block = MetadataFactory::metadata_block(
+            MetadataFactory::seed(DatasetKind::Root)
+                .id_from(dataset_name.as_str())
+                .build(),
+        )
+        .build_typed();
+
+        let create_result = self
+            .dataset_repo_writer
+            .create_dataset(&DatasetAlias::new(None, dataset_name), seed_block)
+            .await
+            .unwrap();
+
+        let dataset_handle = create_result.dataset_handle;
+        let data_hash = self.insert_data_object(&dataset_handle, b"data").await;
+
+        create_result
+            .dataset
+            .commit_event(
+                MetadataEvent::SetDataSchema(MetadataFactory::set_data_schema().build()),
+                CommitOpts::default(),
+            )
+            .await
+            .unwrap();
+
+        let add_data_block_hash = create_result
+            .dataset
+            .commit_event(
+                MetadataEvent::AddData(
+                    MetadataFactory::add_data()
+                        .new_data_physical_hash(data_hash.clone())
+                        .build(),
+                ),
+                CommitOpts::default(),
+            )
+            .await
+            .unwrap()
+            .new_head;
+
+        DatasetWithDataTestCase {
+            dataset_handle,
+            seed_hash: create_result.head,
+            add_data_block_hash,
+            data_hash,
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
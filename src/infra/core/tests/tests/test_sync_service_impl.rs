@@ -17,6 +17,7 @@ use kamu::testing::*;
 use kamu::utils::ipfs_wrapper::IpfsClient;
 use kamu::*;
 use kamu_accounts::CurrentAccountSubject;
+use messaging_outbox::DummyOutboxImpl;
 use opendatafabric::*;
 use time_source::SystemTimeSourceDefault;
 use url::Url;
@@ -113,6 +114,8 @@ async fn do_test_sync(
         .add::<DatasetFactoryImpl>()
         .add::<SyncServiceImpl>()
         .add::<DummySmartTransferProtocolClient>()
+        .add::<CreateDatasetUseCaseImpl>()
+        .add::<DummyOutboxImpl>()
         .build();
 
     let sync_svc = catalog.get_one::<dyn SyncService>().unwrap();
@@ -515,3 +518,220 @@ async fn test_sync_to_from_ipfs() {
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_log::test(tokio::test)]
+async fn test_sync_refs_to_from_local_fs() {
+    let tmp_workspace_dir = tempfile::tempdir().unwrap();
+    let tmp_repo_dir = tempfile::tempdir().unwrap();
+    let repo_ref = DatasetRefRemote::from(Url::from_directory_path(tmp_repo_dir.path()).unwrap());
+
+    // Tests sync of references between "foo" -> remote -> "bar"
+    let foo_alias = DatasetAlias::new(None, DatasetName::new_unchecked("foo"));
+    let bar_alias = DatasetAlias::new(None, DatasetName::new_unchecked("bar"));
+
+    let datasets_dir = tmp_workspace_dir.path().join("datasets");
+    std::fs::create_dir(&datasets_dir).unwrap();
+
+    let catalog = dill::CatalogBuilder::new()
+        .add::<SystemTimeSourceDefault>()
+        .add_value(IpfsGateway::default())
+        .add_value(IpfsClient::default())
+        .add_value(CurrentAccountSubject::new_test())
+        .add::<auth::AlwaysHappyDatasetActionAuthorizer>()
+        .add_builder(
+            DatasetRepositoryLocalFs::builder()
+                .with_root(datasets_dir)
+                .with_multi_tenant(false),
+        )
+        .bind::<dyn DatasetRepository, DatasetRepositoryLocalFs>()
+        .bind::<dyn DatasetRepositoryWriter, DatasetRepositoryLocalFs>()
+        .add_value(RemoteReposDir::new(tmp_workspace_dir.path().join("repos")))
+        .add::<RemoteRepositoryRegistryImpl>()
+        .add::<auth::DummyOdfServerAccessTokenResolver>()
+        .add::<DatasetFactoryImpl>()
+        .add::<SyncServiceImpl>()
+        .add::<DummySmartTransferProtocolClient>()
+        .add::<CreateDatasetUseCaseImpl>()
+        .add::<DummyOutboxImpl>()
+        .build();
+
+    let sync_svc = catalog.get_one::<dyn SyncService>().unwrap();
+    let dataset_repo = catalog.get_one::<DatasetRepositoryLocalFs>().unwrap();
+
+    let sync_with_opts = |src: DatasetRefAny, dst: DatasetRefAny, opts: SyncOptions| {
+        let sync_svc = sync_svc.clone();
+        async move { sync_svc.sync(&src, &dst, opts, None).await }
+    };
+    let sync =
+        |src: DatasetRefAny, dst: DatasetRefAny| sync_with_opts(src, dst, SyncOptions::default());
+
+    let b1 = dataset_repo
+        .create_dataset_from_snapshot(
+            MetadataFactory::dataset_snapshot()
+                .name(foo_alias.clone())
+                .kind(DatasetKind::Root)
+                .push_event(MetadataFactory::set_data_schema().build())
+                .build(),
+        )
+        .await
+        .unwrap()
+        .create_dataset_result
+        .head;
+
+    let foo = dataset_repo
+        .find_dataset_by_ref(&foo_alias.as_local_ref())
+        .await
+        .unwrap();
+    let foo_chain = foo.as_metadata_chain();
+
+    let tag_v1 = BlockRef::tag("v1").unwrap();
+    let branch_dev = BlockRef::branch("dev").unwrap();
+    foo_chain
+        .set_ref(&tag_v1, &b1, SetRefOpts::default())
+        .await
+        .unwrap();
+    foo_chain
+        .set_ref(&branch_dev, &b1, SetRefOpts::default())
+        .await
+        .unwrap();
+
+    // Initial sync creates the references
+    sync(foo_alias.as_any_ref(), repo_ref.as_any_ref())
+        .await
+        .unwrap();
+    sync(repo_ref.as_any_ref(), bar_alias.as_any_ref())
+        .await
+        .unwrap();
+
+    let bar = dataset_repo
+        .find_dataset_by_ref(&bar_alias.as_local_ref())
+        .await
+        .unwrap();
+    let bar_chain = bar.as_metadata_chain();
+
+    assert_eq!(
+        bar_chain.list_refs().await.unwrap(),
+        vec![
+            (BlockRef::Head, b1.clone()),
+            (tag_v1.clone(), b1.clone()),
+            (branch_dev.clone(), b1.clone()),
+        ]
+    );
+
+    // Branches are fast-forwarded, references deleted in the source are kept by
+    // default
+    let b2 = DatasetTestHelper::append_random_data(
+        dataset_repo.as_ref(),
+        &foo_alias,
+        FILE_DATA_ARRAY_SIZE,
+    )
+    .await;
+
+    let tag_v2 = BlockRef::tag("v2").unwrap();
+    foo_chain
+        .set_ref(&branch_dev, &b2, SetRefOpts::default())
+        .await
+        .unwrap();
+    foo_chain
+        .set_ref(&tag_v2, &b2, SetRefOpts::default())
+        .await
+        .unwrap();
+    foo_chain.as_reference_repo().delete(&tag_v1).await.unwrap();
+
+    sync(foo_alias.as_any_ref(), repo_ref.as_any_ref())
+        .await
+        .unwrap();
+    sync(repo_ref.as_any_ref(), bar_alias.as_any_ref())
+        .await
+        .unwrap();
+
+    assert_eq!(
+        bar_chain.list_refs().await.unwrap(),
+        vec![
+            (BlockRef::Head, b2.clone()),
+            (tag_v1.clone(), b1.clone()),
+            (tag_v2.clone(), b2.clone()),
+            (branch_dev.clone(), b2.clone()),
+        ]
+    );
+
+    // Deletions are only propagated when requested explicitly
+    let prune_refs = || SyncOptions {
+        delete_missing_refs: true,
+        ..SyncOptions::default()
+    };
+    sync_with_opts(foo_alias.as_any_ref(), repo_ref.as_any_ref(), prune_refs())
+        .await
+        .unwrap();
+    sync_with_opts(repo_ref.as_any_ref(), bar_alias.as_any_ref(), prune_refs())
+        .await
+        .unwrap();
+
+    assert_eq!(
+        bar_chain.list_refs().await.unwrap(),
+        vec![
+            (BlockRef::Head, b2.clone()),
+            (tag_v2.clone(), b2.clone()),
+            (branch_dev.clone(), b2.clone()),
+        ]
+    );
+
+    // Tags are immutable: a conflicting tag is rejected before any blocks are
+    // transferred
+    let tag_release = BlockRef::tag("release").unwrap();
+    bar_chain
+        .set_ref(&tag_release, &b1, SetRefOpts::default())
+        .await
+        .unwrap();
+
+    let b3 = DatasetTestHelper::append_random_data(
+        dataset_repo.as_ref(),
+        &foo_alias,
+        FILE_DATA_ARRAY_SIZE,
+    )
+    .await;
+    foo_chain
+        .set_ref(&tag_release, &b3, SetRefOpts::default())
+        .await
+        .unwrap();
+
+    sync(foo_alias.as_any_ref(), repo_ref.as_any_ref())
+        .await
+        .unwrap();
+
+    assert_matches!(
+        sync(repo_ref.as_any_ref(), bar_alias.as_any_ref()).await,
+        Err(SyncError::RefConflict(RefConflictError {
+            block_ref,
+            src_hash,
+            dst_hash,
+        })) if block_ref == tag_release && src_hash == b3 && dst_hash == b1
+    );
+
+    assert_eq!(bar_chain.resolve_ref(&BlockRef::Head).await.unwrap(), b2);
+    assert_eq!(bar_chain.resolve_ref(&tag_release).await.unwrap(), b1);
+
+    // Same applies to the pushes
+    foo_chain
+        .as_reference_repo()
+        .delete(&tag_release)
+        .await
+        .unwrap();
+    foo_chain
+        .set_ref(&tag_release, &b2, SetRefOpts::default())
+        .await
+        .unwrap();
+    DatasetTestHelper::append_random_data(dataset_repo.as_ref(), &foo_alias, FILE_DATA_ARRAY_SIZE)
+        .await;
+
+    assert_matches!(
+        sync(foo_alias.as_any_ref(), repo_ref.as_any_ref()).await,
+        Err(SyncError::RefConflict(RefConflictError {
+            block_ref,
+            src_hash,
+            dst_hash,
+        })) if block_ref == tag_release && src_hash == b2 && dst_hash == b3
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -658,6 +658,9 @@ async fn test_transform_with_compaction_retry() {
                         })
                         .merge(MergeStrategyLedger {
                             primary_key: vec!["date".to_string(), "city".to_string()],
+                            mode: None,
+                            dedupe_window_records: None,
+                            dedupe_window_seconds: None,
                         })
                         .build(),
                 )
@@ -737,6 +740,7 @@ async fn test_transform_with_compaction_retry() {
             &bar.as_local_ref(),
             TransformOptions {
                 reset_derivatives_on_diverged_input: true,
+                ..TransformOptions::default()
             },
             None,
         )
@@ -17,11 +17,15 @@ use kamu_accounts::CurrentAccountSubject;
 use kamu_core::auth::DatasetActionAuthorizer;
 use kamu_core::{
     CreateDatasetResult,
+    DatasetLifecycleMessage,
     DatasetRepository,
     GetDatasetError,
     RenameDatasetError,
     RenameDatasetUseCase,
+    MESSAGE_PRODUCER_KAMU_CORE_DATASET_SERVICE,
 };
+use messaging_outbox::{MockOutbox, Outbox};
+use mockall::predicate::{eq, function};
 use opendatafabric::{DatasetAlias, DatasetKind, DatasetName};
 use time_source::SystemTimeSourceDefault;
 
@@ -35,7 +39,14 @@ async fn test_rename_dataset_success_via_ref() {
     let mock_authorizer =
         MockDatasetActionAuthorizer::new().expect_check_write_dataset(&alias_foo, 1, true);
 
-    let harness = RenameUseCaseHarness::new(mock_authorizer);
+    let mut mock_outbox = MockOutbox::new();
+    RenameUseCaseHarness::add_outbox_dataset_renamed_expectation(
+        &mut mock_outbox,
+        &alias_bar.dataset_name,
+        1,
+    );
+
+    let harness = RenameUseCaseHarness::new(mock_authorizer, mock_outbox);
     harness.create_root_dataset(&alias_foo).await;
 
     assert_matches!(harness.check_dataset_exists(&alias_foo).await, Ok(_));
@@ -61,7 +72,7 @@ async fn test_rename_dataset_success_via_ref() {
 
 #[tokio::test]
 async fn test_rename_dataset_not_found() {
-    let harness = RenameUseCaseHarness::new(MockDatasetActionAuthorizer::new());
+    let harness = RenameUseCaseHarness::new(MockDatasetActionAuthorizer::new(), MockOutbox::new());
 
     let alias_foo = DatasetAlias::new(None, DatasetName::new_unchecked("foo"));
     assert_matches!(
@@ -84,6 +95,7 @@ async fn test_rename_dataset_unauthorized() {
 
     let harness = RenameUseCaseHarness::new(
         MockDatasetActionAuthorizer::new().expect_check_write_dataset(&alias_foo, 1, false),
+        MockOutbox::new(),
     );
 
     harness.create_root_dataset(&alias_foo).await;
@@ -111,7 +123,10 @@ struct RenameUseCaseHarness {
 }
 
 impl RenameUseCaseHarness {
-    fn new(mock_dataset_action_authorizer: MockDatasetActionAuthorizer) -> Self {
+    fn new(
+        mock_dataset_action_authorizer: MockDatasetActionAuthorizer,
+        mock_outbox: MockOutbox,
+    ) -> Self {
         let tempdir = tempfile::tempdir().unwrap();
 
         let datasets_dir = tempdir.path().join("datasets");
@@ -129,6 +144,8 @@ impl RenameUseCaseHarness {
             .add_value(CurrentAccountSubject::new_test())
             .add_value(mock_dataset_action_authorizer)
             .bind::<dyn DatasetActionAuthorizer, MockDatasetActionAuthorizer>()
+            .add_value(mock_outbox)
+            .bind::<dyn Outbox, MockOutbox>()
             .add::<SystemTimeSourceDefault>()
             .build();
 
@@ -168,6 +185,28 @@ impl RenameUseCaseHarness {
             .await?;
         Ok(())
     }
+
+    fn add_outbox_dataset_renamed_expectation(
+        mock_outbox: &mut MockOutbox,
+        new_dataset_name: &DatasetName,
+        times: usize,
+    ) {
+        let new_dataset_name = new_dataset_name.clone();
+        mock_outbox
+            .expect_post_message_as_json()
+            .with(
+                eq(MESSAGE_PRODUCER_KAMU_CORE_DATASET_SERVICE),
+                function(move |message_as_json: &serde_json::Value| {
+                    matches!(
+                        serde_json::from_value::<DatasetLifecycleMessage>(message_as_json.clone()),
+                        Ok(DatasetLifecycleMessage::Renamed(message))
+                            if message.new_dataset_name == new_dataset_name
+                    )
+                }),
+            )
+            .times(times)
+            .returning(|_, _| Ok(()));
+    }
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,275 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::net::{SocketAddr, TcpListener};
+use std::sync::{Arc, Mutex};
+
+use serde_json::{json, Value};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// In-memory stand-in for an Ethereum node that serves the subset of JSON-RPC
+/// API used by the logs ingestion. Blocks are mined explicitly and the chain
+/// can be re-organized by dropping the most recent blocks.
+pub struct EthNodeStub {
+    pub url: String,
+    chain: Arc<Mutex<StubChain>>,
+    server: tokio::task::JoinHandle<()>,
+}
+
+#[derive(Debug, Clone)]
+pub struct StubLog {
+    pub address: String,
+    pub topics: Vec<String>,
+    pub data: String,
+}
+
+struct StubChain {
+    chain_id: u64,
+    blocks: Vec<StubBlock>,
+    next_hash: u64,
+}
+
+struct StubBlock {
+    hash: String,
+    parent_hash: String,
+    logs: Vec<StubLog>,
+}
+
+impl EthNodeStub {
+    pub const CHAIN_ID: u64 = 1337;
+
+    pub fn new() -> Self {
+        let mut chain = StubChain {
+            chain_id: Self::CHAIN_ID,
+            blocks: Vec::new(),
+            next_hash: 1,
+        };
+        chain.mine(Vec::new());
+
+        let chain = Arc::new(Mutex::new(chain));
+
+        let app = axum::Router::new()
+            .route("/", axum::routing::post(Self::handle_request))
+            .layer(axum::extract::Extension(chain.clone()));
+
+        let listener = TcpListener::bind(SocketAddr::from(([127, 0, 0, 1], 0))).unwrap();
+        let server = axum::Server::from_tcp(listener)
+            .unwrap()
+            .serve(app.into_make_service());
+        let url = format!("http://{}", server.local_addr());
+
+        let server = tokio::spawn(async move {
+            server.await.unwrap();
+        });
+
+        Self { url, chain, server }
+    }
+
+    /// Appends a new block with the specified logs, returning its number
+    pub fn mine(&self, logs: Vec<StubLog>) -> u64 {
+        self.chain.lock().unwrap().mine(logs)
+    }
+
+    /// Drops the specified number of the most recent blocks, so that blocks
+    /// mined afterwards replace them with different hashes
+    pub fn reorg(&self, depth: usize) {
+        let mut chain = self.chain.lock().unwrap();
+        let len = chain.blocks.len();
+        chain.blocks.truncate(len - depth);
+    }
+
+    async fn handle_request(
+        axum::extract::Extension(chain): axum::extract::Extension<Arc<Mutex<StubChain>>>,
+        axum::Json(request): axum::Json<Value>,
+    ) -> axum::Json<Value> {
+        let chain = chain.lock().unwrap();
+
+        let response = match request {
+            Value::Array(requests) => {
+                Value::Array(requests.iter().map(|r| chain.handle_call(r)).collect())
+            }
+            request => chain.handle_call(&request),
+        };
+
+        axum::Json(response)
+    }
+}
+
+impl Drop for EthNodeStub {
+    fn drop(&mut self) {
+        self.server.abort();
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl StubChain {
+    fn mine(&mut self, logs: Vec<StubLog>) -> u64 {
+        let parent_hash = self
+            .blocks
+            .last()
+            .map_or_else(|| hex_hash(0), |b| b.hash.clone());
+
+        self.blocks.push(StubBlock {
+            hash: hex_hash(self.next_hash),
+            parent_hash,
+            logs,
+        });
+        self.next_hash += 1;
+
+        self.blocks.len() as u64 - 1
+    }
+
+    fn head(&self) -> u64 {
+        self.blocks.len() as u64 - 1
+    }
+
+    fn handle_call(&self, request: &Value) -> Value {
+        let params = &request["params"];
+
+        let result = match request["method"].as_str().unwrap() {
+            "eth_chainId" => json!(hex_quantity(self.chain_id)),
+            "net_version" => json!(self.chain_id.to_string()),
+            "eth_blockNumber" => json!(hex_quantity(self.head())),
+            "eth_getBlockByNumber" => self
+                .resolve_block_number(&params[0])
+                .map_or(Value::Null, |number| self.block_json(number)),
+            "eth_getBlockByHash" => self
+                .find_block_by_hash(params[0].as_str().unwrap())
+                .map_or(Value::Null, |number| self.block_json(number)),
+            "eth_getLogs" => self.logs_json(&params[0]),
+            method => {
+                return json!({
+                    "jsonrpc": "2.0",
+                    "id": request["id"],
+                    "error": {
+                        "code": -32601,
+                        "message": format!("Method not supported by stub: {method}"),
+                    },
+                })
+            }
+        };
+
+        json!({
+            "jsonrpc": "2.0",
+            "id": request["id"],
+            "result": result,
+        })
+    }
+
+    fn resolve_block_number(&self, tag: &Value) -> Option<u64> {
+        let number = match tag.as_str().unwrap_or("latest") {
+            "earliest" => 0,
+            "latest" | "safe" | "finalized" | "pending" => self.head(),
+            number => u64::from_str_radix(number.trim_start_matches("0x"), 16).unwrap(),
+        };
+        (number <= self.head()).then_some(number)
+    }
+
+    fn find_block_by_hash(&self, hash: &str) -> Option<u64> {
+        self.blocks
+            .iter()
+            .position(|b| b.hash == hash)
+            .map(|i| i as u64)
+    }
+
+    fn block_json(&self, number: u64) -> Value {
+        let block = &self.blocks[usize::try_from(number).unwrap()];
+        let zero_hash = hex_hash(0);
+
+        json!({
+            "hash": block.hash,
+            "parentHash": block.parent_hash,
+            "sha3Uncles": zero_hash,
+            "miner": format!("0x{}", "0".repeat(40)),
+            "stateRoot": zero_hash,
+            "transactionsRoot": zero_hash,
+            "receiptsRoot": zero_hash,
+            "logsBloom": format!("0x{}", "0".repeat(512)),
+            "difficulty": "0x0",
+            "number": hex_quantity(number),
+            "gasLimit": "0x1c9c380",
+            "gasUsed": "0x0",
+            "timestamp": hex_quantity(1_700_000_000 + number * 12),
+            "extraData": "0x",
+            "mixHash": zero_hash,
+            "nonce": "0x0000000000000000",
+            "baseFeePerGas": "0x7",
+            "totalDifficulty": "0x0",
+            "size": "0x220",
+            "uncles": [],
+            "transactions": [],
+        })
+    }
+
+    fn logs_json(&self, filter: &Value) -> Value {
+        let (from, to) = if let Some(hash) = filter["blockHash"].as_str() {
+            match self.find_block_by_hash(hash) {
+                Some(number) => (number, number),
+                None => return json!([]),
+            }
+        } else {
+            let from = self
+                .resolve_block_number(&json!(filter["fromBlock"].as_str().unwrap_or("earliest")))
+                .unwrap_or(self.head() + 1);
+            let to = self
+                .resolve_block_number(&filter["toBlock"])
+                .unwrap_or(self.head());
+            (from, to)
+        };
+
+        let addresses: Vec<&str> = match &filter["address"] {
+            Value::String(address) => vec![address.as_str()],
+            Value::Array(addresses) => addresses.iter().filter_map(Value::as_str).collect(),
+            _ => Vec::new(),
+        };
+
+        let mut logs = Vec::new();
+        let mut log_index = 0;
+
+        for number in from..=to {
+            let block = &self.blocks[usize::try_from(number).unwrap()];
+
+            for (transaction_index, log) in block.logs.iter().enumerate() {
+                let matches_address = addresses.is_empty()
+                    || addresses
+                        .iter()
+                        .any(|a| a.eq_ignore_ascii_case(&log.address));
+
+                if matches_address {
+                    logs.push(json!({
+                        "address": log.address,
+                        "topics": log.topics,
+                        "data": log.data,
+                        "blockHash": block.hash,
+                        "blockNumber": hex_quantity(number),
+                        "transactionHash": hex_hash(number * 1000 + transaction_index as u64),
+                        "transactionIndex": hex_quantity(transaction_index as u64),
+                        "logIndex": hex_quantity(log_index),
+                        "removed": false,
+                    }));
+                }
+                log_index += 1;
+            }
+        }
+
+        Value::Array(logs)
+    }
+}
+
+fn hex_quantity(value: u64) -> String {
+    format!("0x{value:x}")
+}
+
+fn hex_hash(value: u64) -> String {
+    format!("0x{value:064x}")
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -50,6 +50,7 @@ impl Engine for EngineStub {
     async fn execute_raw_query(
         &self,
         _request: RawQueryRequestExt,
+        _cancellation_token: &CancellationToken,
     ) -> Result<RawQueryResponseExt, EngineError> {
         unimplemented!()
     }
@@ -57,6 +58,7 @@ impl Engine for EngineStub {
     async fn execute_transform(
         &self,
         _request: TransformRequestExt,
+        _cancellation_token: &CancellationToken,
     ) -> Result<TransformResponseExt, EngineError> {
         // Note: At least 1 output field must be present, watermark is easy to mimic
         Ok(TransformResponseExt {
@@ -65,6 +67,7 @@ impl Engine for EngineStub {
             output_schema: None,
             new_checkpoint: None,
             new_data: None,
+            log_files: Vec::new(),
         })
     }
 }
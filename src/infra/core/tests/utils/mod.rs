@@ -7,6 +7,8 @@
 // the Business Source License, use of this software will be governed
 // by the Apache License, Version 2.0.
 
+#[cfg(feature = "ingest-evm")]
+mod eth_node_stub;
 #[cfg(feature = "ingest-ftp")]
 mod ftp_server;
 mod http_server;
@@ -16,6 +18,8 @@ mod mqtt_broker;
 
 pub mod mock_engine_provisioner;
 
+#[cfg(feature = "ingest-evm")]
+pub use eth_node_stub::*;
 #[cfg(feature = "ingest-ftp")]
 pub use ftp_server::*;
 pub use http_server::*;
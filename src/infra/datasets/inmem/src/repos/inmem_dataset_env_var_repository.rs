@@ -100,6 +100,24 @@ impl DatasetEnvVarRepository for InMemoryDatasetEnvVarRepository {
         Ok(())
     }
 
+    async fn get_all_dataset_env_vars(
+        &self,
+        pagination: &DatabasePaginationOpts,
+    ) -> Result<Vec<DatasetEnvVar>, GetDatasetEnvVarError> {
+        let guard = self.state.lock().unwrap();
+
+        let mut dataset_env_vars: Vec<_> = guard.dataset_env_vars_by_ids.values().collect();
+        dataset_env_vars
+            .sort_by_key(|dataset_env_var| (dataset_env_var.created_at, dataset_env_var.id));
+
+        Ok(dataset_env_vars
+            .into_iter()
+            .skip(usize::try_from(pagination.offset).unwrap())
+            .take(usize::try_from(pagination.limit).unwrap())
+            .cloned()
+            .collect())
+    }
+
     async fn get_all_dataset_env_vars_by_dataset_id(
         &self,
         dataset_id: &DatasetID,
@@ -212,6 +230,7 @@ impl DatasetEnvVarRepository for InMemoryDatasetEnvVarRepository {
         dataset_env_var_id: &Uuid,
         new_value: Vec<u8>,
         secret_nonce: Option<Vec<u8>>,
+        encryption_key_id: Option<String>,
     ) -> Result<(), ModifyDatasetEnvVarError> {
         let mut guard = self.state.lock().unwrap();
         if let Some(existing_dataset_env_var) =
@@ -219,6 +238,7 @@ impl DatasetEnvVarRepository for InMemoryDatasetEnvVarRepository {
         {
             existing_dataset_env_var.value = new_value;
             existing_dataset_env_var.secret_nonce = secret_nonce;
+            existing_dataset_env_var.encryption_key_id = encryption_key_id;
             return Ok(());
         }
         return Err(ModifyDatasetEnvVarError::NotFound(
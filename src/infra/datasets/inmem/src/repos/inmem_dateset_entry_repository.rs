@@ -10,6 +10,7 @@
 use std::collections::HashMap;
 use std::sync::Arc;
 
+use database_common::DatabasePaginationOpts;
 use dill::{component, interface, scope, Singleton};
 use kamu_datasets::{
     DatasetEntry,
@@ -19,6 +20,7 @@ use kamu_datasets::{
     DatasetEntryRepository,
     DeleteEntryDatasetError,
     GetDatasetEntriesByOwnerIdError,
+    GetDatasetEntriesError,
     GetDatasetEntryByNameError,
     GetDatasetEntryError,
     SaveDatasetEntryError,
@@ -100,24 +102,56 @@ impl DatasetEntryRepository for InMemoryDatasetEntryRepository {
         Ok(dataset_entry.clone())
     }
 
+    async fn dataset_entries_count(&self) -> Result<usize, GetDatasetEntriesError> {
+        let readable_state = self.state.read().await;
+
+        Ok(readable_state.rows.len())
+    }
+
+    async fn get_dataset_entries(
+        &self,
+        pagination: &DatabasePaginationOpts,
+    ) -> Result<Vec<DatasetEntry>, GetDatasetEntriesError> {
+        let readable_state = self.state.read().await;
+
+        let mut dataset_entries = readable_state.rows.values().collect::<Vec<_>>();
+
+        dataset_entries.sort_by(|a, b| a.name.cmp(&b.name).then_with(|| a.id.cmp(&b.id)));
+
+        Ok(paginate(dataset_entries, pagination))
+    }
+
+    async fn dataset_entries_count_by_owner_id(
+        &self,
+        owner_id: &AccountID,
+    ) -> Result<usize, GetDatasetEntriesByOwnerIdError> {
+        let readable_state = self.state.read().await;
+
+        let count = readable_state
+            .rows
+            .values()
+            .filter(|dataset| dataset.owner_id == *owner_id)
+            .count();
+
+        Ok(count)
+    }
+
     async fn get_dataset_entries_by_owner_id(
         &self,
         owner_id: &AccountID,
+        pagination: &DatabasePaginationOpts,
     ) -> Result<Vec<DatasetEntry>, GetDatasetEntriesByOwnerIdError> {
         let readable_state = self.state.read().await;
 
-        let dataset_entries = readable_state
+        let mut dataset_entries = readable_state
             .rows
             .values()
-            .fold(vec![], |mut acc, dataset| {
-                if dataset.owner_id == *owner_id {
-                    acc.push(dataset.clone());
-                }
+            .filter(|dataset| dataset.owner_id == *owner_id)
+            .collect::<Vec<_>>();
 
-                acc
-            });
+        dataset_entries.sort_by(|a, b| a.name.cmp(&b.name));
 
-        Ok(dataset_entries)
+        Ok(paginate(dataset_entries, pagination))
     }
 
     async fn save_dataset_entry(
@@ -192,3 +226,17 @@ impl DatasetEntryRepository for InMemoryDatasetEntryRepository {
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+fn paginate(
+    dataset_entries: Vec<&DatasetEntry>,
+    pagination: &DatabasePaginationOpts,
+) -> Vec<DatasetEntry> {
+    dataset_entries
+        .into_iter()
+        .skip(usize::try_from(pagination.offset).unwrap())
+        .take(usize::try_from(pagination.limit).unwrap())
+        .cloned()
+        .collect()
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
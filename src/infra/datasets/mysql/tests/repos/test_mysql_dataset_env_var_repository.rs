@@ -0,0 +1,85 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use database_common::MySqlTransactionManager;
+use database_common_macros::database_transactional_test;
+use dill::{Catalog, CatalogBuilder};
+use kamu_datasets_mysql::MySqlDatasetEnvVarRepository;
+use kamu_datasets_repo_tests::dataset_env_var_repo;
+use sqlx::MySqlPool;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+database_transactional_test!(
+    storage = mysql,
+    fixture = dataset_env_var_repo::test_missing_dataset_env_var_not_found,
+    harness = MySqlDatasetEnvVarRepositoryHarness
+);
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+database_transactional_test!(
+    storage = mysql,
+    fixture = dataset_env_var_repo::test_insert_and_get_dataset_env_var,
+    harness = MySqlDatasetEnvVarRepositoryHarness
+);
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+database_transactional_test!(
+    storage = mysql,
+    fixture = dataset_env_var_repo::test_insert_and_get_multiple_dataset_env_vars,
+    harness = MySqlDatasetEnvVarRepositoryHarness
+);
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+database_transactional_test!(
+    storage = mysql,
+    fixture = dataset_env_var_repo::test_delete_dataset_env_vars,
+    harness = MySqlDatasetEnvVarRepositoryHarness
+);
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+database_transactional_test!(
+    storage = mysql,
+    fixture = dataset_env_var_repo::test_modify_dataset_env_vars,
+    harness = MySqlDatasetEnvVarRepositoryHarness
+);
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+database_transactional_test!(
+    storage = mysql,
+    fixture = dataset_env_var_repo::test_get_all_dataset_env_vars,
+    harness = MySqlDatasetEnvVarRepositoryHarness
+);
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+struct MySqlDatasetEnvVarRepositoryHarness {
+    catalog: Catalog,
+}
+
+impl MySqlDatasetEnvVarRepositoryHarness {
+    pub fn new(mysql_pool: MySqlPool) -> Self {
+        // Initialize catalog with predefined MySQL pool
+        let mut catalog_builder = CatalogBuilder::new();
+        catalog_builder.add_value(mysql_pool);
+        catalog_builder.add::<MySqlTransactionManager>();
+        catalog_builder.add::<MySqlDatasetEnvVarRepository>();
+
+        Self {
+            catalog: catalog_builder.build(),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -7,6 +7,8 @@
 // the Business Source License, use of this software will be governed
 // by the Apache License, Version 2.0.
 
+mod postgres_dataset_entry_repository;
 mod postgres_dataset_env_var_repository;
 
+pub use postgres_dataset_entry_repository::*;
 pub use postgres_dataset_env_var_repository::*;
@@ -46,13 +46,14 @@ impl DatasetEnvVarRepository for PostgresDatasetEnvVarRepository {
 
         sqlx::query!(
             r#"
-                INSERT INTO dataset_env_vars (id, key, value, secret_nonce, created_at, dataset_id)
-                    VALUES ($1, $2, $3, $4, $5, $6)
+                INSERT INTO dataset_env_vars (id, key, value, secret_nonce, encryption_key_id, created_at, dataset_id)
+                    VALUES ($1, $2, $3, $4, $5, $6, $7)
                 "#,
             dataset_env_var.id,
             dataset_env_var.key,
             dataset_env_var.value,
             dataset_env_var.secret_nonce,
+            dataset_env_var.encryption_key_id,
             dataset_env_var.created_at,
             dataset_env_var.dataset_id.to_string(),
         )
@@ -75,6 +76,43 @@ impl DatasetEnvVarRepository for PostgresDatasetEnvVarRepository {
         Ok(())
     }
 
+    async fn get_all_dataset_env_vars(
+        &self,
+        pagination: &DatabasePaginationOpts,
+    ) -> Result<Vec<DatasetEnvVar>, GetDatasetEnvVarError> {
+        let mut tr = self.transaction.lock().await;
+
+        let connection_mut = tr
+            .connection_mut()
+            .await
+            .map_err(GetDatasetEnvVarError::Internal)?;
+
+        let dataset_env_var_rows = sqlx::query_as!(
+            DatasetEnvVarRowModel,
+            r#"
+                    SELECT
+                        id,
+                        key,
+                        value as "value: _",
+                        secret_nonce,
+                        encryption_key_id,
+                        created_at,
+                        dataset_id as "dataset_id: _"
+                    FROM dataset_env_vars
+                    ORDER BY created_at, id
+                    LIMIT $1 OFFSET $2
+                    "#,
+            pagination.limit,
+            pagination.offset,
+        )
+        .fetch_all(connection_mut)
+        .await
+        .int_err()
+        .map_err(GetDatasetEnvVarError::Internal)?;
+
+        Ok(dataset_env_var_rows.into_iter().map(Into::into).collect())
+    }
+
     async fn get_all_dataset_env_vars_by_dataset_id(
         &self,
         dataset_id: &DatasetID,
@@ -95,6 +133,7 @@ impl DatasetEnvVarRepository for PostgresDatasetEnvVarRepository {
                         key,
                         value as "value: _",
                         secret_nonce,
+                        encryption_key_id,
                         created_at,
                         dataset_id as "dataset_id: _"
                     FROM dataset_env_vars
@@ -161,6 +200,7 @@ impl DatasetEnvVarRepository for PostgresDatasetEnvVarRepository {
                         key,
                         value as "value: _",
                         secret_nonce,
+                        encryption_key_id,
                         created_at,
                         dataset_id as "dataset_id: _"
                     FROM dataset_env_vars
@@ -204,6 +244,7 @@ impl DatasetEnvVarRepository for PostgresDatasetEnvVarRepository {
                     key,
                     value as "value: _",
                     secret_nonce,
+                    encryption_key_id,
                     created_at,
                     dataset_id as "dataset_id: _"
                 FROM dataset_env_vars
@@ -263,6 +304,7 @@ impl DatasetEnvVarRepository for PostgresDatasetEnvVarRepository {
         dataset_env_var_id: &Uuid,
         new_value: Vec<u8>,
         secret_nonce: Option<Vec<u8>>,
+        encryption_key_id: Option<String>,
     ) -> Result<(), ModifyDatasetEnvVarError> {
         let mut tr = self.transaction.lock().await;
 
@@ -273,10 +315,11 @@ impl DatasetEnvVarRepository for PostgresDatasetEnvVarRepository {
 
         let update_result = sqlx::query!(
             r#"
-                UPDATE dataset_env_vars SET value = $1, secret_nonce = $2 where id = $3
+                UPDATE dataset_env_vars SET value = $1, secret_nonce = $2, encryption_key_id = $3 where id = $4
             "#,
             new_value,
             secret_nonce,
+            encryption_key_id,
             dataset_env_var_id,
         )
         .execute(&mut *connection_mut)
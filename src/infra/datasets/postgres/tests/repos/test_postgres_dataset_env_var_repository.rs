@@ -56,6 +56,14 @@ database_transactional_test!(
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
+database_transactional_test!(
+    storage = postgres,
+    fixture = dataset_env_var_repo::test_get_all_dataset_env_vars,
+    harness = PostgresDatasetEnvVarRepositoryHarness
+);
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
 struct PostgresDatasetEnvVarRepositoryHarness {
     catalog: Catalog,
 }
@@ -10,7 +10,8 @@
 use std::assert_matches::assert_matches;
 use std::sync::Arc;
 
-use chrono::Utc;
+use chrono::{SubsecRound, Utc};
+use database_common::DatabasePaginationOpts;
 use dill::Catalog;
 use kamu_accounts::{Account, AccountRepository, AccountType};
 use kamu_datasets::{
@@ -115,9 +116,14 @@ pub async fn test_get_dataset_entries_by_owner_id(catalog: &Catalog) {
     let account_1 = new_account_with_name(&account_repo, "user1").await;
     let account_2 = new_account_with_name(&account_repo, "user2").await;
 
+    let pagination = DatabasePaginationOpts {
+        limit: 100,
+        offset: 0,
+    };
+
     {
         let get_res = dataset_entry_repo
-            .get_dataset_entries_by_owner_id(&account_1.id)
+            .get_dataset_entries_by_owner_id(&account_1.id, &pagination)
             .await;
         let expected_dataset_entries = vec![];
 
@@ -129,7 +135,7 @@ pub async fn test_get_dataset_entries_by_owner_id(catalog: &Catalog) {
     }
     {
         let get_res = dataset_entry_repo
-            .get_dataset_entries_by_owner_id(&account_2.id)
+            .get_dataset_entries_by_owner_id(&account_2.id, &pagination)
             .await;
         let expected_dataset_entries = vec![];
 
@@ -166,7 +172,7 @@ pub async fn test_get_dataset_entries_by_owner_id(catalog: &Catalog) {
     }
     {
         let get_res = dataset_entry_repo
-            .get_dataset_entries_by_owner_id(&account_1.id)
+            .get_dataset_entries_by_owner_id(&account_1.id, &pagination)
             .await;
         let mut expected_dataset_entries = vec![dataset_entry_acc_1_1, dataset_entry_acc_1_2];
 
@@ -185,7 +191,7 @@ pub async fn test_get_dataset_entries_by_owner_id(catalog: &Catalog) {
     }
     {
         let get_res = dataset_entry_repo
-            .get_dataset_entries_by_owner_id(&account_2.id)
+            .get_dataset_entries_by_owner_id(&account_2.id, &pagination)
             .await;
         let expected_dataset_entries = vec![dataset_entry_acc_2_3];
 
@@ -199,6 +205,95 @@ pub async fn test_get_dataset_entries_by_owner_id(catalog: &Catalog) {
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
+pub async fn test_get_dataset_entries_with_pagination(catalog: &Catalog) {
+    let account_repo = catalog.get_one::<dyn AccountRepository>().unwrap();
+    let dataset_entry_repo = catalog.get_one::<dyn DatasetEntryRepository>().unwrap();
+
+    let account_1 = new_account_with_name(&account_repo, "user1").await;
+    let account_2 = new_account_with_name(&account_repo, "user2").await;
+
+    {
+        let count_res = dataset_entry_repo.dataset_entries_count().await;
+
+        assert_matches!(count_res, Ok(0));
+    }
+
+    let dataset_entry_acc_1_1 = new_dataset_entry_with(&account_1, "dataset1");
+    let dataset_entry_acc_2_2 = new_dataset_entry_with(&account_2, "dataset2");
+    let dataset_entry_acc_1_3 = new_dataset_entry_with(&account_1, "dataset3");
+    for dataset_entry in [
+        &dataset_entry_acc_1_1,
+        &dataset_entry_acc_2_2,
+        &dataset_entry_acc_1_3,
+    ] {
+        let save_res = dataset_entry_repo.save_dataset_entry(dataset_entry).await;
+
+        assert_matches!(save_res, Ok(_));
+    }
+    {
+        let count_res = dataset_entry_repo.dataset_entries_count().await;
+
+        assert_matches!(count_res, Ok(3));
+    }
+    {
+        let count_res = dataset_entry_repo
+            .dataset_entries_count_by_owner_id(&account_1.id)
+            .await;
+
+        assert_matches!(count_res, Ok(2));
+    }
+    {
+        let get_res = dataset_entry_repo
+            .get_dataset_entries(&DatabasePaginationOpts {
+                limit: 2,
+                offset: 0,
+            })
+            .await;
+        let expected_dataset_entries = vec![dataset_entry_acc_1_1, dataset_entry_acc_2_2];
+
+        assert_matches!(
+            get_res,
+            Ok(actual_dataset_entries)
+                if actual_dataset_entries == expected_dataset_entries
+        );
+    }
+    {
+        let get_res = dataset_entry_repo
+            .get_dataset_entries(&DatabasePaginationOpts {
+                limit: 2,
+                offset: 2,
+            })
+            .await;
+        let expected_dataset_entries = vec![dataset_entry_acc_1_3.clone()];
+
+        assert_matches!(
+            get_res,
+            Ok(actual_dataset_entries)
+                if actual_dataset_entries == expected_dataset_entries
+        );
+    }
+    {
+        let get_res = dataset_entry_repo
+            .get_dataset_entries_by_owner_id(
+                &account_1.id,
+                &DatabasePaginationOpts {
+                    limit: 1,
+                    offset: 1,
+                },
+            )
+            .await;
+        let expected_dataset_entries = vec![dataset_entry_acc_1_3];
+
+        assert_matches!(
+            get_res,
+            Ok(actual_dataset_entries)
+                if actual_dataset_entries == expected_dataset_entries
+        );
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
 pub async fn test_try_save_duplicate_dataset_entry(catalog: &Catalog) {
     let account_repo = catalog.get_one::<dyn AccountRepository>().unwrap();
     let dataset_entry_repo = catalog.get_one::<dyn DatasetEntryRepository>().unwrap();
@@ -408,7 +503,8 @@ fn new_dataset_entry_with(owner: &Account, dataset_name: &str) -> DatasetEntry {
     let (_, dataset_id) = DatasetID::new_generated_ed25519();
     let owner_id = owner.id.clone();
     let dataset_alias = DatasetName::new_unchecked(dataset_name);
-    let created_at = Utc::now();
+    // Databases store timestamps with microsecond precision
+    let created_at = Utc::now().round_subsecs(6);
 
     DatasetEntry::new(dataset_id, owner_id, dataset_alias, created_at)
 }
@@ -14,12 +14,12 @@ use database_common::DatabasePaginationOpts;
 use dill::Catalog;
 use kamu_datasets::{
     DatasetEnvVar,
+    DatasetEnvVarEncryptionKeyRing,
     DatasetEnvVarRepository,
     DatasetEnvVarValue,
     DeleteDatasetEnvVarError,
     GetDatasetEnvVarError,
     ModifyDatasetEnvVarError,
-    SAMPLE_DATASET_ENV_VAR_ENCRYPTION_KEY,
 };
 use opendatafabric::DatasetID;
 use secrecy::Secret;
@@ -73,7 +73,7 @@ pub async fn test_insert_and_get_dataset_env_var(catalog: &Catalog) {
         Utc::now().round_subsecs(6),
         &dataset_env_var_value,
         &dataset_id,
-        SAMPLE_DATASET_ENV_VAR_ENCRYPTION_KEY,
+        &DatasetEnvVarEncryptionKeyRing::sample(),
     )
     .unwrap();
     let save_result = dataset_env_var_repo
@@ -122,7 +122,7 @@ pub async fn test_insert_and_get_multiple_dataset_env_vars(catalog: &Catalog) {
         Utc::now().round_subsecs(6),
         &secret_dataset_env_var_value,
         &dataset_id,
-        SAMPLE_DATASET_ENV_VAR_ENCRYPTION_KEY,
+        &DatasetEnvVarEncryptionKeyRing::sample(),
     )
     .unwrap();
 
@@ -135,7 +135,7 @@ pub async fn test_insert_and_get_multiple_dataset_env_vars(catalog: &Catalog) {
         Utc::now().round_subsecs(6),
         &dataset_env_var_value,
         &dataset_id,
-        SAMPLE_DATASET_ENV_VAR_ENCRYPTION_KEY,
+        &DatasetEnvVarEncryptionKeyRing::sample(),
     )
     .unwrap();
 
@@ -184,7 +184,7 @@ pub async fn test_delete_dataset_env_vars(catalog: &Catalog) {
         Utc::now().round_subsecs(6),
         &DatasetEnvVarValue::Regular("foo".to_string()),
         &dataset_id,
-        SAMPLE_DATASET_ENV_VAR_ENCRYPTION_KEY,
+        &DatasetEnvVarEncryptionKeyRing::sample(),
     )
     .unwrap();
     let new_bar_dataset_env_var = DatasetEnvVar::new(
@@ -192,7 +192,7 @@ pub async fn test_delete_dataset_env_vars(catalog: &Catalog) {
         Utc::now().round_subsecs(6),
         &DatasetEnvVarValue::Regular("bar".to_string()),
         &dataset_id,
-        SAMPLE_DATASET_ENV_VAR_ENCRYPTION_KEY,
+        &DatasetEnvVarEncryptionKeyRing::sample(),
     )
     .unwrap();
     let save_result = dataset_env_var_repo
@@ -240,7 +240,7 @@ pub async fn test_modify_dataset_env_vars(catalog: &Catalog) {
         Utc::now().round_subsecs(6),
         &DatasetEnvVarValue::Regular("foo".to_string()),
         &DatasetID::new_seeded_ed25519(b"foo"),
-        SAMPLE_DATASET_ENV_VAR_ENCRYPTION_KEY,
+        &DatasetEnvVarEncryptionKeyRing::sample(),
     )
     .unwrap();
     let save_result = dataset_env_var_repo
@@ -249,14 +249,14 @@ pub async fn test_modify_dataset_env_vars(catalog: &Catalog) {
     assert!(save_result.is_ok());
 
     let modify_result = dataset_env_var_repo
-        .modify_dataset_env_var(&Uuid::new_v4(), vec![], None)
+        .modify_dataset_env_var(&Uuid::new_v4(), vec![], None, None)
         .await;
 
     assert_matches!(modify_result, Err(ModifyDatasetEnvVarError::NotFound(_)));
-    let (new_value, new_nonce) = new_dataset_env_var
+    let (new_value, new_nonce, new_encryption_key_id) = new_dataset_env_var
         .generate_new_value(
             &DatasetEnvVarValue::Regular("new_foo".to_string()),
-            SAMPLE_DATASET_ENV_VAR_ENCRYPTION_KEY,
+            &DatasetEnvVarEncryptionKeyRing::sample(),
         )
         .unwrap();
 
@@ -265,6 +265,7 @@ pub async fn test_modify_dataset_env_vars(catalog: &Catalog) {
             &new_dataset_env_var.id,
             new_value.clone(),
             new_nonce.clone(),
+            new_encryption_key_id.clone(),
         )
         .await;
 
@@ -275,12 +276,90 @@ pub async fn test_modify_dataset_env_vars(catalog: &Catalog) {
         .await
         .unwrap();
     assert_eq!(db_dataset_env_var.secret_nonce, new_nonce);
+    assert_eq!(db_dataset_env_var.encryption_key_id, new_encryption_key_id);
     assert_eq!(
         db_dataset_env_var
-            .get_exposed_decrypted_value(SAMPLE_DATASET_ENV_VAR_ENCRYPTION_KEY)
+            .get_exposed_decrypted_value(&DatasetEnvVarEncryptionKeyRing::sample())
             .unwrap(),
         std::str::from_utf8(new_value.as_slice()).unwrap()
     );
+
+    let (new_value, new_nonce, new_encryption_key_id) = new_dataset_env_var
+        .generate_new_value(
+            &DatasetEnvVarValue::Secret(Secret::new("new_secret_foo".to_string())),
+            &DatasetEnvVarEncryptionKeyRing::sample(),
+        )
+        .unwrap();
+
+    let modify_result = dataset_env_var_repo
+        .modify_dataset_env_var(
+            &new_dataset_env_var.id,
+            new_value,
+            new_nonce.clone(),
+            new_encryption_key_id.clone(),
+        )
+        .await;
+
+    assert!(modify_result.is_ok());
+
+    let db_dataset_env_var = dataset_env_var_repo
+        .get_dataset_env_var_by_id(&new_dataset_env_var.id)
+        .await
+        .unwrap();
+    assert_eq!(db_dataset_env_var.secret_nonce, new_nonce);
+    assert_eq!(db_dataset_env_var.encryption_key_id, new_encryption_key_id);
+    assert_eq!(
+        db_dataset_env_var
+            .get_exposed_decrypted_value(&DatasetEnvVarEncryptionKeyRing::sample())
+            .unwrap(),
+        "new_secret_foo"
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub async fn test_get_all_dataset_env_vars(catalog: &Catalog) {
+    let dataset_env_var_repo = catalog.get_one::<dyn DatasetEnvVarRepository>().unwrap();
+
+    let mut dataset_env_vars = Vec::new();
+    for (dataset_id, dataset_env_var_key) in [
+        (DatasetID::new_seeded_ed25519(b"foo"), "foo_key"),
+        (DatasetID::new_seeded_ed25519(b"bar"), "bar_key"),
+        (DatasetID::new_seeded_ed25519(b"foo"), "baz_key"),
+    ] {
+        let new_dataset_env_var = DatasetEnvVar::new(
+            dataset_env_var_key,
+            Utc::now().round_subsecs(6),
+            &DatasetEnvVarValue::Secret(Secret::new("secret".to_string())),
+            &dataset_id,
+            &DatasetEnvVarEncryptionKeyRing::sample(),
+        )
+        .unwrap();
+        dataset_env_var_repo
+            .save_dataset_env_var(&new_dataset_env_var)
+            .await
+            .unwrap();
+        dataset_env_vars.push(new_dataset_env_var);
+    }
+    dataset_env_vars
+        .sort_by_key(|dataset_env_var| (dataset_env_var.created_at, dataset_env_var.id));
+
+    let first_page = dataset_env_var_repo
+        .get_all_dataset_env_vars(&DatabasePaginationOpts {
+            offset: 0,
+            limit: 2,
+        })
+        .await
+        .unwrap();
+    let second_page = dataset_env_var_repo
+        .get_all_dataset_env_vars(&DatabasePaginationOpts {
+            offset: 2,
+            limit: 2,
+        })
+        .await
+        .unwrap();
+
+    assert_eq!([first_page, second_page].concat(), dataset_env_vars);
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -47,18 +47,20 @@ impl DatasetEnvVarRepository for SqliteDatasetEnvVarRepository {
         let dataset_env_var_key = &dataset_env_var.key;
         let dataset_env_var_value = &dataset_env_var.value;
         let dataset_env_var_secret_nonce = &dataset_env_var.secret_nonce;
+        let dataset_env_var_encryption_key_id = &dataset_env_var.encryption_key_id;
         let dataset_env_var_created_at = dataset_env_var.created_at;
         let dataset_env_var_dataset_id = dataset_env_var.dataset_id.to_string();
 
         sqlx::query!(
             r#"
-                INSERT INTO dataset_env_vars (id, key, value, secret_nonce, created_at, dataset_id)
-                    VALUES ($1, $2, $3, $4, $5, $6)
+                INSERT INTO dataset_env_vars (id, key, value, secret_nonce, encryption_key_id, created_at, dataset_id)
+                    VALUES ($1, $2, $3, $4, $5, $6, $7)
                 "#,
             dataset_env_var_id,
             dataset_env_var_key,
             dataset_env_var_value,
             dataset_env_var_secret_nonce,
+            dataset_env_var_encryption_key_id,
             dataset_env_var_created_at,
             dataset_env_var_dataset_id,
         )
@@ -81,6 +83,45 @@ impl DatasetEnvVarRepository for SqliteDatasetEnvVarRepository {
         Ok(())
     }
 
+    async fn get_all_dataset_env_vars(
+        &self,
+        pagination: &DatabasePaginationOpts,
+    ) -> Result<Vec<DatasetEnvVar>, GetDatasetEnvVarError> {
+        let mut tr = self.transaction.lock().await;
+
+        let connection_mut = tr
+            .connection_mut()
+            .await
+            .map_err(GetDatasetEnvVarError::Internal)?;
+        let limit = pagination.limit;
+        let offset = pagination.offset;
+
+        let dataset_env_var_rows = sqlx::query_as!(
+            DatasetEnvVarRowModel,
+            r#"
+                SELECT
+                    id as "id: Uuid",
+                    key,
+                    value as "value: _",
+                    secret_nonce as "secret_nonce: _",
+                    encryption_key_id,
+                    created_at as "created_at: _",
+                    dataset_id as "dataset_id: _"
+                FROM dataset_env_vars
+                ORDER BY created_at, id
+                LIMIT $1 OFFSET $2
+                "#,
+            limit,
+            offset,
+        )
+        .fetch_all(connection_mut)
+        .await
+        .int_err()
+        .map_err(GetDatasetEnvVarError::Internal)?;
+
+        Ok(dataset_env_var_rows.into_iter().map(Into::into).collect())
+    }
+
     async fn get_all_dataset_env_vars_by_dataset_id(
         &self,
         dataset_id: &DatasetID,
@@ -104,6 +145,7 @@ impl DatasetEnvVarRepository for SqliteDatasetEnvVarRepository {
                     key,
                     value as "value: _",
                     secret_nonce as "secret_nonce: _",
+                    encryption_key_id,
                     created_at as "created_at: _",
                     dataset_id as "dataset_id: _"
                 FROM dataset_env_vars
@@ -171,6 +213,7 @@ impl DatasetEnvVarRepository for SqliteDatasetEnvVarRepository {
                     key,
                     value as "value: _",
                     secret_nonce as "secret_nonce: _",
+                    encryption_key_id,
                     created_at as "created_at: _",
                     dataset_id as "dataset_id: _"
                 FROM dataset_env_vars
@@ -214,6 +257,7 @@ impl DatasetEnvVarRepository for SqliteDatasetEnvVarRepository {
                     key,
                     value as "value: _",
                     secret_nonce as "secret_nonce: _",
+                    encryption_key_id,
                     created_at as "created_at: _",
                     dataset_id as "dataset_id: _"
                 FROM dataset_env_vars
@@ -275,6 +319,7 @@ impl DatasetEnvVarRepository for SqliteDatasetEnvVarRepository {
         dataset_env_var_id: &Uuid,
         new_value: Vec<u8>,
         secret_nonce: Option<Vec<u8>>,
+        encryption_key_id: Option<String>,
     ) -> Result<(), ModifyDatasetEnvVarError> {
         let mut tr = self.transaction.lock().await;
 
@@ -285,10 +330,11 @@ impl DatasetEnvVarRepository for SqliteDatasetEnvVarRepository {
 
         let update_result = sqlx::query!(
             r#"
-                UPDATE dataset_env_vars SET value = $1, secret_nonce = $2 where id = $3
+                UPDATE dataset_env_vars SET value = $1, secret_nonce = $2, encryption_key_id = $3 where id = $4
             "#,
             new_value,
             secret_nonce,
+            encryption_key_id,
             dataset_env_var_id,
         )
         .execute(&mut *connection_mut)
@@ -7,7 +7,7 @@
 // the Business Source License, use of this software will be governed
 // by the Apache License, Version 2.0.
 
-use database_common::{TransactionRef, TransactionRefT};
+use database_common::{DatabasePaginationOpts, TransactionRef, TransactionRefT};
 use dill::{component, interface};
 use internal_error::{ErrorIntoInternal, ResultIntoInternal};
 use kamu_datasets::{
@@ -19,6 +19,7 @@ use kamu_datasets::{
     DatasetEntryRowModel,
     DeleteEntryDatasetError,
     GetDatasetEntriesByOwnerIdError,
+    GetDatasetEntriesError,
     GetDatasetEntryByNameError,
     GetDatasetEntryError,
     SaveDatasetEntryError,
@@ -125,9 +126,92 @@ impl DatasetEntryRepository for SqliteDatasetEntryRepository {
         }
     }
 
+    async fn dataset_entries_count(&self) -> Result<usize, GetDatasetEntriesError> {
+        let mut tr = self.transaction.lock().await;
+
+        let connection_mut = tr
+            .connection_mut()
+            .await
+            .map_err(GetDatasetEntriesError::Internal)?;
+
+        let dataset_entries_count = sqlx::query_scalar!(
+            r#"
+            SELECT COUNT(*)
+            FROM dataset_entries
+            "#,
+        )
+        .fetch_one(connection_mut)
+        .await
+        .map_int_err(GetDatasetEntriesError::Internal)?;
+
+        Ok(usize::try_from(dataset_entries_count).unwrap_or(0))
+    }
+
+    async fn get_dataset_entries(
+        &self,
+        pagination: &DatabasePaginationOpts,
+    ) -> Result<Vec<DatasetEntry>, GetDatasetEntriesError> {
+        let mut tr = self.transaction.lock().await;
+
+        let connection_mut = tr
+            .connection_mut()
+            .await
+            .map_err(GetDatasetEntriesError::Internal)?;
+
+        let dataset_entry_rows = sqlx::query_as!(
+            DatasetEntryRowModel,
+            r#"
+            SELECT dataset_id   as "id: _",
+                   owner_id     as "owner_id: _",
+                   dataset_name as name,
+                   created_at   as "created_at: _"
+            FROM dataset_entries
+            ORDER BY dataset_name, dataset_id
+            LIMIT $1 OFFSET $2
+            "#,
+            pagination.limit,
+            pagination.offset,
+        )
+        .fetch_all(connection_mut)
+        .await
+        .map_int_err(GetDatasetEntriesError::Internal)?;
+
+        Ok(dataset_entry_rows.into_iter().map(Into::into).collect())
+    }
+
+    async fn dataset_entries_count_by_owner_id(
+        &self,
+        owner_id: &AccountID,
+    ) -> Result<usize, GetDatasetEntriesByOwnerIdError> {
+        let mut tr = self.transaction.lock().await;
+
+        let connection_mut = tr
+            .connection_mut()
+            .await
+            .map_err(GetDatasetEntriesByOwnerIdError::Internal)?;
+
+        let stack_owner_id = owner_id.as_did_str().to_stack_string();
+        let owner_id_as_str = stack_owner_id.as_str();
+
+        let dataset_entries_count = sqlx::query_scalar!(
+            r#"
+            SELECT COUNT(*)
+            FROM dataset_entries
+            WHERE owner_id = $1
+            "#,
+            owner_id_as_str,
+        )
+        .fetch_one(connection_mut)
+        .await
+        .map_int_err(GetDatasetEntriesByOwnerIdError::Internal)?;
+
+        Ok(usize::try_from(dataset_entries_count).unwrap_or(0))
+    }
+
     async fn get_dataset_entries_by_owner_id(
         &self,
         owner_id: &AccountID,
+        pagination: &DatabasePaginationOpts,
     ) -> Result<Vec<DatasetEntry>, GetDatasetEntriesByOwnerIdError> {
         let mut tr = self.transaction.lock().await;
 
@@ -148,8 +232,12 @@ impl DatasetEntryRepository for SqliteDatasetEntryRepository {
                    created_at   as "created_at: _"
             FROM dataset_entries
             WHERE owner_id = $1
+            ORDER BY dataset_name
+            LIMIT $2 OFFSET $3
             "#,
             owner_id_as_str,
+            pagination.limit,
+            pagination.offset,
         )
         .fetch_all(connection_mut)
         .await
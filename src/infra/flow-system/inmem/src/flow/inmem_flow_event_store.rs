@@ -284,8 +284,8 @@ impl EventStore<FlowState> for InMemoryFlowEventStore {
 #[async_trait::async_trait]
 impl FlowEventStore for InMemoryFlowEventStore {
     #[tracing::instrument(level = "debug", skip_all)]
-    fn new_flow_id(&self) -> FlowID {
-        self.inner.as_state().lock().unwrap().next_flow_id()
+    async fn new_flow_id(&self) -> Result<FlowID, InternalError> {
+        Ok(self.inner.as_state().lock().unwrap().next_flow_id())
     }
 
     #[tracing::instrument(level = "debug", skip_all, fields(%dataset_id, ?flow_type))]
@@ -489,6 +489,25 @@ impl FlowEventStore for InMemoryFlowEventStore {
         let g = state.lock().unwrap();
         Ok(g.all_flows.len())
     }
+
+    #[tracing::instrument(level = "debug", skip_all)]
+    fn get_unfinished_flow_ids(&self) -> FlowIDStream {
+        let flow_ids: Vec<_> = {
+            let state = self.inner.as_state();
+            let g = state.lock().unwrap();
+            g.all_flows
+                .iter()
+                .filter(|flow_id| {
+                    g.flow_search_index
+                        .get(flow_id)
+                        .is_some_and(|index_entry| index_entry.flow_status != FlowStatus::Finished)
+                })
+                .map(|flow_id| Ok(*flow_id))
+                .collect()
+        };
+
+        Box::pin(futures::stream::iter(flow_ids))
+    }
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -11,6 +11,8 @@ use std::collections::HashSet;
 use std::sync::Arc;
 
 use chrono::{Duration, Utc};
+use database_common_macros::database_transactional_test;
+use dill::{Catalog, CatalogBuilder};
 use futures::TryStreamExt;
 use kamu_flow_system::*;
 use kamu_flow_system_inmem::InMemoryFlowEventStore;
@@ -1071,7 +1073,7 @@ impl<'a> DatasetFlowGenerator<'a> {
         initial_trigger: FlowTrigger,
         config_snapshot: Option<FlowConfigurationSnapshot>,
     ) -> FlowID {
-        let flow_id = self.flow_event_store.new_flow_id();
+        let flow_id = self.flow_event_store.new_flow_id().await.unwrap();
 
         let creation_moment = Utc::now();
 
@@ -1085,6 +1087,7 @@ impl<'a> DatasetFlowGenerator<'a> {
             .into(),
             initial_trigger,
             config_snapshot,
+            None,
         );
 
         drive_flow_to_status(&mut flow, self.task_event_store.as_ref(), expected_status).await;
@@ -1120,7 +1123,7 @@ impl SystemFlowGenerator {
         initial_trigger: FlowTrigger,
         config_snapshot: Option<FlowConfigurationSnapshot>,
     ) -> FlowID {
-        let flow_id = self.flow_event_store.new_flow_id();
+        let flow_id = self.flow_event_store.new_flow_id().await.unwrap();
 
         let creation_moment = Utc::now();
 
@@ -1130,6 +1133,7 @@ impl SystemFlowGenerator {
             FlowKey::System(FlowKeySystem { flow_type }),
             initial_trigger,
             config_snapshot,
+            None,
         );
 
         drive_flow_to_status(&mut flow, self.task_event_store.as_ref(), expected_status).await;
@@ -1169,6 +1173,7 @@ async fn drive_flow_to_status(
                 start_moment + Duration::try_minutes(10).unwrap(),
                 task_id,
                 TaskOutcome::Success(TaskResult::Empty),
+                None,
             )
             .unwrap();
         } else if expected_status != FlowStatus::Running {
@@ -1178,3 +1183,60 @@ async fn drive_flow_to_status(
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+database_transactional_test!(
+    storage = inmem,
+    fixture = kamu_flow_system_repo_tests::test_flow_event_store_empty,
+    harness = InMemoryFlowEventStoreHarness
+);
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+database_transactional_test!(
+    storage = inmem,
+    fixture = kamu_flow_system_repo_tests::test_flow_event_store_save_and_load,
+    harness = InMemoryFlowEventStoreHarness
+);
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+database_transactional_test!(
+    storage = inmem,
+    fixture = kamu_flow_system_repo_tests::test_flow_event_store_dataset_flow_filters,
+    harness = InMemoryFlowEventStoreHarness
+);
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+database_transactional_test!(
+    storage = inmem,
+    fixture = kamu_flow_system_repo_tests::test_flow_event_store_system_flows,
+    harness = InMemoryFlowEventStoreHarness
+);
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+database_transactional_test!(
+    storage = inmem,
+    fixture = kamu_flow_system_repo_tests::test_flow_event_store_get_unfinished_flow_ids,
+    harness = InMemoryFlowEventStoreHarness
+);
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+struct InMemoryFlowEventStoreHarness {
+    catalog: Catalog,
+}
+
+impl InMemoryFlowEventStoreHarness {
+    pub fn new() -> Self {
+        let mut catalog_builder = CatalogBuilder::new();
+        catalog_builder.add::<InMemoryFlowEventStore>();
+
+        Self {
+            catalog: catalog_builder.build(),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
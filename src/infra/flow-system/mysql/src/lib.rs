@@ -0,0 +1,17 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+// Re-exports
+pub use kamu_flow_system as domain;
+
+mod mysql_flow_configuration_event_store;
+mod mysql_flow_event_store;
+
+pub use mysql_flow_configuration_event_store::*;
+pub use mysql_flow_event_store::*;
@@ -0,0 +1,60 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use database_common::MySqlTransactionManager;
+use database_common_macros::database_transactional_test;
+use dill::{Catalog, CatalogBuilder};
+use kamu_flow_system_mysql::MySqlFlowConfigurationEventStore;
+use sqlx::MySqlPool;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+database_transactional_test!(
+    storage = mysql,
+    fixture = kamu_flow_system_repo_tests::test_event_store_empty,
+    harness = MySqlFlowConfigurationEventStoreHarness
+);
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+database_transactional_test!(
+    storage = mysql,
+    fixture = kamu_flow_system_repo_tests::test_event_store_get_streams,
+    harness = MySqlFlowConfigurationEventStoreHarness
+);
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+database_transactional_test!(
+    storage = mysql,
+    fixture = kamu_flow_system_repo_tests::test_event_store_get_events_with_windowing,
+    harness = MySqlFlowConfigurationEventStoreHarness
+);
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+struct MySqlFlowConfigurationEventStoreHarness {
+    catalog: Catalog,
+}
+
+impl MySqlFlowConfigurationEventStoreHarness {
+    pub fn new(mysql_pool: MySqlPool) -> Self {
+        // Initialize catalog with predefined MySQL pool
+        let mut catalog_builder = CatalogBuilder::new();
+        catalog_builder.add_value(mysql_pool);
+        catalog_builder.add::<MySqlTransactionManager>();
+        catalog_builder.add::<MySqlFlowConfigurationEventStore>();
+
+        Self {
+            catalog: catalog_builder.build(),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
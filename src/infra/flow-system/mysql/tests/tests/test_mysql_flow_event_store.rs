@@ -0,0 +1,76 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use database_common::MySqlTransactionManager;
+use database_common_macros::database_transactional_test;
+use dill::{Catalog, CatalogBuilder};
+use kamu_flow_system_mysql::MySqlFlowEventStore;
+use sqlx::MySqlPool;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+database_transactional_test!(
+    storage = mysql,
+    fixture = kamu_flow_system_repo_tests::test_flow_event_store_empty,
+    harness = MySqlFlowEventStoreHarness
+);
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+database_transactional_test!(
+    storage = mysql,
+    fixture = kamu_flow_system_repo_tests::test_flow_event_store_save_and_load,
+    harness = MySqlFlowEventStoreHarness
+);
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+database_transactional_test!(
+    storage = mysql,
+    fixture = kamu_flow_system_repo_tests::test_flow_event_store_dataset_flow_filters,
+    harness = MySqlFlowEventStoreHarness
+);
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+database_transactional_test!(
+    storage = mysql,
+    fixture = kamu_flow_system_repo_tests::test_flow_event_store_system_flows,
+    harness = MySqlFlowEventStoreHarness
+);
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+database_transactional_test!(
+    storage = mysql,
+    fixture = kamu_flow_system_repo_tests::test_flow_event_store_get_unfinished_flow_ids,
+    harness = MySqlFlowEventStoreHarness
+);
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+struct MySqlFlowEventStoreHarness {
+    catalog: Catalog,
+}
+
+impl MySqlFlowEventStoreHarness {
+    pub fn new(mysql_pool: MySqlPool) -> Self {
+        // Initialize catalog with predefined MySQL pool
+        let mut catalog_builder = CatalogBuilder::new();
+        catalog_builder.add_value(mysql_pool);
+        catalog_builder.add::<MySqlTransactionManager>();
+        catalog_builder.add::<MySqlFlowEventStore>();
+
+        Self {
+            catalog: catalog_builder.build(),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,620 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::collections::HashSet;
+
+use database_common::{TransactionRef, TransactionRefT};
+use dill::*;
+use futures::TryStreamExt;
+use kamu_flow_system::*;
+use opendatafabric::{AccountID, DatasetID};
+use sqlx::{FromRow, Postgres, QueryBuilder};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub struct PostgresFlowEventStore {
+    transaction: TransactionRefT<Postgres>,
+}
+
+#[component(pub)]
+#[interface(dyn FlowEventStore)]
+impl PostgresFlowEventStore {
+    pub fn new(transaction: TransactionRef) -> Self {
+        Self {
+            transaction: transaction.into(),
+        }
+    }
+
+    async fn register_flow(
+        connection_mut: &mut sqlx::PgConnection,
+        event: &FlowEventInitiated,
+    ) -> Result<(), InternalError> {
+        let flow_id = i64::try_from(event.flow_id).int_err()?;
+        let initiator = event
+            .trigger
+            .initiator_account_id()
+            .map(ToString::to_string);
+
+        let (dataset_id, dataset_flow_type, system_flow_type) = match &event.flow_key {
+            FlowKey::Dataset(fk_dataset) => (
+                Some(fk_dataset.dataset_id.to_string()),
+                Some(fk_dataset.flow_type),
+                None,
+            ),
+            FlowKey::System(fk_system) => (None, None, Some(fk_system.flow_type)),
+        };
+
+        sqlx::query!(
+            r#"
+            INSERT INTO flows (flow_id, dataset_id, dataset_flow_type, system_flow_type, initiator, flow_status)
+                VALUES ($1, $2, ($3::text)::dataset_flow_type, ($4::text)::system_flow_type, $5, 'waiting')
+            "#,
+            flow_id,
+            dataset_id,
+            dataset_flow_type as Option<DatasetFlowType>,
+            system_flow_type as Option<SystemFlowType>,
+            initiator,
+        )
+        .execute(connection_mut)
+        .await
+        .int_err()?;
+
+        Ok(())
+    }
+
+    async fn update_flow_status(
+        connection_mut: &mut sqlx::PgConnection,
+        event: &FlowEvent,
+        new_status: FlowStatus,
+    ) -> Result<(), InternalError> {
+        let flow_id = i64::try_from(event.flow_id()).int_err()?;
+
+        // Finished tasks are recorded to compute the run statistics of flow keys
+        let (last_attempt_at, last_success_at) = match event {
+            FlowEvent::TaskFinished(e) => (
+                Some(e.event_time),
+                e.task_outcome.is_success().then_some(e.event_time),
+            ),
+            _ => (None, None),
+        };
+
+        sqlx::query!(
+            r#"
+            UPDATE flows
+                SET flow_status = ($2::text)::flow_status_type,
+                    last_attempt_at = COALESCE($3, last_attempt_at),
+                    last_success_at = COALESCE($4, last_success_at)
+                WHERE flow_id = $1
+            "#,
+            flow_id,
+            new_status as FlowStatus,
+            last_attempt_at,
+            last_success_at,
+        )
+        .execute(connection_mut)
+        .await
+        .int_err()?;
+
+        Ok(())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Splits the initiator filter into the query parameters: whether only the
+/// system-initiated flows are requested and the accepted initiator accounts
+fn initiator_filter_params(filter: Option<&InitiatorFilter>) -> (bool, Option<Vec<String>>) {
+    match filter {
+        None => (false, None),
+        Some(InitiatorFilter::System) => (true, None),
+        Some(InitiatorFilter::Account(account_ids)) => (
+            false,
+            Some(account_ids.iter().map(ToString::to_string).collect()),
+        ),
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[async_trait::async_trait]
+impl EventStore<FlowState> for PostgresFlowEventStore {
+    async fn get_events(&self, flow_id: &FlowID, opts: GetEventsOpts) -> EventStream<FlowEvent> {
+        let mut tr = self.transaction.lock().await;
+
+        let flow_id = i64::try_from(*flow_id).unwrap();
+        let maybe_from_id = opts.from.map(EventID::into_inner);
+        let maybe_to_id = opts.to.map(EventID::into_inner);
+
+        Box::pin(async_stream::stream! {
+            let connection_mut = tr
+                .connection_mut()
+                .await?;
+
+            let mut query_stream = sqlx::query!(
+                r#"
+                SELECT event_id, event_payload FROM flow_events
+                    WHERE flow_id = $1
+                         AND (cast($2 as INT8) IS NULL or event_id > $2)
+                         AND (cast($3 as INT8) IS NULL or event_id <= $3)
+                    ORDER BY event_id
+                "#,
+                flow_id,
+                maybe_from_id,
+                maybe_to_id,
+            ).try_map(|event_row| {
+                let event = serde_json::from_value::<FlowEvent>(event_row.event_payload)
+                    .map_err(|e| sqlx::Error::Decode(Box::new(e)))?;
+
+                Ok((EventID::new(event_row.event_id), event))
+            })
+            .fetch(connection_mut)
+            .map_err(|e| GetEventsError::Internal(e.int_err()));
+
+            while let Some((event_id, event)) = query_stream.try_next().await? {
+                yield Ok((event_id, event));
+            }
+        })
+    }
+
+    async fn save_events(
+        &self,
+        _flow_id: &FlowID,
+        events: Vec<FlowEvent>,
+    ) -> Result<EventID, SaveEventsError> {
+        if events.is_empty() {
+            return Err(SaveEventsError::NothingToSave);
+        }
+
+        let mut tr = self.transaction.lock().await;
+        let connection_mut = tr.connection_mut().await?;
+
+        // Flows are registered before their events are written
+        for event in &events {
+            if let FlowEvent::Initiated(e) = event {
+                Self::register_flow(&mut *connection_mut, e).await?;
+            }
+        }
+
+        #[derive(FromRow)]
+        struct ResultRow {
+            event_id: i64,
+        }
+
+        let mut query_builder = QueryBuilder::<Postgres>::new(
+            r#"
+            INSERT INTO flow_events (flow_id, event_time, event_type, event_payload)
+            "#,
+        );
+
+        query_builder.push_values(&events, |mut b, event| {
+            b.push_bind(i64::try_from(event.flow_id()).unwrap());
+            b.push_bind(event.event_time());
+            b.push_bind(event.typename());
+            b.push_bind(serde_json::to_value(event).unwrap());
+        });
+
+        query_builder.push("RETURNING event_id");
+
+        let rows = query_builder
+            .build_query_as::<ResultRow>()
+            .fetch_all(&mut *connection_mut)
+            .await
+            .int_err()?;
+        let last_event_id = rows.last().unwrap().event_id;
+
+        for event in &events {
+            if !matches!(event, FlowEvent::Initiated(_))
+                && let Some(new_status) = event.new_status()
+            {
+                Self::update_flow_status(&mut *connection_mut, event, new_status).await?;
+            }
+        }
+
+        Ok(EventID::new(last_event_id))
+    }
+
+    async fn len(&self) -> Result<usize, InternalError> {
+        let mut tr = self.transaction.lock().await;
+        let connection_mut = tr.connection_mut().await?;
+
+        let result = sqlx::query!(
+            r#"
+            SELECT COUNT(event_id) AS count FROM flow_events
+            "#,
+        )
+        .fetch_one(connection_mut)
+        .await
+        .int_err()?;
+
+        let count = usize::try_from(result.count.unwrap()).int_err()?;
+        Ok(count)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[async_trait::async_trait]
+impl FlowEventStore for PostgresFlowEventStore {
+    async fn new_flow_id(&self) -> Result<FlowID, InternalError> {
+        let mut tr = self.transaction.lock().await;
+        let connection_mut = tr.connection_mut().await?;
+
+        let result = sqlx::query!(
+            r#"
+            SELECT nextval('flow_id_seq') AS new_flow_id
+            "#
+        )
+        .fetch_one(connection_mut)
+        .await
+        .int_err()?;
+
+        let flow_id = u64::try_from(result.new_flow_id.unwrap()).int_err()?;
+        Ok(FlowID::new(flow_id))
+    }
+
+    async fn get_dataset_flow_run_stats(
+        &self,
+        dataset_id: &DatasetID,
+        flow_type: DatasetFlowType,
+    ) -> Result<FlowRunStats, InternalError> {
+        let mut tr = self.transaction.lock().await;
+        let connection_mut = tr.connection_mut().await?;
+
+        let result = sqlx::query!(
+            r#"
+            SELECT MAX(last_attempt_at) AS last_attempt_time, MAX(last_success_at) AS last_success_time
+                FROM flows
+                WHERE dataset_id = $1 AND dataset_flow_type = ($2::text)::dataset_flow_type
+            "#,
+            dataset_id.to_string(),
+            flow_type as DatasetFlowType,
+        )
+        .fetch_one(connection_mut)
+        .await
+        .int_err()?;
+
+        Ok(FlowRunStats {
+            last_success_time: result.last_success_time,
+            last_attempt_time: result.last_attempt_time,
+        })
+    }
+
+    async fn get_system_flow_run_stats(
+        &self,
+        flow_type: SystemFlowType,
+    ) -> Result<FlowRunStats, InternalError> {
+        let mut tr = self.transaction.lock().await;
+        let connection_mut = tr.connection_mut().await?;
+
+        let result = sqlx::query!(
+            r#"
+            SELECT MAX(last_attempt_at) AS last_attempt_time, MAX(last_success_at) AS last_success_time
+                FROM flows
+                WHERE system_flow_type = ($1::text)::system_flow_type
+            "#,
+            flow_type as SystemFlowType,
+        )
+        .fetch_one(connection_mut)
+        .await
+        .int_err()?;
+
+        Ok(FlowRunStats {
+            last_success_time: result.last_success_time,
+            last_attempt_time: result.last_attempt_time,
+        })
+    }
+
+    fn get_all_flow_ids_by_dataset(
+        &self,
+        dataset_id: &DatasetID,
+        filters: DatasetFlowFilters,
+        pagination: FlowPaginationOpts,
+    ) -> FlowIDStream {
+        let dataset_id = dataset_id.to_string();
+        let (by_system_initiator, by_initiator_accounts) =
+            initiator_filter_params(filters.by_initiator.as_ref());
+
+        Box::pin(async_stream::stream! {
+            let mut tr = self.transaction.lock().await;
+            let connection_mut = tr.connection_mut().await?;
+
+            let limit = i64::try_from(pagination.limit).int_err()?;
+            let offset = i64::try_from(pagination.offset).int_err()?;
+
+            let mut query_stream = sqlx::query!(
+                r#"
+                SELECT flow_id FROM flows
+                    WHERE dataset_id = $1
+                        AND ($2::text IS NULL OR dataset_flow_type = ($2::text)::dataset_flow_type)
+                        AND ($3::text IS NULL OR flow_status = ($3::text)::flow_status_type)
+                        AND (NOT $4::boolean OR initiator IS NULL)
+                        AND ($5::text[] IS NULL OR initiator = ANY($5::text[]))
+                    ORDER BY flow_id DESC
+                    LIMIT $6 OFFSET $7
+                "#,
+                dataset_id,
+                filters.by_flow_type as Option<DatasetFlowType>,
+                filters.by_flow_status as Option<FlowStatus>,
+                by_system_initiator,
+                by_initiator_accounts.as_deref(),
+                limit,
+                offset,
+            )
+            .try_map(|event_row| Ok(FlowID::new(u64::try_from(event_row.flow_id).unwrap())))
+            .fetch(connection_mut)
+            .map_err(ErrorIntoInternal::int_err);
+
+            while let Some(flow_id) = query_stream.try_next().await? {
+                yield Ok(flow_id);
+            }
+        })
+    }
+
+    fn get_unique_flow_initiator_ids_by_dataset(
+        &self,
+        dataset_id: &DatasetID,
+    ) -> InitiatorIDStream {
+        let dataset_id = dataset_id.to_string();
+
+        Box::pin(async_stream::stream! {
+            let mut tr = self.transaction.lock().await;
+            let connection_mut = tr.connection_mut().await?;
+
+            let mut query_stream = sqlx::query!(
+                r#"
+                SELECT initiator FROM flows
+                    WHERE dataset_id = $1 AND initiator IS NOT NULL
+                    GROUP BY initiator
+                    ORDER BY MIN(flow_id)
+                "#,
+                dataset_id,
+            )
+            .try_map(|event_row| {
+                event_row
+                    .initiator
+                    .map(|initiator| AccountID::from_did_str(&initiator))
+                    .transpose()
+                    .map_err(|e| sqlx::Error::Decode(Box::new(e)))
+            })
+            .fetch(connection_mut)
+            .map_err(ErrorIntoInternal::int_err);
+
+            while let Some(maybe_initiator) = query_stream.try_next().await? {
+                if let Some(initiator) = maybe_initiator {
+                    yield Ok(initiator);
+                }
+            }
+        })
+    }
+
+    async fn get_count_flows_by_dataset(
+        &self,
+        dataset_id: &DatasetID,
+        filters: &DatasetFlowFilters,
+    ) -> Result<usize, InternalError> {
+        let mut tr = self.transaction.lock().await;
+        let connection_mut = tr.connection_mut().await?;
+
+        let (by_system_initiator, by_initiator_accounts) =
+            initiator_filter_params(filters.by_initiator.as_ref());
+
+        let result = sqlx::query!(
+            r#"
+            SELECT COUNT(flow_id) AS count FROM flows
+                WHERE dataset_id = $1
+                    AND ($2::text IS NULL OR dataset_flow_type = ($2::text)::dataset_flow_type)
+                    AND ($3::text IS NULL OR flow_status = ($3::text)::flow_status_type)
+                    AND (NOT $4::boolean OR initiator IS NULL)
+                    AND ($5::text[] IS NULL OR initiator = ANY($5::text[]))
+            "#,
+            dataset_id.to_string(),
+            filters.by_flow_type as Option<DatasetFlowType>,
+            filters.by_flow_status as Option<FlowStatus>,
+            by_system_initiator,
+            by_initiator_accounts.as_deref(),
+        )
+        .fetch_one(connection_mut)
+        .await
+        .int_err()?;
+
+        let count = usize::try_from(result.count.unwrap()).int_err()?;
+        Ok(count)
+    }
+
+    fn get_all_flow_ids_by_datasets(
+        &self,
+        dataset_ids: HashSet<DatasetID>,
+        filters: &DatasetFlowFilters,
+        pagination: FlowPaginationOpts,
+    ) -> FlowIDStream {
+        let dataset_ids: Vec<_> = dataset_ids.iter().map(ToString::to_string).collect();
+        let by_flow_type = filters.by_flow_type;
+        let by_flow_status = filters.by_flow_status;
+        let (by_system_initiator, by_initiator_accounts) =
+            initiator_filter_params(filters.by_initiator.as_ref());
+
+        Box::pin(async_stream::stream! {
+            let mut tr = self.transaction.lock().await;
+            let connection_mut = tr.connection_mut().await?;
+
+            let limit = i64::try_from(pagination.limit).int_err()?;
+            let offset = i64::try_from(pagination.offset).int_err()?;
+
+            let mut query_stream = sqlx::query!(
+                r#"
+                SELECT flow_id FROM flows
+                    WHERE dataset_id = ANY($1::text[])
+                        AND ($2::text IS NULL OR dataset_flow_type = ($2::text)::dataset_flow_type)
+                        AND ($3::text IS NULL OR flow_status = ($3::text)::flow_status_type)
+                        AND (NOT $4::boolean OR initiator IS NULL)
+                        AND ($5::text[] IS NULL OR initiator = ANY($5::text[]))
+                    ORDER BY flow_id DESC
+                    LIMIT $6 OFFSET $7
+                "#,
+                &dataset_ids,
+                by_flow_type as Option<DatasetFlowType>,
+                by_flow_status as Option<FlowStatus>,
+                by_system_initiator,
+                by_initiator_accounts.as_deref(),
+                limit,
+                offset,
+            )
+            .try_map(|event_row| Ok(FlowID::new(u64::try_from(event_row.flow_id).unwrap())))
+            .fetch(connection_mut)
+            .map_err(ErrorIntoInternal::int_err);
+
+            while let Some(flow_id) = query_stream.try_next().await? {
+                yield Ok(flow_id);
+            }
+        })
+    }
+
+    fn get_all_system_flow_ids(
+        &self,
+        filters: SystemFlowFilters,
+        pagination: FlowPaginationOpts,
+    ) -> FlowIDStream {
+        let (by_system_initiator, by_initiator_accounts) =
+            initiator_filter_params(filters.by_initiator.as_ref());
+
+        Box::pin(async_stream::stream! {
+            let mut tr = self.transaction.lock().await;
+            let connection_mut = tr.connection_mut().await?;
+
+            let limit = i64::try_from(pagination.limit).int_err()?;
+            let offset = i64::try_from(pagination.offset).int_err()?;
+
+            let mut query_stream = sqlx::query!(
+                r#"
+                SELECT flow_id FROM flows
+                    WHERE system_flow_type IS NOT NULL
+                        AND ($1::text IS NULL OR system_flow_type = ($1::text)::system_flow_type)
+                        AND ($2::text IS NULL OR flow_status = ($2::text)::flow_status_type)
+                        AND (NOT $3::boolean OR initiator IS NULL)
+                        AND ($4::text[] IS NULL OR initiator = ANY($4::text[]))
+                    ORDER BY flow_id DESC
+                    LIMIT $5 OFFSET $6
+                "#,
+                filters.by_flow_type as Option<SystemFlowType>,
+                filters.by_flow_status as Option<FlowStatus>,
+                by_system_initiator,
+                by_initiator_accounts.as_deref(),
+                limit,
+                offset,
+            )
+            .try_map(|event_row| Ok(FlowID::new(u64::try_from(event_row.flow_id).unwrap())))
+            .fetch(connection_mut)
+            .map_err(ErrorIntoInternal::int_err);
+
+            while let Some(flow_id) = query_stream.try_next().await? {
+                yield Ok(flow_id);
+            }
+        })
+    }
+
+    async fn get_count_system_flows(
+        &self,
+        filters: &SystemFlowFilters,
+    ) -> Result<usize, InternalError> {
+        let mut tr = self.transaction.lock().await;
+        let connection_mut = tr.connection_mut().await?;
+
+        let (by_system_initiator, by_initiator_accounts) =
+            initiator_filter_params(filters.by_initiator.as_ref());
+
+        let result = sqlx::query!(
+            r#"
+            SELECT COUNT(flow_id) AS count FROM flows
+                WHERE system_flow_type IS NOT NULL
+                    AND ($1::text IS NULL OR system_flow_type = ($1::text)::system_flow_type)
+                    AND ($2::text IS NULL OR flow_status = ($2::text)::flow_status_type)
+                    AND (NOT $3::boolean OR initiator IS NULL)
+                    AND ($4::text[] IS NULL OR initiator = ANY($4::text[]))
+            "#,
+            filters.by_flow_type as Option<SystemFlowType>,
+            filters.by_flow_status as Option<FlowStatus>,
+            by_system_initiator,
+            by_initiator_accounts.as_deref(),
+        )
+        .fetch_one(connection_mut)
+        .await
+        .int_err()?;
+
+        let count = usize::try_from(result.count.unwrap()).int_err()?;
+        Ok(count)
+    }
+
+    fn get_all_flow_ids(&self, pagination: FlowPaginationOpts) -> FlowIDStream<'_> {
+        Box::pin(async_stream::stream! {
+            let mut tr = self.transaction.lock().await;
+            let connection_mut = tr.connection_mut().await?;
+
+            let limit = i64::try_from(pagination.limit).int_err()?;
+            let offset = i64::try_from(pagination.offset).int_err()?;
+
+            let mut query_stream = sqlx::query!(
+                r#"
+                SELECT flow_id FROM flows
+                    ORDER BY flow_id DESC
+                    LIMIT $1 OFFSET $2
+                "#,
+                limit,
+                offset,
+            )
+            .try_map(|event_row| Ok(FlowID::new(u64::try_from(event_row.flow_id).unwrap())))
+            .fetch(connection_mut)
+            .map_err(ErrorIntoInternal::int_err);
+
+            while let Some(flow_id) = query_stream.try_next().await? {
+                yield Ok(flow_id);
+            }
+        })
+    }
+
+    async fn get_count_all_flows(&self) -> Result<usize, InternalError> {
+        let mut tr = self.transaction.lock().await;
+        let connection_mut = tr.connection_mut().await?;
+
+        let result = sqlx::query!(
+            r#"
+            SELECT COUNT(flow_id) AS count FROM flows
+            "#,
+        )
+        .fetch_one(connection_mut)
+        .await
+        .int_err()?;
+
+        let count = usize::try_from(result.count.unwrap()).int_err()?;
+        Ok(count)
+    }
+
+    fn get_unfinished_flow_ids(&self) -> FlowIDStream<'_> {
+        Box::pin(async_stream::stream! {
+            let mut tr = self.transaction.lock().await;
+            let connection_mut = tr.connection_mut().await?;
+
+            let mut query_stream = sqlx::query!(
+                r#"
+                SELECT flow_id FROM flows
+                    WHERE flow_status != 'finished'::flow_status_type
+                    ORDER BY flow_id
+                "#,
+            )
+            .try_map(|event_row| Ok(FlowID::new(u64::try_from(event_row.flow_id).unwrap())))
+            .fetch(connection_mut)
+            .map_err(ErrorIntoInternal::int_err);
+
+            while let Some(flow_id) = query_stream.try_next().await? {
+                yield Ok(flow_id);
+            }
+        })
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
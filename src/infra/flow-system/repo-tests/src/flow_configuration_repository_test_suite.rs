@@ -64,6 +64,7 @@ pub async fn test_event_store_get_streams(catalog: &Catalog) {
         rule: FlowConfigurationRule::Schedule(Schedule::TimeDelta(ScheduleTimeDelta {
             every: Duration::seconds(5),
         })),
+        retry_policy: None,
     };
     let event_1_2 = FlowConfigurationEventModified {
         event_time: Utc::now(),
@@ -72,6 +73,15 @@ pub async fn test_event_store_get_streams(catalog: &Catalog) {
         rule: FlowConfigurationRule::Schedule(Schedule::TimeDelta(ScheduleTimeDelta {
             every: Duration::seconds(5),
         })),
+        retry_policy: Some(
+            RetryPolicy::new_checked(
+                3,
+                Duration::minutes(1),
+                RetryBackoffType::Exponential,
+                RetryErrorFilter::AnyError,
+            )
+            .unwrap(),
+        ),
     };
 
     event_store
@@ -96,6 +106,7 @@ pub async fn test_event_store_get_streams(catalog: &Catalog) {
         rule: FlowConfigurationRule::Schedule(
             Schedule::try_from_5component_cron_expression("0 * * * *").unwrap(),
         ),
+        retry_policy: None,
     };
 
     event_store
@@ -115,6 +126,7 @@ pub async fn test_event_store_get_streams(catalog: &Catalog) {
         rule: FlowConfigurationRule::Schedule(Schedule::TimeDelta(ScheduleTimeDelta {
             every: Duration::seconds(5),
         })),
+        retry_policy: None,
     };
 
     event_store
@@ -191,6 +203,7 @@ pub async fn test_event_store_get_events_with_windowing(catalog: &Catalog) {
         rule: FlowConfigurationRule::Schedule(Schedule::TimeDelta(ScheduleTimeDelta {
             every: Duration::seconds(5),
         })),
+        retry_policy: None,
     };
     let event_2 = FlowConfigurationEventModified {
         event_time: Utc::now(),
@@ -199,6 +212,7 @@ pub async fn test_event_store_get_events_with_windowing(catalog: &Catalog) {
         rule: FlowConfigurationRule::Schedule(Schedule::TimeDelta(ScheduleTimeDelta {
             every: Duration::seconds(5),
         })),
+        retry_policy: None,
     };
     let event_3 = FlowConfigurationEventCreated {
         event_time: Utc::now(),
@@ -207,6 +221,7 @@ pub async fn test_event_store_get_events_with_windowing(catalog: &Catalog) {
         rule: FlowConfigurationRule::Schedule(Schedule::TimeDelta(ScheduleTimeDelta {
             every: Duration::seconds(5),
         })),
+        retry_policy: None,
     };
 
     let latest_event_id = event_store
@@ -0,0 +1,400 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use chrono::{DateTime, Duration, Utc};
+use dill::Catalog;
+use futures::TryStreamExt;
+use kamu_flow_system::*;
+use kamu_task_system::{TaskID, TaskOutcome, TaskResult};
+use opendatafabric::{AccountID, DatasetID};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub async fn test_flow_event_store_empty(catalog: &Catalog) {
+    let event_store = catalog.get_one::<dyn FlowEventStore>().unwrap();
+
+    let num_events = event_store.len().await.unwrap();
+    assert_eq!(0, num_events);
+
+    let num_flows = event_store.get_count_all_flows().await.unwrap();
+    assert_eq!(0, num_flows);
+
+    let unfinished_flow_ids: Vec<_> = event_store
+        .get_unfinished_flow_ids()
+        .try_collect()
+        .await
+        .unwrap();
+    assert_eq!(unfinished_flow_ids, []);
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub async fn test_flow_event_store_save_and_load(catalog: &Catalog) {
+    let event_store = catalog.get_one::<dyn FlowEventStore>().unwrap();
+
+    let dataset_id = DatasetID::new_seeded_ed25519(b"foo");
+    let flow_id = make_dataset_flow(
+        event_store.as_ref(),
+        &dataset_id,
+        DatasetFlowType::Ingest,
+        FlowStatus::Finished,
+        None,
+    )
+    .await;
+
+    let flow = Flow::load(flow_id, event_store.as_ref()).await.unwrap();
+    assert_eq!(flow.flow_id, flow_id);
+    assert_eq!(
+        flow.flow_key,
+        FlowKey::dataset(dataset_id.clone(), DatasetFlowType::Ingest)
+    );
+    assert_eq!(flow.status(), FlowStatus::Finished);
+
+    let other_flow_id = event_store.new_flow_id().await.unwrap();
+    assert_ne!(flow_id, other_flow_id);
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub async fn test_flow_event_store_dataset_flow_filters(catalog: &Catalog) {
+    let event_store = catalog.get_one::<dyn FlowEventStore>().unwrap();
+
+    let foo_id = DatasetID::new_seeded_ed25519(b"foo");
+    let bar_id = DatasetID::new_seeded_ed25519(b"bar");
+    let initiator_id = AccountID::new_seeded_ed25519(b"wasya");
+
+    let foo_ingest_finished = make_dataset_flow(
+        event_store.as_ref(),
+        &foo_id,
+        DatasetFlowType::Ingest,
+        FlowStatus::Finished,
+        None,
+    )
+    .await;
+    let foo_compaction_running = make_dataset_flow(
+        event_store.as_ref(),
+        &foo_id,
+        DatasetFlowType::HardCompaction,
+        FlowStatus::Running,
+        Some(initiator_id.clone()),
+    )
+    .await;
+    let foo_ingest_waiting = make_dataset_flow(
+        event_store.as_ref(),
+        &foo_id,
+        DatasetFlowType::Ingest,
+        FlowStatus::Waiting,
+        None,
+    )
+    .await;
+    let bar_ingest_waiting = make_dataset_flow(
+        event_store.as_ref(),
+        &bar_id,
+        DatasetFlowType::Ingest,
+        FlowStatus::Waiting,
+        None,
+    )
+    .await;
+
+    assert_dataset_flows(
+        event_store.as_ref(),
+        &foo_id,
+        DatasetFlowFilters::default(),
+        vec![
+            foo_ingest_waiting,
+            foo_compaction_running,
+            foo_ingest_finished,
+        ],
+    )
+    .await;
+
+    assert_dataset_flows(
+        event_store.as_ref(),
+        &foo_id,
+        DatasetFlowFilters {
+            by_flow_type: Some(DatasetFlowType::Ingest),
+            ..Default::default()
+        },
+        vec![foo_ingest_waiting, foo_ingest_finished],
+    )
+    .await;
+
+    assert_dataset_flows(
+        event_store.as_ref(),
+        &foo_id,
+        DatasetFlowFilters {
+            by_flow_status: Some(FlowStatus::Running),
+            ..Default::default()
+        },
+        vec![foo_compaction_running],
+    )
+    .await;
+
+    assert_dataset_flows(
+        event_store.as_ref(),
+        &foo_id,
+        DatasetFlowFilters {
+            by_initiator: Some(InitiatorFilter::System),
+            ..Default::default()
+        },
+        vec![foo_ingest_waiting, foo_ingest_finished],
+    )
+    .await;
+
+    assert_dataset_flows(
+        event_store.as_ref(),
+        &foo_id,
+        DatasetFlowFilters {
+            by_initiator: Some(InitiatorFilter::Account([initiator_id.clone()].into())),
+            ..Default::default()
+        },
+        vec![foo_compaction_running],
+    )
+    .await;
+
+    assert_dataset_flows(
+        event_store.as_ref(),
+        &bar_id,
+        DatasetFlowFilters::default(),
+        vec![bar_ingest_waiting],
+    )
+    .await;
+
+    let flow_ids: Vec<_> = event_store
+        .get_all_flow_ids_by_datasets(
+            [foo_id.clone(), bar_id.clone()].into(),
+            &DatasetFlowFilters {
+                by_flow_status: Some(FlowStatus::Waiting),
+                ..Default::default()
+            },
+            FlowPaginationOpts {
+                offset: 0,
+                limit: 100,
+            },
+        )
+        .try_collect()
+        .await
+        .unwrap();
+    assert_eq!(flow_ids, vec![bar_ingest_waiting, foo_ingest_waiting]);
+
+    let initiator_ids: Vec<_> = event_store
+        .get_unique_flow_initiator_ids_by_dataset(&foo_id)
+        .try_collect()
+        .await
+        .unwrap();
+    assert_eq!(initiator_ids, vec![initiator_id]);
+
+    let run_stats = event_store
+        .get_dataset_flow_run_stats(&foo_id, DatasetFlowType::Ingest)
+        .await
+        .unwrap();
+    assert!(run_stats.last_attempt_time.is_some());
+    assert_eq!(run_stats.last_success_time, run_stats.last_attempt_time);
+
+    let run_stats = event_store
+        .get_dataset_flow_run_stats(&bar_id, DatasetFlowType::Ingest)
+        .await
+        .unwrap();
+    assert!(run_stats.last_attempt_time.is_none());
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub async fn test_flow_event_store_system_flows(catalog: &Catalog) {
+    let event_store = catalog.get_one::<dyn FlowEventStore>().unwrap();
+
+    let gc_finished = make_system_flow(event_store.as_ref(), FlowStatus::Finished).await;
+    let gc_waiting = make_system_flow(event_store.as_ref(), FlowStatus::Waiting).await;
+
+    let flow_ids: Vec<_> = event_store
+        .get_all_system_flow_ids(
+            SystemFlowFilters::default(),
+            FlowPaginationOpts {
+                offset: 0,
+                limit: 100,
+            },
+        )
+        .try_collect()
+        .await
+        .unwrap();
+    assert_eq!(flow_ids, vec![gc_waiting, gc_finished]);
+
+    let num_finished_flows = event_store
+        .get_count_system_flows(&SystemFlowFilters {
+            by_flow_status: Some(FlowStatus::Finished),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+    assert_eq!(num_finished_flows, 1);
+
+    let flow_ids: Vec<_> = event_store
+        .get_all_flow_ids(FlowPaginationOpts {
+            offset: 1,
+            limit: 100,
+        })
+        .try_collect()
+        .await
+        .unwrap();
+    assert_eq!(flow_ids, vec![gc_finished]);
+
+    let run_stats = event_store
+        .get_system_flow_run_stats(SystemFlowType::GC)
+        .await
+        .unwrap();
+    assert!(run_stats.last_success_time.is_some());
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub async fn test_flow_event_store_get_unfinished_flow_ids(catalog: &Catalog) {
+    let event_store = catalog.get_one::<dyn FlowEventStore>().unwrap();
+
+    let dataset_id = DatasetID::new_seeded_ed25519(b"foo");
+
+    let flow_id_1 = make_dataset_flow(
+        event_store.as_ref(),
+        &dataset_id,
+        DatasetFlowType::Ingest,
+        FlowStatus::Running,
+        None,
+    )
+    .await;
+    make_dataset_flow(
+        event_store.as_ref(),
+        &dataset_id,
+        DatasetFlowType::Ingest,
+        FlowStatus::Finished,
+        None,
+    )
+    .await;
+    let flow_id_3 = make_system_flow(event_store.as_ref(), FlowStatus::Waiting).await;
+
+    let unfinished_flow_ids: Vec<_> = event_store
+        .get_unfinished_flow_ids()
+        .try_collect()
+        .await
+        .unwrap();
+    assert_eq!(unfinished_flow_ids, vec![flow_id_1, flow_id_3]);
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+async fn assert_dataset_flows(
+    event_store: &dyn FlowEventStore,
+    dataset_id: &DatasetID,
+    filters: DatasetFlowFilters,
+    expected_flow_ids: Vec<FlowID>,
+) {
+    let total_count = event_store
+        .get_count_flows_by_dataset(dataset_id, &filters)
+        .await
+        .unwrap();
+    assert_eq!(total_count, expected_flow_ids.len());
+
+    let flow_ids: Vec<_> = event_store
+        .get_all_flow_ids_by_dataset(
+            dataset_id,
+            filters,
+            FlowPaginationOpts {
+                offset: 0,
+                limit: 100,
+            },
+        )
+        .try_collect()
+        .await
+        .unwrap();
+    assert_eq!(flow_ids, expected_flow_ids);
+}
+
+async fn make_dataset_flow(
+    event_store: &dyn FlowEventStore,
+    dataset_id: &DatasetID,
+    flow_type: DatasetFlowType,
+    expected_status: FlowStatus,
+    initiator_id: Option<AccountID>,
+) -> FlowID {
+    make_flow(
+        event_store,
+        FlowKey::dataset(dataset_id.clone(), flow_type),
+        expected_status,
+        initiator_id,
+    )
+    .await
+}
+
+async fn make_system_flow(event_store: &dyn FlowEventStore, expected_status: FlowStatus) -> FlowID {
+    make_flow(
+        event_store,
+        FlowKey::System(FlowKeySystem {
+            flow_type: SystemFlowType::GC,
+        }),
+        expected_status,
+        None,
+    )
+    .await
+}
+
+async fn make_flow(
+    event_store: &dyn FlowEventStore,
+    flow_key: FlowKey,
+    expected_status: FlowStatus,
+    initiator_id: Option<AccountID>,
+) -> FlowID {
+    let flow_id = event_store.new_flow_id().await.unwrap();
+    let start_moment = Utc::now();
+
+    let trigger = match initiator_id {
+        Some(initiator_account_id) => FlowTrigger::Manual(FlowTriggerManual {
+            trigger_time: start_moment,
+            initiator_account_id,
+        }),
+        None => FlowTrigger::AutoPolling(FlowTriggerAutoPolling {
+            trigger_time: start_moment,
+        }),
+    };
+
+    let mut flow = Flow::new(start_moment, flow_id, flow_key, trigger, None, None);
+
+    drive_flow_to_status(&mut flow, start_moment, expected_status);
+
+    flow.save(event_store).await.unwrap();
+
+    flow_id
+}
+
+fn drive_flow_to_status(flow: &mut Flow, start_moment: DateTime<Utc>, expected_status: FlowStatus) {
+    flow.set_relevant_start_condition(
+        start_moment + Duration::try_seconds(1).unwrap(),
+        FlowStartCondition::Schedule(FlowStartConditionSchedule {
+            wake_up_at: start_moment + Duration::try_minutes(1).unwrap(),
+        }),
+    )
+    .unwrap();
+
+    if expected_status != FlowStatus::Waiting {
+        let task_id = TaskID::new(i64::try_from(u64::from(flow.flow_id)).unwrap());
+        flow.on_task_scheduled(start_moment + Duration::try_minutes(5).unwrap(), task_id)
+            .unwrap();
+        flow.on_task_running(start_moment + Duration::try_minutes(7).unwrap(), task_id)
+            .unwrap();
+
+        if expected_status == FlowStatus::Finished {
+            flow.on_task_finished(
+                start_moment + Duration::try_minutes(10).unwrap(),
+                task_id,
+                TaskOutcome::Success(TaskResult::Empty),
+                None,
+            )
+            .unwrap();
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
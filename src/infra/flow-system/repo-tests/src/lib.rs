@@ -8,5 +8,7 @@
 // by the Apache License, Version 2.0.
 
 mod flow_configuration_repository_test_suite;
+mod flow_event_repository_test_suite;
 
 pub use flow_configuration_repository_test_suite::*;
+pub use flow_event_repository_test_suite::*;
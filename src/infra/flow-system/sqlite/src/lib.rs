@@ -10,6 +10,8 @@
 // Re-exports
 pub use kamu_flow_system as domain;
 
+mod sqlite_flow_event_store;
 mod sqlite_flow_system_event_store;
 
+pub use sqlite_flow_event_store::*;
 pub use sqlite_flow_system_event_store::*;
@@ -0,0 +1,665 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::collections::HashSet;
+
+use chrono::Utc;
+use database_common::{TransactionRef, TransactionRefT};
+use dill::*;
+use futures::TryStreamExt;
+use kamu_flow_system::*;
+use opendatafabric::{AccountID, DatasetID};
+use sqlx::{FromRow, QueryBuilder, Sqlite};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Debug, sqlx::FromRow, PartialEq, Eq)]
+struct EventModel {
+    event_id: i64,
+    event_payload: sqlx::types::JsonValue,
+}
+
+#[derive(Debug, FromRow)]
+struct ReturningEventModel {
+    event_id: i64,
+}
+
+#[derive(Debug, FromRow)]
+struct NewFlowModel {
+    flow_id: i64,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub struct SqliteFlowEventStore {
+    transaction: TransactionRefT<Sqlite>,
+}
+
+#[component(pub)]
+#[interface(dyn FlowEventStore)]
+impl SqliteFlowEventStore {
+    pub fn new(transaction: TransactionRef) -> Self {
+        Self {
+            transaction: transaction.into(),
+        }
+    }
+
+    async fn register_flow(
+        connection_mut: &mut sqlx::SqliteConnection,
+        event: &FlowEventInitiated,
+    ) -> Result<(), InternalError> {
+        let flow_id = i64::try_from(event.flow_id).int_err()?;
+        let initiator = event
+            .trigger
+            .initiator_account_id()
+            .map(ToString::to_string);
+
+        let (dataset_id, dataset_flow_type, system_flow_type) = match &event.flow_key {
+            FlowKey::Dataset(fk_dataset) => (
+                Some(fk_dataset.dataset_id.to_string()),
+                Some(fk_dataset.flow_type),
+                None,
+            ),
+            FlowKey::System(fk_system) => (None, None, Some(fk_system.flow_type)),
+        };
+
+        sqlx::query!(
+            r#"
+            INSERT INTO flows (flow_id, dataset_id, dataset_flow_type, system_flow_type, initiator, flow_status)
+                VALUES ($1, $2, $3, $4, $5, 'waiting')
+            "#,
+            flow_id,
+            dataset_id,
+            dataset_flow_type,
+            system_flow_type,
+            initiator,
+        )
+        .execute(connection_mut)
+        .await
+        .int_err()?;
+
+        Ok(())
+    }
+
+    async fn update_flow_status(
+        connection_mut: &mut sqlx::SqliteConnection,
+        event: &FlowEvent,
+        new_status: FlowStatus,
+    ) -> Result<(), InternalError> {
+        let flow_id = i64::try_from(event.flow_id()).int_err()?;
+
+        // Finished tasks are recorded to compute the run statistics of flow keys
+        let (last_attempt_at, last_success_at) = match event {
+            FlowEvent::TaskFinished(e) => (
+                Some(e.event_time),
+                e.task_outcome.is_success().then_some(e.event_time),
+            ),
+            _ => (None, None),
+        };
+
+        sqlx::query!(
+            r#"
+            UPDATE flows
+                SET flow_status = $1,
+                    last_attempt_at = COALESCE($2, last_attempt_at),
+                    last_success_at = COALESCE($3, last_success_at)
+                WHERE flow_id = $4
+            "#,
+            new_status,
+            last_attempt_at,
+            last_success_at,
+            flow_id,
+        )
+        .execute(connection_mut)
+        .await
+        .int_err()?;
+
+        Ok(())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Starts a query over the dataset flows, which matches the specified datasets
+/// and filters
+fn dataset_flows_query(
+    select: &str,
+    dataset_ids: Vec<String>,
+    filters: &DatasetFlowFilters,
+) -> QueryBuilder<'static, Sqlite> {
+    let mut query_builder = QueryBuilder::<Sqlite>::new(select);
+
+    query_builder.push(" WHERE ");
+    push_any_of(&mut query_builder, "dataset_id", dataset_ids);
+
+    if let Some(flow_type) = filters.by_flow_type {
+        query_builder
+            .push(" AND dataset_flow_type = ")
+            .push_bind(flow_type);
+    }
+
+    push_common_filters(
+        &mut query_builder,
+        filters.by_flow_status,
+        filters.by_initiator.as_ref(),
+    );
+
+    query_builder
+}
+
+/// Starts a query over the system flows, which matches the specified filters
+fn system_flows_query(select: &str, filters: &SystemFlowFilters) -> QueryBuilder<'static, Sqlite> {
+    let mut query_builder = QueryBuilder::<Sqlite>::new(select);
+
+    query_builder.push(" WHERE system_flow_type IS NOT NULL");
+
+    if let Some(flow_type) = filters.by_flow_type {
+        query_builder
+            .push(" AND system_flow_type = ")
+            .push_bind(flow_type);
+    }
+
+    push_common_filters(
+        &mut query_builder,
+        filters.by_flow_status,
+        filters.by_initiator.as_ref(),
+    );
+
+    query_builder
+}
+
+/// Appends the conditions on the flow status and initiator, which are shared by
+/// the dataset and system flow queries
+fn push_common_filters(
+    query_builder: &mut QueryBuilder<'static, Sqlite>,
+    by_flow_status: Option<FlowStatus>,
+    by_initiator: Option<&InitiatorFilter>,
+) {
+    if let Some(flow_status) = by_flow_status {
+        query_builder
+            .push(" AND flow_status = ")
+            .push_bind(flow_status);
+    }
+
+    match by_initiator {
+        None => {}
+        Some(InitiatorFilter::System) => {
+            query_builder.push(" AND initiator IS NULL");
+        }
+        Some(InitiatorFilter::Account(account_ids)) => {
+            query_builder.push(" AND ");
+            push_any_of(
+                query_builder,
+                "initiator",
+                account_ids.iter().map(ToString::to_string).collect(),
+            );
+        }
+    }
+}
+
+/// SQLite has no array parameters, so the values are bound one by one, while an
+/// empty list matches nothing
+fn push_any_of(
+    query_builder: &mut QueryBuilder<'static, Sqlite>,
+    column: &str,
+    values: Vec<String>,
+) {
+    if values.is_empty() {
+        query_builder.push("FALSE");
+        return;
+    }
+
+    query_builder.push(column).push(" IN (");
+
+    let mut separated = query_builder.separated(", ");
+    for value in values {
+        separated.push_bind(value);
+    }
+    separated.push_unseparated(")");
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[async_trait::async_trait]
+impl EventStore<FlowState> for SqliteFlowEventStore {
+    async fn get_events(&self, flow_id: &FlowID, opts: GetEventsOpts) -> EventStream<FlowEvent> {
+        let mut tr = self.transaction.lock().await;
+
+        let flow_id = i64::try_from(*flow_id).unwrap();
+        let maybe_from_id = opts.from.map(EventID::into_inner);
+        let maybe_to_id = opts.to.map(EventID::into_inner);
+
+        Box::pin(async_stream::stream! {
+            let connection_mut = tr
+                .connection_mut()
+                .await?;
+
+            let mut query_stream = sqlx::query_as!(
+                EventModel,
+                r#"
+                SELECT event_id, event_payload as "event_payload: _" FROM flow_events
+                    WHERE flow_id = $1
+                         AND (cast($2 as INT8) IS NULL or event_id > $2)
+                         AND (cast($3 as INT8) IS NULL or event_id <= $3)
+                    ORDER BY event_id
+                "#,
+                flow_id,
+                maybe_from_id,
+                maybe_to_id,
+            ).try_map(|event_row| {
+                let event = serde_json::from_value::<FlowEvent>(event_row.event_payload)
+                    .map_err(|e| sqlx::Error::Decode(Box::new(e)))?;
+
+                Ok((EventID::new(event_row.event_id), event))
+            })
+            .fetch(connection_mut)
+            .map_err(|e| GetEventsError::Internal(e.int_err()));
+
+            while let Some((event_id, event)) = query_stream.try_next().await? {
+                yield Ok((event_id, event));
+            }
+        })
+    }
+
+    async fn save_events(
+        &self,
+        _flow_id: &FlowID,
+        events: Vec<FlowEvent>,
+    ) -> Result<EventID, SaveEventsError> {
+        if events.is_empty() {
+            return Err(SaveEventsError::NothingToSave);
+        }
+
+        let mut tr = self.transaction.lock().await;
+        let connection_mut = tr.connection_mut().await?;
+
+        // Flows are registered before their events are written
+        for event in &events {
+            if let FlowEvent::Initiated(e) = event {
+                Self::register_flow(&mut *connection_mut, e).await?;
+            }
+        }
+
+        let mut query_builder = QueryBuilder::<Sqlite>::new(
+            r#"
+            INSERT INTO flow_events (flow_id, event_time, event_type, event_payload)
+            "#,
+        );
+
+        query_builder.push_values(&events, |mut b, event| {
+            b.push_bind(i64::try_from(event.flow_id()).unwrap());
+            b.push_bind(event.event_time());
+            b.push_bind(event.typename());
+            b.push_bind(serde_json::to_value(event).unwrap());
+        });
+
+        query_builder.push("RETURNING event_id");
+
+        let rows = query_builder
+            .build_query_as::<ReturningEventModel>()
+            .fetch_all(&mut *connection_mut)
+            .await
+            .int_err()?;
+        let last_event_id = rows.last().unwrap().event_id;
+
+        for event in &events {
+            if !matches!(event, FlowEvent::Initiated(_))
+                && let Some(new_status) = event.new_status()
+            {
+                Self::update_flow_status(&mut *connection_mut, event, new_status).await?;
+            }
+        }
+
+        Ok(EventID::new(last_event_id))
+    }
+
+    async fn len(&self) -> Result<usize, InternalError> {
+        let mut tr = self.transaction.lock().await;
+        let connection_mut = tr.connection_mut().await?;
+
+        let result = sqlx::query!(
+            r#"
+            SELECT COUNT(event_id) AS count FROM flow_events
+            "#,
+        )
+        .fetch_one(connection_mut)
+        .await
+        .int_err()?;
+
+        let count = usize::try_from(result.count).int_err()?;
+        Ok(count)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[async_trait::async_trait]
+impl FlowEventStore for SqliteFlowEventStore {
+    async fn new_flow_id(&self) -> Result<FlowID, InternalError> {
+        let mut tr = self.transaction.lock().await;
+        let connection_mut = tr.connection_mut().await?;
+
+        let created_time = Utc::now();
+
+        let result = sqlx::query_as!(
+            NewFlowModel,
+            r#"
+            INSERT INTO flow_ids(created_time) VALUES($1) RETURNING flow_id as "flow_id: _"
+            "#,
+            created_time
+        )
+        .fetch_one(connection_mut)
+        .await
+        .int_err()?;
+
+        Ok(FlowID::new(u64::try_from(result.flow_id).int_err()?))
+    }
+
+    async fn get_dataset_flow_run_stats(
+        &self,
+        dataset_id: &DatasetID,
+        flow_type: DatasetFlowType,
+    ) -> Result<FlowRunStats, InternalError> {
+        let mut tr = self.transaction.lock().await;
+        let connection_mut = tr.connection_mut().await?;
+
+        let run_stats = sqlx::query_as!(
+            FlowRunStats,
+            r#"
+            SELECT MAX(last_attempt_at) AS "last_attempt_time: _", MAX(last_success_at) AS "last_success_time: _"
+                FROM flows
+                WHERE dataset_id = $1 AND dataset_flow_type = $2
+            "#,
+            dataset_id.to_string(),
+            flow_type,
+        )
+        .fetch_one(connection_mut)
+        .await
+        .int_err()?;
+
+        Ok(run_stats)
+    }
+
+    async fn get_system_flow_run_stats(
+        &self,
+        flow_type: SystemFlowType,
+    ) -> Result<FlowRunStats, InternalError> {
+        let mut tr = self.transaction.lock().await;
+        let connection_mut = tr.connection_mut().await?;
+
+        let run_stats = sqlx::query_as!(
+            FlowRunStats,
+            r#"
+            SELECT MAX(last_attempt_at) AS "last_attempt_time: _", MAX(last_success_at) AS "last_success_time: _"
+                FROM flows
+                WHERE system_flow_type = $1
+            "#,
+            flow_type,
+        )
+        .fetch_one(connection_mut)
+        .await
+        .int_err()?;
+
+        Ok(run_stats)
+    }
+
+    fn get_all_flow_ids_by_dataset(
+        &self,
+        dataset_id: &DatasetID,
+        filters: DatasetFlowFilters,
+        pagination: FlowPaginationOpts,
+    ) -> FlowIDStream {
+        let mut query_builder = dataset_flows_query(
+            "SELECT flow_id FROM flows",
+            vec![dataset_id.to_string()],
+            &filters,
+        );
+
+        Box::pin(async_stream::stream! {
+            let mut tr = self.transaction.lock().await;
+            let connection_mut = tr.connection_mut().await?;
+
+            let limit = i64::try_from(pagination.limit).int_err()?;
+            let offset = i64::try_from(pagination.offset).int_err()?;
+
+            query_builder
+                .push(" ORDER BY flow_id DESC LIMIT ")
+                .push_bind(limit)
+                .push(" OFFSET ")
+                .push_bind(offset);
+
+            let mut query_stream = query_builder
+                .build_query_scalar::<i64>()
+                .fetch(connection_mut)
+                .map_ok(|flow_id| FlowID::new(u64::try_from(flow_id).unwrap()))
+                .map_err(ErrorIntoInternal::int_err);
+
+            while let Some(flow_id) = query_stream.try_next().await? {
+                yield Ok(flow_id);
+            }
+        })
+    }
+
+    fn get_unique_flow_initiator_ids_by_dataset(
+        &self,
+        dataset_id: &DatasetID,
+    ) -> InitiatorIDStream {
+        let dataset_id = dataset_id.to_string();
+
+        Box::pin(async_stream::stream! {
+            let mut tr = self.transaction.lock().await;
+            let connection_mut = tr.connection_mut().await?;
+
+            let mut query_stream = sqlx::query!(
+                r#"
+                SELECT initiator FROM flows
+                    WHERE dataset_id = $1 AND initiator IS NOT NULL
+                    GROUP BY initiator
+                    ORDER BY MIN(flow_id)
+                "#,
+                dataset_id,
+            )
+            .try_map(|event_row| {
+                event_row
+                    .initiator
+                    .map(|initiator| AccountID::from_did_str(&initiator))
+                    .transpose()
+                    .map_err(|e| sqlx::Error::Decode(Box::new(e)))
+            })
+            .fetch(connection_mut)
+            .map_err(ErrorIntoInternal::int_err);
+
+            while let Some(maybe_initiator) = query_stream.try_next().await? {
+                if let Some(initiator) = maybe_initiator {
+                    yield Ok(initiator);
+                }
+            }
+        })
+    }
+
+    async fn get_count_flows_by_dataset(
+        &self,
+        dataset_id: &DatasetID,
+        filters: &DatasetFlowFilters,
+    ) -> Result<usize, InternalError> {
+        let mut tr = self.transaction.lock().await;
+        let connection_mut = tr.connection_mut().await?;
+
+        let mut query_builder = dataset_flows_query(
+            "SELECT COUNT(flow_id) FROM flows",
+            vec![dataset_id.to_string()],
+            filters,
+        );
+
+        let count = query_builder
+            .build_query_scalar::<i64>()
+            .fetch_one(connection_mut)
+            .await
+            .int_err()?;
+
+        let count = usize::try_from(count).int_err()?;
+        Ok(count)
+    }
+
+    fn get_all_flow_ids_by_datasets(
+        &self,
+        dataset_ids: HashSet<DatasetID>,
+        filters: &DatasetFlowFilters,
+        pagination: FlowPaginationOpts,
+    ) -> FlowIDStream {
+        let mut query_builder = dataset_flows_query(
+            "SELECT flow_id FROM flows",
+            dataset_ids.iter().map(ToString::to_string).collect(),
+            filters,
+        );
+
+        Box::pin(async_stream::stream! {
+            let mut tr = self.transaction.lock().await;
+            let connection_mut = tr.connection_mut().await?;
+
+            let limit = i64::try_from(pagination.limit).int_err()?;
+            let offset = i64::try_from(pagination.offset).int_err()?;
+
+            query_builder
+                .push(" ORDER BY flow_id DESC LIMIT ")
+                .push_bind(limit)
+                .push(" OFFSET ")
+                .push_bind(offset);
+
+            let mut query_stream = query_builder
+                .build_query_scalar::<i64>()
+                .fetch(connection_mut)
+                .map_ok(|flow_id| FlowID::new(u64::try_from(flow_id).unwrap()))
+                .map_err(ErrorIntoInternal::int_err);
+
+            while let Some(flow_id) = query_stream.try_next().await? {
+                yield Ok(flow_id);
+            }
+        })
+    }
+
+    fn get_all_system_flow_ids(
+        &self,
+        filters: SystemFlowFilters,
+        pagination: FlowPaginationOpts,
+    ) -> FlowIDStream {
+        let mut query_builder = system_flows_query("SELECT flow_id FROM flows", &filters);
+
+        Box::pin(async_stream::stream! {
+            let mut tr = self.transaction.lock().await;
+            let connection_mut = tr.connection_mut().await?;
+
+            let limit = i64::try_from(pagination.limit).int_err()?;
+            let offset = i64::try_from(pagination.offset).int_err()?;
+
+            query_builder
+                .push(" ORDER BY flow_id DESC LIMIT ")
+                .push_bind(limit)
+                .push(" OFFSET ")
+                .push_bind(offset);
+
+            let mut query_stream = query_builder
+                .build_query_scalar::<i64>()
+                .fetch(connection_mut)
+                .map_ok(|flow_id| FlowID::new(u64::try_from(flow_id).unwrap()))
+                .map_err(ErrorIntoInternal::int_err);
+
+            while let Some(flow_id) = query_stream.try_next().await? {
+                yield Ok(flow_id);
+            }
+        })
+    }
+
+    async fn get_count_system_flows(
+        &self,
+        filters: &SystemFlowFilters,
+    ) -> Result<usize, InternalError> {
+        let mut tr = self.transaction.lock().await;
+        let connection_mut = tr.connection_mut().await?;
+
+        let mut query_builder = system_flows_query("SELECT COUNT(flow_id) FROM flows", filters);
+
+        let count = query_builder
+            .build_query_scalar::<i64>()
+            .fetch_one(connection_mut)
+            .await
+            .int_err()?;
+
+        let count = usize::try_from(count).int_err()?;
+        Ok(count)
+    }
+
+    fn get_all_flow_ids(&self, pagination: FlowPaginationOpts) -> FlowIDStream<'_> {
+        Box::pin(async_stream::stream! {
+            let mut tr = self.transaction.lock().await;
+            let connection_mut = tr.connection_mut().await?;
+
+            let limit = i64::try_from(pagination.limit).int_err()?;
+            let offset = i64::try_from(pagination.offset).int_err()?;
+
+            let mut query_stream = sqlx::query!(
+                r#"
+                SELECT flow_id FROM flows
+                    ORDER BY flow_id DESC
+                    LIMIT $1 OFFSET $2
+                "#,
+                limit,
+                offset,
+            )
+            .try_map(|event_row| Ok(FlowID::new(u64::try_from(event_row.flow_id).unwrap())))
+            .fetch(connection_mut)
+            .map_err(ErrorIntoInternal::int_err);
+
+            while let Some(flow_id) = query_stream.try_next().await? {
+                yield Ok(flow_id);
+            }
+        })
+    }
+
+    async fn get_count_all_flows(&self) -> Result<usize, InternalError> {
+        let mut tr = self.transaction.lock().await;
+        let connection_mut = tr.connection_mut().await?;
+
+        let result = sqlx::query!(
+            r#"
+            SELECT COUNT(flow_id) AS count FROM flows
+            "#,
+        )
+        .fetch_one(connection_mut)
+        .await
+        .int_err()?;
+
+        let count = usize::try_from(result.count).int_err()?;
+        Ok(count)
+    }
+
+    fn get_unfinished_flow_ids(&self) -> FlowIDStream<'_> {
+        Box::pin(async_stream::stream! {
+            let mut tr = self.transaction.lock().await;
+            let connection_mut = tr.connection_mut().await?;
+
+            let mut query_stream = sqlx::query!(
+                r#"
+                SELECT flow_id FROM flows
+                    WHERE flow_status != 'finished'
+                    ORDER BY flow_id
+                "#,
+            )
+            .try_map(|event_row| Ok(FlowID::new(u64::try_from(event_row.flow_id).unwrap())))
+            .fetch(connection_mut)
+            .map_err(ErrorIntoInternal::int_err);
+
+            while let Some(flow_id) = query_stream.try_next().await? {
+                yield Ok(flow_id);
+            }
+        })
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
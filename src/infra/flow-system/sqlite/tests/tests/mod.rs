@@ -8,3 +8,4 @@
 // by the Apache License, Version 2.0.
 
 mod test_sqlite_flow_configuration_event_store;
+mod test_sqlite_flow_event_store;
@@ -159,9 +159,13 @@ async fn merge(prev_path: &str, new_path: &str, expected_rows: usize) {
         odf::DatasetVocabulary::default(),
         odf::MergeStrategyLedger {
             primary_key: vec!["pk1".to_string(), "pk2".to_string()],
+            mode: None,
+            dedupe_window_records: None,
+            dedupe_window_seconds: None,
         },
     )
     .merge(Some(prev), new)
+    .await
     .unwrap();
 
     let res = res.cache().await.unwrap();
@@ -179,6 +179,7 @@ async fn merge_snapshot(prev_path: &str, new_path: &str, expected_rows: usize) {
         },
     )
     .merge(Some(prev), new)
+    .await
     .unwrap();
 
     let res = res.cache().await.unwrap();
@@ -13,9 +13,11 @@
 pub mod merge_strategies;
 pub mod readers;
 mod visitor;
+mod watermark;
 mod writer;
 
 pub use kamu_core::ingest::*;
 pub use merge_strategies::*;
 pub use readers::*;
+pub use watermark::*;
 pub use writer::*;
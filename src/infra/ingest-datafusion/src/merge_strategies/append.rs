@@ -27,8 +27,13 @@ impl MergeStrategyAppend {
     }
 }
 
+#[async_trait::async_trait]
 impl MergeStrategy for MergeStrategyAppend {
-    fn merge(&self, _prev: Option<DataFrame>, new: DataFrame) -> Result<DataFrame, MergeError> {
+    async fn merge(
+        &self,
+        _prev: Option<DataFrame>,
+        new: DataFrame,
+    ) -> Result<DataFrame, MergeError> {
         let df = new
             .with_column(
                 &self.vocab.operation_type_column,
@@ -7,6 +7,11 @@
 // the Business Source License, use of this software will be governed
 // by the Apache License, Version 2.0.
 
+use std::sync::Arc;
+
+use datafusion::common::ScalarValue;
+use datafusion::functions_aggregate::min_max::max;
+use datafusion::logical_expr::expr_fn::scalar_subquery;
 use datafusion::prelude::*;
 use internal_error::*;
 use kamu_data_utils::data::dataframe_ext::DataFrameExt;
@@ -14,12 +19,19 @@ use opendatafabric as odf;
 
 use crate::*;
 
+type Op = odf::OperationType;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
 /// Ledger merge strategy.
 ///
 /// See [`opendatafabric::MergeStrategyLedger`] for details.
 pub struct MergeStrategyLedger {
     vocab: odf::DatasetVocabulary,
     primary_key: Vec<String>,
+    mode: Option<odf::LedgerMergeMode>,
+    dedupe_window_records: Option<u64>,
+    dedupe_window_seconds: Option<u64>,
 }
 
 impl MergeStrategyLedger {
@@ -27,52 +39,237 @@ impl MergeStrategyLedger {
         Self {
             vocab,
             primary_key: cfg.primary_key,
+            mode: cfg.mode,
+            dedupe_window_records: cfg.dedupe_window_records,
+            dedupe_window_seconds: cfg.dedupe_window_seconds,
         }
     }
-}
 
-impl MergeStrategy for MergeStrategyLedger {
-    // TODO: Ideas to add more comparison modes such as:
-    // - input must be a full superset of prev
-    //   - otherwise - raise error
-    //   - otherwise - emit retractions
-    // - input will only contain new events
-    // - input will contain new events and a subset of prev events
-    //   - dedupe against entire prev
-    //   - seen events should be a tail of the prev (less work to dedupe)
-    // - input can contain duplicates
-    fn merge(&self, prev: Option<DataFrame>, new: DataFrame) -> Result<DataFrame, MergeError> {
-        let new_records = if prev.is_none() {
-            // Validate PK columns exist
-            new.clone()
-                .select(
-                    self.primary_key
-                        .iter()
-                        .map(|name| col(Column::from_name(name)))
-                        .collect(),
-                )
+    fn primary_key_cols(&self) -> Vec<&str> {
+        self.primary_key.iter().map(String::as_str).collect()
+    }
+
+    fn validate_primary_key(&self, new: &DataFrame) -> Result<(), InternalError> {
+        new.clone()
+            .select(
+                self.primary_key
+                    .iter()
+                    .map(|name| col(Column::from_name(name)))
+                    .collect(),
+            )
+            .int_err()?;
+        Ok(())
+    }
+
+    /// Collapses the events of the input that have the same primary key, as
+    /// data sources that return overlapping pages will produce duplicates
+    /// within a single input.
+    ///
+    /// The earliest event by event time is kept. Input has no offsets yet, so
+    /// the rest of the columns break the ties to make the choice independent
+    /// of the order in which records were read.
+    fn dedupe_input(&self, new: DataFrame) -> Result<DataFrame, InternalError> {
+        let on_expr = self
+            .primary_key
+            .iter()
+            .map(|name| col(Column::from_name(name)))
+            .collect();
+
+        let select_expr = new
+            .schema()
+            .fields()
+            .iter()
+            .map(|f| col(Column::from_name(f.name())))
+            .collect();
+
+        let other_columns = new
+            .schema()
+            .fields()
+            .iter()
+            .map(|f| f.name())
+            .filter(|name| {
+                **name != self.vocab.event_time_column && !self.primary_key.contains(*name)
+            });
+
+        // DISTINCT ON requires sort expressions to start with the ON expressions
+        let sort_expr = self
+            .primary_key
+            .iter()
+            .chain(std::iter::once(&self.vocab.event_time_column))
+            .chain(other_columns)
+            .map(|name| col(Column::from_name(name)).sort(true, true))
+            .collect();
+
+        new.distinct_on(on_expr, select_expr, Some(sort_expr))
+            .int_err()
+    }
+
+    /// Limits previously seen events to the tail of the ledger defined by the
+    /// dedupe window, so that the comparison does not have to consider the
+    /// entire history
+    fn dedupe_window(&self, prev: DataFrame) -> Result<DataFrame, InternalError> {
+        let mut prev = prev;
+
+        if let Some(records) = self.dedupe_window_records {
+            let offset_col = || col(Column::from_name(&self.vocab.offset_column));
+
+            let lower_bound = prev
+                .clone()
+                .aggregate(vec![], vec![max(offset_col()).alias("max_offset")])
+                .int_err()?
+                .select(vec![
+                    col("max_offset") - lit(i64::try_from(records).unwrap_or(i64::MAX)),
+                ])
+                .int_err()?;
+
+            prev = prev
+                .filter(offset_col().gt(scalar_subquery(Arc::new(
+                    lower_bound.into_unoptimized_plan(),
+                ))))
                 .int_err()?;
+        }
 
-            new
-        } else {
-            let cols: Vec<_> = self.primary_key.iter().map(String::as_str).collect();
+        if let Some(seconds) = self.dedupe_window_seconds {
+            let event_time_col = || col(Column::from_name(&self.vocab.event_time_column));
 
-            new.join(prev.unwrap(), JoinType::LeftAnti, &cols, &cols, None)
+            let days = seconds / 86400;
+            let millis = (seconds % 86400) * 1000;
+
+            let lower_bound = prev
+                .clone()
+                .aggregate(vec![], vec![max(event_time_col()).alias("max_event_time")])
                 .int_err()?
-        };
+                .select(vec![
+                    col("max_event_time")
+                        - lit(ScalarValue::new_interval_dt(
+                            i32::try_from(days).unwrap_or(i32::MAX),
+                            i32::try_from(millis).int_err()?,
+                        )),
+                ])
+                .int_err()?;
+
+            prev = prev
+                .filter(event_time_col().gt_eq(scalar_subquery(Arc::new(
+                    lower_bound.into_unoptimized_plan(),
+                ))))
+                .int_err()?;
+        }
+
+        Ok(prev)
+    }
 
-        let res = new_records
-            .with_column(
-                &self.vocab.operation_type_column,
-                // TODO: Cast to `u8` after Spark is updated
-                // See: https://github.com/kamu-data/kamu-cli/issues/445
-                lit(odf::OperationType::Append as i32),
+    fn with_operation_type(&self, df: DataFrame, op: Op) -> Result<DataFrame, InternalError> {
+        df.with_column(
+            &self.vocab.operation_type_column,
+            // TODO: Cast to `u8` after Spark is updated
+            // See: https://github.com/kamu-data/kamu-cli/issues/445
+            lit(op as i32),
+        )
+        .int_err()?
+        .columns_to_front(&[&self.vocab.operation_type_column])
+        .int_err()
+    }
+
+    /// Appends the input events whose primary key is not among the previously
+    /// seen events
+    fn merge_new_keys(&self, prev: DataFrame, new: DataFrame) -> Result<DataFrame, MergeError> {
+        let cols = self.primary_key_cols();
+
+        let new_records = new
+            .join(
+                self.dedupe_window(prev)?,
+                JoinType::LeftAnti,
+                &cols,
+                &cols,
+                None,
             )
+            .int_err()?;
+
+        Ok(self.with_operation_type(new_records, Op::Append)?)
+    }
+
+    /// Appends the events that were not previously seen and, depending on the
+    /// `retract_missing` flag, either retracts the previously seen events
+    /// that are missing from the input or fails
+    async fn merge_superset(
+        &self,
+        prev: DataFrame,
+        new: DataFrame,
+        retract_missing: bool,
+    ) -> Result<DataFrame, MergeError> {
+        let cols = self.primary_key_cols();
+
+        // Events that were already retracted should not be compared against
+        let state = MergeStrategySnapshot::new(
+            self.vocab.clone(),
+            odf::MergeStrategySnapshot {
+                primary_key: self.primary_key.clone(),
+                compare_columns: None,
+            },
+        )
+        .project(self.dedupe_window(prev)?)?;
+
+        let data_columns = new
+            .schema()
+            .fields()
+            .iter()
+            .map(|f| col(Column::from_name(f.name())))
+            .collect();
+
+        let appends = new
+            .clone()
+            .join(state.clone(), JoinType::LeftAnti, &cols, &cols, None)
+            .int_err()?;
+
+        let missing = state
+            .join(new, JoinType::LeftAnti, &cols, &cols, None)
             .int_err()?
-            .columns_to_front(&[&self.vocab.operation_type_column])
+            .select(data_columns)
             .int_err()?;
 
-        Ok(res)
+        if !retract_missing {
+            let num_missing = missing.count().await.int_err()?;
+            if num_missing != 0 {
+                return Err(InputNotSupersetError { num_missing }.into());
+            }
+
+            return Ok(self.with_operation_type(appends, Op::Append)?);
+        }
+
+        Ok(self
+            .with_operation_type(appends, Op::Append)?
+            .union(self.with_operation_type(missing, Op::Retract)?)
+            .int_err()?)
+    }
+}
+
+#[async_trait::async_trait]
+impl MergeStrategy for MergeStrategyLedger {
+    async fn merge(
+        &self,
+        prev: Option<DataFrame>,
+        new: DataFrame,
+    ) -> Result<DataFrame, MergeError> {
+        type Mode = odf::LedgerMergeMode;
+
+        let Some(prev) = prev else {
+            self.validate_primary_key(&new)?;
+
+            let new_records = match self.mode {
+                Some(Mode::Deduplicate) => self.dedupe_input(new)?,
+                _ => new,
+            };
+
+            return Ok(self.with_operation_type(new_records, Op::Append)?);
+        };
+
+        match self.mode {
+            None => self.merge_new_keys(prev, new),
+            Some(Mode::Deduplicate) => self.merge_new_keys(prev, self.dedupe_input(new)?),
+            Some(Mode::NewEventsOnly) => Ok(self.with_operation_type(new, Op::Append)?),
+            Some(Mode::SupersetRetractMissing) => self.merge_superset(prev, new, true).await,
+            Some(Mode::SupersetStrict) => self.merge_superset(prev, new, false).await,
+        }
     }
 
     fn sort_order(&self) -> Vec<Expr> {
@@ -342,8 +342,13 @@ impl MergeStrategySnapshot {
     }
 }
 
+#[async_trait::async_trait]
 impl MergeStrategy for MergeStrategySnapshot {
-    fn merge(&self, prev: Option<DataFrame>, new: DataFrame) -> Result<DataFrame, MergeError> {
+    async fn merge(
+        &self,
+        prev: Option<DataFrame>,
+        new: DataFrame,
+    ) -> Result<DataFrame, MergeError> {
         if prev.is_none() {
             // Validate PK columns exist
             new.clone()
@@ -0,0 +1,64 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::path::Path;
+use std::sync::Arc;
+
+use datafusion::arrow::datatypes::SchemaRef;
+use datafusion::prelude::*;
+use internal_error::*;
+use kamu_core::ingest::ReadError;
+use opendatafabric::*;
+
+use crate::*;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub struct ReaderAvro {
+    ctx: SessionContext,
+    schema: Option<SchemaRef>,
+}
+
+impl ReaderAvro {
+    pub async fn new(ctx: SessionContext, conf: ReadStepAvro) -> Result<Self, ReadError> {
+        Ok(Self {
+            schema: super::from_ddl_schema(&ctx, &conf.schema)
+                .await?
+                .map(Arc::new),
+            ctx,
+        })
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[async_trait::async_trait]
+impl Reader for ReaderAvro {
+    async fn input_schema(&self) -> Option<SchemaRef> {
+        self.schema.clone()
+    }
+
+    async fn read(&self, path: &Path) -> Result<DataFrame, ReadError> {
+        // Avro object container files embed the writer schema, so when no schema
+        // is specified it is taken from the file itself
+        let options = AvroReadOptions {
+            schema: self.schema.as_deref(),
+            file_extension: path.extension().and_then(|s| s.to_str()).unwrap_or(""),
+            table_partition_cols: Vec::new(),
+        };
+
+        let df = self
+            .ctx
+            .read_avro(path.to_str().unwrap(), options)
+            .await
+            .int_err()?;
+
+        Ok(df)
+    }
+}
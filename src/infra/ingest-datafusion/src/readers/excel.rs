@@ -0,0 +1,168 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::path::Path;
+use std::sync::Arc;
+
+use datafusion::arrow::datatypes::SchemaRef;
+use datafusion::prelude::*;
+use internal_error::*;
+use kamu_core::ingest::ReadError;
+use opendatafabric::*;
+use serde_json::Value as JsonValue;
+
+use super::record_source::{read_records, JsonObject, RecordCallback, RecordSource};
+use crate::*;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub struct ReaderExcel {
+    ctx: SessionContext,
+    schema: Option<SchemaRef>,
+    source: Arc<ExcelSource>,
+}
+
+impl ReaderExcel {
+    pub async fn new(ctx: SessionContext, conf: ReadStepExcel) -> Result<Self, ReadError> {
+        let schema = super::from_ddl_schema(&ctx, &conf.schema)
+            .await?
+            .map(Arc::new);
+
+        // Without a header row columns are named positionally after the schema
+        let column_names = schema
+            .as_ref()
+            .map(|s| s.fields().iter().map(|f| f.name().clone()).collect());
+
+        Ok(Self {
+            ctx,
+            schema,
+            source: Arc::new(ExcelSource { conf, column_names }),
+        })
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[async_trait::async_trait]
+impl Reader for ReaderExcel {
+    async fn input_schema(&self) -> Option<SchemaRef> {
+        self.schema.clone()
+    }
+
+    async fn read(&self, path: &Path) -> Result<DataFrame, ReadError> {
+        read_records(&self.ctx, self.source.clone(), self.schema.clone(), path).await
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Converts rows of the sheet into records
+struct ExcelSource {
+    conf: ReadStepExcel,
+    column_names: Option<Vec<String>>,
+}
+
+impl ExcelSource {
+    /// Empty and error cells are treated as nulls. Excel stores all numbers as
+    /// floats, so integral values are written as integers to let schema
+    /// inference pick up integer columns.
+    #[allow(clippy::cast_possible_truncation)]
+    fn cell_to_json(cell: &calamine::Data) -> Option<JsonValue> {
+        use calamine::Data;
+
+        const MAX_SAFE_INTEGER: f64 = 9_007_199_254_740_991.0;
+
+        match cell {
+            Data::Empty | Data::Error(_) => None,
+            Data::Bool(v) => Some(JsonValue::Bool(*v)),
+            Data::Int(v) => Some(JsonValue::from(*v)),
+            Data::Float(v) if v.fract() == 0.0 && v.abs() <= MAX_SAFE_INTEGER => {
+                Some(JsonValue::from(*v as i64))
+            }
+            Data::Float(v) => serde_json::Number::from_f64(*v).map(JsonValue::Number),
+            Data::String(v) | Data::DateTimeIso(v) | Data::DurationIso(v) => {
+                Some(JsonValue::String(v.clone()))
+            }
+            Data::DateTime(v) => v.as_datetime().map(|dt| {
+                JsonValue::String(
+                    dt.and_utc()
+                        .to_rfc3339_opts(chrono::SecondsFormat::AutoSi, true),
+                )
+            }),
+        }
+    }
+}
+
+impl RecordSource for ExcelSource {
+    fn for_each_record(
+        &self,
+        path: &Path,
+        on_record: &mut RecordCallback<'_>,
+    ) -> Result<(), ReadError> {
+        use calamine::Reader as _;
+
+        let mut workbook: calamine::Xlsx<_> =
+            calamine::open_workbook(path).map_err(|e| bad_input!("Invalid Excel workbook: {e}"))?;
+
+        let sheet = match &self.conf.sheet {
+            Some(sheet) => sheet.clone(),
+            None => workbook
+                .sheet_names()
+                .first()
+                .cloned()
+                .ok_or_else(|| bad_input!("Workbook does not contain any sheets"))?,
+        };
+
+        let range = workbook
+            .worksheet_range(&sheet)
+            .map_err(|e| bad_input!("Failed to read sheet '{sheet}': {e}"))?;
+
+        // The range starts at the first non-empty cell, while rows are skipped
+        // relative to the top of the sheet
+        let first_row = range.start().map_or(0, |(row, _)| u64::from(row));
+        let skip_rows = self.conf.skip_rows.unwrap_or(0).saturating_sub(first_row);
+        let mut rows = range.rows().skip(usize::try_from(skip_rows).int_err()?);
+
+        let header: Option<Vec<String>> = if self.conf.header.unwrap_or(false) {
+            rows.next()
+                .map(|row| row.iter().map(ToString::to_string).collect())
+        } else {
+            None
+        };
+
+        let column_name = |i: usize| -> String {
+            header
+                .as_ref()
+                .and_then(|h| h.get(i))
+                .filter(|name| !name.is_empty())
+                .or_else(|| self.column_names.as_ref().and_then(|names| names.get(i)))
+                .cloned()
+                .unwrap_or_else(|| format!("column_{}", i + 1))
+        };
+
+        for row in rows {
+            let record: JsonObject = row
+                .iter()
+                .enumerate()
+                .filter_map(|(i, cell)| Self::cell_to_json(cell).map(|v| (column_name(i), v)))
+                .collect();
+
+            // Skip blank rows that are common in hand-edited sheets
+            if record.is_empty() {
+                continue;
+            }
+
+            if on_record(record)?.is_break() {
+                break;
+            }
+        }
+
+        Ok(())
+    }
+}
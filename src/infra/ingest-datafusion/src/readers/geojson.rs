@@ -7,92 +7,63 @@
 // the Business Source License, use of this software will be governed
 // by the Apache License, Version 2.0.
 
-use std::path::{Path, PathBuf};
+use std::path::Path;
+use std::sync::Arc;
 
 use datafusion::arrow::datatypes::SchemaRef;
 use datafusion::prelude::*;
 use internal_error::*;
 use kamu_core::ingest::ReadError;
 use opendatafabric::*;
+use serde_json::Value as JsonValue;
 
+use super::json_stream::JsonArrayStream;
+use super::record_source::{read_records, JsonObject, RecordCallback, RecordSource};
 use crate::*;
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
 pub struct ReaderGeoJson {
-    temp_path: PathBuf,
-    inner: ReaderNdJson,
+    ctx: SessionContext,
+    schema: Option<SchemaRef>,
 }
 
 impl ReaderGeoJson {
-    // TODO: This is an ugly API that leaves it to the caller to clean up our temp
-    // file mess. Ideally we should not produce any temp files at all and stream in
-    // all data.
-    pub async fn new(
-        ctx: SessionContext,
-        conf: ReadStepGeoJson,
-        temp_path: impl Into<PathBuf>,
-    ) -> Result<Self, ReadError> {
-        let inner_conf = ReadStepNdJson {
-            schema: conf.schema,
-            date_format: None,
-            encoding: None,
-            timestamp_format: None,
-        };
-
+    pub async fn new(ctx: SessionContext, conf: ReadStepGeoJson) -> Result<Self, ReadError> {
         Ok(Self {
-            inner: ReaderNdJson::new(ctx, inner_conf).await?,
-            temp_path: temp_path.into(),
+            schema: super::from_ddl_schema(&ctx, &conf.schema)
+                .await?
+                .map(Arc::new),
+            ctx,
         })
     }
 
-    fn convert_to_ndjson_blocking(in_path: &Path, out_path: &Path) -> Result<(), ReadError> {
-        use std::io::Write;
-
-        use serde_json::Value as JsonValue;
-
-        let mut feature_col: serde_json::Map<String, JsonValue> =
-            serde_json::from_reader(std::fs::File::open(in_path).int_err()?).int_err()?;
+    /// Flattens the feature into a record of its properties with geometry
+    /// encoded as a GeoJSON string in the `geometry` column
+    pub(crate) fn feature_to_record(feature: JsonValue) -> Result<JsonObject, ReadError> {
+        let JsonValue::Object(mut feature) = feature else {
+            return Err(bad_input!("Invalid geojson").into());
+        };
 
-        if feature_col["type"].as_str() != Some("FeatureCollection") {
-            return Err(bad_input!(
-                "Expected FeatureCollection type but got {} instead",
-                feature_col["type"]
-            )
-            .into());
+        let feature_type = feature.get("type").unwrap_or(&JsonValue::Null);
+        if feature_type.as_str() != Some("Feature") {
+            return Err(bad_input!("Expected Feature type but got {feature_type} instead").into());
         }
 
-        let features = match feature_col.remove("features") {
-            Some(JsonValue::Array(v)) => Ok(v),
+        let mut record = match feature.remove("properties") {
+            Some(JsonValue::Object(v)) => Ok(v),
             _ => Err(bad_input!("Invalid geojson")),
         }?;
 
-        let mut out_file = std::fs::File::create_new(out_path).int_err()?;
-
-        for feature in features {
-            let JsonValue::Object(mut feature) = feature else {
-                return Err(bad_input!("Invalid geojson").into());
-            };
-
-            let mut record = match feature.remove("properties") {
-                Some(JsonValue::Object(v)) => Ok(v),
-                _ => Err(bad_input!("Invalid geojson")),
-            }?;
-
-            let geometry = match feature.remove("geometry") {
-                Some(JsonValue::Object(v)) => Ok(v),
-                _ => Err(bad_input!("Invalid geojson")),
-            }?;
-
-            let geom_str = serde_json::to_string(&geometry).int_err()?;
-            record.insert("geometry".to_string(), JsonValue::String(geom_str));
+        let geometry = match feature.remove("geometry") {
+            Some(JsonValue::Object(v)) => Ok(v),
+            _ => Err(bad_input!("Invalid geojson")),
+        }?;
 
-            serde_json::to_writer(&mut out_file, &record).int_err()?;
-            writeln!(&mut out_file).int_err()?;
-        }
+        let geom_str = serde_json::to_string(&geometry).int_err()?;
+        record.insert("geometry".to_string(), JsonValue::String(geom_str));
 
-        out_file.flush().int_err()?;
-        Ok(())
+        Ok(record)
     }
 }
 
@@ -101,18 +72,38 @@ impl ReaderGeoJson {
 #[async_trait::async_trait]
 impl Reader for ReaderGeoJson {
     async fn input_schema(&self) -> Option<SchemaRef> {
-        self.inner.input_schema().await
+        self.schema.clone()
     }
 
     async fn read(&self, path: &Path) -> Result<DataFrame, ReadError> {
-        // TODO: PERF: This is a temporary, highly inefficient implementation that
-        // re-encodes GeoJson into NdJson which DataFusion can read natively
-        let in_path = path.to_path_buf();
-        let out_path = self.temp_path.clone();
-        tokio::task::spawn_blocking(move || Self::convert_to_ndjson_blocking(&in_path, &out_path))
-            .await
-            .int_err()??;
-
-        self.inner.read(&self.temp_path).await
+        read_records(
+            &self.ctx,
+            Arc::new(GeoJsonSource),
+            self.schema.clone(),
+            path,
+        )
+        .await
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Streams features of the `FeatureCollection` one by one
+struct GeoJsonSource;
+
+impl RecordSource for GeoJsonSource {
+    fn for_each_record(
+        &self,
+        path: &Path,
+        on_record: &mut RecordCallback<'_>,
+    ) -> Result<(), ReadError> {
+        let stream = JsonArrayStream {
+            sub_path: &["features"],
+            root_type: Some("FeatureCollection"),
+        };
+
+        stream.for_each(std::fs::File::open(path).int_err()?, &mut |feature| {
+            on_record(ReaderGeoJson::feature_to_record(feature)?)
+        })
     }
 }
@@ -7,91 +7,48 @@
 // the Business Source License, use of this software will be governed
 // by the Apache License, Version 2.0.
 
-use std::path::{Path, PathBuf};
+use std::path::Path;
+use std::sync::Arc;
 
 use datafusion::arrow::datatypes::SchemaRef;
 use datafusion::prelude::*;
 use internal_error::*;
 use kamu_core::ingest::ReadError;
 use opendatafabric::*;
+use serde_json::Value as JsonValue;
 
+use super::json_stream::JsonArrayStream;
+use super::record_source::{read_records, RecordCallback, RecordSource};
 use crate::*;
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
 pub struct ReaderJson {
-    sub_path: Option<String>,
-    temp_path: PathBuf,
-    inner: ReaderNdJson,
+    ctx: SessionContext,
+    schema: Option<SchemaRef>,
+    conf: ReadStepJson,
+    source: Arc<JsonSource>,
 }
 
 impl ReaderJson {
-    // TODO: This is an ugly API that leaves it to the caller to clean up our temp
-    // file mess. Ideally we should not produce any temp files at all and stream in
-    // all data.
-    pub async fn new(
-        ctx: SessionContext,
-        conf: ReadStepJson,
-        temp_path: impl Into<PathBuf>,
-    ) -> Result<Self, ReadError> {
-        let inner_conf = ReadStepNdJson {
-            schema: conf.schema,
-            date_format: conf.date_format,
-            encoding: conf.encoding,
-            timestamp_format: conf.timestamp_format,
+    pub async fn new(ctx: SessionContext, conf: ReadStepJson) -> Result<Self, ReadError> {
+        let source = JsonSource {
+            sub_path: conf
+                .sub_path
+                .as_deref()
+                .map(|p| p.split('.').map(ToString::to_string).collect())
+                .unwrap_or_default(),
         };
 
         Ok(Self {
-            sub_path: conf.sub_path,
-            temp_path: temp_path.into(),
-            inner: ReaderNdJson::new(ctx, inner_conf).await?,
+            schema: super::from_ddl_schema(&ctx, &conf.schema)
+                .await?
+                .map(Arc::new),
+            ctx,
+            conf,
+            source: Arc::new(source),
         })
     }
-
-    fn convert_to_ndjson_blocking(
-        in_path: &Path,
-        sub_path: Option<&str>,
-        out_path: &Path,
-    ) -> Result<(), ReadError> {
-        use std::io::Write;
-
-        use serde_json::Value as JsonValue;
-
-        let root: JsonValue =
-            serde_json::from_reader(std::fs::File::open(in_path).int_err()?).int_err()?;
-
-        let mut sub_value = &root;
-        let mut path = Vec::new();
-        if let Some(sub_path) = sub_path {
-            for key in sub_path.split('.') {
-                path.push(key);
-                sub_value = &sub_value[key];
-
-                if sub_value.is_null() {
-                    return Err(bad_input!("Sub-path not found: {}", path.join(".")).into());
-                }
-            }
-        }
-        if path.is_empty() {
-            path.push(".");
-        }
-
-        let Some(array) = sub_value.as_array() else {
-            return Err(
-                bad_input!("Sub-path does not specify an array: {}", path.join(".")).into(),
-            );
-        };
-
-        let mut out_file = std::fs::File::create_new(out_path).int_err()?;
-
-        for record in array {
-            serde_json::to_writer(&mut out_file, record).int_err()?;
-            writeln!(&mut out_file).int_err()?;
-        }
-
-        out_file.flush().int_err()?;
-        Ok(())
-    }
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -99,21 +56,53 @@ impl ReaderJson {
 #[async_trait::async_trait]
 impl Reader for ReaderJson {
     async fn input_schema(&self) -> Option<SchemaRef> {
-        self.inner.input_schema().await
+        self.schema.clone()
     }
 
     async fn read(&self, path: &Path) -> Result<DataFrame, ReadError> {
-        // TODO: PERF: This is a temporary, highly inefficient implementation that
-        // re-encodes GeoJson into NdJson which DataFusion can read natively
-        let sub_path = self.sub_path.clone();
-        let in_path = path.to_path_buf();
-        let out_path = self.temp_path.clone();
-        tokio::task::spawn_blocking(move || {
-            Self::convert_to_ndjson_blocking(&in_path, sub_path.as_deref(), &out_path)
-        })
-        .await
-        .int_err()??;
+        // TODO: Move this to reader construction phase
+        match self.conf.encoding.as_deref() {
+            None | Some("utf8") => Ok(()),
+            Some(v) => Err(unsupported!("Unsupported Json.encoding: {}", v)),
+        }?;
+        match self.conf.date_format.as_deref() {
+            None | Some("rfc3339") => Ok(()),
+            Some(v) => Err(unsupported!("Unsupported Json.dateFormat: {}", v)),
+        }?;
+        match self.conf.timestamp_format.as_deref() {
+            None | Some("rfc3339") => Ok(()),
+            Some(v) => Err(unsupported!("Unsupported Json.timestampFormat: {}", v)),
+        }?;
+
+        read_records(&self.ctx, self.source.clone(), self.schema.clone(), path).await
+    }
+}
 
-        self.inner.read(&self.temp_path).await
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Streams objects of the array located under the sub-path of the document
+struct JsonSource {
+    sub_path: Vec<String>,
+}
+
+impl RecordSource for JsonSource {
+    fn for_each_record(
+        &self,
+        path: &Path,
+        on_record: &mut RecordCallback<'_>,
+    ) -> Result<(), ReadError> {
+        let sub_path: Vec<&str> = self.sub_path.iter().map(String::as_str).collect();
+
+        let stream = JsonArrayStream {
+            sub_path: &sub_path,
+            root_type: None,
+        };
+
+        stream.for_each(std::fs::File::open(path).int_err()?, &mut |element| {
+            let JsonValue::Object(record) = element else {
+                return Err(bad_input!("Expected array elements to be objects").into());
+            };
+            on_record(record)
+        })
     }
 }
@@ -0,0 +1,213 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::fmt;
+use std::ops::ControlFlow;
+
+use kamu_core::ingest::ReadError;
+use serde::de::{self, DeserializeSeed, IgnoredAny, MapAccess, SeqAccess, Visitor};
+use serde_json::Value as JsonValue;
+
+use super::bad_input;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Streams elements of an array nested in a JSON document under the specified
+/// sub-path, without loading the entire document into memory
+pub(crate) struct JsonArrayStream<'a> {
+    pub sub_path: &'a [&'a str],
+    /// When specified the root object is expected to have a `type` property
+    /// with this value, as in GeoJSON `FeatureCollection`
+    pub root_type: Option<&'a str>,
+}
+
+impl<'a> JsonArrayStream<'a> {
+    pub fn for_each(
+        &self,
+        reader: impl std::io::Read,
+        on_element: &mut dyn FnMut(JsonValue) -> Result<ControlFlow<()>, ReadError>,
+    ) -> Result<(), ReadError> {
+        let mut state = State {
+            on_element,
+            error: None,
+            expected: None,
+            stopped: false,
+            root_type_seen: false,
+        };
+
+        let mut de = serde_json::Deserializer::from_reader(std::io::BufReader::new(reader));
+
+        let res = Level {
+            state: &mut state,
+            stream: self,
+            depth: 0,
+        }
+        .deserialize(&mut de)
+        .and_then(|()| de.end());
+
+        if let Some(err) = state.error {
+            return Err(err);
+        }
+
+        match res {
+            Ok(()) => (),
+            Err(_) if state.stopped => return Ok(()),
+            Err(e) if e.is_data() && state.expected.is_some() => {
+                return Err(state.expected.unwrap());
+            }
+            Err(e) => return Err(bad_input!("Invalid JSON: {e}").into()),
+        }
+
+        if let Some(root_type) = self.root_type
+            && !state.root_type_seen
+        {
+            return Err(bad_input!("Expected {root_type} type but got null instead").into());
+        }
+
+        Ok(())
+    }
+
+    fn not_found(&self, depth: usize) -> ReadError {
+        bad_input!("Sub-path not found: {}", self.sub_path[..=depth].join(".")).into()
+    }
+
+    fn not_an_array(&self) -> ReadError {
+        let path = if self.sub_path.is_empty() {
+            ".".to_string()
+        } else {
+            self.sub_path.join(".")
+        };
+        bad_input!("Sub-path does not specify an array: {path}").into()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+struct State<'f> {
+    on_element: &'f mut dyn FnMut(JsonValue) -> Result<ControlFlow<()>, ReadError>,
+    /// Error raised while handling the elements
+    error: Option<ReadError>,
+    /// Error to report if the value at the current level has unexpected type
+    expected: Option<ReadError>,
+    stopped: bool,
+    root_type_seen: bool,
+}
+
+/// Visits one level of the document on the way to the array
+struct Level<'s, 'f, 'a> {
+    state: &'s mut State<'f>,
+    stream: &'a JsonArrayStream<'a>,
+    depth: usize,
+}
+
+impl<'s, 'f, 'a> Level<'s, 'f, 'a> {
+    fn is_array_level(&self) -> bool {
+        self.depth == self.stream.sub_path.len()
+    }
+
+    fn abort<E: de::Error>(&mut self, err: ReadError) -> E {
+        self.state.error = Some(err);
+        E::custom("aborted")
+    }
+}
+
+impl<'de, 's, 'f, 'a> DeserializeSeed<'de> for Level<'s, 'f, 'a> {
+    type Value = ();
+
+    fn deserialize<D: de::Deserializer<'de>>(self, deserializer: D) -> Result<(), D::Error> {
+        self.state.expected = Some(if self.is_array_level() {
+            self.stream.not_an_array()
+        } else {
+            self.stream.not_found(self.depth)
+        });
+
+        deserializer.deserialize_any(self)
+    }
+}
+
+impl<'de, 's, 'f, 'a> Visitor<'de> for Level<'s, 'f, 'a> {
+    type Value = ();
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.is_array_level() {
+            write!(f, "an array")
+        } else {
+            write!(f, "an object")
+        }
+    }
+
+    fn visit_map<A: MapAccess<'de>>(mut self, mut map: A) -> Result<(), A::Error> {
+        if self.is_array_level() {
+            return Err(de::Error::invalid_type(de::Unexpected::Map, &self));
+        }
+        self.state.expected = None;
+
+        let key = self.stream.sub_path[self.depth];
+        let check_root_type = self.depth == 0 && self.stream.root_type.is_some();
+        let mut found = false;
+
+        while let Some(k) = map.next_key::<String>()? {
+            if k == key && !found {
+                found = true;
+                map.next_value_seed(Level {
+                    state: &mut *self.state,
+                    stream: self.stream,
+                    depth: self.depth + 1,
+                })?;
+            } else if k == "type" && check_root_type {
+                let expected = self.stream.root_type.unwrap();
+                let actual = map.next_value::<JsonValue>()?;
+                if actual.as_str() != Some(expected) {
+                    return Err(self.abort(
+                        bad_input!("Expected {expected} type but got {actual} instead").into(),
+                    ));
+                }
+                self.state.root_type_seen = true;
+            } else {
+                map.next_value::<IgnoredAny>()?;
+            }
+        }
+
+        if !found {
+            let err = self.stream.not_found(self.depth);
+            return Err(self.abort(err));
+        }
+
+        Ok(())
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(mut self, mut seq: A) -> Result<(), A::Error> {
+        if !self.is_array_level() {
+            return Err(de::Error::invalid_type(de::Unexpected::Seq, &self));
+        }
+        self.state.expected = None;
+
+        while let Some(element) = seq.next_element::<JsonValue>()? {
+            match (self.state.on_element)(element) {
+                Ok(ControlFlow::Continue(())) => (),
+                Ok(ControlFlow::Break(())) => {
+                    self.state.stopped = true;
+                    return Err(de::Error::custom("stopped"));
+                }
+                Err(err) => return Err(self.abort(err)),
+            }
+        }
+
+        Ok(())
+    }
+
+    fn visit_unit<E: de::Error>(mut self) -> Result<(), E> {
+        // Null is treated the same as a missing key
+        let err = match self.depth {
+            0 => self.state.expected.take().unwrap(),
+            depth => self.stream.not_found(depth - 1),
+        };
+        Err(self.abort(err))
+    }
+}
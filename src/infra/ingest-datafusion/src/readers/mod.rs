@@ -7,19 +7,27 @@
 // the Business Source License, use of this software will be governed
 // by the Apache License, Version 2.0.
 
+mod avro;
 mod csv;
+mod excel;
 mod geojson;
 mod json;
+mod json_stream;
 mod ndgeojson;
 mod ndjson;
+mod orc;
 mod parquet;
+mod record_source;
 mod shapefile;
 
+pub use avro::*;
 pub use csv::*;
+pub use excel::*;
 pub use geojson::*;
 pub use json::*;
 pub use ndgeojson::*;
 pub use ndjson::*;
+pub use orc::*;
 pub use parquet::*;
 pub use shapefile::*;
 
@@ -7,7 +7,8 @@
 // the Business Source License, use of this software will be governed
 // by the Apache License, Version 2.0.
 
-use std::path::{Path, PathBuf};
+use std::path::Path;
+use std::sync::Arc;
 
 use datafusion::arrow::datatypes::SchemaRef;
 use datafusion::prelude::*;
@@ -15,45 +16,60 @@ use internal_error::*;
 use kamu_core::ingest::ReadError;
 use opendatafabric::*;
 
+use super::record_source::{read_records, RecordCallback, RecordSource};
 use crate::*;
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
 pub struct ReaderNdGeoJson {
-    temp_path: PathBuf,
-    inner: ReaderNdJson,
+    ctx: SessionContext,
+    schema: Option<SchemaRef>,
 }
 
 impl ReaderNdGeoJson {
-    // TODO: This is an ugly API that leaves it to the caller to clean up our temp
-    // file mess. Ideally we should not produce any temp files at all and stream in
-    // all data.
-    pub async fn new(
-        ctx: SessionContext,
-        conf: ReadStepNdGeoJson,
-        temp_path: impl Into<PathBuf>,
-    ) -> Result<Self, ReadError> {
-        let inner_conf = ReadStepNdJson {
-            schema: conf.schema,
-            date_format: None,
-            encoding: None,
-            timestamp_format: None,
-        };
-
+    pub async fn new(ctx: SessionContext, conf: ReadStepNdGeoJson) -> Result<Self, ReadError> {
         Ok(Self {
-            inner: ReaderNdJson::new(ctx, inner_conf).await?,
-            temp_path: temp_path.into(),
+            schema: super::from_ddl_schema(&ctx, &conf.schema)
+                .await?
+                .map(Arc::new),
+            ctx,
         })
     }
+}
 
-    fn convert_to_ndjson_blocking(in_path: &Path, out_path: &Path) -> Result<(), ReadError> {
-        use std::io::prelude::*;
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[async_trait::async_trait]
+impl Reader for ReaderNdGeoJson {
+    async fn input_schema(&self) -> Option<SchemaRef> {
+        self.schema.clone()
+    }
+
+    async fn read(&self, path: &Path) -> Result<DataFrame, ReadError> {
+        read_records(
+            &self.ctx,
+            Arc::new(NdGeoJsonSource),
+            self.schema.clone(),
+            path,
+        )
+        .await
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
-        use serde_json::Value as JsonValue;
+/// Reads features line by line
+struct NdGeoJsonSource;
 
-        let in_file = std::fs::File::open(in_path).int_err()?;
-        let mut out_file = std::fs::File::create_new(out_path).int_err()?;
+impl RecordSource for NdGeoJsonSource {
+    fn for_each_record(
+        &self,
+        path: &Path,
+        on_record: &mut RecordCallback<'_>,
+    ) -> Result<(), ReadError> {
+        use std::io::prelude::*;
 
+        let in_file = std::fs::File::open(path).int_err()?;
         let mut reader = std::io::BufReader::new(in_file);
         let mut buffer = String::new();
 
@@ -64,59 +80,19 @@ impl ReaderNdGeoJson {
             let line = buffer.trim();
 
             if !line.is_empty() {
-                let mut feature: serde_json::Map<String, JsonValue> =
-                    serde_json::from_str(line).int_err()?;
-
-                if feature["type"].as_str() != Some("Feature") {
-                    return Err(bad_input!(
-                        "Expected Feature type but got {} instead",
-                        feature["type"]
-                    )
-                    .into());
-                }
-
-                let mut record = match feature.remove("properties") {
-                    Some(JsonValue::Object(v)) => Ok(v),
-                    _ => Err(bad_input!("Invalid geojson")),
-                }?;
+                let feature =
+                    serde_json::from_str(line).map_err(|e| bad_input!("Invalid JSON: {e}"))?;
 
-                let geometry = match feature.remove("geometry") {
-                    Some(JsonValue::Object(v)) => Ok(v),
-                    _ => Err(bad_input!("Invalid geojson")),
-                }?;
+                let record = ReaderGeoJson::feature_to_record(feature)?;
 
-                let geom_str = serde_json::to_string(&geometry).int_err()?;
-                record.insert("geometry".to_string(), JsonValue::String(geom_str));
-
-                serde_json::to_writer(&mut out_file, &record).int_err()?;
-                writeln!(&mut out_file).int_err()?;
+                if on_record(record)?.is_break() {
+                    break;
+                }
             }
 
             buffer.clear();
         }
 
-        out_file.flush().int_err()?;
         Ok(())
     }
 }
-
-////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
-
-#[async_trait::async_trait]
-impl Reader for ReaderNdGeoJson {
-    async fn input_schema(&self) -> Option<SchemaRef> {
-        self.inner.input_schema().await
-    }
-
-    async fn read(&self, path: &Path) -> Result<DataFrame, ReadError> {
-        // TODO: PERF: This is a temporary, highly inefficient implementation that
-        // re-encodes NdGeoJson into NdJson which DataFusion can read natively
-        let in_path = path.to_path_buf();
-        let out_path = self.temp_path.clone();
-        tokio::task::spawn_blocking(move || Self::convert_to_ndjson_blocking(&in_path, &out_path))
-            .await
-            .int_err()??;
-
-        self.inner.read(&self.temp_path).await
-    }
-}
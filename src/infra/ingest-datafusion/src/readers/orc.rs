@@ -0,0 +1,184 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use datafusion::arrow::datatypes::SchemaRef;
+use datafusion::arrow::record_batch::RecordBatch;
+use datafusion::common::Column;
+use datafusion::datasource::streaming::StreamingTable;
+use datafusion::error::DataFusionError;
+use datafusion::execution::{SendableRecordBatchStream, TaskContext};
+use datafusion::physical_plan::stream::RecordBatchStreamAdapter;
+use datafusion::physical_plan::streaming::PartitionStream;
+use datafusion::prelude::*;
+use internal_error::*;
+use kamu_core::ingest::ReadError;
+use opendatafabric::*;
+use tokio::sync::mpsc;
+
+use super::record_source::{BATCH_SIZE, CHANNEL_CAPACITY};
+use crate::*;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub struct ReaderOrc {
+    ctx: SessionContext,
+    schema: Option<SchemaRef>,
+}
+
+impl ReaderOrc {
+    pub async fn new(ctx: SessionContext, conf: ReadStepOrc) -> Result<Self, ReadError> {
+        Ok(Self {
+            schema: super::from_ddl_schema(&ctx, &conf.schema)
+                .await?
+                .map(Arc::new),
+            ctx,
+        })
+    }
+
+    fn open_blocking(
+        path: &Path,
+    ) -> Result<orc_rust::ArrowReaderBuilder<std::fs::File>, ReadError> {
+        let file = std::fs::File::open(path).int_err()?;
+
+        let builder = orc_rust::ArrowReaderBuilder::try_new(file)
+            .map_err(|e| bad_input!("Invalid ORC file: {e}"))?
+            .with_batch_size(BATCH_SIZE);
+
+        Ok(builder)
+    }
+
+    /// Selects columns of the specified schema casting them to the specified
+    /// types
+    fn coerce_to_schema(df: DataFrame, schema: &SchemaRef) -> Result<DataFrame, ReadError> {
+        let mut columns = Vec::new();
+
+        for field in schema.fields() {
+            if df
+                .schema()
+                .field_with_unqualified_name(field.name())
+                .is_err()
+            {
+                return Err(
+                    bad_input!("Column '{}' not found in the ORC file", field.name()).into(),
+                );
+            }
+
+            columns.push(
+                cast(
+                    col(Column::from_name(field.name())),
+                    field.data_type().clone(),
+                )
+                .alias(field.name()),
+            );
+        }
+
+        df.select(columns).int_err().map_err(Into::into)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[async_trait::async_trait]
+impl Reader for ReaderOrc {
+    async fn input_schema(&self) -> Option<SchemaRef> {
+        self.schema.clone()
+    }
+
+    async fn read(&self, path: &Path) -> Result<DataFrame, ReadError> {
+        // DataFusion has no native ORC support yet, so the file is decoded
+        // stripe by stripe as the data frame is being consumed. Only the
+        // footer is read here to determine the schema of the file.
+        let in_path = path.to_path_buf();
+        let file_schema = tokio::task::spawn_blocking(move || {
+            Self::open_blocking(&in_path).map(|builder| builder.build().schema())
+        })
+        .await
+        .int_err()??;
+
+        let partition = OrcPartition {
+            path: path.to_path_buf(),
+            schema: file_schema.clone(),
+        };
+
+        let table = StreamingTable::try_new(file_schema, vec![Arc::new(partition)]).int_err()?;
+        let df = self.ctx.read_table(Arc::new(table)).int_err()?;
+
+        match &self.schema {
+            None => Ok(df),
+            Some(schema) => Self::coerce_to_schema(df, schema),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Partition that re-reads the file from the beginning on every execution
+struct OrcPartition {
+    path: PathBuf,
+    schema: SchemaRef,
+}
+
+impl OrcPartition {
+    fn decode_blocking(
+        path: &Path,
+        tx: &mpsc::Sender<Result<RecordBatch, DataFusionError>>,
+    ) -> Result<(), ReadError> {
+        let reader = ReaderOrc::open_blocking(path)?.build();
+
+        for batch in reader {
+            let batch = batch.map_err(|e| bad_input!("Failed to decode ORC stripe: {e}"))?;
+
+            // Send only fails when the consumer has gone away, e.g. when the query
+            // was cancelled or has a limit, in which case reading stops early
+            if tx.blocking_send(Ok(batch)).is_err() {
+                break;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl std::fmt::Debug for OrcPartition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("OrcPartition")
+            .field("path", &self.path)
+            .finish_non_exhaustive()
+    }
+}
+
+impl PartitionStream for OrcPartition {
+    fn schema(&self) -> &SchemaRef {
+        &self.schema
+    }
+
+    fn execute(&self, _ctx: Arc<TaskContext>) -> SendableRecordBatchStream {
+        let (tx, rx) = mpsc::channel(CHANNEL_CAPACITY);
+
+        let path = self.path.clone();
+
+        tokio::task::spawn_blocking(move || {
+            if let Err(err) = Self::decode_blocking(&path, &tx) {
+                // Receiver is gone if the query was cancelled, so error can be ignored
+                let _ = tx.blocking_send(Err(DataFusionError::External(err.into())));
+            }
+        });
+
+        let stream =
+            futures::stream::unfold(
+                rx,
+                |mut rx| async move { rx.recv().await.map(|res| (res, rx)) },
+            );
+
+        Box::pin(RecordBatchStreamAdapter::new(self.schema.clone(), stream))
+    }
+}
@@ -0,0 +1,206 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::ops::ControlFlow;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use datafusion::arrow::datatypes::SchemaRef;
+use datafusion::arrow::json::reader::{infer_json_schema_from_iterator, ReaderBuilder};
+use datafusion::arrow::record_batch::RecordBatch;
+use datafusion::datasource::streaming::StreamingTable;
+use datafusion::error::DataFusionError;
+use datafusion::execution::{SendableRecordBatchStream, TaskContext};
+use datafusion::physical_plan::stream::RecordBatchStreamAdapter;
+use datafusion::physical_plan::streaming::PartitionStream;
+use datafusion::prelude::*;
+use internal_error::*;
+use kamu_core::ingest::ReadError;
+use serde_json::Value as JsonValue;
+use tokio::sync::mpsc;
+
+use super::bad_input;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub(crate) type JsonObject = serde_json::Map<String, JsonValue>;
+
+pub(crate) type RecordCallback<'a> =
+    dyn FnMut(JsonObject) -> Result<ControlFlow<()>, ReadError> + 'a;
+
+/// Source of records for the formats that DataFusion cannot read natively.
+///
+/// Records are produced one by one on a blocking thread and are decoded into
+/// Arrow batches as they arrive, so the input file is never fully loaded into
+/// memory and no intermediate files are produced.
+pub(crate) trait RecordSource: Send + Sync + 'static {
+    /// Passes records of the file to the callback until all records are
+    /// exhausted or the callback requests to stop
+    fn for_each_record(
+        &self,
+        path: &Path,
+        on_record: &mut RecordCallback<'_>,
+    ) -> Result<(), ReadError>;
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+const DEFAULT_INFER_SCHEMA_ROWS: usize = 1000;
+pub(super) const BATCH_SIZE: usize = 8192;
+/// Number of decoded batches that can be buffered ahead of the consumer
+pub(super) const CHANNEL_CAPACITY: usize = 2;
+
+/// Creates a data frame that lazily streams records of the file from the
+/// specified source. When schema is not provided it is inferred from the
+/// first records of the file.
+pub(crate) async fn read_records(
+    ctx: &SessionContext,
+    source: Arc<dyn RecordSource>,
+    schema: Option<SchemaRef>,
+    path: &Path,
+) -> Result<DataFrame, ReadError> {
+    let path = path.to_path_buf();
+
+    let schema = match schema {
+        Some(schema) => schema,
+        None => {
+            let source = source.clone();
+            let path = path.clone();
+            tokio::task::spawn_blocking(move || infer_schema_blocking(source.as_ref(), &path))
+                .await
+                .int_err()??
+        }
+    };
+
+    let partition = RecordSourcePartition {
+        source,
+        path,
+        schema: schema.clone(),
+    };
+
+    let table = StreamingTable::try_new(schema, vec![Arc::new(partition)]).int_err()?;
+    let df = ctx.read_table(Arc::new(table)).int_err()?;
+
+    Ok(df)
+}
+
+fn infer_schema_blocking(source: &dyn RecordSource, path: &Path) -> Result<SchemaRef, ReadError> {
+    let mut sample = Vec::new();
+
+    source.for_each_record(path, &mut |record| {
+        sample.push(JsonValue::Object(record));
+
+        if sample.len() < DEFAULT_INFER_SCHEMA_ROWS {
+            Ok(ControlFlow::Continue(()))
+        } else {
+            Ok(ControlFlow::Break(()))
+        }
+    })?;
+
+    let schema = infer_json_schema_from_iterator(sample.into_iter().map(Ok))
+        .map_err(|e| bad_input!("Failed to infer schema: {e}"))?;
+
+    Ok(Arc::new(schema))
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Partition that re-reads the file from the beginning on every execution
+struct RecordSourcePartition {
+    source: Arc<dyn RecordSource>,
+    path: PathBuf,
+    schema: SchemaRef,
+}
+
+impl RecordSourcePartition {
+    fn decode_blocking(
+        source: &dyn RecordSource,
+        path: &Path,
+        schema: SchemaRef,
+        tx: &mpsc::Sender<Result<RecordBatch, DataFusionError>>,
+    ) -> Result<(), ReadError> {
+        let mut decoder = ReaderBuilder::new(schema)
+            .with_batch_size(BATCH_SIZE)
+            .build_decoder()
+            .int_err()?;
+
+        let mut flush = |buffer: &mut Vec<JsonObject>| -> Result<ControlFlow<()>, ReadError> {
+            decoder
+                .serialize(buffer)
+                .map_err(|e| bad_input!("Failed to decode records: {e}"))?;
+            buffer.clear();
+
+            let Some(batch) = decoder
+                .flush()
+                .map_err(|e| bad_input!("Failed to decode records: {e}"))?
+            else {
+                return Ok(ControlFlow::Continue(()));
+            };
+
+            // Send only fails when the consumer has gone away, e.g. when the query
+            // was cancelled or has a limit, in which case reading stops early
+            match tx.blocking_send(Ok(batch)) {
+                Ok(()) => Ok(ControlFlow::Continue(())),
+                Err(_) => Ok(ControlFlow::Break(())),
+            }
+        };
+
+        let mut buffer = Vec::with_capacity(BATCH_SIZE);
+
+        source.for_each_record(path, &mut |record| {
+            buffer.push(record);
+
+            if buffer.len() < BATCH_SIZE {
+                Ok(ControlFlow::Continue(()))
+            } else {
+                flush(&mut buffer)
+            }
+        })?;
+
+        flush(&mut buffer)?;
+        Ok(())
+    }
+}
+
+impl std::fmt::Debug for RecordSourcePartition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RecordSourcePartition")
+            .field("path", &self.path)
+            .finish_non_exhaustive()
+    }
+}
+
+impl PartitionStream for RecordSourcePartition {
+    fn schema(&self) -> &SchemaRef {
+        &self.schema
+    }
+
+    fn execute(&self, _ctx: Arc<TaskContext>) -> SendableRecordBatchStream {
+        let (tx, rx) = mpsc::channel(CHANNEL_CAPACITY);
+
+        let source = self.source.clone();
+        let path = self.path.clone();
+        let schema = self.schema.clone();
+
+        tokio::task::spawn_blocking(move || {
+            if let Err(err) = Self::decode_blocking(source.as_ref(), &path, schema, &tx) {
+                // Receiver is gone if the query was cancelled, so error can be ignored
+                let _ = tx.blocking_send(Err(DataFusionError::External(err.into())));
+            }
+        });
+
+        let stream =
+            futures::stream::unfold(
+                rx,
+                |mut rx| async move { rx.recv().await.map(|res| (res, rx)) },
+            );
+
+        Box::pin(RecordBatchStreamAdapter::new(self.schema.clone(), stream))
+    }
+}
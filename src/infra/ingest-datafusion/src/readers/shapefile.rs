@@ -8,130 +8,82 @@
 // by the Apache License, Version 2.0.
 
 use std::path::{Path, PathBuf};
+use std::sync::Arc;
 
 use datafusion::arrow::datatypes::SchemaRef;
 use datafusion::prelude::*;
 use internal_error::*;
 use kamu_core::ingest::ReadError;
 use opendatafabric::*;
+use serde_json::Value as JsonValue;
 
+use super::record_source::{read_records, RecordCallback, RecordSource};
 use crate::*;
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
 pub struct ReaderEsriShapefile {
+    ctx: SessionContext,
+    schema: Option<SchemaRef>,
     sub_path: Option<String>,
     temp_path: PathBuf,
-    inner: ReaderNdJson,
 }
 
 impl ReaderEsriShapefile {
-    // TODO: This is an ugly API that leaves it to the caller to clean up our temp
-    // file mess. Ideally we should not produce any temp files at all and stream in
-    // all data.
+    /// Shapefile is extracted from the archive into a temporary directory
+    /// created under `temp_path`, which is removed as soon as reading is
+    /// finished
     pub async fn new(
         ctx: SessionContext,
         conf: ReadStepEsriShapefile,
         temp_path: impl Into<PathBuf>,
     ) -> Result<Self, ReadError> {
-        let inner_conf = ReadStepNdJson {
-            schema: conf.schema,
-            date_format: None,
-            encoding: None,
-            timestamp_format: None,
-        };
-
         Ok(Self {
+            schema: super::from_ddl_schema(&ctx, &conf.schema)
+                .await?
+                .map(Arc::new),
+            ctx,
             sub_path: conf.sub_path,
             temp_path: temp_path.into(),
-            inner: ReaderNdJson::new(ctx, inner_conf).await?,
         })
     }
 
-    fn convert_to_ndjson_blocking(
-        in_path: &Path,
-        tmp_path: &Path,
-        sub_path: Option<&str>,
-    ) -> Result<PathBuf, ReadError> {
-        use std::io::Write;
-
-        use serde_json::Value as JsonValue;
-
-        Self::extract_zip(in_path, tmp_path)?;
-        let shp_path = Self::locate_shp_file(tmp_path, sub_path)?;
-
-        let temp_json_path = tmp_path.join("__temp.json");
-        let mut file = std::fs::File::create_new(&temp_json_path).int_err()?;
-
-        let mut reader = shapefile::Reader::from_path(shp_path).int_err()?;
-        for rec in reader.iter_shapes_and_records() {
-            let (shape, record) = rec.int_err()?;
-
-            let geometry: geo_types::Geometry = shape.try_into().int_err()?;
-            let geometry = geojson::Geometry {
-                value: geojson::Value::from(&geometry),
-                bbox: None,
-                foreign_members: None,
-            };
-
-            let mut json = Self::shp_record_to_json(record);
-            json.insert(
-                "geometry".to_string(),
-                JsonValue::String(geometry.to_string()),
-            );
-
-            serde_json::to_writer(&mut file, &json).int_err()?;
-            writeln!(&mut file).int_err()?;
-        }
-
-        file.flush().int_err()?;
-        Ok(temp_json_path)
-    }
-
-    // TODO: PERF: Consider subPath argument to skip extracting unrelated data
-    fn extract_zip(in_path: &Path, out_path: &Path) -> Result<(), InternalError> {
-        std::fs::create_dir(out_path).int_err()?;
-        let mut archive =
-            zip::ZipArchive::new(std::fs::File::open(in_path).int_err()?).int_err()?;
-        archive.extract(out_path).int_err()?;
-        Ok(())
-    }
-
-    fn locate_shp_file(dir: &Path, subpath: Option<&str>) -> Result<PathBuf, ReadError> {
-        let is_shp_file = |p: &Path| -> bool { p.extension().is_some_and(|s| s == "shp") };
+    /// Returns the name of the `.shp` entry of the archive selected by the
+    /// sub-path
+    fn locate_shp_entry(in_path: &Path, subpath: Option<&str>) -> Result<String, ReadError> {
+        let archive = zip::ZipArchive::new(std::fs::File::open(in_path).int_err()?)
+            .map_err(|e| bad_input!("Invalid zip archive: {e}"))?;
 
-        let list_shp_files = || -> Vec<PathBuf> {
-            walkdir::WalkDir::new(dir)
-                .into_iter()
-                .filter_map(Result::ok)
-                .filter(|e| is_shp_file(e.path()))
-                .map(walkdir::DirEntry::into_path)
-                .collect()
+        let is_shp_file = |name: &str| -> bool {
+            Path::new(name)
+                .extension()
+                .is_some_and(|s| s.eq_ignore_ascii_case("shp"))
         };
 
-        let to_relative_paths = |paths: Vec<PathBuf>| -> Vec<String> {
-            paths
-                .into_iter()
-                .filter_map(|p| {
-                    p.strip_prefix(dir)
-                        .ok()
-                        .map(|p| p.to_string_lossy().into_owned())
-                })
-                .collect()
-        };
+        let mut shp_files: Vec<String> = archive
+            .file_names()
+            .filter(|name| is_shp_file(name))
+            .map(ToString::to_string)
+            .collect();
+        shp_files.sort();
 
         if let Some(subpath) = subpath {
-            let path = dir.join(subpath);
-
             // Try exact match
-            if path.is_file() {
-                Ok(path)
+            if archive.file_names().any(|name| name == subpath) {
+                Ok(subpath.to_string())
             } else {
                 // Try globed match
-                let matches: Vec<_> = glob::glob(path.to_str().unwrap())
-                    .int_err()?
-                    .filter_map(Result::ok)
-                    .filter(|p| is_shp_file(p))
+                let pattern = glob::Pattern::new(subpath)
+                    .map_err(|e| bad_input!("Invalid sub-path pattern '{subpath}': {e}"))?;
+                let match_options = glob::MatchOptions {
+                    require_literal_separator: true,
+                    ..Default::default()
+                };
+
+                let matches: Vec<_> = shp_files
+                    .iter()
+                    .filter(|name| pattern.matches_with(name, match_options))
+                    .cloned()
                     .collect();
 
                 if matches.len() == 1 {
@@ -141,21 +93,19 @@ impl ReaderEsriShapefile {
                         "Archive does not contain any .shp files under '{}' sub-path. Possible \
                          entries are:\n  - {}",
                         subpath,
-                        to_relative_paths(list_shp_files()).join("\n  - ")
+                        shp_files.join("\n  - ")
                     )
                     .into())
                 } else {
                     Err(bad_input!(
                         "Archive contains multiple .shp files matching sub-path '{}':\n  - {}",
                         subpath,
-                        to_relative_paths(matches).join("\n  - ")
+                        matches.join("\n  - ")
                     )
                     .into())
                 }
             }
         } else {
-            let shp_files = list_shp_files();
-
             use std::cmp::Ordering;
 
             match shp_files.len().cmp(&1) {
@@ -163,7 +113,7 @@ impl ReaderEsriShapefile {
                 Ordering::Greater => Err(bad_input!(
                     "Archive contains multiple .shp files. Specify `subPath` argument to select \
                      one of:\n  - {}",
-                    to_relative_paths(shp_files).join("\n  - ")
+                    shp_files.join("\n  - ")
                 )
                 .into()),
                 Ordering::Less => {
@@ -176,7 +126,6 @@ impl ReaderEsriShapefile {
     fn shp_record_to_json(
         record: shapefile::dbase::Record,
     ) -> serde_json::Map<String, serde_json::Value> {
-        use serde_json::Value as JsonValue;
         use shapefile::dbase::FieldValue as ShpValue;
 
         let mut json = serde_json::Map::new();
@@ -223,22 +172,115 @@ impl ReaderEsriShapefile {
 #[async_trait::async_trait]
 impl Reader for ReaderEsriShapefile {
     async fn input_schema(&self) -> Option<SchemaRef> {
-        self.inner.input_schema().await
+        self.schema.clone()
     }
 
     async fn read(&self, path: &Path) -> Result<DataFrame, ReadError> {
-        // TODO: PERF: This is a temporary, highly inefficient implementation that
-        // decodes Shapefile into NdJson which DataFusion can read natively
+        // Sub-path is resolved eagerly to report errors before the data is read
         let in_path = path.to_path_buf();
-        let out_path = self.temp_path.clone();
         let sub_path = self.sub_path.clone();
-
-        let temp_json_path = tokio::task::spawn_blocking(move || {
-            Self::convert_to_ndjson_blocking(&in_path, &out_path, sub_path.as_deref())
+        let shp_entry = tokio::task::spawn_blocking(move || {
+            Self::locate_shp_entry(&in_path, sub_path.as_deref())
         })
         .await
         .int_err()??;
 
-        self.inner.read(&temp_json_path).await
+        let source = ShapefileSource {
+            shp_entry,
+            temp_path: self.temp_path.clone(),
+        };
+
+        read_records(&self.ctx, Arc::new(source), self.schema.clone(), path).await
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Extracts only the files of the selected shapefile from the archive and
+/// reads its shapes and records one by one
+struct ShapefileSource {
+    shp_entry: String,
+    temp_path: PathBuf,
+}
+
+impl ShapefileSource {
+    /// Extracts `.shp` entry along with its sidecar files (`.dbf`, `.shx`,
+    /// etc.) that share the same name, returning the path to the extracted
+    /// `.shp` file
+    fn extract_shapefile(&self, in_path: &Path, out_dir: &Path) -> Result<PathBuf, ReadError> {
+        let mut archive =
+            zip::ZipArchive::new(std::fs::File::open(in_path).int_err()?).int_err()?;
+
+        let shp_stem = Path::new(&self.shp_entry).with_extension("");
+
+        let entries: Vec<String> = archive
+            .file_names()
+            .filter(|name| Path::new(name).with_extension("") == shp_stem)
+            .map(ToString::to_string)
+            .collect();
+
+        // Files are flattened and extensions normalized, so that the shapefile
+        // reader can locate the sidecar files
+        let file_stem = shp_stem.file_name().unwrap_or_default();
+        let out_path_with_ext = |ext: &std::ffi::OsStr| {
+            let mut file_name = file_stem.to_os_string();
+            file_name.push(".");
+            file_name.push(ext);
+            out_dir.join(file_name)
+        };
+
+        for entry in entries {
+            let Some(ext) = Path::new(&entry).extension() else {
+                continue;
+            };
+
+            let out_path = out_path_with_ext(&ext.to_ascii_lowercase());
+
+            let mut out_file = std::fs::File::create_new(out_path).int_err()?;
+            let mut entry_file = archive.by_name(&entry).int_err()?;
+            std::io::copy(&mut entry_file, &mut out_file).int_err()?;
+        }
+
+        Ok(out_path_with_ext("shp".as_ref()))
+    }
+}
+
+impl RecordSource for ShapefileSource {
+    fn for_each_record(
+        &self,
+        path: &Path,
+        on_record: &mut RecordCallback<'_>,
+    ) -> Result<(), ReadError> {
+        std::fs::create_dir_all(&self.temp_path).int_err()?;
+        let temp_dir = tempfile::Builder::new()
+            .prefix("shapefile-")
+            .tempdir_in(&self.temp_path)
+            .int_err()?;
+
+        let shp_path = self.extract_shapefile(path, temp_dir.path())?;
+
+        let mut reader = shapefile::Reader::from_path(shp_path).int_err()?;
+        for rec in reader.iter_shapes_and_records() {
+            let (shape, record) = rec.int_err()?;
+
+            let geometry: geo_types::Geometry = shape.try_into().int_err()?;
+            let geometry = geojson::Geometry {
+                value: geojson::Value::from(&geometry),
+                bbox: None,
+                foreign_members: None,
+            };
+
+            let mut json = ReaderEsriShapefile::shp_record_to_json(record);
+            json.insert(
+                "geometry".to_string(),
+                JsonValue::String(geometry.to_string()),
+            );
+
+            if on_record(json)?.is_break() {
+                break;
+            }
+        }
+
+        Ok(())
     }
 }
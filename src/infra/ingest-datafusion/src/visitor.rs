@@ -7,6 +7,8 @@
 // the Business Source License, use of this software will be governed
 // by the Apache License, Version 2.0.
 
+use std::collections::HashSet;
+
 use kamu_core::{
     HashedMetadataBlockRef,
     MetadataChainVisitor,
@@ -14,6 +16,8 @@ use kamu_core::{
 };
 use opendatafabric::{
     AddPushSource,
+    DisablePollingSource,
+    DisablePushSource,
     MergeStrategy,
     MergeStrategyAppend,
     MetadataEvent,
@@ -25,10 +29,17 @@ use crate::{ScanMetadataError, SourceNotFoundError};
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
+/// Searches for the active source event. When the source was disabled and no
+/// other source is active, the disabling event is returned in its place.
 pub struct SourceEventVisitor<'a> {
     maybe_source_name: Option<&'a str>,
     next_block_flags: Flag,
     maybe_source_event: Option<MetadataEvent>,
+    maybe_disable_source_event: Option<MetadataEvent>,
+    is_polling_source_disabled: bool,
+    // Names of push sources for which the latest event was already visited, so
+    // that older events of these sources are ignored
+    visited_push_sources: HashSet<String>,
 }
 
 impl<'a> SourceEventVisitor<'a> {
@@ -44,6 +55,9 @@ impl<'a> SourceEventVisitor<'a> {
             next_block_flags: INITIAL_NEXT_BLOCK_FLAGS,
 
             maybe_source_event: None,
+            maybe_disable_source_event: None,
+            is_polling_source_disabled: false,
+            visited_push_sources: HashSet::new(),
         }
     }
 
@@ -57,6 +71,14 @@ impl<'a> SourceEventVisitor<'a> {
                 MetadataEvent::AddPushSource(e) => Ok(e.merge.clone()),
                 _ => unreachable!(),
             },
+            // Source was disabled - no data can be merged, but watermarks can still be
+            // written
+            (None, _) if self.maybe_disable_source_event.is_some() => {
+                return Ok((
+                    self.maybe_disable_source_event,
+                    MergeStrategy::Append(MergeStrategyAppend {}),
+                ));
+            }
             // No source defined - assuming append strategy
             (None, None) => Ok(MergeStrategy::Append(MergeStrategyAppend {})),
             // Source expected but not found
@@ -70,6 +92,10 @@ impl<'a> SourceEventVisitor<'a> {
     }
 
     fn handle_set_polling_source(&mut self, e: &SetPollingSource) -> Result<(), ScanMetadataError> {
+        if self.is_polling_source_disabled {
+            return Ok(());
+        }
+
         if self.maybe_source_name.is_some() {
             return Err(SourceNotFoundError::new(
                 self.maybe_source_name,
@@ -83,7 +109,19 @@ impl<'a> SourceEventVisitor<'a> {
         Ok(())
     }
 
+    fn handle_disable_polling_source(&mut self) {
+        self.is_polling_source_disabled = true;
+
+        if self.maybe_source_name.is_none() && self.maybe_disable_source_event.is_none() {
+            self.maybe_disable_source_event = Some(DisablePollingSource {}.into());
+        }
+    }
+
     fn handle_add_push_source(&mut self, e: &AddPushSource) -> Result<(), ScanMetadataError> {
+        if !self.visited_push_sources.insert(e.source_name.clone()) {
+            return Ok(());
+        }
+
         if self.maybe_source_event.is_none() {
             if self.maybe_source_name.is_none()
                 || self.maybe_source_name == Some(e.source_name.as_str())
@@ -104,6 +142,19 @@ impl<'a> SourceEventVisitor<'a> {
 
         Ok(())
     }
+
+    fn handle_disable_push_source(&mut self, e: &DisablePushSource) {
+        if !self.visited_push_sources.insert(e.source_name.clone()) {
+            return;
+        }
+
+        if (self.maybe_source_name.is_none()
+            || self.maybe_source_name == Some(e.source_name.as_str()))
+            && self.maybe_disable_source_event.is_none()
+        {
+            self.maybe_disable_source_event = Some(e.clone().into());
+        }
+    }
 }
 
 impl<'a> MetadataChainVisitor for SourceEventVisitor<'a> {
@@ -119,18 +170,27 @@ impl<'a> MetadataChainVisitor for SourceEventVisitor<'a> {
                 self.handle_set_polling_source(e)?;
 
                 if self.maybe_source_name.is_none() {
-                    self.next_block_flags -= Flag::SET_POLLING_SOURCE;
+                    self.next_block_flags -=
+                        Flag::SET_POLLING_SOURCE | Flag::DISABLE_POLLING_SOURCE;
                 }
             }
+            MetadataEvent::DisablePollingSource(_) => {
+                self.handle_disable_polling_source();
+                self.next_block_flags -= Flag::DISABLE_POLLING_SOURCE;
+            }
             MetadataEvent::AddPushSource(e) => {
                 self.handle_add_push_source(e)?;
 
                 if self.maybe_source_name.is_some() && self.maybe_source_event.is_some() {
-                    self.next_block_flags -= Flag::ADD_PUSH_SOURCE;
+                    self.next_block_flags -= Flag::ADD_PUSH_SOURCE | Flag::DISABLE_PUSH_SOURCE;
                 }
             }
-            MetadataEvent::DisablePollingSource(_) | MetadataEvent::DisablePushSource(_) => {
-                unimplemented!("Disabling sources is not yet fully supported")
+            MetadataEvent::DisablePushSource(e) => {
+                self.handle_disable_push_source(e);
+
+                if self.maybe_source_name == Some(e.source_name.as_str()) {
+                    self.next_block_flags -= Flag::ADD_PUSH_SOURCE | Flag::DISABLE_PUSH_SOURCE;
+                }
             }
             MetadataEvent::Seed(_)
             | MetadataEvent::AddData(_)
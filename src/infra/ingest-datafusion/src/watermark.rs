@@ -0,0 +1,106 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use chrono::{DateTime, Duration, Utc};
+use opendatafabric as odf;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Defines how the watermark of a root dataset advances as new data is
+/// ingested.
+///
+/// See [`opendatafabric::WatermarkStrategy`] for details.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum WatermarkStrategy {
+    /// Advances the watermark to the maximum event time seen so far
+    #[default]
+    MaxEventTime,
+    /// Lags behind the maximum event time seen so far by the specified
+    /// duration, allowing events to arrive out of order within that bound
+    BoundedOutOfOrderness { lag: Duration },
+    /// Follows the system time of ingestion minus the specified delay,
+    /// regardless of the event times in the data.
+    ///
+    /// When an ingest produces no data the watermark is only advanced once it
+    /// has moved by at least `min_idle_advance`, as every advance results in
+    /// a new metadata block.
+    SystemTime {
+        delay: Duration,
+        min_idle_advance: Duration,
+    },
+    /// Watermark is never advanced automatically and can only be set
+    /// explicitly
+    Manual,
+}
+
+impl WatermarkStrategy {
+    pub const DEFAULT_MIN_IDLE_ADVANCE: Duration = Duration::minutes(1);
+
+    /// Computes the new watermark given the previous one, the maximum event
+    /// time of the new data (if any) and the system time of ingestion.
+    /// Watermark never decreases.
+    pub fn next_watermark(
+        &self,
+        prev_watermark: Option<DateTime<Utc>>,
+        event_time_max: Option<DateTime<Utc>>,
+        system_time: DateTime<Utc>,
+    ) -> Option<DateTime<Utc>> {
+        let new_watermark = match self {
+            Self::MaxEventTime => event_time_max,
+            Self::BoundedOutOfOrderness { lag } => event_time_max.map(|t| sub_duration(t, *lag)),
+            Self::SystemTime {
+                delay,
+                min_idle_advance,
+            } => {
+                let new_watermark = sub_duration(system_time, *delay);
+                match prev_watermark {
+                    Some(prev)
+                        if event_time_max.is_none() && new_watermark - prev < *min_idle_advance =>
+                    {
+                        None
+                    }
+                    _ => Some(new_watermark),
+                }
+            }
+            Self::Manual => None,
+        };
+
+        match (prev_watermark, new_watermark) {
+            (Some(prev), Some(new)) if new <= prev => Some(prev),
+            (prev, None) => prev,
+            (_, new) => new,
+        }
+    }
+}
+
+impl From<odf::WatermarkStrategy> for WatermarkStrategy {
+    fn from(value: odf::WatermarkStrategy) -> Self {
+        match value {
+            odf::WatermarkStrategy::MaxEventTime(_) => Self::MaxEventTime,
+            odf::WatermarkStrategy::BoundedOutOfOrderness(cfg) => Self::BoundedOutOfOrderness {
+                lag: seconds(cfg.lag_seconds),
+            },
+            odf::WatermarkStrategy::SystemTime(cfg) => Self::SystemTime {
+                delay: seconds(cfg.delay_seconds),
+                min_idle_advance: cfg
+                    .min_idle_advance_seconds
+                    .map_or(Self::DEFAULT_MIN_IDLE_ADVANCE, seconds),
+            },
+            odf::WatermarkStrategy::Manual(_) => Self::Manual,
+        }
+    }
+}
+
+fn seconds(value: u64) -> Duration {
+    Duration::seconds(i64::try_from(value).unwrap_or(i64::MAX))
+}
+
+fn sub_duration(t: DateTime<Utc>, d: Duration) -> DateTime<Utc> {
+    t.checked_sub_signed(d).unwrap_or(DateTime::<Utc>::MIN_UTC)
+}
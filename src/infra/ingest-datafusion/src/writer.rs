@@ -14,8 +14,7 @@ use std::sync::Arc;
 
 use chrono::{DateTime, TimeZone, Utc};
 use datafusion::arrow::array::Array;
-use datafusion::arrow::datatypes::{DataType, Field, SchemaRef, TimeUnit};
-use datafusion::common::DFSchema;
+use datafusion::arrow::datatypes::{DataType, Field, Schema, SchemaRef, TimeUnit};
 use datafusion::config::{ParquetColumnOptions, ParquetOptions, TableParquetOptions};
 use datafusion::dataframe::DataFrameWriteOptions;
 use datafusion::functions_aggregate::min_max::{max, min};
@@ -23,10 +22,12 @@ use datafusion::prelude::*;
 use internal_error::*;
 use kamu_core::ingest::*;
 use kamu_core::*;
+use kamu_data_utils::schema::cmp::evolve_schema;
 use odf::{AsTypedBlock, DatasetVocabulary, MetadataEvent};
 use opendatafabric as odf;
 
 use crate::visitor::SourceEventVisitor;
+use crate::watermark::WatermarkStrategy;
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
@@ -35,6 +36,7 @@ use crate::visitor::SourceEventVisitor;
 pub struct DataWriterDataFusion {
     dataset: Arc<dyn Dataset>,
     merge_strategy: Arc<dyn MergeStrategy>,
+    watermark_strategy: WatermarkStrategy,
     block_ref: BlockRef,
 
     // Mutable
@@ -71,6 +73,7 @@ impl DataWriterDataFusion {
         ctx: SessionContext,
         dataset: Arc<dyn Dataset>,
         merge_strategy: Arc<dyn MergeStrategy>,
+        watermark_strategy: WatermarkStrategy,
         block_ref: BlockRef,
         metadata_state: DataWriterMetadataState,
     ) -> Self {
@@ -78,6 +81,7 @@ impl DataWriterDataFusion {
             ctx,
             dataset,
             merge_strategy,
+            watermark_strategy,
             block_ref,
             meta: metadata_state,
         }
@@ -187,17 +191,14 @@ impl DataWriterDataFusion {
     fn ensure_event_time_column(
         &self,
         df: DataFrame,
-        prev_schema: Option<&DFSchema>,
+        prev_schema: Option<&Schema>,
     ) -> Result<DataFrame, InternalError> {
         if !df
             .schema()
             .has_column_with_unqualified_name(&self.meta.vocab.event_time_column)
         {
             let data_type = prev_schema
-                .and_then(|s| {
-                    s.field_with_unqualified_name(&self.meta.vocab.event_time_column)
-                        .ok()
-                })
+                .and_then(|s| s.field_with_name(&self.meta.vocab.event_time_column).ok())
                 .map_or(
                     DataType::Timestamp(TimeUnit::Millisecond, Some(Arc::from("UTC"))),
                     |f| f.data_type().clone(),
@@ -217,10 +218,96 @@ impl DataWriterDataFusion {
         }
     }
 
+    /// Reconciles the new data with the schema of the dataset according to the
+    /// schema evolution rules. Returns the new data cast to the resulting
+    /// schema, along with the schema that the previously written data should be
+    /// read with.
+    fn evolve_data_schema(
+        &self,
+        df: DataFrame,
+    ) -> Result<(DataFrame, Option<SchemaRef>), StageDataError> {
+        let Some(prev_schema) = &self.meta.schema else {
+            return Ok((df, None));
+        };
+
+        let is_system_column = |f: &Arc<Field>| {
+            [
+                &self.meta.vocab.offset_column,
+                &self.meta.vocab.operation_type_column,
+                &self.meta.vocab.system_time_column,
+            ]
+            .contains(&f.name())
+        };
+
+        let prev_data_schema = Schema::new(
+            prev_schema
+                .fields()
+                .iter()
+                .filter(|f| !is_system_column(f))
+                .cloned()
+                .collect::<Vec<_>>(),
+        );
+
+        let data_schema = match evolve_schema(&prev_data_schema, df.schema().as_arrow()) {
+            Ok(Some(evolved)) => {
+                tracing::info!(schema = ?evolved, "Evolving dataset schema");
+                evolved
+            }
+            Ok(None) => prev_data_schema,
+            Err(diff) => {
+                let err = IncompatibleSchemaError::new(
+                    format!(
+                        "New data contains changes that are not compatible with the schema \
+                         defined by SetDataSchema event:\n{diff}"
+                    ),
+                    prev_schema.clone(),
+                    SchemaRef::new(df.schema().into()),
+                );
+                return Err(err.into());
+            }
+        };
+
+        // Cast the new data to the types of the resulting schema
+        let select: Vec<Expr> = data_schema
+            .fields()
+            .iter()
+            .map(|f| {
+                let data_type = df
+                    .schema()
+                    .field_with_unqualified_name(f.name())
+                    .map(|nf| nf.data_type().clone())
+                    .int_err()?;
+
+                let column = col(Column::from_name(f.name()));
+                if data_type == *f.data_type() {
+                    Ok(column)
+                } else {
+                    Ok(cast(column, f.data_type().clone()).alias(f.name()))
+                }
+            })
+            .collect::<Result<_, InternalError>>()?;
+
+        let df = df.select(select).int_err()?;
+
+        let read_schema = Schema::new_with_metadata(
+            prev_schema
+                .fields()
+                .iter()
+                .filter(|f| is_system_column(f))
+                .chain(data_schema.fields().iter())
+                .cloned()
+                .collect::<Vec<_>>(),
+            prev_schema.metadata().clone(),
+        );
+
+        Ok((df, Some(SchemaRef::new(read_schema))))
+    }
+
     // TODO: PERF: This will not scale well as number of blocks grows
     async fn get_all_previous_data(
         &self,
         prev_data_slices: &[odf::Multihash],
+        schema: Option<&Schema>,
     ) -> Result<Option<DataFrame>, InternalError> {
         if prev_data_slices.is_empty() {
             return Ok(None);
@@ -240,8 +327,9 @@ impl DataWriterDataFusion {
             .read_parquet(
                 prev_data_paths,
                 ParquetReadOptions {
-                    // TODO: Specify schema
-                    schema: None,
+                    // Slices written under older versions of the schema are adapted to the
+                    // latest one
+                    schema,
                     file_extension: "",
                     // TODO: PERF: Possibly speed up by specifying `offset`
                     file_sort_order: Vec::new(),
@@ -256,6 +344,135 @@ impl DataWriterDataFusion {
         Ok(Some(df))
     }
 
+    /// Retracts the previously written records that match the filter, unless
+    /// they were already retracted or corrected. The result is aligned with the
+    /// columns of the merged data.
+    fn retractions(
+        &self,
+        prev: DataFrame,
+        filter: &str,
+        merged: &DataFrame,
+    ) -> Result<DataFrame, StageDataError> {
+        use datafusion::functions_aggregate::sum::sum_udaf;
+        use datafusion::logical_expr::expr::WindowFunction;
+        use datafusion::logical_expr::{
+            BuiltInWindowFunction,
+            WindowFrame,
+            WindowFunctionDefinition,
+        };
+
+        type Op = odf::OperationType;
+
+        let predicate = self
+            .ctx
+            .parse_sql_expr(filter, prev.schema())
+            .map_err(|e| {
+                BadInputSchemaError::new(
+                    format!("Retraction filter is not applicable to the dataset: {e}"),
+                    SchemaRef::new(prev.schema().into()),
+                )
+            })?;
+
+        let op_col = || col(Column::from_name(&self.meta.vocab.operation_type_column));
+        let offset_col = || col(Column::from_name(&self.meta.vocab.offset_column));
+
+        let data_columns: Vec<_> = prev
+            .schema()
+            .fields()
+            .iter()
+            .filter(|f| {
+                f.name() != &self.meta.vocab.offset_column
+                    && f.name() != &self.meta.vocab.operation_type_column
+                    && f.name() != &self.meta.vocab.system_time_column
+            })
+            .map(|f| col(Column::from_name(f.name())))
+            .collect();
+
+        let is_added = || {
+            op_col().in_list(
+                vec![lit(Op::Append as i32), lit(Op::CorrectTo as i32)],
+                false,
+            )
+        };
+        let is_removed = || {
+            op_col().in_list(
+                vec![lit(Op::Retract as i32), lit(Op::CorrectFrom as i32)],
+                false,
+            )
+        };
+
+        // Identical records can be added more than once, so each removal cancels only
+        // one of them. Records are ranked among the identical additions by offset and
+        // the ones with rank above the number of identical removals are still live.
+        let rank_col = "__rank";
+        let removed_col = "__removed";
+
+        let live = prev
+            .filter(predicate)
+            .int_err()?
+            .window(vec![
+                Expr::WindowFunction(WindowFunction {
+                    fun: WindowFunctionDefinition::BuiltInWindowFunction(
+                        BuiltInWindowFunction::RowNumber,
+                    ),
+                    args: Vec::new(),
+                    partition_by: data_columns
+                        .iter()
+                        .cloned()
+                        .chain(std::iter::once(is_added()))
+                        .collect(),
+                    order_by: vec![offset_col().sort(true, false)],
+                    window_frame: WindowFrame::new(Some(false)),
+                    null_treatment: None,
+                })
+                .alias(rank_col),
+                Expr::WindowFunction(WindowFunction {
+                    fun: WindowFunctionDefinition::AggregateUDF(sum_udaf()),
+                    args: vec![when(is_removed(), lit(1_i64))
+                        .otherwise(lit(0_i64))
+                        .int_err()?],
+                    partition_by: data_columns.clone(),
+                    order_by: Vec::new(),
+                    window_frame: WindowFrame::new(None),
+                    null_treatment: None,
+                })
+                .alias(removed_col),
+            ])
+            .int_err()?
+            .filter(
+                is_added().and(
+                    cast(col(Column::from_name(rank_col)), DataType::Int64)
+                        .gt(col(Column::from_name(removed_col))),
+                ),
+            )
+            .int_err()?
+            .select(data_columns)
+            .int_err()?;
+
+        let select: Vec<_> = merged
+            .schema()
+            .fields()
+            .iter()
+            .map(|f| {
+                if f.name() == &self.meta.vocab.operation_type_column {
+                    // TODO: Cast to `u8` after Spark is updated
+                    // See: https://github.com/kamu-data/kamu-cli/issues/445
+                    lit(Op::Retract as i32).alias(f.name())
+                } else if live.schema().has_column_with_unqualified_name(f.name()) {
+                    cast(col(Column::from_name(f.name())), f.data_type().clone()).alias(f.name())
+                } else {
+                    cast(
+                        lit(datafusion::scalar::ScalarValue::Null),
+                        f.data_type().clone(),
+                    )
+                    .alias(f.name())
+                }
+            })
+            .collect();
+
+        live.select(select).int_err().map_err(Into::into)
+    }
+
     fn with_system_columns(
         &self,
         df: DataFrame,
@@ -387,6 +604,26 @@ impl DataWriterDataFusion {
         }
     }
 
+    /// Checks that the output schema is either equivalent to the schema of the
+    /// dataset or differs from it only by compatible changes. Returns the
+    /// evolved schema that should be committed in the latter case.
+    pub fn evolve_output_schema(
+        prev_schema: &SchemaRef,
+        new_schema: &SchemaRef,
+    ) -> Result<Option<SchemaRef>, IncompatibleSchemaError> {
+        match evolve_schema(prev_schema, new_schema) {
+            Ok(evolved) => Ok(evolved.map(SchemaRef::new)),
+            Err(diff) => Err(IncompatibleSchemaError::new(
+                format!(
+                    "Schema of the new slice contains changes that are not compatible with the \
+                     schema defined by SetDataSchema event:\n{diff}"
+                ),
+                prev_schema.clone(),
+                new_schema.clone(),
+            )),
+        }
+    }
+
     fn is_schema_equivalent(lhs: &SchemaRef, rhs: &SchemaRef) -> bool {
         lhs.fields().len() == rhs.fields().len()
             && lhs
@@ -478,12 +715,12 @@ impl DataWriterDataFusion {
         }
     }
 
-    // Read output file back (metadata-only query) to get offsets and watermark
-    async fn compute_offset_and_watermark(
+    // Read output file back (metadata-only query) to get offsets and max event
+    // time
+    async fn compute_offset_interval_and_event_time_max(
         &self,
         path: &Path,
-        prev_watermark: Option<DateTime<Utc>>,
-    ) -> Result<(odf::OffsetInterval, Option<DateTime<Utc>>), InternalError> {
+    ) -> Result<(odf::OffsetInterval, DateTime<Utc>), InternalError> {
         use datafusion::arrow::array::*;
 
         let df = self
@@ -512,7 +749,6 @@ impl DataWriterDataFusion {
                 vec![
                     min(col(Column::from_name(&self.meta.vocab.offset_column))),
                     max(col(Column::from_name(&self.meta.vocab.offset_column))),
-                    // TODO: Add support for more watermark strategies
                     max(col(Column::from_name(&self.meta.vocab.event_time_column))),
                 ],
             )
@@ -565,14 +801,7 @@ impl DataWriterDataFusion {
             .int_err());
         };
 
-        // Ensure watermark is monotonically non-decreasing
-        let output_watermark = match prev_watermark {
-            None => Some(event_time_max),
-            Some(prev) if prev < event_time_max => Some(event_time_max),
-            prev => prev,
-        };
-
-        Ok((offset_interval, output_watermark))
+        Ok((offset_interval, event_time_max))
     }
 }
 
@@ -635,14 +864,29 @@ impl DataWriter for DataWriterDataFusion {
             // Normalize timestamps
             let df = self.normalize_raw_result(new_data)?;
 
+            // Populate event time with nulls if missing, using matching type to prev data
+            let df = self.ensure_event_time_column(df, self.meta.schema.as_deref())?;
+
+            // Schema evolution
+            let (df, prev_read_schema) = self.evolve_data_schema(df)?;
+
             // Merge step
             // TODO: PERF: We could likely benefit from checkpointing here
-            let prev = self.get_all_previous_data(&self.meta.data_slices).await?;
+            let prev = self
+                .get_all_previous_data(&self.meta.data_slices, prev_read_schema.as_deref())
+                .await?;
 
-            // Populate event time with nulls if missing, using matching type to prev data
-            let df = self.ensure_event_time_column(df, prev.as_ref().map(DataFrame::schema))?;
+            let prev_for_retractions = opts.retraction_filter.as_ref().and(prev.clone());
 
-            let df = self.merge_strategy.merge(prev, df)?;
+            let df = self.merge_strategy.merge(prev, df).await?;
+
+            let df = match (&opts.retraction_filter, prev_for_retractions) {
+                (Some(filter), Some(prev)) => {
+                    let retractions = self.retractions(prev, filter, &df)?;
+                    df.union(retractions).int_err()?
+                }
+                _ => df,
+            };
 
             tracing::debug!(
                 schema = ?df.schema(),
@@ -658,13 +902,14 @@ impl DataWriter for DataWriterDataFusion {
                 self.meta.prev_offset.map_or(0, |e| e + 1),
             )?;
 
-            // Validate schema matches the declared one
-            let new_schema = SchemaRef::new(df.schema().into());
-            tracing::info!(schema = ?new_schema, "Final output schema");
+            // Validate schema matches the declared one or is its compatible evolution
+            let output_schema = SchemaRef::new(df.schema().into());
+            tracing::info!(schema = ?output_schema, "Final output schema");
 
-            if let Some(prev_schema) = &self.meta.schema {
-                Self::validate_output_schema_equivalence(prev_schema, &new_schema)?;
-            }
+            let new_schema = match &self.meta.schema {
+                None => Some(output_schema),
+                Some(prev_schema) => Self::evolve_output_schema(prev_schema, &output_schema)?,
+            };
 
             // Write output
             let data_file = self.write_output(opts.data_staging_path, df).await?;
@@ -673,28 +918,37 @@ impl DataWriter for DataWriterDataFusion {
             let prev_offset = self.meta.prev_offset;
             let prev_checkpoint = self.meta.prev_checkpoint.clone();
             let new_source_state = opts.new_source_state;
-            let prev_watermark = self.meta.prev_watermark;
 
             if data_file.is_none() {
-                // Empty result - carry watermark and propagate source state
+                // Empty result - carry watermark (unless strategy advances it regardless of
+                // data) and propagate source state
+                let new_watermark = self.watermark_strategy.next_watermark(
+                    self.meta.prev_watermark,
+                    None,
+                    opts.system_time,
+                );
                 (
                     AddDataParams {
                         prev_checkpoint,
                         prev_offset,
                         new_offset_interval: None,
-                        new_watermark: opts.new_watermark.or(prev_watermark),
+                        new_watermark: opts.new_watermark.or(new_watermark),
                         new_source_state,
                     },
-                    Some(new_schema),
+                    new_schema,
                     None,
                 )
             } else {
-                let (new_offset_interval, new_watermark_from_data) = self
-                    .compute_offset_and_watermark(
+                let (new_offset_interval, event_time_max) = self
+                    .compute_offset_interval_and_event_time_max(
                         data_file.as_ref().unwrap().as_path(),
-                        prev_watermark,
                     )
                     .await?;
+                let new_watermark_from_data = self.watermark_strategy.next_watermark(
+                    self.meta.prev_watermark,
+                    Some(event_time_max),
+                    opts.system_time,
+                );
 
                 (
                     AddDataParams {
@@ -704,7 +958,7 @@ impl DataWriter for DataWriterDataFusion {
                         new_watermark: opts.new_watermark.or(new_watermark_from_data),
                         new_source_state,
                     },
-                    Some(new_schema),
+                    new_schema,
                     data_file,
                 )
             }
@@ -721,13 +975,6 @@ impl DataWriter for DataWriterDataFusion {
             (add_data, None, None)
         };
 
-        // Do we need to commit `SetDataSchema` event?
-        let new_schema = if self.meta.schema.is_none() {
-            new_schema
-        } else {
-            None
-        };
-
         // Do we have anything to commit in `AddData` event?
         let add_data = if add_data.new_offset_interval.is_some()
             || add_data.new_watermark != self.meta.prev_watermark
@@ -993,11 +1240,13 @@ impl DataWriterDataFusionBuilder {
 
         let merge_strategy =
             Self::merge_strategy_for(metadata_state.merge_strategy.clone(), &metadata_state.vocab);
+        let watermark_strategy = Self::watermark_strategy_for(metadata_state.source_event.as_ref());
 
         DataWriterDataFusion::new(
             self.ctx,
             self.dataset,
             merge_strategy,
+            watermark_strategy,
             self.block_ref,
             metadata_state,
         )
@@ -1019,6 +1268,16 @@ impl DataWriterDataFusionBuilder {
             }
         }
     }
+
+    fn watermark_strategy_for(source_event: Option<&odf::MetadataEvent>) -> WatermarkStrategy {
+        let conf = match source_event {
+            Some(odf::MetadataEvent::SetPollingSource(e)) => e.watermark.clone(),
+            Some(odf::MetadataEvent::AddPushSource(e)) => e.watermark.clone(),
+            _ => None,
+        };
+
+        conf.map(Into::into).unwrap_or_default()
+    }
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
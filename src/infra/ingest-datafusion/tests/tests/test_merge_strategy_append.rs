@@ -123,7 +123,7 @@ where
         }
     };
     let new = make_input(&ctx, input);
-    let actual = strat.merge(prev, new).unwrap();
+    let actual = strat.merge(prev, new).await.unwrap();
     let expected = make_output(&ctx, expected);
 
     // Sort events according to the strategy
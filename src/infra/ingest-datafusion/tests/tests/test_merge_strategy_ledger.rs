@@ -107,6 +107,52 @@ fn make_output_empty(ctx: &SessionContext) -> DataFrame {
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
+fn make_ledger<I, S>(ctx: &SessionContext, rows: I) -> DataFrame
+where
+    I: IntoIterator<Item = (Op, i32, S, i64)>,
+    S: Into<String>,
+{
+    let schema = Arc::new(Schema::new(vec![
+        // TODO: Replace with UInt64 and UInt8 after Spark is updated
+        // See: https://github.com/kamu-data/kamu-cli/issues/445
+        Field::new("offset", DataType::Int64, false),
+        Field::new("op", DataType::Int32, false),
+        Field::new("year", DataType::Int32, false),
+        Field::new("city", DataType::Utf8, false),
+        Field::new("population", DataType::Int64, false),
+    ]));
+
+    let mut offset = Vec::new();
+    let mut op = Vec::new();
+    let mut year = Vec::new();
+    let mut city = Vec::new();
+    let mut population = Vec::new();
+
+    for (i, (o, y, c, p)) in rows.into_iter().enumerate() {
+        offset.push(i64::try_from(i).unwrap());
+        op.push(o as i32);
+        year.push(y);
+        city.push(c.into());
+        population.push(p);
+    }
+
+    let batch = RecordBatch::try_new(
+        schema,
+        vec![
+            Arc::new(array::Int64Array::from(offset)),
+            Arc::new(array::Int32Array::from(op)),
+            Arc::new(array::Int32Array::from(year)),
+            Arc::new(array::StringArray::from(city)),
+            Arc::new(array::Int64Array::from(population)),
+        ],
+    )
+    .unwrap();
+
+    ctx.read_batch(batch).unwrap()
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
 async fn test_ledger_merge<L, I, E, S>(ledger: L, input: I, expected: E)
 where
     L: IntoIterator<Item = (Op, i32, S, i64)>,
@@ -122,6 +168,9 @@ where
         },
         odf::MergeStrategyLedger {
             primary_key: vec!["year".to_string(), "city".to_string()],
+            mode: None,
+            dedupe_window_records: None,
+            dedupe_window_seconds: None,
         },
     );
 
@@ -135,7 +184,7 @@ where
     };
     let new = make_input(&ctx, input);
     let expected = make_output(&ctx, expected);
-    let actual = strat.merge(prev, new).unwrap();
+    let actual = strat.merge(prev, new).await.unwrap();
 
     // Sort events according to the strategy
     let actual = actual.sort(strat.sort_order()).unwrap();
@@ -245,9 +294,13 @@ async fn test_ledger_merge_respects_pk() {
         odf::DatasetVocabulary::default(),
         odf::MergeStrategyLedger {
             primary_key: vec!["year".to_string()],
+            mode: None,
+            dedupe_window_records: None,
+            dedupe_window_seconds: None,
         },
     )
     .merge(Some(prev), new)
+    .await
     .unwrap();
     let expected = make_output_empty(&ctx);
     assert_dfs_equivalent(expected, actual, false, true, true).await;
@@ -258,9 +311,13 @@ async fn test_ledger_merge_respects_pk() {
         odf::DatasetVocabulary::default(),
         odf::MergeStrategyLedger {
             primary_key: vec!["year".to_string(), "city".to_string()],
+            mode: None,
+            dedupe_window_records: None,
+            dedupe_window_seconds: None,
         },
     )
     .merge(Some(prev), new)
+    .await
     .unwrap();
     let expected = make_output_empty(&ctx);
     assert_dfs_equivalent(expected, actual, false, true, true).await;
@@ -275,9 +332,13 @@ async fn test_ledger_merge_respects_pk() {
                 "city".to_string(),
                 "population".to_string(),
             ],
+            mode: None,
+            dedupe_window_records: None,
+            dedupe_window_seconds: None,
         },
     )
     .merge(Some(prev), new)
+    .await
     .unwrap();
     let expected = make_output(&ctx, [(Op::Append, 2020, "seattle", 3)]);
     assert_dfs_equivalent(expected, actual, false, true, true).await;
@@ -288,9 +349,13 @@ async fn test_ledger_merge_respects_pk() {
         odf::DatasetVocabulary::default(),
         odf::MergeStrategyLedger {
             primary_key: vec!["year".to_string(), "city".to_string()],
+            mode: None,
+            dedupe_window_records: None,
+            dedupe_window_seconds: None,
         },
     )
     .merge(Some(prev), new)
+    .await
     .unwrap();
     let expected = make_output(&ctx, [(Op::Append, 2021, "seattle", 3)]);
     assert_dfs_equivalent(expected, actual, false, true, true).await;
@@ -306,6 +371,9 @@ async fn test_ledger_merge_invalid_pk() {
         odf::DatasetVocabulary::default(),
         odf::MergeStrategyLedger {
             primary_key: vec!["year".to_string(), "city".to_string(), "foo".to_string()],
+            mode: None,
+            dedupe_window_records: None,
+            dedupe_window_seconds: None,
         },
     );
 
@@ -318,8 +386,231 @@ async fn test_ledger_merge_invalid_pk() {
         ],
     );
 
-    let res = strat.merge(None, new);
+    let res = strat.merge(None, new).await;
     assert_matches!(res, Err(MergeError::Internal(_)));
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+async fn test_ledger_merge_with_cfg<L, I, E, S>(
+    cfg: odf::MergeStrategyLedger,
+    ledger: L,
+    input: I,
+    expected: E,
+) where
+    L: IntoIterator<Item = (Op, i32, S, i64)>,
+    I: IntoIterator<Item = (i32, S, i64)>,
+    E: IntoIterator<Item = (Op, i32, S, i64)>,
+    S: Into<String>,
+{
+    let ctx = SessionContext::new();
+    let strat = ledger_strategy(cfg);
+
+    let prev = make_ledger(&ctx, ledger);
+    let new = make_input(&ctx, input);
+    let expected = make_output(&ctx, expected);
+    let actual = strat.merge(Some(prev), new).await.unwrap();
+
+    assert_dfs_equivalent(expected, actual, true, true, true).await;
+}
+
+fn ledger_strategy(cfg: odf::MergeStrategyLedger) -> MergeStrategyLedger {
+    MergeStrategyLedger::new(
+        odf::DatasetVocabulary {
+            event_time_column: "year".to_string(),
+            ..Default::default()
+        },
+        cfg,
+    )
+}
+
+fn ledger_cfg(mode: Option<odf::LedgerMergeMode>) -> odf::MergeStrategyLedger {
+    odf::MergeStrategyLedger {
+        primary_key: vec!["year".to_string(), "city".to_string()],
+        mode,
+        dedupe_window_records: None,
+        dedupe_window_seconds: None,
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_group::group(engine, ingest, datafusion)]
+#[test_log::test(tokio::test)]
+async fn test_ledger_merge_default_mode_keeps_input_duplicates() {
+    test_ledger_merge_with_cfg(
+        ledger_cfg(None),
+        [(Op::Append, 2020, "vancouver", 1)],
+        [
+            (2020, "vancouver", 1),
+            (2020, "seattle", 2),
+            (2020, "seattle", 2),
+        ],
+        [
+            (Op::Append, 2020, "seattle", 2),
+            (Op::Append, 2020, "seattle", 2),
+        ],
+    )
+    .await;
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_group::group(engine, ingest, datafusion)]
+#[test_log::test(tokio::test)]
+async fn test_ledger_merge_dedupes_input() {
+    test_ledger_merge_with_cfg(
+        ledger_cfg(Some(odf::LedgerMergeMode::Deduplicate)),
+        [(Op::Append, 2020, "vancouver", 1)],
+        [
+            (2020, "vancouver", 1),
+            (2020, "seattle", 2),
+            (2020, "seattle", 2),
+            (2020, "kyiv", 3),
+        ],
+        [
+            (Op::Append, 2020, "kyiv", 3),
+            (Op::Append, 2020, "seattle", 2),
+        ],
+    )
+    .await;
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_group::group(engine, ingest, datafusion)]
+#[test_log::test(tokio::test)]
+async fn test_ledger_merge_dedupe_input_is_deterministic() {
+    // Duplicates that differ in non-key columns are resolved the same way
+    // regardless of the order in the input
+    for input in [
+        [(2020, "seattle", 5), (2020, "seattle", 2)],
+        [(2020, "seattle", 2), (2020, "seattle", 5)],
+    ] {
+        test_ledger_merge_with_cfg(
+            ledger_cfg(Some(odf::LedgerMergeMode::Deduplicate)),
+            [(Op::Append, 2020, "vancouver", 1)],
+            input,
+            [(Op::Append, 2020, "seattle", 2)],
+        )
+        .await;
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_group::group(engine, ingest, datafusion)]
+#[test_log::test(tokio::test)]
+async fn test_ledger_merge_dedupe_window_records() {
+    // Only the last two records are compared against, so the oldest event is
+    // considered new
+    test_ledger_merge_with_cfg(
+        odf::MergeStrategyLedger {
+            dedupe_window_records: Some(2),
+            ..ledger_cfg(Some(odf::LedgerMergeMode::Deduplicate))
+        },
+        [
+            (Op::Append, 2020, "vancouver", 1),
+            (Op::Append, 2020, "seattle", 2),
+            (Op::Append, 2020, "kyiv", 3),
+        ],
+        [
+            (2020, "vancouver", 1),
+            (2020, "seattle", 2),
+            (2021, "kyiv", 4),
+        ],
+        [
+            (Op::Append, 2020, "vancouver", 1),
+            (Op::Append, 2021, "kyiv", 4),
+        ],
+    )
+    .await;
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_group::group(engine, ingest, datafusion)]
+#[test_log::test(tokio::test)]
+async fn test_ledger_merge_new_events_only() {
+    test_ledger_merge_with_cfg(
+        ledger_cfg(Some(odf::LedgerMergeMode::NewEventsOnly)),
+        [(Op::Append, 2020, "vancouver", 1)],
+        [(2020, "vancouver", 1), (2020, "seattle", 2)],
+        [
+            (Op::Append, 2020, "seattle", 2),
+            (Op::Append, 2020, "vancouver", 1),
+        ],
+    )
+    .await;
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_group::group(engine, ingest, datafusion)]
+#[test_log::test(tokio::test)]
+async fn test_ledger_merge_superset_retracts_missing() {
+    test_ledger_merge_with_cfg(
+        ledger_cfg(Some(odf::LedgerMergeMode::SupersetRetractMissing)),
+        [
+            (Op::Append, 2020, "vancouver", 1),
+            (Op::Append, 2020, "seattle", 2),
+            (Op::Append, 2020, "kyiv", 3),
+            (Op::Retract, 2020, "kyiv", 3),
+        ],
+        [
+            (2020, "vancouver", 1),
+            (2020, "kyiv", 3),
+            (2021, "odessa", 4),
+        ],
+        [
+            (Op::Append, 2020, "kyiv", 3),
+            (Op::Retract, 2020, "seattle", 2),
+            (Op::Append, 2021, "odessa", 4),
+        ],
+    )
+    .await;
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_group::group(engine, ingest, datafusion)]
+#[test_log::test(tokio::test)]
+async fn test_ledger_merge_superset_strict() {
+    test_ledger_merge_with_cfg(
+        ledger_cfg(Some(odf::LedgerMergeMode::SupersetStrict)),
+        [
+            (Op::Append, 2020, "vancouver", 1),
+            (Op::Append, 2020, "seattle", 2),
+        ],
+        [
+            (2020, "vancouver", 1),
+            (2020, "seattle", 2),
+            (2021, "odessa", 4),
+        ],
+        [(Op::Append, 2021, "odessa", 4)],
+    )
+    .await;
+
+    let ctx = SessionContext::new();
+    let res = ledger_strategy(ledger_cfg(Some(odf::LedgerMergeMode::SupersetStrict)))
+        .merge(
+            Some(make_ledger(
+                &ctx,
+                [
+                    (Op::Append, 2020, "vancouver", 1),
+                    (Op::Append, 2020, "seattle", 2),
+                ],
+            )),
+            make_input(&ctx, [(2020, "vancouver", 1), (2021, "odessa", 4)]),
+        )
+        .await;
+
+    assert_matches!(
+        res,
+        Err(MergeError::InputNotSuperset(InputNotSupersetError {
+            num_missing: 1
+        }))
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -299,7 +299,7 @@ where
 
     let new = make_input(&ctx, input);
     let expected = make_output(&ctx, expected);
-    let actual = strat.merge(prev, new).unwrap();
+    let actual = strat.merge(prev, new).await.unwrap();
 
     // Sort events according to the strategy
     let actual = actual.sort(strat.sort_order()).unwrap();
@@ -393,7 +393,7 @@ async fn test_snapshot_merge_invalid_pk() {
 
     let new = make_input(&ctx, [("vancouver", 1), ("seattle", 2)]);
 
-    let res = strat.merge(None, new);
+    let res = strat.merge(None, new).await;
     assert_matches!(res, Err(MergeError::Internal(_)));
 }
 
@@ -445,7 +445,7 @@ async fn test_snapshot_merge_input_carries_event_time() {
         ],
     );
 
-    let actual = strat.merge(Some(prev), new).unwrap();
+    let actual = strat.merge(Some(prev), new).await.unwrap();
 
     // Sort events according to the strategy
     let actual = actual.sort(strat.sort_order()).unwrap();
@@ -0,0 +1,88 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::path::Path;
+
+use datafusion::prelude::SessionContext;
+use indoc::indoc;
+use kamu_ingest_datafusion::*;
+use opendatafabric::*;
+
+use super::test_reader_common;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+fn write_test_data(path: impl AsRef<Path>) {
+    use apache_avro::types::Record;
+    use apache_avro::{Schema, Writer};
+
+    let schema = Schema::parse_str(indoc!(
+        r#"
+        {
+            "type": "record",
+            "name": "city",
+            "fields": [
+                {"name": "city", "type": "string"},
+                {"name": "population", "type": "long"}
+            ]
+        }
+        "#
+    ))
+    .unwrap();
+
+    let mut writer = Writer::new(&schema, std::fs::File::create(path).unwrap());
+
+    for (city, population) in [
+        ("vancouver", 675_000_i64),
+        ("seattle", 733_000),
+        ("kyiv", 2_884_000),
+    ] {
+        let mut record = Record::new(writer.schema()).unwrap();
+        record.put("city", city);
+        record.put("population", population);
+        writer.append(record).unwrap();
+    }
+
+    writer.into_inner().unwrap();
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_group::group(engine, ingest, datafusion)]
+#[test_log::test(tokio::test)]
+async fn test_read_avro_embedded_schema() {
+    test_reader_common::test_reader_success(
+        ReaderAvro::new(SessionContext::new(), ReadStepAvro { schema: None })
+            .await
+            .unwrap(),
+        |path| async {
+            write_test_data(path);
+        },
+        indoc!(
+            r#"
+            message arrow_schema {
+              REQUIRED BYTE_ARRAY city (STRING);
+              REQUIRED INT64 population;
+            }
+            "#
+        ),
+        indoc!(
+            r#"
+            +-----------+------------+
+            | city      | population |
+            +-----------+------------+
+            | vancouver | 675000     |
+            | seattle   | 733000     |
+            | kyiv      | 2884000    |
+            +-----------+------------+
+            "#
+        ),
+    )
+    .await;
+}
@@ -0,0 +1,165 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::assert_matches::assert_matches;
+use std::path::Path;
+
+use datafusion::prelude::SessionContext;
+use indoc::indoc;
+use kamu_ingest_datafusion::*;
+use opendatafabric::*;
+
+use super::test_reader_common;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+fn write_test_data(path: impl AsRef<Path>) {
+    let mut workbook = rust_xlsxwriter::Workbook::new();
+
+    // A sheet that should be ignored when the sheet name is specified
+    workbook
+        .add_worksheet()
+        .set_name("notes")
+        .unwrap()
+        .write(0, 0, "Not a table")
+        .unwrap();
+
+    let sheet = workbook.add_worksheet().set_name("cities").unwrap();
+    sheet.write(0, 0, "Population by city").unwrap();
+    sheet.write(2, 0, "city").unwrap();
+    sheet.write(2, 1, "population").unwrap();
+    for (row, (city, population)) in [
+        ("vancouver", 675_000),
+        ("seattle", 733_000),
+        ("kyiv", 2_884_000),
+    ]
+    .into_iter()
+    .enumerate()
+    {
+        let row = u32::try_from(row).unwrap() + 3;
+        sheet.write(row, 0, city).unwrap();
+        sheet.write(row, 1, population).unwrap();
+    }
+
+    workbook.save(path.as_ref()).unwrap();
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_group::group(engine, ingest, datafusion)]
+#[test_log::test(tokio::test)]
+async fn test_read_excel_with_header() {
+    test_reader_common::test_reader_success(
+        ReaderExcel::new(
+            SessionContext::new(),
+            ReadStepExcel {
+                sheet: Some("cities".to_string()),
+                header: Some(true),
+                skip_rows: Some(2),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap(),
+        |path| async {
+            write_test_data(path);
+        },
+        indoc!(
+            r#"
+            message arrow_schema {
+              OPTIONAL BYTE_ARRAY city (STRING);
+              OPTIONAL INT64 population;
+            }
+            "#
+        ),
+        indoc!(
+            r#"
+            +-----------+------------+
+            | city      | population |
+            +-----------+------------+
+            | vancouver | 675000     |
+            | seattle   | 733000     |
+            | kyiv      | 2884000    |
+            +-----------+------------+
+            "#
+        ),
+    )
+    .await;
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_group::group(engine, ingest, datafusion)]
+#[test_log::test(tokio::test)]
+async fn test_read_excel_with_schema() {
+    test_reader_common::test_reader_success(
+        ReaderExcel::new(
+            SessionContext::new(),
+            ReadStepExcel {
+                schema: Some(vec![
+                    "name string not null".to_string(),
+                    "population int not null".to_string(),
+                ]),
+                sheet: Some("cities".to_string()),
+                header: Some(false),
+                skip_rows: Some(3),
+            },
+        )
+        .await
+        .unwrap(),
+        |path| async {
+            write_test_data(path);
+        },
+        indoc!(
+            r#"
+            message arrow_schema {
+              REQUIRED BYTE_ARRAY name (STRING);
+              REQUIRED INT32 population;
+            }
+            "#
+        ),
+        indoc!(
+            r#"
+            +-----------+------------+
+            | name      | population |
+            +-----------+------------+
+            | vancouver | 675000     |
+            | seattle   | 733000     |
+            | kyiv      | 2884000    |
+            +-----------+------------+
+            "#
+        ),
+    )
+    .await;
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_group::group(engine, ingest, datafusion)]
+#[test_log::test(tokio::test)]
+async fn test_read_excel_sheet_not_found() {
+    test_reader_common::test_reader(
+        ReaderExcel::new(
+            SessionContext::new(),
+            ReadStepExcel {
+                sheet: Some("missing".to_string()),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap(),
+        |path| async {
+            write_test_data(path);
+        },
+        |res| async {
+            assert_matches!(res, Err(ReadError::BadInput(_)));
+        },
+    )
+    .await;
+}
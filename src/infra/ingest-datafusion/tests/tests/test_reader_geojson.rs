@@ -7,6 +7,8 @@
 // the Business Source License, use of this software will be governed
 // by the Apache License, Version 2.0.
 
+use std::assert_matches::assert_matches;
+
 use datafusion::prelude::SessionContext;
 use indoc::indoc;
 use kamu_ingest_datafusion::*;
@@ -19,8 +21,6 @@ use super::test_reader_common;
 #[test_group::group(engine, ingest, datafusion)]
 #[test_log::test(tokio::test)]
 async fn test_read_geojson_with_schema() {
-    let temp_dir: tempfile::TempDir = tempfile::tempdir().unwrap();
-
     test_reader_common::test_reader_success_textual(
         ReaderGeoJson::new(
             SessionContext::new(),
@@ -32,7 +32,6 @@ async fn test_read_geojson_with_schema() {
                     "geometry string not null".to_string(),
                 ]),
             },
-            temp_dir.path().join("reader-tmp")
         )
         .await
         .unwrap(),
@@ -73,15 +72,12 @@ async fn test_read_geojson_with_schema() {
 #[test_group::group(engine, ingest, datafusion)]
 #[test_log::test(tokio::test)]
 async fn test_read_geojson_infer_schema() {
-    let temp_dir: tempfile::TempDir = tempfile::tempdir().unwrap();
-
     test_reader_common::test_reader_success_textual(
         ReaderGeoJson::new(
             SessionContext::new(),
             ReadStepGeoJson {
                 schema: None,
             },
-            temp_dir.path().join("reader-tmp"),
             )
             .await
             .unwrap(),
@@ -116,3 +112,59 @@ async fn test_read_geojson_infer_schema() {
     )
     .await;
 }
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_group::group(engine, ingest, datafusion)]
+#[test_log::test(tokio::test)]
+async fn test_read_geojson_type_after_features() {
+    test_reader_common::test_reader_success_textual(
+        ReaderGeoJson::new(SessionContext::new(), ReadStepGeoJson { schema: None })
+            .await
+            .unwrap(),
+        indoc!(
+            r#"
+            {"features":[
+                {"type": "Feature", "properties": {"id": 0}, "geometry": {"type": "Point", "coordinates": [0.0, 0.0]}},
+                {"type": "Feature", "properties": {"id": 1}, "geometry": {"type": "Point", "coordinates": [1.0, 1.0]}}
+            ],"type":"FeatureCollection"}
+            "#
+        ),
+        indoc!(
+            r#"
+            message arrow_schema {
+              OPTIONAL BYTE_ARRAY geometry (STRING);
+              OPTIONAL INT64 id;
+            }
+            "#
+        ),
+        indoc!(
+            r#"
+            +------------------------------------------+----+
+            | geometry                                 | id |
+            +------------------------------------------+----+
+            | {"coordinates":[0.0,0.0],"type":"Point"} | 0  |
+            | {"coordinates":[1.0,1.0],"type":"Point"} | 1  |
+            +------------------------------------------+----+
+            "#
+        ),
+    )
+    .await;
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_group::group(engine, ingest, datafusion)]
+#[test_log::test(tokio::test)]
+async fn test_read_geojson_not_a_feature_collection() {
+    test_reader_common::test_reader_textual(
+        ReaderGeoJson::new(SessionContext::new(), ReadStepGeoJson { schema: None })
+            .await
+            .unwrap(),
+        r#"{"type": "Feature", "properties": {"id": 0}, "geometry": null}"#,
+        |res| async move {
+            assert_matches!(res, Err(ReadError::BadInput(_)));
+        },
+    )
+    .await;
+}
@@ -7,6 +7,8 @@
 // the Business Source License, use of this software will be governed
 // by the Apache License, Version 2.0.
 
+use std::assert_matches::assert_matches;
+
 use datafusion::prelude::SessionContext;
 use indoc::indoc;
 use kamu_ingest_datafusion::*;
@@ -19,8 +21,6 @@ use super::test_reader_common;
 #[test_group::group(engine, ingest, datafusion)]
 #[test_log::test(tokio::test)]
 async fn test_read_json_object() {
-    let temp_dir: tempfile::TempDir = tempfile::tempdir().unwrap();
-
     test_reader_common::test_reader_success_textual(
         ReaderJson::new(
             SessionContext::new(),
@@ -32,7 +32,6 @@ async fn test_read_json_object() {
                 ]),
                 ..Default::default()
             },
-            temp_dir.path().join("reader-tmp"),
         )
         .await
         .unwrap(),
@@ -77,8 +76,6 @@ async fn test_read_json_object() {
 #[test_group::group(engine, ingest, datafusion)]
 #[test_log::test(tokio::test)]
 async fn test_read_json_array() {
-    let temp_dir: tempfile::TempDir = tempfile::tempdir().unwrap();
-
     test_reader_common::test_reader_success_textual(
         ReaderJson::new(
             SessionContext::new(),
@@ -90,7 +87,6 @@ async fn test_read_json_array() {
                 ]),
                 ..Default::default()
             },
-            temp_dir.path().join("reader-tmp"),
         )
         .await
         .unwrap(),
@@ -125,3 +121,108 @@ async fn test_read_json_array() {
     )
     .await;
 }
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_group::group(engine, ingest, datafusion)]
+#[test_log::test(tokio::test)]
+async fn test_read_json_sub_path_skips_unrelated_values() {
+    test_reader_common::test_reader_success_textual(
+        ReaderJson::new(
+            SessionContext::new(),
+            ReadStepJson {
+                sub_path: Some("result.cities".to_string()),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap(),
+        indoc!(
+            r#"
+            {
+                "meta": {"cities": [{"city": "X"}]},
+                "result": {
+                    "count": 2,
+                    "cities": [
+                        {"city": "A", "population": 1000},
+                        {"city": "B", "population": 2000}
+                    ],
+                    "next": null
+                },
+                "status": "ok"
+            }
+            "#
+        ),
+        indoc!(
+            r#"
+            message arrow_schema {
+              OPTIONAL BYTE_ARRAY city (STRING);
+              OPTIONAL INT64 population;
+            }
+            "#
+        ),
+        indoc!(
+            r#"
+            +------+------------+
+            | city | population |
+            +------+------------+
+            | A    | 1000       |
+            | B    | 2000       |
+            +------+------------+
+            "#
+        ),
+    )
+    .await;
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_group::group(engine, ingest, datafusion)]
+#[test_log::test(tokio::test)]
+async fn test_read_json_sub_path_not_found() {
+    test_reader_common::test_reader_textual(
+        ReaderJson::new(
+            SessionContext::new(),
+            ReadStepJson {
+                sub_path: Some("result.cities".to_string()),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap(),
+        r#"{"result": {"rows": []}}"#,
+        |res| async move {
+            assert_matches!(
+                res,
+                Err(ReadError::BadInput(e)) if e.to_string() == "Sub-path not found: result.cities"
+            );
+        },
+    )
+    .await;
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_group::group(engine, ingest, datafusion)]
+#[test_log::test(tokio::test)]
+async fn test_read_json_sub_path_not_an_array() {
+    test_reader_common::test_reader_textual(
+        ReaderJson::new(
+            SessionContext::new(),
+            ReadStepJson {
+                sub_path: Some("result".to_string()),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap(),
+        r#"{"result": {"rows": []}}"#,
+        |res| async move {
+            assert_matches!(
+                res,
+                Err(ReadError::BadInput(e)) if e.to_string() == "Sub-path does not specify an array: result"
+            );
+        },
+    )
+    .await;
+}
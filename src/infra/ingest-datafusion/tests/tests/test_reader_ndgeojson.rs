@@ -19,8 +19,6 @@ use super::test_reader_common;
 #[test_group::group(engine, ingest, datafusion)]
 #[test_log::test(tokio::test)]
 async fn test_read_ndgeojson_with_schema() {
-    let temp_dir: tempfile::TempDir = tempfile::tempdir().unwrap();
-
     test_reader_common::test_reader_success_textual(
         ReaderNdGeoJson::new(
             SessionContext::new(),
@@ -32,7 +30,6 @@ async fn test_read_ndgeojson_with_schema() {
                     "geometry string not null".to_string(),
                 ]),
             },
-            temp_dir.path().join("reader-tmp")
         )
         .await
         .unwrap(),
@@ -71,15 +68,12 @@ async fn test_read_ndgeojson_with_schema() {
 #[test_group::group(engine, ingest, datafusion)]
 #[test_log::test(tokio::test)]
 async fn test_read_ndgeojson_infer_schema() {
-    let temp_dir: tempfile::TempDir = tempfile::tempdir().unwrap();
-
     test_reader_common::test_reader_success_textual(
         ReaderNdGeoJson::new(
             SessionContext::new(),
             ReadStepNdGeoJson {
                 schema: None,
             },
-            temp_dir.path().join("reader-tmp"),
         )
         .await
         .unwrap(),
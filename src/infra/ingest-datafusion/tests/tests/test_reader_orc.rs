@@ -0,0 +1,178 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::path::Path;
+use std::sync::Arc;
+
+use datafusion::prelude::SessionContext;
+use indoc::indoc;
+use kamu_ingest_datafusion::*;
+use opendatafabric::*;
+
+use super::test_reader_common;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+fn write_test_data(path: impl AsRef<Path>) {
+    use datafusion::arrow::array;
+    use datafusion::arrow::datatypes::{DataType, Field, Schema};
+    use datafusion::arrow::record_batch::RecordBatch;
+
+    let schema = Arc::new(Schema::new(vec![
+        Field::new("city", DataType::Utf8, false),
+        Field::new("population", DataType::Int64, false),
+    ]));
+
+    let record_batch = RecordBatch::try_new(
+        schema.clone(),
+        vec![
+            Arc::new(array::StringArray::from(vec![
+                "vancouver",
+                "seattle",
+                "kyiv",
+            ])),
+            Arc::new(array::Int64Array::from(vec![675_000, 733_000, 2_884_000])),
+        ],
+    )
+    .unwrap();
+
+    let mut writer =
+        orc_rust::ArrowWriterBuilder::new(std::fs::File::create(path).unwrap(), schema)
+            .try_build()
+            .unwrap();
+
+    writer.write(&record_batch).unwrap();
+    writer.close().unwrap();
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_group::group(engine, ingest, datafusion)]
+#[test_log::test(tokio::test)]
+async fn test_read_orc() {
+    test_reader_common::test_reader_success(
+        ReaderOrc::new(SessionContext::new(), ReadStepOrc { schema: None })
+            .await
+            .unwrap(),
+        |path| async {
+            write_test_data(path);
+        },
+        indoc!(
+            r#"
+            message arrow_schema {
+              OPTIONAL BYTE_ARRAY city (STRING);
+              OPTIONAL INT64 population;
+            }
+            "#
+        ),
+        indoc!(
+            r#"
+            +-----------+------------+
+            | city      | population |
+            +-----------+------------+
+            | vancouver | 675000     |
+            | seattle   | 733000     |
+            | kyiv      | 2884000    |
+            +-----------+------------+
+            "#
+        ),
+    )
+    .await;
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_group::group(engine, ingest, datafusion)]
+#[test_log::test(tokio::test)]
+async fn test_read_orc_schema_coercion() {
+    test_reader_common::test_reader_success(
+        ReaderOrc::new(
+            SessionContext::new(),
+            ReadStepOrc {
+                schema: Some(vec![
+                    "population string".to_string(),
+                    "city string".to_string(),
+                ]),
+            },
+        )
+        .await
+        .unwrap(),
+        |path| async {
+            write_test_data(path);
+        },
+        indoc!(
+            r#"
+            message arrow_schema {
+              OPTIONAL BYTE_ARRAY population (STRING);
+              OPTIONAL BYTE_ARRAY city (STRING);
+            }
+            "#
+        ),
+        indoc!(
+            r#"
+            +------------+-----------+
+            | population | city      |
+            +------------+-----------+
+            | 675000     | vancouver |
+            | 733000     | seattle   |
+            | 2884000    | kyiv      |
+            +------------+-----------+
+            "#
+        ),
+    )
+    .await;
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_group::group(engine, ingest, datafusion)]
+#[test_log::test(tokio::test)]
+async fn test_read_orc_in_batches() {
+    use datafusion::arrow::array;
+    use datafusion::arrow::datatypes::{DataType, Field, Schema};
+    use datafusion::arrow::record_batch::RecordBatch;
+
+    const NUM_ROWS: i64 = 20_000;
+
+    test_reader_common::test_reader(
+        ReaderOrc::new(SessionContext::new(), ReadStepOrc { schema: None })
+            .await
+            .unwrap(),
+        |path| async move {
+            let schema = Arc::new(Schema::new(vec![Field::new("id", DataType::Int64, false)]));
+
+            let mut writer = orc_rust::ArrowWriterBuilder::new(
+                std::fs::File::create(path).unwrap(),
+                schema.clone(),
+            )
+            .try_build()
+            .unwrap();
+
+            let record_batch = RecordBatch::try_new(
+                schema,
+                vec![Arc::new(array::Int64Array::from_iter_values(0..NUM_ROWS))],
+            )
+            .unwrap();
+
+            writer.write(&record_batch).unwrap();
+            writer.close().unwrap();
+        },
+        |res| async {
+            let batches = res.unwrap().collect().await.unwrap();
+
+            assert!(batches.len() > 1);
+            assert!(batches.iter().all(|b| b.num_rows() <= 8192));
+            assert_eq!(
+                batches.iter().map(RecordBatch::num_rows).sum::<usize>(),
+                usize::try_from(NUM_ROWS).unwrap()
+            );
+        },
+    )
+    .await;
+}
@@ -9,7 +9,7 @@
 
 use std::assert_matches::assert_matches;
 
-use datafusion::arrow::array::StringArray;
+use datafusion::arrow::array::{RecordBatch, StringArray};
 use datafusion::prelude::{SessionContext, *};
 use indoc::indoc;
 use kamu_ingest_datafusion::*;
@@ -259,3 +259,36 @@ async fn test_read_shapefile_geom() {
     )
     .await;
 }
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_group::group(engine, ingest, datafusion)]
+#[test_log::test(tokio::test)]
+async fn test_read_shapefile_cleans_up_temp_files() {
+    let temp_dir: tempfile::TempDir = tempfile::tempdir().unwrap();
+    let reader_temp_path = temp_dir.path().join("reader-tmp");
+
+    test_reader_common::test_reader(
+        ReaderEsriShapefile::new(
+            SessionContext::new(),
+            ReadStepEsriShapefile {
+                schema: None,
+                sub_path: None,
+            },
+            &reader_temp_path,
+        )
+        .await
+        .unwrap(),
+        |path| async {
+            std::fs::copy("tests/data/ukraine.zip", path).unwrap();
+        },
+        |res| async {
+            let batches = res.unwrap().collect().await.unwrap();
+            let num_rows: usize = batches.iter().map(RecordBatch::num_rows).sum();
+            assert_eq!(num_rows, 27);
+        },
+    )
+    .await;
+
+    assert_eq!(std::fs::read_dir(&reader_temp_path).unwrap().count(), 0);
+}
@@ -0,0 +1,130 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::collections::btree_map::Entry;
+use std::collections::BTreeMap;
+use std::sync::{Arc, Mutex};
+
+use dill::{component, interface, scope, Singleton};
+use internal_error::InternalError;
+
+use crate::domain::*;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub struct InMemoryOutboxDeadLetterRepository {
+    state: Arc<Mutex<State>>,
+}
+
+type DeadLetterKey = (String, String, OutboxMessageID);
+
+#[derive(Default)]
+struct State {
+    dead_letters: BTreeMap<DeadLetterKey, OutboxDeadLetter>,
+}
+
+#[component(pub)]
+#[scope(Singleton)]
+#[interface(dyn OutboxDeadLetterRepository)]
+impl InMemoryOutboxDeadLetterRepository {
+    pub fn new() -> Self {
+        Self {
+            state: Arc::new(Mutex::new(State::default())),
+        }
+    }
+
+    fn make_key(
+        producer_name: &str,
+        consumer_name: &str,
+        message_id: OutboxMessageID,
+    ) -> DeadLetterKey {
+        (
+            producer_name.to_string(),
+            consumer_name.to_string(),
+            message_id,
+        )
+    }
+}
+
+#[async_trait::async_trait]
+impl OutboxDeadLetterRepository for InMemoryOutboxDeadLetterRepository {
+    async fn push_dead_letter(
+        &self,
+        dead_letter: OutboxDeadLetter,
+    ) -> Result<(), PushDeadLetterError> {
+        let key = Self::make_key(
+            &dead_letter.producer_name,
+            &dead_letter.consumer_name,
+            dead_letter.message_id,
+        );
+
+        let mut guard = self.state.lock().unwrap();
+
+        if let Entry::Vacant(e) = guard.dead_letters.entry(key) {
+            e.insert(dead_letter);
+            Ok(())
+        } else {
+            Err(PushDeadLetterError::DuplicateDeadLetter(
+                DuplicateDeadLetterError {
+                    producer_name: dead_letter.producer_name,
+                    consumer_name: dead_letter.consumer_name,
+                    message_id: dead_letter.message_id,
+                },
+            ))
+        }
+    }
+
+    async fn list_dead_letters(&self) -> Result<OutboxDeadLetterStream, InternalError> {
+        let dead_letters = {
+            let guard = self.state.lock().unwrap();
+            guard
+                .dead_letters
+                .values()
+                .cloned()
+                .map(Ok)
+                .collect::<Vec<_>>()
+        };
+
+        Ok(Box::pin(tokio_stream::iter(dead_letters)))
+    }
+
+    async fn find_dead_letter(
+        &self,
+        producer_name: &str,
+        consumer_name: &str,
+        message_id: OutboxMessageID,
+    ) -> Result<Option<OutboxDeadLetter>, InternalError> {
+        let key = Self::make_key(producer_name, consumer_name, message_id);
+        let guard = self.state.lock().unwrap();
+        Ok(guard.dead_letters.get(&key).cloned())
+    }
+
+    async fn delete_dead_letter(
+        &self,
+        producer_name: &str,
+        consumer_name: &str,
+        message_id: OutboxMessageID,
+    ) -> Result<(), DeleteDeadLetterError> {
+        let key = Self::make_key(producer_name, consumer_name, message_id);
+
+        let mut guard = self.state.lock().unwrap();
+
+        if guard.dead_letters.remove(&key).is_some() {
+            Ok(())
+        } else {
+            Err(DeleteDeadLetterError::NotFound(DeadLetterNotFoundError {
+                producer_name: producer_name.to_string(),
+                consumer_name: consumer_name.to_string(),
+                message_id,
+            }))
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
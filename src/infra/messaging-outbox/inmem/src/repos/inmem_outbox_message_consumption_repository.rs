@@ -25,6 +25,7 @@ pub struct InMemoryOutboxMessageConsumptionRepository {
 #[derive(Default)]
 struct State {
     consumption_boundaries: HashMap<String, OutboxMessageConsumptionBoundary>,
+    consumption_retries: HashMap<String, OutboxMessageConsumptionRetry>,
 }
 
 #[component(pub)]
@@ -104,6 +105,7 @@ impl OutboxMessageConsumptionRepository for InMemoryOutboxMessageConsumptionRepo
         let maybe_boundary = guard.consumption_boundaries.get_mut(&key);
         if let Some(existing_boundary) = maybe_boundary {
             existing_boundary.last_consumed_message_id = boundary.last_consumed_message_id;
+            guard.consumption_retries.remove(&key);
             Ok(())
         } else {
             Err(UpdateConsumptionBoundaryError::ConsumptionBoundaryNotFound(
@@ -114,6 +116,50 @@ impl OutboxMessageConsumptionRepository for InMemoryOutboxMessageConsumptionRepo
             ))
         }
     }
+
+    async fn find_consumption_retry(
+        &self,
+        consumer_name: &str,
+        producer_name: &str,
+    ) -> Result<Option<OutboxMessageConsumptionRetry>, InternalError> {
+        let key = self.make_key(consumer_name, producer_name);
+        let guard = self.state.lock().unwrap();
+        Ok(guard.consumption_retries.get(&key).cloned())
+    }
+
+    async fn list_consumption_retries(
+        &self,
+        producer_name: &str,
+    ) -> Result<Vec<OutboxMessageConsumptionRetry>, InternalError> {
+        let guard = self.state.lock().unwrap();
+        Ok(guard
+            .consumption_retries
+            .values()
+            .filter(|retry| retry.producer_name == producer_name)
+            .cloned()
+            .collect())
+    }
+
+    async fn save_consumption_retry(
+        &self,
+        retry: OutboxMessageConsumptionRetry,
+    ) -> Result<(), UpdateConsumptionBoundaryError> {
+        let key = self.make_key(&retry.consumer_name, &retry.producer_name);
+
+        let mut guard = self.state.lock().unwrap();
+
+        if !guard.consumption_boundaries.contains_key(&key) {
+            return Err(UpdateConsumptionBoundaryError::ConsumptionBoundaryNotFound(
+                ConsumptionBoundaryNotFoundError {
+                    consumer_name: retry.consumer_name,
+                    producer_name: retry.producer_name,
+                },
+            ));
+        }
+
+        guard.consumption_retries.insert(key, retry);
+        Ok(())
+    }
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -7,8 +7,10 @@
 // the Business Source License, use of this software will be governed
 // by the Apache License, Version 2.0.
 
+mod inmem_outbox_dead_letter_repository;
 mod inmem_outbox_message_consumption_repository;
 mod inmem_outbox_message_repository;
 
+pub use inmem_outbox_dead_letter_repository::*;
 pub use inmem_outbox_message_consumption_repository::*;
 pub use inmem_outbox_message_repository::*;
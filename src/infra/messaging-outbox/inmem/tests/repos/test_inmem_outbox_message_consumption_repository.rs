@@ -53,6 +53,14 @@ database_transactional_test!(
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
+database_transactional_test!(
+    storage = inmem,
+    fixture = kamu_messaging_outbox_repo_tests::test_consumption_retries,
+    harness = InMemoryOutboxMessageConsumptionRepositoryHarness
+);
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
 struct InMemoryOutboxMessageConsumptionRepositoryHarness {
     catalog: Catalog,
 }
@@ -0,0 +1,294 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use chrono::{DateTime, Utc};
+use database_common::{TransactionRef, TransactionRefT};
+use dill::{component, interface};
+use internal_error::{ErrorIntoInternal, InternalError};
+
+use crate::domain::*;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub struct MySqlOutboxMessageConsumptionRepository {
+    transaction: TransactionRefT<sqlx::MySql>,
+}
+
+#[component(pub)]
+#[interface(dyn OutboxMessageConsumptionRepository)]
+impl MySqlOutboxMessageConsumptionRepository {
+    pub fn new(transaction: TransactionRef) -> Self {
+        Self {
+            transaction: transaction.into(),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl OutboxMessageConsumptionRepository for MySqlOutboxMessageConsumptionRepository {
+    async fn list_consumption_boundaries(
+        &self,
+    ) -> Result<OutboxMessageConsumptionBoundariesStream, InternalError> {
+        let mut tr = self.transaction.lock().await;
+
+        Ok(Box::pin(async_stream::stream! {
+            let connection_mut = tr
+                .connection_mut()
+                .await?;
+
+            let mut query_stream = sqlx::query_as!(
+                OutboxMessageConsumptionBoundary,
+                r#"
+                    SELECT
+                        consumer_name, producer_name, last_consumed_message_id
+                    FROM outbox_message_consumptions
+                "#,
+            )
+            .fetch(connection_mut)
+            .map_err(ErrorIntoInternal::int_err);
+
+            use futures::TryStreamExt;
+            while let Some(consumption) = query_stream.try_next().await? {
+                yield Ok(consumption);
+            }
+        }))
+    }
+
+    async fn find_consumption_boundary(
+        &self,
+        consumer_name: &str,
+        producer_name: &str,
+    ) -> Result<Option<OutboxMessageConsumptionBoundary>, InternalError> {
+        let mut tr = self.transaction.lock().await;
+
+        let connection_mut = tr.connection_mut().await?;
+
+        sqlx::query_as!(
+            OutboxMessageConsumptionBoundary,
+            r#"
+                SELECT
+                    consumer_name, producer_name, last_consumed_message_id
+                FROM outbox_message_consumptions
+                WHERE consumer_name = ? and producer_name = ?
+            "#,
+            consumer_name,
+            producer_name,
+        )
+        .fetch_optional(connection_mut)
+        .await
+        .map_err(ErrorIntoInternal::int_err)
+    }
+
+    async fn create_consumption_boundary(
+        &self,
+        boundary: OutboxMessageConsumptionBoundary,
+    ) -> Result<(), CreateConsumptionBoundaryError> {
+        let mut tr = self.transaction.lock().await;
+
+        let connection_mut = tr
+            .connection_mut()
+            .await
+            .map_err(|e| CreateConsumptionBoundaryError::Internal(e.int_err()))?;
+
+        let last_consumed_message_id = boundary.last_consumed_message_id.into_inner();
+
+        sqlx::query!(
+            r#"
+                INSERT INTO outbox_message_consumptions (consumer_name, producer_name, last_consumed_message_id)
+                    VALUES (?, ?, ?)
+            "#,
+            boundary.consumer_name,
+            boundary.producer_name,
+            last_consumed_message_id,
+        )
+        .execute(connection_mut)
+        .await
+        .map_err(|e| {
+            if let sqlx::Error::Database(e) = &e
+                && e.is_unique_violation()
+            {
+                CreateConsumptionBoundaryError::DuplicateConsumptionBoundary(
+                    DuplicateConsumptionBoundaryError {
+                        consumer_name: boundary.consumer_name,
+                        producer_name: boundary.producer_name,
+                    },
+                )
+            } else {
+                CreateConsumptionBoundaryError::Internal(e.int_err())
+            }
+        })?;
+
+        Ok(())
+    }
+
+    async fn update_consumption_boundary(
+        &self,
+        boundary: OutboxMessageConsumptionBoundary,
+    ) -> Result<(), UpdateConsumptionBoundaryError> {
+        let mut tr = self.transaction.lock().await;
+
+        let connection_mut = tr
+            .connection_mut()
+            .await
+            .map_err(|e| UpdateConsumptionBoundaryError::Internal(e.int_err()))?;
+
+        let last_consumed_message_id = boundary.last_consumed_message_id.into_inner();
+
+        let res = sqlx::query!(
+            r#"
+                UPDATE outbox_message_consumptions
+                    SET last_consumed_message_id = ?, failed_message_id = NULL, failed_attempts = 0, next_attempt_at = NULL
+                    WHERE consumer_name = ? and producer_name = ? and last_consumed_message_id < ?
+            "#,
+            last_consumed_message_id,
+            boundary.consumer_name,
+            boundary.producer_name,
+            last_consumed_message_id,
+        )
+        .execute(connection_mut)
+        .await
+        .map_err(|e| UpdateConsumptionBoundaryError::Internal(e.int_err()))?;
+
+        if res.rows_affected() != 1 {
+            Err(UpdateConsumptionBoundaryError::ConsumptionBoundaryNotFound(
+                ConsumptionBoundaryNotFoundError {
+                    consumer_name: boundary.consumer_name,
+                    producer_name: boundary.producer_name,
+                },
+            ))
+        } else {
+            Ok(())
+        }
+    }
+
+    async fn find_consumption_retry(
+        &self,
+        consumer_name: &str,
+        producer_name: &str,
+    ) -> Result<Option<OutboxMessageConsumptionRetry>, InternalError> {
+        let mut tr = self.transaction.lock().await;
+
+        let connection_mut = tr.connection_mut().await?;
+
+        let maybe_row = sqlx::query_as!(
+            OutboxMessageConsumptionRetryRowModel,
+            r#"
+                SELECT
+                    producer_name,
+                    consumer_name,
+                    failed_message_id as "failed_message_id!",
+                    failed_attempts,
+                    next_attempt_at as "next_attempt_at!: _"
+                FROM outbox_message_consumptions
+                WHERE consumer_name = ? and producer_name = ? and failed_message_id IS NOT NULL
+            "#,
+            consumer_name,
+            producer_name
+        )
+        .fetch_optional(connection_mut)
+        .await
+        .map_err(ErrorIntoInternal::int_err)?;
+
+        Ok(maybe_row.map(Into::into))
+    }
+
+    async fn list_consumption_retries(
+        &self,
+        producer_name: &str,
+    ) -> Result<Vec<OutboxMessageConsumptionRetry>, InternalError> {
+        let mut tr = self.transaction.lock().await;
+
+        let connection_mut = tr.connection_mut().await?;
+
+        let rows = sqlx::query_as!(
+            OutboxMessageConsumptionRetryRowModel,
+            r#"
+                SELECT
+                    producer_name,
+                    consumer_name,
+                    failed_message_id as "failed_message_id!",
+                    failed_attempts,
+                    next_attempt_at as "next_attempt_at!: _"
+                FROM outbox_message_consumptions
+                WHERE producer_name = ? and failed_message_id IS NOT NULL
+            "#,
+            producer_name
+        )
+        .fetch_all(connection_mut)
+        .await
+        .map_err(ErrorIntoInternal::int_err)?;
+
+        Ok(rows.into_iter().map(Into::into).collect())
+    }
+
+    async fn save_consumption_retry(
+        &self,
+        retry: OutboxMessageConsumptionRetry,
+    ) -> Result<(), UpdateConsumptionBoundaryError> {
+        let mut tr = self.transaction.lock().await;
+
+        let connection_mut = tr
+            .connection_mut()
+            .await
+            .map_err(|e| UpdateConsumptionBoundaryError::Internal(e.int_err()))?;
+
+        let failed_message_id = retry.failed_message_id.into_inner();
+
+        let res = sqlx::query!(
+            r#"
+                UPDATE outbox_message_consumptions
+                    SET failed_message_id = ?, failed_attempts = ?, next_attempt_at = ?
+                    WHERE consumer_name = ? and producer_name = ?
+            "#,
+            failed_message_id,
+            retry.failed_attempts,
+            retry.next_attempt_at,
+            retry.consumer_name,
+            retry.producer_name,
+        )
+        .execute(connection_mut)
+        .await
+        .map_err(|e| UpdateConsumptionBoundaryError::Internal(e.int_err()))?;
+
+        if res.rows_affected() != 1 {
+            Err(UpdateConsumptionBoundaryError::ConsumptionBoundaryNotFound(
+                ConsumptionBoundaryNotFoundError {
+                    consumer_name: retry.consumer_name,
+                    producer_name: retry.producer_name,
+                },
+            ))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+struct OutboxMessageConsumptionRetryRowModel {
+    producer_name: String,
+    consumer_name: String,
+    failed_message_id: i64,
+    failed_attempts: i32,
+    next_attempt_at: DateTime<Utc>,
+}
+
+impl From<OutboxMessageConsumptionRetryRowModel> for OutboxMessageConsumptionRetry {
+    fn from(row: OutboxMessageConsumptionRetryRowModel) -> Self {
+        Self {
+            producer_name: row.producer_name,
+            consumer_name: row.consumer_name,
+            failed_message_id: OutboxMessageID::new(row.failed_message_id),
+            failed_attempts: row.failed_attempts,
+            next_attempt_at: row.next_attempt_at,
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
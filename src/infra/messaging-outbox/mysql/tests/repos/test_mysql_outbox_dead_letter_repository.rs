@@ -0,0 +1,67 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use database_common::MySqlTransactionManager;
+use database_common_macros::database_transactional_test;
+use dill::{Catalog, CatalogBuilder};
+use kamu_messaging_outbox_mysql::MySqlOutboxDeadLetterRepository;
+use sqlx::MySqlPool;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+database_transactional_test!(
+    storage = mysql,
+    fixture = kamu_messaging_outbox_repo_tests::test_no_dead_letters_initially,
+    harness = MySqlOutboxDeadLetterRepositoryHarness
+);
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+database_transactional_test!(
+    storage = mysql,
+    fixture = kamu_messaging_outbox_repo_tests::test_push_dead_letter,
+    harness = MySqlOutboxDeadLetterRepositoryHarness
+);
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+database_transactional_test!(
+    storage = mysql,
+    fixture = kamu_messaging_outbox_repo_tests::test_delete_dead_letter,
+    harness = MySqlOutboxDeadLetterRepositoryHarness
+);
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+database_transactional_test!(
+    storage = mysql,
+    fixture = kamu_messaging_outbox_repo_tests::test_multiple_dead_letters,
+    harness = MySqlOutboxDeadLetterRepositoryHarness
+);
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+struct MySqlOutboxDeadLetterRepositoryHarness {
+    catalog: Catalog,
+}
+
+impl MySqlOutboxDeadLetterRepositoryHarness {
+    pub fn new(mysql_pool: MySqlPool) -> Self {
+        let mut catalog_builder = CatalogBuilder::new();
+        catalog_builder.add_value(mysql_pool);
+        catalog_builder.add::<MySqlTransactionManager>();
+        catalog_builder.add::<MySqlOutboxDeadLetterRepository>();
+
+        Self {
+            catalog: catalog_builder.build(),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,215 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use chrono::{DateTime, Utc};
+use database_common::{TransactionRef, TransactionRefT};
+use dill::{component, interface};
+use futures::TryStreamExt;
+use internal_error::{ErrorIntoInternal, InternalError};
+
+use crate::domain::*;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub struct PostgresOutboxDeadLetterRepository {
+    transaction: TransactionRefT<sqlx::Postgres>,
+}
+
+#[component(pub)]
+#[interface(dyn OutboxDeadLetterRepository)]
+impl PostgresOutboxDeadLetterRepository {
+    pub fn new(transaction: TransactionRef) -> Self {
+        Self {
+            transaction: transaction.into(),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl OutboxDeadLetterRepository for PostgresOutboxDeadLetterRepository {
+    async fn push_dead_letter(
+        &self,
+        dead_letter: OutboxDeadLetter,
+    ) -> Result<(), PushDeadLetterError> {
+        let mut tr = self.transaction.lock().await;
+
+        let connection_mut = tr
+            .connection_mut()
+            .await
+            .map_err(|e| PushDeadLetterError::Internal(e.int_err()))?;
+
+        sqlx::query!(
+            r#"
+                INSERT INTO outbox_dead_letters (producer_name, consumer_name, message_id, content_json, occurred_on, attempts, last_error, dead_lettered_at)
+                    VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+            "#,
+            dead_letter.producer_name,
+            dead_letter.consumer_name,
+            dead_letter.message_id.into_inner(),
+            &dead_letter.content_json,
+            dead_letter.occurred_on,
+            dead_letter.attempts,
+            dead_letter.last_error,
+            dead_letter.dead_lettered_at,
+        )
+        .execute(connection_mut)
+        .await
+        .map_err(|e| {
+            if let sqlx::Error::Database(e) = &e
+                && e.is_unique_violation()
+            {
+                PushDeadLetterError::DuplicateDeadLetter(DuplicateDeadLetterError {
+                    producer_name: dead_letter.producer_name,
+                    consumer_name: dead_letter.consumer_name,
+                    message_id: dead_letter.message_id,
+                })
+            } else {
+                PushDeadLetterError::Internal(e.int_err())
+            }
+        })?;
+
+        Ok(())
+    }
+
+    async fn list_dead_letters(&self) -> Result<OutboxDeadLetterStream, InternalError> {
+        let mut tr = self.transaction.lock().await;
+
+        Ok(Box::pin(async_stream::stream! {
+            let connection_mut = tr
+                .connection_mut()
+                .await?;
+
+            let mut query_stream = sqlx::query_as!(
+                OutboxDeadLetterRowModel,
+                r#"
+                    SELECT
+                        producer_name,
+                        consumer_name,
+                        message_id,
+                        content_json,
+                        occurred_on,
+                        attempts,
+                        last_error,
+                        dead_lettered_at
+                    FROM outbox_dead_letters
+                    ORDER BY producer_name, consumer_name, message_id
+                "#,
+            )
+            .fetch(connection_mut)
+            .map_err(ErrorIntoInternal::int_err);
+
+            while let Some(row) = query_stream.try_next().await? {
+                yield Ok(row.into());
+            }
+        }))
+    }
+
+    async fn find_dead_letter(
+        &self,
+        producer_name: &str,
+        consumer_name: &str,
+        message_id: OutboxMessageID,
+    ) -> Result<Option<OutboxDeadLetter>, InternalError> {
+        let mut tr = self.transaction.lock().await;
+
+        let connection_mut = tr.connection_mut().await?;
+
+        let maybe_row = sqlx::query_as!(
+            OutboxDeadLetterRowModel,
+            r#"
+                SELECT
+                    producer_name,
+                    consumer_name,
+                    message_id,
+                    content_json,
+                    occurred_on,
+                    attempts,
+                    last_error,
+                    dead_lettered_at
+                FROM outbox_dead_letters
+                WHERE producer_name = $1 and consumer_name = $2 and message_id = $3
+            "#,
+            producer_name,
+            consumer_name,
+            message_id.into_inner(),
+        )
+        .fetch_optional(connection_mut)
+        .await
+        .map_err(ErrorIntoInternal::int_err)?;
+
+        Ok(maybe_row.map(Into::into))
+    }
+
+    async fn delete_dead_letter(
+        &self,
+        producer_name: &str,
+        consumer_name: &str,
+        message_id: OutboxMessageID,
+    ) -> Result<(), DeleteDeadLetterError> {
+        let mut tr = self.transaction.lock().await;
+
+        let connection_mut = tr
+            .connection_mut()
+            .await
+            .map_err(|e| DeleteDeadLetterError::Internal(e.int_err()))?;
+
+        let res = sqlx::query!(
+            r#"
+                DELETE FROM outbox_dead_letters
+                    WHERE producer_name = $1 and consumer_name = $2 and message_id = $3
+            "#,
+            producer_name,
+            consumer_name,
+            message_id.into_inner(),
+        )
+        .execute(connection_mut)
+        .await
+        .map_err(|e| DeleteDeadLetterError::Internal(e.int_err()))?;
+
+        if res.rows_affected() != 1 {
+            Err(DeleteDeadLetterError::NotFound(DeadLetterNotFoundError {
+                producer_name: producer_name.to_string(),
+                consumer_name: consumer_name.to_string(),
+                message_id,
+            }))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+struct OutboxDeadLetterRowModel {
+    producer_name: String,
+    consumer_name: String,
+    message_id: i64,
+    content_json: serde_json::Value,
+    occurred_on: DateTime<Utc>,
+    attempts: i32,
+    last_error: String,
+    dead_lettered_at: DateTime<Utc>,
+}
+
+impl From<OutboxDeadLetterRowModel> for OutboxDeadLetter {
+    fn from(row: OutboxDeadLetterRowModel) -> Self {
+        Self {
+            producer_name: row.producer_name,
+            consumer_name: row.consumer_name,
+            message_id: OutboxMessageID::new(row.message_id),
+            content_json: row.content_json,
+            occurred_on: row.occurred_on,
+            attempts: row.attempts,
+            last_error: row.last_error,
+            dead_lettered_at: row.dead_lettered_at,
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
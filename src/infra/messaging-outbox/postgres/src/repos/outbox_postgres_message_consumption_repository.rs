@@ -7,6 +7,7 @@
 // the Business Source License, use of this software will be governed
 // by the Apache License, Version 2.0.
 
+use chrono::{DateTime, Utc};
 use database_common::{TransactionRef, TransactionRefT};
 use dill::{component, interface};
 use internal_error::{ErrorIntoInternal, InternalError};
@@ -137,7 +138,8 @@ impl OutboxMessageConsumptionRepository for PostgresOutboxMessageConsumptionRepo
 
         let res = sqlx::query!(
             r#"
-                UPDATE outbox_message_consumptions SET last_consumed_message_id = $3
+                UPDATE outbox_message_consumptions
+                    SET last_consumed_message_id = $3, failed_message_id = NULL, failed_attempts = 0, next_attempt_at = NULL
                     WHERE consumer_name = $1 and producer_name = $2 and last_consumed_message_id < $3
             "#,
             boundary.consumer_name,
@@ -159,6 +161,129 @@ impl OutboxMessageConsumptionRepository for PostgresOutboxMessageConsumptionRepo
             Ok(())
         }
     }
+
+    async fn find_consumption_retry(
+        &self,
+        consumer_name: &str,
+        producer_name: &str,
+    ) -> Result<Option<OutboxMessageConsumptionRetry>, InternalError> {
+        let mut tr = self.transaction.lock().await;
+
+        let connection_mut = tr.connection_mut().await?;
+
+        let maybe_row = sqlx::query_as!(
+            OutboxMessageConsumptionRetryRowModel,
+            r#"
+                SELECT
+                    producer_name,
+                    consumer_name,
+                    failed_message_id as "failed_message_id!",
+                    failed_attempts,
+                    next_attempt_at as "next_attempt_at!"
+                FROM outbox_message_consumptions
+                WHERE consumer_name = $1 and producer_name = $2 and failed_message_id IS NOT NULL
+            "#,
+            consumer_name,
+            producer_name
+        )
+        .fetch_optional(connection_mut)
+        .await
+        .map_err(ErrorIntoInternal::int_err)?;
+
+        Ok(maybe_row.map(Into::into))
+    }
+
+    async fn list_consumption_retries(
+        &self,
+        producer_name: &str,
+    ) -> Result<Vec<OutboxMessageConsumptionRetry>, InternalError> {
+        let mut tr = self.transaction.lock().await;
+
+        let connection_mut = tr.connection_mut().await?;
+
+        let rows = sqlx::query_as!(
+            OutboxMessageConsumptionRetryRowModel,
+            r#"
+                SELECT
+                    producer_name,
+                    consumer_name,
+                    failed_message_id as "failed_message_id!",
+                    failed_attempts,
+                    next_attempt_at as "next_attempt_at!"
+                FROM outbox_message_consumptions
+                WHERE producer_name = $1 and failed_message_id IS NOT NULL
+            "#,
+            producer_name
+        )
+        .fetch_all(connection_mut)
+        .await
+        .map_err(ErrorIntoInternal::int_err)?;
+
+        Ok(rows.into_iter().map(Into::into).collect())
+    }
+
+    async fn save_consumption_retry(
+        &self,
+        retry: OutboxMessageConsumptionRetry,
+    ) -> Result<(), UpdateConsumptionBoundaryError> {
+        let mut tr = self.transaction.lock().await;
+
+        let connection_mut = tr
+            .connection_mut()
+            .await
+            .map_err(|e| UpdateConsumptionBoundaryError::Internal(e.int_err()))?;
+
+        let failed_message_id = retry.failed_message_id.into_inner();
+
+        let res = sqlx::query!(
+            r#"
+                UPDATE outbox_message_consumptions
+                    SET failed_message_id = $3, failed_attempts = $4, next_attempt_at = $5
+                    WHERE consumer_name = $1 and producer_name = $2
+            "#,
+            retry.consumer_name,
+            retry.producer_name,
+            failed_message_id,
+            retry.failed_attempts,
+            retry.next_attempt_at,
+        )
+        .execute(connection_mut)
+        .await
+        .map_err(|e| UpdateConsumptionBoundaryError::Internal(e.int_err()))?;
+
+        if res.rows_affected() != 1 {
+            Err(UpdateConsumptionBoundaryError::ConsumptionBoundaryNotFound(
+                ConsumptionBoundaryNotFoundError {
+                    consumer_name: retry.consumer_name,
+                    producer_name: retry.producer_name,
+                },
+            ))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+struct OutboxMessageConsumptionRetryRowModel {
+    producer_name: String,
+    consumer_name: String,
+    failed_message_id: i64,
+    failed_attempts: i32,
+    next_attempt_at: DateTime<Utc>,
+}
+
+impl From<OutboxMessageConsumptionRetryRowModel> for OutboxMessageConsumptionRetry {
+    fn from(row: OutboxMessageConsumptionRetryRowModel) -> Self {
+        Self {
+            producer_name: row.producer_name,
+            consumer_name: row.consumer_name,
+            failed_message_id: OutboxMessageID::new(row.failed_message_id),
+            failed_attempts: row.failed_attempts,
+            next_attempt_at: row.next_attempt_at,
+        }
+    }
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
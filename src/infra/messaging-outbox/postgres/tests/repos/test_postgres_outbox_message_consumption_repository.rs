@@ -55,6 +55,14 @@ database_transactional_test!(
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
+database_transactional_test!(
+    storage = postgres,
+    fixture = kamu_messaging_outbox_repo_tests::test_consumption_retries,
+    harness = PostgresOutboxMessageConsumptionRepositoryHarness
+);
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
 struct PostgresOutboxMessageConsumptionRepositoryHarness {
     catalog: Catalog,
 }
@@ -9,8 +9,10 @@
 
 #![feature(assert_matches)]
 
+mod outbox_dead_letter_repository_test_suite;
 mod outbox_message_consumption_repository_test_suite;
 mod outbox_message_repository_test_suite;
 
+pub use outbox_dead_letter_repository_test_suite::*;
 pub use outbox_message_consumption_repository_test_suite::*;
 pub use outbox_message_repository_test_suite::*;
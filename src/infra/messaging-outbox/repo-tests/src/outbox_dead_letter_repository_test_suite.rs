@@ -0,0 +1,179 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::assert_matches::assert_matches;
+
+use chrono::{TimeZone, Utc};
+use dill::Catalog;
+use messaging_outbox::{
+    DeadLetterNotFoundError,
+    DeleteDeadLetterError,
+    DuplicateDeadLetterError,
+    OutboxDeadLetter,
+    OutboxDeadLetterRepository,
+    OutboxMessageID,
+    PushDeadLetterError,
+};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+const TEST_CONSUMER: &str = "test-consumer";
+const TEST_PRODUCER: &str = "test-producer";
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub async fn test_no_dead_letters_initially(catalog: &Catalog) {
+    let dead_letter_repo = catalog.get_one::<dyn OutboxDeadLetterRepository>().unwrap();
+
+    let dead_letters = read_dead_letters(dead_letter_repo.as_ref()).await;
+    assert_eq!(0, dead_letters.len());
+
+    let res = dead_letter_repo
+        .find_dead_letter(TEST_PRODUCER, TEST_CONSUMER, OutboxMessageID::new(1))
+        .await;
+    assert_matches!(res, Ok(None));
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub async fn test_push_dead_letter(catalog: &Catalog) {
+    let dead_letter_repo = catalog.get_one::<dyn OutboxDeadLetterRepository>().unwrap();
+
+    let dead_letter = make_dead_letter(TEST_PRODUCER, TEST_CONSUMER, 5);
+
+    // 1st push should pass
+    let res = dead_letter_repo.push_dead_letter(dead_letter.clone()).await;
+    assert_matches!(res, Ok(_));
+
+    // Try reading pushed record
+    let dead_letters = read_dead_letters(dead_letter_repo.as_ref()).await;
+    assert_eq!(dead_letters, vec![dead_letter.clone()]);
+
+    let res = dead_letter_repo
+        .find_dead_letter(TEST_PRODUCER, TEST_CONSUMER, OutboxMessageID::new(5))
+        .await;
+    assert_matches!(res, Ok(Some(a_dead_letter)) if a_dead_letter == dead_letter);
+
+    // 2nd push of the same message for the same consumer should fail
+    let res = dead_letter_repo.push_dead_letter(dead_letter.clone()).await;
+    assert_matches!(
+        res,
+        Err(PushDeadLetterError::DuplicateDeadLetter(DuplicateDeadLetterError {
+            producer_name,
+            consumer_name,
+            message_id,
+        })) if producer_name == TEST_PRODUCER
+            && consumer_name == TEST_CONSUMER
+            && message_id == OutboxMessageID::new(5)
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub async fn test_delete_dead_letter(catalog: &Catalog) {
+    let dead_letter_repo = catalog.get_one::<dyn OutboxDeadLetterRepository>().unwrap();
+
+    let dead_letter_1 = make_dead_letter(TEST_PRODUCER, TEST_CONSUMER, 5);
+    let dead_letter_2 = make_dead_letter(TEST_PRODUCER, TEST_CONSUMER, 7);
+
+    for dead_letter in [&dead_letter_1, &dead_letter_2] {
+        let res = dead_letter_repo.push_dead_letter(dead_letter.clone()).await;
+        assert_matches!(res, Ok(_));
+    }
+
+    // Delete
+    let res = dead_letter_repo
+        .delete_dead_letter(TEST_PRODUCER, TEST_CONSUMER, OutboxMessageID::new(5))
+        .await;
+    assert_matches!(res, Ok(_));
+
+    let dead_letters = read_dead_letters(dead_letter_repo.as_ref()).await;
+    assert_eq!(dead_letters, vec![dead_letter_2]);
+
+    // 2nd delete should fail
+    let res = dead_letter_repo
+        .delete_dead_letter(TEST_PRODUCER, TEST_CONSUMER, OutboxMessageID::new(5))
+        .await;
+    assert_matches!(
+        res,
+        Err(DeleteDeadLetterError::NotFound(DeadLetterNotFoundError {
+            producer_name,
+            consumer_name,
+            message_id,
+        })) if producer_name == TEST_PRODUCER
+            && consumer_name == TEST_CONSUMER
+            && message_id == OutboxMessageID::new(5)
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub async fn test_multiple_dead_letters(catalog: &Catalog) {
+    let dead_letter_repo = catalog.get_one::<dyn OutboxDeadLetterRepository>().unwrap();
+
+    for (producer_suffix, consumer_suffix, message_id) in
+        [("B", "X", 3), ("A", "Y", 1), ("A", "X", 2), ("A", "X", 1)]
+    {
+        let dead_letter = make_dead_letter(
+            &format!("{TEST_PRODUCER}_{producer_suffix}"),
+            &format!("{TEST_CONSUMER}_{consumer_suffix}"),
+            message_id,
+        );
+        let res = dead_letter_repo.push_dead_letter(dead_letter).await;
+        assert_matches!(res, Ok(_));
+    }
+
+    let dead_letters = read_dead_letters(dead_letter_repo.as_ref()).await;
+    assert_eq!(
+        dead_letters
+            .iter()
+            .map(|d| (
+                d.producer_name.as_str(),
+                d.consumer_name.as_str(),
+                d.message_id.into_inner()
+            ))
+            .collect::<Vec<_>>(),
+        vec![
+            ("test-producer_A", "test-consumer_X", 1),
+            ("test-producer_A", "test-consumer_X", 2),
+            ("test-producer_A", "test-consumer_Y", 1),
+            ("test-producer_B", "test-consumer_X", 3),
+        ]
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+fn make_dead_letter(producer_name: &str, consumer_name: &str, message_id: i64) -> OutboxDeadLetter {
+    OutboxDeadLetter {
+        producer_name: producer_name.to_string(),
+        consumer_name: consumer_name.to_string(),
+        message_id: OutboxMessageID::new(message_id),
+        content_json: serde_json::json!({ "body": format!("message {message_id}") }),
+        occurred_on: Utc.with_ymd_and_hms(2050, 1, 1, 12, 0, 0).unwrap(),
+        attempts: 5,
+        last_error: "Consumer failed".to_string(),
+        dead_lettered_at: Utc.with_ymd_and_hms(2050, 1, 1, 12, 30, 0).unwrap(),
+    }
+}
+
+async fn read_dead_letters(
+    outbox_dead_letter_repo: &dyn OutboxDeadLetterRepository,
+) -> Vec<OutboxDeadLetter> {
+    use futures::TryStreamExt;
+    outbox_dead_letter_repo
+        .list_dead_letters()
+        .await
+        .unwrap()
+        .try_collect()
+        .await
+        .unwrap()
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
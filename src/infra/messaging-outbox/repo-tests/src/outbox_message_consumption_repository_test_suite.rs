@@ -9,6 +9,7 @@
 
 use std::assert_matches::assert_matches;
 
+use chrono::{TimeZone, Utc};
 use dill::Catalog;
 use messaging_outbox::{
     ConsumptionBoundaryNotFoundError,
@@ -16,6 +17,7 @@ use messaging_outbox::{
     DuplicateConsumptionBoundaryError,
     OutboxMessageConsumptionBoundary,
     OutboxMessageConsumptionRepository,
+    OutboxMessageConsumptionRetry,
     OutboxMessageID,
     UpdateConsumptionBoundaryError,
 };
@@ -224,6 +226,98 @@ pub async fn test_multiple_boundaries(catalog: &Catalog) {
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
+pub async fn test_consumption_retries(catalog: &Catalog) {
+    let consumption_repo = catalog
+        .get_one::<dyn OutboxMessageConsumptionRepository>()
+        .unwrap();
+
+    let retry = OutboxMessageConsumptionRetry {
+        producer_name: TEST_PRODUCER.to_string(),
+        consumer_name: TEST_CONSUMER.to_string(),
+        failed_message_id: OutboxMessageID::new(6),
+        failed_attempts: 1,
+        next_attempt_at: Utc.with_ymd_and_hms(2050, 1, 2, 12, 0, 0).unwrap(),
+    };
+
+    // Retries require a boundary
+    let res = consumption_repo.save_consumption_retry(retry.clone()).await;
+    assert_matches!(
+        res,
+        Err(UpdateConsumptionBoundaryError::ConsumptionBoundaryNotFound(
+            ConsumptionBoundaryNotFoundError {
+                consumer_name,
+                producer_name,
+            }
+        )) if consumer_name == TEST_CONSUMER && producer_name == TEST_PRODUCER
+    );
+
+    let boundary = OutboxMessageConsumptionBoundary {
+        consumer_name: TEST_CONSUMER.to_string(),
+        producer_name: TEST_PRODUCER.to_string(),
+        last_consumed_message_id: OutboxMessageID::new(5),
+    };
+    let res = consumption_repo
+        .create_consumption_boundary(boundary.clone())
+        .await;
+    assert_matches!(res, Ok(_));
+
+    // No retries initially
+    let res = consumption_repo
+        .find_consumption_retry(TEST_CONSUMER, TEST_PRODUCER)
+        .await;
+    assert_matches!(res, Ok(None));
+
+    // Record a couple of failed attempts
+    let res = consumption_repo.save_consumption_retry(retry.clone()).await;
+    assert_matches!(res, Ok(_));
+
+    let retry_2 = OutboxMessageConsumptionRetry {
+        failed_attempts: 2,
+        next_attempt_at: Utc.with_ymd_and_hms(2050, 1, 2, 12, 5, 0).unwrap(),
+        ..retry
+    };
+    let res = consumption_repo
+        .save_consumption_retry(retry_2.clone())
+        .await;
+    assert_matches!(res, Ok(_));
+
+    let res = consumption_repo
+        .find_consumption_retry(TEST_CONSUMER, TEST_PRODUCER)
+        .await;
+    assert_matches!(res, Ok(Some(a_retry)) if a_retry == retry_2);
+
+    let res = consumption_repo
+        .list_consumption_retries(TEST_PRODUCER)
+        .await;
+    assert_matches!(res, Ok(retries) if retries == vec![retry_2.clone()]);
+
+    let res = consumption_repo
+        .list_consumption_retries("another-producer")
+        .await;
+    assert_matches!(res, Ok(retries) if retries.is_empty());
+
+    // Moving the boundary clears the retry
+    let res = consumption_repo
+        .update_consumption_boundary(OutboxMessageConsumptionBoundary {
+            last_consumed_message_id: OutboxMessageID::new(6),
+            ..boundary
+        })
+        .await;
+    assert_matches!(res, Ok(_));
+
+    let res = consumption_repo
+        .find_consumption_retry(TEST_CONSUMER, TEST_PRODUCER)
+        .await;
+    assert_matches!(res, Ok(None));
+
+    let res = consumption_repo
+        .list_consumption_retries(TEST_PRODUCER)
+        .await;
+    assert_matches!(res, Ok(retries) if retries.is_empty());
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
 async fn read_boundaries(
     outbox_message_consumption_repo: &dyn OutboxMessageConsumptionRepository,
 ) -> Vec<OutboxMessageConsumptionBoundary> {
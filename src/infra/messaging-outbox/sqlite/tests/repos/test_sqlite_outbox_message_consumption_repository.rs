@@ -55,6 +55,14 @@ database_transactional_test!(
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
+database_transactional_test!(
+    storage = sqlite,
+    fixture = kamu_messaging_outbox_repo_tests::test_consumption_retries,
+    harness = SqliteOutboxMessageConsumptionRepositoryHarness
+);
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
 struct SqliteOutboxMessageConsumptionRepositoryHarness {
     catalog: Catalog,
 }
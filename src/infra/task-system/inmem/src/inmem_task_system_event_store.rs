@@ -8,6 +8,7 @@
 // by the Apache License, Version 2.0.
 
 use std::collections::hash_map::{Entry, HashMap};
+use std::collections::HashSet;
 
 use dill::*;
 use kamu_task_system::*;
@@ -157,6 +158,34 @@ impl TaskSystemEventStore for InMemoryTaskSystemEventStore {
         let g = state.lock().unwrap();
         Ok(g.tasks_by_dataset.get(dataset_id).map_or(0, Vec::len))
     }
+
+    async fn get_unfinished_tasks(&self) -> TaskIDStream {
+        let unfinished_task_ids: Vec<_> = {
+            let state = self.inner.as_state();
+            let g = state.lock().unwrap();
+
+            let finished_task_ids: HashSet<_> = g
+                .events
+                .iter()
+                .filter_map(|event| match event {
+                    TaskEvent::TaskFinished(e) => Some(e.task_id),
+                    _ => None,
+                })
+                .collect();
+
+            g.events
+                .iter()
+                .filter_map(|event| match event {
+                    TaskEvent::TaskCreated(e) if !finished_task_ids.contains(&e.task_id) => {
+                        Some(Ok(e.task_id))
+                    }
+                    _ => None,
+                })
+                .collect()
+        };
+
+        Box::pin(futures::stream::iter(unfinished_task_ids))
+    }
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -53,6 +53,14 @@ database_transactional_test!(
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
+database_transactional_test!(
+    storage = inmem,
+    fixture = kamu_task_system_repo_tests::test_event_store_get_unfinished_tasks,
+    harness = InMemoryTaskSystemEventStoreHarness
+);
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
 struct InMemoryTaskSystemEventStoreHarness {
     catalog: Catalog,
 }
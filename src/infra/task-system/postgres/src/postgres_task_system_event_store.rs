@@ -214,6 +214,35 @@ impl TaskSystemEventStore for PostgresTaskSystemEventStore {
         let count = usize::try_from(result.count.unwrap()).int_err()?;
         Ok(count)
     }
+
+    /// Returns the tasks that have not reached a final outcome yet, in the
+    /// order of their creation
+    async fn get_unfinished_tasks(&self) -> TaskIDStream {
+        let mut tr = self.transaction.lock().await;
+
+        Box::pin(async_stream::stream! {
+            let connection_mut = tr.connection_mut().await?;
+
+            let mut query_stream = sqlx::query!(
+                r#"
+                SELECT task_id
+                    FROM task_events
+                    WHERE event_type = 'TaskEventCreated'
+                        AND task_id NOT IN (
+                            SELECT task_id FROM task_events WHERE event_type = 'TaskEventFinished'
+                        )
+                    ORDER BY task_id
+                "#,
+            )
+            .try_map(|event_row| Ok(TaskID::new(event_row.task_id)))
+            .fetch(connection_mut)
+            .map_err(ErrorIntoInternal::int_err);
+
+            while let Some(task_id) = query_stream.try_next().await? {
+                yield Ok(task_id);
+            }
+        })
+    }
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
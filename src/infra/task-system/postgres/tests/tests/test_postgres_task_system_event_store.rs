@@ -55,6 +55,14 @@ database_transactional_test!(
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
+database_transactional_test!(
+    storage = postgres,
+    fixture = kamu_task_system_repo_tests::test_event_store_get_unfinished_tasks,
+    harness = PostgresTaskSystemEventStoreHarness
+);
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
 struct PostgresTaskSystemEventStoreHarness {
     catalog: Catalog,
 }
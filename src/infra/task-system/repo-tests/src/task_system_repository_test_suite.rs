@@ -503,3 +503,76 @@ pub async fn test_event_store_get_dataset_tasks(catalog: &Catalog) {
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub async fn test_event_store_get_unfinished_tasks(catalog: &Catalog) {
+    let event_store = catalog.get_one::<dyn TaskSystemEventStore>().unwrap();
+
+    let task_ids: Vec<_> = event_store
+        .get_unfinished_tasks()
+        .await
+        .try_collect()
+        .await
+        .unwrap();
+    assert_eq!(task_ids, []);
+
+    let task_id_1 = event_store.new_task_id().await.unwrap();
+    let task_id_2 = event_store.new_task_id().await.unwrap();
+    let task_id_3 = event_store.new_task_id().await.unwrap();
+
+    for task_id in [task_id_1, task_id_2, task_id_3] {
+        event_store
+            .save_events(
+                &task_id,
+                vec![TaskEventCreated {
+                    event_time: Utc::now(),
+                    task_id,
+                    logical_plan: Probe::default().into(),
+                }
+                .into()],
+            )
+            .await
+            .unwrap();
+    }
+
+    event_store
+        .save_events(
+            &task_id_1,
+            vec![TaskEventRunning {
+                event_time: Utc::now(),
+                task_id: task_id_1,
+            }
+            .into()],
+        )
+        .await
+        .unwrap();
+
+    event_store
+        .save_events(
+            &task_id_2,
+            vec![
+                TaskEventRunning {
+                    event_time: Utc::now(),
+                    task_id: task_id_2,
+                }
+                .into(),
+                TaskEventFinished {
+                    event_time: Utc::now(),
+                    task_id: task_id_2,
+                    outcome: TaskOutcome::Success(TaskResult::Empty),
+                }
+                .into(),
+            ],
+        )
+        .await
+        .unwrap();
+
+    let task_ids: Vec<_> = event_store
+        .get_unfinished_tasks()
+        .await
+        .try_collect()
+        .await
+        .unwrap();
+    assert_eq!(task_ids, [task_id_1, task_id_3]);
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -55,6 +55,14 @@ database_transactional_test!(
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
+database_transactional_test!(
+    storage = sqlite,
+    fixture = kamu_task_system_repo_tests::test_event_store_get_unfinished_tasks,
+    harness = SqliteTaskSystemEventStoreHarness
+);
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
 struct SqliteTaskSystemEventStoreHarness {
     catalog: Catalog,
 }
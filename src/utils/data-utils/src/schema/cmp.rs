@@ -9,7 +9,7 @@
 
 use std::sync::Arc;
 
-use datafusion::arrow::datatypes::{Field, Schema};
+use datafusion::arrow::datatypes::{DataType, Field, Schema};
 
 /// Compare schemas optionally performing some normalization
 pub fn assert_schemas_equal(lhs: &Schema, rhs: &Schema, ignore_nullability: bool) {
@@ -31,3 +31,244 @@ pub fn assert_schemas_equal(lhs: &Schema, rhs: &Schema, ignore_nullability: bool
     );
     assert_eq!(lhs, rhs);
 }
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// Schema evolution
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Change of a single column between two versions of a schema
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SchemaFieldChange {
+    /// Column exists only in the new schema
+    Added {
+        name: String,
+        data_type: DataType,
+        nullable: bool,
+    },
+    /// Column exists only in the old schema
+    Removed { name: String, data_type: DataType },
+    /// Column has a different type in the new schema
+    TypeChanged {
+        name: String,
+        old_type: DataType,
+        new_type: DataType,
+    },
+    /// Column has a different nullability in the new schema
+    NullabilityChanged { name: String, nullable: bool },
+}
+
+impl SchemaFieldChange {
+    /// Whether the data written under the old schema can still be read under
+    /// the new one. Compatible changes are: adding a nullable column, widening
+    /// a numeric type, and relaxing nullability.
+    pub fn is_compatible(&self) -> bool {
+        match self {
+            Self::Added { nullable, .. } | Self::NullabilityChanged { nullable, .. } => *nullable,
+            Self::Removed { .. } => false,
+            Self::TypeChanged {
+                old_type, new_type, ..
+            } => is_widening(old_type, new_type),
+        }
+    }
+}
+
+impl std::fmt::Display for SchemaFieldChange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let nullability = |nullable: bool| if nullable { "NULL" } else { "NOT NULL" };
+
+        match self {
+            Self::Added {
+                name,
+                data_type,
+                nullable,
+            } => write!(f, "+ {name}: {data_type} {}", nullability(*nullable)),
+            Self::Removed { name, data_type } => write!(f, "- {name}: {data_type}"),
+            Self::TypeChanged {
+                name,
+                old_type,
+                new_type,
+            } => write!(f, "~ {name}: {old_type} -> {new_type}"),
+            Self::NullabilityChanged { name, nullable } => write!(
+                f,
+                "~ {name}: {} -> {}",
+                nullability(!*nullable),
+                nullability(*nullable)
+            ),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Difference between two versions of a schema. Columns are matched by name,
+/// so the order of columns is not taken into account.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SchemaDiff {
+    pub changes: Vec<SchemaFieldChange>,
+}
+
+impl SchemaDiff {
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    pub fn is_compatible(&self) -> bool {
+        self.changes.iter().all(SchemaFieldChange::is_compatible)
+    }
+}
+
+impl std::fmt::Display for SchemaDiff {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for change in &self.changes {
+            if change.is_compatible() {
+                writeln!(f, "{change}")?;
+            } else {
+                writeln!(f, "{change} (incompatible)")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Lists changes between the `old` and the `new` versions of a schema
+pub fn diff_schemas(old: &Schema, new: &Schema) -> SchemaDiff {
+    let mut changes = Vec::new();
+
+    for old_field in old.fields() {
+        let Ok(new_field) = new.field_with_name(old_field.name()) else {
+            changes.push(SchemaFieldChange::Removed {
+                name: old_field.name().clone(),
+                data_type: old_field.data_type().clone(),
+            });
+            continue;
+        };
+
+        if old_field.data_type() != new_field.data_type() {
+            changes.push(SchemaFieldChange::TypeChanged {
+                name: old_field.name().clone(),
+                old_type: old_field.data_type().clone(),
+                new_type: new_field.data_type().clone(),
+            });
+        }
+
+        if old_field.is_nullable() != new_field.is_nullable() {
+            changes.push(SchemaFieldChange::NullabilityChanged {
+                name: old_field.name().clone(),
+                nullable: new_field.is_nullable(),
+            });
+        }
+    }
+
+    for new_field in new.fields() {
+        if old.field_with_name(new_field.name()).is_err() {
+            changes.push(SchemaFieldChange::Added {
+                name: new_field.name().clone(),
+                data_type: new_field.data_type().clone(),
+                nullable: new_field.is_nullable(),
+            });
+        }
+    }
+
+    SchemaDiff { changes }
+}
+
+/// Computes the schema under which both the data written under the `prev`
+/// schema and the new data of `new` schema can be stored.
+///
+/// Unlike [`diff_schemas`] this accepts new data that is stricter than `prev`
+/// (narrower types, non-nullable columns), as such data can be cast to the
+/// `prev` schema. Columns that are only present in the new data are added as
+/// nullable, since records written before don't have values for them.
+///
+/// Returns `None` if the new data can be stored under the `prev` schema as is,
+/// and a diff of incompatible changes if no such schema exists.
+pub fn evolve_schema(prev: &Schema, new: &Schema) -> Result<Option<Schema>, SchemaDiff> {
+    let mut fields = Vec::with_capacity(prev.fields().len());
+    let mut incompatible = Vec::new();
+    let mut changed = false;
+
+    for prev_field in prev.fields() {
+        let Ok(new_field) = new.field_with_name(prev_field.name()) else {
+            incompatible.push(SchemaFieldChange::Removed {
+                name: prev_field.name().clone(),
+                data_type: prev_field.data_type().clone(),
+            });
+            continue;
+        };
+
+        let data_type = if prev_field.data_type() == new_field.data_type()
+            || is_widening(new_field.data_type(), prev_field.data_type())
+        {
+            prev_field.data_type().clone()
+        } else if is_widening(prev_field.data_type(), new_field.data_type()) {
+            changed = true;
+            new_field.data_type().clone()
+        } else {
+            incompatible.push(SchemaFieldChange::TypeChanged {
+                name: prev_field.name().clone(),
+                old_type: prev_field.data_type().clone(),
+                new_type: new_field.data_type().clone(),
+            });
+            continue;
+        };
+
+        let nullable = prev_field.is_nullable() || new_field.is_nullable();
+        changed |= nullable != prev_field.is_nullable();
+
+        fields.push(
+            prev_field
+                .as_ref()
+                .clone()
+                .with_data_type(data_type)
+                .with_nullable(nullable),
+        );
+    }
+
+    for new_field in new.fields() {
+        if prev.field_with_name(new_field.name()).is_err() {
+            changed = true;
+            fields.push(new_field.as_ref().clone().with_nullable(true));
+        }
+    }
+
+    if !incompatible.is_empty() {
+        return Err(SchemaDiff {
+            changes: incompatible,
+        });
+    }
+
+    if !changed {
+        return Ok(None);
+    }
+
+    Ok(Some(Schema::new_with_metadata(
+        fields,
+        prev.metadata().clone(),
+    )))
+}
+
+/// Whether all values of the `from` type can be represented by the `to` type
+/// without loss of precision
+pub fn is_widening(from: &DataType, to: &DataType) -> bool {
+    use DataType::*;
+
+    match (from, to) {
+        (Int8, Int16 | Int32 | Int64 | Float32 | Float64)
+        | (Int16, Int32 | Int64 | Float32 | Float64)
+        | (Int32, Int64 | Float64)
+        | (UInt8, UInt16 | UInt32 | UInt64 | Int16 | Int32 | Int64 | Float32 | Float64)
+        | (UInt16, UInt32 | UInt64 | Int32 | Int64 | Float32 | Float64)
+        | (UInt32, UInt64 | Int64 | Float64)
+        | (Float16, Float32 | Float64)
+        | (Float32, Float64) => true,
+        (Decimal128(from_precision, from_scale), Decimal128(to_precision, to_scale)) => {
+            to_scale >= from_scale
+                && i16::from(*to_precision) - i16::from(*to_scale)
+                    >= i16::from(*from_precision) - i16::from(*from_scale)
+                && (to_precision, to_scale) != (from_precision, from_scale)
+        }
+        _ => false,
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -7,6 +7,9 @@
 // the Business Source License, use of this software will be governed
 // by the Apache License, Version 2.0.
 
+use datafusion::arrow::datatypes::{DataType, Field, Schema};
+use kamu_data_utils::schema::cmp::*;
+
 #[test_log::test(tokio::test)]
 async fn test_parse_ddl() {
     let ctx = datafusion::prelude::SessionContext::new();
@@ -46,3 +49,128 @@ message arrow_schema {
         "#,
     );
 }
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+fn schema(fields: &[(&str, DataType, bool)]) -> Schema {
+    Schema::new(
+        fields
+            .iter()
+            .map(|(name, data_type, nullable)| Field::new(*name, data_type.clone(), *nullable))
+            .collect::<Vec<_>>(),
+    )
+}
+
+#[test]
+fn test_diff_schemas_compatible() {
+    let old = schema(&[("a", DataType::Int32, false), ("b", DataType::Utf8, false)]);
+    let new = schema(&[
+        ("a", DataType::Int64, false),
+        ("b", DataType::Utf8, true),
+        ("c", DataType::Float64, true),
+    ]);
+
+    let diff = diff_schemas(&old, &new);
+
+    assert!(diff.is_compatible());
+    assert_eq!(
+        diff.to_string(),
+        indoc::indoc!(
+            r#"
+            ~ a: Int32 -> Int64
+            ~ b: NOT NULL -> NULL
+            + c: Float64 NULL
+            "#
+        )
+    );
+}
+
+#[test]
+fn test_diff_schemas_incompatible() {
+    let old = schema(&[("a", DataType::Int64, true), ("b", DataType::Utf8, false)]);
+    let new = schema(&[
+        ("a", DataType::Int32, true),
+        ("c", DataType::Float64, false),
+    ]);
+
+    let diff = diff_schemas(&old, &new);
+
+    assert!(!diff.is_compatible());
+    assert_eq!(
+        diff.to_string(),
+        indoc::indoc!(
+            r#"
+            ~ a: Int64 -> Int32 (incompatible)
+            - b: Utf8 (incompatible)
+            + c: Float64 NOT NULL (incompatible)
+            "#
+        )
+    );
+}
+
+#[test]
+fn test_evolve_schema() {
+    let prev = schema(&[
+        ("a", DataType::Int32, false),
+        ("b", DataType::Float64, false),
+    ]);
+
+    // Stricter data fits into the previous schema
+    let new = schema(&[
+        ("a", DataType::Int16, false),
+        ("b", DataType::Float32, false),
+    ]);
+    assert_eq!(evolve_schema(&prev, &new), Ok(None));
+
+    // Widening, relaxed nullability and new columns produce a new schema
+    let new = schema(&[
+        ("a", DataType::Int64, false),
+        ("b", DataType::Float64, true),
+        ("c", DataType::Utf8, false),
+    ]);
+    assert_eq!(
+        evolve_schema(&prev, &new),
+        Ok(Some(schema(&[
+            ("a", DataType::Int64, false),
+            ("b", DataType::Float64, true),
+            ("c", DataType::Utf8, true),
+        ])))
+    );
+
+    // Missing columns and incompatible types are reported
+    let new = schema(&[("a", DataType::Utf8, false)]);
+    assert_eq!(
+        evolve_schema(&prev, &new).unwrap_err().changes,
+        vec![
+            SchemaFieldChange::TypeChanged {
+                name: "a".to_string(),
+                old_type: DataType::Int32,
+                new_type: DataType::Utf8,
+            },
+            SchemaFieldChange::Removed {
+                name: "b".to_string(),
+                data_type: DataType::Float64,
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_is_widening() {
+    assert!(is_widening(&DataType::Int8, &DataType::Int64));
+    assert!(is_widening(&DataType::UInt32, &DataType::Int64));
+    assert!(is_widening(&DataType::Float32, &DataType::Float64));
+    assert!(is_widening(
+        &DataType::Decimal128(10, 2),
+        &DataType::Decimal128(12, 4)
+    ));
+
+    assert!(!is_widening(&DataType::Int64, &DataType::Int32));
+    assert!(!is_widening(&DataType::Int64, &DataType::Float64));
+    assert!(!is_widening(&DataType::UInt64, &DataType::Int64));
+    assert!(!is_widening(
+        &DataType::Decimal128(10, 2),
+        &DataType::Decimal128(10, 4)
+    ));
+    assert!(!is_widening(&DataType::Int32, &DataType::Int32));
+}
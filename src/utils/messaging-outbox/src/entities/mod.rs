@@ -7,8 +7,10 @@
 // the Business Source License, use of this software will be governed
 // by the Apache License, Version 2.0.
 
+mod outbox_dead_letter;
 mod outbox_message;
 mod outbox_message_id;
 
+pub use outbox_dead_letter::*;
 pub use outbox_message::*;
 pub use outbox_message_id::*;
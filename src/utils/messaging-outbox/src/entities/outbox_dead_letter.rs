@@ -0,0 +1,52 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use chrono::{DateTime, Utc};
+
+use crate::{OutboxMessage, OutboxMessageID};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// A message that a durable consumer failed to process after exhausting all
+/// retry attempts. The consumption boundary of the consumer is moved past such
+/// message, so it is kept aside until an operator replays or discards it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OutboxDeadLetter {
+    pub producer_name: String,
+    pub consumer_name: String,
+    pub message_id: OutboxMessageID,
+    pub content_json: serde_json::Value,
+    pub occurred_on: DateTime<Utc>,
+    pub attempts: i32,
+    pub last_error: String,
+    pub dead_lettered_at: DateTime<Utc>,
+}
+
+impl OutboxDeadLetter {
+    pub fn new(
+        message: &OutboxMessage,
+        consumer_name: &str,
+        attempts: i32,
+        last_error: String,
+        dead_lettered_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            producer_name: message.producer_name.clone(),
+            consumer_name: consumer_name.to_string(),
+            message_id: message.message_id,
+            content_json: message.content_json.clone(),
+            occurred_on: message.occurred_on,
+            attempts,
+            last_error,
+            dead_lettered_at,
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
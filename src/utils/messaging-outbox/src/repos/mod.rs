@@ -7,8 +7,10 @@
 // the Business Source License, use of this software will be governed
 // by the Apache License, Version 2.0.
 
+mod outbox_dead_letter_repository;
 mod outbox_message_consumption_repository;
 mod outbox_message_repository;
 
+pub use outbox_dead_letter_repository::*;
 pub use outbox_message_consumption_repository::*;
 pub use outbox_message_repository::*;
@@ -0,0 +1,92 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use internal_error::InternalError;
+use thiserror::Error;
+
+use crate::{OutboxDeadLetter, OutboxMessageID};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[async_trait::async_trait]
+pub trait OutboxDeadLetterRepository: Send + Sync {
+    async fn push_dead_letter(
+        &self,
+        dead_letter: OutboxDeadLetter,
+    ) -> Result<(), PushDeadLetterError>;
+
+    /// Lists dead letters ordered by producer, consumer and message ID
+    async fn list_dead_letters(&self) -> Result<OutboxDeadLetterStream, InternalError>;
+
+    async fn find_dead_letter(
+        &self,
+        producer_name: &str,
+        consumer_name: &str,
+        message_id: OutboxMessageID,
+    ) -> Result<Option<OutboxDeadLetter>, InternalError>;
+
+    async fn delete_dead_letter(
+        &self,
+        producer_name: &str,
+        consumer_name: &str,
+        message_id: OutboxMessageID,
+    ) -> Result<(), DeleteDeadLetterError>;
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Error, Debug)]
+pub enum PushDeadLetterError {
+    #[error(transparent)]
+    DuplicateDeadLetter(DuplicateDeadLetterError),
+
+    #[error(transparent)]
+    Internal(InternalError),
+}
+
+#[derive(Error, Debug)]
+#[error(
+    "Message {message_id} of producer '{producer_name}' is already dead-lettered for consumer \
+     '{consumer_name}'"
+)]
+pub struct DuplicateDeadLetterError {
+    pub producer_name: String,
+    pub consumer_name: String,
+    pub message_id: OutboxMessageID,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Error, Debug)]
+pub enum DeleteDeadLetterError {
+    #[error(transparent)]
+    NotFound(DeadLetterNotFoundError),
+
+    #[error(transparent)]
+    Internal(InternalError),
+}
+
+#[derive(Error, Debug)]
+#[error(
+    "Message {message_id} of producer '{producer_name}' is not dead-lettered for consumer \
+     '{consumer_name}'"
+)]
+pub struct DeadLetterNotFoundError {
+    pub producer_name: String,
+    pub consumer_name: String,
+    pub message_id: OutboxMessageID,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub type OutboxDeadLetterStream<'a> = std::pin::Pin<
+    Box<dyn tokio_stream::Stream<Item = Result<OutboxDeadLetter, InternalError>> + Send + 'a>,
+>;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
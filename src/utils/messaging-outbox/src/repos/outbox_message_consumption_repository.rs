@@ -7,6 +7,7 @@
 // the Business Source License, use of this software will be governed
 // by the Apache License, Version 2.0.
 
+use chrono::{DateTime, Utc};
 use internal_error::InternalError;
 use thiserror::Error;
 
@@ -35,6 +36,24 @@ pub trait OutboxMessageConsumptionRepository: Send + Sync {
         &self,
         boundary: OutboxMessageConsumptionBoundary,
     ) -> Result<(), UpdateConsumptionBoundaryError>;
+
+    async fn find_consumption_retry(
+        &self,
+        consumer_name: &str,
+        producer_name: &str,
+    ) -> Result<Option<OutboxMessageConsumptionRetry>, InternalError>;
+
+    async fn list_consumption_retries(
+        &self,
+        producer_name: &str,
+    ) -> Result<Vec<OutboxMessageConsumptionRetry>, InternalError>;
+
+    /// Records a failed delivery attempt. The retry state is cleared
+    /// automatically once the consumption boundary moves forward
+    async fn save_consumption_retry(
+        &self,
+        retry: OutboxMessageConsumptionRetry,
+    ) -> Result<(), UpdateConsumptionBoundaryError>;
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -48,6 +67,17 @@ pub struct OutboxMessageConsumptionBoundary {
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct OutboxMessageConsumptionRetry {
+    pub producer_name: String,
+    pub consumer_name: String,
+    pub failed_message_id: OutboxMessageID,
+    pub failed_attempts: i32,
+    pub next_attempt_at: DateTime<Utc>,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
 #[derive(Error, Debug)]
 pub enum CreateConsumptionBoundaryError {
     #[error(transparent)]
@@ -0,0 +1,105 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::sync::Arc;
+
+use dill::{component, interface, Catalog};
+use internal_error::{InternalError, ResultIntoInternal};
+
+use crate::*;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub struct OutboxDeadLetterServiceImpl {
+    catalog: Catalog,
+    dead_letter_repository: Arc<dyn OutboxDeadLetterRepository>,
+    message_dispatchers: Vec<Arc<dyn MessageDispatcher>>,
+}
+
+#[component(pub)]
+#[interface(dyn OutboxDeadLetterService)]
+impl OutboxDeadLetterServiceImpl {
+    pub fn new(
+        catalog: Catalog,
+        dead_letter_repository: Arc<dyn OutboxDeadLetterRepository>,
+        message_dispatchers: Vec<Arc<dyn MessageDispatcher>>,
+    ) -> Self {
+        Self {
+            catalog,
+            dead_letter_repository,
+            message_dispatchers,
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl OutboxDeadLetterService for OutboxDeadLetterServiceImpl {
+    #[tracing::instrument(level = "debug", skip_all)]
+    async fn list_dead_letters(&self) -> Result<Vec<OutboxDeadLetter>, InternalError> {
+        use futures::TryStreamExt;
+        self.dead_letter_repository
+            .list_dead_letters()
+            .await?
+            .try_collect()
+            .await
+    }
+
+    #[tracing::instrument(level = "info", skip_all, fields(producer_name, consumer_name, %message_id))]
+    async fn replay_dead_letter(
+        &self,
+        producer_name: &str,
+        consumer_name: &str,
+        message_id: OutboxMessageID,
+    ) -> Result<(), ReplayDeadLetterError> {
+        let Some(dead_letter) = self
+            .dead_letter_repository
+            .find_dead_letter(producer_name, consumer_name, message_id)
+            .await
+            .map_err(ReplayDeadLetterError::Internal)?
+        else {
+            return Err(ReplayDeadLetterError::NotFound(DeadLetterNotFoundError {
+                producer_name: producer_name.to_string(),
+                consumer_name: consumer_name.to_string(),
+                message_id,
+            }));
+        };
+
+        let dispatcher = self
+            .message_dispatchers
+            .iter()
+            .find(|d| d.get_producer_name() == producer_name)
+            .ok_or_else(|| format!("No dispatcher for producer '{producer_name}'"))
+            .int_err()
+            .map_err(ReplayDeadLetterError::Internal)?;
+
+        let content_json = dead_letter.content_json.to_string();
+        dispatcher
+            .dispatch_message(
+                &self.catalog,
+                ConsumerFilter::SelectedConsumer(consumer_name),
+                &content_json,
+            )
+            .await
+            .map_err(|e| {
+                ReplayDeadLetterError::ConsumerFailed(ReplayConsumerFailedError {
+                    consumer_name: consumer_name.to_string(),
+                    message_id,
+                    reason: e.reason(),
+                })
+            })?;
+
+        self.dead_letter_repository
+            .delete_dead_letter(producer_name, consumer_name, message_id)
+            .await?;
+
+        Ok(())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -12,10 +12,12 @@ mod testing;
 
 mod outbox;
 mod outbox_config;
+mod outbox_dead_letter_service;
 mod outbox_transactional_processor;
 
 pub use implementation::*;
 pub use outbox::*;
 pub use outbox_config::*;
+pub use outbox_dead_letter_service::*;
 pub use outbox_transactional_processor::*;
 pub use testing::*;
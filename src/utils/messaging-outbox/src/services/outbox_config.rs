@@ -18,15 +18,42 @@ pub struct OutboxConfig {
     pub awaiting_step: Duration,
     /// Defines maximum number of messages attempted to read in 1 step
     pub batch_size: i64,
+    /// Defines how many times a durable consumer is invoked with the same
+    /// message before the message is moved to dead letters
+    pub max_attempts: u32,
+    /// Delay before the first retry of a failed message, doubled with every
+    /// next attempt
+    pub retry_backoff_base: Duration,
+    /// Upper bound for the delay between retries
+    pub retry_backoff_max: Duration,
 }
 
 impl OutboxConfig {
-    pub fn new(awaiting_step: Duration, batch_size: i64) -> Self {
+    pub fn new(
+        awaiting_step: Duration,
+        batch_size: i64,
+        max_attempts: u32,
+        retry_backoff_base: Duration,
+        retry_backoff_max: Duration,
+    ) -> Self {
         Self {
             awaiting_step,
             batch_size,
+            max_attempts,
+            retry_backoff_base,
+            retry_backoff_max,
         }
     }
+
+    /// Returns the delay to wait before the next attempt, given the number of
+    /// attempts that have already failed
+    pub fn retry_backoff(&self, failed_attempts: u32) -> Duration {
+        let exponent = failed_attempts.saturating_sub(1).min(30);
+        self.retry_backoff_base
+            .checked_mul(1 << exponent)
+            .unwrap_or(self.retry_backoff_max)
+            .min(self.retry_backoff_max)
+    }
 }
 
 impl Default for OutboxConfig {
@@ -34,6 +61,9 @@ impl Default for OutboxConfig {
         Self {
             awaiting_step: Duration::seconds(1),
             batch_size: 20,
+            max_attempts: 5,
+            retry_backoff_base: Duration::seconds(1),
+            retry_backoff_max: Duration::minutes(5),
         }
     }
 }
@@ -0,0 +1,62 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use internal_error::InternalError;
+use thiserror::Error;
+
+use crate::{DeadLetterNotFoundError, DeleteDeadLetterError, OutboxDeadLetter, OutboxMessageID};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[async_trait::async_trait]
+pub trait OutboxDeadLetterService: Send + Sync {
+    async fn list_dead_letters(&self) -> Result<Vec<OutboxDeadLetter>, InternalError>;
+
+    /// Delivers a dead-lettered message to its consumer once again and removes
+    /// it from dead letters if the consumer succeeds
+    async fn replay_dead_letter(
+        &self,
+        producer_name: &str,
+        consumer_name: &str,
+        message_id: OutboxMessageID,
+    ) -> Result<(), ReplayDeadLetterError>;
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Error, Debug)]
+pub enum ReplayDeadLetterError {
+    #[error(transparent)]
+    NotFound(DeadLetterNotFoundError),
+
+    #[error(transparent)]
+    ConsumerFailed(ReplayConsumerFailedError),
+
+    #[error(transparent)]
+    Internal(InternalError),
+}
+
+impl From<DeleteDeadLetterError> for ReplayDeadLetterError {
+    fn from(value: DeleteDeadLetterError) -> Self {
+        match value {
+            DeleteDeadLetterError::NotFound(e) => Self::NotFound(e),
+            DeleteDeadLetterError::Internal(e) => Self::Internal(e),
+        }
+    }
+}
+
+#[derive(Error, Debug)]
+#[error("Consumer '{consumer_name}' failed to process message {message_id} again: {reason}")]
+pub struct ReplayConsumerFailedError {
+    pub consumer_name: String,
+    pub message_id: OutboxMessageID,
+    pub reason: String,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
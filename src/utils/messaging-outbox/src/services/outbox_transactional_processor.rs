@@ -13,6 +13,7 @@ use std::sync::Arc;
 use database_common::DatabaseTransactionRunner;
 use dill::{component, scope, Catalog, Singleton};
 use internal_error::{InternalError, ResultIntoInternal};
+use time_source::SystemTimeSource;
 
 use crate::*;
 
@@ -31,6 +32,7 @@ impl OutboxTransactionalProcessor {
     pub fn new(
         catalog: Catalog,
         config: Arc<OutboxConfig>,
+        time_source: Arc<dyn SystemTimeSource>,
         message_dispatchers_by_producers: Vec<Arc<dyn MessageDispatcher>>,
     ) -> Self {
         let routes_static_info = Arc::new(Self::make_static_routes_info(
@@ -43,6 +45,7 @@ impl OutboxTransactionalProcessor {
             producer_relay_jobs.push(ProducerRelayJob::new(
                 catalog.clone(),
                 config.clone(),
+                time_source.clone(),
                 routes_static_info.clone(),
                 producer_name.clone(),
                 consumer_names.clone(),
@@ -290,6 +293,7 @@ impl RoutesStaticInfo {
 struct ProducerRelayJob {
     catalog: Catalog,
     config: Arc<OutboxConfig>,
+    time_source: Arc<dyn SystemTimeSource>,
     relay_routes_static_info: Arc<RoutesStaticInfo>,
     producer_name: String,
     consumer_names: Vec<String>,
@@ -299,6 +303,7 @@ impl ProducerRelayJob {
     fn new(
         catalog: Catalog,
         config: Arc<OutboxConfig>,
+        time_source: Arc<dyn SystemTimeSource>,
         relay_routes_static_info: Arc<RoutesStaticInfo>,
         producer_name: String,
         consumer_names: Vec<String>,
@@ -306,6 +311,7 @@ impl ProducerRelayJob {
         Self {
             catalog,
             config,
+            time_source,
             relay_routes_static_info,
             producer_name,
             consumer_names,
@@ -340,6 +346,9 @@ impl ProducerRelayJob {
                 )
                 .await?;
 
+            // Consumers that are waiting for a retry must not see any later messages
+            let mut postponed_consumers = self.select_consumers_awaiting_retry().await?;
+
             // Feed consumers if they are behind this message
             // We must respect the sequential order of messages,
             // but individual consumers may process each message concurrently
@@ -347,6 +356,9 @@ impl ProducerRelayJob {
                 // Prepare consumer invocation tasks
                 let mut consumer_tasks = Vec::new();
                 for consumer_name in &self.consumer_names {
+                    if postponed_consumers.contains(consumer_name) {
+                        continue;
+                    }
                     let boundary_id = consumption_boundaries
                         .get(consumer_name)
                         .copied()
@@ -356,18 +368,22 @@ impl ProducerRelayJob {
                     }
                 }
 
-                // Consume concurrently
-                use futures::{StreamExt, TryStreamExt};
-                futures::stream::iter(consumer_tasks)
-                    .map(Ok)
-                    .try_for_each_concurrent(
-                        /* limit */ None,
+                // Consume concurrently. A failure of one consumer must not affect the others,
+                // so each delivery is awaited independently
+                let delivery_outcomes =
+                    futures::future::try_join_all(consumer_tasks.into_iter().map(
                         |(consumer_name, message)| async move {
-                            self.invoke_consumer(consumer_name, message).await?;
-                            Ok(())
+                            let outcome = self.deliver_to_consumer(consumer_name, message).await?;
+                            Ok::<_, InternalError>((consumer_name, outcome))
                         },
-                    )
+                    ))
                     .await?;
+
+                for (consumer_name, outcome) in delivery_outcomes {
+                    if outcome == DeliveryOutcome::Postponed {
+                        postponed_consumers.insert(consumer_name.to_string());
+                    }
+                }
             }
         }
 
@@ -399,6 +415,25 @@ impl ProducerRelayJob {
         earliest_seen_id
     }
 
+    async fn select_consumers_awaiting_retry(&self) -> Result<HashSet<String>, InternalError> {
+        let retries = DatabaseTransactionRunner::new(self.catalog.clone())
+            .transactional_with(
+                |outbox_consumption_repository: Arc<dyn OutboxMessageConsumptionRepository>| async move {
+                    outbox_consumption_repository
+                        .list_consumption_retries(&self.producer_name)
+                        .await
+                },
+            )
+            .await?;
+
+        let now = self.time_source.now();
+        Ok(retries
+            .into_iter()
+            .filter(|retry| retry.next_attempt_at > now)
+            .map(|retry| retry.consumer_name)
+            .collect())
+    }
+
     #[tracing::instrument(level = "debug", skip_all, fields(above_id, batch_size))]
     async fn load_messages_above(
         &self,
@@ -419,6 +454,86 @@ impl ProducerRelayJob {
             .await
     }
 
+    async fn deliver_to_consumer(
+        &self,
+        consumer_name: &str,
+        message: &OutboxMessage,
+    ) -> Result<DeliveryOutcome, InternalError> {
+        // Moving the boundary forward also clears any retry state
+        let error = match self.invoke_consumer(consumer_name, message).await {
+            Ok(()) => return Ok(DeliveryOutcome::Consumed),
+            Err(e) => e,
+        };
+
+        let maybe_retry = DatabaseTransactionRunner::new(self.catalog.clone())
+            .transactional_with(
+                |outbox_consumption_repository: Arc<dyn OutboxMessageConsumptionRepository>| async move {
+                    outbox_consumption_repository
+                        .find_consumption_retry(consumer_name, &self.producer_name)
+                        .await
+                },
+            )
+            .await?;
+
+        let failed_attempts = match maybe_retry {
+            Some(retry) if retry.failed_message_id == message.message_id => {
+                u32::try_from(retry.failed_attempts).unwrap_or(0) + 1
+            }
+            _ => 1,
+        };
+
+        if failed_attempts >= self.config.max_attempts {
+            tracing::error!(
+                producer_name = %self.producer_name,
+                consumer_name,
+                message_id = %message.message_id,
+                failed_attempts,
+                error = ?error,
+                error_msg = %error.reason(),
+                "Consumer failed to process message, moving it to dead letters",
+            );
+
+            self.dead_letter_message(consumer_name, message, failed_attempts, error.reason())
+                .await?;
+
+            return Ok(DeliveryOutcome::DeadLettered);
+        }
+
+        let backoff = self.config.retry_backoff(failed_attempts);
+
+        tracing::warn!(
+            producer_name = %self.producer_name,
+            consumer_name,
+            message_id = %message.message_id,
+            failed_attempts,
+            retry_in = %backoff,
+            error = ?error,
+            error_msg = %error.reason(),
+            "Consumer failed to process message, will retry",
+        );
+
+        let retry = OutboxMessageConsumptionRetry {
+            producer_name: self.producer_name.clone(),
+            consumer_name: consumer_name.to_string(),
+            failed_message_id: message.message_id,
+            failed_attempts: i32::try_from(failed_attempts).unwrap_or(i32::MAX),
+            next_attempt_at: self.time_source.now() + backoff,
+        };
+
+        DatabaseTransactionRunner::new(self.catalog.clone())
+            .transactional_with(
+                |outbox_consumption_repository: Arc<dyn OutboxMessageConsumptionRepository>| async move {
+                    outbox_consumption_repository
+                        .save_consumption_retry(retry)
+                        .await
+                        .int_err()
+                },
+            )
+            .await?;
+
+        Ok(DeliveryOutcome::Postponed)
+    }
+
     #[tracing::instrument(level = "debug", skip_all, fields(consumer_name, ?message, is_first_consumption))]
     async fn invoke_consumer(
         &self,
@@ -461,6 +576,59 @@ impl ProducerRelayJob {
             })
             .await
     }
+
+    #[tracing::instrument(level = "debug", skip_all, fields(consumer_name, ?message, failed_attempts))]
+    async fn dead_letter_message(
+        &self,
+        consumer_name: &str,
+        message: &OutboxMessage,
+        failed_attempts: u32,
+        last_error: String,
+    ) -> Result<(), InternalError> {
+        let dead_letter = OutboxDeadLetter::new(
+            message,
+            consumer_name,
+            i32::try_from(failed_attempts).unwrap_or(i32::MAX),
+            last_error,
+            self.time_source.now(),
+        );
+
+        DatabaseTransactionRunner::new(self.catalog.clone())
+            .transactional(|transaction_catalog| async move {
+                let dead_letter_repository = transaction_catalog
+                    .get_one::<dyn OutboxDeadLetterRepository>()
+                    .unwrap();
+                dead_letter_repository
+                    .push_dead_letter(dead_letter)
+                    .await
+                    .int_err()?;
+
+                // Let the consumer proceed with the next messages
+                let consumption_repository = transaction_catalog
+                    .get_one::<dyn OutboxMessageConsumptionRepository>()
+                    .unwrap();
+                consumption_repository
+                    .update_consumption_boundary(OutboxMessageConsumptionBoundary {
+                        consumer_name: consumer_name.to_string(),
+                        producer_name: message.producer_name.to_string(),
+                        last_consumed_message_id: message.message_id,
+                    })
+                    .await
+                    .int_err()?;
+
+                Ok(())
+            })
+            .await
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum DeliveryOutcome {
+    Consumed,
+    Postponed,
+    DeadLettered,
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -9,5 +9,6 @@
 
 mod test_dispatching_outbox_impl;
 mod test_immediate_outbox_impl;
+mod test_outbox_dead_letters;
 mod test_outbox_transactional_processor;
 mod test_transactional_outbox_impl;
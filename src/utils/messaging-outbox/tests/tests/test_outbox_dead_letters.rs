@@ -0,0 +1,320 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::assert_matches::assert_matches;
+use std::sync::{Arc, Mutex};
+
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use database_common::NoOpDatabasePlugin;
+use dill::*;
+use internal_error::InternalError;
+use kamu_messaging_outbox_inmem::{
+    InMemoryOutboxDeadLetterRepository,
+    InMemoryOutboxMessageConsumptionRepository,
+    InMemoryOutboxMessageRepository,
+};
+use messaging_outbox::*;
+use serde::{Deserialize, Serialize};
+use time_source::{SystemTimeSource, SystemTimeSourceStub};
+
+use crate::{test_message_consumer, test_message_type};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+const TEST_PRODUCER_D: &str = "TEST-PRODUCER-D";
+const FLAKY_CONSUMER: &str = "TestMessageConsumerFlaky";
+const BAD_MESSAGE_BODY: &str = "bad";
+
+test_message_type!(D);
+
+test_message_consumer!(D, D, TEST_PRODUCER_D, Durable);
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_log::test(tokio::test)]
+async fn test_failing_consumer_does_not_block_others() {
+    let harness = DeadLettersHarness::new();
+
+    let bad_message = harness.post_message(BAD_MESSAGE_BODY).await;
+    let good_message = harness.post_message("good").await;
+
+    // 1st attempt: the healthy consumer gets everything,
+    // the flaky one fails on the first message and waits for a retry
+    harness.run_iteration().await;
+    harness.check_delivered(&[bad_message.clone(), good_message.clone()], &[]);
+    harness.check_boundaries(2, 0).await;
+    assert_eq!(harness.flaky_consumer.attempts(), 1);
+
+    // Backoff has not passed yet, the flaky consumer is not invoked
+    harness.run_iteration().await;
+    assert_eq!(harness.flaky_consumer.attempts(), 1);
+
+    // 2nd attempt after the base backoff
+    harness.advance_time(Duration::seconds(10));
+    harness.run_iteration().await;
+    assert_eq!(harness.flaky_consumer.attempts(), 2);
+    harness.check_boundaries(2, 0).await;
+    assert!(harness.list_dead_letters().await.is_empty());
+
+    // 3rd attempt waits twice longer
+    harness.advance_time(Duration::seconds(10));
+    harness.run_iteration().await;
+    assert_eq!(harness.flaky_consumer.attempts(), 2);
+
+    // The last attempt moves the message to dead letters,
+    // and the consumer proceeds with the next message
+    harness.advance_time(Duration::seconds(10));
+    harness.run_iteration().await;
+    assert_eq!(harness.flaky_consumer.attempts(), 4);
+    harness.check_delivered(&[bad_message, good_message.clone()], &[good_message]);
+    harness.check_boundaries(2, 2).await;
+
+    let dead_letters = harness.list_dead_letters().await;
+    assert_eq!(dead_letters.len(), 1);
+    assert_eq!(dead_letters[0].producer_name, TEST_PRODUCER_D);
+    assert_eq!(dead_letters[0].consumer_name, FLAKY_CONSUMER);
+    assert_eq!(dead_letters[0].message_id, OutboxMessageID::new(1));
+    assert_eq!(dead_letters[0].attempts, 3);
+    assert_eq!(
+        dead_letters[0].dead_lettered_at,
+        harness.initial_time + Duration::seconds(30)
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_log::test(tokio::test)]
+async fn test_replay_dead_letter() {
+    let harness = DeadLettersHarness::new();
+
+    let bad_message = harness.post_message(BAD_MESSAGE_BODY).await;
+    harness.exhaust_retries().await;
+    assert_eq!(harness.list_dead_letters().await.len(), 1);
+
+    // Replaying while the consumer still fails keeps the dead letter
+    let res = harness.replay(1).await;
+    assert_matches!(
+        res,
+        Err(ReplayDeadLetterError::ConsumerFailed(ReplayConsumerFailedError { consumer_name, .. }))
+            if consumer_name == FLAKY_CONSUMER
+    );
+    assert_eq!(harness.list_dead_letters().await.len(), 1);
+
+    // Once the consumer is fixed, the message is delivered and forgotten
+    harness.flaky_consumer.heal();
+    let res = harness.replay(1).await;
+    assert_matches!(res, Ok(()));
+    harness.check_delivered(&[bad_message.clone()], &[bad_message]);
+    assert!(harness.list_dead_letters().await.is_empty());
+
+    // Nothing to replay anymore
+    let res = harness.replay(1).await;
+    assert_matches!(res, Err(ReplayDeadLetterError::NotFound(_)));
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+struct TestMessageConsumerFlaky {
+    state: Arc<Mutex<FlakyConsumerState>>,
+}
+
+#[derive(Default)]
+struct FlakyConsumerState {
+    healed: bool,
+    attempts: usize,
+    captured_messages: Vec<TestMessageD>,
+}
+
+#[component(pub)]
+#[scope(Singleton)]
+#[interface(dyn MessageConsumer)]
+#[interface(dyn MessageConsumerT<TestMessageD>)]
+#[meta(MessageConsumerMeta {
+    consumer_name: FLAKY_CONSUMER,
+    feeding_producers: &[TEST_PRODUCER_D],
+    durability: MessageConsumptionDurability::Durable,
+})]
+impl TestMessageConsumerFlaky {
+    fn new() -> Self {
+        Self {
+            state: Arc::new(Mutex::new(FlakyConsumerState::default())),
+        }
+    }
+
+    fn heal(&self) {
+        self.state.lock().unwrap().healed = true;
+    }
+
+    fn attempts(&self) -> usize {
+        self.state.lock().unwrap().attempts
+    }
+
+    fn get_messages(&self) -> Vec<TestMessageD> {
+        self.state.lock().unwrap().captured_messages.clone()
+    }
+}
+
+impl MessageConsumer for TestMessageConsumerFlaky {}
+
+#[async_trait::async_trait]
+impl MessageConsumerT<TestMessageD> for TestMessageConsumerFlaky {
+    async fn consume_message(
+        &self,
+        _: &Catalog,
+        message: &TestMessageD,
+    ) -> Result<(), InternalError> {
+        let mut guard = self.state.lock().unwrap();
+        guard.attempts += 1;
+        if !guard.healed && message.body == BAD_MESSAGE_BODY {
+            return InternalError::bail("Cannot digest this message");
+        }
+        guard.captured_messages.push(message.clone());
+        Ok(())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+struct DeadLettersHarness {
+    catalog: Catalog,
+    initial_time: DateTime<Utc>,
+    time_source: SystemTimeSourceStub,
+    outbox_processor: Arc<OutboxTransactionalProcessor>,
+    outbox: Arc<dyn Outbox>,
+    flaky_consumer: Arc<TestMessageConsumerFlaky>,
+}
+
+impl DeadLettersHarness {
+    fn new() -> Self {
+        let initial_time = Utc.with_ymd_and_hms(2050, 1, 1, 12, 0, 0).unwrap();
+        let time_source = SystemTimeSourceStub::new_set(initial_time);
+
+        let mut b = CatalogBuilder::new();
+        b.add::<OutboxTransactionalProcessor>();
+        b.add_value(OutboxConfig {
+            max_attempts: 3,
+            retry_backoff_base: Duration::seconds(10),
+            retry_backoff_max: Duration::minutes(1),
+            ..OutboxConfig::default()
+        });
+        b.add::<InMemoryOutboxMessageRepository>();
+        b.add::<InMemoryOutboxMessageConsumptionRepository>();
+        b.add::<InMemoryOutboxDeadLetterRepository>();
+        b.add::<OutboxTransactionalImpl>();
+        b.bind::<dyn Outbox, OutboxTransactionalImpl>();
+        b.add::<OutboxDeadLetterServiceImpl>();
+        b.add_value(time_source.clone());
+        b.bind::<dyn SystemTimeSource, SystemTimeSourceStub>();
+
+        b.add::<TestMessageConsumerD>();
+        b.add::<TestMessageConsumerFlaky>();
+
+        register_message_dispatcher::<TestMessageD>(&mut b, TEST_PRODUCER_D);
+
+        NoOpDatabasePlugin::init_database_components(&mut b);
+
+        let catalog = b.build();
+
+        let outbox_processor = catalog.get_one::<OutboxTransactionalProcessor>().unwrap();
+        let outbox = catalog.get_one::<dyn Outbox>().unwrap();
+        let flaky_consumer = catalog.get_one::<TestMessageConsumerFlaky>().unwrap();
+
+        Self {
+            catalog,
+            initial_time,
+            time_source,
+            outbox_processor,
+            outbox,
+            flaky_consumer,
+        }
+    }
+
+    async fn post_message(&self, body: &str) -> TestMessageD {
+        let message = TestMessageD {
+            body: body.to_string(),
+        };
+        self.outbox
+            .post_message(TEST_PRODUCER_D, message.clone())
+            .await
+            .unwrap();
+        message
+    }
+
+    async fn run_iteration(&self) {
+        self.outbox_processor
+            .run_single_iteration_only()
+            .await
+            .unwrap();
+    }
+
+    async fn exhaust_retries(&self) {
+        for _ in 0..3 {
+            self.run_iteration().await;
+            self.advance_time(Duration::minutes(1));
+        }
+    }
+
+    fn advance_time(&self, duration: Duration) {
+        let now = self.time_source.now();
+        self.time_source.set(now + duration);
+    }
+
+    async fn replay(&self, message_id: i64) -> Result<(), ReplayDeadLetterError> {
+        let dead_letter_service = self
+            .catalog
+            .get_one::<dyn OutboxDeadLetterService>()
+            .unwrap();
+        dead_letter_service
+            .replay_dead_letter(
+                TEST_PRODUCER_D,
+                FLAKY_CONSUMER,
+                OutboxMessageID::new(message_id),
+            )
+            .await
+    }
+
+    async fn list_dead_letters(&self) -> Vec<OutboxDeadLetter> {
+        let dead_letter_service = self
+            .catalog
+            .get_one::<dyn OutboxDeadLetterService>()
+            .unwrap();
+        dead_letter_service.list_dead_letters().await.unwrap()
+    }
+
+    fn check_delivered(&self, healthy_messages: &[TestMessageD], flaky_messages: &[TestMessageD]) {
+        let healthy_consumer = self.catalog.get_one::<TestMessageConsumerD>().unwrap();
+        assert_eq!(healthy_consumer.get_messages(), healthy_messages);
+        assert_eq!(self.flaky_consumer.get_messages(), flaky_messages);
+    }
+
+    async fn check_boundaries(&self, healthy_boundary: i64, flaky_boundary: i64) {
+        let consumption_repository = self
+            .catalog
+            .get_one::<dyn OutboxMessageConsumptionRepository>()
+            .unwrap();
+
+        for (consumer_name, expected_boundary) in [
+            ("TestMessageConsumerD", healthy_boundary),
+            (FLAKY_CONSUMER, flaky_boundary),
+        ] {
+            let boundary = consumption_repository
+                .find_consumption_boundary(consumer_name, TEST_PRODUCER_D)
+                .await
+                .unwrap()
+                .unwrap();
+            assert_eq!(
+                boundary.last_consumed_message_id,
+                OutboxMessageID::new(expected_boundary),
+                "Unexpected boundary of {consumer_name}"
+            );
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////